@@ -13,7 +13,9 @@ CREATE TABLE connector_events_queue
     `latency` UInt128,
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
-    `refund_id` Nullable(String)
+    `refund_id` Nullable(String),
+    `merchant_connector_id` Nullable(String),
+    `error_code` Nullable(String)
 )
 ENGINE = Kafka
 SETTINGS kafka_broker_list = 'kafka0:29092', kafka_topic_list = 'hyperswitch-outgoing-connector-events', kafka_group_name = 'hyper', kafka_format = 'JSONEachRow', kafka_handle_error_mode = 'stream';
@@ -55,6 +57,8 @@ CREATE TABLE connector_events (
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
     `refund_id` Nullable(String),
+    `merchant_connector_id` Nullable(String),
+    `error_code` Nullable(String),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector_name TYPE bloom_filter GRANULARITY 1,
     INDEX statusIndex status_code TYPE bloom_filter GRANULARITY 1
@@ -149,7 +153,9 @@ CREATE MATERIALIZED VIEW connector_events_mv TO connector_events (
     `latency` UInt128,
     `method` LowCardinality(String),
     `refund_id` Nullable(String),
-    `dispute_id` Nullable(String)
+    `dispute_id` Nullable(String),
+    `merchant_connector_id` Nullable(String),
+    `error_code` Nullable(String)
 ) AS
 SELECT
     merchant_id,
@@ -167,7 +173,9 @@ SELECT
     latency,
     method,
     refund_id,
-    dispute_id
+    dispute_id,
+    merchant_connector_id,
+    error_code
 FROM
     connector_events_queue
 WHERE
@@ -15,6 +15,10 @@ CREATE TABLE outgoing_webhook_events_queue (
     `initial_attempt_id` Nullable(String),
     `status_code` Nullable(UInt16),
     `delivery_attempt` LowCardinality(String),
+    `profile_id` Nullable(String),
+    `endpoint` Nullable(String),
+    `status_code_class` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt128),
     `created_at_timestamp` DateTime64(3)
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-outgoing-webhook-events',
@@ -39,6 +43,10 @@ CREATE TABLE outgoing_webhook_events (
     `initial_attempt_id` Nullable(String),
     `status_code` Nullable(UInt16),
     `delivery_attempt` LowCardinality(String),
+    `profile_id` Nullable(String),
+    `endpoint` Nullable(String),
+    `status_code_class` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt128),
     `created_at` DateTime64(3),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     INDEX eventIndex event_type TYPE bloom_filter GRANULARITY 1,
@@ -70,6 +78,10 @@ CREATE TABLE outgoing_webhook_events_audit (
     `initial_attempt_id` Nullable(String),
     `status_code` Nullable(UInt16),
     `delivery_attempt` LowCardinality(String),
+    `profile_id` Nullable(String),
+    `endpoint` Nullable(String),
+    `status_code_class` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt128),
     `created_at` DateTime64(3),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4)
 ) ENGINE = MergeTree PARTITION BY merchant_id
@@ -93,6 +105,10 @@ CREATE MATERIALIZED VIEW outgoing_webhook_events_mv TO outgoing_webhook_events (
     `initial_attempt_id` Nullable(String),
     `status_code` Nullable(UInt16),
     `delivery_attempt` LowCardinality(String),
+    `profile_id` Nullable(String),
+    `endpoint` Nullable(String),
+    `status_code_class` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt128),
     `created_at` DateTime64(3),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4)
 ) AS
@@ -113,6 +129,10 @@ SELECT
     initial_attempt_id,
     status_code,
     delivery_attempt,
+    profile_id,
+    endpoint,
+    status_code_class,
+    latency,
     created_at_timestamp AS created_at,
     now() AS inserted_at
 FROM
@@ -137,6 +157,10 @@ CREATE MATERIALIZED VIEW outgoing_webhook_events_audit_mv TO outgoing_webhook_ev
     `initial_attempt_id` Nullable(String),
     `status_code` Nullable(UInt16),
     `delivery_attempt` LowCardinality(String),
+    `profile_id` Nullable(String),
+    `endpoint` Nullable(String),
+    `status_code_class` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt128),
     `created_at` DateTime64(3),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4)
 ) AS
@@ -157,6 +181,10 @@ SELECT
     initial_attempt_id,
     status_code,
     delivery_attempt,
+    profile_id,
+    endpoint,
+    status_code_class,
+    latency,
     created_at_timestamp AS created_at,
     now() AS inserted_at
 FROM
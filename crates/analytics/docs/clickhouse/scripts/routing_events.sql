@@ -18,7 +18,9 @@ CREATE TABLE routing_events_queue
     `created_at` DateTime64(9),
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
-    `routing_approach` Nullable(String)
+    `routing_approach` Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String)
 )
 ENGINE = Kafka
 SETTINGS kafka_broker_list = 'kafka0:29092', kafka_topic_list = 'hyperswitch-routing-api-events', kafka_group_name = 'hyper', kafka_format = 'JSONEachRow', kafka_handle_error_mode = 'stream';
@@ -63,6 +65,8 @@ CREATE TABLE routing_events (
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
     `routing_approach` Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX profileIndex profile_id TYPE bloom_filter GRANULARITY 1
 ) ENGINE = MergeTree
@@ -90,6 +94,8 @@ CREATE TABLE routing_events_audit (
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
     `routing_approach` Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX profileIndex profile_id TYPE bloom_filter GRANULARITY 1
 ) ENGINE = MergeTree 
@@ -117,7 +123,9 @@ CREATE MATERIALIZED VIEW routing_events_mv TO routing_events (
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
-    `routing_approach` Nullable(String)
+    `routing_approach` Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String)
 ) AS
 SELECT
     merchant_id,
@@ -138,7 +146,9 @@ SELECT
     now() AS inserted_at,
     method,
     routing_engine,
-    routing_approach
+    routing_approach,
+    routing_algorithm_id,
+    rule_name
 FROM
     routing_events_queue
 WHERE
@@ -163,7 +173,9 @@ CREATE MATERIALIZED VIEW routing_events_audit_mv TO routing_events_audit (
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
-    `routing_approach` Nullable(String)
+    `routing_approach` Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String)
 ) AS
 SELECT
     merchant_id,
@@ -184,7 +196,9 @@ SELECT
     now() AS inserted_at,
     method,
     routing_engine,
-    routing_approach
+    routing_approach,
+    routing_algorithm_id,
+    rule_name
 FROM
     routing_events_queue
 WHERE
@@ -0,0 +1,19 @@
+-- Table read by the Sqlx analytics provider for connector events metrics.
+-- It is expected to be populated from the `hyperswitch-outgoing-connector-events` topic.
+CREATE TABLE IF NOT EXISTS connector_events (
+    request_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    dispute_id VARCHAR(64),
+    connector_name VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64),
+    flow VARCHAR(64) NOT NULL,
+    method VARCHAR(16) NOT NULL,
+    status_code INTEGER NOT NULL,
+    error_code VARCHAR(255),
+    latency BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS connector_events_merchant_id_created_at_index ON connector_events (merchant_id, created_at);
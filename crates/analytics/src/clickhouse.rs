@@ -29,10 +29,12 @@ use crate::{
         metrics::{latency::LatencyAvg, ApiEventMetricRow},
     },
     auth_events::filters::AuthEventFilterRow,
-    connector_events::events::ConnectorEventsResult,
+    connector_events::{events::ConnectorEventsResult, metrics::ConnectorEventMetricRow},
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
-    outgoing_webhook_event::events::OutgoingWebhookLogsResult,
-    routing_events::events::RoutingEventsResult,
+    outgoing_webhook_event::{
+        events::OutgoingWebhookLogsResult, metrics::OutgoingWebhookEventMetricRow,
+    },
+    routing_events::{events::RoutingEventsResult, metrics::RoutingEventMetricRow},
     sdk_events::events::SdkEventsResult,
     types::TableEngine,
 };
@@ -151,9 +153,12 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::SdkEventsAnalytics
             | AnalyticsCollection::ApiEvents
             | AnalyticsCollection::ConnectorEvents
+            | AnalyticsCollection::ConnectorEventsAnalytics
             | AnalyticsCollection::RoutingEvents
+            | AnalyticsCollection::RoutingEventsAnalytics
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
+            | AnalyticsCollection::OutgoingWebhookEventAnalytics
             | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
         }
     }
@@ -189,11 +194,17 @@ impl super::api_event::events::ApiLogsFilterAnalytics for ClickhouseClient {}
 impl super::api_event::filters::ApiEventFilterAnalytics for ClickhouseClient {}
 impl super::api_event::metrics::ApiEventMetricAnalytics for ClickhouseClient {}
 impl super::connector_events::events::ConnectorEventLogAnalytics for ClickhouseClient {}
+impl super::connector_events::metrics::ConnectorEventMetricAnalytics for ClickhouseClient {}
 impl super::routing_events::events::RoutingEventLogAnalytics for ClickhouseClient {}
+impl super::routing_events::metrics::RoutingEventMetricAnalytics for ClickhouseClient {}
 impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
     for ClickhouseClient
 {
 }
+impl super::outgoing_webhook_event::metrics::OutgoingWebhookEventMetricAnalytics
+    for ClickhouseClient
+{
+}
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
 
@@ -239,6 +250,26 @@ impl TryInto<ConnectorEventsResult> for serde_json::Value {
     }
 }
 
+impl TryInto<ConnectorEventMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorEventMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorEventMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<OutgoingWebhookEventMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<OutgoingWebhookEventMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse OutgoingWebhookEventMetricRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<RoutingEventsResult> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -458,6 +489,16 @@ impl TryInto<ActivePaymentsMetricRow> for serde_json::Value {
     }
 }
 
+impl TryInto<RoutingEventMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<RoutingEventMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse RoutingEventMetricRow in clickhouse results",
+        ))
+    }
+}
+
 impl ToSql<ClickhouseClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.assume_utc().unix_timestamp().to_string())
@@ -479,12 +520,15 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::PaymentIntent => Ok("payment_intents".to_string()),
             Self::PaymentIntentSessionized => Ok("sessionizer_payment_intents".to_string()),
             Self::ConnectorEvents => Ok("connector_events_audit".to_string()),
+            Self::ConnectorEventsAnalytics => Ok("connector_events".to_string()),
             Self::OutgoingWebhookEvent => Ok("outgoing_webhook_events_audit".to_string()),
+            Self::OutgoingWebhookEventAnalytics => Ok("outgoing_webhook_events".to_string()),
             Self::Dispute => Ok("dispute".to_string()),
             Self::DisputeSessionized => Ok("sessionizer_dispute".to_string()),
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
             Self::Authentications => Ok("authentications".to_string()),
             Self::RoutingEvents => Ok("routing_events_audit".to_string()),
+            Self::RoutingEventsAnalytics => Ok("routing_events".to_string()),
         }
     }
 }
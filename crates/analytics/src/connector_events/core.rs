@@ -1,9 +1,29 @@
-use api_models::analytics::connector_events::ConnectorEventsRequest;
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    connector_events::{
+        ConnectorEventsMetricsBucketIdentifier, ConnectorEventsMetricsBucketResponse,
+        ConnectorEventsMetricsBucketValue, ConnectorEventsRequest,
+    },
+    AnalyticsMetadata, GetConnectorEventMetricRequest, MetricsResponse,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
 
-use super::events::{get_connector_events, ConnectorEventsResult};
-use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+use super::{
+    events::{get_connector_events, ConnectorEventsResult},
+    metrics::ConnectorEventMetricRow,
+};
+use crate::{
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    types::FiltersError,
+    AnalyticsProvider,
+};
 
 pub async fn connector_events_core(
     pool: &AnalyticsProvider,
@@ -24,3 +44,98 @@ pub async fn connector_events_core(
     .switch()?;
     Ok(data)
 }
+
+#[instrument(skip_all)]
+pub async fn get_connector_event_metrics(
+    pool: &AnalyticsProvider,
+    merchant_id: &common_utils::id_type::MerchantId,
+    req: GetConnectorEventMetricRequest,
+) -> AnalyticsResult<MetricsResponse<ConnectorEventsMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        ConnectorEventsMetricsBucketIdentifier,
+        ConnectorEventMetricRow,
+    > = HashMap::new();
+
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_connector_events_metrics_query",
+            connector_event_metric = metric_type.as_ref()
+        );
+
+        let merchant_id_scoped = merchant_id.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_connector_event_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &merchant_id_scoped,
+                        &req.filters,
+                        req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = router_env::metric_attributes!(
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        );
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+        for (id, value) in data {
+            metrics_accumulator
+                .entry(id)
+                .and_modify(|data| {
+                    data.latency_p50 = data.latency_p50.or(value.latency_p50);
+                    data.latency_p90 = data.latency_p90.or(value.latency_p90);
+                    data.latency_p99 = data.latency_p99.or(value.latency_p99);
+                    data.status_code_count = data.status_code_count.or(value.status_code_count);
+                    data.timeout_count = data.timeout_count.or(value.timeout_count);
+                    data.error_code_count = data.error_code_count.or(value.error_code_count);
+                })
+                .or_insert(value);
+        }
+    }
+
+    let query_data: Vec<ConnectorEventsMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| ConnectorEventsMetricsBucketResponse {
+            values: ConnectorEventsMetricsBucketValue {
+                latency_p50: val.latency_p50.and_then(|v| u64::try_from(v).ok()),
+                latency_p90: val.latency_p90.and_then(|v| u64::try_from(v).ok()),
+                latency_p99: val.latency_p99.and_then(|v| u64::try_from(v).ok()),
+                status_code_count: val.status_code_count.and_then(|v| u64::try_from(v).ok()),
+                timeout_count: val.timeout_count.and_then(|v| u64::try_from(v).ok()),
+                error_code_count: val.error_code_count.and_then(|v| u64::try_from(v).ok()),
+            },
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
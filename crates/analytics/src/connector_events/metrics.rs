@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    connector_events::{
+        ConnectorEventsDimensions, ConnectorEventsFilters, ConnectorEventsMetrics,
+        ConnectorEventsMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, MetricsResult},
+};
+
+mod error_code_count;
+mod latency_percentiles;
+mod status_code_count;
+mod timeout_count;
+
+use error_code_count::ErrorCodeCount;
+use latency_percentiles::LatencyPercentiles;
+use status_code_count::StatusCodeCount;
+use timeout_count::TimeoutCount;
+
+#[derive(Debug, PartialEq, Eq, serde::Deserialize, Hash)]
+pub struct ConnectorEventMetricRow {
+    pub connector_name: Option<String>,
+    pub flow: Option<String>,
+    pub merchant_connector_id: Option<String>,
+    pub status_code: Option<i32>,
+    pub error_code: Option<String>,
+    pub latency_p50: Option<i64>,
+    pub latency_p90: Option<i64>,
+    pub latency_p99: Option<i64>,
+    pub status_code_count: Option<i64>,
+    pub timeout_count: Option<i64>,
+    pub error_code_count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait ConnectorEventMetricAnalytics: LoadRow<ConnectorEventMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait ConnectorEventMetric<T>
+where
+    T: AnalyticsDataSource + ConnectorEventMetricAnalytics,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorEventsDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &ConnectorEventsFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            ConnectorEventsMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> ConnectorEventMetric<T> for ConnectorEventsMetrics
+where
+    T: AnalyticsDataSource + ConnectorEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorEventsDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &ConnectorEventsFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            ConnectorEventsMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    > {
+        match self {
+            Self::LatencyPercentiles => {
+                LatencyPercentiles
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::StatusCodeCount => {
+                StatusCodeCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::TimeoutCount => {
+                TimeoutCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ErrorCodeCount => {
+                ErrorCodeCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+}
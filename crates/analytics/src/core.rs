@@ -41,6 +41,16 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_api_event_dimensions(),
         },
+        AnalyticsDomain::ConnectorEvents => GetInfoResponse {
+            metrics: utils::get_connector_event_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_connector_event_dimensions(),
+        },
+        AnalyticsDomain::OutgoingWebhookEvents => GetInfoResponse {
+            metrics: utils::get_outgoing_webhook_event_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_outgoing_webhook_event_dimensions(),
+        },
         AnalyticsDomain::Dispute => GetInfoResponse {
             metrics: utils::get_dispute_metrics_info(),
             download_dimensions: None,
@@ -51,6 +61,11 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_payment_dimensions(),
         },
+        AnalyticsDomain::RoutingEvents => GetInfoResponse {
+            metrics: utils::get_routing_event_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_routing_event_dimensions(),
+        },
     };
     Ok(info)
 }
@@ -23,6 +23,7 @@ mod sqlx;
 mod types;
 use api_event::metrics::{ApiEventMetric, ApiEventMetricRow};
 use common_utils::errors::CustomResult;
+use connector_events::metrics::{ConnectorEventMetric, ConnectorEventMetricRow};
 use disputes::metrics::{DisputeMetric, DisputeMetricRow};
 use enums::AuthInfo;
 use hyperswitch_interfaces::secrets_interface::{
@@ -45,14 +46,26 @@ use api_models::analytics::{
     auth_events::{
         AuthEventDimensions, AuthEventFilters, AuthEventMetrics, AuthEventMetricsBucketIdentifier,
     },
+    connector_events::{
+        ConnectorEventsDimensions, ConnectorEventsFilters, ConnectorEventsMetrics,
+        ConnectorEventsMetricsBucketIdentifier,
+    },
     disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics, DisputeMetricsBucketIdentifier},
     frm::{FrmDimensions, FrmFilters, FrmMetrics, FrmMetricsBucketIdentifier},
+    outgoing_webhook_event::{
+        OutgoingWebhookEventDimensions, OutgoingWebhookEventFilters, OutgoingWebhookEventMetrics,
+        OutgoingWebhookEventMetricsBucketIdentifier,
+    },
     payment_intents::{
         PaymentIntentDimensions, PaymentIntentFilters, PaymentIntentMetrics,
         PaymentIntentMetricsBucketIdentifier,
     },
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
+    routing_events::{
+        RoutingEventDimensions, RoutingEventFilters, RoutingEventMetrics,
+        RoutingEventMetricsBucketIdentifier,
+    },
     sdk_events::{
         SdkEventDimensions, SdkEventFilters, SdkEventMetrics, SdkEventMetricsBucketIdentifier,
     },
@@ -73,12 +86,14 @@ use self::{
     active_payments::metrics::{ActivePaymentsMetric, ActivePaymentsMetricRow},
     auth_events::metrics::{AuthEventMetric, AuthEventMetricRow},
     frm::metrics::{FrmMetric, FrmMetricRow},
+    outgoing_webhook_event::metrics::{OutgoingWebhookEventMetric, OutgoingWebhookEventMetricRow},
     payment_intents::metrics::{PaymentIntentMetric, PaymentIntentMetricRow},
     payments::{
         distribution::{PaymentDistribution, PaymentDistributionRow},
         metrics::{PaymentMetric, PaymentMetricRow},
     },
     refunds::metrics::{RefundMetric, RefundMetricRow},
+    routing_events::metrics::{RoutingEventMetric, RoutingEventMetricRow},
     sdk_events::metrics::{SdkEventMetric, SdkEventMetricRow},
     sqlx::SqlxClient,
     types::MetricsError,
@@ -969,6 +984,115 @@ impl AnalyticsProvider {
         }
     }
 
+    pub async fn get_connector_event_metrics(
+        &self,
+        metric: &ConnectorEventsMetrics,
+        dimensions: &[ConnectorEventsDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &ConnectorEventsFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        HashSet<(
+            ConnectorEventsMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    > {
+        match self {
+            Self::Sqlx(pool) => {
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::Clickhouse(ckh_pool)
+            | Self::CombinedCkh(_, ckh_pool)
+            | Self::CombinedSqlx(_, ckh_pool) => {
+                // Connector events are sourced from kafka, so prefer ckh whenever it is available
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    )
+                    .await
+            }
+        }
+    }
+
+    pub async fn get_outgoing_webhook_event_metrics(
+        &self,
+        metric: &OutgoingWebhookEventMetrics,
+        dimensions: &[OutgoingWebhookEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &OutgoingWebhookEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        HashSet<(
+            OutgoingWebhookEventMetricsBucketIdentifier,
+            OutgoingWebhookEventMetricRow,
+        )>,
+    > {
+        match self {
+            Self::Sqlx(_pool) => Err(report!(MetricsError::NotImplemented)),
+            Self::Clickhouse(ckh_pool)
+            | Self::CombinedCkh(_, ckh_pool)
+            | Self::CombinedSqlx(_, ckh_pool) => {
+                // Since outgoing webhook events are ckh only use ckh here
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    )
+                    .await
+            }
+        }
+    }
+
+    pub async fn get_routing_event_metrics(
+        &self,
+        metric: &RoutingEventMetrics,
+        dimensions: &[RoutingEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &RoutingEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<HashSet<(RoutingEventMetricsBucketIdentifier, RoutingEventMetricRow)>>
+    {
+        match self {
+            Self::Sqlx(_pool) => Err(report!(MetricsError::NotImplemented)),
+            Self::Clickhouse(ckh_pool)
+            | Self::CombinedCkh(_, ckh_pool)
+            | Self::CombinedSqlx(_, ckh_pool) => {
+                // Since routing events are ckh only use ckh here
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    )
+                    .await
+            }
+        }
+    }
+
     pub async fn from_conf(
         config: &AnalyticsConfig,
         tenant: &dyn storage_impl::config::TenantConfig,
@@ -1153,13 +1277,25 @@ pub enum AnalyticsFlow {
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
+    GetConnectorEventMetrics,
     GetOutgoingWebhookEvents,
+    GetOutgoingWebhookEventMetrics,
+    GetRoutingEventMetrics,
     GetGlobalSearchResults,
     GetSearchResults,
     GetDisputeFilters,
     GetDisputeMetrics,
     GetSankey,
     GetRoutingEvents,
+    CreateReportSchedule,
+    ListReportSchedules,
+    RetrieveReportSchedule,
+    DeleteReportSchedule,
+    DownloadReportExport,
+    CreateMetricAlertRule,
+    ListMetricAlertRules,
+    RetrieveMetricAlertRule,
+    DeleteMetricAlertRule,
 }
 
 impl FlowMetric for AnalyticsFlow {}
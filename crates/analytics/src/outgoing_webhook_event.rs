@@ -1,6 +1,13 @@
+pub mod accumulator;
 mod core;
 pub mod events;
+pub mod metrics;
+pub mod types;
+
+pub use accumulator::{
+    OutgoingWebhookEventMetricAccumulator, OutgoingWebhookEventMetricsAccumulator,
+};
 
 pub trait OutgoingWebhookEventAnalytics: events::OutgoingWebhookLogsFilterAnalytics {}
 
-pub use self::core::outgoing_webhook_events_core;
+pub use self::core::{get_outgoing_webhook_event_metrics, outgoing_webhook_events_core};
@@ -0,0 +1,140 @@
+use api_models::analytics::outgoing_webhook_event::OutgoingWebhookEventMetricsBucketValue;
+use router_env::logger;
+
+use super::metrics::OutgoingWebhookEventMetricRow;
+
+/// Status code class recorded for webhooks acknowledged by the merchant endpoint
+const SUCCESS_STATUS_CODE_CLASS: &str = "2xx";
+
+#[derive(Debug, Default)]
+pub struct OutgoingWebhookEventMetricsAccumulator {
+    pub delivery_count: CountAccumulator,
+    pub delivery_success_rate: SuccessRateAccumulator,
+    pub first_attempt_success_rate: SuccessRateAccumulator,
+    pub retry_depth: RetryDepthAccumulator,
+    pub delivery_latency: AverageLatencyAccumulator,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct SuccessRateAccumulator {
+    pub success: i64,
+    pub total: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct RetryDepthAccumulator {
+    pub attempts: i64,
+    pub webhooks: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct AverageLatencyAccumulator {
+    pub latency_sum: u64,
+    pub count: u64,
+}
+
+pub trait OutgoingWebhookEventMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &OutgoingWebhookEventMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl OutgoingWebhookEventMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &OutgoingWebhookEventMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl OutgoingWebhookEventMetricAccumulator for SuccessRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &OutgoingWebhookEventMetricRow) {
+        if metrics.status_code_class.as_deref() == Some(SUCCESS_STATUS_CODE_CLASS) {
+            self.success += metrics.count.unwrap_or_default();
+        }
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.success).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.total).ok()?),
+            )
+        }
+    }
+}
+
+impl OutgoingWebhookEventMetricAccumulator for RetryDepthAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &OutgoingWebhookEventMetricRow) {
+        self.attempts += metrics.count.unwrap_or_default();
+        self.webhooks += metrics.webhook_count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.webhooks <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.attempts).ok()?)
+                    / f64::from(u32::try_from(self.webhooks).ok()?),
+            )
+        }
+    }
+}
+
+impl OutgoingWebhookEventMetricAccumulator for AverageLatencyAccumulator {
+    type MetricOutput = Option<u64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &OutgoingWebhookEventMetricRow) {
+        let count = metrics.count.and_then(|count| u64::try_from(count).ok());
+
+        match (metrics.latency_sum, count) {
+            (Some(latency_sum), Some(count)) => {
+                self.latency_sum += latency_sum;
+                self.count += count;
+            }
+            _ => {
+                logger::error!(message="Dropping metrics for average latency accumulator", metric=?metrics);
+            }
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        self.latency_sum.checked_div(self.count)
+    }
+}
+
+impl OutgoingWebhookEventMetricsAccumulator {
+    pub fn collect(self) -> OutgoingWebhookEventMetricsBucketValue {
+        OutgoingWebhookEventMetricsBucketValue {
+            delivery_count: self.delivery_count.collect(),
+            delivery_success_rate: self.delivery_success_rate.collect(),
+            first_attempt_success_rate: self.first_attempt_success_rate.collect(),
+            retry_depth: self.retry_depth.collect(),
+            delivery_latency: self.delivery_latency.collect(),
+        }
+    }
+}
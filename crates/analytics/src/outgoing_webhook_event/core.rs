@@ -1,9 +1,29 @@
-use api_models::analytics::outgoing_webhook_event::OutgoingWebhookLogsRequest;
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    outgoing_webhook_event::{
+        OutgoingWebhookEventMetrics, OutgoingWebhookEventMetricsBucketIdentifier,
+        OutgoingWebhookEventMetricsBucketResponse, OutgoingWebhookLogsRequest,
+    },
+    AnalyticsMetadata, GetOutgoingWebhookEventMetricRequest, MetricsResponse,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
 
-use super::events::{get_outgoing_webhook_event, OutgoingWebhookLogsResult};
-use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+use super::{
+    events::{get_outgoing_webhook_event, OutgoingWebhookLogsResult},
+    OutgoingWebhookEventMetricAccumulator, OutgoingWebhookEventMetricsAccumulator,
+};
+use crate::{
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    types::FiltersError,
+    AnalyticsProvider,
+};
 
 pub async fn outgoing_webhook_events_core(
     pool: &AnalyticsProvider,
@@ -24,3 +44,100 @@ pub async fn outgoing_webhook_events_core(
     .switch()?;
     Ok(data)
 }
+
+#[instrument(skip_all)]
+pub async fn get_outgoing_webhook_event_metrics(
+    pool: &AnalyticsProvider,
+    merchant_id: &common_utils::id_type::MerchantId,
+    req: GetOutgoingWebhookEventMetricRequest,
+) -> AnalyticsResult<MetricsResponse<OutgoingWebhookEventMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        OutgoingWebhookEventMetricsBucketIdentifier,
+        OutgoingWebhookEventMetricsAccumulator,
+    > = HashMap::new();
+
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_outgoing_webhook_events_metrics_query",
+            outgoing_webhook_event_metric = metric_type.as_ref()
+        );
+
+        let merchant_id_scoped = merchant_id.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_outgoing_webhook_event_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &merchant_id_scoped,
+                        &req.filters,
+                        req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = router_env::metric_attributes!(
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        );
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                OutgoingWebhookEventMetrics::DeliveryCount => {
+                    metrics_builder.delivery_count.add_metrics_bucket(&value)
+                }
+                OutgoingWebhookEventMetrics::DeliverySuccessRate => metrics_builder
+                    .delivery_success_rate
+                    .add_metrics_bucket(&value),
+                OutgoingWebhookEventMetrics::FirstAttemptSuccessRate => metrics_builder
+                    .first_attempt_success_rate
+                    .add_metrics_bucket(&value),
+                OutgoingWebhookEventMetrics::RetryDepth => {
+                    metrics_builder.retry_depth.add_metrics_bucket(&value)
+                }
+                OutgoingWebhookEventMetrics::DeliveryLatency => {
+                    metrics_builder.delivery_latency.add_metrics_bucket(&value)
+                }
+            }
+        }
+    }
+
+    let query_data: Vec<OutgoingWebhookEventMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| OutgoingWebhookEventMetricsBucketResponse {
+            values: val.collect(),
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
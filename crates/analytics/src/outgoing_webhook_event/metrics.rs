@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    outgoing_webhook_event::{
+        OutgoingWebhookEventDimensions, OutgoingWebhookEventFilters, OutgoingWebhookEventMetrics,
+        OutgoingWebhookEventMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, MetricsResult},
+};
+
+mod delivery_count;
+mod delivery_latency;
+mod delivery_success_rate;
+mod first_attempt_success_rate;
+mod retry_depth;
+
+use delivery_count::DeliveryCount;
+use delivery_latency::DeliveryLatency;
+use delivery_success_rate::DeliverySuccessRate;
+use first_attempt_success_rate::FirstAttemptSuccessRate;
+use retry_depth::RetryDepth;
+
+#[derive(Debug, PartialEq, Eq, serde::Deserialize, Hash)]
+pub struct OutgoingWebhookEventMetricRow {
+    pub event_type: Option<String>,
+    pub status_code_class: Option<String>,
+    pub endpoint: Option<String>,
+    pub profile_id: Option<String>,
+    pub count: Option<i64>,
+    pub webhook_count: Option<i64>,
+    pub latency_sum: Option<u64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait OutgoingWebhookEventMetricAnalytics: LoadRow<OutgoingWebhookEventMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait OutgoingWebhookEventMetric<T>
+where
+    T: AnalyticsDataSource + OutgoingWebhookEventMetricAnalytics,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[OutgoingWebhookEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &OutgoingWebhookEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            OutgoingWebhookEventMetricsBucketIdentifier,
+            OutgoingWebhookEventMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> OutgoingWebhookEventMetric<T> for OutgoingWebhookEventMetrics
+where
+    T: AnalyticsDataSource + OutgoingWebhookEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[OutgoingWebhookEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &OutgoingWebhookEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            OutgoingWebhookEventMetricsBucketIdentifier,
+            OutgoingWebhookEventMetricRow,
+        )>,
+    > {
+        match self {
+            Self::DeliveryCount => {
+                DeliveryCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::DeliverySuccessRate => {
+                DeliverySuccessRate
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::FirstAttemptSuccessRate => {
+                FirstAttemptSuccessRate
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::RetryDepth => {
+                RetryDepth
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::DeliveryLatency => {
+                DeliveryLatency
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+}
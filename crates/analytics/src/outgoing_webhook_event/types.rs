@@ -0,0 +1,52 @@
+use api_models::analytics::outgoing_webhook_event::{
+    OutgoingWebhookEventDimensions, OutgoingWebhookEventFilters,
+};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for OutgoingWebhookEventFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.event_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    OutgoingWebhookEventDimensions::EventType,
+                    &self.event_type,
+                )
+                .attach_printable("Error adding event_type filter")?;
+        }
+        if !self.status_code_class.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    OutgoingWebhookEventDimensions::StatusCodeClass,
+                    &self.status_code_class,
+                )
+                .attach_printable("Error adding status_code_class filter")?;
+        }
+        if !self.endpoint.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    OutgoingWebhookEventDimensions::Endpoint,
+                    &self.endpoint,
+                )
+                .attach_printable("Error adding endpoint filter")?;
+        }
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    OutgoingWebhookEventDimensions::ProfileId,
+                    &self.profile_id,
+                )
+                .attach_printable("Error adding profile_id filter")?;
+        }
+
+        Ok(())
+    }
+}
@@ -5,11 +5,14 @@ use api_models::{
         self as analytics_api,
         api_event::ApiEventDimensions,
         auth_events::{AuthEventDimensions, AuthEventFlows},
+        connector_events::ConnectorEventsDimensions,
         disputes::DisputeDimensions,
         frm::{FrmDimensions, FrmTransactionType},
+        outgoing_webhook_event::OutgoingWebhookEventDimensions,
         payment_intents::PaymentIntentDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
         refunds::{RefundDimensions, RefundDistributions, RefundType},
+        routing_events::RoutingEventDimensions,
         sdk_events::{SdkEventDimensions, SdkEventNames},
         Granularity,
     },
@@ -21,6 +24,7 @@ use api_models::{
 };
 use common_enums::{
     AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, TransactionStatus,
+    WebhookDeliveryAttempt,
 };
 use common_utils::{
     errors::{CustomResult, ParsingError},
@@ -529,7 +533,14 @@ impl_to_sql_for_to_string!(
     DisputeDimensions,
     DisputeStage,
     AuthEventDimensions,
-    &AuthEventDimensions
+    &AuthEventDimensions,
+    &ConnectorEventsDimensions,
+    ConnectorEventsDimensions,
+    &OutgoingWebhookEventDimensions,
+    OutgoingWebhookEventDimensions,
+    WebhookDeliveryAttempt,
+    &RoutingEventDimensions,
+    RoutingEventDimensions
 );
 
 #[derive(Debug, Clone, Copy)]
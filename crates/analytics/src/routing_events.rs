@@ -1,5 +1,11 @@
+pub mod accumulator;
 mod core;
 pub mod events;
+pub mod metrics;
+pub mod types;
+
+pub use accumulator::{RoutingEventMetricAccumulator, RoutingEventMetricsAccumulator};
+
 pub trait RoutingEventAnalytics: events::RoutingEventLogAnalytics {}
 
-pub use self::core::routing_events_core;
+pub use self::core::{get_routing_event_metrics, routing_events_core};
@@ -0,0 +1,78 @@
+use api_models::analytics::routing_events::RoutingEventMetricsBucketValue;
+
+use super::metrics::RoutingEventMetricRow;
+
+#[derive(Debug, Default)]
+pub struct RoutingEventMetricsAccumulator {
+    pub rule_hit_count: CountAccumulator,
+    pub connector_distribution: CountAccumulator,
+    pub rule_success_rate: SuccessRateAccumulator,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct SuccessRateAccumulator {
+    pub success: i64,
+    pub total: i64,
+}
+
+pub trait RoutingEventMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &RoutingEventMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl RoutingEventMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &RoutingEventMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl RoutingEventMetricAccumulator for SuccessRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &RoutingEventMetricRow) {
+        if metrics.is_successful.unwrap_or_default() > 0 {
+            self.success += metrics.count.unwrap_or_default();
+        }
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.success).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.total).ok()?),
+            )
+        }
+    }
+}
+
+impl RoutingEventMetricsAccumulator {
+    pub fn collect(self) -> RoutingEventMetricsBucketValue {
+        RoutingEventMetricsBucketValue {
+            rule_hit_count: self.rule_hit_count.collect(),
+            connector_distribution: self.connector_distribution.collect(),
+            rule_success_rate: self.rule_success_rate.collect(),
+        }
+    }
+}
@@ -1,9 +1,29 @@
-use api_models::analytics::routing_events::RoutingEventsRequest;
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    routing_events::{
+        RoutingEventMetrics, RoutingEventMetricsBucketIdentifier,
+        RoutingEventMetricsBucketResponse, RoutingEventsRequest,
+    },
+    AnalyticsMetadata, GetRoutingEventMetricRequest, MetricsResponse,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
 
-use super::events::{get_routing_events, RoutingEventsResult};
-use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+use super::{
+    events::{get_routing_events, RoutingEventsResult},
+    RoutingEventMetricAccumulator, RoutingEventMetricsAccumulator,
+};
+use crate::{
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    types::FiltersError,
+    AnalyticsProvider,
+};
 
 pub async fn routing_events_core(
     pool: &AnalyticsProvider,
@@ -24,3 +44,94 @@ pub async fn routing_events_core(
     .switch()?;
     Ok(data)
 }
+
+#[instrument(skip_all)]
+pub async fn get_routing_event_metrics(
+    pool: &AnalyticsProvider,
+    merchant_id: &common_utils::id_type::MerchantId,
+    req: GetRoutingEventMetricRequest,
+) -> AnalyticsResult<MetricsResponse<RoutingEventMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        RoutingEventMetricsBucketIdentifier,
+        RoutingEventMetricsAccumulator,
+    > = HashMap::new();
+
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_routing_events_metrics_query",
+            routing_event_metric = metric_type.as_ref()
+        );
+
+        let merchant_id_scoped = merchant_id.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_routing_event_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &merchant_id_scoped,
+                        &req.filters,
+                        req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = router_env::metric_attributes!(
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        );
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                RoutingEventMetrics::RuleHitCount => {
+                    metrics_builder.rule_hit_count.add_metrics_bucket(&value)
+                }
+                RoutingEventMetrics::ConnectorDistribution => metrics_builder
+                    .connector_distribution
+                    .add_metrics_bucket(&value),
+                RoutingEventMetrics::RuleSuccessRate => {
+                    metrics_builder.rule_success_rate.add_metrics_bucket(&value)
+                }
+            }
+        }
+    }
+
+    let query_data: Vec<RoutingEventMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| RoutingEventMetricsBucketResponse {
+            values: val.collect(),
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
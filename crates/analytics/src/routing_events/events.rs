@@ -71,4 +71,6 @@ pub struct RoutingEventsResult {
     pub method: String,
     pub routing_engine: String,
     pub routing_approach: Option<String>,
+    pub routing_algorithm_id: Option<String>,
+    pub rule_name: Option<String>,
 }
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    routing_events::{
+        RoutingEventDimensions, RoutingEventFilters, RoutingEventMetrics,
+        RoutingEventMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, MetricsResult},
+};
+
+mod connector_distribution;
+mod rule_hit_count;
+mod rule_success_rate;
+
+use connector_distribution::ConnectorDistribution;
+use rule_hit_count::RuleHitCount;
+use rule_success_rate::RuleSuccessRate;
+
+#[derive(Debug, PartialEq, Eq, serde::Deserialize, Hash)]
+pub struct RoutingEventMetricRow {
+    pub routing_algorithm_id: Option<String>,
+    pub rule_name: Option<String>,
+    pub payment_connector: Option<String>,
+    pub routing_approach: Option<String>,
+    pub profile_id: Option<String>,
+    pub is_successful: Option<i64>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait RoutingEventMetricAnalytics: LoadRow<RoutingEventMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait RoutingEventMetric<T>
+where
+    T: AnalyticsDataSource + RoutingEventMetricAnalytics,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[RoutingEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &RoutingEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(RoutingEventMetricsBucketIdentifier, RoutingEventMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> RoutingEventMetric<T> for RoutingEventMetrics
+where
+    T: AnalyticsDataSource + RoutingEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[RoutingEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &RoutingEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(RoutingEventMetricsBucketIdentifier, RoutingEventMetricRow)>> {
+        match self {
+            Self::RuleHitCount => {
+                RuleHitCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ConnectorDistribution => {
+                ConnectorDistribution
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::RuleSuccessRate => {
+                RuleSuccessRate
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+}
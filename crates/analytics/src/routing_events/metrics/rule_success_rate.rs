@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    routing_events::{
+        RoutingEventDimensions, RoutingEventFilters, RoutingEventMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use api_models::enums::AttemptStatus;
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::RoutingEventMetricRow;
+use crate::{
+    query::{
+        filter_type_to_sql, Aggregate, FilterTypes, GroupByClause, QueryBuilder, QueryFilter,
+        SeriesBucket, ToSql, Window,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult, TableEngine},
+};
+
+#[derive(Default)]
+pub(super) struct RuleSuccessRate;
+
+#[async_trait::async_trait]
+impl<T> super::RoutingEventMetric<T> for RuleSuccessRate
+where
+    T: AnalyticsDataSource + super::RoutingEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[RoutingEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &RoutingEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(RoutingEventMetricsBucketIdentifier, RoutingEventMetricRow)>> {
+        // Routing events do not carry the payment outcome, so it is looked up from the payment
+        // attempts of the merchant
+        let payment_attempts = <AnalyticsCollection as ToSql<T>>::to_sql(
+            &AnalyticsCollection::Payment,
+            &TableEngine::BasicTree,
+        )
+        .change_context(MetricsError::QueryBuildingError)?;
+        // The attempts are bounded by the same time range as the routing events, as an attempt is
+        // created when its routing decision is made
+        let mut attempts_time_range = vec![filter_type_to_sql(
+            "created_at",
+            FilterTypes::Gte,
+            &<PrimitiveDateTime as ToSql<T>>::to_sql(
+                &time_range.start_time,
+                &TableEngine::BasicTree,
+            )
+            .change_context(MetricsError::QueryBuildingError)?,
+        )];
+        if let Some(end_time) = time_range.end_time {
+            attempts_time_range.push(filter_type_to_sql(
+                "created_at",
+                FilterTypes::Lte,
+                &<PrimitiveDateTime as ToSql<T>>::to_sql(&end_time, &TableEngine::BasicTree)
+                    .change_context(MetricsError::QueryBuildingError)?,
+            ));
+        }
+        let is_successful = format!(
+            "payment_id IN (SELECT payment_id FROM {payment_attempts} \
+             WHERE merchant_id = '{}' AND status = '{}' AND {}) AS is_successful",
+            merchant_id.get_string_repr(),
+            AttemptStatus::Charged,
+            attempts_time_range.join(" AND "),
+        );
+
+        let mut query_builder: QueryBuilder<T> =
+            QueryBuilder::new(AnalyticsCollection::RoutingEventsAnalytics);
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
+        query_builder.add_select_column(is_successful).switch()?;
+
+        query_builder
+            .add_select_column(Aggregate::Count {
+                field: None,
+                alias: Some("count"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Min {
+                field: "created_at",
+                alias: Some("start_bucket"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Max {
+                field: "created_at",
+                alias: Some("end_bucket"),
+            })
+            .switch()?;
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        query_builder
+            .add_filter_clause("merchant_id", merchant_id)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        // Only decisions made by a configured routing algorithm are attributable to a rule
+        query_builder
+            .add_custom_filter_clause(
+                RoutingEventDimensions::RoutingAlgorithmId,
+                "NULL",
+                FilterTypes::IsNotNull,
+            )
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
+        query_builder
+            .add_group_by_clause("is_successful")
+            .attach_printable("Error grouping by payment outcome")
+            .switch()?;
+
+        if let Some(granularity) = granularity {
+            granularity
+                .set_group_by_clause(&mut query_builder)
+                .attach_printable("Error adding granularity")
+                .switch()?;
+        }
+
+        query_builder
+            .execute_query::<RoutingEventMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|i| {
+                Ok((
+                    RoutingEventMetricsBucketIdentifier::new(
+                        i.routing_algorithm_id.clone(),
+                        i.rule_name.clone(),
+                        i.payment_connector.clone(),
+                        i.routing_approach.clone(),
+                        i.profile_id.clone(),
+                        TimeRange {
+                            start_time: match (granularity, i.start_bucket) {
+                                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                                _ => time_range.start_time,
+                            },
+                            end_time: granularity.as_ref().map_or_else(
+                                || Ok(time_range.end_time),
+                                |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                            )?,
+                        },
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<HashSet<_>, crate::query::PostProcessingError>>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
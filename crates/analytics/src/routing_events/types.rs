@@ -0,0 +1,52 @@
+use api_models::analytics::routing_events::{RoutingEventDimensions, RoutingEventFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for RoutingEventFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.routing_algorithm_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    RoutingEventDimensions::RoutingAlgorithmId,
+                    &self.routing_algorithm_id,
+                )
+                .attach_printable("Error adding routing_algorithm_id filter")?;
+        }
+        if !self.rule_name.is_empty() {
+            builder
+                .add_filter_in_range_clause(RoutingEventDimensions::RuleName, &self.rule_name)
+                .attach_printable("Error adding rule_name filter")?;
+        }
+        if !self.payment_connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    RoutingEventDimensions::PaymentConnector,
+                    &self.payment_connector,
+                )
+                .attach_printable("Error adding payment_connector filter")?;
+        }
+        if !self.routing_approach.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    RoutingEventDimensions::RoutingApproach,
+                    &self.routing_approach,
+                )
+                .attach_printable("Error adding routing_approach filter")?;
+        }
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(RoutingEventDimensions::ProfileId, &self.profile_id)
+                .attach_printable("Error adding profile_id filter")?;
+        }
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use api_models::{
     analytics::{frm::FrmTransactionType, refunds::RefundType},
     enums::{DisputeStage, DisputeStatus},
 };
+use bigdecimal::{FromPrimitive, ToPrimitive};
 use common_enums::{
     AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, TransactionStatus,
 };
@@ -169,6 +170,7 @@ impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for SqlxClient {}
+impl super::connector_events::metrics::ConnectorEventMetricAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
@@ -1434,6 +1436,95 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+/// `percentile_cont` interpolates between rows and returns a `double precision` value
+fn percentile_to_i64(value: f64) -> Option<i64> {
+    bigdecimal::BigDecimal::from_f64(value).and_then(|value| value.round(0).to_i64())
+}
+
+impl<'a> FromRow<'a, PgRow> for super::connector_events::metrics::ConnectorEventMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector_name: Option<String> =
+            row.try_get("connector_name").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let flow: Option<String> = row.try_get("flow").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let merchant_connector_id: Option<String> =
+            row.try_get("merchant_connector_id").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status_code: Option<i32> = row.try_get("status_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let latency_p50: Option<i64> = row
+            .try_get::<Option<f64>, _>("latency_p50")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?
+            .and_then(percentile_to_i64);
+        let latency_p90: Option<i64> = row
+            .try_get::<Option<f64>, _>("latency_p90")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?
+            .and_then(percentile_to_i64);
+        let latency_p99: Option<i64> = row
+            .try_get::<Option<f64>, _>("latency_p99")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?
+            .and_then(percentile_to_i64);
+        let status_code_count: Option<i64> =
+            row.try_get("status_code_count").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let timeout_count: Option<i64> = row.try_get("timeout_count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_code_count: Option<i64> =
+            row.try_get("error_code_count").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector_name,
+            flow,
+            merchant_connector_id,
+            status_code,
+            error_code,
+            latency_p50,
+            latency_p90,
+            latency_p99,
+            status_code_count,
+            timeout_count,
+            error_code_count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
@@ -1463,12 +1554,17 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
             .attach_printable("PaymentIntentSessionized table is not implemented for Sqlx"))?,
             Self::ConnectorEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ConnectorEvents table is not implemented for Sqlx"))?,
+            Self::ConnectorEventsAnalytics => Ok("connector_events".to_string()),
             Self::ApiEventsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ApiEvents table is not implemented for Sqlx"))?,
             Self::ActivePaymentsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ActivePaymentsAnalytics table is not implemented for Sqlx"))?,
             Self::OutgoingWebhookEvent => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("OutgoingWebhookEvents table is not implemented for Sqlx"))?,
+            Self::OutgoingWebhookEventAnalytics => Err(error_stack::report!(
+                ParsingError::UnknownError
+            )
+            .attach_printable("OutgoingWebhookEventAnalytics table is not implemented for Sqlx"))?,
             Self::Dispute => Ok("dispute".to_string()),
             Self::DisputeSessionized => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("DisputeSessionized table is not implemented for Sqlx"))?,
@@ -1476,6 +1572,8 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
                 .attach_printable("Authentications table is not implemented for Sqlx"))?,
             Self::RoutingEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("RoutingEvents table is not implemented for Sqlx"))?,
+            Self::RoutingEventsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("RoutingEventsAnalytics table is not implemented for Sqlx"))?,
         }
     }
 }
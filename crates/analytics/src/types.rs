@@ -20,8 +20,11 @@ pub enum AnalyticsDomain {
     AuthEvents,
     SdkEvents,
     ApiEvents,
+    ConnectorEvents,
+    OutgoingWebhookEvents,
     Dispute,
     Routing,
+    RoutingEvents,
 }
 
 #[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
@@ -37,13 +40,16 @@ pub enum AnalyticsCollection {
     PaymentIntent,
     PaymentIntentSessionized,
     ConnectorEvents,
+    ConnectorEventsAnalytics,
     OutgoingWebhookEvent,
+    OutgoingWebhookEventAnalytics,
     Authentications,
     Dispute,
     DisputeSessionized,
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
     RoutingEvents,
+    RoutingEventsAnalytics,
 }
 
 #[allow(dead_code)]
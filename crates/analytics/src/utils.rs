@@ -1,11 +1,14 @@
 use api_models::analytics::{
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::{AuthEventDimensions, AuthEventMetrics},
+    connector_events::{ConnectorEventsDimensions, ConnectorEventsMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
+    outgoing_webhook_event::{OutgoingWebhookEventDimensions, OutgoingWebhookEventMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
+    routing_events::{RoutingEventDimensions, RoutingEventMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
     NameDescription,
 };
@@ -124,6 +127,34 @@ pub fn get_api_event_metrics_info() -> Vec<NameDescription> {
     ApiEventMetrics::iter().map(Into::into).collect()
 }
 
+pub fn get_connector_event_metrics_info() -> Vec<NameDescription> {
+    ConnectorEventsMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_connector_event_dimensions() -> Vec<NameDescription> {
+    ConnectorEventsDimensions::iter().map(Into::into).collect()
+}
+
+pub fn get_outgoing_webhook_event_metrics_info() -> Vec<NameDescription> {
+    OutgoingWebhookEventMetrics::iter()
+        .map(Into::into)
+        .collect()
+}
+
+pub fn get_outgoing_webhook_event_dimensions() -> Vec<NameDescription> {
+    OutgoingWebhookEventDimensions::iter()
+        .map(Into::into)
+        .collect()
+}
+
+pub fn get_routing_event_metrics_info() -> Vec<NameDescription> {
+    RoutingEventMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_routing_event_dimensions() -> Vec<NameDescription> {
+    RoutingEventDimensions::iter().map(Into::into).collect()
+}
+
 pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
     DisputeMetrics::iter().map(Into::into).collect()
 }
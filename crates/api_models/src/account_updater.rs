@@ -0,0 +1,86 @@
+use common_utils::{events::ApiEventMetric, id_type};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request to enable or disable the account updater of a merchant at a connector
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountUpdaterRequest {
+    /// The merchant connector account through which the stored cards are checked for updates
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AccountUpdaterResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// The connector through which the stored cards are checked for updates
+    pub connector: String,
+    /// Whether the stored cards are periodically checked for updates
+    pub enabled: bool,
+    /// Date and time of the next scheduled run, if enabled
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodAccountUpdateResponse {
+    /// Identifier for the update
+    pub update_id: String,
+    /// The payment method which was checked for updates
+    pub payment_method_id: String,
+    /// The connector which provided the update
+    pub connector: String,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    #[schema(value_type = CardAccountUpdateStatus)]
+    pub status: api_enums::CardAccountUpdateStatus,
+    /// Last 4 digits of the card before the update
+    pub previous_card_last4: Option<String>,
+    pub previous_card_exp_month: Option<String>,
+    pub previous_card_exp_year: Option<String>,
+    /// Last 4 digits of the card after the update, if the card was reissued
+    pub updated_card_last4: Option<String>,
+    pub updated_card_exp_month: Option<String>,
+    pub updated_card_exp_year: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    /// Date and time at which the update was received
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodAccountUpdateListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodAccountUpdateListResponse {
+    /// The number of updates included in the list
+    pub size: usize,
+    /// The list of updates, latest first
+    pub data: Vec<PaymentMethodAccountUpdateResponse>,
+}
+
+/// Tracking data of the process tracker task which checks the stored cards of a merchant for
+/// updates
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct AccountUpdaterPTData {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// The number of payment methods already checked in the current run
+    pub offset: i64,
+}
+
+impl ApiEventMetric for AccountUpdaterRequest {}
+impl ApiEventMetric for AccountUpdaterResponse {}
+impl ApiEventMetric for PaymentMethodAccountUpdateListConstraints {}
+impl ApiEventMetric for PaymentMethodAccountUpdateListResponse {}
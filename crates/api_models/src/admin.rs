@@ -1999,6 +1999,67 @@ pub struct ToggleAllKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KvMigrationRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the merchant is to be migrated to the KV storage scheme, or to Postgres only
+    #[schema(example = false)]
+    pub kv_enabled: bool,
+    /// Time in seconds for which reads keep checking both Redis and Postgres after the pending
+    /// drainer entries of the merchant are applied to Postgres
+    #[schema(example = 300)]
+    pub grace_period_in_secs: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KvMigrationStatus {
+    /// Waiting for the grace period to be observed by all the pods before switching the storage scheme
+    Scheduled,
+    /// The storage scheme is switched, and the pending drainer entries of the merchant are being
+    /// applied to Postgres
+    Draining,
+    /// All the pending drainer entries are applied, reads check both stores until the grace period ends
+    GracePeriod,
+    /// The migration is complete
+    Completed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KvMigrationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the merchant is being migrated to the KV storage scheme, or to Postgres only
+    #[schema(example = false)]
+    pub kv_enabled: bool,
+    pub status: KvMigrationStatus,
+    /// Number of entries of the merchant which were pending in the drainer streams, as of the last check
+    #[schema(example = 0)]
+    pub pending_drainer_entries: usize,
+    /// Time in seconds for which reads check both stores after the pending entries are drained
+    #[schema(example = 300)]
+    pub grace_period_in_secs: u32,
+    /// The time at which the migration was requested
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The time at which the storage scheme of the merchant was switched
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub switched_at: Option<time::PrimitiveDateTime>,
+    /// The time at which no pending drainer entries were found for the merchant
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub drained_at: Option<time::PrimitiveDateTime>,
+    /// The time at which the migration was completed
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
 /// Merchant connector details used to make payments.
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
@@ -2196,6 +2257,12 @@ pub struct ProfileCreate {
     /// Time interval (in hours) for polling the connector to check dispute statuses
     #[schema(value_type = Option<i32>, example = 2)]
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+
+    /// Indicates if failed payouts are automatically retried with the same or the next eligible connector
+    pub is_payout_auto_retries_enabled: Option<bool>,
+
+    /// Maximum number of auto retries allowed for a payout
+    pub max_payout_auto_retries_enabled: Option<u8>,
 }
 
 #[nutype::nutype(
@@ -2353,6 +2420,10 @@ pub struct ProfileCreate {
     /// Enable split payments, i.e., split the amount between multiple payment methods
     #[schema(value_type = Option<SplitTxnsEnabled>, default = "skip")]
     pub split_txns_enabled: Option<common_enums::SplitTxnsEnabled>,
+
+    /// The hosts to which requests can be forwarded through the vault proxy. When not set, requests can be forwarded to any host
+    #[schema(value_type = Option<Vec<String>>, example = json!(["api.example.com", "*.example.org"]))]
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v1")]
@@ -2538,6 +2609,13 @@ pub struct ProfileResponse {
 
     #[schema(value_type = Option<u32>, example = 2)]
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+
+    /// Indicates if failed payouts are automatically retried with the same or the next eligible connector
+    #[schema(default = false, example = false)]
+    pub is_payout_auto_retries_enabled: bool,
+
+    /// Maximum number of auto retries allowed for a payout
+    pub max_payout_auto_retries_enabled: Option<i16>,
 }
 
 #[cfg(feature = "v2")]
@@ -2703,6 +2781,10 @@ pub struct ProfileResponse {
     /// Enable split payments, i.e., split the amount between multiple payment methods
     #[schema(value_type = SplitTxnsEnabled, default = "skip")]
     pub split_txns_enabled: common_enums::SplitTxnsEnabled,
+
+    /// The hosts to which requests can be forwarded through the vault proxy. When not set, requests can be forwarded to any host
+    #[schema(value_type = Option<Vec<String>>, example = json!(["api.example.com", "*.example.org"]))]
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v1")]
@@ -2873,6 +2955,12 @@ pub struct ProfileUpdate {
 
     #[schema(value_type = Option<u32>, example = 2)]
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+
+    /// Indicates if failed payouts are automatically retried with the same or the next eligible connector
+    pub is_payout_auto_retries_enabled: Option<bool>,
+
+    /// Maximum number of auto retries allowed for a payout
+    pub max_payout_auto_retries_enabled: Option<u8>,
 }
 
 #[cfg(feature = "v2")]
@@ -3025,6 +3113,10 @@ pub struct ProfileUpdate {
     /// Enable split payments, i.e., split the amount between multiple payment methods
     #[schema(value_type = Option<SplitTxnsEnabled>, default = "skip")]
     pub split_txns_enabled: Option<common_enums::SplitTxnsEnabled>,
+
+    /// The hosts to which requests can be forwarded through the vault proxy. When not set, requests can be forwarded to any host
+    #[schema(value_type = Option<Vec<String>>, example = json!(["api.example.com", "*.example.org"]))]
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -8,11 +8,14 @@ use self::{
     active_payments::ActivePaymentsMetrics,
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::{AuthEventDimensions, AuthEventFilters, AuthEventMetrics},
+    connector_events::{ConnectorEventsDimensions, ConnectorEventsMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
+    outgoing_webhook_event::{OutgoingWebhookEventDimensions, OutgoingWebhookEventMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
     refunds::{RefundDimensions, RefundDistributions, RefundMetrics},
+    routing_events::{RoutingEventDimensions, RoutingEventMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
 };
 pub mod active_payments;
@@ -21,10 +24,12 @@ pub mod auth_events;
 pub mod connector_events;
 pub mod disputes;
 pub mod frm;
+pub mod metric_alerts;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
 pub mod payments;
 pub mod refunds;
+pub mod report_schedules;
 pub mod routing_events;
 pub mod sdk_events;
 pub mod search;
@@ -470,6 +475,48 @@ pub struct GetApiEventMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorEventMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorEventsDimensions>,
+    #[serde(default)]
+    pub filters: connector_events::ConnectorEventsFilters,
+    pub metrics: HashSet<ConnectorEventsMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOutgoingWebhookEventMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<OutgoingWebhookEventDimensions>,
+    #[serde(default)]
+    pub filters: outgoing_webhook_event::OutgoingWebhookEventFilters,
+    pub metrics: HashSet<OutgoingWebhookEventMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRoutingEventMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<RoutingEventDimensions>,
+    #[serde(default)]
+    pub filters: routing_events::RoutingEventFilters,
+    pub metrics: HashSet<RoutingEventMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetDisputeFilterRequest {
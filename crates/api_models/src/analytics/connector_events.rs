@@ -1,6 +1,170 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct ConnectorEventsRequest {
     pub payment_id: common_utils::id_type::PaymentId,
     pub refund_id: Option<String>,
     pub dispute_id: Option<String>,
 }
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorEventsDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    ConnectorName,
+    Flow,
+    MerchantConnectorId,
+    StatusCode,
+    ErrorCode,
+}
+
+impl From<ConnectorEventsDimensions> for NameDescription {
+    fn from(value: ConnectorEventsDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorEventsFilters {
+    #[serde(default)]
+    pub connector_name: Vec<String>,
+    #[serde(default)]
+    pub flow: Vec<String>,
+    #[serde(default)]
+    pub merchant_connector_id: Vec<String>,
+    #[serde(default)]
+    pub status_code: Vec<u64>,
+    #[serde(default)]
+    pub error_code: Vec<String>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorEventsMetrics {
+    /// p50, p90 and p99 of the connector call latency in milliseconds
+    LatencyPercentiles,
+    /// Number of connector calls, group by `status_code` for the HTTP status distribution
+    StatusCodeCount,
+    /// Number of connector calls that timed out
+    TimeoutCount,
+    /// Number of failed connector calls, group by `error_code` for the error distribution
+    ErrorCodeCount,
+}
+
+impl From<ConnectorEventsMetrics> for NameDescription {
+    fn from(value: ConnectorEventsMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct ConnectorEventsMetricsBucketIdentifier {
+    pub connector_name: Option<String>,
+    pub flow: Option<String>,
+    pub merchant_connector_id: Option<String>,
+    pub status_code: Option<u16>,
+    pub error_code: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl ConnectorEventsMetricsBucketIdentifier {
+    pub fn new(
+        connector_name: Option<String>,
+        flow: Option<String>,
+        merchant_connector_id: Option<String>,
+        status_code: Option<u16>,
+        error_code: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector_name,
+            flow,
+            merchant_connector_id,
+            status_code,
+            error_code,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+impl Hash for ConnectorEventsMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector_name.hash(state);
+        self.flow.hash(state);
+        self.merchant_connector_id.hash(state);
+        self.status_code.hash(state);
+        self.error_code.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for ConnectorEventsMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorEventsMetricsBucketValue {
+    pub latency_p50: Option<u64>,
+    pub latency_p90: Option<u64>,
+    pub latency_p99: Option<u64>,
+    pub status_code_count: Option<u64>,
+    pub timeout_count: Option<u64>,
+    pub error_code_count: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorEventsMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: ConnectorEventsMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: ConnectorEventsMetricsBucketIdentifier,
+}
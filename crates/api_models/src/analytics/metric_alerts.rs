@@ -0,0 +1,217 @@
+use common_utils::{id_type, pii, types::TimeRange};
+use utoipa::ToSchema;
+
+use super::Granularity;
+
+/// The metric watched by an alert rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AlertMetric {
+    /// Percentage of payment attempts which succeeded
+    ConnectorSuccessRate,
+    /// Percentage of failed payment attempts which failed with the same error message
+    PaymentErrorMessage,
+    /// Percentage of refunds which failed
+    RefundFailureRate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertComparison {
+    Above,
+    Below,
+}
+
+impl AlertComparison {
+    pub fn is_breached(self, value: f64, limit: f64) -> bool {
+        match self {
+            Self::Above => value > limit,
+            Self::Below => value < limit,
+        }
+    }
+}
+
+/// The condition on which an alert is triggered
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertCondition {
+    /// The value of the metric in the evaluation window is above or below a fixed value
+    Threshold {
+        comparison: AlertComparison,
+        value: f64,
+    },
+    /// The value of the metric in the evaluation window deviates from the average of the
+    /// preceding `baseline_windows` windows by more than `deviation` percentage points
+    Baseline {
+        comparison: AlertComparison,
+        deviation: f64,
+        baseline_windows: u8,
+    },
+}
+
+impl AlertCondition {
+    /// Number of windows preceding the evaluation window which are needed to evaluate the condition
+    pub fn get_baseline_windows(&self) -> u8 {
+        match self {
+            Self::Threshold { .. } => 0,
+            Self::Baseline {
+                baseline_windows, ..
+            } => *baseline_windows,
+        }
+    }
+}
+
+/// Length of the window over which the metric is computed, rules are evaluated once every window
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertWindow {
+    FifteenMin,
+    ThirtyMin,
+    OneHour,
+    OneDay,
+}
+
+impl AlertWindow {
+    pub fn get_granularity(self) -> Granularity {
+        match self {
+            Self::FifteenMin => Granularity::FifteenMin,
+            Self::ThirtyMin => Granularity::ThirtyMin,
+            Self::OneHour => Granularity::OneHour,
+            Self::OneDay => Granularity::OneDay,
+        }
+    }
+
+    pub fn get_duration(self) -> time::Duration {
+        match self {
+            Self::FifteenMin => time::Duration::minutes(15),
+            Self::ThirtyMin => time::Duration::minutes(30),
+            Self::OneHour => time::Duration::hours(1),
+            Self::OneDay => time::Duration::days(1),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricAlertRuleCreateRequest {
+    pub name: String,
+    pub metric: AlertMetric,
+    pub condition: AlertCondition,
+    pub window: AlertWindow,
+    /// Evaluate the rule separately for every connector, instead of across the profile
+    #[serde(default)]
+    pub group_by_connector: bool,
+    /// Minimum number of payments or refunds in the evaluation window for the rule to be evaluated
+    pub min_sample_size: Option<u64>,
+    /// Minimum time between two notifications of an alert which has not recovered, defaults to
+    /// the length of the window
+    pub cooldown_minutes: Option<u32>,
+    /// Email addresses which are notified when an alert is triggered
+    #[serde(default)]
+    pub recipients: Vec<pii::Email>,
+    /// Send a `metric_alert_triggered` outgoing webhook when an alert is triggered
+    #[serde(default)]
+    pub send_webhook: bool,
+}
+
+/// An alert rule, as stored against the profile
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricAlertRule {
+    pub rule_id: String,
+    pub profile_id: id_type::ProfileId,
+    pub name: String,
+    pub metric: AlertMetric,
+    pub condition: AlertCondition,
+    pub window: AlertWindow,
+    pub group_by_connector: bool,
+    pub min_sample_size: u64,
+    pub cooldown_minutes: u32,
+    pub recipients: Vec<pii::Email>,
+    pub send_webhook: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub next_evaluation_at: time::PrimitiveDateTime,
+    /// Alerts of the rule which have been triggered and have not recovered yet
+    #[serde(default)]
+    pub active_alerts: Vec<ActiveMetricAlert>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveMetricAlert {
+    pub alert_id: String,
+    pub connector: Option<String>,
+    pub error_message: Option<String>,
+    pub observed_value: f64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub triggered_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_notified_at: time::PrimitiveDateTime,
+}
+
+impl ActiveMetricAlert {
+    pub fn is_for(&self, connector: Option<&str>, error_message: Option<&str>) -> bool {
+        self.connector.as_deref() == connector && self.error_message.as_deref() == error_message
+    }
+}
+
+/// Details of a triggered metric alert, sent in the `metric_alert_triggered` outgoing webhook
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct MetricAlertDetails {
+    /// The identifier of the alert, which stays the same until the alert recovers
+    pub alert_id: String,
+    pub rule_id: String,
+    pub rule_name: String,
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    pub metric: AlertMetric,
+    pub condition: AlertCondition,
+    /// The connector for which the alert was triggered, for rules grouped by connector
+    pub connector: Option<String>,
+    /// The error message for which the alert was triggered, for `payment_error_message` rules
+    pub error_message: Option<String>,
+    /// The value of the metric in the evaluation window
+    pub observed_value: f64,
+    /// The threshold, or the baseline value, against which the metric was compared
+    pub reference_value: f64,
+    /// Number of payments or refunds in the evaluation window
+    pub sample_size: u64,
+    pub time_range: TimeRange,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub triggered_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricAlertRuleListResponse {
+    pub count: usize,
+    pub data: Vec<MetricAlertRule>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct MetricAlertRuleId {
+    pub rule_id: String,
+}
+
+/// Tracking data of the process tracker task which evaluates an alert rule
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct MetricAlertPTData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub rule_id: String,
+}
@@ -1,3 +1,10 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct OutgoingWebhookLogsRequest {
     pub payment_id: common_utils::id_type::PaymentId,
@@ -8,3 +15,154 @@ pub struct OutgoingWebhookLogsRequest {
     pub payment_method_id: Option<String>,
     pub attempt_id: Option<String>,
 }
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookEventDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    EventType,
+    StatusCodeClass,
+    Endpoint,
+    ProfileId,
+}
+
+impl From<OutgoingWebhookEventDimensions> for NameDescription {
+    fn from(value: OutgoingWebhookEventDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct OutgoingWebhookEventFilters {
+    #[serde(default)]
+    pub event_type: Vec<String>,
+    #[serde(default)]
+    pub status_code_class: Vec<String>,
+    #[serde(default)]
+    pub endpoint: Vec<String>,
+    #[serde(default)]
+    pub profile_id: Vec<common_utils::id_type::ProfileId>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OutgoingWebhookEventMetrics {
+    /// Number of webhook delivery attempts, including retries
+    DeliveryCount,
+    /// Percentage of delivery attempts that received a 2xx response
+    DeliverySuccessRate,
+    /// Percentage of webhooks delivered successfully on the initial attempt
+    FirstAttemptSuccessRate,
+    /// Average number of delivery attempts made per webhook
+    RetryDepth,
+    /// Average time taken by the merchant endpoint to respond, in milliseconds
+    DeliveryLatency,
+}
+
+impl From<OutgoingWebhookEventMetrics> for NameDescription {
+    fn from(value: OutgoingWebhookEventMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct OutgoingWebhookEventMetricsBucketIdentifier {
+    pub event_type: Option<String>,
+    pub status_code_class: Option<String>,
+    pub endpoint: Option<String>,
+    pub profile_id: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl OutgoingWebhookEventMetricsBucketIdentifier {
+    pub fn new(
+        event_type: Option<String>,
+        status_code_class: Option<String>,
+        endpoint: Option<String>,
+        profile_id: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            event_type,
+            status_code_class,
+            endpoint,
+            profile_id,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+impl Hash for OutgoingWebhookEventMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.event_type.hash(state);
+        self.status_code_class.hash(state);
+        self.endpoint.hash(state);
+        self.profile_id.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for OutgoingWebhookEventMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct OutgoingWebhookEventMetricsBucketValue {
+    pub delivery_count: Option<u64>,
+    pub delivery_success_rate: Option<f64>,
+    pub first_attempt_success_rate: Option<f64>,
+    pub retry_depth: Option<f64>,
+    pub delivery_latency: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct OutgoingWebhookEventMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: OutgoingWebhookEventMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: OutgoingWebhookEventMetricsBucketIdentifier,
+}
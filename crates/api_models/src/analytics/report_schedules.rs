@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+
+use common_utils::{id_type, pii};
+
+use super::{
+    disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics},
+    payments::{PaymentDimensions, PaymentFilters, PaymentMetrics},
+    refunds::{RefundDimensions, RefundFilters, RefundMetrics},
+    Granularity, TimeRange,
+};
+
+/// The analytics query executed on every run of a scheduled report
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "domain", rename_all = "snake_case")]
+pub enum ReportQuery {
+    Payments(PaymentReportQuery),
+    Refunds(RefundReportQuery),
+    Disputes(DisputeReportQuery),
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentReportQuery {
+    pub metrics: HashSet<PaymentMetrics>,
+    #[serde(default)]
+    pub group_by_names: Vec<PaymentDimensions>,
+    #[serde(default)]
+    pub filters: PaymentFilters,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundReportQuery {
+    pub metrics: HashSet<RefundMetrics>,
+    #[serde(default)]
+    pub group_by_names: Vec<RefundDimensions>,
+    #[serde(default)]
+    pub filters: RefundFilters,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeReportQuery {
+    pub metrics: HashSet<DisputeMetrics>,
+    #[serde(default)]
+    pub group_by_names: Vec<DisputeDimensions>,
+    #[serde(default)]
+    pub filters: DisputeFilters,
+}
+
+/// How often a scheduled report is run. Every run covers the period which ended at the time of
+/// the run, with periods starting at 00:00 UTC, on Mondays for weekly reports and on the first
+/// day of the month for monthly reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Csv,
+    Parquet,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportScheduleCreateRequest {
+    pub name: String,
+    #[serde(flatten)]
+    pub query: ReportQuery,
+    /// Granularity of the time buckets of the report, the whole period is aggregated if absent
+    pub granularity: Option<Granularity>,
+    #[serde(default)]
+    pub format: ReportFormat,
+    pub frequency: ReportFrequency,
+    /// Email addresses to which the link to every export of the report is sent
+    pub recipients: Vec<pii::Email>,
+}
+
+/// A report definition, as stored against the profile
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportSchedule {
+    pub report_id: String,
+    pub profile_id: id_type::ProfileId,
+    pub name: String,
+    #[serde(flatten)]
+    pub query: ReportQuery,
+    pub granularity: Option<Granularity>,
+    pub format: ReportFormat,
+    pub frequency: ReportFrequency,
+    pub recipients: Vec<pii::Email>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub next_run_at: time::PrimitiveDateTime,
+    pub last_export: Option<ReportExport>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// A generated file of a scheduled report
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportExport {
+    pub export_id: String,
+    pub time_range: TimeRange,
+    pub row_count: usize,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportScheduleListResponse {
+    pub count: usize,
+    pub data: Vec<ReportSchedule>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReportScheduleId {
+    pub report_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReportExportId {
+    pub report_id: String,
+    pub export_id: String,
+}
+
+/// Tracking data of the process tracker task which runs a scheduled report
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ReportSchedulePTData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub report_id: String,
+}
@@ -1,6 +1,166 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct RoutingEventsRequest {
     pub payment_id: common_utils::id_type::PaymentId,
     pub refund_id: Option<String>,
     pub dispute_id: Option<String>,
 }
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingEventDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    RoutingAlgorithmId,
+    RuleName,
+    PaymentConnector,
+    RoutingApproach,
+    ProfileId,
+}
+
+impl From<RoutingEventDimensions> for NameDescription {
+    fn from(value: RoutingEventDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct RoutingEventFilters {
+    #[serde(default)]
+    pub routing_algorithm_id: Vec<String>,
+    #[serde(default)]
+    pub rule_name: Vec<String>,
+    #[serde(default)]
+    pub payment_connector: Vec<String>,
+    #[serde(default)]
+    pub routing_approach: Vec<String>,
+    #[serde(default)]
+    pub profile_id: Vec<common_utils::id_type::ProfileId>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingEventMetrics {
+    /// Number of routing decisions made by a routing algorithm, group by `rule_name` for the
+    /// number of times each rule matched
+    RuleHitCount,
+    /// Number of routing decisions that selected each connector
+    ConnectorDistribution,
+    /// Percentage of routing decisions whose payment was eventually charged
+    RuleSuccessRate,
+}
+
+impl From<RoutingEventMetrics> for NameDescription {
+    fn from(value: RoutingEventMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct RoutingEventMetricsBucketIdentifier {
+    pub routing_algorithm_id: Option<String>,
+    pub rule_name: Option<String>,
+    pub payment_connector: Option<String>,
+    pub routing_approach: Option<String>,
+    pub profile_id: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl RoutingEventMetricsBucketIdentifier {
+    pub fn new(
+        routing_algorithm_id: Option<String>,
+        rule_name: Option<String>,
+        payment_connector: Option<String>,
+        routing_approach: Option<String>,
+        profile_id: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            routing_algorithm_id,
+            rule_name,
+            payment_connector,
+            routing_approach,
+            profile_id,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+impl Hash for RoutingEventMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.routing_algorithm_id.hash(state);
+        self.rule_name.hash(state);
+        self.payment_connector.hash(state);
+        self.routing_approach.hash(state);
+        self.profile_id.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for RoutingEventMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RoutingEventMetricsBucketValue {
+    pub rule_hit_count: Option<u64>,
+    pub connector_distribution: Option<u64>,
+    pub rule_success_rate: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RoutingEventMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: RoutingEventMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: RoutingEventMetricsBucketIdentifier,
+}
@@ -0,0 +1,50 @@
+use common_utils::events::ApiEventMetric;
+
+/// Operation executed by every pod on its in-memory caches
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CacheIntrospectionOperation {
+    /// Report the number of entries and the memory used by every cache
+    Stats,
+    /// Report whether the key is present in every cache
+    Lookup { key: String },
+    /// Remove every entry whose key starts with the prefix
+    InvalidatePrefix { prefix: String },
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CachePrefixInvalidationRequest {
+    /// Prefix of the keys to be invalidated, for instance the merchant ID
+    pub prefix: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CacheReport {
+    pub cache_name: String,
+    pub entry_count: u64,
+    /// Approximate memory used by the entries of the cache
+    pub estimated_size_bytes: u64,
+    /// Whether the key is present in the cache, for key lookups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_present: Option<bool>,
+    /// Number of entries removed from the cache, for prefix invalidations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalidated_entries: Option<u64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PodCacheReport {
+    pub pod: String,
+    pub caches: Vec<CacheReport>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CacheIntrospectionResponse {
+    /// Number of pods which received the request
+    pub pods_expected: usize,
+    /// Reports of the pods which responded before the timeout
+    pub pods: Vec<PodCacheReport>,
+}
+
+impl ApiEventMetric for CachePrefixInvalidationRequest {}
+impl ApiEventMetric for CacheIntrospectionResponse {}
@@ -386,3 +386,113 @@ pub struct CustomerUpdateRequestInternal {
     pub id: id_type::GlobalCustomerId,
     pub request: CustomerUpdateRequest,
 }
+
+/// A customer sharing the email or phone number of another customer
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDuplicate {
+    /// The identifier for the duplicate customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The customer fields which matched
+    #[schema(value_type = Vec<CustomerBlindIndexField>)]
+    pub matched_on: Vec<common_enums::CustomerBlindIndexField>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDuplicatesResponse {
+    /// The identifier for the customer whose duplicates were looked up
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The customers sharing the email or phone number of the customer
+    pub duplicates: Vec<CustomerDuplicate>,
+}
+
+/// A group of customers sharing the same email or phone number
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDuplicateGroup {
+    /// The customer field shared by the customers of the group
+    #[schema(value_type = CustomerBlindIndexField)]
+    pub matched_on: common_enums::CustomerBlindIndexField,
+    /// The customers of the group, oldest first
+    #[schema(value_type = Vec<String>)]
+    pub customer_ids: Vec<id_type::CustomerId>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDuplicateGroupListResponse {
+    /// The number of groups included in the list
+    pub count: usize,
+    /// The list of groups of duplicate customers
+    pub data: Vec<CustomerDuplicateGroup>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerBlindIndexSyncResponse {
+    /// The number of customers whose email and phone number were indexed
+    pub customers_indexed: usize,
+    /// The offset to continue indexing from, absent when all the customers have been indexed
+    pub next_offset: Option<u32>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerMergeRequest {
+    /// The duplicate customer whose payment methods, mandates and payments are moved to the
+    /// surviving customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_kcm1opf6zcsbc5dwgbwbr3v2ui")]
+    pub merged_customer_id: id_type::CustomerId,
+    /// The reason for merging the customers
+    #[schema(max_length = 255, example = "Same customer signed up twice")]
+    pub reason: Option<String>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerMergeRequestInternal {
+    pub customer_id: id_type::CustomerId,
+    pub request: CustomerMergeRequest,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerMergeResponse {
+    /// The identifier for the merge
+    pub merge_id: String,
+    /// The customer which now holds the payment methods, mandates and payments
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub surviving_customer_id: id_type::CustomerId,
+    /// The duplicate customer which was merged
+    #[schema(value_type = String, example = "cus_kcm1opf6zcsbc5dwgbwbr3v2ui")]
+    pub merged_customer_id: id_type::CustomerId,
+    /// The payment methods moved to the surviving customer
+    pub payment_method_ids: Vec<String>,
+    /// The mandates moved to the surviving customer
+    pub mandate_ids: Vec<String>,
+    /// The number of payments moved to the surviving customer
+    pub payments_moved: i64,
+    /// The status of the merge, a merge which is still in progress is resumed by merging the
+    /// customers again
+    #[schema(value_type = CustomerMergeStatus)]
+    pub status: common_enums::CustomerMergeStatus,
+    /// The reason for merging the customers
+    pub reason: Option<String>,
+    /// Date and time of the merge
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerMergeListResponse {
+    /// The number of merges included in the list
+    pub count: usize,
+    /// The merges in which the customer survived or was merged, latest first
+    pub data: Vec<CustomerMergeResponse>,
+}
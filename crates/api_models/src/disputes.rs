@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use common_utils::types::{StringMinorUnit, TimeRange};
+use common_types::disputes::{DisputeFinancialRecord, DisputeFinancialRecordType};
+use common_utils::types::{MinorUnit, StringMinorUnit, TimeRange};
 use masking::{Deserialize, Serialize};
 use serde::de::Error;
 use time::PrimitiveDateTime;
@@ -55,6 +56,8 @@ pub struct DisputeResponse {
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     #[schema(value_type = Option<String>)]
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    /// Fees and amount adjustments associated with the dispute
+    pub financial_records: Vec<DisputeFinancialRecord>,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -236,6 +239,55 @@ pub struct DisputeRetrieveRequest {
 pub struct DisputesAggregateResponse {
     /// Different status of disputes with their count
     pub status_with_count: HashMap<DisputeStatus, i64>,
+    /// Financial impact of the disputes, grouped by connector, reason code and currency
+    pub net_loss: Vec<DisputeNetLoss>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct DisputeNetLoss {
+    /// Connector to which the disputes are associated with
+    pub connector: String,
+    /// Reason code of the disputes sent by connector
+    pub connector_reason_code: Option<String>,
+    /// Currency of the financial records
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+    /// Number of disputes having financial records in the currency
+    pub dispute_count: i64,
+    /// Total chargeback fees charged, net of the fees refunded
+    #[schema(value_type = i64)]
+    pub total_fees: MinorUnit,
+    /// Total disputed amount withdrawn from the merchant's balance
+    #[schema(value_type = i64)]
+    pub total_amount_debited: MinorUnit,
+    /// Total amount reinstated after disputes were won
+    #[schema(value_type = i64)]
+    pub total_amount_reinstated: MinorUnit,
+    /// Net loss incurred due to the disputes, i.e. fees and debited amounts less the reinstated amounts
+    #[schema(value_type = i64)]
+    pub net_loss: MinorUnit,
+}
+
+/// Request to record a fee or amount adjustment against a dispute, for connectors
+/// which do not share them through webhooks
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeFinancialRecordRequest {
+    /// The identifier for dispute
+    #[serde(skip_deserializing)]
+    pub dispute_id: String,
+    /// The kind of money movement
+    pub record_type: DisputeFinancialRecordType,
+    /// Amount of the record in minor units
+    #[schema(value_type = i64, example = 1500)]
+    pub amount: MinorUnit,
+    /// Currency of the record
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+    /// Reference of the record at the connector
+    pub reference_id: Option<String>,
+    /// Free form description of the record
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -244,6 +296,105 @@ pub struct DisputeRetrieveBody {
     pub force_sync: Option<bool>,
 }
 
+/// Template from which the evidence of disputes raised under a profile is built.
+/// Values explicitly provided while submitting evidence take precedence over the template.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceTemplate {
+    /// Product description, used when the payment does not have a description
+    pub product_description: Option<String>,
+    /// Keys of the payment metadata from which order details are read
+    pub metadata_keys: Option<EvidenceMetadataKeys>,
+    /// Include the name and email of the customer
+    #[serde(default)]
+    pub include_customer_details: bool,
+    /// Include the billing address of the payment
+    #[serde(default)]
+    pub include_billing_address: bool,
+    /// Include the shipping address of the payment
+    #[serde(default)]
+    pub include_shipping_address: bool,
+    /// Include the IP address from which the payment was made
+    #[serde(default)]
+    pub include_purchase_ip: bool,
+    /// Include the 3DS authentication and AVS / CVV check results of the payment
+    #[serde(default)]
+    pub include_authentication_results: bool,
+    /// File Id of refund policy
+    pub refund_policy: Option<String>,
+    /// Details of showing refund policy to customer before purchase
+    pub refund_policy_disclosure: Option<String>,
+    /// File Id of cancellation policy
+    pub cancellation_policy: Option<String>,
+    /// Details of showing cancellation policy to customer before purchase
+    pub cancellation_policy_disclosure: Option<String>,
+    /// Any additional evidence statements
+    pub uncategorized_text: Option<String>,
+}
+
+/// Keys of the payment metadata holding the order details of a payment
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EvidenceMetadataKeys {
+    /// Key of the delivery service that shipped the product
+    pub shipping_carrier: Option<String>,
+    /// Key of the tracking number of the shipped product
+    pub shipping_tracking_number: Option<String>,
+    /// Key of the shipping date
+    pub shipping_date: Option<String>,
+    /// Key of the service date
+    pub service_date: Option<String>,
+    /// Key of the logs showing the usage of service by customer
+    pub access_activity_log: Option<String>,
+}
+
+/// Disputes in the currency with amount less than or equal to `max_amount` are accepted automatically
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAutoAcceptRule {
+    /// Currency of the dispute
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+    /// Maximum dispute amount in minor units
+    #[schema(value_type = i64, example = 1000)]
+    pub max_amount: MinorUnit,
+}
+
+/// Rules for handling disputes without manual intervention
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAutomationRules {
+    /// Rules for accepting low value disputes automatically
+    #[serde(default)]
+    pub auto_accept: Vec<DisputeAutoAcceptRule>,
+    /// Evidence built from the template is submitted automatically these many days before `challenge_required_by`
+    #[schema(example = 2)]
+    pub auto_submit_days_before_due: Option<u16>,
+}
+
+/// Dispute evidence template and automation rules of a profile
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAutomationConfig {
+    /// Template from which dispute evidence is built
+    pub evidence_template: Option<DisputeEvidenceTemplate>,
+    /// Rules for handling disputes without manual intervention
+    #[serde(default)]
+    pub rules: DisputeAutomationRules,
+}
+
+/// Dispute automation config of a profile
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeAutomationConfigResponse {
+    /// The identifier of the profile
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Template from which dispute evidence is built
+    pub evidence_template: Option<DisputeEvidenceTemplate>,
+    /// Rules for handling disputes without manual intervention
+    pub rules: DisputeAutomationRules,
+}
+
 fn parse_comma_separated<'de, D, T>(v: D) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::Deserializer<'de>,
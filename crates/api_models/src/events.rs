@@ -29,9 +29,9 @@ use crate::tokenization;
 use crate::{
     admin::*,
     analytics::{
-        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, routing_events::RoutingEventsRequest,
-        sdk_events::*, search::*, *,
+        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest, metric_alerts::*,
+        outgoing_webhook_event::OutgoingWebhookLogsRequest, report_schedules::*,
+        routing_events::RoutingEventsRequest, sdk_events::*, search::*, *,
     },
     api_keys::*,
     cards_info::*,
@@ -80,6 +80,16 @@ impl_api_event_type!(
         MandateRevokedResponse,
         RetrievePaymentLinkRequest,
         PaymentLinkListConstraints,
+        ReusablePaymentLinkCreateRequest,
+        ReusablePaymentLinkUpdateRequest,
+        ReusablePaymentLinkResponse,
+        ReusablePaymentLinkId,
+        ReusablePaymentLinkListConstraints,
+        ReusablePaymentLinkListResponse,
+        ReusablePaymentLinkAnalyticsResponse,
+        ReusablePaymentLinkVisitRequest,
+        ReusablePaymentLinkConfirmRequest,
+        ReusablePaymentLinkQrCodeRequest,
         MandateId,
         DisputeListGetConstraints,
         RetrieveApiKeyResponse,
@@ -91,6 +101,8 @@ impl_api_event_type!(
         ToggleKVRequest,
         ToggleAllKVRequest,
         ToggleAllKVResponse,
+        KvMigrationRequest,
+        KvMigrationResponse,
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
         CardInfoResponse,
@@ -128,6 +140,9 @@ impl_api_event_type!(
         SdkEventFiltersResponse,
         ApiLogsRequest,
         GetApiEventMetricRequest,
+        GetConnectorEventMetricRequest,
+        GetOutgoingWebhookEventMetricRequest,
+        GetRoutingEventMetricRequest,
         SdkEventsRequest,
         ReportRequest,
         ConnectorEventsRequest,
@@ -145,7 +160,16 @@ impl_api_event_type!(
         OrganizationUpdateRequest,
         OrganizationId,
         CustomerListRequest,
-        RoutingEventsRequest
+        RoutingEventsRequest,
+        ReportScheduleCreateRequest,
+        ReportSchedule,
+        ReportScheduleListResponse,
+        ReportScheduleId,
+        ReportExportId,
+        MetricAlertRuleCreateRequest,
+        MetricAlertRule,
+        MetricAlertRuleListResponse,
+        MetricAlertRuleId
     )
 );
 
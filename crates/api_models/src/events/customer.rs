@@ -1,5 +1,10 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
+#[cfg(feature = "v1")]
+use crate::customers::{
+    CustomerBlindIndexSyncResponse, CustomerDuplicateGroupListResponse, CustomerDuplicatesResponse,
+    CustomerMergeListResponse, CustomerMergeRequestInternal, CustomerMergeResponse,
+};
 use crate::customers::{
     CustomerDeleteResponse, CustomerRequest, CustomerResponse, CustomerUpdateRequestInternal,
 };
@@ -73,3 +78,39 @@ impl ApiEventMetric for CustomerUpdateRequestInternal {
         })
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDuplicatesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.surviving_customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDuplicateGroupListResponse {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerBlindIndexSyncResponse {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeListResponse {}
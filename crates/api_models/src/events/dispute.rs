@@ -1,7 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
+    DeleteEvidenceRequest, DisputeAutomationConfig, DisputeAutomationConfigResponse,
+    DisputeFinancialRecordRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
     DisputeRetrieveRequest, DisputesAggregateResponse, SubmitEvidenceRequest,
 };
 
@@ -43,8 +44,25 @@ impl ApiEventMetric for DeleteEvidenceRequest {
     }
 }
 
+impl ApiEventMetric for DisputeFinancialRecordRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for DisputesAggregateResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for DisputeAutomationConfig {}
+impl ApiEventMetric for DisputeAutomationConfigResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
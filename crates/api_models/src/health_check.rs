@@ -3,6 +3,9 @@ use std::collections::hash_map::HashMap;
 pub struct RouterHealthCheckResponse {
     pub database: bool,
     pub redis: bool,
+    /// Addresses of the Redis primary nodes the application is currently connected to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redis_primary_nodes: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vault: Option<bool>,
     #[cfg(feature = "olap")]
@@ -1,9 +1,11 @@
+pub mod account_updater;
 pub mod admin;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
 pub mod authentication;
 pub mod blocklist;
+pub mod cache;
 pub mod cards_info;
 pub mod chat;
 pub mod conditional_configs;
@@ -39,15 +41,18 @@ pub mod profile_acquirer;
 pub mod proxy;
 #[cfg(feature = "recon")]
 pub mod recon;
+pub mod recovery_enrollments;
 pub mod refunds;
 pub mod relay;
 pub mod routing;
+pub mod subscriptions;
 pub mod surcharge_decision_configs;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod user;
 pub mod user_role;
+pub mod velocity_rules;
 pub mod verifications;
 pub mod verify_connector;
 pub mod webhook_events;
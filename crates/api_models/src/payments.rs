@@ -5489,6 +5489,10 @@ pub struct PaymentsResponse {
 
     /// Allow partial authorization for this payment
     pub enable_partial_authorization: Option<bool>,
+
+    /// Outcome of the velocity rules configured for the profile, present when any rule was breached
+    #[schema(value_type = Option<VelocityCheckResponse>)]
+    pub velocity_check: Option<crate::velocity_rules::VelocityCheckResponse>,
 }
 
 #[cfg(feature = "v2")]
@@ -8695,6 +8699,17 @@ pub mod amount {
     {
         deserializer.deserialize_option(OptionalAmountVisitor)
     }
+
+    /// Serializes the amount as an integer, so that it can be deserialized using `deserialize_option`
+    pub(crate) fn serialize_option<S>(
+        amount: &Option<Amount>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&amount.map(MinorUnit::from), serializer)
+    }
 }
 
 #[cfg(test)]
@@ -8950,6 +8965,225 @@ pub enum PaymentLinkStatusWrap {
     IntentStatus(api_enums::IntentStatus),
 }
 
+/// Request to create a reusable payment link, which creates a new payment for every visit
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkCreateRequest {
+    /// The identifier for the profile under which the payments are created
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// Whether the amount is fixed by the merchant or chosen by the customer on each visit
+    #[schema(value_type = ReusablePaymentLinkAmountType, example = "fixed")]
+    #[serde(default)]
+    pub amount_type: api_enums::ReusablePaymentLinkAmountType,
+
+    /// The amount of each payment, in the lowest denomination of the currency. Required for
+    /// fixed amount links, and used as the suggested amount for customer chosen amount links
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+
+    /// The minimum amount the customer can choose, for customer chosen amount links
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+
+    /// The maximum amount the customer can choose, for customer chosen amount links
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+
+    /// The currency of the payments
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Description shown on the payment link and set on each payment
+    #[schema(max_length = 255, example = "Support our work")]
+    pub description: Option<String>,
+
+    /// Custom payment link configuration applied to each payment
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+
+    /// The maximum number of successful payments that can be made through the link
+    #[schema(example = 100)]
+    pub usage_limit: Option<i32>,
+
+    /// Date and time after which the link can no longer be used
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+
+    /// Metadata set on the link, which is also copied onto each payment
+    #[schema(value_type = Option<Object>, example = r#"{ "campaign": "spring" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request to update a reusable payment link
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkUpdateRequest {
+    /// Whether the link can be used to create payments
+    pub is_active: Option<bool>,
+
+    /// Description shown on the payment link and set on each payment
+    #[schema(max_length = 255, example = "Support our work")]
+    pub description: Option<String>,
+
+    /// The maximum number of successful payments that can be made through the link
+    #[schema(example = 100)]
+    pub usage_limit: Option<i32>,
+
+    /// Date and time after which the link can no longer be used
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+
+    /// Custom payment link configuration applied to each payment
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+
+    /// Metadata set on the link, which is also copied onto each payment
+    #[schema(value_type = Option<Object>, example = r#"{ "campaign": "spring" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkResponse {
+    /// Identifier for the reusable payment link
+    pub reusable_payment_link_id: String,
+    /// Identifier for Merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Identifier for the profile under which the payments are created
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// Whether the amount is fixed by the merchant or chosen by the customer
+    #[schema(value_type = ReusablePaymentLinkAmountType)]
+    pub amount_type: api_enums::ReusablePaymentLinkAmountType,
+    /// The amount of each payment, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// The minimum amount the customer can choose
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+    /// The maximum amount the customer can choose
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// Description for the reusable payment link
+    pub description: Option<String>,
+    /// Link which creates a new payment and redirects to its payment link on every visit
+    pub link_to_pay: String,
+    /// Link to the QR code image of the link
+    pub qr_code_url: String,
+    /// Status of the reusable payment link
+    pub status: ReusablePaymentLinkStatus,
+    /// The maximum number of successful payments that can be made through the link
+    pub usage_limit: Option<i32>,
+    /// The number of successful payments made through the link
+    pub usage_count: i32,
+    /// Date and time after which the link can no longer be used
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Date and time of creation of the link
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Status of the reusable payment link
+#[derive(PartialEq, Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReusablePaymentLinkStatus {
+    Active,
+    Inactive,
+    Expired,
+    UsageLimitReached,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkId {
+    /// Identifier for the reusable payment link
+    pub reusable_payment_link_id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkListResponse {
+    /// The number of reusable payment links included in the list
+    pub size: usize,
+    /// The list of reusable payment links
+    pub data: Vec<ReusablePaymentLinkResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkAnalyticsResponse {
+    /// Identifier for the reusable payment link
+    pub reusable_payment_link_id: String,
+    /// The number of times the link was visited
+    pub visit_count: i32,
+    /// The number of successful payments made through the link
+    pub usage_count: i32,
+    /// The number of payments that can still be created, if the link has a usage limit
+    pub remaining_uses: Option<i32>,
+    /// The share of visits which resulted in a successful payment, as a percentage
+    pub conversion_rate: f64,
+    /// Date and time of the last successful payment made through the link
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkVisitRequest {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Identifier for the reusable payment link
+    pub reusable_payment_link_id: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkConfirmRequest {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Identifier for the reusable payment link
+    pub reusable_payment_link_id: String,
+    /// The amount chosen by the customer in the major unit of the currency, for customer
+    /// chosen amount links
+    #[schema(value_type = Option<String>, example = "65.40")]
+    pub amount: Option<StringMajorUnit>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct ReusablePaymentLinkConfirmForm {
+    /// The amount chosen by the customer in the major unit of the currency
+    pub amount: Option<StringMajorUnit>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkQrCodeRequest {
+    /// The image format of the QR code
+    #[serde(default)]
+    pub format: QrCodeImageFormat,
+}
+
+/// Image format of a QR code
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum QrCodeImageFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
 #[derive(Debug, Default, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct ExtendedCardInfoResponse {
     // Encrypted customer payment method data
@@ -52,7 +52,11 @@ pub struct PayoutCreateRequest {
     #[schema(value_type = Option<u64>, example = 1000)]
     #[mandatory_in(PayoutsCreateRequest = u64)]
     #[remove_in(PayoutsConfirmRequest)]
-    #[serde(default, deserialize_with = "payments::amount::deserialize_option")]
+    #[serde(
+        default,
+        deserialize_with = "payments::amount::deserialize_option",
+        serialize_with = "payments::amount::serialize_option"
+    )]
     pub amount: Option<payments::Amount>,
 
     /// The currency of the payout request can be specified here
@@ -242,6 +246,16 @@ impl Default for PayoutMethodData {
     }
 }
 
+impl PayoutMethodData {
+    /// Country to which the payout is sent, when it can be derived from the payout method
+    pub fn get_destination_country(&self) -> Option<api_enums::CountryAlpha2> {
+        match self {
+            Self::Bank(bank) => bank.get_bank_country_code(),
+            Self::Card(_) | Self::Wallet(_) => None,
+        }
+    }
+}
+
 #[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct CardPayout {
     /// The card number
@@ -270,6 +284,18 @@ pub enum Bank {
     Pix(PixBankTransfer),
 }
 
+impl Bank {
+    pub fn get_bank_country_code(&self) -> Option<api_enums::CountryAlpha2> {
+        match self {
+            Self::Ach(bank) => bank.bank_country_code,
+            Self::Bacs(bank) => bank.bank_country_code,
+            Self::Sepa(bank) => bank.bank_country_code,
+            // Pix transfers are only supported for Brazilian bank accounts
+            Self::Pix(_) => Some(api_enums::CountryAlpha2::BR),
+        }
+    }
+}
+
 #[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct AchBankTransfer {
     /// Bank name
@@ -838,6 +864,323 @@ pub struct PayoutLinkInitiateRequest {
     pub payout_id: id_type::PayoutId,
 }
 
+/// Request to create a batch of payouts, which are processed asynchronously
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The business profile under which the payouts of the batch are created. If not passed, the default profile of the merchant is used
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The payouts to be created. Each item accepts the same fields as the payout create request, `confirm` defaults to `true`
+    #[schema(value_type = Vec<PayoutsCreateRequest>, min_items = 1)]
+    pub items: Vec<PayoutCreateRequest>,
+}
+
+/// A row of the payout batch CSV file
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct PayoutBatchRecord {
+    pub payout_id: Option<id_type::PayoutId>,
+    pub merchant_order_reference_id: Option<String>,
+    pub amount: Option<common_utils::types::MinorUnit>,
+    pub currency: Option<api_enums::Currency>,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub description: Option<String>,
+    pub priority: Option<api_enums::PayoutSendPriority>,
+    pub entity_type: Option<api_enums::PayoutEntityType>,
+    pub auto_fulfill: Option<bool>,
+    pub payout_token: Option<String>,
+    pub payout_method_id: Option<String>,
+
+    // Customer details
+    pub customer_id: Option<id_type::CustomerId>,
+    pub email: Option<Email>,
+    pub name: Option<Secret<String>>,
+    pub phone: Option<Secret<String>>,
+    pub phone_country_code: Option<String>,
+
+    // Card details
+    pub card_number: Option<CardNumber>,
+    pub card_expiry_month: Option<Secret<String>>,
+    pub card_expiry_year: Option<Secret<String>>,
+    pub card_holder_name: Option<Secret<String>>,
+
+    // Bank details
+    pub bank_name: Option<String>,
+    pub bank_country_code: Option<api_enums::CountryAlpha2>,
+    pub bank_city: Option<String>,
+    pub bank_account_number: Option<Secret<String>>,
+    pub bank_routing_number: Option<Secret<String>>,
+    pub bank_sort_code: Option<Secret<String>>,
+    pub iban: Option<Secret<String>>,
+    pub bic: Option<Secret<String>>,
+
+    // Wallet details
+    pub paypal_email: Option<Email>,
+    pub paypal_id: Option<Secret<String>>,
+
+    // Billing details
+    pub billing_address_first_name: Option<Secret<String>>,
+    pub billing_address_last_name: Option<Secret<String>>,
+    pub billing_address_line1: Option<Secret<String>>,
+    pub billing_address_line2: Option<Secret<String>>,
+    pub billing_address_city: Option<String>,
+    pub billing_address_state: Option<Secret<String>>,
+    pub billing_address_zip: Option<Secret<String>>,
+    pub billing_address_country: Option<api_enums::CountryAlpha2>,
+}
+
+impl PayoutBatchRecord {
+    /// Builds the payout method data from the columns present in the row.
+    /// The payout method is inferred from the account identifiers, in the order of card number,
+    /// IBAN, sort code, routing number and PayPal details.
+    pub fn get_payout_method_data(&self) -> Result<Option<PayoutMethodData>, String> {
+        let missing_field = |field_name: &str| format!("Missing required field: {field_name}");
+
+        if let Some(card_number) = self.card_number.clone() {
+            return Ok(Some(PayoutMethodData::Card(CardPayout {
+                card_number,
+                expiry_month: self
+                    .card_expiry_month
+                    .clone()
+                    .ok_or_else(|| missing_field("card_expiry_month"))?,
+                expiry_year: self
+                    .card_expiry_year
+                    .clone()
+                    .ok_or_else(|| missing_field("card_expiry_year"))?,
+                card_holder_name: self.card_holder_name.clone(),
+            })));
+        }
+
+        if let Some(iban) = self.iban.clone() {
+            return Ok(Some(PayoutMethodData::Bank(Bank::Sepa(SepaBankTransfer {
+                bank_name: self.bank_name.clone(),
+                bank_country_code: self.bank_country_code,
+                bank_city: self.bank_city.clone(),
+                iban,
+                bic: self.bic.clone(),
+            }))));
+        }
+
+        if let Some(bank_sort_code) = self.bank_sort_code.clone() {
+            return Ok(Some(PayoutMethodData::Bank(Bank::Bacs(BacsBankTransfer {
+                bank_name: self.bank_name.clone(),
+                bank_country_code: self.bank_country_code,
+                bank_city: self.bank_city.clone(),
+                bank_account_number: self
+                    .bank_account_number
+                    .clone()
+                    .ok_or_else(|| missing_field("bank_account_number"))?,
+                bank_sort_code,
+            }))));
+        }
+
+        if let Some(bank_routing_number) = self.bank_routing_number.clone() {
+            return Ok(Some(PayoutMethodData::Bank(Bank::Ach(AchBankTransfer {
+                bank_name: self.bank_name.clone(),
+                bank_country_code: self.bank_country_code,
+                bank_city: self.bank_city.clone(),
+                bank_account_number: self
+                    .bank_account_number
+                    .clone()
+                    .ok_or_else(|| missing_field("bank_account_number"))?,
+                bank_routing_number,
+            }))));
+        }
+
+        if self.paypal_email.is_some() || self.paypal_id.is_some() {
+            return Ok(Some(PayoutMethodData::Wallet(Wallet::Paypal(Paypal {
+                email: self.paypal_email.clone(),
+                telephone_number: None,
+                paypal_id: self.paypal_id.clone(),
+            }))));
+        }
+
+        Ok(None)
+    }
+
+    fn get_billing_address(&self) -> Option<payments::Address> {
+        let address = payments::AddressDetails {
+            first_name: self.billing_address_first_name.clone(),
+            last_name: self.billing_address_last_name.clone(),
+            line1: self.billing_address_line1.clone(),
+            line2: self.billing_address_line2.clone(),
+            line3: None,
+            city: self.billing_address_city.clone(),
+            state: self.billing_address_state.clone(),
+            zip: self.billing_address_zip.clone(),
+            country: self.billing_address_country,
+            origin_zip: None,
+        };
+
+        (address != payments::AddressDetails::default()).then_some(payments::Address {
+            address: Some(address),
+            phone: None,
+            email: None,
+        })
+    }
+}
+
+impl TryFrom<PayoutBatchRecord> for PayoutCreateRequest {
+    type Error = String;
+
+    fn try_from(record: PayoutBatchRecord) -> Result<Self, Self::Error> {
+        let payout_method_data = record.get_payout_method_data()?;
+        let billing = record.get_billing_address();
+        let payout_type = record.payout_type.or_else(|| {
+            payout_method_data
+                .as_ref()
+                .map(|payout_method_data| match payout_method_data {
+                    PayoutMethodData::Card(_) => api_enums::PayoutType::Card,
+                    PayoutMethodData::Bank(_) => api_enums::PayoutType::Bank,
+                    PayoutMethodData::Wallet(_) => api_enums::PayoutType::Wallet,
+                })
+        });
+
+        Ok(Self {
+            payout_id: record.payout_id,
+            merchant_order_reference_id: record.merchant_order_reference_id,
+            amount: record.amount.map(payments::Amount::from),
+            currency: record.currency,
+            payout_type,
+            payout_method_data,
+            billing,
+            auto_fulfill: record.auto_fulfill,
+            customer_id: record.customer_id,
+            description: record.description,
+            entity_type: record.entity_type,
+            priority: record.priority,
+            payout_token: record.payout_token,
+            payout_method_id: record.payout_method_id,
+            email: record.email,
+            name: record.name,
+            phone: record.phone,
+            phone_country_code: record.phone_country_code,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// Unique identifier of the payout batch
+    #[schema(example = "payout_batch_Mt1BGkqAjFvEj9mXhRL9")]
+    pub batch_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The business profile under which the payouts of the batch are created
+    #[schema(value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// Status of the payout batch
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// Name of the uploaded file, if the batch was created from a CSV file
+    pub file_name: Option<String>,
+
+    /// Total number of items in the batch
+    pub total_count: i32,
+
+    /// Number of items in each status
+    #[schema(value_type = HashMap<PayoutBatchItemStatus, i64>)]
+    pub status_with_count: HashMap<api_enums::PayoutBatchItemStatus, i64>,
+
+    /// Time at which the batch was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the batch was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutBatchRetrieveRequest {
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchItemListConstraints {
+    /// Only list the items in this status
+    #[schema(value_type = Option<PayoutBatchItemStatus>)]
+    pub status: Option<api_enums::PayoutBatchItemStatus>,
+
+    /// Limit on the number of items to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The number of items to skip when listing
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutBatchItemListRequest {
+    pub batch_id: String,
+    pub constraints: PayoutBatchItemListConstraints,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// Unique identifier of the item
+    pub item_id: String,
+
+    /// Position of the item in the batch, starting from 1
+    pub line_number: i32,
+
+    /// Processing status of the item
+    #[schema(value_type = PayoutBatchItemStatus, example = "succeeded")]
+    pub status: api_enums::PayoutBatchItemStatus,
+
+    /// The merchant reference passed for the payout
+    pub merchant_order_reference_id: Option<String>,
+
+    /// Identifier of the payout assigned to the item
+    #[schema(value_type = Option<String>, example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_id: Option<id_type::PayoutId>,
+
+    /// Status of the payout created for the item
+    #[schema(value_type = Option<PayoutStatus>, example = "success")]
+    pub payout_status: Option<api_enums::PayoutStatus>,
+
+    /// Error code, if the item was invalid or could not be processed
+    pub error_code: Option<String>,
+
+    /// Error message, if the item was invalid or could not be processed
+    pub error_message: Option<String>,
+
+    /// Time at which the item was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchItemListResponse {
+    /// Unique identifier of the payout batch
+    pub batch_id: String,
+
+    /// The number of items included in the list
+    pub count: usize,
+
+    /// The list of items
+    pub data: Vec<PayoutBatchItemResponse>,
+}
+
+/// Tracking data of the process tracker task which processes a payout batch
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutBatchPTData {
+    pub merchant_id: id_type::MerchantId,
+    pub batch_id: String,
+}
+
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct PayoutLinkDetails {
     pub publishable_key: Secret<String>,
@@ -29,7 +29,13 @@ impl Headers {
 
 #[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
 pub struct ProxyRequest {
-    /// The request body that needs to be forwarded
+    /// The request body that needs to be forwarded.
+    ///
+    /// Sensitive data is referenced as `{{$field_name}}`, or as `{{$alias.field_name}}` to pick
+    /// the token it is read from. References can be placed in any string of a JSON body, in the
+    /// values of a form body given as an object, or anywhere in a form or XML body given as a
+    /// string.
+    #[schema(value_type = Object)]
     pub request_body: Value,
     /// The destination URL where the request needs to be forwarded
     #[schema(value_type = String, example = "https://api.example.com/endpoint")]
@@ -45,6 +51,52 @@ pub struct ProxyRequest {
     /// The type of token that is used to fetch sensitive data from the vault
     #[schema(value_type = TokenType, example = "payment_method_id")]
     pub token_type: TokenType,
+    /// The alias by which the request body can refer to the data of `token`
+    #[schema(example = "card")]
+    pub token_alias: Option<String>,
+    /// Other vault tokens whose data is referenced in the request body. References without an
+    /// alias are looked up in the data of `token` first and then in these tokens, in order.
+    #[serde(default)]
+    pub additional_tokens: Vec<ProxyToken>,
+    /// The format in which the request body is forwarded
+    #[serde(default)]
+    #[schema(value_type = ProxyContentType, example = "json")]
+    pub content_type: ProxyContentType,
+    /// The fields of the response from the destination which are to be tokenized before the
+    /// response is returned
+    pub response_tokenization: Option<ProxyResponseTokenization>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct ProxyToken {
+    /// The vault token that is used to fetch sensitive data from the vault
+    pub token: String,
+    /// The type of token that is used to fetch sensitive data from the vault
+    #[schema(value_type = TokenType, example = "tokenization_id")]
+    pub token_type: TokenType,
+    /// The alias by which the request body can refer to the data of the token
+    #[schema(example = "network_token")]
+    pub alias: Option<String>,
+}
+
+#[derive(Debug, Default, ToSchema, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyContentType {
+    /// The request body is forwarded as JSON
+    #[default]
+    Json,
+    /// The request body, a flat object or an encoded string, is forwarded form-urlencoded
+    FormUrlEncoded,
+    /// The request body, an XML document as a string, is forwarded as XML
+    Xml,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct ProxyResponseTokenization {
+    /// JSON pointers (RFC 6901) to the fields of the response which are to be tokenized. Each
+    /// field is stored in the vault and replaced in the response by the id of its token.
+    #[schema(example = json!(["/card/number", "/card/cvc"]))]
+    pub fields: Vec<String>,
 }
 
 #[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
@@ -63,6 +115,19 @@ pub struct ProxyResponse {
     /// The headers of the response
     #[schema(value_type = Object, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub response_headers: Headers,
+    /// The fields of the response which were tokenized, along with their tokens
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tokenized_fields: Vec<ProxyTokenizedField>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct ProxyTokenizedField {
+    /// The JSON pointer to the field of the response
+    #[schema(example = "/card/number")]
+    pub field: String,
+    /// The id of the token holding the value of the field
+    #[schema(example = "12345_tok_01926c58bc6e77c09e809964e72af8c8")]
+    pub token: String,
 }
 
 impl common_utils::events::ApiEventMetric for ProxyRequest {}
@@ -0,0 +1,138 @@
+use common_utils::{events::ApiEventMetric, id_type, pii, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request to enroll a failed merchant initiated payment for revenue recovery
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RecoveryEnrollmentCreateRequest {
+    /// The failed merchant initiated payment to be recovered
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+
+    /// Maximum number of retries of the payment. Defaults to, and cannot exceed, the limit
+    /// configured for the card network of the payment method
+    #[schema(example = 4)]
+    pub max_retries: Option<u16>,
+
+    /// Number of days from now during which retries may be scheduled. Defaults to, and cannot
+    /// exceed, the configured recovery window
+    #[schema(example = 14)]
+    pub recovery_window_days: Option<u16>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "invoice": "in_1234" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RecoveryEnrollmentResponse {
+    /// Identifier for the enrollment
+    pub enrollment_id: String,
+    /// Identifier for Merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The failed payment which is being recovered
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+    /// The payment method charged by the retries
+    pub payment_method_id: String,
+    /// The amount charged by the retries
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    #[schema(value_type = RecoveryEnrollmentStatus)]
+    pub status: api_enums::RecoveryEnrollmentStatus,
+    /// The decline category of the latest failure, which determines the retry schedule
+    #[schema(value_type = Option<ErrorCategory>)]
+    pub decline_category: Option<api_enums::ErrorCategory>,
+    /// The number of retries made so far
+    pub retry_count: i32,
+    /// Maximum number of retries of the payment
+    pub max_retries: i32,
+    /// Date and time of the next scheduled retry
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+    /// No retries are scheduled after this date and time
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    /// Error code of the latest failure
+    pub last_error_code: Option<String>,
+    /// Error message of the latest failure
+    pub last_error_message: Option<String>,
+    /// The retry payment which succeeded
+    #[schema(value_type = Option<String>)]
+    pub recovered_payment_id: Option<id_type::PaymentId>,
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Date and time of creation of the enrollment
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The retries made so far, only included when retrieving a single enrollment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<Vec<RecoveryAttemptResponse>>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RecoveryAttemptResponse {
+    /// Identifier for the retry
+    pub attempt_id: String,
+    /// The payment created for the retry
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<id_type::PaymentId>,
+    /// The position of the retry, starting from 1
+    pub attempt_number: i32,
+    #[schema(value_type = RecoveryAttemptStatus)]
+    pub status: api_enums::RecoveryAttemptStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[schema(value_type = Option<ErrorCategory>)]
+    pub decline_category: Option<api_enums::ErrorCategory>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub attempted_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RecoveryEnrollmentId {
+    /// Identifier for the enrollment
+    pub enrollment_id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RecoveryEnrollmentListConstraints {
+    /// Only list the enrollments with this status
+    #[schema(value_type = Option<RecoveryEnrollmentStatus>)]
+    pub status: Option<api_enums::RecoveryEnrollmentStatus>,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RecoveryEnrollmentListResponse {
+    /// The number of enrollments included in the list
+    pub size: usize,
+    /// The list of enrollments
+    pub data: Vec<RecoveryEnrollmentResponse>,
+}
+
+/// Tracking data of the process tracker task which retries an enrolled payment
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RecoveryEnrollmentRetryPTData {
+    pub merchant_id: id_type::MerchantId,
+    pub enrollment_id: String,
+}
+
+impl ApiEventMetric for RecoveryEnrollmentCreateRequest {}
+impl ApiEventMetric for RecoveryEnrollmentResponse {}
+impl ApiEventMetric for RecoveryEnrollmentId {}
+impl ApiEventMetric for RecoveryEnrollmentListConstraints {}
+impl ApiEventMetric for RecoveryEnrollmentListResponse {}
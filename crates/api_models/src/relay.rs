@@ -25,6 +25,14 @@ pub struct RelayRequest {
 pub enum RelayData {
     /// The data that is associated with a refund relay request
     Refund(RelayRefundRequestData),
+    /// The data that is associated with a capture relay request
+    Capture(RelayCaptureRequestData),
+    /// The data that is associated with a void relay request
+    Void(RelayVoidRequestData),
+    /// The data that is associated with an incremental authorization relay request
+    IncrementalAuthorization(RelayIncrementalAuthorizationRequestData),
+    /// The data that is associated with a sync relay request
+    Sync(RelaySyncRequestData),
 }
 
 #[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
@@ -40,6 +48,61 @@ pub struct RelayRefundRequestData {
     pub reason: Option<String>,
 }
 
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct RelayCaptureRequestData {
+    /// The amount that was authorized for the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub authorized_amount: MinorUnit,
+    /// The amount that is being captured
+    #[schema(value_type = i64, example = 6540)]
+    pub amount_to_capture: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct RelayVoidRequestData {
+    /// The amount that was authorized for the payment
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<api_enums::Currency>,
+    /// The reason for cancelling the payment
+    #[schema(max_length = 255, example = "requested_by_customer")]
+    pub cancellation_reason: Option<String>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct RelayIncrementalAuthorizationRequestData {
+    /// The total amount that is to be authorized for the payment, including the additional amount
+    #[schema(value_type = i64, example = 8000)]
+    pub total_amount: MinorUnit,
+    /// The amount that is being additionally authorized
+    #[schema(value_type = i64, example = 1460)]
+    pub additional_amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The reason for the incremental authorization
+    #[schema(max_length = 255, example = "Extended stay")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct RelaySyncRequestData {
+    /// The amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The capture method of the payment
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<api_enums::CaptureMethod>,
+}
+
 #[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
 pub struct RelayResponse {
     /// The unique identifier for the Relay
@@ -68,6 +131,10 @@ pub struct RelayResponse {
     pub relay_type: api_enums::RelayType,
     /// The data that is associated with the relay request
     pub data: Option<RelayData>,
+    /// The status of the payment at the connector, as of the latest capture, void, incremental
+    /// authorization or sync relay request
+    #[schema(value_type = Option<AttemptStatus>, example = "charged")]
+    pub connector_payment_status: Option<api_enums::AttemptStatus>,
 }
 
 #[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
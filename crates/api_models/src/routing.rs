@@ -155,6 +155,8 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::PayoutType,
+        DirKeyKind::PayoutDestinationCountry,
     ];
 }
 
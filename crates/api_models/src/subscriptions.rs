@@ -0,0 +1,417 @@
+use common_utils::{events::ApiEventMetric, id_type, pii, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Request to create a product, which groups the prices a customer can subscribe to
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionProductCreateRequest {
+    /// Name of the product
+    #[schema(max_length = 255, example = "Premium plan")]
+    pub name: String,
+
+    /// Description of the product
+    #[schema(max_length = 255, example = "Unlimited access to all the features")]
+    pub description: Option<String>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request to update a product
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionProductUpdateRequest {
+    /// Name of the product
+    #[schema(max_length = 255, example = "Premium plan")]
+    pub name: Option<String>,
+
+    /// Description of the product
+    #[schema(max_length = 255, example = "Unlimited access to all the features")]
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the prices of the product
+    pub is_active: Option<bool>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SubscriptionProductResponse {
+    /// Identifier for the product
+    pub product_id: String,
+    /// Identifier for Merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Name of the product
+    pub name: String,
+    /// Description of the product
+    pub description: Option<String>,
+    /// Whether new subscriptions can be created for the prices of the product
+    pub is_active: bool,
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Date and time of creation of the product
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SubscriptionProductId {
+    /// Identifier for the product
+    pub product_id: String,
+}
+
+/// Request to create a recurring price of a product
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPriceCreateRequest {
+    /// Identifier for the product of the price
+    pub product_id: String,
+
+    /// The amount charged per unit for each billing period, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+
+    /// The currency of the price
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of the billing period
+    #[schema(value_type = BillingInterval, example = "month")]
+    pub billing_interval: api_enums::BillingInterval,
+
+    /// The number of intervals in a billing period, defaults to 1
+    #[schema(example = 1)]
+    pub interval_count: Option<i32>,
+
+    /// The number of trial days granted to new subscriptions of the price
+    #[schema(example = 14)]
+    pub trial_period_days: Option<i32>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request to update a price. The amount and billing period of a price cannot be changed, a new
+/// price has to be created instead
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPriceUpdateRequest {
+    /// Whether new subscriptions can be created for the price
+    pub is_active: Option<bool>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SubscriptionPriceResponse {
+    /// Identifier for the price
+    pub price_id: String,
+    /// Identifier for the product of the price
+    pub product_id: String,
+    /// Identifier for Merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The amount charged per unit for each billing period
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The unit of the billing period
+    #[schema(value_type = BillingInterval)]
+    pub billing_interval: api_enums::BillingInterval,
+    /// The number of intervals in a billing period
+    pub interval_count: i32,
+    /// The number of trial days granted to new subscriptions of the price
+    pub trial_period_days: Option<i32>,
+    /// Whether new subscriptions can be created for the price
+    pub is_active: bool,
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Date and time of creation of the price
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SubscriptionPriceId {
+    /// Identifier for the price
+    pub price_id: String,
+}
+
+/// Request to subscribe a customer to a price
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the profile under which the invoices are charged
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The customer who is subscribed
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The price the customer is subscribed to
+    pub price_id: String,
+
+    /// A payment method of the customer saved for off-session payments, which is used to charge
+    /// the invoices of the subscription
+    pub payment_method_id: String,
+
+    /// The number of units of the price, defaults to 1
+    #[schema(example = 1)]
+    pub quantity: Option<i32>,
+
+    /// Overrides the trial period of the price, `0` creates the subscription without a trial
+    #[schema(example = 14)]
+    pub trial_period_days: Option<i32>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "plan": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request to update a subscription, or to change its price or quantity
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionUpdateRequest {
+    /// The new price of the subscription. It must have the same currency and billing period as
+    /// the current price
+    pub price_id: Option<String>,
+
+    /// The new number of units of the price
+    #[schema(example = 2)]
+    pub quantity: Option<i32>,
+
+    /// How the price or quantity change is prorated, defaults to `create_prorations`
+    #[schema(value_type = Option<ProrationBehavior>, example = "create_prorations")]
+    pub proration_behavior: Option<api_enums::ProrationBehavior>,
+
+    /// A payment method of the customer saved for off-session payments, which is used to charge
+    /// the invoices of the subscription
+    pub payment_method_id: Option<String>,
+
+    /// Whether the subscription is cancelled at the end of the current billing period
+    pub cancel_at_period_end: Option<bool>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "plan": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request to cancel a subscription
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    /// Cancel the subscription at the end of the current billing period instead of immediately
+    #[serde(default)]
+    pub at_period_end: bool,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// Identifier for the subscription
+    pub subscription_id: String,
+    /// Identifier for Merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The identifier for the profile under which the invoices are charged
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The customer who is subscribed
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+    /// The price the customer is subscribed to
+    pub price_id: String,
+    /// The price which replaces `price_id` from the next billing period
+    pub pending_price_id: Option<String>,
+    /// The payment method used to charge the invoices of the subscription
+    pub payment_method_id: String,
+    #[schema(value_type = SubscriptionStatus)]
+    pub status: api_enums::SubscriptionStatus,
+    /// The number of units of the price
+    pub quantity: i32,
+    /// The start of the first paid billing period, from which the billing periods are computed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub billing_cycle_anchor: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_start: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// Whether the subscription is cancelled at the end of the current billing period
+    pub cancel_at_period_end: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    /// Prorations to be added to the next invoice, negative for credits
+    #[schema(value_type = i64, example = 0)]
+    pub pending_proration_amount: MinorUnit,
+    /// The latest invoice of the subscription
+    pub latest_invoice_id: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Date and time of creation of the subscription
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SubscriptionId {
+    /// Identifier for the subscription
+    pub subscription_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct InvoiceResponse {
+    /// Identifier for the invoice
+    pub invoice_id: String,
+    /// Identifier for the subscription of the invoice
+    pub subscription_id: String,
+    /// Identifier for Merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+    #[schema(value_type = InvoiceStatus)]
+    pub status: api_enums::InvoiceStatus,
+    #[schema(value_type = InvoiceBillingReason)]
+    pub billing_reason: api_enums::InvoiceBillingReason,
+    /// Total amount of the invoice, including `proration_amount`
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+    /// Prorations of price or quantity changes included in the invoice, negative for credits
+    #[schema(value_type = i64, example = 0)]
+    pub proration_amount: MinorUnit,
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// Start of the billing period covered by the invoice
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+    /// End of the billing period covered by the invoice
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    /// The payment of the latest attempt to charge the invoice
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<id_type::PaymentId>,
+    /// The number of attempts made to charge the invoice
+    pub attempt_count: i32,
+    /// Date and time of the next attempt to charge the invoice
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_payment_attempt_at: Option<PrimitiveDateTime>,
+    /// Error code of the latest failed attempt
+    pub error_code: Option<String>,
+    /// Error message of the latest failed attempt
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub paid_at: Option<PrimitiveDateTime>,
+    /// Date and time of creation of the invoice
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct InvoiceId {
+    /// Identifier for the invoice
+    pub invoice_id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionListConstraints {
+    /// Only list the subscriptions of this customer
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPriceListConstraints {
+    /// The product whose prices are listed
+    pub product_id: String,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+/// Pagination constraints for lists of products and invoices
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPaginationConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SubscriptionProductListResponse {
+    /// The number of products included in the list
+    pub size: usize,
+    /// The list of products
+    pub data: Vec<SubscriptionProductResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SubscriptionPriceListResponse {
+    /// The number of prices included in the list
+    pub size: usize,
+    /// The list of prices
+    pub data: Vec<SubscriptionPriceResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SubscriptionListResponse {
+    /// The number of subscriptions included in the list
+    pub size: usize,
+    /// The list of subscriptions
+    pub data: Vec<SubscriptionResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct InvoiceListResponse {
+    /// The number of invoices included in the list
+    pub size: usize,
+    /// The list of invoices
+    pub data: Vec<InvoiceResponse>,
+}
+
+/// Tracking data of the process tracker task which bills a subscription
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SubscriptionBillingPTData {
+    pub merchant_id: id_type::MerchantId,
+    pub subscription_id: String,
+}
+
+impl ApiEventMetric for SubscriptionProductCreateRequest {}
+impl ApiEventMetric for SubscriptionProductUpdateRequest {}
+impl ApiEventMetric for SubscriptionProductResponse {}
+impl ApiEventMetric for SubscriptionProductId {}
+impl ApiEventMetric for SubscriptionProductListResponse {}
+impl ApiEventMetric for SubscriptionPriceCreateRequest {}
+impl ApiEventMetric for SubscriptionPriceUpdateRequest {}
+impl ApiEventMetric for SubscriptionPriceResponse {}
+impl ApiEventMetric for SubscriptionPriceId {}
+impl ApiEventMetric for SubscriptionPriceListConstraints {}
+impl ApiEventMetric for SubscriptionPriceListResponse {}
+impl ApiEventMetric for SubscriptionCreateRequest {}
+impl ApiEventMetric for SubscriptionUpdateRequest {}
+impl ApiEventMetric for SubscriptionCancelRequest {}
+impl ApiEventMetric for SubscriptionResponse {}
+impl ApiEventMetric for SubscriptionId {}
+impl ApiEventMetric for SubscriptionListConstraints {}
+impl ApiEventMetric for SubscriptionListResponse {}
+impl ApiEventMetric for SubscriptionPaginationConstraints {}
+impl ApiEventMetric for InvoiceResponse {}
+impl ApiEventMetric for InvoiceId {}
+impl ApiEventMetric for InvoiceListResponse {}
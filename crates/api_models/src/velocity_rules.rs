@@ -0,0 +1,174 @@
+use common_utils::{events::ApiEventMetric, id_type};
+use utoipa::ToSchema;
+
+/// The attribute of a payment on which velocity counters are maintained
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleKey {
+    /// Fingerprint of the card number, generated using the profile's card testing secret key
+    CardFingerprint,
+    /// Email of the customer making the payment
+    Email,
+    /// IP address present in the browser information of the payment
+    IpAddress,
+    /// First six digits of the card number
+    CardBin,
+}
+
+/// The quantity that is counted within the window of a velocity rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleMetric {
+    /// Number of payment attempts
+    AttemptCount,
+    /// Number of payment attempts declined by the connector
+    DeclineCount,
+    /// Sum of the amounts (in minor units) of the payment attempts, counted separately for each
+    /// currency
+    Amount,
+}
+
+/// The action taken when a velocity rule is breached.
+/// Actions are ordered by severity, `block` being the most severe.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleAction {
+    /// Allow the payment, but report the breached rule in the payment response and audit events
+    Flag,
+    /// Force a 3DS challenge for the payment
+    ThreeDs,
+    /// Reject the payment
+    Block,
+}
+
+/// A single velocity rule
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityRule {
+    /// Name of the rule, unique within a profile
+    #[schema(example = "too_many_declines_per_card")]
+    pub name: String,
+    /// The payment attribute on which the counter is maintained
+    #[schema(value_type = VelocityRuleKey)]
+    pub key: VelocityRuleKey,
+    /// The quantity that is counted
+    #[schema(value_type = VelocityRuleMetric)]
+    pub metric: VelocityRuleMetric,
+    /// The rule is breached when the counted value exceeds this threshold.
+    /// For the `amount` metric, the threshold is in minor units and applies to each currency.
+    #[schema(example = 5)]
+    pub threshold: i64,
+    /// Length of the sliding window in seconds
+    #[schema(example = 3600)]
+    pub window_in_secs: u32,
+    /// The action to be taken when the rule is breached
+    #[schema(value_type = VelocityRuleAction)]
+    pub action: VelocityRuleAction,
+}
+
+/// Request to create or replace the velocity rules of a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityRulesRequest {
+    /// The list of rules to be evaluated for every payment confirmed under the profile
+    pub rules: Vec<VelocityRule>,
+}
+
+/// Velocity rules configured for a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VelocityRulesRecord {
+    /// The list of rules to be evaluated for every payment confirmed under the profile
+    pub rules: Vec<VelocityRule>,
+    /// Time at which the rules were first configured, as a unix timestamp
+    pub created_at: i64,
+    /// Time at which the rules were last modified, as a unix timestamp
+    pub modified_at: i64,
+}
+
+/// Response containing the velocity rules configured for a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VelocityRulesResponse {
+    /// The identifier of the profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The list of rules to be evaluated for every payment confirmed under the profile
+    pub rules: Vec<VelocityRule>,
+    /// Time at which the rules were first configured, as a unix timestamp
+    pub created_at: i64,
+    /// Time at which the rules were last modified, as a unix timestamp
+    pub modified_at: i64,
+}
+
+/// A velocity rule that was breached by a payment
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct TriggeredVelocityRule {
+    /// Name of the rule
+    pub name: String,
+    /// The payment attribute on which the counter is maintained
+    #[schema(value_type = VelocityRuleKey)]
+    pub key: VelocityRuleKey,
+    /// The quantity that is counted
+    #[schema(value_type = VelocityRuleMetric)]
+    pub metric: VelocityRuleMetric,
+    /// The configured threshold of the rule
+    pub threshold: i64,
+    /// The value observed within the window, including the current payment
+    pub observed_value: i64,
+    /// The action configured for the rule
+    #[schema(value_type = VelocityRuleAction)]
+    pub action: VelocityRuleAction,
+}
+
+/// Outcome of the velocity rules evaluated for a payment
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VelocityCheckResponse {
+    /// The most severe action among the breached rules
+    #[schema(value_type = VelocityRuleAction)]
+    pub action: VelocityRuleAction,
+    /// The rules which were breached by the payment
+    pub triggered_rules: Vec<TriggeredVelocityRule>,
+}
+
+impl ApiEventMetric for VelocityRulesRequest {}
+impl ApiEventMetric for VelocityRulesResponse {}
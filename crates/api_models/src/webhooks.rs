@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{analytics, disputes, enums as api_enums, mandates, payments, refunds, subscriptions};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -431,6 +431,12 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = MetricAlertDetails, title = "MetricAlertDetails")]
+    MetricAlertDetails(Box<analytics::metric_alerts::MetricAlertDetails>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
+    #[schema(value_type = InvoiceResponse, title = "InvoiceResponse")]
+    InvoiceDetails(Box<subscriptions::InvoiceResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -448,6 +454,12 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = MetricAlertDetails, title = "MetricAlertDetails")]
+    MetricAlertDetails(Box<analytics::metric_alerts::MetricAlertDetails>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
+    #[schema(value_type = InvoiceResponse, title = "InvoiceResponse")]
+    InvoiceDetails(Box<subscriptions::InvoiceResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1499,6 +1499,8 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    MetricAlerts,
+    Subscriptions,
 }
 
 impl EventClass {
@@ -1537,6 +1539,16 @@ impl EventClass {
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
             ]),
+            Self::MetricAlerts => HashSet::from([EventType::MetricAlertTriggered]),
+            Self::Subscriptions => HashSet::from([
+                EventType::SubscriptionCreated,
+                EventType::SubscriptionUpdated,
+                EventType::SubscriptionPastDue,
+                EventType::SubscriptionCancelled,
+                EventType::InvoiceCreated,
+                EventType::InvoicePaid,
+                EventType::InvoicePaymentFailed,
+            ]),
         }
     }
 }
@@ -1596,6 +1608,14 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    MetricAlertTriggered,
+    SubscriptionCreated,
+    SubscriptionUpdated,
+    SubscriptionPastDue,
+    SubscriptionCancelled,
+    InvoiceCreated,
+    InvoicePaid,
+    InvoicePaymentFailed,
 }
 
 #[derive(
@@ -2430,6 +2450,10 @@ pub enum RelayStatus {
 #[serde(rename_all = "snake_case")]
 pub enum RelayType {
     Refund,
+    Capture,
+    Void,
+    IncrementalAuthorization,
+    Sync,
 }
 
 #[derive(
@@ -7182,6 +7206,78 @@ pub enum PayoutStatus {
     RequiresVendorAccountCreation,
 }
 
+/// Status of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The batch is accepted and waiting to be processed
+    #[default]
+    Pending,
+    /// Payouts are being created for the items of the batch
+    Processing,
+    /// Payouts were created for all the items of the batch
+    Completed,
+    /// Payouts were created for some of the items of the batch
+    PartiallyCompleted,
+    /// Payouts could not be created for any of the items of the batch
+    Failed,
+}
+
+/// Status of a single item of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The item is waiting to be processed
+    #[default]
+    Pending,
+    /// A payout was created for the item
+    Succeeded,
+    /// The payout could not be created, or it failed at the connector
+    Failed,
+    /// The item failed validation and was not processed
+    Invalid,
+}
+
+impl PayoutBatchItemStatus {
+    pub fn is_terminal(self) -> bool {
+        match self {
+            Self::Succeeded | Self::Failed | Self::Invalid => true,
+            Self::Pending => false,
+        }
+    }
+}
+
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
 #[derive(
     Clone,
@@ -8252,6 +8348,322 @@ pub enum EnablePaymentLinkRequest {
     Skip,
 }
 
+/// How the amount of each payment made through a reusable payment link is determined
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReusablePaymentLinkAmountType {
+    /// Every payment is made for the amount of the link, as on a product page
+    #[default]
+    Fixed,
+    /// The customer chooses the amount of each payment within the limits of the link, as for donations or tips
+    CustomerChosen,
+}
+
+/// The status of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The first invoice of the subscription has not been paid yet
+    #[default]
+    Incomplete,
+    /// The subscription is in its trial period, no invoice is charged until the trial ends
+    Trialing,
+    /// The latest invoice of the subscription was paid
+    Active,
+    /// The latest invoice of the subscription could not be charged and is being retried
+    PastDue,
+    /// All the retries of the latest invoice failed, the subscription is no longer billed
+    Unpaid,
+    /// The subscription was cancelled and is no longer billed
+    Cancelled,
+}
+
+impl SubscriptionStatus {
+    /// Whether the subscription is still billed at the end of its current period
+    pub fn is_billable(self) -> bool {
+        matches!(self, Self::Trialing | Self::Active | Self::PastDue)
+    }
+}
+
+/// The status of an invoice of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// The invoice is awaiting payment
+    #[default]
+    Open,
+    /// The invoice was paid
+    Paid,
+    /// The invoice was cancelled before it was paid
+    Void,
+    /// All the attempts to charge the invoice failed
+    Uncollectible,
+}
+
+/// Why an invoice of a subscription was created
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InvoiceBillingReason {
+    /// The first invoice of a subscription created without a trial
+    SubscriptionCreate,
+    /// The invoice for a new billing period of a subscription
+    SubscriptionCycle,
+    /// The invoice of the prorations of a plan change
+    SubscriptionUpdate,
+}
+
+/// The unit of the billing period of a price
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BillingInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// How a change of the price of a subscription in the middle of a billing period is billed
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProrationBehavior {
+    /// The prorated difference is added to, or credited on, the next invoice
+    #[default]
+    CreateProrations,
+    /// The prorated difference is invoiced and charged immediately, credits are carried over to the next invoice
+    AlwaysInvoice,
+    /// The new price applies from the next billing period, without prorations
+    None,
+}
+
+/// The status of a failed payment enrolled for native revenue recovery
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecoveryEnrollmentStatus {
+    /// A retry of the payment is scheduled, or is being processed
+    #[default]
+    Scheduled,
+    /// A retry of the payment succeeded
+    Recovered,
+    /// No more retries are possible, either because the retries were used up, the recovery window
+    /// ended, the card expired or the payment was hard declined
+    Exhausted,
+    /// The enrollment was cancelled by the merchant
+    Cancelled,
+}
+
+impl RecoveryEnrollmentStatus {
+    pub fn is_terminal(self) -> bool {
+        !matches!(self, Self::Scheduled)
+    }
+}
+
+/// The status of a single retry of a payment enrolled for native revenue recovery
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecoveryAttemptStatus {
+    /// The retry payment is still being processed by the connector
+    #[default]
+    Processing,
+    Succeeded,
+    Failed,
+}
+
+/// A customer field which is indexed with a keyed hash for detecting duplicate customers
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerBlindIndexField {
+    Email,
+    Phone,
+}
+
+/// The status of the merge of a duplicate customer into a surviving customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerMergeStatus {
+    /// The payment methods, mandates and payments of the merged customer are being moved
+    InProgress,
+    /// Everything held by the merged customer was moved to the surviving customer
+    Completed,
+}
+
+/// The outcome of checking a stored card with the card networks through an account updater
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardAccountUpdateStatus {
+    /// The card was reissued with a new card number, and possibly a new expiry
+    CardUpdated,
+    /// The card was reissued with a new expiry under the same card number
+    ExpiryUpdated,
+    /// The account of the card was closed by the issuer
+    AccountClosed,
+    /// The issuer reported no change to the card
+    NoChange,
+    /// The issuer asked for the cardholder to be contacted for new card details
+    ContactCardholder,
+    /// The card could not be checked or the update could not be applied
+    Failed,
+}
+
 #[derive(
     Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize, Default, ToSchema,
 )]
@@ -8733,6 +9145,14 @@ pub enum ProcessTrackerRunner {
     PassiveRecoveryWorkflow,
     ProcessDisputeWorkflow,
     DisputeListWorkflow,
+    DisputeAutomationWorkflow,
+    PayoutBatchWorkflow,
+    AnalyticsReportWorkflow,
+    MetricAlertWorkflow,
+    KvMigrationWorkflow,
+    SubscriptionBillingWorkflow,
+    RecoveryEnrollmentWorkflow,
+    AccountUpdaterWorkflow,
 }
 
 #[derive(Debug)]
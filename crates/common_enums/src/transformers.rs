@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::enums::PayoutStatus;
 use crate::enums::{
     AttemptStatus, Country, CountryAlpha2, CountryAlpha3, DisputeStatus, EventType, IntentStatus,
-    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
+    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus, SubscriptionStatus,
 };
 
 impl Display for NumericCountryCodeParseError {
@@ -2212,6 +2212,19 @@ impl From<MandateStatus> for Option<EventType> {
     }
 }
 
+impl From<SubscriptionStatus> for Option<EventType> {
+    fn from(value: SubscriptionStatus) -> Self {
+        match value {
+            SubscriptionStatus::Trialing
+            | SubscriptionStatus::Active
+            | SubscriptionStatus::Unpaid => Some(EventType::SubscriptionUpdated),
+            SubscriptionStatus::PastDue => Some(EventType::SubscriptionPastDue),
+            SubscriptionStatus::Cancelled => Some(EventType::SubscriptionCancelled),
+            SubscriptionStatus::Incomplete => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
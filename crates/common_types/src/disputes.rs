@@ -0,0 +1,218 @@
+//! Dispute related types
+
+use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The kind of money movement recorded against a dispute
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeFinancialRecordType {
+    /// Fee charged by the connector for processing the chargeback
+    ChargebackFee,
+    /// Disputed amount withdrawn from the merchant's balance
+    AmountDebited,
+    /// Amount returned to the merchant's balance after the dispute was fully or partially won
+    AmountReinstated,
+    /// Chargeback fee returned by the connector
+    FeeRefunded,
+}
+
+/// Origin of a dispute financial record
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeFinancialRecordSource {
+    /// Record was received from the connector through webhooks
+    Connector,
+    /// Record was added by the merchant
+    Manual,
+}
+
+/// A single fee or amount adjustment associated with a dispute
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DisputeFinancialRecord {
+    /// The kind of money movement
+    pub record_type: DisputeFinancialRecordType,
+    /// Amount of the record in minor units, always non-negative
+    #[schema(value_type = i64, example = 1500)]
+    pub amount: MinorUnit,
+    /// Currency of the record, which can differ from the dispute currency
+    #[schema(value_type = Currency)]
+    pub currency: common_enums::Currency,
+    /// Origin of the record
+    pub source: DisputeFinancialRecordSource,
+    /// Reference of the record at the connector, e.g. the balance transaction id
+    pub reference_id: Option<String>,
+    /// Free form description of the record
+    pub description: Option<String>,
+    /// Time at which the record was added
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl DisputeFinancialRecord {
+    /// Amount of the record as seen from the merchant's balance, negative values are losses
+    pub fn get_signed_amount(&self) -> MinorUnit {
+        match self.record_type {
+            DisputeFinancialRecordType::ChargebackFee
+            | DisputeFinancialRecordType::AmountDebited => MinorUnit::new(0) - self.amount,
+            DisputeFinancialRecordType::AmountReinstated
+            | DisputeFinancialRecordType::FeeRefunded => self.amount,
+        }
+    }
+
+    fn is_same_connector_record(&self, other: &Self) -> bool {
+        self.record_type == other.record_type
+            && self.reference_id.is_some()
+            && self.reference_id == other.reference_id
+    }
+}
+
+/// Fees and amount adjustments associated with a dispute
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, FromSqlRow, AsExpression,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct DisputeFinancialRecords(pub Vec<DisputeFinancialRecord>);
+
+impl_to_sql_from_sql_json!(DisputeFinancialRecords);
+
+impl DisputeFinancialRecords {
+    /// Appends the given records, skipping the ones which are already present.
+    /// Records are considered the same when they have the same type and reference id,
+    /// so that repeated connector webhooks do not duplicate the records.
+    pub fn merge(mut self, records: Vec<DisputeFinancialRecord>) -> Self {
+        for record in records {
+            if !self
+                .0
+                .iter()
+                .any(|existing_record| existing_record.is_same_connector_record(&record))
+            {
+                self.0.push(record);
+            }
+        }
+        self
+    }
+
+    /// Returns the records as a slice
+    pub fn get_records(&self) -> &[DisputeFinancialRecord] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(
+        record_type: DisputeFinancialRecordType,
+        amount: i64,
+        reference_id: Option<&str>,
+    ) -> DisputeFinancialRecord {
+        DisputeFinancialRecord {
+            record_type,
+            amount: MinorUnit::new(amount),
+            currency: common_enums::Currency::USD,
+            source: DisputeFinancialRecordSource::Connector,
+            reference_id: reference_id.map(str::to_owned),
+            description: None,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_merge_skips_repeated_connector_records() {
+        let records = DisputeFinancialRecords::default().merge(vec![
+            record(
+                DisputeFinancialRecordType::ChargebackFee,
+                1500,
+                Some("txn_1"),
+            ),
+            record(
+                DisputeFinancialRecordType::AmountDebited,
+                10000,
+                Some("txn_2"),
+            ),
+        ]);
+
+        let merged_records = records.merge(vec![
+            record(
+                DisputeFinancialRecordType::ChargebackFee,
+                1500,
+                Some("txn_1"),
+            ),
+            record(
+                DisputeFinancialRecordType::AmountReinstated,
+                10000,
+                Some("txn_3"),
+            ),
+        ]);
+
+        assert_eq!(
+            merged_records
+                .get_records()
+                .iter()
+                .map(|record| record.record_type)
+                .collect::<Vec<_>>(),
+            vec![
+                DisputeFinancialRecordType::ChargebackFee,
+                DisputeFinancialRecordType::AmountDebited,
+                DisputeFinancialRecordType::AmountReinstated,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_keeps_records_without_reference_id() {
+        let records = DisputeFinancialRecords::default()
+            .merge(vec![record(
+                DisputeFinancialRecordType::ChargebackFee,
+                1500,
+                None,
+            )])
+            .merge(vec![record(
+                DisputeFinancialRecordType::ChargebackFee,
+                1500,
+                None,
+            )]);
+
+        assert_eq!(records.get_records().len(), 2);
+    }
+
+    #[test]
+    fn test_merge_keeps_records_of_different_types_with_same_reference_id() {
+        let records = DisputeFinancialRecords::default().merge(vec![
+            record(
+                DisputeFinancialRecordType::ChargebackFee,
+                1500,
+                Some("txn_1"),
+            ),
+            record(DisputeFinancialRecordType::FeeRefunded, 1500, Some("txn_1")),
+        ]);
+
+        assert_eq!(records.get_records().len(), 2);
+    }
+
+    #[test]
+    fn test_signed_amount() {
+        assert_eq!(
+            record(DisputeFinancialRecordType::ChargebackFee, 1500, None).get_signed_amount(),
+            MinorUnit::new(-1500)
+        );
+        assert_eq!(
+            record(DisputeFinancialRecordType::AmountDebited, 10000, None).get_signed_amount(),
+            MinorUnit::new(-10000)
+        );
+        assert_eq!(
+            record(DisputeFinancialRecordType::AmountReinstated, 10000, None).get_signed_amount(),
+            MinorUnit::new(10000)
+        );
+        assert_eq!(
+            record(DisputeFinancialRecordType::FeeRefunded, 1500, None).get_signed_amount(),
+            MinorUnit::new(1500)
+        );
+    }
+}
@@ -14,6 +14,9 @@ pub enum ApiEventsType {
     Payout {
         payout_id: id_type::PayoutId,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
     #[cfg(feature = "v1")]
     Payment {
         payment_id: id_type::PaymentId,
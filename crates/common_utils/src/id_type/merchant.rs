@@ -102,6 +102,16 @@ impl MerchantId {
         format!("step_up_enabled_{}", self.get_string_repr())
     }
 
+    /// get_kv_migration_key
+    pub fn get_kv_migration_key(&self) -> String {
+        format!("kv_migration_{}", self.get_string_repr())
+    }
+
+    /// get_kv_migration_grace_period_key
+    pub fn get_kv_migration_grace_period_key(&self) -> String {
+        format!("kv_migration_grace_period_{}", self.get_string_repr())
+    }
+
     /// get_max_auto_retries_enabled key
     pub fn get_max_auto_retries_enabled(&self) -> String {
         format!("max_auto_retries_enabled_{}", self.get_string_repr())
@@ -22,6 +22,29 @@ impl crate::events::ApiEventMetric for ProfileId {
     }
 }
 
+/// All the keys that can be formed from profile id
+impl ProfileId {
+    /// get_velocity_rules_config_key
+    pub fn get_velocity_rules_config_key(&self) -> String {
+        format!("velocity_rules_{}", self.get_string_repr())
+    }
+
+    /// get_dispute_automation_config_key
+    pub fn get_dispute_automation_config_key(&self) -> String {
+        format!("dispute_automation_{}", self.get_string_repr())
+    }
+
+    /// get_analytics_report_schedules_key
+    pub fn get_analytics_report_schedules_key(&self) -> String {
+        format!("analytics_report_schedules_{}", self.get_string_repr())
+    }
+
+    /// get_metric_alert_rules_key
+    pub fn get_metric_alert_rules_key(&self) -> String {
+        format!("metric_alert_rules_{}", self.get_string_repr())
+    }
+}
+
 impl FromStr for ProfileId {
     type Err = error_stack::Report<crate::errors::ValidationError>;
 
@@ -385,6 +385,67 @@ impl AuthenticationUpdateInternal {
     }
 }
 
+impl From<&AuthenticationNew> for Authentication {
+    fn from(authentication_new: &AuthenticationNew) -> Self {
+        let now = common_utils::date_time::now();
+        Self {
+            authentication_id: authentication_new.authentication_id.clone(),
+            merchant_id: authentication_new.merchant_id.clone(),
+            authentication_connector: authentication_new.authentication_connector.clone(),
+            connector_authentication_id: authentication_new.connector_authentication_id.clone(),
+            authentication_data: None,
+            payment_method_id: authentication_new.payment_method_id.clone(),
+            authentication_type: authentication_new.authentication_type,
+            authentication_status: authentication_new.authentication_status,
+            authentication_lifecycle_status: authentication_new.authentication_lifecycle_status,
+            created_at: now,
+            modified_at: now,
+            error_message: authentication_new.error_message.clone(),
+            error_code: authentication_new.error_code.clone(),
+            connector_metadata: authentication_new.connector_metadata.clone(),
+            maximum_supported_version: authentication_new.maximum_supported_version.clone(),
+            threeds_server_transaction_id: authentication_new.threeds_server_transaction_id.clone(),
+            cavv: authentication_new.cavv.clone(),
+            authentication_flow_type: authentication_new.authentication_flow_type.clone(),
+            message_version: authentication_new.message_version.clone(),
+            eci: authentication_new.eci.clone(),
+            trans_status: authentication_new.trans_status.clone(),
+            acquirer_bin: authentication_new.acquirer_bin.clone(),
+            acquirer_merchant_id: authentication_new.acquirer_merchant_id.clone(),
+            three_ds_method_data: authentication_new.three_ds_method_data.clone(),
+            three_ds_method_url: authentication_new.three_ds_method_url.clone(),
+            acs_url: authentication_new.acs_url.clone(),
+            challenge_request: authentication_new.challenge_request.clone(),
+            acs_reference_number: authentication_new.acs_reference_number.clone(),
+            acs_trans_id: authentication_new.acs_trans_id.clone(),
+            acs_signed_content: authentication_new.acs_signed_content.clone(),
+            profile_id: authentication_new.profile_id.clone(),
+            payment_id: authentication_new.payment_id.clone(),
+            merchant_connector_id: authentication_new.merchant_connector_id.clone(),
+            ds_trans_id: authentication_new.ds_trans_id.clone(),
+            directory_server_id: authentication_new.directory_server_id.clone(),
+            acquirer_country_code: authentication_new.acquirer_country_code.clone(),
+            service_details: authentication_new.service_details.clone(),
+            organization_id: authentication_new.organization_id.clone(),
+            authentication_client_secret: authentication_new.authentication_client_secret.clone(),
+            force_3ds_challenge: authentication_new.force_3ds_challenge,
+            psd2_sca_exemption_type: authentication_new.psd2_sca_exemption_type,
+            return_url: authentication_new.return_url.clone(),
+            amount: authentication_new.amount,
+            currency: authentication_new.currency,
+            billing_address: authentication_new.billing_address.clone(),
+            shipping_address: authentication_new.shipping_address.clone(),
+            browser_info: authentication_new.browser_info.clone(),
+            email: authentication_new.email.clone(),
+            profile_acquirer_id: authentication_new.profile_acquirer_id.clone(),
+            challenge_code: authentication_new.challenge_code.clone(),
+            challenge_cancel: authentication_new.challenge_cancel.clone(),
+            challenge_code_reason: authentication_new.challenge_code_reason.clone(),
+            message_extension: authentication_new.message_extension.clone(),
+        }
+    }
+}
+
 impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
     fn from(auth_update: AuthenticationUpdate) -> Self {
         match auth_update {
@@ -78,6 +78,8 @@ pub struct Profile {
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
 }
 
 #[cfg(feature = "v1")]
@@ -136,6 +138,8 @@ pub struct ProfileNew {
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
 }
 
 #[cfg(feature = "v1")]
@@ -194,6 +198,8 @@ pub struct ProfileUpdateInternal {
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
 }
 
 #[cfg(feature = "v1")]
@@ -249,6 +255,8 @@ impl ProfileUpdateInternal {
             merchant_category_code,
             merchant_country_code,
             dispute_polling_interval,
+            is_payout_auto_retries_enabled,
+            max_payout_auto_retries_enabled,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -335,6 +343,10 @@ impl ProfileUpdateInternal {
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
             merchant_country_code: merchant_country_code.or(source.merchant_country_code),
             dispute_polling_interval: dispute_polling_interval.or(source.dispute_polling_interval),
+            is_payout_auto_retries_enabled: is_payout_auto_retries_enabled
+                .or(source.is_payout_auto_retries_enabled),
+            max_payout_auto_retries_enabled: max_payout_auto_retries_enabled
+                .or(source.max_payout_auto_retries_enabled),
         }
     }
 }
@@ -399,6 +411,8 @@ pub struct Profile {
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -413,6 +427,8 @@ pub struct Profile {
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub split_txns_enabled: Option<common_enums::SplitTxnsEnabled>,
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 impl Profile {
@@ -489,6 +505,8 @@ pub struct ProfileNew {
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub split_txns_enabled: Option<common_enums::SplitTxnsEnabled>,
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v2")]
@@ -549,6 +567,8 @@ pub struct ProfileUpdateInternal {
     pub is_external_vault_enabled: Option<bool>,
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub split_txns_enabled: Option<common_enums::SplitTxnsEnabled>,
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v2")]
@@ -606,6 +626,7 @@ impl ProfileUpdateInternal {
             merchant_category_code,
             merchant_country_code,
             split_txns_enabled,
+            allowed_proxy_destination_hosts,
         } = self;
         Profile {
             id: source.id,
@@ -702,7 +723,11 @@ impl ProfileUpdateInternal {
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
             merchant_country_code: merchant_country_code.or(source.merchant_country_code),
             dispute_polling_interval: None,
+            is_payout_auto_retries_enabled: None,
+            max_payout_auto_retries_enabled: None,
             split_txns_enabled: split_txns_enabled.or(source.split_txns_enabled),
+            allowed_proxy_destination_hosts: allowed_proxy_destination_hosts
+                .or(source.allowed_proxy_destination_hosts),
         }
     }
 }
@@ -0,0 +1,133 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{customer_blind_index, customer_merge},
+};
+
+/// A keyed hash of a normalized customer field, which allows finding customers sharing the same
+/// email or phone number without decrypting the customer details
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = customer_blind_index, primary_key(merchant_id, customer_id, field), check_for_backend(diesel::pg::Pg))]
+pub struct CustomerBlindIndex {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub field: storage_enums::CustomerBlindIndexField,
+    pub hashed_value: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_blind_index)]
+pub struct CustomerBlindIndexNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub field: storage_enums::CustomerBlindIndexField,
+    pub hashed_value: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Audit record of the merge of a duplicate customer into a surviving customer
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = customer_merge, primary_key(merge_id), check_for_backend(diesel::pg::Pg))]
+pub struct CustomerMerge {
+    pub merge_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub surviving_customer_id: common_utils::id_type::CustomerId,
+    pub merged_customer_id: common_utils::id_type::CustomerId,
+    /// The payment methods moved from the merged customer
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub payment_method_ids: Vec<String>,
+    /// The mandates moved from the merged customer
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub mandate_ids: Vec<String>,
+    /// The number of payments whose customer reference was moved
+    pub payments_moved: i64,
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub status: storage_enums::CustomerMergeStatus,
+    /// The locker references of the merged customer whose data was moved to the surviving
+    /// customer, but could not be deleted from the locker
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub orphaned_locker_references: Vec<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_merge)]
+pub struct CustomerMergeNew {
+    pub merge_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub surviving_customer_id: common_utils::id_type::CustomerId,
+    pub merged_customer_id: common_utils::id_type::CustomerId,
+    pub payment_method_ids: Vec<String>,
+    pub mandate_ids: Vec<String>,
+    pub payments_moved: i64,
+    pub reason: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub status: storage_enums::CustomerMergeStatus,
+    pub orphaned_locker_references: Vec<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum CustomerMergeUpdate {
+    /// A payment method was moved to the surviving customer
+    PaymentMethodMoved {
+        payment_method_ids: Vec<String>,
+        orphaned_locker_references: Vec<String>,
+    },
+    /// The mandates and payments were moved to the surviving customer, completing the merge
+    Completed {
+        mandate_ids: Vec<String>,
+        payments_moved: i64,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_merge)]
+pub struct CustomerMergeUpdateInternal {
+    payment_method_ids: Option<Vec<String>>,
+    mandate_ids: Option<Vec<String>>,
+    payments_moved: Option<i64>,
+    status: Option<storage_enums::CustomerMergeStatus>,
+    orphaned_locker_references: Option<Vec<String>>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<CustomerMergeUpdate> for CustomerMergeUpdateInternal {
+    fn from(customer_merge_update: CustomerMergeUpdate) -> Self {
+        match customer_merge_update {
+            CustomerMergeUpdate::PaymentMethodMoved {
+                payment_method_ids,
+                orphaned_locker_references,
+            } => Self {
+                payment_method_ids: Some(payment_method_ids),
+                mandate_ids: None,
+                payments_moved: None,
+                status: None,
+                orphaned_locker_references: Some(orphaned_locker_references),
+                modified_at: common_utils::date_time::now(),
+            },
+            CustomerMergeUpdate::Completed {
+                mandate_ids,
+                payments_moved,
+            } => Self {
+                payment_method_ids: None,
+                mandate_ids: Some(mandate_ids),
+                payments_moved: Some(payments_moved),
+                status: Some(storage_enums::CustomerMergeStatus::Completed),
+                orphaned_locker_references: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -1,15 +1,16 @@
+use common_types::disputes::DisputeFinancialRecords;
 use common_utils::{
     custom_serde,
     types::{MinorUnit, StringMinorUnit},
 };
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
 
-#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = dispute)]
 #[serde(deny_unknown_fields)]
 pub struct DisputeNew {
@@ -35,9 +36,10 @@ pub struct DisputeNew {
     pub dispute_amount: MinorUnit,
     pub organization_id: common_utils::id_type::OrganizationId,
     pub dispute_currency: Option<storage_enums::Currency>,
+    pub financial_records: Option<DisputeFinancialRecords>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable, Selectable)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Identifiable, Queryable, Selectable)]
 #[diesel(table_name = dispute, primary_key(dispute_id), check_for_backend(diesel::pg::Pg))]
 pub struct Dispute {
     pub dispute_id: String,
@@ -66,6 +68,15 @@ pub struct Dispute {
     pub dispute_amount: MinorUnit,
     pub organization_id: common_utils::id_type::OrganizationId,
     pub dispute_currency: Option<storage_enums::Currency>,
+    pub financial_records: Option<DisputeFinancialRecords>,
+}
+
+/// The fields of a dispute which are required for computing its financial impact
+#[derive(Clone, Debug, PartialEq, Queryable)]
+pub struct DisputeFinancialRecordsEntry {
+    pub connector: String,
+    pub connector_reason_code: Option<String>,
+    pub financial_records: Option<DisputeFinancialRecords>,
 }
 
 #[derive(Debug)]
@@ -78,6 +89,7 @@ pub enum DisputeUpdate {
         connector_reason_code: Option<String>,
         challenge_required_by: Option<PrimitiveDateTime>,
         connector_updated_at: Option<PrimitiveDateTime>,
+        financial_records: Option<DisputeFinancialRecords>,
     },
     StatusUpdate {
         dispute_status: storage_enums::DisputeStatus,
@@ -86,9 +98,12 @@ pub enum DisputeUpdate {
     EvidenceUpdate {
         evidence: Secret<serde_json::Value>,
     },
+    FinancialRecordsUpdate {
+        financial_records: DisputeFinancialRecords,
+    },
 }
 
-#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, AsChangeset, Serialize, Deserialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = dispute)]
 pub struct DisputeUpdateInternal {
     dispute_stage: Option<storage_enums::DisputeStage>,
@@ -100,6 +115,7 @@ pub struct DisputeUpdateInternal {
     connector_updated_at: Option<PrimitiveDateTime>,
     modified_at: PrimitiveDateTime,
     evidence: Option<Secret<serde_json::Value>>,
+    financial_records: Option<DisputeFinancialRecords>,
 }
 
 impl From<DisputeUpdate> for DisputeUpdateInternal {
@@ -113,6 +129,7 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 connector_reason_code,
                 challenge_required_by,
                 connector_updated_at,
+                financial_records,
             } => Self {
                 dispute_stage: Some(dispute_stage),
                 dispute_status: Some(dispute_status),
@@ -123,6 +140,7 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 connector_updated_at,
                 modified_at: common_utils::date_time::now(),
                 evidence: None,
+                financial_records,
             },
             DisputeUpdate::StatusUpdate {
                 dispute_status,
@@ -137,6 +155,7 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 challenge_required_by: None,
                 connector_updated_at: None,
                 evidence: None,
+                financial_records: None,
             },
             DisputeUpdate::EvidenceUpdate { evidence } => Self {
                 evidence: Some(evidence),
@@ -148,7 +167,88 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 challenge_required_by: None,
                 connector_updated_at: None,
                 modified_at: common_utils::date_time::now(),
+                financial_records: None,
+            },
+            DisputeUpdate::FinancialRecordsUpdate { financial_records } => Self {
+                financial_records: Some(financial_records),
+                dispute_stage: None,
+                dispute_status: None,
+                connector_status: None,
+                connector_reason: None,
+                connector_reason_code: None,
+                challenge_required_by: None,
+                connector_updated_at: None,
+                modified_at: common_utils::date_time::now(),
+                evidence: None,
             },
         }
     }
 }
+
+impl DisputeUpdateInternal {
+    pub fn apply_changeset(self, source: Dispute) -> Dispute {
+        let Self {
+            dispute_stage,
+            dispute_status,
+            connector_status,
+            connector_reason,
+            connector_reason_code,
+            challenge_required_by,
+            connector_updated_at,
+            modified_at,
+            evidence,
+            financial_records,
+        } = self;
+
+        Dispute {
+            dispute_stage: dispute_stage.unwrap_or(source.dispute_stage),
+            dispute_status: dispute_status.unwrap_or(source.dispute_status),
+            connector_status: connector_status.unwrap_or(source.connector_status),
+            connector_reason: connector_reason.or(source.connector_reason),
+            connector_reason_code: connector_reason_code.or(source.connector_reason_code),
+            challenge_required_by: challenge_required_by.or(source.challenge_required_by),
+            connector_updated_at: connector_updated_at.or(source.connector_updated_at),
+            modified_at,
+            evidence: evidence.unwrap_or(source.evidence),
+            financial_records: financial_records.or(source.financial_records),
+            ..source
+        }
+    }
+}
+
+impl From<&DisputeNew> for Dispute {
+    fn from(dispute_new: &DisputeNew) -> Self {
+        let now = common_utils::date_time::now();
+        Self {
+            dispute_id: dispute_new.dispute_id.clone(),
+            amount: dispute_new.amount.clone(),
+            currency: dispute_new.currency.clone(),
+            dispute_stage: dispute_new.dispute_stage,
+            dispute_status: dispute_new.dispute_status,
+            payment_id: dispute_new.payment_id.clone(),
+            attempt_id: dispute_new.attempt_id.clone(),
+            merchant_id: dispute_new.merchant_id.clone(),
+            connector_status: dispute_new.connector_status.clone(),
+            connector_dispute_id: dispute_new.connector_dispute_id.clone(),
+            connector_reason: dispute_new.connector_reason.clone(),
+            connector_reason_code: dispute_new.connector_reason_code.clone(),
+            challenge_required_by: dispute_new.challenge_required_by,
+            connector_created_at: dispute_new.connector_created_at,
+            connector_updated_at: dispute_new.connector_updated_at,
+            created_at: now,
+            modified_at: now,
+            connector: dispute_new.connector.clone(),
+            // Defaults to an empty object in the database
+            evidence: dispute_new
+                .evidence
+                .clone()
+                .unwrap_or_else(|| Secret::new(serde_json::json!({}))),
+            profile_id: dispute_new.profile_id.clone(),
+            merchant_connector_id: dispute_new.merchant_connector_id.clone(),
+            dispute_amount: dispute_new.dispute_amount,
+            organization_id: dispute_new.organization_id.clone(),
+            dispute_currency: dispute_new.dispute_currency,
+            financial_records: dispute_new.financial_records.clone(),
+        }
+    }
+}
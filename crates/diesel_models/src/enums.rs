@@ -81,6 +81,9 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    MetricAlertDetails,
+    SubscriptionDetails,
+    InvoiceDetails,
 }
 
 // Refund
@@ -102,6 +102,17 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    MetricAlert {
+        rule_id: String,
+        alert_id: String,
+    },
+    Subscription {
+        subscription_id: String,
+    },
+    Invoice {
+        subscription_id: String,
+        invoice_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
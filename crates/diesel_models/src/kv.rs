@@ -9,7 +9,10 @@ use crate::payment_intent::PaymentIntentUpdate;
 use crate::payment_intent::PaymentIntentUpdateInternal;
 use crate::{
     address::{Address, AddressNew, AddressUpdateInternal},
+    authentication::{Authentication, AuthenticationNew, AuthenticationUpdateInternal},
+    capture::{Capture, CaptureNew, CaptureUpdate},
     customers::{Customer, CustomerNew, CustomerUpdateInternal},
+    dispute::{Dispute, DisputeNew, DisputeUpdateInternal},
     errors,
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::PaymentIntentNew,
@@ -48,6 +51,9 @@ impl DBOperation {
                 Insertable::ReverseLookUp(_) => "reverse_lookup",
                 Insertable::PaymentMethod(_) => "payment_method",
                 Insertable::Mandate(_) => "mandate",
+                Insertable::Dispute(_) => "dispute",
+                Insertable::Capture(_) => "captures",
+                Insertable::Authentication(_) => "authentication",
             },
             Self::Update { updatable } => match **updatable {
                 Updateable::PaymentIntentUpdate(_) => "payment_intent",
@@ -59,6 +65,9 @@ impl DBOperation {
                 Updateable::PayoutAttemptUpdate(_) => "payout_attempt",
                 Updateable::PaymentMethodUpdate(_) => "payment_method",
                 Updateable::MandateUpdate(_) => " mandate",
+                Updateable::DisputeUpdate(_) => "dispute",
+                Updateable::CaptureUpdate(_) => "captures",
+                Updateable::AuthenticationUpdate(_) => "authentication",
             },
         }
     }
@@ -76,6 +85,9 @@ pub enum DBResult {
     PayoutAttempt(Box<PayoutAttempt>),
     PaymentMethod(Box<PaymentMethod>),
     Mandate(Box<Mandate>),
+    Dispute(Box<Dispute>),
+    Capture(Box<Capture>),
+    Authentication(Box<Authentication>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -110,6 +122,11 @@ impl DBOperation {
                     DBResult::PaymentMethod(Box::new(rev.insert(conn).await?))
                 }
                 Insertable::Mandate(m) => DBResult::Mandate(Box::new(m.insert(conn).await?)),
+                Insertable::Dispute(d) => DBResult::Dispute(Box::new(d.insert(conn).await?)),
+                Insertable::Capture(c) => DBResult::Capture(Box::new(c.insert(conn).await?)),
+                Insertable::Authentication(a) => {
+                    DBResult::Authentication(Box::new(a.insert(conn).await?))
+                }
             },
             Self::Update { updatable } => match *updatable {
                 #[cfg(feature = "v1")]
@@ -164,6 +181,21 @@ impl DBOperation {
                     )
                     .await?,
                 )),
+                Updateable::DisputeUpdate(d) => {
+                    DBResult::Dispute(Box::new(d.orig.update(conn, d.update_data).await?))
+                }
+                Updateable::CaptureUpdate(c) => DBResult::Capture(Box::new(
+                    c.orig.update_with_capture_id(conn, c.update_data).await?,
+                )),
+                Updateable::AuthenticationUpdate(a) => DBResult::Authentication(Box::new(
+                    Authentication::update_by_merchant_id_authentication_id(
+                        conn,
+                        a.orig.merchant_id,
+                        a.orig.authentication_id,
+                        a.update_data,
+                    )
+                    .await?,
+                )),
                 #[cfg(feature = "v1")]
                 Updateable::CustomerUpdate(cust) => DBResult::Customer(Box::new(
                     Customer::update_by_customer_id_merchant_id(
@@ -217,6 +249,9 @@ pub enum Insertable {
     PayoutAttempt(PayoutAttemptNew),
     PaymentMethod(PaymentMethodNew),
     Mandate(MandateNew),
+    Dispute(Box<DisputeNew>),
+    Capture(Box<CaptureNew>),
+    Authentication(Box<AuthenticationNew>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -231,6 +266,9 @@ pub enum Updateable {
     PayoutAttemptUpdate(PayoutAttemptUpdateMems),
     PaymentMethodUpdate(Box<PaymentMethodUpdateMems>),
     MandateUpdate(MandateUpdateMems),
+    DisputeUpdate(Box<DisputeUpdateMems>),
+    CaptureUpdate(Box<CaptureUpdateMems>),
+    AuthenticationUpdate(Box<AuthenticationUpdateMems>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -301,3 +339,21 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisputeUpdateMems {
+    pub orig: Dispute,
+    pub update_data: DisputeUpdateInternal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CaptureUpdateMems {
+    pub orig: Capture,
+    pub update_data: CaptureUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthenticationUpdateMems {
+    pub orig: Authentication,
+    pub update_data: AuthenticationUpdateInternal,
+}
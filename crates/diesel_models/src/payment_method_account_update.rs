@@ -0,0 +1,49 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_method_account_update};
+
+/// The result of checking a stored card with the card networks through an account updater
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = payment_method_account_update, primary_key(update_id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentMethodAccountUpdate {
+    pub update_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+    pub connector: String,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub status: storage_enums::CardAccountUpdateStatus,
+    pub previous_card_last4: Option<String>,
+    pub previous_card_exp_month: Option<String>,
+    pub previous_card_exp_year: Option<String>,
+    /// The card details after the update, if the card was reissued
+    pub updated_card_last4: Option<String>,
+    pub updated_card_exp_month: Option<String>,
+    pub updated_card_exp_year: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_account_update)]
+pub struct PaymentMethodAccountUpdateNew {
+    pub update_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+    pub connector: String,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub status: storage_enums::CardAccountUpdateStatus,
+    pub previous_card_last4: Option<String>,
+    pub previous_card_exp_month: Option<String>,
+    pub previous_card_exp_year: Option<String>,
+    pub updated_card_last4: Option<String>,
+    pub updated_card_exp_month: Option<String>,
+    pub updated_card_exp_year: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
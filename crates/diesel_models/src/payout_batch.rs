@@ -0,0 +1,136 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    Identifiable,
+    Queryable,
+    Selectable,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[diesel(table_name = payout_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: storage_enums::PayoutBatchStatus,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payout_batch_item, primary_key(item_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatchItem {
+    pub item_id: String,
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    /// Encrypted payout create request of the item, removed once the item is processed
+    pub request: Option<Encryption>,
+    pub merchant_order_reference_id: Option<String>,
+    pub payout_id: Option<common_utils::id_type::PayoutId>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub item_id: String,
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub request: Option<Encryption>,
+    pub merchant_order_reference_id: Option<String>,
+    pub payout_id: Option<common_utils::id_type::PayoutId>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchItemUpdate {
+    ResultUpdate {
+        status: storage_enums::PayoutBatchItemStatus,
+        payout_id: Option<common_utils::id_type::PayoutId>,
+        payout_status: Option<storage_enums::PayoutStatus>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub request: Option<Option<Encryption>>,
+    pub payout_id: Option<common_utils::id_type::PayoutId>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(item_update: PayoutBatchItemUpdate) -> Self {
+        match item_update {
+            PayoutBatchItemUpdate::ResultUpdate {
+                status,
+                payout_id,
+                payout_status,
+                error_code,
+                error_message,
+            } => Self {
+                status,
+                // The request holds payout method data, which is not retained after processing
+                request: Some(None),
+                payout_id,
+                payout_status,
+                error_code,
+                error_message,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -2,9 +2,7 @@ use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    authentication::{
-        Authentication, AuthenticationNew, AuthenticationUpdate, AuthenticationUpdateInternal,
-    },
+    authentication::{Authentication, AuthenticationNew, AuthenticationUpdateInternal},
     errors,
     schema::authentication::dsl,
     PgPooledConn, StorageResult,
@@ -21,7 +19,7 @@ impl Authentication {
         conn: &PgPooledConn,
         merchant_id: common_utils::id_type::MerchantId,
         authentication_id: common_utils::id_type::AuthenticationId,
-        authorization_update: AuthenticationUpdate,
+        authorization_update: impl Into<AuthenticationUpdateInternal>,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
@@ -33,7 +31,7 @@ impl Authentication {
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
                 .and(dsl::authentication_id.eq(authentication_id.to_owned())),
-            AuthenticationUpdateInternal::from(authorization_update),
+            authorization_update.into(),
         )
         .await
         {
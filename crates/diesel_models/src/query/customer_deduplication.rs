@@ -0,0 +1,173 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{self, db_metrics};
+use crate::{
+    customer_deduplication::{
+        CustomerBlindIndex, CustomerBlindIndexNew, CustomerMerge, CustomerMergeNew,
+        CustomerMergeUpdateInternal,
+    },
+    enums as storage_enums, errors,
+    schema::{customer_blind_index::dsl, customer_merge::dsl as merge_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerBlindIndexNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerBlindIndex> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerBlindIndex {
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            Some(dsl::field.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_field_hashed_value(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        field: storage_enums::CustomerBlindIndexField,
+        hashed_value: &str,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::field.eq(field))
+                .and(dsl::hashed_value.eq(hashed_value.to_owned())),
+            limit,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Lists the hashed values shared by more than one customer of the merchant, along with the
+    /// number of customers sharing each of them
+    pub async fn list_duplicates_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<(storage_enums::CustomerBlindIndexField, String, i64)>> {
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .group_by((dsl::field, dsl::hashed_value))
+            .having(diesel::dsl::count_star().gt(1))
+            .select((dsl::field, dsl::hashed_value, diesel::dsl::count_star()))
+            .order((dsl::field.asc(), dsl::hashed_value.asc()))
+            .limit(limit)
+            .offset(offset);
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(storage_enums::CustomerBlindIndexField, String, i64)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while listing duplicate customer blind indexes")
+    }
+
+    pub async fn delete_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl CustomerMergeNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerMerge> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerMerge {
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        customer_merge: CustomerMergeUpdateInternal,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            merge_dsl::merge_id.eq(self.merge_id.to_owned()),
+            customer_merge,
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    /// Finds the merge of the customer into another customer which is yet to be completed
+    pub async fn find_in_progress_by_merchant_id_merged_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merged_customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            merge_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(merge_dsl::merged_customer_id.eq(merged_customer_id.to_owned()))
+                .and(merge_dsl::status.eq(storage_enums::CustomerMergeStatus::InProgress)),
+        )
+        .await
+    }
+
+    /// Lists the merges in which the customer either survived or was merged into another customer
+    pub async fn list_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            merge_dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                merge_dsl::surviving_customer_id
+                    .eq(customer_id.to_owned())
+                    .or(merge_dsl::merged_customer_id.eq(customer_id.to_owned())),
+            ),
+            None,
+            None,
+            Some(merge_dsl::created_at.desc()),
+        )
+        .await
+    }
+}
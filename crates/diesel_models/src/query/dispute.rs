@@ -2,7 +2,7 @@ use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, T
 
 use super::generics;
 use crate::{
-    dispute::{Dispute, DisputeNew, DisputeUpdate, DisputeUpdateInternal},
+    dispute::{Dispute, DisputeNew, DisputeUpdateInternal},
     errors,
     schema::dispute::dsl,
     PgPooledConn, StorageResult,
@@ -67,7 +67,11 @@ impl Dispute {
         .await
     }
 
-    pub async fn update(self, conn: &PgPooledConn, dispute: DisputeUpdate) -> StorageResult<Self> {
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        dispute: impl Into<DisputeUpdateInternal>,
+    ) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
             _,
@@ -76,7 +80,7 @@ impl Dispute {
         >(
             conn,
             dsl::dispute_id.eq(self.dispute_id.to_owned()),
-            DisputeUpdateInternal::from(dispute),
+            dispute.into(),
         )
         .await
         {
@@ -175,6 +175,27 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_payment_method_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method: storage_enums::PaymentMethod,
+        status: storage_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(payment_method))
+                .and(dsl::status.eq(status)),
+            limit,
+            offset,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -199,6 +220,33 @@ impl PaymentMethod {
             result => result,
         }
     }
+
+    /// Moves the payment method to another customer, along with the references of its data which
+    /// was stored in the locker for the new customer
+    pub async fn update_customer_id_and_locker_ids_by_payment_method_id(
+        conn: &PgPooledConn,
+        payment_method_id: &str,
+        new_customer_id: &common_utils::id_type::CustomerId,
+        locker_id: Option<String>,
+        network_token_locker_id: Option<String>,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payment_method_id.eq(payment_method_id.to_owned()),
+            (
+                dsl::customer_id.eq(new_customer_id.to_owned()),
+                dsl::locker_id.eq(locker_id),
+                dsl::network_token_locker_id.eq(network_token_locker_id),
+                dsl::last_modified.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "v2")]
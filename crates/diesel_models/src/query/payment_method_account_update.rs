@@ -0,0 +1,34 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_method_account_update::{PaymentMethodAccountUpdate, PaymentMethodAccountUpdateNew},
+    schema::payment_method_account_update::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentMethodAccountUpdateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethodAccountUpdate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentMethodAccountUpdate {
+    pub async fn list_by_merchant_id_payment_method_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.eq(payment_method_id.to_owned())),
+            limit,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
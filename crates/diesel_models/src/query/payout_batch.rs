@@ -0,0 +1,159 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{self, db_metrics};
+use crate::{
+    enums as storage_enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+        PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdateInternal,
+    },
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_status(
+        self,
+        conn: &PgPooledConn,
+        status: storage_enums::PayoutBatchStatus,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::batch_id.eq(self.batch_id.to_owned()),
+            PayoutBatchUpdateInternal {
+                status,
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+    }
+}
+
+impl PayoutBatchItemNew {
+    pub async fn insert_batch(
+        conn: &PgPooledConn,
+        batch: Vec<Self>,
+    ) -> StorageResult<Vec<PayoutBatchItem>> {
+        let query = diesel::insert_into(<PayoutBatchItem>::table()).values(batch);
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<PayoutBatchItem as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while inserting payout batch items")
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn filter_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        status: Option<storage_enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = item_dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(item_dsl::batch_id.eq(batch_id.to_owned()));
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(item_dsl::status.eq(status)),
+                    limit,
+                    offset,
+                    Some(item_dsl::line_number.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    limit,
+                    offset,
+                    Some(item_dsl::line_number.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::item_id.eq(self.item_id.to_owned()),
+            PayoutBatchItemUpdateInternal::from(item_update),
+        )
+        .await
+    }
+
+    pub async fn get_status_with_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Vec<(storage_enums::PayoutBatchItemStatus, i64)>> {
+        let query = <Self as HasTable>::table()
+            .group_by(item_dsl::status)
+            .select((item_dsl::status, diesel::dsl::count_star()))
+            .filter(
+                item_dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(item_dsl::batch_id.eq(batch_id.to_owned())),
+            );
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(storage_enums::PayoutBatchItemStatus, i64)>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while counting payout batch items")
+    }
+}
@@ -1,8 +1,9 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
 
 use super::generics;
 use crate::{
-    errors,
+    enums, errors,
     relay::{Relay, RelayNew, RelayUpdateInternal},
     schema::relay::dsl,
     PgPooledConn, StorageResult,
@@ -60,4 +61,27 @@ impl Relay {
         )
         .await
     }
+
+    /// Latest capture, void, incremental authorization or sync relay made against the connector
+    /// resource
+    pub async fn find_latest_payment_relay_by_profile_id_connector_resource_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        connector_resource_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::connector_resource_id.eq(connector_resource_id.to_owned()))
+                .and(dsl::relay_type.ne(enums::RelayType::Refund)),
+            Some(1),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await?
+        .into_iter()
+        .nth(0)
+        .ok_or(report!(errors::DatabaseError::NotFound))
+    }
 }
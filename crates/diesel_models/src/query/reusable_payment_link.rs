@@ -0,0 +1,115 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    reusable_payment_link::{
+        ReusablePaymentLink, ReusablePaymentLinkNew, ReusablePaymentLinkUpdate,
+        ReusablePaymentLinkUpdateInternal,
+    },
+    schema::reusable_payment_link::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ReusablePaymentLinkNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReusablePaymentLink> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReusablePaymentLink {
+    pub async fn find_by_merchant_id_reusable_payment_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        link_update: ReusablePaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::reusable_payment_link_id.eq(self.reusable_payment_link_id.to_owned()),
+            ReusablePaymentLinkUpdateInternal::from(link_update),
+        )
+        .await
+    }
+
+    pub async fn increment_visit_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned())),
+            dsl::visit_count.eq(dsl::visit_count + 1),
+        )
+        .await
+    }
+
+    /// Counts a successful payment made through the link. The payment is counted even if the
+    /// link was deactivated, expired or reached its usage limit while it was being made.
+    pub async fn increment_usage_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+        used_at: PrimitiveDateTime,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned())),
+            (
+                dsl::usage_count.eq(dsl::usage_count + 1),
+                dsl::last_used_at.eq(Some(used_at)),
+                dsl::modified_at.eq(used_at),
+            ),
+        )
+        .await
+    }
+}
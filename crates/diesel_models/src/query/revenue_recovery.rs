@@ -0,0 +1,169 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    revenue_recovery::{
+        RevenueRecoveryAttempt, RevenueRecoveryAttemptNew, RevenueRecoveryAttemptUpdate,
+        RevenueRecoveryAttemptUpdateInternal, RevenueRecoveryEnrollment,
+        RevenueRecoveryEnrollmentNew, RevenueRecoveryEnrollmentUpdate,
+        RevenueRecoveryEnrollmentUpdateInternal,
+    },
+    schema::{revenue_recovery_attempt, revenue_recovery_enrollment},
+    PgPooledConn, StorageResult,
+};
+
+impl RevenueRecoveryEnrollmentNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RevenueRecoveryEnrollment> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RevenueRecoveryEnrollment {
+    pub async fn find_by_merchant_id_enrollment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            revenue_recovery_enrollment::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(revenue_recovery_enrollment::dsl::enrollment_id.eq(enrollment_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            revenue_recovery_enrollment::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(revenue_recovery_enrollment::dsl::payment_id.eq(payment_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<storage_enums::RecoveryEnrollmentStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    revenue_recovery_enrollment::dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(revenue_recovery_enrollment::dsl::status.eq(status)),
+                    limit,
+                    offset,
+                    Some(revenue_recovery_enrollment::dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    revenue_recovery_enrollment::dsl::merchant_id.eq(merchant_id.to_owned()),
+                    limit,
+                    offset,
+                    Some(revenue_recovery_enrollment::dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        enrollment_update: RevenueRecoveryEnrollmentUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            revenue_recovery_enrollment::dsl::enrollment_id.eq(self.enrollment_id.to_owned()),
+            RevenueRecoveryEnrollmentUpdateInternal::from(enrollment_update),
+        )
+        .await
+    }
+}
+
+impl RevenueRecoveryAttemptNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RevenueRecoveryAttempt> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RevenueRecoveryAttempt {
+    pub async fn list_by_merchant_id_enrollment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            revenue_recovery_attempt::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(revenue_recovery_attempt::dsl::enrollment_id.eq(enrollment_id.to_owned())),
+            None,
+            None,
+            Some(revenue_recovery_attempt::dsl::attempt_number.asc()),
+        )
+        .await
+    }
+
+    /// Lists the completed attempts of a merchant made after `attempted_after`, used for computing
+    /// the historical success rates of retries
+    pub async fn list_completed_by_merchant_id_attempted_after(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempted_after: PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            revenue_recovery_attempt::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(revenue_recovery_attempt::dsl::attempted_at.ge(attempted_after))
+                .and(
+                    revenue_recovery_attempt::dsl::status
+                        .ne(storage_enums::RecoveryAttemptStatus::Processing),
+                ),
+            limit,
+            None,
+            Some(revenue_recovery_attempt::dsl::attempted_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        attempt_update: RevenueRecoveryAttemptUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            revenue_recovery_attempt::dsl::attempt_id.eq(self.attempt_id.to_owned()),
+            RevenueRecoveryAttemptUpdateInternal::from(attempt_update),
+        )
+        .await
+    }
+}
@@ -0,0 +1,282 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::{subscription, subscription_invoice, subscription_price, subscription_product},
+    subscription::{
+        Subscription, SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+        SubscriptionInvoiceUpdateInternal, SubscriptionNew, SubscriptionPrice,
+        SubscriptionPriceNew, SubscriptionPriceUpdate, SubscriptionPriceUpdateInternal,
+        SubscriptionProduct, SubscriptionProductNew, SubscriptionProductUpdate,
+        SubscriptionProductUpdateInternal, SubscriptionUpdate, SubscriptionUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionProductNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionProduct> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionProduct {
+    pub async fn find_by_merchant_id_product_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            subscription_product::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription_product::dsl::product_id.eq(product_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            subscription_product::dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(subscription_product::dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        product_update: SubscriptionProductUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            subscription_product::dsl::product_id.eq(self.product_id.to_owned()),
+            SubscriptionProductUpdateInternal::from(product_update),
+        )
+        .await
+    }
+}
+
+impl SubscriptionPriceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPrice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPrice {
+    pub async fn find_by_merchant_id_price_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            subscription_price::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription_price::dsl::price_id.eq(price_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_product_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            subscription_price::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription_price::dsl::product_id.eq(product_id.to_owned())),
+            limit,
+            offset,
+            Some(subscription_price::dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        price_update: SubscriptionPriceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            subscription_price::dsl::price_id.eq(self.price_id.to_owned()),
+            SubscriptionPriceUpdateInternal::from(price_update),
+        )
+        .await
+    }
+}
+
+impl SubscriptionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Subscription> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Subscription {
+    pub async fn find_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            subscription::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription::dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            subscription::dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(subscription::dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            subscription::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription::dsl::customer_id.eq(customer_id.to_owned())),
+            limit,
+            offset,
+            Some(subscription::dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        subscription_update: SubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            subscription::dsl::subscription_id.eq(self.subscription_id.to_owned()),
+            SubscriptionUpdateInternal::from(subscription_update),
+        )
+        .await
+    }
+}
+
+impl SubscriptionInvoiceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionInvoice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionInvoice {
+    pub async fn find_by_merchant_id_invoice_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            subscription_invoice::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription_invoice::dsl::invoice_id.eq(invoice_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            subscription_invoice::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription_invoice::dsl::subscription_id.eq(subscription_id.to_owned())),
+            limit,
+            offset,
+            Some(subscription_invoice::dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn find_open_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            subscription_invoice::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription_invoice::dsl::subscription_id.eq(subscription_id.to_owned()))
+                .and(subscription_invoice::dsl::status.eq(storage_enums::InvoiceStatus::Open)),
+            None,
+            None,
+            Some(subscription_invoice::dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        invoice_update: SubscriptionInvoiceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            subscription_invoice::dsl::invoice_id.eq(self.invoice_id.to_owned()),
+            SubscriptionInvoiceUpdateInternal::from(invoice_update),
+        )
+        .await
+    }
+}
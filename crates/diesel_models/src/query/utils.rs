@@ -97,6 +97,14 @@ impl_get_primary_key!(
     schema::events::table,
     schema::merchant_account::table,
     schema::process_tracker::table,
+    schema::payout_batch_item::table,
+    schema::reusable_payment_link::table,
+    schema::revenue_recovery_attempt::table,
+    schema::revenue_recovery_enrollment::table,
+    schema::subscription::table,
+    schema::subscription_invoice::table,
+    schema::subscription_price::table,
+    schema::subscription_product::table,
     // v2 tables
     schema_v2::dashboard_metadata::table,
     schema_v2::merchant_connector_account::table,
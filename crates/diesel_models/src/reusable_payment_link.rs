@@ -0,0 +1,110 @@
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::reusable_payment_link};
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = reusable_payment_link, primary_key(reusable_payment_link_id), check_for_backend(diesel::pg::Pg))]
+pub struct ReusablePaymentLink {
+    pub reusable_payment_link_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub amount_type: storage_enums::ReusablePaymentLinkAmountType,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub link_to_pay: String,
+    pub is_active: bool,
+    pub usage_limit: Option<i32>,
+    /// Number of payments created through the link
+    pub usage_count: i32,
+    /// Number of times the link was opened
+    pub visit_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkNew {
+    pub reusable_payment_link_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub amount_type: storage_enums::ReusablePaymentLinkAmountType,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub link_to_pay: String,
+    pub is_active: bool,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
+    pub visit_count: i32,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReusablePaymentLinkUpdate {
+    Update {
+        is_active: Option<bool>,
+        description: Option<String>,
+        usage_limit: Option<i32>,
+        expires_at: Option<PrimitiveDateTime>,
+        payment_link_config: Option<serde_json::Value>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkUpdateInternal {
+    pub is_active: Option<bool>,
+    pub description: Option<String>,
+    pub usage_limit: Option<i32>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<ReusablePaymentLinkUpdate> for ReusablePaymentLinkUpdateInternal {
+    fn from(link_update: ReusablePaymentLinkUpdate) -> Self {
+        match link_update {
+            ReusablePaymentLinkUpdate::Update {
+                is_active,
+                description,
+                usage_limit,
+                expires_at,
+                payment_link_config,
+                metadata,
+            } => Self {
+                is_active,
+                description,
+                usage_limit,
+                expires_at,
+                payment_link_config,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
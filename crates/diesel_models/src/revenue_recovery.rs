@@ -0,0 +1,233 @@
+use common_enums::ErrorCategory;
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{revenue_recovery_attempt, revenue_recovery_enrollment},
+};
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = revenue_recovery_enrollment, primary_key(enrollment_id), check_for_backend(diesel::pg::Pg))]
+pub struct RevenueRecoveryEnrollment {
+    pub enrollment_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The failed payment which is being recovered
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub payment_method_id: String,
+    pub mandate_id: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::RecoveryEnrollmentStatus,
+    /// The decline category of the latest failure, as configured in the gateway status map
+    pub decline_category: Option<ErrorCategory>,
+    pub retry_count: i32,
+    pub max_retries: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+    /// No retries are scheduled after the end of the recovery window
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    pub last_error_code: Option<String>,
+    pub last_error_message: Option<String>,
+    pub recovered_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = revenue_recovery_enrollment)]
+pub struct RevenueRecoveryEnrollmentNew {
+    pub enrollment_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub payment_method_id: String,
+    pub mandate_id: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::RecoveryEnrollmentStatus,
+    pub decline_category: Option<ErrorCategory>,
+    pub retry_count: i32,
+    pub max_retries: i32,
+    pub next_retry_at: Option<PrimitiveDateTime>,
+    pub expires_at: PrimitiveDateTime,
+    pub last_error_code: Option<String>,
+    pub last_error_message: Option<String>,
+    pub recovered_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum RevenueRecoveryEnrollmentUpdate {
+    RetryScheduled {
+        retry_count: i32,
+        next_retry_at: Option<PrimitiveDateTime>,
+    },
+    RetryFailed {
+        status: storage_enums::RecoveryEnrollmentStatus,
+        retry_count: i32,
+        next_retry_at: Option<PrimitiveDateTime>,
+        decline_category: Option<ErrorCategory>,
+        last_error_code: Option<String>,
+        last_error_message: Option<String>,
+    },
+    Recovered {
+        retry_count: i32,
+        recovered_payment_id: common_utils::id_type::PaymentId,
+    },
+    StatusUpdate {
+        status: storage_enums::RecoveryEnrollmentStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = revenue_recovery_enrollment)]
+pub struct RevenueRecoveryEnrollmentUpdateInternal {
+    pub status: Option<storage_enums::RecoveryEnrollmentStatus>,
+    pub decline_category: Option<Option<ErrorCategory>>,
+    pub retry_count: Option<i32>,
+    pub next_retry_at: Option<Option<PrimitiveDateTime>>,
+    pub last_error_code: Option<Option<String>>,
+    pub last_error_message: Option<Option<String>>,
+    pub recovered_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<RevenueRecoveryEnrollmentUpdate> for RevenueRecoveryEnrollmentUpdateInternal {
+    fn from(enrollment_update: RevenueRecoveryEnrollmentUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match enrollment_update {
+            RevenueRecoveryEnrollmentUpdate::RetryScheduled {
+                retry_count,
+                next_retry_at,
+            } => Self {
+                retry_count: Some(retry_count),
+                next_retry_at: Some(next_retry_at),
+                modified_at,
+                ..Default::default()
+            },
+            RevenueRecoveryEnrollmentUpdate::RetryFailed {
+                status,
+                retry_count,
+                next_retry_at,
+                decline_category,
+                last_error_code,
+                last_error_message,
+            } => Self {
+                status: Some(status),
+                decline_category: Some(decline_category),
+                retry_count: Some(retry_count),
+                next_retry_at: Some(next_retry_at),
+                last_error_code: Some(last_error_code),
+                last_error_message: Some(last_error_message),
+                modified_at,
+                ..Default::default()
+            },
+            RevenueRecoveryEnrollmentUpdate::Recovered {
+                retry_count,
+                recovered_payment_id,
+            } => Self {
+                status: Some(storage_enums::RecoveryEnrollmentStatus::Recovered),
+                retry_count: Some(retry_count),
+                next_retry_at: Some(None),
+                recovered_payment_id: Some(recovered_payment_id),
+                modified_at,
+                ..Default::default()
+            },
+            RevenueRecoveryEnrollmentUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                next_retry_at: Some(None),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = revenue_recovery_attempt, primary_key(attempt_id), check_for_backend(diesel::pg::Pg))]
+pub struct RevenueRecoveryAttempt {
+    pub attempt_id: String,
+    pub enrollment_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The payment created for the retry, absent if the payment could not be created
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_number: i32,
+    pub status: storage_enums::RecoveryAttemptStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub decline_category: Option<ErrorCategory>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub attempted_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = revenue_recovery_attempt)]
+pub struct RevenueRecoveryAttemptNew {
+    pub attempt_id: String,
+    pub enrollment_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_number: i32,
+    pub status: storage_enums::RecoveryAttemptStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub decline_category: Option<ErrorCategory>,
+    pub attempted_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum RevenueRecoveryAttemptUpdate {
+    StatusUpdate {
+        status: storage_enums::RecoveryAttemptStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+        decline_category: Option<ErrorCategory>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = revenue_recovery_attempt)]
+pub struct RevenueRecoveryAttemptUpdateInternal {
+    pub status: Option<storage_enums::RecoveryAttemptStatus>,
+    pub error_code: Option<Option<String>>,
+    pub error_message: Option<Option<String>>,
+    pub decline_category: Option<Option<ErrorCategory>>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<RevenueRecoveryAttemptUpdate> for RevenueRecoveryAttemptUpdateInternal {
+    fn from(attempt_update: RevenueRecoveryAttemptUpdate) -> Self {
+        match attempt_update {
+            RevenueRecoveryAttemptUpdate::StatusUpdate {
+                status,
+                error_code,
+                error_message,
+                decline_category,
+            } => Self {
+                status: Some(status),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                decline_category: Some(decline_category),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
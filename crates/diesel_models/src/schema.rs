@@ -253,6 +253,8 @@ diesel::table! {
         #[max_length = 32]
         merchant_country_code -> Nullable<Varchar>,
         dispute_polling_interval -> Nullable<Int4>,
+        is_payout_auto_retries_enabled -> Nullable<Bool>,
+        max_payout_auto_retries_enabled -> Nullable<Int2>,
     }
 }
 
@@ -345,6 +347,49 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_blind_index (merchant_id, customer_id, field) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        field -> Varchar,
+        #[max_length = 64]
+        hashed_value -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_merge (merge_id) {
+        #[max_length = 64]
+        merge_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        surviving_customer_id -> Varchar,
+        #[max_length = 64]
+        merged_customer_id -> Varchar,
+        payment_method_ids -> Array<Nullable<Text>>,
+        mandate_ids -> Array<Nullable<Text>>,
+        payments_moved -> Int8,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        #[max_length = 32]
+        status -> Varchar,
+        orphaned_locker_references -> Array<Nullable<Text>>,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -442,6 +487,7 @@ diesel::table! {
         #[max_length = 32]
         organization_id -> Varchar,
         dispute_currency -> Nullable<Currency>,
+        financial_records -> Nullable<Jsonb>,
     }
 }
 
@@ -1119,6 +1165,42 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_account_update (update_id) {
+        #[max_length = 64]
+        update_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        merchant_connector_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 4]
+        previous_card_last4 -> Nullable<Varchar>,
+        #[max_length = 2]
+        previous_card_exp_month -> Nullable<Varchar>,
+        #[max_length = 4]
+        previous_card_exp_year -> Nullable<Varchar>,
+        #[max_length = 4]
+        updated_card_last4 -> Nullable<Varchar>,
+        #[max_length = 2]
+        updated_card_exp_month -> Nullable<Varchar>,
+        #[max_length = 4]
+        updated_card_exp_year -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1226,6 +1308,55 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        file_name -> Nullable<Varchar>,
+        total_count -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (item_id) {
+        #[max_length = 64]
+        item_id -> Varchar,
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 32]
+        status -> Varchar,
+        request -> Nullable<Bytea>,
+        #[max_length = 255]
+        merchant_order_reference_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        payout_status -> Nullable<PayoutStatus>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1392,6 +1523,106 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    reusable_payment_link (reusable_payment_link_id) {
+        #[max_length = 64]
+        reusable_payment_link_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        amount_type -> Varchar,
+        amount -> Nullable<Int8>,
+        min_amount -> Nullable<Int8>,
+        max_amount -> Nullable<Int8>,
+        currency -> Currency,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        payment_link_config -> Nullable<Jsonb>,
+        #[max_length = 255]
+        link_to_pay -> Varchar,
+        is_active -> Bool,
+        usage_limit -> Nullable<Int4>,
+        usage_count -> Int4,
+        visit_count -> Int4,
+        expires_at -> Nullable<Timestamp>,
+        last_used_at -> Nullable<Timestamp>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    revenue_recovery_attempt (attempt_id) {
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        enrollment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        attempt_number -> Int4,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        #[max_length = 64]
+        decline_category -> Nullable<Varchar>,
+        attempted_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    revenue_recovery_enrollment (enrollment_id) {
+        #[max_length = 64]
+        enrollment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        decline_category -> Nullable<Varchar>,
+        retry_count -> Int4,
+        max_retries -> Int4,
+        next_retry_at -> Nullable<Timestamp>,
+        expires_at -> Timestamp,
+        #[max_length = 255]
+        last_error_code -> Nullable<Varchar>,
+        last_error_message -> Nullable<Text>,
+        #[max_length = 64]
+        recovered_payment_id -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1465,6 +1696,123 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        price_id -> Varchar,
+        #[max_length = 64]
+        pending_price_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        quantity -> Int4,
+        billing_cycle_anchor -> Timestamp,
+        current_period_start -> Timestamp,
+        current_period_end -> Timestamp,
+        trial_start -> Nullable<Timestamp>,
+        trial_end -> Nullable<Timestamp>,
+        cancel_at_period_end -> Bool,
+        cancelled_at -> Nullable<Timestamp>,
+        pending_proration_amount -> Int8,
+        #[max_length = 64]
+        latest_invoice_id -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_invoice (invoice_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 32]
+        billing_reason -> Varchar,
+        amount -> Int8,
+        proration_amount -> Int8,
+        currency -> Currency,
+        period_start -> Timestamp,
+        period_end -> Timestamp,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        attempt_count -> Int4,
+        next_payment_attempt_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        paid_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_price (price_id) {
+        #[max_length = 64]
+        price_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        product_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 16]
+        billing_interval -> Varchar,
+        interval_count -> Int4,
+        trial_period_days -> Nullable<Int4>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_product (product_id) {
+        #[max_length = 64]
+        product_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1622,6 +1970,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    customer_blind_index,
+    customer_merge,
     customers,
     dashboard_metadata,
     dispute,
@@ -1641,15 +1991,25 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_link,
+    payment_method_account_update,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
     refund,
     relay,
+    reusable_payment_link,
+    revenue_recovery_attempt,
+    revenue_recovery_enrollment,
     reverse_lookup,
     roles,
     routing_algorithm,
+    subscription,
+    subscription_invoice,
+    subscription_price,
+    subscription_product,
     themes,
     unified_translations,
     user_authentication_methods,
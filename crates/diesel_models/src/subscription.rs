@@ -0,0 +1,449 @@
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{subscription, subscription_invoice, subscription_price, subscription_product},
+};
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = subscription_product, primary_key(product_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionProduct {
+    pub product_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_product)]
+pub struct SubscriptionProductNew {
+    pub product_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionProductUpdate {
+    Update {
+        name: Option<String>,
+        description: Option<String>,
+        is_active: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_product)]
+pub struct SubscriptionProductUpdateInternal {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub is_active: Option<bool>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionProductUpdate> for SubscriptionProductUpdateInternal {
+    fn from(product_update: SubscriptionProductUpdate) -> Self {
+        match product_update {
+            SubscriptionProductUpdate::Update {
+                name,
+                description,
+                is_active,
+                metadata,
+            } => Self {
+                name,
+                description,
+                is_active,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = subscription_price, primary_key(price_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionPrice {
+    pub price_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub product_id: String,
+    /// Amount charged per unit for each billing period
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::BillingInterval,
+    /// Number of intervals in a billing period
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_price)]
+pub struct SubscriptionPriceNew {
+    pub price_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub product_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::BillingInterval,
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The amount and billing period of a price cannot be changed, a new price has to be created instead
+#[derive(Debug)]
+pub enum SubscriptionPriceUpdate {
+    Update {
+        is_active: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_price)]
+pub struct SubscriptionPriceUpdateInternal {
+    pub is_active: Option<bool>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionPriceUpdate> for SubscriptionPriceUpdateInternal {
+    fn from(price_update: SubscriptionPriceUpdate) -> Self {
+        match price_update {
+            SubscriptionPriceUpdate::Update {
+                is_active,
+                metadata,
+            } => Self {
+                is_active,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = subscription, primary_key(subscription_id), check_for_backend(diesel::pg::Pg))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub price_id: String,
+    /// The price which replaces `price_id` from the next billing period
+    pub pending_price_id: Option<String>,
+    pub payment_method_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub quantity: i32,
+    /// The start of the first paid billing period, from which the billing periods are computed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub billing_cycle_anchor: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_start: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    /// Prorations to be added to the next invoice, negative for credits
+    pub pending_proration_amount: MinorUnit,
+    pub latest_invoice_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub price_id: String,
+    pub pending_price_id: Option<String>,
+    pub payment_method_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub quantity: i32,
+    pub billing_cycle_anchor: PrimitiveDateTime,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_start: Option<PrimitiveDateTime>,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub pending_proration_amount: MinorUnit,
+    pub latest_invoice_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    Update {
+        payment_method_id: Option<String>,
+        cancel_at_period_end: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    PriceUpdate {
+        price_id: String,
+        pending_price_id: Option<String>,
+        quantity: i32,
+        pending_proration_amount: MinorUnit,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+        latest_invoice_id: Option<String>,
+        pending_proration_amount: Option<MinorUnit>,
+    },
+    PeriodUpdate {
+        status: storage_enums::SubscriptionStatus,
+        price_id: String,
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        pending_proration_amount: MinorUnit,
+        latest_invoice_id: Option<String>,
+    },
+    Cancel {
+        cancelled_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    pub price_id: Option<String>,
+    pub pending_price_id: Option<Option<String>>,
+    pub payment_method_id: Option<String>,
+    pub status: Option<storage_enums::SubscriptionStatus>,
+    pub quantity: Option<i32>,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: Option<bool>,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub pending_proration_amount: Option<MinorUnit>,
+    pub latest_invoice_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match subscription_update {
+            SubscriptionUpdate::Update {
+                payment_method_id,
+                cancel_at_period_end,
+                metadata,
+            } => Self {
+                payment_method_id,
+                cancel_at_period_end,
+                metadata,
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PriceUpdate {
+                price_id,
+                pending_price_id,
+                quantity,
+                pending_proration_amount,
+            } => Self {
+                price_id: Some(price_id),
+                pending_price_id: Some(pending_price_id),
+                quantity: Some(quantity),
+                pending_proration_amount: Some(pending_proration_amount),
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::StatusUpdate {
+                status,
+                latest_invoice_id,
+                pending_proration_amount,
+            } => Self {
+                status: Some(status),
+                latest_invoice_id,
+                pending_proration_amount,
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PeriodUpdate {
+                status,
+                price_id,
+                current_period_start,
+                current_period_end,
+                pending_proration_amount,
+                latest_invoice_id,
+            } => Self {
+                status: Some(status),
+                price_id: Some(price_id),
+                pending_price_id: Some(None),
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                pending_proration_amount: Some(pending_proration_amount),
+                latest_invoice_id,
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::Cancel { cancelled_at } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Cancelled),
+                cancelled_at: Some(cancelled_at),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = subscription_invoice, primary_key(invoice_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionInvoice {
+    pub invoice_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub subscription_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub status: storage_enums::InvoiceStatus,
+    pub billing_reason: storage_enums::InvoiceBillingReason,
+    /// Total amount to be charged, including `proration_amount`
+    pub amount: MinorUnit,
+    pub proration_amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    /// The payment of the latest attempt to charge the invoice
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_payment_attempt_at: Option<PrimitiveDateTime>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub paid_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceNew {
+    pub invoice_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub subscription_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub status: storage_enums::InvoiceStatus,
+    pub billing_reason: storage_enums::InvoiceBillingReason,
+    pub amount: MinorUnit,
+    pub proration_amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: i32,
+    pub next_payment_attempt_at: Option<PrimitiveDateTime>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionInvoiceUpdate {
+    PaymentUpdate {
+        status: storage_enums::InvoiceStatus,
+        payment_id: Option<common_utils::id_type::PaymentId>,
+        attempt_count: i32,
+        next_payment_attempt_at: Option<PrimitiveDateTime>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+        paid_at: Option<PrimitiveDateTime>,
+    },
+    StatusUpdate {
+        status: storage_enums::InvoiceStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceUpdateInternal {
+    pub status: Option<storage_enums::InvoiceStatus>,
+    pub payment_id: Option<Option<common_utils::id_type::PaymentId>>,
+    pub attempt_count: Option<i32>,
+    pub next_payment_attempt_at: Option<Option<PrimitiveDateTime>>,
+    pub error_code: Option<Option<String>>,
+    pub error_message: Option<Option<String>>,
+    pub paid_at: Option<Option<PrimitiveDateTime>>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionInvoiceUpdate> for SubscriptionInvoiceUpdateInternal {
+    fn from(invoice_update: SubscriptionInvoiceUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match invoice_update {
+            SubscriptionInvoiceUpdate::PaymentUpdate {
+                status,
+                payment_id,
+                attempt_count,
+                next_payment_attempt_at,
+                error_code,
+                error_message,
+                paid_at,
+            } => Self {
+                status: Some(status),
+                payment_id: Some(payment_id),
+                attempt_count: Some(attempt_count),
+                next_payment_attempt_at: Some(next_payment_attempt_at),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                paid_at: Some(paid_at),
+                modified_at,
+            },
+            SubscriptionInvoiceUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                next_payment_attempt_at: Some(None),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -25,6 +25,34 @@ impl SecretsHandler for Database {
     }
 }
 
+/// Decrypts the Redis ACL and Sentinel passwords. `RedisSettings` is defined in `redis_interface`,
+/// hence `SecretsHandler` cannot be implemented for it here.
+async fn convert_redis_to_raw_secret(
+    redis: redis_interface::RedisSettings,
+    secret_management_client: &dyn SecretManagementInterface,
+) -> CustomResult<redis_interface::RedisSettings, SecretsManagementError> {
+    let password = match redis.password {
+        Some(password) => Some(secret_management_client.get_secret(password).await?),
+        None => None,
+    };
+    let sentinel = match redis.sentinel {
+        Some(sentinel) => Some(redis_interface::RedisSentinelSettings {
+            password: match sentinel.password {
+                Some(password) => Some(secret_management_client.get_secret(password).await?),
+                None => None,
+            },
+            ..sentinel
+        }),
+        None => None,
+    };
+
+    Ok(redis_interface::RedisSettings {
+        password,
+        sentinel,
+        ..redis
+    })
+}
+
 /// # Panics
 ///
 /// Will panic even if fetching raw secret fails for at least one config value
@@ -37,10 +65,15 @@ pub async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt database password");
 
+    #[allow(clippy::expect_used)]
+    let redis = convert_redis_to_raw_secret(conf.redis, secret_management_client)
+        .await
+        .expect("Failed to decrypt redis configs");
+
     Settings {
         server: conf.server,
         master_database: database,
-        redis: conf.redis,
+        redis,
         log: conf.log,
         drainer: conf.drainer,
         encryption_management: conf.encryption_management,
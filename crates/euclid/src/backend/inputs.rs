@@ -52,6 +52,13 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutDataInput {
+    #[cfg(feature = "payouts")]
+    pub payout_type: Option<enums::PayoutType>,
+    pub destination_country: Option<enums::Country>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -61,4 +68,5 @@ pub struct BackendInput {
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
+    pub payout_data: Option<PayoutDataInput>,
 }
@@ -156,6 +156,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -199,6 +200,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -243,6 +245,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -287,6 +290,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -331,6 +335,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -375,6 +380,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -419,6 +425,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -463,6 +470,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -507,6 +515,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -553,6 +562,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -597,6 +607,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -650,6 +661,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout_data: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -665,4 +677,72 @@ mod test {
             "rule_1"
         );
     }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_type_and_destination_country() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           payout_type = bank_transfer & payout_destination_country = Germany
+        }
+
+        rule_2: ["adyen"]
+        {
+           payout_type = card
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_bank = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::EUR,
+                authentication_type: None,
+                capture_method: None,
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::BankTransfer),
+                payment_method_type: Some(enums::PaymentMethodType::Sepa),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            payout_data: Some(inputs::PayoutDataInput {
+                payout_type: Some(enums::PayoutType::BankTransfer),
+                destination_country: Some(enums::Country::Germany),
+            }),
+        };
+        let mut inp_other_country = inp_bank.clone();
+        inp_other_country.payout_data = Some(inputs::PayoutDataInput {
+            payout_type: Some(enums::PayoutType::BankTransfer),
+            destination_country: Some(enums::Country::France),
+        });
+        let mut inp_card = inp_bank.clone();
+        inp_card.payout_data = Some(inputs::PayoutDataInput {
+            payout_type: Some(enums::PayoutType::Card),
+            destination_country: None,
+        });
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_bank = backend.execute(inp_bank).expect("Execution");
+        let result_other_country = backend.execute(inp_other_country).expect("Execution");
+        let result_card = backend.execute(inp_card).expect("Execution");
+        assert_eq!(result_bank.rule_name.expect("Rule Name").as_str(), "rule_1");
+        assert!(result_other_country.rule_name.is_none());
+        assert_eq!(result_card.rule_name.expect("Rule Name").as_str(), "rule_2");
+    }
 }
@@ -58,6 +58,7 @@ impl Context {
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
         let payment_mandate = input.mandate;
+        let payout_data = input.payout_data;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -143,6 +144,17 @@ impl Context {
             }
         }
 
+        // Handle payout data
+        if let Some(payout) = payout_data {
+            #[cfg(feature = "payouts")]
+            if let Some(payout_type) = payout.payout_type {
+                enum_values.insert(EuclidValue::PayoutType(payout_type));
+            }
+            if let Some(country) = payout.destination_country {
+                enum_values.insert(EuclidValue::PayoutDestinationCountry(country));
+            }
+        }
+
         let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
@@ -82,6 +82,9 @@ impl cgraph::NodeViz for dir::DirValue {
             }
             Self::AcquirerCountry(acquirer_country) => acquirer_country.to_string(),
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
+            #[cfg(feature = "payouts")]
+            Self::PayoutType(payout_type) => payout_type.to_string(),
+            Self::PayoutDestinationCountry(country) => country.to_string(),
         }
     }
 }
@@ -275,6 +275,9 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        #[cfg(feature = "payouts")]
+        dir::DirKeyKind::PayoutType => lower_enum!(PayoutType, value),
+        dir::DirKeyKind::PayoutDestinationCountry => lower_enum!(PayoutDestinationCountry, value),
     }
 }
 
@@ -334,6 +334,21 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[cfg(feature = "payouts")]
+    #[strum(
+        serialize = "payout_type",
+        detailed_message = "Type of the payout - eg. card, bank transfer, wallet",
+        props(Category = "Payouts")
+    )]
+    #[serde(rename = "payout_type")]
+    PayoutType,
+    #[strum(
+        serialize = "payout_destination_country",
+        detailed_message = "Country to which the payout is sent",
+        props(Category = "Payouts")
+    )]
+    #[serde(rename = "payout_destination_country")]
+    PayoutDestinationCountry,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -391,6 +406,9 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            #[cfg(feature = "payouts")]
+            Self::PayoutType => types::DataType::EnumVariant,
+            Self::PayoutDestinationCountry => types::DataType::EnumVariant,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -555,6 +573,17 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            #[cfg(feature = "payouts")]
+            Self::PayoutType => Some(
+                enums::PayoutType::iter()
+                    .map(DirValue::PayoutType)
+                    .collect(),
+            ),
+            Self::PayoutDestinationCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::PayoutDestinationCountry)
+                    .collect(),
+            ),
         }
     }
 }
@@ -640,6 +669,11 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "payout_type")]
+    PayoutType(enums::PayoutType),
+    #[serde(rename = "payout_destination_country")]
+    PayoutDestinationCountry(enums::Country),
 }
 
 impl DirValue {
@@ -683,6 +717,9 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            #[cfg(feature = "payouts")]
+            Self::PayoutType(_) => (DirKeyKind::PayoutType, None),
+            Self::PayoutDestinationCountry(_) => (DirKeyKind::PayoutDestinationCountry, None),
         };
 
         DirKey::new(kind, data)
@@ -727,6 +764,9 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            #[cfg(feature = "payouts")]
+            Self::PayoutType(_) => None,
+            Self::PayoutDestinationCountry(_) => None,
         }
     }
 
@@ -782,6 +822,9 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            #[cfg(feature = "payouts")]
+            (Self::PayoutType(pt1), Self::PayoutType(pt2)) => pt1 == pt2,
+            (Self::PayoutDestinationCountry(c1), Self::PayoutDestinationCountry(c2)) => c1 == c2,
             _ => false,
         }
     }
@@ -844,6 +887,14 @@ pub enum PayoutDirKeyKind {
     #[serde(rename = "payment_method")]
     PayoutType,
 
+    #[strum(
+        serialize = "payout_destination_country",
+        detailed_message = "Country to which the payout is sent",
+        props(Category = "Payout Methods")
+    )]
+    #[serde(rename = "payout_destination_country")]
+    PayoutDestinationCountry,
+
     #[strum(
         serialize = "wallet",
         detailed_message = "Supported types of Wallets for payouts",
@@ -285,6 +285,11 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         }
         dir::DirValue::AcquirerCountry(country) => EuclidValue::AcquirerCountry(country),
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
+        #[cfg(feature = "payouts")]
+        dir::DirValue::PayoutType(payout_type) => EuclidValue::PayoutType(payout_type),
+        dir::DirValue::PayoutDestinationCountry(country) => {
+            EuclidValue::PayoutDestinationCountry(country)
+        }
     })
 }
 
@@ -77,6 +77,11 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[cfg(feature = "payouts")]
+    #[strum(serialize = "payout_type")]
+    PayoutType,
+    #[strum(serialize = "payout_destination_country")]
+    PayoutDestinationCountry,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -163,6 +168,9 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            #[cfg(feature = "payouts")]
+            Self::PayoutType => DataType::EnumVariant,
+            Self::PayoutDestinationCountry => DataType::EnumVariant,
         }
     }
 }
@@ -285,6 +293,9 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    #[cfg(feature = "payouts")]
+    PayoutType(enums::PayoutType),
+    PayoutDestinationCountry(enums::Country),
 }
 
 impl EuclidValue {
@@ -320,6 +331,9 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            #[cfg(feature = "payouts")]
+            Self::PayoutType(_) => EuclidKey::PayoutType,
+            Self::PayoutDestinationCountry(_) => EuclidKey::PayoutDestinationCountry,
         }
     }
 }
@@ -1902,6 +1902,7 @@ impl IncomingWebhook for Adyen {
             connector_status: notif.event_code.to_string(),
             created_at: notif.event_date,
             updated_at: notif.event_date,
+            financial_records: Vec::new(),
         })
     }
 
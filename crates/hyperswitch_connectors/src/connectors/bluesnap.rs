@@ -1192,6 +1192,7 @@ impl IncomingWebhook for Bluesnap {
             connector_status: dispute_details.cb_status,
             created_at: None,
             updated_at: None,
+            financial_records: Vec::new(),
         })
     }
 
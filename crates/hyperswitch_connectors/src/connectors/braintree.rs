@@ -1057,6 +1057,7 @@ impl IncomingWebhook for Braintree {
                 connector_status: dispute_data.status,
                 created_at: dispute_data.created_at,
                 updated_at: dispute_data.updated_at,
+                financial_records: Vec::new(),
             }),
             None => Err(errors::ConnectorError::WebhookResourceObjectNotFound)?,
         }
@@ -985,6 +985,7 @@ impl webhooks::IncomingWebhook for Novalnet {
             connector_status: dispute_status,
             created_at: None,
             updated_at: None,
+            financial_records: Vec::new(),
         })
     }
 }
@@ -1263,6 +1263,7 @@ impl webhooks::IncomingWebhook for Payme {
             connector_status: webhook_object.sale_status.to_string(),
             created_at: None,
             updated_at: None,
+            financial_records: Vec::new(),
         })
     }
 }
@@ -2199,6 +2199,7 @@ impl IncomingWebhook for Paypal {
                     challenge_required_by: None,
                     created_at: payload.create_time,
                     updated_at: payload.update_time,
+                    financial_records: Vec::new(),
                 })
             }
         }
@@ -2503,6 +2503,9 @@ impl IncomingWebhook for Stripe {
                 .to_string(),
             created_at: Some(details.event_data.event_object.created),
             updated_at: None,
+            financial_records: stripe::get_dispute_financial_records(
+                details.event_data.event_object.balance_transactions,
+            ),
         })
     }
 }
@@ -3,6 +3,7 @@ use std::{collections::HashMap, fmt::Debug, ops::Deref};
 use api_models::{self, enums as api_enums, payments};
 use common_enums::{enums, AttemptStatus, PaymentChargeType, StripeChargeType};
 use common_types::{
+    disputes::{DisputeFinancialRecord, DisputeFinancialRecordSource, DisputeFinancialRecordType},
     payments::{AcceptanceType, SplitPaymentsRequest},
     primitive_wrappers,
 };
@@ -4139,6 +4140,7 @@ pub struct WebhookEventObjectData {
     pub status: Option<WebhookEventStatus>,
     pub metadata: Option<StripeMetadata>,
     pub last_payment_error: Option<ErrorDetails>,
+    pub balance_transactions: Option<Vec<StripeDisputeBalanceTransaction>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, strum::Display)]
@@ -4233,6 +4235,57 @@ pub struct EvidenceDetails {
     pub due_by: PrimitiveDateTime,
 }
 
+/// Balance transaction attached to a dispute, describing the funds withdrawn or
+/// reinstated and the dispute fee charged by Stripe
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StripeDisputeBalanceTransaction {
+    pub id: String,
+    /// Negative when funds are withdrawn, positive when they are reinstated
+    pub amount: MinorUnit,
+    #[serde(deserialize_with = "convert_uppercase")]
+    pub currency: enums::Currency,
+    /// Negative when the dispute fee is returned
+    pub fee: MinorUnit,
+    #[serde(with = "common_utils::custom_serde::timestamp")]
+    pub created: PrimitiveDateTime,
+}
+
+pub fn get_dispute_financial_records(
+    balance_transactions: Option<Vec<StripeDisputeBalanceTransaction>>,
+) -> Vec<DisputeFinancialRecord> {
+    balance_transactions
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|transaction| {
+            let amount = transaction.amount.get_amount_as_i64();
+            let fee = transaction.fee.get_amount_as_i64();
+            let amount_record_type = if amount < 0 {
+                DisputeFinancialRecordType::AmountDebited
+            } else {
+                DisputeFinancialRecordType::AmountReinstated
+            };
+            let fee_record_type = if fee < 0 {
+                DisputeFinancialRecordType::FeeRefunded
+            } else {
+                DisputeFinancialRecordType::ChargebackFee
+            };
+            [(amount_record_type, amount), (fee_record_type, fee)]
+                .into_iter()
+                .filter(|(_, value)| *value != 0)
+                .map(|(record_type, value)| DisputeFinancialRecord {
+                    record_type,
+                    amount: MinorUnit::new(value.abs()),
+                    currency: transaction.currency,
+                    source: DisputeFinancialRecordSource::Connector,
+                    reference_id: Some(transaction.id.clone()),
+                    description: None,
+                    created_at: transaction.created,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 impl
     TryFrom<(
         &SetupMandateRouterData,
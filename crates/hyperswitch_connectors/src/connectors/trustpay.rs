@@ -1077,6 +1077,7 @@ impl webhooks::IncomingWebhook for Trustpay {
             connector_status: payment_info.status.to_string(),
             created_at: None,
             updated_at: None,
+            financial_records: Vec::new(),
         })
     }
 }
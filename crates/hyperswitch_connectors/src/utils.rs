@@ -5415,6 +5415,37 @@ impl QrImage {
         }
         Err(common_utils::errors::QrCodeError::InvalidHexColor)
     }
+
+    /// Renders the data as a QR code and returns the raw bytes of the PNG image
+    pub fn png_bytes_from_data(
+        data: &str,
+    ) -> Result<Vec<u8>, error_stack::Report<common_utils::errors::QrCodeError>> {
+        let qr_code = qrcode::QrCode::new(data.as_bytes())
+            .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
+
+        let qrcode_image_buffer = qr_code.render::<Luma<u8>>().build();
+        let qrcode_dynamic_image = DynamicImage::ImageLuma8(qrcode_image_buffer);
+
+        let mut image_bytes = std::io::Cursor::new(Vec::new());
+        qrcode_dynamic_image
+            .write_to(&mut image_bytes, ImageFormat::Png)
+            .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
+
+        Ok(image_bytes.into_inner())
+    }
+
+    /// Renders the data as a QR code and returns the SVG document of the image
+    pub fn svg_from_data(
+        data: &str,
+    ) -> Result<String, error_stack::Report<common_utils::errors::QrCodeError>> {
+        let qr_code = qrcode::QrCode::new(data.as_bytes())
+            .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
+
+        Ok(qr_code
+            .render::<qrcode::render::svg::Color<'_>>()
+            .min_dimensions(200, 200)
+            .build())
+    }
 }
 
 #[cfg(test)]
@@ -5425,6 +5456,15 @@ mod tests {
         let qr_image_data_source_url = utils::QrImage::new_from_data("Hyperswitch".to_string());
         assert!(qr_image_data_source_url.is_ok());
     }
+
+    #[test]
+    fn test_qr_image_png_and_svg() {
+        let png_bytes = utils::QrImage::png_bytes_from_data("Hyperswitch");
+        assert!(png_bytes.is_ok_and(|bytes| bytes.starts_with(b"\x89PNG")));
+
+        let svg = utils::QrImage::svg_from_data("Hyperswitch");
+        assert!(svg.is_ok_and(|svg| svg.contains("<svg")));
+    }
 }
 
 pub fn is_mandate_supported(
@@ -6375,6 +6415,7 @@ pub(crate) fn convert_payment_authorize_router_response<F1, F2, T1, T2>(
         psd2_sca_exemption_type: data.psd2_sca_exemption_type,
         raw_connector_response: data.raw_connector_response.clone(),
         is_payment_id_from_merchant: data.is_payment_id_from_merchant,
+        merchant_connector_id: data.merchant_connector_id.clone(),
         l2_l3_data: data.l2_l3_data.clone(),
         minor_amount_capturable: data.minor_amount_capturable,
     }
@@ -84,6 +84,8 @@ pub struct Profile {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub is_payout_auto_retries_enabled: bool,
+    pub max_payout_auto_retries_enabled: Option<i16>,
 }
 
 #[cfg(feature = "v1")]
@@ -140,6 +142,8 @@ pub struct ProfileSetter {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub is_payout_auto_retries_enabled: bool,
+    pub max_payout_auto_retries_enabled: Option<i16>,
 }
 
 #[cfg(feature = "v1")]
@@ -203,6 +207,8 @@ impl From<ProfileSetter> for Profile {
             merchant_category_code: value.merchant_category_code,
             merchant_country_code: value.merchant_country_code,
             dispute_polling_interval: value.dispute_polling_interval,
+            is_payout_auto_retries_enabled: value.is_payout_auto_retries_enabled,
+            max_payout_auto_retries_enabled: value.max_payout_auto_retries_enabled,
         }
     }
 }
@@ -266,6 +272,8 @@ pub struct ProfileGeneralUpdate {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
 }
 
 #[cfg(feature = "v1")]
@@ -348,6 +356,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_category_code,
                     merchant_country_code,
                     dispute_polling_interval,
+                    is_payout_auto_retries_enabled,
+                    max_payout_auto_retries_enabled,
                 } = *update;
 
                 Self {
@@ -401,6 +411,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_category_code,
                     merchant_country_code,
                     dispute_polling_interval,
+                    is_payout_auto_retries_enabled,
+                    max_payout_auto_retries_enabled,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -457,6 +469,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -510,6 +524,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -563,6 +579,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -616,6 +634,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -669,6 +689,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -722,6 +744,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
             },
             ProfileUpdate::AcquirerConfigMapUpdate {
                 acquirer_config_map,
@@ -775,6 +799,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 dispute_polling_interval: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
             },
         }
     }
@@ -848,6 +874,8 @@ impl super::behaviour::Conversion for Profile {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
+            is_payout_auto_retries_enabled: Some(self.is_payout_auto_retries_enabled),
+            max_payout_auto_retries_enabled: self.max_payout_auto_retries_enabled,
         })
     }
 
@@ -947,6 +975,10 @@ impl super::behaviour::Conversion for Profile {
                 merchant_category_code: item.merchant_category_code,
                 merchant_country_code: item.merchant_country_code,
                 dispute_polling_interval: item.dispute_polling_interval,
+                is_payout_auto_retries_enabled: item
+                    .is_payout_auto_retries_enabled
+                    .unwrap_or(false),
+                max_payout_auto_retries_enabled: item.max_payout_auto_retries_enabled,
             })
         }
         .await
@@ -1013,6 +1045,8 @@ impl super::behaviour::Conversion for Profile {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
+            is_payout_auto_retries_enabled: Some(self.is_payout_auto_retries_enabled),
+            max_payout_auto_retries_enabled: self.max_payout_auto_retries_enabled,
         })
     }
 }
@@ -1075,6 +1109,7 @@ pub struct Profile {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub split_txns_enabled: common_enums::SplitTxnsEnabled,
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v2")]
@@ -1133,6 +1168,7 @@ pub struct ProfileSetter {
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub split_txns_enabled: common_enums::SplitTxnsEnabled,
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v2")]
@@ -1196,6 +1232,7 @@ impl From<ProfileSetter> for Profile {
             merchant_category_code: value.merchant_category_code,
             merchant_country_code: value.merchant_country_code,
             split_txns_enabled: value.split_txns_enabled,
+            allowed_proxy_destination_hosts: value.allowed_proxy_destination_hosts,
         }
     }
 }
@@ -1384,6 +1421,7 @@ pub struct ProfileGeneralUpdate {
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub revenue_recovery_retry_algorithm_type: Option<common_enums::RevenueRecoveryAlgorithmType>,
     pub split_txns_enabled: Option<common_enums::SplitTxnsEnabled>,
+    pub allowed_proxy_destination_hosts: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v2")]
@@ -1466,6 +1504,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_country_code,
                     revenue_recovery_retry_algorithm_type,
                     split_txns_enabled,
+                    allowed_proxy_destination_hosts,
                 } = *update;
                 Self {
                     profile_name,
@@ -1520,6 +1559,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_category_code,
                     merchant_country_code,
                     split_txns_enabled,
+                    allowed_proxy_destination_hosts,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -1577,6 +1617,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -1632,6 +1673,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -1687,6 +1729,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1742,6 +1785,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1797,6 +1841,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::CollectCvvDuringPaymentUpdate {
                 should_collect_cvv_during_payment,
@@ -1852,6 +1897,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::DecisionManagerRecordUpdate {
                 three_ds_decision_manager_config,
@@ -1907,6 +1953,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -1962,6 +2009,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
             ProfileUpdate::RevenueRecoveryAlgorithmUpdate {
                 revenue_recovery_retry_algorithm_type,
@@ -2018,6 +2066,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 merchant_category_code: None,
                 merchant_country_code: None,
                 split_txns_enabled: None,
+                allowed_proxy_destination_hosts: None,
             },
         }
     }
@@ -2097,7 +2146,10 @@ impl super::behaviour::Conversion for Profile {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: None,
+            is_payout_auto_retries_enabled: None,
+            max_payout_auto_retries_enabled: None,
             split_txns_enabled: Some(self.split_txns_enabled),
+            allowed_proxy_destination_hosts: self.allowed_proxy_destination_hosts,
         })
     }
 
@@ -2194,6 +2246,7 @@ impl super::behaviour::Conversion for Profile {
                 merchant_category_code: item.merchant_category_code,
                 merchant_country_code: item.merchant_country_code,
                 split_txns_enabled: item.split_txns_enabled.unwrap_or_default(),
+                allowed_proxy_destination_hosts: item.allowed_proxy_destination_hosts,
             })
         }
         .await
@@ -2265,6 +2318,7 @@ impl super::behaviour::Conversion for Profile {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             split_txns_enabled: Some(self.split_txns_enabled),
+            allowed_proxy_destination_hosts: self.allowed_proxy_destination_hosts,
         })
     }
 }
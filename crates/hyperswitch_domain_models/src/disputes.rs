@@ -13,6 +13,8 @@ pub struct DisputeListConstraints {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub time_range: Option<common_utils::types::TimeRange>,
+    /// `(created_at, dispute_id)` of the dispute after which the disputes are listed
+    pub starting_after: Option<(time::PrimitiveDateTime, String)>,
 }
 
 impl
@@ -84,6 +86,7 @@ impl
             merchant_connector_id,
             currency,
             time_range,
+            starting_after: None,
         })
     }
 }
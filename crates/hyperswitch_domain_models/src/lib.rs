@@ -43,6 +43,7 @@ pub mod transformers;
 pub mod type_encryption;
 pub mod types;
 pub mod vault;
+pub mod velocity_check_data;
 
 #[cfg(not(feature = "payouts"))]
 pub trait PayoutAttemptInterface {}
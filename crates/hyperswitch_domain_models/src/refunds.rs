@@ -16,6 +16,8 @@ pub struct RefundListConstraints {
     pub merchant_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub refund_status: Option<Vec<common_enums::RefundStatus>>,
+    /// `(created_at, refund_id)` of the refund after which the refunds are listed
+    pub starting_after: Option<(time::PrimitiveDateTime, String)>,
 }
 
 #[cfg(feature = "v2")]
@@ -97,6 +99,7 @@ impl
             merchant_connector_id,
             currency,
             refund_status,
+            starting_after: None,
         })
     }
 }
@@ -47,7 +47,7 @@ impl Relay {
             connector_id: relay_request.connector_id.clone(),
             profile_id: profile_id.clone(),
             merchant_id: merchant_id.clone(),
-            relay_type: common_enums::RelayType::Refund,
+            relay_type: relay_request.relay_type,
             request_data: relay_request.data.clone().map(From::from),
             status: common_enums::RelayStatus::Created,
             connector_reference_id: None,
@@ -64,11 +64,19 @@ impl From<api_models::relay::RelayData> for RelayData {
     fn from(relay: api_models::relay::RelayData) -> Self {
         match relay {
             api_models::relay::RelayData::Refund(relay_refund_request) => {
-                Self::Refund(RelayRefundData {
-                    amount: relay_refund_request.amount,
-                    currency: relay_refund_request.currency,
-                    reason: relay_refund_request.reason,
-                })
+                Self::Refund(relay_refund_request.into())
+            }
+            api_models::relay::RelayData::Capture(relay_capture_request) => {
+                Self::Capture(relay_capture_request.into())
+            }
+            api_models::relay::RelayData::Void(relay_void_request) => {
+                Self::Void(relay_void_request.into())
+            }
+            api_models::relay::RelayData::IncrementalAuthorization(
+                relay_incremental_authorization_request,
+            ) => Self::IncrementalAuthorization(relay_incremental_authorization_request.into()),
+            api_models::relay::RelayData::Sync(relay_sync_request) => {
+                Self::Sync(relay_sync_request.into())
             }
         }
     }
@@ -84,6 +92,49 @@ impl From<api_models::relay::RelayRefundRequestData> for RelayRefundData {
     }
 }
 
+impl From<api_models::relay::RelayCaptureRequestData> for RelayCaptureData {
+    fn from(relay: api_models::relay::RelayCaptureRequestData) -> Self {
+        Self {
+            authorized_amount: relay.authorized_amount,
+            amount_to_capture: relay.amount_to_capture,
+            currency: relay.currency,
+        }
+    }
+}
+
+impl From<api_models::relay::RelayVoidRequestData> for RelayVoidData {
+    fn from(relay: api_models::relay::RelayVoidRequestData) -> Self {
+        Self {
+            amount: relay.amount,
+            currency: relay.currency,
+            cancellation_reason: relay.cancellation_reason,
+        }
+    }
+}
+
+impl From<api_models::relay::RelayIncrementalAuthorizationRequestData>
+    for RelayIncrementalAuthorizationData
+{
+    fn from(relay: api_models::relay::RelayIncrementalAuthorizationRequestData) -> Self {
+        Self {
+            total_amount: relay.total_amount,
+            additional_amount: relay.additional_amount,
+            currency: relay.currency,
+            reason: relay.reason,
+        }
+    }
+}
+
+impl From<api_models::relay::RelaySyncRequestData> for RelaySyncData {
+    fn from(relay: api_models::relay::RelaySyncRequestData) -> Self {
+        Self {
+            amount: relay.amount,
+            currency: relay.currency,
+            capture_method: relay.capture_method,
+        }
+    }
+}
+
 impl RelayUpdate {
     pub fn from(
         response: Result<router_response_types::RefundsResponseData, ErrorResponse>,
@@ -97,7 +148,95 @@ impl RelayUpdate {
             Ok(response) => Self::StatusUpdate {
                 connector_reference_id: Some(response.connector_refund_id),
                 status: common_enums::RelayStatus::from(response.refund_status),
+                response_data: None,
+            },
+        }
+    }
+
+    /// Builds the update of a capture, void, incremental authorization or sync relay from the
+    /// connector response and the payment status reported by the connector
+    pub fn from_payments_response(
+        relay_type: enums::RelayType,
+        response: Result<router_response_types::PaymentsResponseData, ErrorResponse>,
+        attempt_status: enums::AttemptStatus,
+    ) -> Self {
+        match response {
+            Err(error) => Self::ErrorUpdate {
+                error_code: error.code,
+                error_message: error.reason.unwrap_or(error.message),
+                status: common_enums::RelayStatus::Failure,
             },
+            Ok(router_response_types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status,
+                connector_authorization_id,
+                error_code,
+                error_message,
+            }) => match status {
+                common_enums::AuthorizationStatus::Failure => Self::ErrorUpdate {
+                    error_code: error_code.unwrap_or_default(),
+                    error_message: error_message.unwrap_or_default(),
+                    status: common_enums::RelayStatus::Failure,
+                },
+                common_enums::AuthorizationStatus::Success
+                | common_enums::AuthorizationStatus::Processing
+                | common_enums::AuthorizationStatus::Unresolved => Self::StatusUpdate {
+                    connector_reference_id: connector_authorization_id,
+                    status: get_relay_status_for_authorization_status(status),
+                    response_data: None,
+                },
+            },
+            Ok(response) => Self::StatusUpdate {
+                connector_reference_id: response.get_connector_transaction_id().ok(),
+                status: get_relay_status_for_attempt_status(relay_type, attempt_status),
+                response_data: RelayPaymentResponseData { attempt_status }.to_secret_value(),
+            },
+        }
+    }
+}
+
+fn get_relay_status_for_authorization_status(
+    status: common_enums::AuthorizationStatus,
+) -> common_enums::RelayStatus {
+    match status {
+        common_enums::AuthorizationStatus::Success => common_enums::RelayStatus::Success,
+        common_enums::AuthorizationStatus::Failure => common_enums::RelayStatus::Failure,
+        common_enums::AuthorizationStatus::Processing
+        | common_enums::AuthorizationStatus::Unresolved => common_enums::RelayStatus::Pending,
+    }
+}
+
+/// Status of a capture, void or sync relay, given the status of the payment at the connector
+pub fn get_relay_status_for_attempt_status(
+    relay_type: enums::RelayType,
+    attempt_status: enums::AttemptStatus,
+) -> common_enums::RelayStatus {
+    match relay_type {
+        enums::RelayType::Capture => match attempt_status {
+            enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable => {
+                common_enums::RelayStatus::Success
+            }
+            enums::AttemptStatus::CaptureFailed | enums::AttemptStatus::Failure => {
+                common_enums::RelayStatus::Failure
+            }
+            _ => common_enums::RelayStatus::Pending,
+        },
+        enums::RelayType::Void => match attempt_status {
+            enums::AttemptStatus::Voided | enums::AttemptStatus::VoidedPostCharge => {
+                common_enums::RelayStatus::Success
+            }
+            enums::AttemptStatus::VoidFailed | enums::AttemptStatus::Failure => {
+                common_enums::RelayStatus::Failure
+            }
+            _ => common_enums::RelayStatus::Pending,
+        },
+        // The status of the payment is recorded in the response data of a sync relay, the relay
+        // itself succeeds once the connector responds
+        enums::RelayType::Sync => common_enums::RelayStatus::Success,
+        // The status of refunds and incremental authorizations is not derived from the payment
+        enums::RelayType::Refund | enums::RelayType::IncrementalAuthorization => {
+            common_enums::RelayStatus::Pending
         }
     }
 }
@@ -112,6 +251,38 @@ impl From<RelayData> for api_models::relay::RelayData {
                     reason: relay_refund_request.reason,
                 })
             }
+            RelayData::Capture(relay_capture_request) => {
+                Self::Capture(api_models::relay::RelayCaptureRequestData {
+                    authorized_amount: relay_capture_request.authorized_amount,
+                    amount_to_capture: relay_capture_request.amount_to_capture,
+                    currency: relay_capture_request.currency,
+                })
+            }
+            RelayData::Void(relay_void_request) => {
+                Self::Void(api_models::relay::RelayVoidRequestData {
+                    amount: relay_void_request.amount,
+                    currency: relay_void_request.currency,
+                    cancellation_reason: relay_void_request.cancellation_reason,
+                })
+            }
+            RelayData::IncrementalAuthorization(relay_incremental_authorization_request) => {
+                Self::IncrementalAuthorization(
+                    api_models::relay::RelayIncrementalAuthorizationRequestData {
+                        total_amount: relay_incremental_authorization_request.total_amount,
+                        additional_amount: relay_incremental_authorization_request
+                            .additional_amount,
+                        currency: relay_incremental_authorization_request.currency,
+                        reason: relay_incremental_authorization_request.reason,
+                    },
+                )
+            }
+            RelayData::Sync(relay_sync_request) => {
+                Self::Sync(api_models::relay::RelaySyncRequestData {
+                    amount: relay_sync_request.amount,
+                    currency: relay_sync_request.currency,
+                    capture_method: relay_sync_request.capture_method,
+                })
+            }
         }
     }
 }
@@ -128,15 +299,12 @@ impl From<Relay> for api_models::relay::RelayResponse {
                 },
             );
 
-        let data = value.request_data.map(|relay_data| match relay_data {
-            RelayData::Refund(relay_refund_request) => {
-                api_models::relay::RelayData::Refund(api_models::relay::RelayRefundRequestData {
-                    amount: relay_refund_request.amount,
-                    currency: relay_refund_request.currency,
-                    reason: relay_refund_request.reason,
-                })
-            }
-        });
+        let connector_payment_status = value
+            .response_data
+            .as_ref()
+            .and_then(RelayPaymentResponseData::from_secret_value)
+            .map(|response_data| response_data.attempt_status);
+        let data = value.request_data.map(api_models::relay::RelayData::from);
         Self {
             id: value.id,
             status: value.status,
@@ -147,14 +315,37 @@ impl From<Relay> for api_models::relay::RelayResponse {
             relay_type: value.relay_type,
             data,
             connector_reference_id: value.connector_reference_id,
+            connector_payment_status,
         }
     }
 }
 
+/// Stored untagged, the variant is decided by the type of the relay when it is read back
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case", untagged)]
 pub enum RelayData {
     Refund(RelayRefundData),
+    Capture(RelayCaptureData),
+    Void(RelayVoidData),
+    IncrementalAuthorization(RelayIncrementalAuthorizationData),
+    Sync(RelaySyncData),
+}
+
+impl RelayData {
+    fn from_value(
+        relay_type: enums::RelayType,
+        value: serde_json::Value,
+    ) -> Result<Self, serde_json::Error> {
+        match relay_type {
+            enums::RelayType::Refund => serde_json::from_value(value).map(Self::Refund),
+            enums::RelayType::Capture => serde_json::from_value(value).map(Self::Capture),
+            enums::RelayType::Void => serde_json::from_value(value).map(Self::Void),
+            enums::RelayType::IncrementalAuthorization => {
+                serde_json::from_value(value).map(Self::IncrementalAuthorization)
+            }
+            enums::RelayType::Sync => serde_json::from_value(value).map(Self::Sync),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -164,6 +355,51 @@ pub struct RelayRefundData {
     pub reason: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayCaptureData {
+    pub authorized_amount: MinorUnit,
+    pub amount_to_capture: MinorUnit,
+    pub currency: enums::Currency,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayVoidData {
+    pub amount: Option<MinorUnit>,
+    pub currency: Option<enums::Currency>,
+    pub cancellation_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayIncrementalAuthorizationData {
+    pub total_amount: MinorUnit,
+    pub additional_amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelaySyncData {
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub capture_method: Option<enums::CaptureMethod>,
+}
+
+/// Response of the connector for the relays made against a payment, stored in `response_data`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayPaymentResponseData {
+    pub attempt_status: enums::AttemptStatus,
+}
+
+impl RelayPaymentResponseData {
+    pub fn to_secret_value(&self) -> Option<pii::SecretSerdeValue> {
+        serde_json::to_value(self).ok().map(Secret::new)
+    }
+
+    pub fn from_secret_value(value: &pii::SecretSerdeValue) -> Option<Self> {
+        serde_json::from_value(value.clone().expose()).ok()
+    }
+}
+
 #[derive(Debug)]
 pub enum RelayUpdate {
     ErrorUpdate {
@@ -174,6 +410,7 @@ pub enum RelayUpdate {
     StatusUpdate {
         connector_reference_id: Option<String>,
         status: common_enums::RelayStatus,
+        response_data: Option<pii::SecretSerdeValue>,
     },
 }
 
@@ -189,16 +426,19 @@ impl From<RelayUpdate> for RelayUpdateInternal {
                 error_message: Some(error_message),
                 connector_reference_id: None,
                 status: Some(status),
+                response_data: None,
                 modified_at: common_utils::date_time::now(),
             },
             RelayUpdate::StatusUpdate {
                 connector_reference_id,
                 status,
+                response_data,
             } => Self {
                 connector_reference_id,
                 status: Some(status),
                 error_code: None,
                 error_message: None,
+                response_data,
                 modified_at: common_utils::date_time::now(),
             },
         }
@@ -249,11 +489,11 @@ impl super::behaviour::Conversion for Relay {
             connector_id: item.connector_id,
             profile_id: item.profile_id,
             merchant_id: item.merchant_id,
-            relay_type: enums::RelayType::Refund,
+            relay_type: item.relay_type,
             request_data: item
                 .request_data
                 .map(|data| {
-                    serde_json::from_value(data.expose()).change_context(
+                    RelayData::from_value(item.relay_type, data.expose()).change_context(
                         ValidationError::InvalidValue {
                             message: "Failed while decrypting business profile data".to_string(),
                         },
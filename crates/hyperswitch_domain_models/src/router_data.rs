@@ -112,6 +112,9 @@ pub struct RouterData<Flow, Request, Response> {
     /// Indicates whether the payment ID was provided by the merchant (true),
     /// or generated internally by Hyperswitch (false)
     pub is_payment_id_from_merchant: Option<bool>,
+
+    /// Merchant connector account used to process the request, if known
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
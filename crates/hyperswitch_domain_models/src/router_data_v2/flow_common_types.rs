@@ -132,6 +132,11 @@ pub struct WebhookSourceVerifyData {
     pub merchant_id: common_utils::id_type::MerchantId,
 }
 
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterFlowData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthenticationTokenFlowData {}
 
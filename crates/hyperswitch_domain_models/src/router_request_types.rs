@@ -1194,6 +1194,20 @@ pub struct MandateRevokeRequestData {
     pub connector_mandate_id: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterRequestData {
+    pub cards: Vec<AccountUpdaterCardData>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterCardData {
+    /// Identifies the card within the batch, and is returned with the result of the card
+    pub card_reference: String,
+    pub card_number: cards::CardNumber,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentsSessionData {
     pub amount: i64,
@@ -568,6 +568,24 @@ pub struct MandateRevokeResponseData {
     pub mandate_status: common_enums::MandateStatus,
 }
 
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterResponseData {
+    pub card_updates: Vec<AccountUpdaterCardUpdate>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterCardUpdate {
+    /// The reference of the card in the request
+    pub card_reference: String,
+    pub status: common_enums::CardAccountUpdateStatus,
+    /// The new card number, if the card was reissued with a new card number
+    pub card_number: Option<cards::CardNumber>,
+    pub card_exp_month: Option<masking::Secret<String>>,
+    pub card_exp_year: Option<masking::Secret<String>>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum AuthenticationResponseData {
     PreAuthVersionCallResponse {
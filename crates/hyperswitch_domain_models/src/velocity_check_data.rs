@@ -0,0 +1,26 @@
+use api_models::velocity_rules::{
+    TriggeredVelocityRule, VelocityCheckResponse, VelocityRuleAction,
+};
+use serde::{self, Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct VelocityCounter {
+    pub cache_key: String,
+    pub window_in_secs: u32,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct VelocityCheckData {
+    pub action: Option<VelocityRuleAction>,
+    pub triggered_rules: Vec<TriggeredVelocityRule>,
+    pub decline_counters: Vec<VelocityCounter>,
+}
+
+impl VelocityCheckData {
+    pub fn get_velocity_check_response(&self) -> Option<VelocityCheckResponse> {
+        self.action.map(|action| VelocityCheckResponse {
+            action,
+            triggered_rules: self.triggered_rules.clone(),
+        })
+    }
+}
@@ -46,11 +46,12 @@ use hyperswitch_domain_models::{
     },
     router_data_v2::{
         flow_common_types::{AuthenticationTokenFlowData, WebhookSourceVerifyData},
-        AccessTokenFlowData, MandateRevokeFlowData, UasFlowData,
+        AccessTokenFlowData, AccountUpdaterFlowData, MandateRevokeFlowData, UasFlowData,
     },
     router_flow_types::{
-        mandate_revoke::MandateRevoke, AccessTokenAuth, AccessTokenAuthentication, Authenticate,
-        AuthenticationConfirmation, PostAuthenticate, PreAuthenticate, VerifyWebhookSource,
+        account_updater::AccountUpdate, mandate_revoke::MandateRevoke, AccessTokenAuth,
+        AccessTokenAuthentication, Authenticate, AuthenticationConfirmation, PostAuthenticate,
+        PreAuthenticate, VerifyWebhookSource,
     },
     router_request_types::{
         unified_authentication_service::{
@@ -58,12 +59,12 @@ use hyperswitch_domain_models::{
             UasConfirmationRequestData, UasPostAuthenticationRequestData,
             UasPreAuthenticationRequestData,
         },
-        AccessTokenAuthenticationRequestData, AccessTokenRequestData, MandateRevokeRequestData,
-        VerifyWebhookSourceRequestData,
+        AccessTokenAuthenticationRequestData, AccessTokenRequestData, AccountUpdaterRequestData,
+        MandateRevokeRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        ConnectorInfo, MandateRevokeResponseData, PaymentMethodDetails, SupportedPaymentMethods,
-        VerifyWebhookSourceResponseData,
+        AccountUpdaterResponseData, ConnectorInfo, MandateRevokeResponseData, PaymentMethodDetails,
+        SupportedPaymentMethods, VerifyWebhookSourceResponseData,
     },
 };
 use masking::Maskable;
@@ -99,6 +100,7 @@ pub trait Connector:
     + ConnectorVerifyWebhookSource
     + FraudCheck
     + ConnectorMandateRevoke
+    + ConnectorAccountUpdater
     + authentication::ExternalAuthentication
     + TaxCalculation
     + UnifiedAuthenticationService
@@ -122,6 +124,7 @@ impl<
             + ConnectorVerifyWebhookSource
             + FraudCheck
             + ConnectorMandateRevoke
+            + ConnectorAccountUpdater
             + authentication::ExternalAuthentication
             + TaxCalculation
             + UnifiedAuthenticationService
@@ -459,6 +462,25 @@ pub trait ConnectorMandateRevokeV2:
 {
 }
 
+/// trait ConnectorAccountUpdater
+///
+/// Checks a batch of stored cards with the card networks for reissued, expired or closed cards
+pub trait ConnectorAccountUpdater:
+    ConnectorIntegration<AccountUpdate, AccountUpdaterRequestData, AccountUpdaterResponseData>
+{
+}
+
+/// trait ConnectorAccountUpdaterV2
+pub trait ConnectorAccountUpdaterV2:
+    ConnectorIntegrationV2<
+    AccountUpdate,
+    AccountUpdaterFlowData,
+    AccountUpdaterRequestData,
+    AccountUpdaterResponseData,
+>
+{
+}
+
 /// trait ConnectorAuthenticationToken
 pub trait ConnectorAuthenticationToken:
     ConnectorIntegration<
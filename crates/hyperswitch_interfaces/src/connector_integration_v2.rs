@@ -31,6 +31,7 @@ pub trait ConnectorV2:
     + api::ConnectorVerifyWebhookSourceV2
     + api::FraudCheckV2
     + api::ConnectorMandateRevokeV2
+    + api::ConnectorAccountUpdaterV2
     + api::authentication_v2::ExternalAuthenticationV2
     + api::UnifiedAuthenticationServiceV2
     + api::revenue_recovery_v2::RevenueRecoveryV2
@@ -52,6 +53,7 @@ impl<
             + api::ConnectorVerifyWebhookSourceV2
             + api::FraudCheckV2
             + api::ConnectorMandateRevokeV2
+            + api::ConnectorAccountUpdaterV2
             + api::authentication_v2::ExternalAuthenticationV2
             + api::UnifiedAuthenticationServiceV2
             + api::revenue_recovery_v2::RevenueRecoveryV2
@@ -9,10 +9,11 @@ use hyperswitch_domain_models::{
     router_data::{self, RouterData},
     router_data_v2::{
         flow_common_types::{
-            AccessTokenFlowData, AuthenticationTokenFlowData, BillingConnectorInvoiceSyncFlowData,
-            BillingConnectorPaymentsSyncFlowData, DisputesFlowData, ExternalAuthenticationFlowData,
-            ExternalVaultProxyFlowData, FilesFlowData, MandateRevokeFlowData, PaymentFlowData,
-            RefundFlowData, RevenueRecoveryRecordBackData, UasFlowData, VaultConnectorFlowData,
+            AccessTokenFlowData, AccountUpdaterFlowData, AuthenticationTokenFlowData,
+            BillingConnectorInvoiceSyncFlowData, BillingConnectorPaymentsSyncFlowData,
+            DisputesFlowData, ExternalAuthenticationFlowData, ExternalVaultProxyFlowData,
+            FilesFlowData, MandateRevokeFlowData, PaymentFlowData, RefundFlowData,
+            RevenueRecoveryRecordBackData, UasFlowData, VaultConnectorFlowData,
             WebhookSourceVerifyData,
         },
         RouterDataV2,
@@ -86,6 +87,7 @@ fn get_default_router_data<F, Req, Resp>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
         l2_l3_data: None,
         minor_amount_capturable: None,
     }
@@ -591,6 +593,44 @@ impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for WebhookS
     }
 }
 
+impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for AccountUpdaterFlowData {
+    fn from_old_router_data(
+        old_router_data: &RouterData<T, Req, Resp>,
+    ) -> CustomResult<RouterDataV2<T, Self, Req, Resp>, ConnectorError>
+    where
+        Self: Sized,
+    {
+        let resource_common_data = Self {
+            merchant_id: old_router_data.merchant_id.clone(),
+        };
+        Ok(RouterDataV2 {
+            tenant_id: old_router_data.tenant_id.clone(),
+            flow: std::marker::PhantomData,
+            resource_common_data,
+            connector_auth_type: old_router_data.connector_auth_type.clone(),
+            request: old_router_data.request.clone(),
+            response: old_router_data.response.clone(),
+        })
+    }
+
+    fn to_old_router_data(
+        new_router_data: RouterDataV2<T, Self, Req, Resp>,
+    ) -> CustomResult<RouterData<T, Req, Resp>, ConnectorError>
+    where
+        Self: Sized,
+    {
+        let Self { merchant_id } = new_router_data.resource_common_data;
+        let mut router_data = get_default_router_data(
+            new_router_data.tenant_id.clone(),
+            "account updater",
+            new_router_data.request,
+            new_router_data.response,
+        );
+        router_data.merchant_id = merchant_id;
+        Ok(router_data)
+    }
+}
+
 impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for MandateRevokeFlowData {
     fn from_old_router_data(
         old_router_data: &RouterData<T, Req, Resp>,
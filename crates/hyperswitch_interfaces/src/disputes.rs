@@ -1,4 +1,5 @@
 //! Disputes interface
+use common_types::disputes::DisputeFinancialRecord;
 use common_utils::types::StringMinorUnit;
 use hyperswitch_domain_models::router_response_types::DisputeSyncResponse;
 use time::PrimitiveDateTime;
@@ -26,6 +27,8 @@ pub struct DisputePayload {
     pub created_at: Option<PrimitiveDateTime>,
     /// updated_at
     pub updated_at: Option<PrimitiveDateTime>,
+    /// fees and amount adjustments of the dispute sent by the connector
+    pub financial_records: Vec<DisputeFinancialRecord>,
 }
 
 impl From<DisputeSyncResponse> for DisputePayload {
@@ -41,6 +44,7 @@ impl From<DisputeSyncResponse> for DisputePayload {
             challenge_required_by: dispute_sync_data.challenge_required_by,
             created_at: dispute_sync_data.created_at,
             updated_at: dispute_sync_data.updated_at,
+            financial_records: Vec::new(),
         }
     }
 }
@@ -26,6 +26,8 @@ pub struct ConnectorEvent {
     refund_id: Option<String>,
     dispute_id: Option<String>,
     status_code: u16,
+    merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    error_code: Option<String>,
 }
 
 impl ConnectorEvent {
@@ -45,6 +47,7 @@ impl ConnectorEvent {
         refund_id: Option<String>,
         dispute_id: Option<String>,
         status_code: u16,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     ) -> Self {
         Self {
             tenant_id,
@@ -69,6 +72,8 @@ impl ConnectorEvent {
             refund_id,
             dispute_id,
             status_code,
+            merchant_connector_id,
+            error_code: None,
         }
     }
 
@@ -96,4 +101,14 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// fn set_error_code
+    pub fn set_error_code(&mut self, error_code: String) {
+        self.error_code = Some(error_code);
+    }
+
+    /// fn set_status_code
+    pub fn set_status_code(&mut self, status_code: u16) {
+        self.status_code = status_code;
+    }
 }
@@ -17,6 +17,8 @@ pub enum RoutingEngine {
     IntelligentRouter,
     /// Decision engine for routing
     DecisionEngine,
+    /// Static routing evaluated in-process by the euclid interpreter
+    Euclid,
 }
 
 /// Method type enum
@@ -27,6 +29,8 @@ pub enum ApiMethod {
     Grpc,
     /// Rest call
     Rest(Method),
+    /// In-process evaluation, no network call is made
+    Internal,
 }
 
 impl fmt::Display for ApiMethod {
@@ -34,6 +38,7 @@ impl fmt::Display for ApiMethod {
         match self {
             Self::Grpc => write!(f, "Grpc"),
             Self::Rest(method) => write!(f, "Rest ({method})"),
+            Self::Internal => write!(f, "Internal"),
         }
     }
 }
@@ -58,6 +63,8 @@ pub struct RoutingEvent {
     request_id: String,
     routing_engine: RoutingEngine,
     routing_approach: Option<String>,
+    routing_algorithm_id: Option<String>,
+    rule_name: Option<String>,
 }
 
 impl RoutingEvent {
@@ -96,6 +103,8 @@ impl RoutingEvent {
             routing_engine,
             payment_connector: None,
             routing_approach: None,
+            routing_algorithm_id: None,
+            rule_name: None,
         }
     }
 
@@ -164,6 +173,16 @@ impl RoutingEvent {
         self.routing_approach = Some(approach);
     }
 
+    /// set the routing algorithm that was evaluated
+    pub fn set_routing_algorithm_id(&mut self, algorithm_id: &common_utils::id_type::RoutingId) {
+        self.routing_algorithm_id = Some(algorithm_id.get_string_repr().to_string());
+    }
+
+    /// set the name of the rule that matched
+    pub fn set_rule_name(&mut self, rule_name: String) {
+        self.rule_name = Some(rule_name);
+    }
+
     /// Returns the request ID of the event.
     pub fn get_request_id(&self) -> &str {
         &self.request_id
@@ -246,13 +246,20 @@ pub mod core {
         SerializationError(String),
         #[error("Invalid template: {0}")]
         InvalidTemplate(String),
+        #[error("Token resolution failed: {0}")]
+        TokenResolutionFailed(String),
+        #[error("Vault connector mismatch: {0}")]
+        VaultConnectorMismatch(String),
     }
 
+    /// Processes the request with the vault configuration of the merchant connector account
+    /// holding its tokens, as resolved by the caller
     #[instrument(skip_all)]
     pub async fn injector_core(
         request: InjectorRequest,
+        vault_connector_config: injector_types::VaultConnectorConfig,
     ) -> error_stack::Result<InjectorResponse, InjectorError> {
-        let injector = Injector::new();
+        let injector = Injector::with_vault_connector_config(vault_connector_config);
         injector.injector_core(request).await
     }
 
@@ -261,33 +268,92 @@ pub mod core {
     pub struct TokenReference {
         /// The field name to be replaced (without the {{$}} wrapper)
         pub field: String,
+        /// Alias of the token whose data holds the field, when referenced as {{$alias.field_name}}
+        pub alias: Option<String>,
     }
 
     /// Parses a single token reference from a string using nom parser combinators
     ///
-    /// Expects tokens in the format `{{$field_name}}` where field_name contains
-    /// only alphanumeric characters and underscores.
+    /// Expects tokens in the format `{{$field_name}}` or `{{$alias.field_name}}` where the alias
+    /// and field_name contain only alphanumeric characters and underscores.
     pub fn parse_token(input: &str) -> IResult<&str, TokenReference> {
-        let (input, field) = delimited(
+        let (remaining, reference) = delimited(
             tag("{{"),
             preceded(
                 multispace0,
                 preceded(
                     char('$'),
                     terminated(
-                        take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+                        take_while1(|c: char| c.is_alphanumeric() || c == '_' || c == '.'),
                         multispace0,
                     ),
                 ),
             ),
             tag("}}"),
         )(input)?;
-        Ok((
-            input,
-            TokenReference {
-                field: field.to_string(),
+
+        let token_ref = match reference.split_once('.') {
+            None => TokenReference {
+                field: reference.to_string(),
+                alias: None,
             },
-        ))
+            Some((alias, field))
+                if !alias.is_empty() && !field.is_empty() && !field.contains('.') =>
+            {
+                TokenReference {
+                    field: field.to_string(),
+                    alias: Some(alias.to_string()),
+                }
+            }
+            Some(_) => {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Verify,
+                )))
+            }
+        };
+
+        Ok((remaining, token_ref))
+    }
+
+    /// A part of a template string, either literal text or a token reference
+    #[derive(Debug)]
+    pub enum TemplateSegment<'a> {
+        Literal(&'a str),
+        Token(TokenReference),
+    }
+
+    /// Splits a template string into literal text and token references, in order
+    pub fn parse_template(input: &str) -> Vec<TemplateSegment<'_>> {
+        let mut segments = Vec::new();
+        let mut literal_start = 0;
+        let mut search_from = 0;
+
+        while let Some(offset) = input.get(search_from..).and_then(|rest| rest.find("{{")) {
+            let token_start = search_from.saturating_add(offset);
+            match input.get(token_start..).map(parse_token) {
+                Some(Ok((remaining, token_ref))) => {
+                    if token_start > literal_start {
+                        segments.push(TemplateSegment::Literal(
+                            input.get(literal_start..token_start).unwrap_or_default(),
+                        ));
+                    }
+                    segments.push(TemplateSegment::Token(token_ref));
+                    literal_start = input.len().saturating_sub(remaining.len());
+                    search_from = literal_start;
+                }
+                // Move past the opening braces if they do not start a token
+                _ => search_from = token_start.saturating_add(1),
+            }
+        }
+
+        if literal_start < input.len() {
+            segments.push(TemplateSegment::Literal(
+                input.get(literal_start..).unwrap_or_default(),
+            ));
+        }
+
+        segments
     }
 
     /// Finds all token references in a string using nom parser
@@ -295,24 +361,81 @@ pub mod core {
     /// Scans through the entire input string and extracts all valid token references.
     /// Returns a vector of TokenReference structs containing the field names.
     pub fn find_all_tokens(input: &str) -> Vec<TokenReference> {
-        let mut tokens = Vec::new();
-        let mut current_input = input;
+        parse_template(input)
+            .into_iter()
+            .filter_map(|segment| match segment {
+                TemplateSegment::Token(token_ref) => Some(token_ref),
+                TemplateSegment::Literal(_) => None,
+            })
+            .collect()
+    }
 
-        while !current_input.is_empty() {
-            if let Ok((remaining, token_ref)) = parse_token(current_input) {
-                tokens.push(token_ref);
-                current_input = remaining;
-            } else {
-                // Move forward one character if no token found
-                if let Some((_, rest)) = current_input.split_at_checked(1) {
-                    current_input = rest;
-                } else {
-                    break;
-                }
+    /// Escapes a value substituted into a template, so that it stays a single value of the
+    /// payload format
+    pub fn escape_value_for_content_type(value: &str, content_type: &ContentType) -> String {
+        match content_type {
+            // Escapes the value as the contents of a JSON string, without the quotes
+            ContentType::ApplicationJson => serde_json::to_string(value)
+                .ok()
+                .and_then(|quoted| {
+                    quoted
+                        .strip_prefix('"')
+                        .and_then(|quoted| quoted.strip_suffix('"'))
+                        .map(ToString::to_string)
+                })
+                .unwrap_or_else(|| value.to_string()),
+            ContentType::ApplicationXWwwFormUrlencoded => {
+                url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
             }
+            ContentType::ApplicationXml | ContentType::TextXml => value
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+                .replace('\'', "&apos;"),
+            ContentType::TextPlain => value.to_string(),
         }
+    }
 
-        tokens
+    /// Determines the content type of the payload from the `Content-Type` header, defaulting to
+    /// form-urlencoded
+    pub fn get_content_type_from_headers(
+        headers: &HashMap<String, masking::Secret<String>>,
+    ) -> ContentType {
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("Content-Type"))
+            .and_then(|(_, content_type)| {
+                let content_type = content_type.clone().expose();
+                let mime_type = content_type
+                    .split(';')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_ascii_lowercase();
+                match mime_type.as_str() {
+                    "application/json" => Some(ContentType::ApplicationJson),
+                    "application/x-www-form-urlencoded" => {
+                        Some(ContentType::ApplicationXWwwFormUrlencoded)
+                    }
+                    "application/xml" => Some(ContentType::ApplicationXml),
+                    "text/xml" => Some(ContentType::TextXml),
+                    "text/plain" => Some(ContentType::TextPlain),
+                    _ => None,
+                }
+            })
+            .unwrap_or(ContentType::ApplicationXWwwFormUrlencoded)
+    }
+
+    /// Vault data of a token which can be referenced by a template
+    #[derive(Debug)]
+    pub struct TokenSource<'a> {
+        /// Alias by which the template refers to the token, if any
+        pub alias: Option<&'a str>,
+        /// The data of the token retrieved from the vault
+        pub vault_data: &'a Value,
+        /// The vault connector holding the token
+        pub vault_connector: &'a injector_types::VaultConnectors,
     }
 
     /// Recursively searches for a field in vault data JSON structure
@@ -344,11 +467,103 @@ pub mod core {
         ) -> error_stack::Result<InjectorResponse, InjectorError>;
     }
 
-    pub struct Injector;
+    pub struct Injector {
+        /// Vault configuration of the merchant connector account holding the tokens
+        vault_connector_config: Option<injector_types::VaultConnectorConfig>,
+        #[cfg(any(test, feature = "in_memory_vault"))]
+        in_memory_vault: injector_types::InMemoryVault,
+    }
 
     impl Injector {
         pub fn new() -> Self {
-            Self
+            Self {
+                vault_connector_config: None,
+                #[cfg(any(test, feature = "in_memory_vault"))]
+                in_memory_vault: injector_types::InMemoryVault::new(),
+            }
+        }
+
+        /// Creates an injector resolving the tokens of the vault of a merchant connector account
+        pub fn with_vault_connector_config(
+            vault_connector_config: injector_types::VaultConnectorConfig,
+        ) -> Self {
+            Self {
+                vault_connector_config: Some(vault_connector_config),
+                #[cfg(any(test, feature = "in_memory_vault"))]
+                in_memory_vault: injector_types::InMemoryVault::new(),
+            }
+        }
+
+        /// Creates an injector resolving IN_MEMORY tokens from the given vault
+        #[cfg(any(test, feature = "in_memory_vault"))]
+        pub fn with_in_memory_vault(in_memory_vault: injector_types::InMemoryVault) -> Self {
+            Self {
+                vault_connector_config: None,
+                in_memory_vault,
+            }
+        }
+
+        /// Returns the resolver of the tokens held by the vault connector
+        ///
+        /// Tokens of a vault connector other than the one of the configured merchant connector
+        /// account are rejected, and vaults which are called to detokenize the tokens are only
+        /// called at the endpoint configured in the merchant connector account.
+        pub fn get_vault_token_resolver(
+            &self,
+            vault_connector: &injector_types::VaultConnectors,
+        ) -> error_stack::Result<Box<dyn injector_types::VaultTokenResolver + '_>, InjectorError>
+        {
+            if let Some(config) = self
+                .vault_connector_config
+                .as_ref()
+                .filter(|config| config.vault_connector != *vault_connector)
+            {
+                return Err(error_stack::Report::new(
+                    InjectorError::VaultConnectorMismatch(format!(
+                        "expected {:?}, found {vault_connector:?}",
+                        config.vault_connector
+                    )),
+                ));
+            }
+
+            match vault_connector {
+                injector_types::VaultConnectors::VGS => {
+                    Ok(Box::new(injector_types::VgsTokenResolver))
+                }
+                injector_types::VaultConnectors::HyperswitchVault => {
+                    Ok(Box::new(injector_types::HyperswitchVaultTokenResolver))
+                }
+                injector_types::VaultConnectors::HttpDetokenize => self
+                    .vault_connector_config
+                    .as_ref()
+                    .and_then(|config| config.detokenize_config.as_ref())
+                    .map(|detokenize_config| {
+                        Box::new(injector_types::HttpDetokenizeTokenResolver::new(
+                            detokenize_config,
+                        ))
+                            as Box<dyn injector_types::VaultTokenResolver + '_>
+                    })
+                    .ok_or_else(|| {
+                        error_stack::Report::new(InjectorError::TokenResolutionFailed(
+                            "HTTP_DETOKENIZE connector is not configured".to_string(),
+                        ))
+                    }),
+                #[cfg(any(test, feature = "in_memory_vault"))]
+                injector_types::VaultConnectors::InMemory => {
+                    Ok(Box::new(self.in_memory_vault.clone()))
+                }
+            }
+        }
+
+        /// Resolves the token data into vault data using the resolver of its vault connector
+        #[instrument(skip_all)]
+        pub async fn resolve_token_data(
+            &self,
+            token_data: &injector_types::DomainTokenData,
+        ) -> error_stack::Result<Value, InjectorError> {
+            self.get_vault_token_resolver(&token_data.vault_connector)?
+                .resolve_token_data(token_data)
+                .await
         }
 
         /// Processes a string template and replaces token references with vault data
@@ -359,29 +574,98 @@ pub mod core {
             vault_data: &Value,
             vault_connector: &injector_types::VaultConnectors,
         ) -> error_stack::Result<String, InjectorError> {
-            // Find all tokens using nom parser
-            let tokens = find_all_tokens(&template);
-            let mut result = template;
-
-            for token_ref in tokens.into_iter() {
-                let extracted_field_value = self.extract_field_from_vault_data(
+            self.interpolate_template_with_token_sources(
+                &template,
+                &[TokenSource {
+                    alias: None,
                     vault_data,
-                    &token_ref.field,
                     vault_connector,
-                )?;
-                let token_str = match extracted_field_value {
-                    Value::String(token_value) => token_value,
-                    _ => serde_json::to_string(&extracted_field_value).unwrap_or_default(),
-                };
-
-                // Replace the token in the result string
-                let token_pattern = format!("{{{{${}}}}}", token_ref.field);
-                result = result.replace(&token_pattern, &token_str);
+                }],
+                &ContentType::TextPlain,
+            )
+        }
+
+        /// Processes a string template and replaces token references with the data of the
+        /// tokens they refer to, escaping the substituted values for the content type
+        ///
+        /// A reference with an alias is resolved from the token with that alias, a reference
+        /// without one from the first token whose data holds the field.
+        #[instrument(skip_all)]
+        pub fn interpolate_template_with_token_sources(
+            &self,
+            template: &str,
+            token_sources: &[TokenSource<'_>],
+            content_type: &ContentType,
+        ) -> error_stack::Result<String, InjectorError> {
+            let mut result = String::with_capacity(template.len());
+
+            for segment in parse_template(template) {
+                match segment {
+                    TemplateSegment::Literal(literal) => result.push_str(literal),
+                    TemplateSegment::Token(token_ref) => {
+                        let extracted_field_value =
+                            self.extract_field_from_token_sources(token_sources, &token_ref)?;
+                        let token_str = match extracted_field_value {
+                            Value::String(token_value) => {
+                                escape_value_for_content_type(&token_value, content_type)
+                            }
+                            _ => serde_json::to_string(&extracted_field_value).unwrap_or_default(),
+                        };
+                        result.push_str(&token_str);
+                    }
+                }
             }
 
             Ok(result)
         }
 
+        #[instrument(skip_all)]
+        fn extract_field_from_token_sources(
+            &self,
+            token_sources: &[TokenSource<'_>],
+            token_ref: &TokenReference,
+        ) -> error_stack::Result<Value, InjectorError> {
+            match &token_ref.alias {
+                Some(alias) => {
+                    let token_source = token_sources
+                        .iter()
+                        .find(|token_source| token_source.alias == Some(alias.as_str()))
+                        .ok_or_else(|| {
+                            error_stack::Report::new(InjectorError::TokenReplacementFailed(
+                                format!("Token with alias '{alias}' not found"),
+                            ))
+                        })?;
+                    self.extract_field_from_vault_data(
+                        token_source.vault_data,
+                        &token_ref.field,
+                        token_source.vault_connector,
+                    )
+                }
+                None => token_sources
+                    .iter()
+                    .find_map(|token_source| match token_source.vault_data {
+                        Value::Object(obj) => {
+                            find_field_recursively_in_vault_data(obj, &token_ref.field)
+                                .map(|raw_value| (raw_value, token_source.vault_connector))
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| {
+                        error_stack::Report::new(InjectorError::TokenReplacementFailed(format!(
+                            "Field '{}' not found",
+                            token_ref.field
+                        )))
+                    })
+                    .and_then(|(raw_value, vault_connector)| {
+                        self.apply_vault_specific_transformation(
+                            raw_value,
+                            vault_connector,
+                            &token_ref.field,
+                        )
+                    }),
+            }
+        }
+
         #[instrument(skip_all)]
         pub fn interpolate_token_references_with_vault_data(
             &self,
@@ -451,7 +735,11 @@ pub mod core {
             _field_name: &str,
         ) -> error_stack::Result<Value, InjectorError> {
             match vault_connector {
-                injector_types::VaultConnectors::VGS => Ok(extracted_field_value),
+                injector_types::VaultConnectors::VGS
+                | injector_types::VaultConnectors::HyperswitchVault
+                | injector_types::VaultConnectors::HttpDetokenize => Ok(extracted_field_value),
+                #[cfg(any(test, feature = "in_memory_vault"))]
+                injector_types::VaultConnectors::InMemory => Ok(extracted_field_value),
             }
         }
 
@@ -642,36 +930,44 @@ pub mod core {
             // Convert API model to domain model
             let domain_request: injector_types::DomainInjectorRequest = request.into();
 
-            // Extract token data from SecretSerdeValue for vault data lookup
-            let vault_data = domain_request
-                .token_data
-                .specific_token_data
-                .expose()
-                .clone();
+            // Resolve the token data into vault data using the resolver of each vault connector
+            let vault_data = self.resolve_token_data(&domain_request.token_data).await?;
 
-            // Process template string directly with vault-specific logic
-            let processed_payload = self.interpolate_string_template_with_vault_data(
-                domain_request.connector_payload.template,
-                &vault_data,
-                &domain_request.token_data.vault_connector,
-            )?;
+            let mut additional_vault_data =
+                Vec::with_capacity(domain_request.additional_token_data.len());
+            for token_data in &domain_request.additional_token_data {
+                additional_vault_data.push(self.resolve_token_data(token_data).await?);
+            }
+
+            let token_sources = std::iter::once(TokenSource {
+                alias: domain_request.token_data.alias.as_deref(),
+                vault_data: &vault_data,
+                vault_connector: &domain_request.token_data.vault_connector,
+            })
+            .chain(
+                domain_request
+                    .additional_token_data
+                    .iter()
+                    .zip(additional_vault_data.iter())
+                    .map(|(token_data, vault_data)| TokenSource {
+                        alias: token_data.alias.as_deref(),
+                        vault_data,
+                        vault_connector: &token_data.vault_connector,
+                    }),
+            )
+            .collect::<Vec<_>>();
 
             // Determine content type from headers or default to form-urlencoded
-            let content_type = domain_request
-                .connection_config
-                .headers
-                .get("Content-Type")
-                .and_then(|ct| match ct.clone().expose().as_str() {
-                    "application/json" => Some(ContentType::ApplicationJson),
-                    "application/x-www-form-urlencoded" => {
-                        Some(ContentType::ApplicationXWwwFormUrlencoded)
-                    }
-                    "application/xml" => Some(ContentType::ApplicationXml),
-                    "text/xml" => Some(ContentType::TextXml),
-                    "text/plain" => Some(ContentType::TextPlain),
-                    _ => None,
-                })
-                .unwrap_or(ContentType::ApplicationXWwwFormUrlencoded);
+            let content_type =
+                get_content_type_from_headers(&domain_request.connection_config.headers);
+
+            // Process template string with vault-specific logic, escaping the substituted values
+            // so that they are valid in the payload format
+            let processed_payload = self.interpolate_template_with_token_sources(
+                &domain_request.connector_payload.template,
+                &token_sources,
+                &content_type,
+            )?;
 
             // Make HTTP request to connector and return raw response
             let response_data = self
@@ -701,6 +997,13 @@ mod tests {
     use super::core::*;
     use crate::*;
 
+    fn vgs_vault_connector_config() -> VaultConnectorConfig {
+        VaultConnectorConfig {
+            vault_connector: VaultConnectors::VGS,
+            detokenize_config: None,
+        }
+    }
+
     #[test]
     fn test_token_parsing() {
         let result = parse_token("{{$card_number}}");
@@ -747,6 +1050,103 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_aliased_token_parsing() {
+        let (_, token_ref) = parse_token("{{ $card.number }}").unwrap();
+        assert_eq!(token_ref.field, "number");
+        assert_eq!(token_ref.alias.as_deref(), Some("card"));
+
+        assert!(parse_token("{{$card.}}").is_err());
+        assert!(parse_token("{{$card.number.last4}}").is_err());
+    }
+
+    #[test]
+    fn test_multi_token_interpolation() {
+        let injector = Injector::new();
+        let card_data = serde_json::json!({
+            "card_number": "4111111111111111",
+            "cvc": "123"
+        });
+        let network_token_data = serde_json::json!({
+            "network_token": "4895370012003478",
+            "cvc": "999"
+        });
+        let token_sources = [
+            TokenSource {
+                alias: Some("card"),
+                vault_data: &card_data,
+                vault_connector: &VaultConnectors::VGS,
+            },
+            TokenSource {
+                alias: Some("nt"),
+                vault_data: &network_token_data,
+                vault_connector: &VaultConnectors::VGS,
+            },
+        ];
+
+        let result = injector
+            .interpolate_template_with_token_sources(
+                "pan={{$card_number}}&cvc={{$cvc}}&nt={{$network_token}}&nt_cvc={{$nt.cvc}}",
+                &token_sources,
+                &ContentType::TextPlain,
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            "pan=4111111111111111&cvc=123&nt=4895370012003478&nt_cvc=999"
+        );
+
+        let unknown_alias = injector.interpolate_template_with_token_sources(
+            "{{$wallet.cvc}}",
+            &token_sources,
+            &ContentType::TextPlain,
+        );
+        assert!(unknown_alias.is_err());
+    }
+
+    #[test]
+    fn test_interpolation_escapes_values_for_content_type() {
+        let injector = Injector::new();
+        let vault_data = serde_json::json!({
+            "name": "Tom & \"Jerry\" <co>"
+        });
+        let token_sources = [TokenSource {
+            alias: None,
+            vault_data: &vault_data,
+            vault_connector: &VaultConnectors::VGS,
+        }];
+
+        let xml = injector
+            .interpolate_template_with_token_sources(
+                "<name>{{$name}}</name>",
+                &token_sources,
+                &ContentType::ApplicationXml,
+            )
+            .unwrap();
+        assert_eq!(xml, "<name>Tom &amp; &quot;Jerry&quot; &lt;co&gt;</name>");
+
+        let form = injector
+            .interpolate_template_with_token_sources(
+                "name={{$name}}&amount=50",
+                &token_sources,
+                &ContentType::ApplicationXWwwFormUrlencoded,
+            )
+            .unwrap();
+        assert_eq!(form, "name=Tom+%26+%22Jerry%22+%3Cco%3E&amount=50");
+
+        let json = injector
+            .interpolate_template_with_token_sources(
+                r#"{"name":"{{$name}}"}"#,
+                &token_sources,
+                &ContentType::ApplicationJson,
+            )
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!({ "name": "Tom & \"Jerry\" <co>" })
+        );
+    }
+
     #[test]
     fn test_recursive_field_search() {
         let vault_data = serde_json::json!({
@@ -785,6 +1185,153 @@ mod tests {
         assert_eq!(vgs_result, serde_json::Value::String("TOKEN".to_string()));
     }
 
+    #[test]
+    fn test_vault_connector_from_connector_name() {
+        assert_eq!(
+            "hyperswitch_vault".parse::<VaultConnectors>().unwrap(),
+            VaultConnectors::HyperswitchVault
+        );
+        assert_eq!(
+            "VGS".parse::<VaultConnectors>().unwrap(),
+            VaultConnectors::VGS
+        );
+        assert!("stripe".parse::<VaultConnectors>().is_err());
+
+        let vault_connector: VaultConnectors =
+            serde_json::from_value(serde_json::json!("HTTP_DETOKENIZE")).unwrap();
+        assert_eq!(vault_connector, VaultConnectors::HttpDetokenize);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_vault_token_resolution() {
+        let in_memory_vault = InMemoryVault::new();
+        in_memory_vault.insert_token("tok_card", serde_json::json!("4111111111111111"));
+        let injector = Injector::with_in_memory_vault(in_memory_vault.clone());
+
+        let token_data = DomainTokenData {
+            specific_token_data: common_utils::pii::SecretSerdeValue::new(serde_json::json!({
+                "card_number": "tok_card",
+                "exp_month": 12
+            })),
+            vault_connector: VaultConnectors::InMemory,
+            alias: None,
+        };
+
+        let vault_data = injector.resolve_token_data(&token_data).await.unwrap();
+        assert_eq!(
+            vault_data,
+            serde_json::json!({ "card_number": "4111111111111111", "exp_month": 12 })
+        );
+
+        in_memory_vault.remove_token("tok_card");
+        assert!(injector.resolve_token_data(&token_data).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_hyperswitch_vault_token_resolution() {
+        let injector = Injector::new();
+        let token_data = DomainTokenData {
+            specific_token_data: common_utils::pii::SecretSerdeValue::new(serde_json::json!({
+                "data": { "card_number": "4111111111111111" }
+            })),
+            vault_connector: VaultConnectors::HyperswitchVault,
+            alias: None,
+        };
+
+        let vault_data = injector.resolve_token_data(&token_data).await.unwrap();
+        assert_eq!(
+            vault_data,
+            serde_json::json!({ "card_number": "4111111111111111" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_detokenize_requires_config() {
+        let injector = Injector::new();
+        let token_data = DomainTokenData {
+            specific_token_data: common_utils::pii::SecretSerdeValue::new(serde_json::json!({
+                "card_number": "tok_card"
+            })),
+            vault_connector: VaultConnectors::HttpDetokenize,
+            alias: None,
+        };
+
+        assert!(injector.resolve_token_data(&token_data).await.is_err());
+    }
+
+    #[test]
+    fn test_vault_connector_config_from_merchant_connector_account() {
+        use masking::PeekInterface;
+
+        let metadata = common_utils::pii::SecretSerdeValue::new(serde_json::json!({
+            "detokenize_url": "https://vault.example.com/detokenize"
+        }));
+        let connector_account_details =
+            common_utils::pii::SecretSerdeValue::new(serde_json::json!({
+                "auth_type": "HeaderKey",
+                "api_key": "vault_api_key"
+            }));
+
+        let config = VaultConnectorConfig::from_merchant_connector_account(
+            "http_detokenize",
+            Some(&metadata),
+            &connector_account_details,
+        )
+        .unwrap();
+        let detokenize_config = config.detokenize_config.unwrap();
+        assert_eq!(config.vault_connector, VaultConnectors::HttpDetokenize);
+        assert_eq!(
+            detokenize_config.url.as_str(),
+            "https://vault.example.com/detokenize"
+        );
+        assert_eq!(
+            detokenize_config
+                .headers
+                .get("Authorization")
+                .map(|api_key| api_key.peek().as_str()),
+            Some("vault_api_key")
+        );
+
+        // The detokenize endpoint can only be configured through the connector metadata
+        assert!(VaultConnectorConfig::from_merchant_connector_account(
+            "http_detokenize",
+            None,
+            &connector_account_details,
+        )
+        .is_err());
+
+        let config = VaultConnectorConfig::from_merchant_connector_account(
+            "vgs",
+            None,
+            &connector_account_details,
+        )
+        .unwrap();
+        assert_eq!(config.vault_connector, VaultConnectors::VGS);
+        assert!(config.detokenize_config.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_vault_connector_mismatch() {
+        let config = VaultConnectorConfig {
+            vault_connector: VaultConnectors::HyperswitchVault,
+            detokenize_config: None,
+        };
+        let injector = Injector::with_vault_connector_config(config);
+        let token_data = DomainTokenData {
+            specific_token_data: common_utils::pii::SecretSerdeValue::new(serde_json::json!({
+                "card_number": "tok_card"
+            })),
+            vault_connector: VaultConnectors::HttpDetokenize,
+            alias: None,
+        };
+
+        let error = injector.resolve_token_data(&token_data).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            InjectorError::VaultConnectorMismatch(_)
+        ));
+    }
+
     #[tokio::test]
     #[ignore = "Integration test that requires network access"]
     async fn test_injector_core_integration() {
@@ -815,7 +1362,9 @@ mod tests {
             token_data: TokenData {
                 vault_connector: VaultConnectors::VGS,
                 specific_token_data,
+                alias: None,
             },
+            additional_token_data: Vec::new(),
             connection_config: ConnectionConfig {
                 base_url: "https://api.stripe.com".to_string(),
                 endpoint_path: "/v1/payment_intents".to_string(),
@@ -834,7 +1383,7 @@ mod tests {
         };
 
         // Test the core function - this will make a real HTTP request to httpbin.org
-        let result = injector_core(request).await;
+        let result = injector_core(request, vgs_vault_connector_config()).await;
 
         // The request should succeed (httpbin.org should be accessible)
         if let Err(ref e) = result {
@@ -890,7 +1439,9 @@ mod tests {
             token_data: TokenData {
                 vault_connector: VaultConnectors::VGS,
                 specific_token_data,
+                alias: None,
             },
+            additional_token_data: Vec::new(),
             connection_config: ConnectionConfig {
                 base_url: "https://api.stripe.com".to_string(),
                 endpoint_path: "/v1/payment_intents".to_string(),
@@ -908,7 +1459,7 @@ mod tests {
             },
         };
 
-        let result = injector_core(request).await;
+        let result = injector_core(request, vgs_vault_connector_config()).await;
 
         // Should succeed even with insecure flag
         assert!(
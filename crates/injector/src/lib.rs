@@ -1,6 +1,8 @@
 pub mod injector;
 pub mod types;
+pub mod vault;
 
 // Re-export all functionality
 pub use injector::*;
 pub use types::*;
+pub use vault::*;
@@ -2,7 +2,7 @@ pub mod models {
     use std::collections::HashMap;
 
     use common_utils::pii::SecretSerdeValue;
-    use masking::Secret;
+    use masking::{PeekInterface, Secret};
     use serde::{Deserialize, Serialize};
 
     // Enums for the injector - making it standalone
@@ -42,15 +42,131 @@ pub mod models {
 
     /// Vault connectors supported by the injector for token management
     ///
-    /// Currently supports VGS as the primary vault connector. While only VGS is
-    /// implemented today, this enum structure is maintained for future extensibility
-    /// to support additional vault providers (e.g., Basis Theory, Skyflow, etc.)
-    /// without breaking API compatibility.
-    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// Each vault connector is backed by a `VaultTokenResolver`, which turns the token data of
+    /// a request into the values referenced by the template. The vault connector of a token is
+    /// derived from the connector name of the merchant connector account holding it.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
     #[serde(rename_all = "UPPERCASE")]
     pub enum VaultConnectors {
         /// VGS (Very Good Security) vault connector
         VGS,
+        /// Hyperswitch vault connector
+        #[serde(rename = "HYPERSWITCH_VAULT")]
+        HyperswitchVault,
+        /// Self-hosted vault exposing an HTTP detokenize endpoint
+        #[serde(rename = "HTTP_DETOKENIZE")]
+        HttpDetokenize,
+        /// In-memory vault, meant for tests
+        #[cfg(any(test, feature = "in_memory_vault"))]
+        #[serde(rename = "IN_MEMORY")]
+        InMemory,
+    }
+
+    impl std::str::FromStr for VaultConnectors {
+        type Err = String;
+
+        /// Parses the vault connector from the connector name of a merchant connector account
+        fn from_str(connector_name: &str) -> Result<Self, Self::Err> {
+            match connector_name.to_ascii_lowercase().as_str() {
+                "vgs" => Ok(Self::VGS),
+                "hyperswitch_vault" => Ok(Self::HyperswitchVault),
+                "http_detokenize" => Ok(Self::HttpDetokenize),
+                #[cfg(any(test, feature = "in_memory_vault"))]
+                "in_memory" => Ok(Self::InMemory),
+                _ => Err(format!("Unsupported vault connector: {connector_name}")),
+            }
+        }
+    }
+
+    /// Configuration of a self-hosted vault exposing an HTTP detokenize endpoint
+    ///
+    /// The endpoint is sent the token data of the request as a JSON object of field names to
+    /// tokens and responds with a JSON object of the same field names to their values,
+    /// optionally nested under `data`.
+    #[derive(Clone, Debug)]
+    pub struct HttpDetokenizeConfig {
+        /// URL of the detokenize endpoint
+        pub url: url::Url,
+        /// HTTP headers to include in the detokenize request, such as credentials
+        pub headers: HashMap<String, Secret<String>>,
+    }
+
+    /// Metadata of the merchant connector account of a self-hosted vault
+    #[derive(Debug, Deserialize)]
+    struct HttpDetokenizeMetadata {
+        /// URL of the detokenize endpoint of the vault
+        detokenize_url: url::Url,
+    }
+
+    /// Credentials of the merchant connector account of a self-hosted vault, as stored in its
+    /// connector account details
+    #[derive(Debug, Deserialize)]
+    #[serde(tag = "auth_type")]
+    enum HttpDetokenizeAuth {
+        /// API key sent in the `Authorization` header of the detokenize request
+        HeaderKey { api_key: Secret<String> },
+        /// The detokenize endpoint does not require any credentials
+        NoKey,
+    }
+
+    /// Vault configuration of the merchant connector account holding the tokens of a request
+    ///
+    /// The configuration is derived by the caller from the merchant connector account and is
+    /// never taken from the request, so that requests cannot direct the injector to other vaults.
+    #[derive(Clone, Debug)]
+    pub struct VaultConnectorConfig {
+        /// Vault connector of the merchant connector account
+        pub vault_connector: VaultConnectors,
+        /// Configuration of the detokenize endpoint, present for the HTTP_DETOKENIZE connector
+        pub detokenize_config: Option<HttpDetokenizeConfig>,
+    }
+
+    impl VaultConnectorConfig {
+        /// Derives the vault configuration from the connector name, metadata and connector
+        /// account details of a merchant connector account
+        pub fn from_merchant_connector_account(
+            connector_name: &str,
+            metadata: Option<&SecretSerdeValue>,
+            connector_account_details: &SecretSerdeValue,
+        ) -> Result<Self, String> {
+            let vault_connector = connector_name.parse::<VaultConnectors>()?;
+
+            let detokenize_config = match vault_connector {
+                VaultConnectors::HttpDetokenize => {
+                    let metadata = metadata
+                        .map(|metadata| metadata.peek().clone())
+                        .ok_or("Metadata is required for the HTTP_DETOKENIZE connector")?;
+                    let HttpDetokenizeMetadata { detokenize_url } =
+                        serde_json::from_value(metadata).map_err(|error| {
+                            format!("Invalid HTTP_DETOKENIZE connector metadata: {error}")
+                        })?;
+
+                    let auth = serde_json::from_value::<HttpDetokenizeAuth>(
+                        connector_account_details.peek().clone(),
+                    )
+                    .map_err(|error| {
+                        format!("Invalid HTTP_DETOKENIZE connector account details: {error}")
+                    })?;
+                    let headers = match auth {
+                        HttpDetokenizeAuth::HeaderKey { api_key } => {
+                            HashMap::from([("Authorization".to_string(), api_key)])
+                        }
+                        HttpDetokenizeAuth::NoKey => HashMap::new(),
+                    };
+
+                    Some(HttpDetokenizeConfig {
+                        url: detokenize_url,
+                        headers,
+                    })
+                }
+                _ => None,
+            };
+
+            Ok(Self {
+                vault_connector,
+                detokenize_config,
+            })
+        }
     }
 
     /// Token data containing vault-specific information for token replacement
@@ -58,8 +174,12 @@ pub mod models {
     pub struct TokenData {
         /// The specific token data retrieved from the vault
         pub specific_token_data: SecretSerdeValue,
-        /// The type of vault connector being used (e.g., VGS)
+        /// The type of vault connector being used (e.g., VGS), which must be the vault connector
+        /// of the merchant connector account the injector is configured with
         pub vault_connector: VaultConnectors,
+        /// Optional alias by which the template can refer to this token as {{$alias.field_name}}
+        #[serde(default)]
+        pub alias: Option<String>,
     }
 
     /// Connector payload containing the template to be processed
@@ -103,6 +223,10 @@ pub mod models {
     pub struct InjectorRequest {
         /// Token data from the vault
         pub token_data: TokenData,
+        /// Data of any other tokens referenced by the template. Token references without an
+        /// alias are looked up in `token_data` first and then in these, in order.
+        #[serde(default)]
+        pub additional_token_data: Vec<TokenData>,
         /// Payload template to process
         pub connector_payload: ConnectorPayload,
         /// HTTP connection configuration
@@ -120,6 +244,8 @@ pub mod models {
         pub specific_token_data: SecretSerdeValue,
         /// The type of vault connector being used for token retrieval
         pub vault_connector: VaultConnectors,
+        /// Alias by which the template refers to this token, if any
+        pub alias: Option<String>,
     }
 
     impl From<TokenData> for DomainTokenData {
@@ -127,6 +253,7 @@ pub mod models {
             Self {
                 specific_token_data: token_data.specific_token_data,
                 vault_connector: token_data.vault_connector,
+                alias: token_data.alias,
             }
         }
     }
@@ -199,6 +326,8 @@ pub mod models {
     pub struct DomainInjectorRequest {
         /// Token data retrieved from the vault for replacement
         pub token_data: DomainTokenData,
+        /// Data of the other tokens referenced by the template
+        pub additional_token_data: Vec<DomainTokenData>,
         /// Payload template containing token references to be processed
         pub connector_payload: DomainConnectorPayload,
         /// HTTP connection configuration for making the external request
@@ -209,6 +338,11 @@ pub mod models {
         fn from(request: InjectorRequest) -> Self {
             Self {
                 token_data: request.token_data.into(),
+                additional_token_data: request
+                    .additional_token_data
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
                 connector_payload: request.connector_payload.into(),
                 connection_config: request.connection_config.into(),
             }
@@ -0,0 +1,206 @@
+pub mod backends {
+    #[cfg(any(test, feature = "in_memory_vault"))]
+    use std::{
+        collections::HashMap,
+        sync::{Arc, RwLock},
+    };
+
+    use async_trait::async_trait;
+    use common_utils::request::{Method, RequestBuilder, RequestContent};
+    use error_stack::ResultExt;
+    use masking::ExposeInterface;
+    use router_env::{instrument, logger, tracing};
+    use serde_json::Value;
+
+    use crate::{send_request, DomainTokenData, HttpDetokenizeConfig, InjectorError, Proxy};
+
+    /// Resolves the data of a vault token into the values which templates refer to
+    ///
+    /// Each vault connector has its own resolver, so that vaults which hand out tokens in place
+    /// of the sensitive values can be detokenized before the template is processed.
+    #[async_trait]
+    pub trait VaultTokenResolver: Send + Sync {
+        /// Returns the vault data of the token, a JSON object of field names to values
+        async fn resolve_token_data(
+            &self,
+            token_data: &DomainTokenData,
+        ) -> error_stack::Result<Value, InjectorError>;
+    }
+
+    /// Resolver for VGS, whose token data is used as is since VGS replaces the aliases in the
+    /// outgoing request itself
+    #[derive(Debug, Default)]
+    pub struct VgsTokenResolver;
+
+    #[async_trait]
+    impl VaultTokenResolver for VgsTokenResolver {
+        async fn resolve_token_data(
+            &self,
+            token_data: &DomainTokenData,
+        ) -> error_stack::Result<Value, InjectorError> {
+            Ok(token_data.specific_token_data.clone().expose())
+        }
+    }
+
+    /// Resolver for the Hyperswitch vault, whose token data is the data retrieved from the
+    /// vault, optionally nested under `data` as returned by the vault
+    #[derive(Debug, Default)]
+    pub struct HyperswitchVaultTokenResolver;
+
+    #[async_trait]
+    impl VaultTokenResolver for HyperswitchVaultTokenResolver {
+        async fn resolve_token_data(
+            &self,
+            token_data: &DomainTokenData,
+        ) -> error_stack::Result<Value, InjectorError> {
+            let vault_data = token_data.specific_token_data.clone().expose();
+            Ok(unwrap_data_field(vault_data))
+        }
+    }
+
+    /// Resolver for self-hosted vaults, which detokenizes the token data by calling the HTTP
+    /// detokenize endpoint of the vault configured in the merchant connector account
+    #[derive(Debug)]
+    pub struct HttpDetokenizeTokenResolver<'a> {
+        config: &'a HttpDetokenizeConfig,
+    }
+
+    impl<'a> HttpDetokenizeTokenResolver<'a> {
+        pub fn new(config: &'a HttpDetokenizeConfig) -> Self {
+            Self { config }
+        }
+    }
+
+    #[async_trait]
+    impl VaultTokenResolver for HttpDetokenizeTokenResolver<'_> {
+        #[instrument(skip_all)]
+        async fn resolve_token_data(
+            &self,
+            token_data: &DomainTokenData,
+        ) -> error_stack::Result<Value, InjectorError> {
+            let config = self.config;
+            let headers = config
+                .headers
+                .clone()
+                .into_iter()
+                .map(|(key, value)| (key, masking::Maskable::new_masked(value)))
+                .collect::<Vec<_>>();
+
+            let request = RequestBuilder::new()
+                .method(Method::Post)
+                .url(config.url.as_str())
+                .headers(headers)
+                .set_body(RequestContent::Json(Box::new(
+                    token_data.specific_token_data.clone().expose(),
+                )))
+                .build();
+
+            logger::debug!("Sending detokenize request to the vault");
+            let response = send_request(&Proxy::default(), request, None).await?;
+
+            let status_code = response.status();
+            if !status_code.is_success() {
+                return Err(error_stack::Report::new(
+                    InjectorError::TokenResolutionFailed(format!(
+                        "Detokenize request failed with status code {}",
+                        status_code.as_u16()
+                    )),
+                ));
+            }
+
+            let vault_data = response.json::<Value>().await.change_context(
+                InjectorError::TokenResolutionFailed(
+                    "Failed to parse the detokenize response".to_string(),
+                ),
+            )?;
+
+            Ok(unwrap_data_field(vault_data))
+        }
+    }
+
+    /// Vault holding its tokens in memory, meant for tests
+    ///
+    /// The token data of a request is a JSON object of field names to tokens, each of which is
+    /// replaced by the value stored for it.
+    #[cfg(any(test, feature = "in_memory_vault"))]
+    #[derive(Clone, Debug, Default)]
+    pub struct InMemoryVault {
+        tokens: Arc<RwLock<HashMap<String, Value>>>,
+    }
+
+    #[cfg(any(test, feature = "in_memory_vault"))]
+    impl InMemoryVault {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Stores the value under the token, replacing any value stored before
+        pub fn insert_token(&self, token: impl Into<String>, value: Value) {
+            if let Ok(mut tokens) = self.tokens.write() {
+                tokens.insert(token.into(), value);
+            }
+        }
+
+        /// Removes the token, returning the value stored for it
+        pub fn remove_token(&self, token: &str) -> Option<Value> {
+            self.tokens
+                .write()
+                .ok()
+                .and_then(|mut tokens| tokens.remove(token))
+        }
+
+        fn get_token(&self, token: &str) -> Option<Value> {
+            self.tokens
+                .read()
+                .ok()
+                .and_then(|tokens| tokens.get(token).cloned())
+        }
+    }
+
+    #[cfg(any(test, feature = "in_memory_vault"))]
+    #[async_trait]
+    impl VaultTokenResolver for InMemoryVault {
+        async fn resolve_token_data(
+            &self,
+            token_data: &DomainTokenData,
+        ) -> error_stack::Result<Value, InjectorError> {
+            match token_data.specific_token_data.clone().expose() {
+                Value::Object(fields) => fields
+                    .into_iter()
+                    .map(|(field, token)| match token {
+                        Value::String(token) => match self.get_token(&token) {
+                            Some(value) => Ok((field, value)),
+                            None => Err(error_stack::Report::new(
+                                InjectorError::TokenResolutionFailed(format!(
+                                    "Token not found for field '{field}'"
+                                )),
+                            )),
+                        },
+                        value => Ok((field, value)),
+                    })
+                    .collect::<error_stack::Result<serde_json::Map<_, _>, InjectorError>>()
+                    .map(Value::Object),
+                _ => Err(error_stack::Report::new(
+                    InjectorError::TokenResolutionFailed(
+                        "Token data is not a valid JSON object".to_string(),
+                    ),
+                )),
+            }
+        }
+    }
+
+    /// Returns the object nested under `data` when it is the only field of the vault data
+    fn unwrap_data_field(vault_data: Value) -> Value {
+        match vault_data {
+            Value::Object(mut obj)
+                if obj.len() == 1 && obj.get("data").is_some_and(Value::is_object) =>
+            {
+                obj.remove("data").unwrap_or_default()
+            }
+            other => other,
+        }
+    }
+}
+
+// Re-export all items
+pub use backends::*;
@@ -57,6 +57,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
+        (name = "Subscriptions", description = "Create and manage recurring subscriptions, their prices and invoices"),
+        (name = "Revenue Recovery", description = "Retry failed merchant initiated payments on a smart schedule"),
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
@@ -82,6 +84,12 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::reusable_payment_link_create,
+        routes::payment_link::reusable_payment_link_retrieve,
+        routes::payment_link::reusable_payment_link_update,
+        routes::payment_link::reusable_payment_link_list,
+        routes::payment_link::reusable_payment_link_analytics,
+        routes::payment_link::reusable_payment_link_qr_code,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
@@ -97,6 +105,34 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_update,
         routes::refunds::refunds_list,
 
+        // Routes for subscriptions
+        routes::subscriptions::subscription_product_create,
+        routes::subscriptions::subscription_product_retrieve,
+        routes::subscriptions::subscription_product_update,
+        routes::subscriptions::subscription_product_list,
+        routes::subscriptions::subscription_price_create,
+        routes::subscriptions::subscription_price_retrieve,
+        routes::subscriptions::subscription_price_update,
+        routes::subscriptions::subscription_price_list,
+        routes::subscriptions::subscription_create,
+        routes::subscriptions::subscription_retrieve,
+        routes::subscriptions::subscription_update,
+        routes::subscriptions::subscription_cancel,
+        routes::subscriptions::subscription_list,
+        routes::subscriptions::subscription_invoice_list,
+        routes::subscriptions::subscription_invoice_retrieve,
+
+        // Routes for revenue recovery enrollments
+        routes::recovery_enrollments::recovery_enrollment_create,
+        routes::recovery_enrollments::recovery_enrollment_retrieve,
+        routes::recovery_enrollments::recovery_enrollment_cancel,
+        routes::recovery_enrollments::recovery_enrollment_list,
+
+        // Routes for account updater
+        routes::account_updater::account_updater_enable,
+        routes::account_updater::account_updater_disable,
+        routes::account_updater::payment_method_account_updates_list,
+
         // Routes for Organization
         routes::organization::organization_create,
         routes::organization::organization_retrieve,
@@ -108,6 +144,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::merchant_account_kv_migration_create,
+        routes::merchant_account::merchant_account_kv_migration_retrieve,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::connector_create,
@@ -133,6 +171,11 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::list_customer_duplicate_groups,
+        routes::customers::sync_customer_blind_indexes,
+        routes::customers::retrieve_customer_duplicates,
+        routes::customers::merge_customers,
+        routes::customers::list_customer_merges,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -150,10 +193,17 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
         routes::profile::profile_delete,
+        routes::profile::velocity_rules_upsert,
+        routes::profile::velocity_rules_retrieve,
+        routes::profile::velocity_rules_delete,
+        routes::profile::dispute_automation_config_upsert,
+        routes::profile::dispute_automation_config_retrieve,
+        routes::profile::dispute_automation_config_delete,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
         routes::disputes::retrieve_disputes_list,
+        routes::disputes::add_dispute_financial_record,
 
         // Routes for routing
         routes::routing::routing_create_config,
@@ -191,6 +241,11 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payout_batch_create,
+        routes::payouts::payout_batch_upload,
+        routes::payouts::payout_batch_retrieve,
+        routes::payouts::payout_batch_items_list,
+        routes::payouts::payout_batch_results,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -272,6 +327,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::three_ds_decision_rule::CustomerDeviceData,
         api_models::three_ds_decision_rule::IssuerData,
         api_models::three_ds_decision_rule::AcquirerData,
+        api_models::velocity_rules::VelocityRuleKey,
+        api_models::velocity_rules::VelocityRuleMetric,
+        api_models::velocity_rules::VelocityRuleAction,
+        api_models::velocity_rules::VelocityRule,
+        api_models::velocity_rules::VelocityRulesRequest,
+        api_models::velocity_rules::VelocityRulesResponse,
+        api_models::velocity_rules::TriggeredVelocityRule,
+        api_models::velocity_rules::VelocityCheckResponse,
         api_models::refunds::RefundRequest,
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
@@ -296,6 +359,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerUpdateRequest,
         api_models::customers::CustomerDeleteResponse,
+        api_models::customers::CustomerDuplicate,
+        api_models::customers::CustomerDuplicatesResponse,
+        api_models::customers::CustomerDuplicateGroup,
+        api_models::customers::CustomerDuplicateGroupListResponse,
+        api_models::customers::CustomerBlindIndexSyncResponse,
+        api_models::customers::CustomerMergeRequest,
+        api_models::customers::CustomerMergeResponse,
+        api_models::customers::CustomerMergeListResponse,
+        api_models::enums::CustomerBlindIndexField,
+        api_models::enums::CustomerMergeStatus,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodResponse,
         api_models::payment_methods::CustomerPaymentMethod,
@@ -404,6 +477,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::disputes::DisputeFinancialRecordRequest,
+        api_models::disputes::DisputeEvidenceTemplate,
+        api_models::disputes::EvidenceMetadataKeys,
+        api_models::disputes::DisputeAutoAcceptRule,
+        api_models::disputes::DisputeAutomationRules,
+        api_models::disputes::DisputeAutomationConfig,
+        api_models::disputes::DisputeAutomationConfigResponse,
+        common_types::disputes::DisputeFinancialRecord,
+        common_types::disputes::DisputeFinancialRecordType,
+        common_types::disputes::DisputeFinancialRecordSource,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -628,6 +711,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::RelayType,
         api_models::relay::RelayData,
         api_models::relay::RelayRefundRequestData,
+        api_models::relay::RelayCaptureRequestData,
+        api_models::relay::RelayVoidRequestData,
+        api_models::relay::RelayIncrementalAuthorizationRequestData,
+        api_models::relay::RelaySyncRequestData,
         api_models::enums::RelayStatus,
         api_models::relay::RelayError,
         api_models::payments::AmountFilter,
@@ -672,14 +759,56 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutLinkResponse,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchItemListConstraints,
+        api_models::payouts::PayoutBatchItemResponse,
+        api_models::payouts::PayoutBatchItemListResponse,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
+        api_models::analytics::metric_alerts::MetricAlertDetails,
+        api_models::subscriptions::SubscriptionProductCreateRequest,
+        api_models::subscriptions::SubscriptionProductUpdateRequest,
+        api_models::subscriptions::SubscriptionProductResponse,
+        api_models::subscriptions::SubscriptionProductListResponse,
+        api_models::subscriptions::SubscriptionPriceCreateRequest,
+        api_models::subscriptions::SubscriptionPriceUpdateRequest,
+        api_models::subscriptions::SubscriptionPriceResponse,
+        api_models::subscriptions::SubscriptionPriceListResponse,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionUpdateRequest,
+        api_models::subscriptions::SubscriptionCancelRequest,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::subscriptions::SubscriptionListResponse,
+        api_models::subscriptions::InvoiceResponse,
+        api_models::subscriptions::InvoiceListResponse,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::InvoiceStatus,
+        api_models::enums::InvoiceBillingReason,
+        api_models::enums::BillingInterval,
+        api_models::enums::ProrationBehavior,
+        api_models::recovery_enrollments::RecoveryEnrollmentCreateRequest,
+        api_models::recovery_enrollments::RecoveryEnrollmentResponse,
+        api_models::recovery_enrollments::RecoveryAttemptResponse,
+        api_models::recovery_enrollments::RecoveryEnrollmentListResponse,
+        api_models::enums::RecoveryEnrollmentStatus,
+        api_models::enums::RecoveryAttemptStatus,
+        api_models::account_updater::AccountUpdaterRequest,
+        api_models::account_updater::AccountUpdaterResponse,
+        api_models::account_updater::PaymentMethodAccountUpdateResponse,
+        api_models::account_updater::PaymentMethodAccountUpdateListResponse,
+        api_models::enums::CardAccountUpdateStatus,
+        api_models::analytics::metric_alerts::AlertMetric,
+        api_models::analytics::metric_alerts::AlertCondition,
+        api_models::analytics::metric_alerts::AlertComparison,
         api_models::enums::EventClass,
         api_models::enums::EventType,
         api_models::enums::DecoupledAuthenticationType,
@@ -689,6 +818,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
+        api_models::admin::KvMigrationRequest,
+        api_models::admin::KvMigrationResponse,
+        api_models::admin::KvMigrationStatus,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
@@ -749,6 +881,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ast::NumberComparison,
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
+        api_models::payments::ReusablePaymentLinkCreateRequest,
+        api_models::payments::ReusablePaymentLinkUpdateRequest,
+        api_models::payments::ReusablePaymentLinkResponse,
+        api_models::payments::ReusablePaymentLinkStatus,
+        api_models::payments::ReusablePaymentLinkListResponse,
+        api_models::payments::ReusablePaymentLinkAnalyticsResponse,
+        api_models::payments::QrCodeImageFormat,
+        api_models::enums::ReusablePaymentLinkAmountType,
         api_models::blocklist::BlocklistRequest,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
@@ -357,6 +357,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        common_types::disputes::DisputeFinancialRecord,
+        common_types::disputes::DisputeFinancialRecordType,
+        common_types::disputes::DisputeFinancialRecordSource,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -659,6 +662,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
+        api_models::analytics::metric_alerts::MetricAlertDetails,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::subscriptions::InvoiceResponse,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::InvoiceStatus,
+        api_models::enums::InvoiceBillingReason,
+        api_models::analytics::metric_alerts::AlertMetric,
+        api_models::analytics::metric_alerts::AlertCondition,
+        api_models::analytics::metric_alerts::AlertComparison,
         api_models::enums::EventClass,
         api_models::enums::EventType,
         api_models::enums::DecoupledAuthenticationType,
@@ -803,6 +815,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ProcessTrackerStatus,
         api_models::proxy::ProxyRequest,
         api_models::proxy::ProxyResponse,
+        api_models::proxy::ProxyToken,
+        api_models::proxy::ProxyContentType,
+        api_models::proxy::ProxyResponseTokenization,
+        api_models::proxy::ProxyTokenizedField,
         api_models::proxy::TokenType,
         routes::payments::ForceSync,
         api_models::tokenization::GenericTokenizationRequest,
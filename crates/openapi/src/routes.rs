@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+pub mod account_updater;
 pub mod api_keys;
 pub mod authentication;
 pub mod blocklist;
@@ -19,10 +20,12 @@ pub mod poll;
 pub mod profile;
 pub mod profile_acquirer;
 pub mod proxy;
+pub mod recovery_enrollments;
 pub mod refunds;
 pub mod relay;
 pub mod revenue_recovery;
 pub mod routing;
+pub mod subscriptions;
 pub mod three_ds_decision_rule;
 pub mod tokenization;
 pub mod webhook_events;
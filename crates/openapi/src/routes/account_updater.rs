@@ -0,0 +1,56 @@
+/// Account Updater - Enable
+///
+/// To periodically check the stored cards of the merchant for reissued card numbers, updated
+/// expiry dates and closed accounts through a connector. Cards nearing their expiry are sent to
+/// the connector in batches, and the updates it returns are applied to the stored cards
+#[utoipa::path(
+    post,
+    path = "/account_updater/enable",
+    request_body = AccountUpdaterRequest,
+    responses(
+        (status = 200, description = "Account updater enabled", body = AccountUpdaterResponse),
+        (status = 400, description = "The merchant connector account is not an enabled payment processor"),
+        (status = 404, description = "Merchant connector account does not exist")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Enable the Account Updater",
+    security(("api_key" = []))
+)]
+pub async fn account_updater_enable() {}
+
+/// Account Updater - Disable
+///
+/// To stop checking the stored cards of the merchant for updates through a connector
+#[utoipa::path(
+    post,
+    path = "/account_updater/disable",
+    request_body = AccountUpdaterRequest,
+    responses(
+        (status = 200, description = "Account updater disabled", body = AccountUpdaterResponse),
+        (status = 404, description = "Merchant connector account does not exist")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Disable the Account Updater",
+    security(("api_key" = []))
+)]
+pub async fn account_updater_disable() {}
+
+/// Payment Method - Account Updates
+///
+/// To list the updates of a stored card received from the connectors, latest first
+#[utoipa::path(
+    get,
+    path = "/payment_methods/{payment_method_id}/account_updates",
+    params(
+        ("payment_method_id" = String, Path, description = "The identifier for the payment method"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of updates to include in the response"),
+    ),
+    responses(
+        (status = 200, description = "Account updates retrieved", body = PaymentMethodAccountUpdateListResponse),
+        (status = 404, description = "Payment method does not exist")
+    ),
+    tag = "Payment Methods",
+    operation_id = "List the Account Updates of a Payment Method",
+    security(("api_key" = []))
+)]
+pub async fn payment_method_account_updates_list() {}
@@ -108,6 +108,97 @@ pub async fn customers_delete() {}
 #[cfg(feature = "v1")]
 pub async fn customers_list() {}
 
+/// Customers - List Duplicates
+///
+/// Lists the groups of customers sharing the same email or phone number.
+#[utoipa::path(
+    get,
+    path = "/customers/duplicates",
+    params (("offset" = Option<u32>, Query, description = "Offset for pagination"),
+    ("limit" = Option<u16>, Query, description = "Limit for pagination")),
+    responses(
+        (status = 200, description = "Duplicate customers retrieved", body = CustomerDuplicateGroupListResponse),
+    ),
+    tag = "Customers",
+    operation_id = "List Duplicate Customers for a Merchant",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn list_customer_duplicate_groups() {}
+
+/// Customers - Index for Duplicates
+///
+/// Indexes a batch of the existing customers, oldest first, so that they are considered for duplicate detection. Customers created or updated after duplicate detection was enabled are indexed automatically.
+#[utoipa::path(
+    post,
+    path = "/customers/duplicates/index",
+    params (("offset" = Option<u32>, Query, description = "Offset of the batch of customers to index"),
+    ("limit" = Option<u16>, Query, description = "Number of customers to index, at most 1000")),
+    responses(
+        (status = 200, description = "Customers indexed", body = CustomerBlindIndexSyncResponse),
+    ),
+    tag = "Customers",
+    operation_id = "Index Customers for Duplicate Detection",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn sync_customer_blind_indexes() {}
+
+/// Customers - Retrieve Duplicates
+///
+/// Retrieves the customers sharing the email or phone number of a customer.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/duplicates",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Duplicates of the customer retrieved", body = CustomerDuplicatesResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve Duplicates of a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn retrieve_customer_duplicates() {}
+
+/// Customers - Merge
+///
+/// Merges a duplicate customer into this customer, moving over its payment methods, mandates and payments.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/merge",
+    request_body = CustomerMergeRequest,
+    params (("customer_id" = String, Path, description = "The unique identifier for the surviving Customer")),
+    responses(
+        (status = 200, description = "Customers were merged", body = CustomerMergeResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Merge Customers",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn merge_customers() {}
+
+/// Customers - List Merges
+///
+/// Lists the merges in which the customer either survived or was merged into another customer.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/merges",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Merges of the customer retrieved", body = CustomerMergeListResponse),
+    ),
+    tag = "Customers",
+    operation_id = "List Merges of a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn list_customer_merges() {}
+
 /// Customers - Create
 ///
 /// Creates a customer object and stores the customer details to be reused for future payments.
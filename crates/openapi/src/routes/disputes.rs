@@ -70,3 +70,22 @@ pub async fn retrieve_disputes_list() {}
     security(("api_key" = []))
 )]
 pub async fn retrieve_disputes_list_profile() {}
+
+/// Disputes - Add Financial Record
+/// Records a chargeback fee or amount adjustment against a dispute, for connectors which do not share them through webhooks
+#[utoipa::path(
+    post,
+    path = "/disputes/{dispute_id}/financial_records",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    request_body = DisputeFinancialRecordRequest,
+    responses(
+        (status = 200, description = "The financial record was added to the dispute", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Add a Financial Record to a Dispute",
+    security(("api_key" = []))
+)]
+pub async fn add_dispute_financial_record() {}
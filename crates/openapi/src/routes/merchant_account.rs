@@ -274,6 +274,56 @@ pub async fn delete_merchant_account() {}
 )]
 pub async fn merchant_account_kv_status() {}
 
+#[cfg(feature = "v1")]
+/// Merchant Account - Start KV Migration
+///
+/// Migrate the Merchant Account to or from the KV storage scheme. The storage scheme is switched
+/// once the grace period is observed by all the pods, and the migration is completed after the
+/// pending drainer entries of the merchant are applied to Postgres and the grace period ends.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/kv/migration",
+    request_body (
+        content = KvMigrationRequest,
+        examples (
+            ("Migrate Merchant to Postgres" = (
+                value = json!({
+                "kv_enabled": false,
+                "grace_period_in_secs": 300
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Storage scheme migration scheduled for the Merchant Account", body = KvMigrationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found"),
+        (status = 412, description = "A storage scheme migration is already in progress")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Start a storage scheme migration for a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_kv_migration_create() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - KV Migration Status
+///
+/// Retrieve the progress of the latest storage scheme migration of the Merchant Account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/kv/migration",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Storage scheme migration retrieved", body = KvMigrationResponse),
+        (status = 404, description = "No storage scheme migration found for the Merchant Account")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the storage scheme migration of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_kv_migration_retrieve() {}
+
 /// Merchant Connector - List
 ///
 /// List Merchant Connector Details for the merchant
@@ -17,3 +17,117 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Reusable Payment Link - Create
+///
+/// To create a payment link which creates a new payment every time a customer confirms it. The amount can be fixed, or chosen by the customer within an optional range
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable",
+    request_body = ReusablePaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link created", body = ReusablePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_create() {}
+
+/// Reusable Payment Link - Retrieve
+///
+/// To retrieve the properties of a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+    ),
+    responses(
+        (status = 200, description = "Gets details regarding the reusable payment link", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_retrieve() {}
+
+/// Reusable Payment Link - Update
+///
+/// To update the properties of a reusable payment link, or to deactivate it
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable/{reusable_payment_link_id}",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+    ),
+    request_body = ReusablePaymentLinkUpdateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link updated", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Update a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_update() {}
+
+/// Reusable Payment Link - List
+///
+/// To list the reusable payment links of the merchant
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of links to return"),
+        ("offset" = Option<i64>, Query, description = "The number of links to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of reusable payment links", body = ReusablePaymentLinkListResponse),
+    ),
+    tag = "Payments",
+    operation_id = "List Reusable Payment Links",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_list() {}
+
+/// Reusable Payment Link - Analytics
+///
+/// To retrieve the number of visits and payments of a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}/analytics",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+    ),
+    responses(
+        (status = 200, description = "Analytics of the reusable payment link", body = ReusablePaymentLinkAnalyticsResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve Reusable Payment Link Analytics",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_analytics() {}
+
+/// Reusable Payment Link - QR Code
+///
+/// To retrieve a QR code image of the reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{merchant_id}/{reusable_payment_link_id}/qr",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+        ("format" = Option<QrCodeImageFormat>, Query, description = "The image format of the QR code, png by default"),
+    ),
+    responses(
+        (status = 200, description = "QR code image of the reusable payment link"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve Reusable Payment Link QR Code"
+)]
+pub async fn reusable_payment_link_qr_code() {}
@@ -190,3 +190,93 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Batch Create
+///
+/// Creates a batch of payouts, which are processed asynchronously. Items that fail validation are recorded as `invalid` and do not stop the rest of the batch.
+#[utoipa::path(
+    post,
+    path = "/payouts/batches",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid payout batch")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_create() {}
+
+/// Payouts - Batch Upload
+///
+/// Creates a batch of payouts from a CSV file. The file has one payout per row, with columns such as `amount`, `currency`, `customer_id`, `merchant_order_reference_id`, and the payout method details (`card_number`, `iban`, `bank_sort_code`, `bank_routing_number` or `paypal_email` along with their related columns).
+#[utoipa::path(
+    post,
+    path = "/payouts/batches/upload",
+    request_body(content = String, content_type = "multipart/form-data", description = "The CSV file in the `file` field, and optionally the `profile_id`"),
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid payout batch")
+    ),
+    tag = "Payouts",
+    operation_id = "Upload a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_upload() {}
+
+/// Payouts - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_retrieve() {}
+
+/// Payouts - Batch Items List
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{batch_id}/items",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch"),
+        ("status" = Option<PayoutBatchItemStatus>, Query, description = "Only list the items in this status"),
+        ("limit" = Option<u32>, Query, description = "Limit on the number of items to return"),
+        ("offset" = Option<u32>, Query, description = "The number of items to skip when listing")
+    ),
+    responses(
+        (status = 200, description = "Payout batch items listed", body = PayoutBatchItemListResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List the items of a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_items_list() {}
+
+/// Payouts - Batch Results
+///
+/// Downloads a CSV file with the status, payout id and error of each item of the batch.
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{batch_id}/results",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch results file", content_type = "text/csv", body = String),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Download the results of a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_results() {}
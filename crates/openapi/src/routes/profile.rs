@@ -90,6 +90,177 @@ pub async fn profile_update() {}
 )]
 pub async fn profile_retrieve() {}
 
+#[cfg(feature = "v1")]
+/// Profile - Upsert Velocity Rules
+///
+/// Create or replace the velocity rules evaluated for payments confirmed under the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/velocity_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = VelocityRulesRequest,
+        examples(
+            (
+                "Block cards with more than 3 declines in an hour" = (
+                    value = json!({
+                        "rules": [{
+                            "name": "declines_per_card",
+                            "key": "card_fingerprint",
+                            "metric": "decline_count",
+                            "threshold": 3,
+                            "window_in_secs": 3600,
+                            "action": "block"
+                        }]
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Velocity Rules Updated", body = VelocityRulesResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert Velocity Rules for a Profile",
+    security(("api_key" = []))
+)]
+pub async fn velocity_rules_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Velocity Rules
+///
+/// Retrieve the velocity rules configured for the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/velocity_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Velocity Rules Retrieved", body = VelocityRulesResponse),
+        (status = 404, description = "Velocity rules are not configured for the profile")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve Velocity Rules of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn velocity_rules_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Velocity Rules
+///
+/// Delete the velocity rules configured for the *profile*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/velocity_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Velocity Rules Deleted"),
+        (status = 404, description = "Velocity rules are not configured for the profile")
+    ),
+    tag = "Profile",
+    operation_id = "Delete Velocity Rules of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn velocity_rules_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Dispute Automation Config
+///
+/// Create or replace the dispute evidence template and the rules for accepting disputes or submitting evidence automatically for the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/dispute_automation",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = DisputeAutomationConfig,
+        examples(
+            (
+                "Accept disputes up to 10 USD and submit evidence 2 days before due date" = (
+                    value = json!({
+                        "evidence_template": {
+                            "metadata_keys": {
+                                "shipping_carrier": "carrier",
+                                "shipping_tracking_number": "tracking_number"
+                            },
+                            "include_customer_details": true,
+                            "include_shipping_address": true,
+                            "include_authentication_results": true,
+                            "refund_policy": "file_123"
+                        },
+                        "rules": {
+                            "auto_accept": [{
+                                "currency": "USD",
+                                "max_amount": 1000
+                            }],
+                            "auto_submit_days_before_due": 2
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Dispute Automation Config Updated", body = DisputeAutomationConfigResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert Dispute Automation Config for a Profile",
+    security(("api_key" = []))
+)]
+pub async fn dispute_automation_config_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Dispute Automation Config
+///
+/// Retrieve the dispute evidence template and automation rules configured for the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/dispute_automation",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Dispute Automation Config Retrieved", body = DisputeAutomationConfigResponse),
+        (status = 404, description = "Dispute automation is not configured for the profile")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve Dispute Automation Config of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn dispute_automation_config_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Dispute Automation Config
+///
+/// Delete the dispute evidence template and automation rules configured for the *profile*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/dispute_automation",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Dispute Automation Config Deleted"),
+        (status = 404, description = "Dispute automation is not configured for the profile")
+    ),
+    tag = "Profile",
+    operation_id = "Delete Dispute Automation Config of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn dispute_automation_config_delete() {}
+
 // ******************************************** Common profile routes ******************************************** //
 
 /// Profile - Delete
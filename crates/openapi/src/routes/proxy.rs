@@ -36,12 +36,42 @@
                     "token_type": "PaymentMethodId",
                     "method": "POST"
                 })
+            ),
+            "Create a form proxy request with multiple tokens" = (
+                value = json!({
+                    "request_body": {
+                        "card[number]": "{{$card.card_number}}",
+                        "card[cvc]": "{{$card.card_cvc}}",
+                        "network_token": "{{$network.network_token}}",
+                        "amount": "6540"
+                    },
+                    "destination_url": "https://api.example.com/payments",
+                    "headers": {
+                        "Authorization": "Bearer sk_test_example"
+                    },
+                    "token": "pm_0196ea5a42a67583863d5b1253d62931",
+                    "token_type": "payment_method_id",
+                    "token_alias": "card",
+                    "additional_tokens": [
+                        {
+                            "token": "12345_tok_01926c58bc6e77c09e809964e72af8c8",
+                            "token_type": "tokenization_id",
+                            "alias": "network"
+                        }
+                    ],
+                    "content_type": "form_url_encoded",
+                    "response_tokenization": {
+                        "fields": ["/source/fingerprint"]
+                    },
+                    "method": "POST"
+                })
             )
         ))
     ),
     responses(
         (status = 200, description = "Proxy request", body = ProxyResponse),
-        (status = 400, description = "Invalid data")
+        (status = 400, description = "Invalid data"),
+        (status = 403, description = "Destination host is not allowed for the profile")
     ),
     params(
         ("X-Profile-Id" = String, Header, description = "Profile ID for authentication"),
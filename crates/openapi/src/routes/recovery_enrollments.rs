@@ -0,0 +1,77 @@
+/// Recovery Enrollment - Create
+///
+/// To enroll a failed merchant initiated payment for revenue recovery. The payment is retried
+/// off-session on a schedule computed from its decline category, the expiry of its card and the
+/// hours at which past retries succeeded
+#[utoipa::path(
+    post,
+    path = "/recovery_enrollments",
+    request_body = RecoveryEnrollmentCreateRequest,
+    responses(
+        (status = 200, description = "Payment enrolled for recovery", body = RecoveryEnrollmentResponse),
+        (status = 400, description = "The payment cannot be recovered")
+    ),
+    tag = "Revenue Recovery",
+    operation_id = "Create a Recovery Enrollment",
+    security(("api_key" = []))
+)]
+pub async fn recovery_enrollment_create() {}
+
+/// Recovery Enrollment - Retrieve
+///
+/// To retrieve a revenue recovery enrollment along with its retries
+#[utoipa::path(
+    get,
+    path = "/recovery_enrollments/{enrollment_id}",
+    params(
+        ("enrollment_id" = String, Path, description = "The identifier for the recovery enrollment"),
+    ),
+    responses(
+        (status = 200, description = "Recovery enrollment retrieved", body = RecoveryEnrollmentResponse),
+        (status = 404, description = "Recovery enrollment does not exist")
+    ),
+    tag = "Revenue Recovery",
+    operation_id = "Retrieve a Recovery Enrollment",
+    security(("api_key" = []))
+)]
+pub async fn recovery_enrollment_retrieve() {}
+
+/// Recovery Enrollment - Cancel
+///
+/// To stop retrying an enrolled payment
+#[utoipa::path(
+    post,
+    path = "/recovery_enrollments/{enrollment_id}/cancel",
+    params(
+        ("enrollment_id" = String, Path, description = "The identifier for the recovery enrollment"),
+    ),
+    responses(
+        (status = 200, description = "Recovery enrollment cancelled", body = RecoveryEnrollmentResponse),
+        (status = 400, description = "The recovery enrollment is already completed"),
+        (status = 404, description = "Recovery enrollment does not exist")
+    ),
+    tag = "Revenue Recovery",
+    operation_id = "Cancel a Recovery Enrollment",
+    security(("api_key" = []))
+)]
+pub async fn recovery_enrollment_cancel() {}
+
+/// Recovery Enrollment - List
+///
+/// To list the revenue recovery enrollments of the merchant, optionally filtered by status
+#[utoipa::path(
+    get,
+    path = "/recovery_enrollments/list",
+    params(
+        ("status" = Option<RecoveryEnrollmentStatus>, Query, description = "The status of the recovery enrollments"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of recovery enrollments to return"),
+        ("offset" = Option<i64>, Query, description = "The number of recovery enrollments to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of recovery enrollments", body = RecoveryEnrollmentListResponse)
+    ),
+    tag = "Revenue Recovery",
+    operation_id = "List Recovery Enrollments",
+    security(("api_key" = []))
+)]
+pub async fn recovery_enrollment_list() {}
@@ -0,0 +1,290 @@
+/// Subscription Product - Create
+///
+/// To create a product which can be subscribed to through its prices
+#[utoipa::path(
+    post,
+    path = "/subscriptions/products",
+    request_body = SubscriptionProductCreateRequest,
+    responses(
+        (status = 200, description = "Subscription product created", body = SubscriptionProductResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription Product",
+    security(("api_key" = []))
+)]
+pub async fn subscription_product_create() {}
+
+/// Subscription Product - Retrieve
+///
+/// To retrieve a subscription product
+#[utoipa::path(
+    get,
+    path = "/subscriptions/products/{product_id}",
+    params(
+        ("product_id" = String, Path, description = "The identifier for the subscription product"),
+    ),
+    responses(
+        (status = 200, description = "Subscription product retrieved", body = SubscriptionProductResponse),
+        (status = 404, description = "Subscription product does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription Product",
+    security(("api_key" = []))
+)]
+pub async fn subscription_product_retrieve() {}
+
+/// Subscription Product - Update
+///
+/// To update the details of a subscription product, or to deactivate it
+#[utoipa::path(
+    post,
+    path = "/subscriptions/products/{product_id}",
+    params(
+        ("product_id" = String, Path, description = "The identifier for the subscription product"),
+    ),
+    request_body = SubscriptionProductUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription product updated", body = SubscriptionProductResponse),
+        (status = 404, description = "Subscription product does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription Product",
+    security(("api_key" = []))
+)]
+pub async fn subscription_product_update() {}
+
+/// Subscription Product - List
+///
+/// To list the subscription products of the merchant
+#[utoipa::path(
+    get,
+    path = "/subscriptions/products/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of products to return"),
+        ("offset" = Option<i64>, Query, description = "The number of products to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of subscription products", body = SubscriptionProductListResponse)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscription Products",
+    security(("api_key" = []))
+)]
+pub async fn subscription_product_list() {}
+
+/// Subscription Price - Create
+///
+/// To create a recurring price of a subscription product
+#[utoipa::path(
+    post,
+    path = "/subscriptions/prices",
+    request_body = SubscriptionPriceCreateRequest,
+    responses(
+        (status = 200, description = "Subscription price created", body = SubscriptionPriceResponse),
+        (status = 400, description = "Invalid billing period or amount"),
+        (status = 404, description = "Subscription product does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription Price",
+    security(("api_key" = []))
+)]
+pub async fn subscription_price_create() {}
+
+/// Subscription Price - Retrieve
+///
+/// To retrieve a subscription price
+#[utoipa::path(
+    get,
+    path = "/subscriptions/prices/{price_id}",
+    params(
+        ("price_id" = String, Path, description = "The identifier for the subscription price"),
+    ),
+    responses(
+        (status = 200, description = "Subscription price retrieved", body = SubscriptionPriceResponse),
+        (status = 404, description = "Subscription price does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription Price",
+    security(("api_key" = []))
+)]
+pub async fn subscription_price_retrieve() {}
+
+/// Subscription Price - Update
+///
+/// To update the metadata of a subscription price, or to deactivate it
+#[utoipa::path(
+    post,
+    path = "/subscriptions/prices/{price_id}",
+    params(
+        ("price_id" = String, Path, description = "The identifier for the subscription price"),
+    ),
+    request_body = SubscriptionPriceUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription price updated", body = SubscriptionPriceResponse),
+        (status = 404, description = "Subscription price does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription Price",
+    security(("api_key" = []))
+)]
+pub async fn subscription_price_update() {}
+
+/// Subscription Price - List
+///
+/// To list the prices of a subscription product
+#[utoipa::path(
+    get,
+    path = "/subscriptions/prices/list",
+    params(
+        ("product_id" = String, Query, description = "The identifier for the subscription product"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of prices to return"),
+        ("offset" = Option<i64>, Query, description = "The number of prices to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of subscription prices", body = SubscriptionPriceListResponse)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscription Prices",
+    security(("api_key" = []))
+)]
+pub async fn subscription_price_list() {}
+
+/// Subscription - Create
+///
+/// To subscribe a customer to a price. The first billing period is charged immediately with the saved payment method, unless the subscription starts with a trial
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Missing mandatory fields"),
+        (status = 404, description = "Customer, price or payment method does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_create() {}
+
+/// Subscription - Retrieve
+///
+/// To retrieve a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription"),
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_retrieve() {}
+
+/// Subscription - Update
+///
+/// To change the price, quantity or payment method of a subscription. Price and quantity changes are prorated as per the proration behavior
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription"),
+    ),
+    request_body = SubscriptionUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription updated", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist"),
+        (status = 412, description = "Subscription is cancelled or unpaid")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_update() {}
+
+/// Subscription - Cancel
+///
+/// To cancel a subscription immediately, or at the end of its current billing period
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription"),
+    ),
+    request_body = SubscriptionCancelRequest,
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist"),
+        (status = 412, description = "Subscription is already cancelled")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_cancel() {}
+
+/// Subscription - List
+///
+/// To list the subscriptions of the merchant, optionally filtered by customer
+#[utoipa::path(
+    get,
+    path = "/subscriptions/list",
+    params(
+        ("customer_id" = Option<String>, Query, description = "The identifier for the customer"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of subscriptions to return"),
+        ("offset" = Option<i64>, Query, description = "The number of subscriptions to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of subscriptions", body = SubscriptionListResponse)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscriptions",
+    security(("api_key" = []))
+)]
+pub async fn subscription_list() {}
+
+/// Subscription Invoice - List
+///
+/// To list the invoices of a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}/invoices",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of invoices to return"),
+        ("offset" = Option<i64>, Query, description = "The number of invoices to skip"),
+    ),
+    responses(
+        (status = 200, description = "List of invoices of the subscription", body = InvoiceListResponse),
+        (status = 404, description = "Subscription does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Invoices of a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn subscription_invoice_list() {}
+
+/// Subscription Invoice - Retrieve
+///
+/// To retrieve an invoice of a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/invoices/{invoice_id}",
+    params(
+        ("invoice_id" = String, Path, description = "The identifier for the invoice"),
+    ),
+    responses(
+        (status = 200, description = "Invoice retrieved", body = InvoiceResponse),
+        (status = 404, description = "Invoice does not exist")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve an Invoice",
+    security(("api_key" = []))
+)]
+pub async fn subscription_invoice_retrieve() {}
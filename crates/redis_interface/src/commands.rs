@@ -485,6 +485,18 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_by(
+        &self,
+        key: &RedisKey,
+        increment: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr_by(key.tenant_aware_key(self), increment)
+            .await
+            .change_context(errors::RedisError::IncrementKeyFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_fields<V>(
         &self,
@@ -679,6 +691,18 @@ impl super::RedisConnectionPool {
             .collect())
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_field(
+        &self,
+        key: &RedisKey,
+        field: &str,
+    ) -> CustomResult<DelReply, errors::RedisError> {
+        self.pool
+            .hdel(key.tenant_aware_key(self), field)
+            .await
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field<V>(
         &self,
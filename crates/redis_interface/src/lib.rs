@@ -29,6 +29,7 @@ use fred::{
     interfaces::ClientLike,
     prelude::{EventInterface, TransactionInterface},
 };
+use masking::PeekInterface;
 
 pub use self::types::*;
 
@@ -125,6 +126,32 @@ impl RedisConnectionPool {
         let mut config = fred::types::RedisConfig::from_url(&redis_connection_url)
             .change_context(errors::RedisError::RedisConnectionError)?;
 
+        config.username = conf.username.clone();
+        config.password = conf
+            .password
+            .as_ref()
+            .map(|password| password.peek().clone());
+
+        if let Some(sentinel) = &conf.sentinel {
+            config.server = fred::types::ServerConfig::Sentinel {
+                hosts: sentinel
+                    .get_nodes()?
+                    .into_iter()
+                    .map(|(host, port)| fred::types::Server::new(host, port))
+                    .collect(),
+                service_name: sentinel.service_name.clone(),
+                username: sentinel.username.clone(),
+                password: sentinel
+                    .password
+                    .as_ref()
+                    .map(|password| password.peek().clone()),
+            };
+        }
+
+        if let Some(tls) = &conf.tls {
+            config.tls = Some(get_tls_connector(tls)?.into());
+        }
+
         let perf = fred::types::PerformanceConfig {
             auto_pipeline: conf.auto_pipeline,
             default_command_timeout: std::time::Duration::from_secs(conf.default_command_timeout),
@@ -231,6 +258,97 @@ impl RedisConnectionPool {
     pub fn get_transaction(&self) -> Transaction {
         self.pool.next().multi()
     }
+
+    /// Returns the addresses of the primary nodes the connection pool is connected to. When
+    /// Sentinel is used, this is the primary most recently discovered through the Sentinel nodes.
+    pub async fn get_primary_nodes(&self) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .next()
+            .active_connections()
+            .await
+            .map(|servers| {
+                servers
+                    .into_iter()
+                    .map(|server| format!("{}:{}", server.host, server.port))
+                    .collect()
+            })
+            .change_context(errors::RedisError::RedisConnectionError)
+    }
+}
+
+/// Builds the TLS connector from the configured CA and client certificates, falling back to the
+/// system's root certificates if no CA certificates are configured
+fn get_tls_connector(
+    tls: &RedisTlsSettings,
+) -> CustomResult<fred::types::TlsConnector, errors::RedisError> {
+    fn read_pem_file(path: &str) -> CustomResult<Vec<u8>, errors::RedisError> {
+        std::fs::read(path).change_context(errors::RedisError::InvalidConfiguration(format!(
+            "Failed to read Redis TLS file `{path}`"
+        )))
+    }
+
+    let mut root_store = rustls::RootCertStore::empty();
+    let ca_certs = match &tls.ca_cert_path {
+        Some(ca_cert_path) => rustls_pemfile::certs(&mut read_pem_file(ca_cert_path)?.as_slice())
+            .change_context(errors::RedisError::InvalidConfiguration(
+            "Invalid Redis TLS CA certificates".into(),
+        ))?,
+        None => rustls_native_certs::load_native_certs()
+            .change_context(errors::RedisError::InvalidConfiguration(
+                "Failed to load the system's root certificates".into(),
+            ))?
+            .into_iter()
+            .map(|cert| cert.0)
+            .collect(),
+    };
+    for ca_cert in ca_certs {
+        root_store
+            .add(&rustls::Certificate(ca_cert))
+            .change_context(errors::RedisError::InvalidConfiguration(
+                "Invalid Redis TLS CA certificate".into(),
+            ))?;
+    }
+
+    let config_builder = rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(root_store);
+
+    let client_config = match (&tls.client_cert_path, &tls.client_key_path) {
+        (Some(client_cert_path), Some(client_key_path)) => {
+            let client_certs =
+                rustls_pemfile::certs(&mut read_pem_file(client_cert_path)?.as_slice())
+                    .change_context(errors::RedisError::InvalidConfiguration(
+                        "Invalid Redis TLS client certificate".into(),
+                    ))?
+                    .into_iter()
+                    .map(rustls::Certificate)
+                    .collect();
+            let client_key =
+                rustls_pemfile::read_all(&mut read_pem_file(client_key_path)?.as_slice())
+                    .change_context(errors::RedisError::InvalidConfiguration(
+                        "Invalid Redis TLS client key".into(),
+                    ))?
+                    .into_iter()
+                    .find_map(|item| match item {
+                        rustls_pemfile::Item::PKCS8Key(key)
+                        | rustls_pemfile::Item::RSAKey(key)
+                        | rustls_pemfile::Item::ECKey(key) => Some(rustls::PrivateKey(key)),
+                        _ => None,
+                    })
+                    .ok_or(errors::RedisError::InvalidConfiguration(
+                        "No private key found in the Redis TLS client key file".into(),
+                    ))?;
+
+            config_builder
+                .with_client_auth_cert(client_certs, client_key)
+                .change_context(errors::RedisError::InvalidConfiguration(
+                    "Invalid Redis TLS client certificate or key".into(),
+                ))?
+        }
+        _ => config_builder.with_no_client_auth(),
+    };
+
+    Ok(fred::types::TlsConnector::from(client_config))
 }
 
 pub struct RedisConfig {
@@ -3,6 +3,7 @@
 
 use common_utils::errors::CustomResult;
 use fred::types::RedisValue as FredRedisValue;
+use masking::Secret;
 
 use crate::{errors, RedisConnectionPool};
 
@@ -67,6 +68,60 @@ pub struct RedisSettings {
     pub default_command_timeout: u64,
     pub max_feed_count: u64,
     pub unresponsive_timeout: u64,
+    /// Username used for Redis ACL authentication, the `default` user is used if not specified
+    pub username: Option<String>,
+    /// Password used for Redis authentication, fetched through the secrets manager
+    pub password: Option<Secret<String>>,
+    /// TLS configuration, connections are made over plain TCP if not specified
+    pub tls: Option<RedisTlsSettings>,
+    /// Sentinel configuration, the primary is discovered through the Sentinel nodes instead of
+    /// connecting to `host` and `port` if specified
+    pub sentinel: Option<RedisSentinelSettings>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RedisTlsSettings {
+    /// Path to the PEM encoded CA certificates used to verify the server, the system's root
+    /// certificates are used if not specified
+    pub ca_cert_path: Option<String>,
+    /// Path to the PEM encoded client certificate chain, for mutual TLS
+    pub client_cert_path: Option<String>,
+    /// Path to the PEM encoded private key of the client certificate, for mutual TLS
+    pub client_key_path: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RedisSentinelSettings {
+    /// Name of the monitored primary, as configured in the Sentinel nodes
+    pub service_name: String,
+    /// Addresses of the Sentinel nodes, in the `host:port` format
+    pub nodes: Vec<String>,
+    /// Username used for ACL authentication with the Sentinel nodes
+    pub username: Option<String>,
+    /// Password used for authentication with the Sentinel nodes, fetched through the secrets
+    /// manager
+    pub password: Option<Secret<String>>,
+}
+
+impl RedisSentinelSettings {
+    /// Parses the addresses of the Sentinel nodes
+    pub fn get_nodes(&self) -> CustomResult<Vec<(String, u16)>, errors::RedisError> {
+        self.nodes
+            .iter()
+            .map(|node| {
+                node.rsplit_once(':')
+                    .and_then(|(host, port)| Some((host.to_string(), port.parse().ok()?)))
+                    .ok_or(
+                        errors::RedisError::InvalidConfiguration(format!(
+                            "Invalid Redis Sentinel node address `{node}`, expected `host:port`"
+                        ))
+                        .into(),
+                    )
+            })
+            .collect()
+    }
 }
 
 impl RedisSettings {
@@ -86,6 +141,39 @@ impl RedisSettings {
             ))
         })?;
 
+        if let Some(sentinel) = &self.sentinel {
+            when(self.cluster_enabled, || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel` cannot be specified if `cluster_enabled` is `true`".into(),
+                ))
+            })?;
+
+            when(sentinel.service_name.is_default_or_empty(), || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel.service_name` must be specified".into(),
+                ))
+            })?;
+
+            when(sentinel.nodes.is_empty(), || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel.nodes` must be specified".into(),
+                ))
+            })?;
+
+            sentinel.get_nodes()?;
+        }
+
+        if let Some(tls) = &self.tls {
+            when(
+                tls.client_cert_path.is_some() != tls.client_key_path.is_some(),
+                || {
+                    Err(errors::RedisError::InvalidConfiguration(
+                        "Redis `tls.client_cert_path` and `tls.client_key_path` must be specified together".into(),
+                    ))
+                },
+            )?;
+        }
+
         when(
             self.default_command_timeout < self.unresponsive_timeout,
             || {
@@ -118,6 +206,10 @@ impl Default for RedisSettings {
             default_command_timeout: 30,
             max_feed_count: 200,
             unresponsive_timeout: 10,
+            username: None,
+            password: None,
+            tls: None,
+            sentinel: None,
         }
     }
 }
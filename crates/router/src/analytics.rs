@@ -15,15 +15,19 @@ pub mod routes {
     };
     use api_models::analytics::{
         api_event::QueryType,
+        metric_alerts::{MetricAlertRuleCreateRequest, MetricAlertRuleId},
+        report_schedules::{ReportExportId, ReportScheduleCreateRequest, ReportScheduleId},
         search::{
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
         AnalyticsRequest, GenerateReportRequest, GetActivePaymentsMetricRequest,
         GetApiEventFiltersRequest, GetApiEventMetricRequest, GetAuthEventFilterRequest,
-        GetAuthEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
-        GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
-        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetAuthEventMetricRequest, GetConnectorEventMetricRequest, GetDisputeMetricRequest,
+        GetFrmFilterRequest, GetFrmMetricRequest, GetOutgoingWebhookEventMetricRequest,
+        GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest, GetPaymentIntentMetricRequest,
+        GetPaymentMetricRequest, GetRefundFilterRequest, GetRefundMetricRequest,
+        GetRoutingEventMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest,
+        ReportRequest,
     };
     use common_enums::EntityType;
     use common_utils::types::TimeRange;
@@ -33,7 +37,12 @@ pub mod routes {
     use crate::{
         analytics_validator::request_validator,
         consts::opensearch::SEARCH_INDEXES,
-        core::{api_locking, errors::user::UserErrors, verification::utils},
+        core::{
+            analytics_reports, api_locking,
+            errors::{self, user::UserErrors},
+            metric_alerts,
+            verification::utils,
+        },
         db::{user::UserInterface, user_role::ListUserRolesByUserIdPayload},
         routes::AppState,
         services::{
@@ -153,6 +162,18 @@ pub mod routes {
                             web::resource("filters/api_events")
                                 .route(web::post().to(get_merchant_api_event_filters)),
                         )
+                        .service(
+                            web::resource("metrics/connector_events")
+                                .route(web::post().to(get_merchant_connector_event_metrics)),
+                        )
+                        .service(
+                            web::resource("metrics/outgoing_webhook_events")
+                                .route(web::post().to(get_merchant_outgoing_webhook_event_metrics)),
+                        )
+                        .service(
+                            web::resource("metrics/routing_events")
+                                .route(web::post().to(get_merchant_routing_event_metrics)),
+                        )
                         .service(
                             web::resource("search")
                                 .route(web::post().to(get_global_search_results)),
@@ -239,6 +260,18 @@ pub mod routes {
                                     web::resource("filters/api_events")
                                         .route(web::post().to(get_merchant_api_event_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/connector_events").route(
+                                        web::post().to(get_merchant_connector_event_metrics),
+                                    ),
+                                )
+                                .service(web::resource("metrics/outgoing_webhook_events").route(
+                                    web::post().to(get_merchant_outgoing_webhook_event_metrics),
+                                ))
+                                .service(
+                                    web::resource("metrics/routing_events")
+                                        .route(web::post().to(get_merchant_routing_event_metrics)),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_merchant_dispute_metrics)),
@@ -383,10 +416,43 @@ pub mod routes {
                                     web::resource("outgoing_webhook_event_logs")
                                         .route(web::get().to(get_profile_outgoing_webhook_events)),
                                 )
+                                .service(web::resource("metrics/outgoing_webhook_events").route(
+                                    web::post().to(get_profile_outgoing_webhook_event_metrics),
+                                ))
+                                .service(
+                                    web::resource("metrics/routing_events")
+                                        .route(web::post().to(get_profile_routing_event_metrics)),
+                                )
                                 .service(
                                     web::resource("report/dispute")
                                         .route(web::post().to(generate_profile_dispute_report)),
                                 )
+                                .service(
+                                    web::resource("report_schedules")
+                                        .route(web::get().to(list_profile_report_schedules))
+                                        .route(web::post().to(create_profile_report_schedule)),
+                                )
+                                .service(
+                                    web::resource("report_schedules/{report_id}")
+                                        .route(web::get().to(retrieve_profile_report_schedule))
+                                        .route(web::delete().to(delete_profile_report_schedule)),
+                                )
+                                .service(
+                                    web::resource(
+                                        "report_schedules/{report_id}/exports/{export_id}",
+                                    )
+                                    .route(web::get().to(download_profile_report_export)),
+                                )
+                                .service(
+                                    web::resource("metric_alerts")
+                                        .route(web::get().to(list_profile_metric_alert_rules))
+                                        .route(web::post().to(create_profile_metric_alert_rule)),
+                                )
+                                .service(
+                                    web::resource("metric_alerts/{rule_id}")
+                                        .route(web::get().to(retrieve_profile_metric_alert_rule))
+                                        .route(web::delete().to(delete_profile_metric_alert_rule)),
+                                )
                                 .service(
                                     web::resource("report/refunds")
                                         .route(web::post().to(generate_profile_refund_report)),
@@ -2362,6 +2428,489 @@ pub mod routes {
         .await
     }
 
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetConnectorEventMetricRequest` element.
+    pub async fn get_merchant_connector_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetConnectorEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetConnectorEventMetricRequest");
+        let flow = AnalyticsFlow::GetConnectorEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state.clone(),
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::connector_events::get_connector_event_metrics(
+                    &state.pool,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetOutgoingWebhookEventMetricRequest` element.
+    pub async fn get_merchant_outgoing_webhook_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetOutgoingWebhookEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetOutgoingWebhookEventMetricRequest");
+        let flow = AnalyticsFlow::GetOutgoingWebhookEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state.clone(),
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::outgoing_webhook_event::get_outgoing_webhook_event_metrics(
+                    &state.pool,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetOutgoingWebhookEventMetricRequest` element.
+    pub async fn get_profile_outgoing_webhook_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetOutgoingWebhookEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetOutgoingWebhookEventMetricRequest");
+        let flow = AnalyticsFlow::GetOutgoingWebhookEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, mut req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                // Restrict the metrics to the profile the user is logged into
+                req.filters.profile_id = vec![profile_id];
+                analytics::outgoing_webhook_event::get_outgoing_webhook_event_metrics(
+                    &state.pool,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetRoutingEventMetricRequest` element.
+    pub async fn get_merchant_routing_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetRoutingEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetRoutingEventMetricRequest");
+        let flow = AnalyticsFlow::GetRoutingEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state.clone(),
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::routing_events::get_routing_event_metrics(
+                    &state.pool,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetRoutingEventMetricRequest` element.
+    pub async fn get_profile_routing_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetRoutingEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetRoutingEventMetricRequest");
+        let flow = AnalyticsFlow::GetRoutingEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, mut req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                // Restrict the metrics to the profile the user is logged into
+                req.filters.profile_id = vec![profile_id];
+                analytics::routing_events::get_routing_event_metrics(
+                    &state.pool,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn create_profile_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<ReportScheduleCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateReportSchedule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                analytics_reports::create_report_schedule(
+                    state,
+                    auth.merchant_account.get_id().clone(),
+                    profile_id,
+                    req,
+                )
+                .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_profile_report_schedules(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListReportSchedules;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                analytics_reports::list_report_schedules(state, profile_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn retrieve_profile_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveReportSchedule;
+        let payload = ReportScheduleId {
+            report_id: path.into_inner(),
+        };
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                analytics_reports::retrieve_report_schedule(state, profile_id, req.report_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn delete_profile_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteReportSchedule;
+        let payload = ReportScheduleId {
+            report_id: path.into_inner(),
+        };
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                analytics_reports::delete_report_schedule(
+                    state,
+                    auth.merchant_account.get_id().clone(),
+                    profile_id,
+                    req.report_id,
+                )
+                .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn download_profile_report_export(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<(String, String)>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReportExport;
+        let (report_id, export_id) = path.into_inner();
+        let payload = ReportExportId {
+            report_id,
+            export_id,
+        };
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                analytics_reports::download_report_export(
+                    state,
+                    auth.merchant_account.get_id().clone(),
+                    profile_id,
+                    req,
+                )
+                .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn create_profile_metric_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<MetricAlertRuleCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateMetricAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                metric_alerts::create_metric_alert_rule(
+                    state,
+                    auth.merchant_account.get_id().clone(),
+                    profile_id,
+                    req,
+                )
+                .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_profile_metric_alert_rules(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListMetricAlertRules;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                metric_alerts::list_metric_alert_rules(state, profile_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn retrieve_profile_metric_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveMetricAlertRule;
+        let payload = MetricAlertRuleId {
+            rule_id: path.into_inner(),
+        };
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                metric_alerts::retrieve_metric_alert_rule(state, profile_id, req.rule_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn delete_profile_metric_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteMetricAlertRule;
+        let payload = MetricAlertRuleId {
+            rule_id: path.into_inner(),
+        };
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+                metric_alerts::delete_metric_alert_rule(
+                    state,
+                    auth.merchant_account.get_id().clone(),
+                    profile_id,
+                    req.rule_id,
+                )
+                .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_merchant_api_event_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -1,2 +1,5 @@
+#[cfg(feature = "adyen")]
+pub mod adyen;
+#[cfg(feature = "stripe")]
 pub mod stripe;
 pub mod wrap;
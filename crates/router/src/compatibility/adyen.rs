@@ -0,0 +1,17 @@
+pub mod app;
+pub mod errors;
+pub mod payments;
+pub mod webhooks;
+use actix_web::{web, Scope};
+
+use crate::routes;
+
+pub struct AdyenApis;
+
+impl AdyenApis {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/adyen/v71")
+            .service(app::Payments::server(state.clone()))
+            .service(app::Webhooks::server(state))
+    }
+}
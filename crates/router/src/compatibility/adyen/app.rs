@@ -0,0 +1,34 @@
+use actix_web::{web, Scope};
+
+use super::{payments::*, webhooks::*};
+use crate::routes::{self, webhooks};
+
+pub struct Payments;
+
+impl Payments {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/payments")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payments_create)))
+            .service(web::resource("/details").route(web::post().to(payments_details)))
+            .service(
+                web::resource("/{payment_id}/captures").route(web::post().to(payments_capture)),
+            )
+            .service(web::resource("/{payment_id}/refunds").route(web::post().to(payments_refund)))
+            .service(web::resource("/{payment_id}/cancels").route(web::post().to(payments_cancel)))
+    }
+}
+
+pub struct Webhooks;
+
+impl Webhooks {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/webhooks")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("/{merchant_id}/{connector_name}")
+                    .route(web::post().to(webhooks::receive_incoming_webhook::<AdyenNotification>))
+                    .route(web::get().to(webhooks::receive_incoming_webhook::<AdyenNotification>)),
+            )
+    }
+}
@@ -0,0 +1,84 @@
+use common_utils::errors::ErrorSwitch;
+use hyperswitch_domain_models::errors::api_error_response as errors;
+use serde::Serialize;
+
+/// Error response in the shape of the Adyen Checkout API service exceptions
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenErrorResponse {
+    pub status: u16,
+    pub error_code: String,
+    pub message: String,
+    pub error_type: AdyenErrorType,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdyenErrorType {
+    Validation,
+    Security,
+    Configuration,
+    Internal,
+}
+
+impl std::fmt::Display for AdyenErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).unwrap_or_else(|_| self.message.clone())
+        )
+    }
+}
+
+impl std::error::Error for AdyenErrorResponse {}
+
+impl actix_web::ResponseError for AdyenErrorResponse {
+    fn status_code(&self) -> reqwest::StatusCode {
+        reqwest::StatusCode::from_u16(self.status)
+            .unwrap_or(reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        use actix_web::http::header;
+
+        actix_web::HttpResponseBuilder::new(self.status_code())
+            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
+            .body(self.to_string())
+    }
+}
+
+impl From<errors::ApiErrorResponse> for AdyenErrorResponse {
+    fn from(value: errors::ApiErrorResponse) -> Self {
+        let api_error: api_models::errors::types::ApiErrorResponse = value.switch();
+        let status = actix_web::ResponseError::status_code(&api_error);
+        let error = api_models::errors::types::ErrorResponse::from(&api_error);
+        let error_type = match status {
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                AdyenErrorType::Security
+            }
+            status if status.is_server_error() => AdyenErrorType::Internal,
+            _ => match value {
+                errors::ApiErrorResponse::MerchantConnectorAccountNotFound { .. }
+                | errors::ApiErrorResponse::MerchantConnectorAccountDisabled
+                | errors::ApiErrorResponse::ConfigNotFound => AdyenErrorType::Configuration,
+                _ => AdyenErrorType::Validation,
+            },
+        };
+
+        Self {
+            status: status.as_u16(),
+            error_code: error.code,
+            message: error.message,
+            error_type,
+        }
+    }
+}
+
+impl ErrorSwitch<AdyenErrorResponse> for errors::ApiErrorResponse {
+    fn switch(&self) -> AdyenErrorResponse {
+        self.clone().into()
+    }
+}
+
+impl crate::services::EmbedError for error_stack::Report<AdyenErrorResponse> {}
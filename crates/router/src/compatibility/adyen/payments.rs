@@ -0,0 +1,453 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::{payments as payment_types, refunds as refund_types};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow, Tag};
+
+use crate::{
+    compatibility::{adyen::errors, wrap},
+    core::{
+        api_locking::{self, GetLockingInput},
+        errors::{ApiErrorResponse, RouterResponse},
+        payments, refunds,
+    },
+    logger,
+    routes::{self, payments::get_or_generate_payment_id, SessionState},
+    services::{self, api, authentication as auth},
+    types::{api as api_types, domain},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate))]
+pub async fn payments_create(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<types::AdyenPaymentRequest>,
+) -> HttpResponse {
+    let payload = json_payload.into_inner();
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let mut create_payment_req = payment_types::PaymentsRequest::from(payload);
+
+    if let Err(err) = get_or_generate_payment_id(&mut create_payment_req) {
+        return api::log_and_return_error_response(err);
+    }
+
+    let flow = Flow::PaymentsCreate;
+    let locking_action = create_payment_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenPaymentResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payment_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            let eligible_connectors = req.connector.clone();
+            payments::payments_core::<
+                api_types::Authorize,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Authorize>,
+            >(
+                state,
+                req_state,
+                merchant_context,
+                None,
+                payments::PaymentCreate,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                eligible_connectors,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+/// Adyen submits the result of a redirect here; the outcome is fetched from the connector
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieveForceSync, payment_id))]
+pub async fn payments_details(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<types::AdyenPaymentDetailsRequest>,
+) -> HttpResponse {
+    let payload = json_payload.into_inner();
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let Some(payment_id) = payload.payment_data else {
+        return api::log_and_return_error_response(report!(errors::AdyenErrorResponse::from(
+            ApiErrorResponse::MissingRequiredField {
+                field_name: "paymentData"
+            }
+        )));
+    };
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let sync_payment_req = payment_types::PaymentsRetrieveRequest {
+        resource_id: api_types::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: None,
+        force_sync: true,
+        connector: None,
+        param: None,
+        merchant_connector_details: None,
+        client_secret: None,
+        expand_attempts: None,
+        expand_captures: None,
+        all_keys_required: None,
+    };
+
+    let flow = Flow::PaymentsRetrieveForceSync;
+    let locking_action = sync_payment_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenPaymentResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        sync_payment_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::payments_core::<
+                api_types::PSync,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::PSync>,
+            >(
+                state,
+                req_state,
+                merchant_context,
+                None,
+                payments::PaymentStatus,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCapture, payment_id))]
+pub async fn payments_capture(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: web::Json<types::AdyenModificationRequest>,
+) -> HttpResponse {
+    let payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let capture_req = payment_types::PaymentsCaptureRequest {
+        payment_id,
+        amount_to_capture: payload.get_amount(),
+        ..Default::default()
+    };
+
+    let flow = Flow::PaymentsCapture;
+    let locking_action = capture_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenModificationResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        capture_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            capture_payment(
+                state,
+                req_state,
+                merchant_context,
+                req,
+                payload.reference.clone(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+async fn capture_payment(
+    state: SessionState,
+    req_state: routes::app::ReqState,
+    merchant_context: domain::MerchantContext,
+    req: payment_types::PaymentsCaptureRequest,
+    reference: Option<String>,
+) -> RouterResponse<types::AdyenModificationResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let amount_to_capture = req.amount_to_capture;
+
+    let response = Box::pin(payments::payments_core::<
+        api_types::Capture,
+        api_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::Capture>,
+    >(
+        state,
+        req_state,
+        merchant_context,
+        None,
+        payments::PaymentCapture,
+        req,
+        api::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    .get_json_body()
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected response from payments core")?;
+
+    Ok(services::ApplicationResponse::Json(
+        types::AdyenModificationResponse {
+            merchant_account: merchant_id,
+            psp_reference: response.payment_id.get_string_repr().to_owned(),
+            amount: Some(types::AdyenResponseAmount {
+                value: amount_to_capture
+                    .unwrap_or(response.amount_capturable)
+                    .get_amount_as_i64(),
+                currency: response.currency,
+            }),
+            payment_psp_reference: response.payment_id,
+            reference,
+            status: "received",
+        },
+    ))
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancel, payment_id))]
+pub async fn payments_cancel(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: web::Json<types::AdyenModificationRequest>,
+) -> HttpResponse {
+    let payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let cancel_req = payment_types::PaymentsCancelRequest {
+        payment_id,
+        ..Default::default()
+    };
+
+    let flow = Flow::PaymentsCancel;
+    let locking_action = cancel_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenModificationResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        cancel_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            cancel_payment(
+                state,
+                req_state,
+                merchant_context,
+                req,
+                payload.reference.clone(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+async fn cancel_payment(
+    state: SessionState,
+    req_state: routes::app::ReqState,
+    merchant_context: domain::MerchantContext,
+    req: payment_types::PaymentsCancelRequest,
+    reference: Option<String>,
+) -> RouterResponse<types::AdyenModificationResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+
+    let response = Box::pin(payments::payments_core::<
+        api_types::Void,
+        api_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api_types::Void>,
+    >(
+        state,
+        req_state,
+        merchant_context,
+        None,
+        payments::PaymentCancel,
+        req,
+        api::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    .get_json_body()
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected response from payments core")?;
+
+    Ok(services::ApplicationResponse::Json(
+        types::AdyenModificationResponse {
+            merchant_account: merchant_id,
+            psp_reference: response.payment_id.get_string_repr().to_owned(),
+            payment_psp_reference: response.payment_id,
+            reference,
+            status: "received",
+            amount: None,
+        },
+    ))
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCreate, payment_id))]
+pub async fn payments_refund(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: web::Json<types::AdyenModificationRequest>,
+) -> HttpResponse {
+    let payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let refund_req = payload.get_refund_request(payment_id);
+
+    let flow = Flow::RefundsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenModificationResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        refund_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_payment(state, merchant_context, req, payload.reference.clone())
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+async fn refund_payment(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: refund_types::RefundRequest,
+    reference: Option<String>,
+) -> RouterResponse<types::AdyenModificationResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+
+    let response = refunds::refund_create_core(state, merchant_context, None, req)
+        .await?
+        .get_json_body()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Unexpected response from refunds core")?;
+
+    Ok(services::ApplicationResponse::Json(
+        types::AdyenModificationResponse {
+            merchant_account: merchant_id,
+            payment_psp_reference: response.payment_id,
+            psp_reference: response.refund_id,
+            reference,
+            status: "received",
+            amount: Some(types::AdyenResponseAmount {
+                value: response.amount.get_amount_as_i64(),
+                currency: response.currency,
+            }),
+        },
+    ))
+}
@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+
+use api_models::{payments, refunds};
+use common_utils::{id_type, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+
+use crate::{events::api_logs::ApiEventMetric, pii::Email, types::api::enums as api_enums};
+
+/// Key of the refund metadata in which the Adyen `reference` of a refund is stored
+pub const MERCHANT_REFERENCE_METADATA_KEY: &str = "merchant_reference";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AdyenAmount {
+    pub currency: api_enums::Currency,
+    pub value: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentRequest {
+    pub amount: AdyenAmount,
+    pub reference: String,
+    pub merchant_account: Option<String>,
+    pub payment_method: AdyenPaymentMethod,
+    pub return_url: Option<url::Url>,
+    pub shopper_reference: Option<id_type::CustomerId>,
+    pub shopper_email: Option<Email>,
+    pub shopper_name: Option<AdyenShopperName>,
+    pub telephone_number: Option<masking::Secret<String>>,
+    pub billing_address: Option<AdyenAddress>,
+    pub delivery_address: Option<AdyenAddress>,
+    pub shopper_interaction: Option<AdyenShopperInteraction>,
+    pub store_payment_method: Option<bool>,
+    pub shopper_statement: Option<String>,
+    pub additional_data: Option<HashMap<String, String>>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AdyenPaymentMethod {
+    Scheme(AdyenCard),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCard {
+    pub number: cards::CardNumber,
+    pub expiry_month: masking::Secret<String>,
+    pub expiry_year: masking::Secret<String>,
+    pub cvc: masking::Secret<String>,
+    pub holder_name: Option<masking::Secret<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenShopperName {
+    pub first_name: Option<masking::Secret<String>>,
+    pub last_name: Option<masking::Secret<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAddress {
+    pub city: Option<String>,
+    pub country: Option<api_enums::CountryAlpha2>,
+    pub house_number_or_name: Option<masking::Secret<String>>,
+    pub postal_code: Option<masking::Secret<String>>,
+    pub state_or_province: Option<masking::Secret<String>>,
+    pub street: Option<masking::Secret<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum AdyenShopperInteraction {
+    Ecommerce,
+    ContAuth,
+    Moto,
+    #[serde(rename = "POS")]
+    Pos,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentDetailsRequest {
+    pub payment_data: Option<id_type::PaymentId>,
+    pub details: Option<HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenModificationRequest {
+    pub amount: Option<AdyenAmount>,
+    pub merchant_account: Option<String>,
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenResponseAmount {
+    pub currency: String,
+    pub value: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentResponse {
+    pub psp_reference: id_type::PaymentId,
+    pub result_code: AdyenResultCode,
+    pub amount: AdyenResponseAmount,
+    pub merchant_reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<AdyenAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal_reason_code: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum AdyenResultCode {
+    Authorised,
+    Refused,
+    Cancelled,
+    Pending,
+    Received,
+    RedirectShopper,
+    Error,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAction {
+    #[serde(rename = "type")]
+    pub action_type: &'static str,
+    pub url: String,
+    pub method: &'static str,
+    /// The `pspReference` of the payment, which is passed back in `/payments/details`
+    pub payment_data: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenModificationResponse {
+    pub merchant_account: id_type::MerchantId,
+    pub payment_psp_reference: id_type::PaymentId,
+    pub psp_reference: String,
+    pub reference: Option<String>,
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<AdyenResponseAmount>,
+}
+
+impl ApiEventMetric for AdyenModificationResponse {}
+
+impl From<AdyenAddress> for payments::AddressDetails {
+    fn from(address: AdyenAddress) -> Self {
+        Self {
+            city: address.city,
+            country: address.country,
+            line1: address.street,
+            line2: address.house_number_or_name,
+            line3: None,
+            zip: address.postal_code,
+            state: address.state_or_province,
+            first_name: None,
+            last_name: None,
+            origin_zip: None,
+        }
+    }
+}
+
+impl From<AdyenCard> for payments::Card {
+    fn from(card: AdyenCard) -> Self {
+        Self {
+            card_number: card.number,
+            card_exp_month: card.expiry_month,
+            card_exp_year: card.expiry_year,
+            card_holder_name: card.holder_name,
+            card_cvc: card.cvc,
+            card_issuer: None,
+            card_network: None,
+            bank_code: None,
+            card_issuing_country: None,
+            card_type: None,
+            nick_name: None,
+        }
+    }
+}
+
+impl From<AdyenPaymentRequest> for payments::PaymentsRequest {
+    fn from(item: AdyenPaymentRequest) -> Self {
+        let is_manual_capture = item
+            .additional_data
+            .as_ref()
+            .and_then(|additional_data| additional_data.get("manualCapture"))
+            .is_some_and(|manual_capture| manual_capture == "true");
+
+        let (first_name, last_name) = item
+            .shopper_name
+            .map(|name| (name.first_name, name.last_name))
+            .unwrap_or_default();
+
+        let billing = payments::Address {
+            address: item
+                .billing_address
+                .map(|address| payments::AddressDetails {
+                    first_name,
+                    last_name,
+                    ..address.into()
+                }),
+            phone: item
+                .telephone_number
+                .clone()
+                .map(|number| payments::PhoneDetails {
+                    number: Some(number),
+                    country_code: None,
+                }),
+            email: item.shopper_email.clone(),
+        };
+
+        let AdyenPaymentMethod::Scheme(card) = item.payment_method;
+
+        Self {
+            amount: Some(MinorUnit::new(item.amount.value).into()),
+            currency: Some(item.amount.currency),
+            merchant_order_reference_id: Some(item.reference),
+            confirm: Some(true),
+            capture_method: Some(if is_manual_capture {
+                api_enums::CaptureMethod::Manual
+            } else {
+                api_enums::CaptureMethod::Automatic
+            }),
+            customer_id: item.shopper_reference,
+            email: item.shopper_email,
+            phone: item.telephone_number,
+            return_url: item.return_url,
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            payment_method_data: Some(payments::PaymentMethodDataRequest {
+                payment_method_data: Some(payments::PaymentMethodData::Card(card.into())),
+                billing: Some(billing.clone()),
+            }),
+            billing: Some(billing),
+            shipping: item.delivery_address.map(|address| payments::Address {
+                address: Some(address.into()),
+                phone: None,
+                email: None,
+            }),
+            off_session: item
+                .shopper_interaction
+                .map(|interaction| matches!(interaction, AdyenShopperInteraction::ContAuth)),
+            setup_future_usage: item
+                .store_payment_method
+                .filter(|store_payment_method| *store_payment_method)
+                .map(|_| api_enums::FutureUsage::OffSession),
+            statement_descriptor_name: item.shopper_statement,
+            metadata: item.metadata,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<api_enums::IntentStatus> for AdyenResultCode {
+    fn from(status: api_enums::IntentStatus) -> Self {
+        match status {
+            api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture => Self::Authorised,
+            api_enums::IntentStatus::Failed => Self::Refused,
+            api_enums::IntentStatus::Cancelled
+            | api_enums::IntentStatus::CancelledPostCapture
+            | api_enums::IntentStatus::Expired => Self::Cancelled,
+            api_enums::IntentStatus::Processing => Self::Received,
+            api_enums::IntentStatus::RequiresCustomerAction => Self::RedirectShopper,
+            api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::RequiresPaymentMethod
+            | api_enums::IntentStatus::RequiresConfirmation => Self::Pending,
+            api_enums::IntentStatus::Conflicted => Self::Error,
+        }
+    }
+}
+
+impl From<payments::PaymentsResponse> for AdyenPaymentResponse {
+    fn from(resp: payments::PaymentsResponse) -> Self {
+        let action = match resp.next_action {
+            Some(payments::NextActionData::RedirectToUrl { redirect_to_url })
+            | Some(payments::NextActionData::RedirectInsidePopup {
+                popup_url: redirect_to_url,
+                ..
+            }) => Some(AdyenAction {
+                action_type: "redirect",
+                url: redirect_to_url,
+                method: "GET",
+                payment_data: resp.payment_id.get_string_repr().to_owned(),
+            }),
+            _ => None,
+        };
+        let result_code = match (AdyenResultCode::from(resp.status), &action) {
+            (AdyenResultCode::RedirectShopper, None) => AdyenResultCode::Pending,
+            (result_code, _) => result_code,
+        };
+
+        Self {
+            psp_reference: resp.payment_id,
+            result_code,
+            amount: AdyenResponseAmount {
+                currency: resp.currency,
+                value: resp.amount.get_amount_as_i64(),
+            },
+            merchant_reference: resp.merchant_order_reference_id,
+            action,
+            refusal_reason: resp.unified_message.or(resp.error_message),
+            refusal_reason_code: resp.unified_code.or(resp.error_code),
+        }
+    }
+}
+
+impl AdyenModificationRequest {
+    pub fn get_amount(&self) -> Option<MinorUnit> {
+        self.amount
+            .as_ref()
+            .map(|amount| MinorUnit::new(amount.value))
+    }
+
+    pub fn get_refund_request(&self, payment_id: id_type::PaymentId) -> refunds::RefundRequest {
+        refunds::RefundRequest {
+            payment_id,
+            amount: self.get_amount(),
+            refund_type: Some(refunds::RefundType::Instant),
+            metadata: self.reference.as_ref().map(|reference| {
+                masking::Secret::new(serde_json::json!({
+                    MERCHANT_REFERENCE_METADATA_KEY: reference
+                }))
+            }),
+            ..Default::default()
+        }
+    }
+}
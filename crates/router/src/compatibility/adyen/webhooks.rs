@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+
+use api_models::{
+    enums::EventType,
+    webhooks::{self as api},
+};
+use base64::Engine;
+use common_utils::{
+    crypto::SignMessage,
+    ext_traits::Encode,
+    types::{AmountConvertor, StringMinorUnitForConnector},
+};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{env, logger};
+use serde::Serialize;
+
+use super::payments::types::MERCHANT_REFERENCE_METADATA_KEY;
+use crate::{
+    consts,
+    core::{
+        errors,
+        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+    },
+    headers,
+    services::request::Maskable,
+};
+
+/// Key of `additionalData` in which the HMAC signature of a notification item is sent
+const HMAC_SIGNATURE_KEY: &str = "hmacSignature";
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNotification {
+    live: &'static str,
+    notification_items: Vec<AdyenNotificationItem>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AdyenNotificationItem {
+    #[serde(rename = "NotificationRequestItem")]
+    notification_request_item: AdyenNotificationRequestItem,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNotificationRequestItem {
+    additional_data: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amount: Option<AdyenNotificationAmount>,
+    event_code: &'static str,
+    event_date: String,
+    merchant_account_code: String,
+    merchant_reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_reference: Option<String>,
+    psp_reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    success: &'static str,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AdyenNotificationAmount {
+    currency: String,
+    value: i64,
+}
+
+impl AdyenNotificationRequestItem {
+    /// Builds the payload signed by Adyen, i.e. the colon separated values of
+    /// `pspReference`, `originalReference`, `merchantAccountCode`, `merchantReference`,
+    /// `value`, `currency`, `eventCode` and `success`
+    fn get_signature_payload(&self) -> String {
+        let (value, currency) = self
+            .amount
+            .as_ref()
+            .map(|amount| (amount.value.to_string(), amount.currency.as_str()))
+            .unwrap_or_default();
+
+        [
+            self.psp_reference.as_str(),
+            self.original_reference.as_deref().unwrap_or_default(),
+            self.merchant_account_code.as_str(),
+            self.merchant_reference.as_str(),
+            value.as_str(),
+            currency,
+            self.event_code,
+            self.success,
+        ]
+        .join(":")
+    }
+}
+
+impl OutgoingWebhookType for AdyenNotification {
+    fn get_outgoing_webhooks_signature(
+        &self,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
+        let payment_response_hash_key = payment_response_hash_key
+            .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
+            .attach_printable("For adyen compatibility payment_response_hash_key is mandatory")?;
+
+        let hmac_key = hex::decode(payment_response_hash_key.as_ref())
+            .change_context(errors::WebhooksFlowError::MerchantConfigInvalid)
+            .attach_printable(
+                "For adyen compatibility payment_response_hash_key must be a hex encoded HMAC key",
+            )?;
+
+        let mut notification = self.clone();
+        let mut signature = None;
+        for item in notification.notification_items.iter_mut() {
+            let item = &mut item.notification_request_item;
+            let item_signature = consts::BASE64_ENGINE.encode(
+                common_utils::crypto::HmacSha256::sign_message(
+                    &common_utils::crypto::HmacSha256,
+                    &hmac_key,
+                    item.get_signature_payload().as_bytes(),
+                )
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+                .attach_printable("Failed to sign the message")?,
+            );
+            item.additional_data
+                .insert(HMAC_SIGNATURE_KEY.to_string(), item_signature.clone());
+            signature = Some(item_signature);
+        }
+
+        let webhook_signature_payload = notification
+            .encode_to_string_of_json()
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("failed encoding outgoing webhook payload")?;
+
+        Ok(OutgoingWebhookPayloadWithSignature {
+            payload: webhook_signature_payload.into(),
+            signature,
+        })
+    }
+
+    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+        header.push((
+            headers::ADYEN_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
+            signature.into(),
+        ))
+    }
+}
+
+/// Returns the Adyen event code and the value of the `success` field for an event type
+pub fn get_adyen_event_code(event_type: EventType) -> (&'static str, bool) {
+    match event_type {
+        EventType::PaymentSucceeded
+        | EventType::PaymentAuthorized
+        | EventType::PaymentPartiallyAuthorized => ("AUTHORISATION", true),
+        EventType::PaymentFailed => ("AUTHORISATION", false),
+        EventType::PaymentCaptured => ("CAPTURE", true),
+        EventType::PaymentCancelled => ("CANCELLATION", true),
+        EventType::PaymentCancelledPostCapture => ("CANCEL_OR_REFUND", true),
+        EventType::PaymentExpired => ("EXPIRE", true),
+        EventType::PaymentProcessing | EventType::ActionRequired => ("PENDING", true),
+        EventType::RefundSucceeded => ("REFUND", true),
+        EventType::RefundFailed => ("REFUND_FAILED", true),
+        EventType::DisputeOpened => ("NOTIFICATION_OF_CHARGEBACK", true),
+        EventType::DisputeChallenged => ("INFORMATION_SUPPLIED", true),
+        EventType::DisputeLost | EventType::DisputeAccepted | EventType::DisputeExpired => {
+            ("CHARGEBACK", true)
+        }
+        EventType::DisputeWon | EventType::DisputeCancelled => ("CHARGEBACK_REVERSED", true),
+        EventType::MandateActive => ("RECURRING_CONTRACT", true),
+        EventType::MandateRevoked => ("RECURRING_CONTRACT", false),
+        EventType::PayoutSuccess => ("PAYOUT_THIRDPARTY", true),
+        EventType::PayoutFailed | EventType::PayoutCancelled => ("PAYOUT_DECLINE", true),
+        EventType::PayoutExpired => ("PAYOUT_EXPIRE", true),
+        EventType::PayoutReversed => ("PAID_OUT_REVERSED", true),
+        EventType::PayoutInitiated | EventType::PayoutProcessing => ("PENDING", true),
+
+        // the below is not really adyen compatible because adyen doesn't provide this
+        EventType::MetricAlertTriggered => ("METRIC_ALERT_TRIGGERED", true),
+        EventType::SubscriptionCreated => ("SUBSCRIPTION_CREATED", true),
+        EventType::SubscriptionUpdated => ("SUBSCRIPTION_UPDATED", true),
+        EventType::SubscriptionPastDue => ("SUBSCRIPTION_UPDATED", false),
+        EventType::SubscriptionCancelled => ("SUBSCRIPTION_CANCELLED", true),
+        EventType::InvoiceCreated => ("INVOICE_CREATED", true),
+        EventType::InvoicePaid => ("INVOICE_PAID", true),
+        EventType::InvoicePaymentFailed => ("INVOICE_PAID", false),
+    }
+}
+
+/// The fields of a notification item that depend on the webhook content
+struct AdyenNotificationContent {
+    psp_reference: String,
+    original_reference: Option<String>,
+    merchant_reference: String,
+    amount: Option<AdyenNotificationAmount>,
+    reason: Option<String>,
+}
+
+impl From<api::OutgoingWebhookContent> for AdyenNotificationContent {
+    fn from(value: api::OutgoingWebhookContent) -> Self {
+        match value {
+            api::OutgoingWebhookContent::PaymentDetails(payment) => {
+                let payment = *payment;
+                let psp_reference = payment.payment_id.get_string_repr().to_owned();
+                Self {
+                    merchant_reference: payment
+                        .merchant_order_reference_id
+                        .unwrap_or_else(|| psp_reference.clone()),
+                    psp_reference,
+                    original_reference: None,
+                    amount: Some(AdyenNotificationAmount {
+                        currency: payment.currency,
+                        value: payment.amount.get_amount_as_i64(),
+                    }),
+                    reason: payment.unified_message.or(payment.error_message),
+                }
+            }
+            api::OutgoingWebhookContent::RefundDetails(refund) => {
+                let refund = *refund;
+                Self {
+                    merchant_reference: refund
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.peek().get(MERCHANT_REFERENCE_METADATA_KEY))
+                        .and_then(|reference| reference.as_str())
+                        .map(ToOwned::to_owned)
+                        .unwrap_or_else(|| refund.refund_id.clone()),
+                    psp_reference: refund.refund_id,
+                    original_reference: Some(refund.payment_id.get_string_repr().to_owned()),
+                    amount: Some(AdyenNotificationAmount {
+                        currency: refund.currency,
+                        value: refund.amount.get_amount_as_i64(),
+                    }),
+                    reason: refund.error_message,
+                }
+            }
+            api::OutgoingWebhookContent::DisputeDetails(dispute) => Self {
+                psp_reference: dispute.payment_id.get_string_repr().to_owned(),
+                original_reference: None,
+                amount: StringMinorUnitForConnector
+                    .convert_back(dispute.amount, dispute.currency)
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to convert dispute amount to minor unit")
+                    })
+                    .ok()
+                    .map(|amount| AdyenNotificationAmount {
+                        currency: dispute.currency.to_string(),
+                        value: amount.get_amount_as_i64(),
+                    }),
+                merchant_reference: dispute.dispute_id,
+                reason: dispute.connector_reason,
+            },
+            api::OutgoingWebhookContent::MandateDetails(mandate) => Self {
+                psp_reference: mandate.mandate_id,
+                original_reference: None,
+                merchant_reference: mandate.payment_method_id,
+                amount: None,
+                reason: None,
+            },
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutDetails(payout) => {
+                let payout = *payout;
+                let psp_reference = payout.payout_id.get_string_repr().to_owned();
+                Self {
+                    merchant_reference: payout
+                        .merchant_order_reference_id
+                        .unwrap_or_else(|| psp_reference.clone()),
+                    psp_reference,
+                    original_reference: None,
+                    amount: Some(AdyenNotificationAmount {
+                        currency: payout.currency.to_string(),
+                        value: payout.amount.get_amount_as_i64(),
+                    }),
+                    reason: payout.error_message,
+                }
+            }
+            api::OutgoingWebhookContent::MetricAlertDetails(alert) => Self {
+                merchant_reference: alert.alert_id.clone(),
+                psp_reference: alert.alert_id,
+                original_reference: None,
+                amount: None,
+                reason: alert.error_message,
+            },
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => Self {
+                merchant_reference: subscription.subscription_id.clone(),
+                psp_reference: subscription.subscription_id,
+                original_reference: subscription.latest_invoice_id,
+                amount: None,
+                reason: None,
+            },
+            api::OutgoingWebhookContent::InvoiceDetails(invoice) => {
+                let invoice = *invoice;
+                Self {
+                    merchant_reference: invoice.invoice_id.clone(),
+                    psp_reference: invoice.invoice_id,
+                    original_reference: invoice
+                        .payment_id
+                        .map(|payment_id| payment_id.get_string_repr().to_owned()),
+                    amount: Some(AdyenNotificationAmount {
+                        currency: invoice.currency.to_string(),
+                        value: invoice.amount.get_amount_as_i64(),
+                    }),
+                    reason: invoice.error_message,
+                }
+            }
+        }
+    }
+}
+
+impl From<api::OutgoingWebhook> for AdyenNotification {
+    fn from(value: api::OutgoingWebhook) -> Self {
+        let (event_code, success) = get_adyen_event_code(value.event_type);
+        let content = AdyenNotificationContent::from(value.content);
+        let event_date = value
+            .timestamp
+            .assume_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_else(|error| {
+                logger::error!(
+                    %error,
+                    "incorrect value for `webhook.timestamp` provided {}", value.timestamp
+                );
+                value.timestamp.to_string()
+            });
+
+        Self {
+            live: if matches!(env::which(), env::Env::Production) {
+                "true"
+            } else {
+                "false"
+            },
+            notification_items: vec![AdyenNotificationItem {
+                notification_request_item: AdyenNotificationRequestItem {
+                    additional_data: HashMap::from([("eventId".to_string(), value.event_id)]),
+                    amount: content.amount,
+                    event_code,
+                    event_date,
+                    merchant_account_code: value.merchant_id.get_string_repr().to_owned(),
+                    merchant_reference: content.merchant_reference,
+                    original_reference: content.original_reference,
+                    psp_reference: content.psp_reference,
+                    reason: content.reason,
+                    success: if success { "true" } else { "false" },
+                },
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_notification_request_item() -> AdyenNotificationRequestItem {
+        AdyenNotificationRequestItem {
+            additional_data: HashMap::new(),
+            amount: Some(AdyenNotificationAmount {
+                currency: "EUR".to_string(),
+                value: 1130,
+            }),
+            event_code: "REFUND",
+            event_date: "2024-01-01T00:00:00Z".to_string(),
+            merchant_account_code: "merchant_123".to_string(),
+            merchant_reference: "order_1".to_string(),
+            original_reference: Some("pay_1".to_string()),
+            psp_reference: "ref_1".to_string(),
+            reason: None,
+            success: "true",
+        }
+    }
+
+    #[test]
+    fn test_signature_payload() {
+        let item = get_notification_request_item();
+        assert_eq!(
+            item.get_signature_payload(),
+            "ref_1:pay_1:merchant_123:order_1:1130:EUR:REFUND:true"
+        );
+
+        let item = AdyenNotificationRequestItem {
+            amount: None,
+            original_reference: None,
+            ..item
+        };
+        assert_eq!(
+            item.get_signature_payload(),
+            "ref_1::merchant_123:order_1:::REFUND:true"
+        );
+    }
+
+    #[test]
+    fn test_signature_requires_hex_encoded_key() {
+        let notification = AdyenNotification {
+            live: "false",
+            notification_items: vec![AdyenNotificationItem {
+                notification_request_item: get_notification_request_item(),
+            }],
+        };
+
+        let signature = notification
+            .get_outgoing_webhooks_signature(Some("44782DEF547AAA06C910C43932B1EB0C"))
+            .unwrap();
+        assert!(signature.signature.is_some());
+
+        let error = notification
+            .get_outgoing_webhooks_signature(Some("not a hex key"))
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::WebhooksFlowError::MerchantConfigInvalid
+        ));
+    }
+}
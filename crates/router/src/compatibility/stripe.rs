@@ -1,6 +1,13 @@
 pub mod app;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod disputes;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod events;
+pub mod pagination;
 pub mod payment_intents;
+#[cfg(feature = "v1")]
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -18,13 +25,16 @@ impl StripeApis {
     pub fn server(state: routes::AppState) -> Scope {
         let max_depth = 10;
         let strict = false;
-        web::scope("/vs/v1")
+        let route = web::scope("/vs/v1")
             .app_data(web::Data::new(serde_qs::Config::new(max_depth, strict)))
             .service(app::SetupIntents::server(state.clone()))
             .service(app::PaymentIntents::server(state.clone()))
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::PaymentMethods::server(state.clone()));
+        #[cfg(feature = "olap")]
+        let route = route.service(app::Events::server(state.clone()));
+        route.service(app::Disputes::server(state))
     }
 }
@@ -1,7 +1,9 @@
 use actix_web::{web, Scope};
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::events::*;
 #[cfg(feature = "v1")]
-use super::{customers::*, payment_intents::*, setup_intents::*};
+use super::{customers::*, disputes::*, payment_intents::*, payment_methods::*, setup_intents::*};
 use super::{refunds::*, webhooks::*};
 use crate::routes::{self, mandates, webhooks};
 
@@ -64,9 +66,12 @@ pub struct Refunds;
 
 impl Refunds {
     pub fn server(config: routes::AppState) -> Scope {
+        let refund_create_or_list = web::resource("").route(web::post().to(refund_create));
+        #[cfg(feature = "olap")]
+        let refund_create_or_list = refund_create_or_list.route(web::get().to(refund_list));
         web::scope("/refunds")
             .app_data(web::Data::new(config))
-            .service(web::resource("").route(web::post().to(refund_create)))
+            .service(refund_create_or_list)
             .service(
                 web::resource("/sync").route(web::post().to(refund_retrieve_with_gateway_creds)),
             )
@@ -117,12 +122,43 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+#[cfg(feature = "v1")]
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::post().to(payment_method_create)))
+            .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)))
             .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
     }
 }
+
+pub struct Disputes;
+
+#[cfg(feature = "v1")]
+impl Disputes {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/disputes")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(dispute_list)))
+            .service(
+                web::resource("/{dispute_id}")
+                    .route(web::get().to(dispute_retrieve))
+                    .route(web::post().to(dispute_update)),
+            )
+            .service(web::resource("/{dispute_id}/close").route(web::post().to(dispute_close)))
+    }
+}
+
+pub struct Events;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Events {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(event_list)))
+    }
+}
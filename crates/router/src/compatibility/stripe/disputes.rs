@@ -0,0 +1,260 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::disputes as dispute_models;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::disputes::DisputeListConstraints;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{
+        stripe::{
+            errors,
+            pagination::{StripeList, StripePagination},
+        },
+        wrap,
+    },
+    core::{
+        api_locking, disputes,
+        errors::{utils::StorageErrorExt, ApiErrorResponse, RouterResponse},
+    },
+    routes::{self, SessionState},
+    services::{self, api, authentication as auth},
+    types::{api::disputes as dispute_types, domain, transformers::ForeignFrom},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+pub async fn dispute_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeDisputeListConstraints>,
+) -> HttpResponse {
+    let payload = query_payload.into_inner();
+    let pagination = match StripePagination::new(payload.limit, payload.starting_after) {
+        Ok(pagination) => pagination,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
+    let dispute_list_req = dispute_models::DisputeListGetConstraints {
+        dispute_id: None,
+        payment_id: payload.payment_intent,
+        limit: Some(u32::from(pagination.get_fetch_limit())),
+        offset: None,
+        profile_id: None,
+        dispute_status: None,
+        dispute_stage: None,
+        reason: None,
+        connector: None,
+        currency: None,
+        merchant_connector_id: None,
+        time_range: None,
+    };
+
+    let flow = Flow::DisputesList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeList<types::StripeDispute>,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        dispute_list_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            list_disputes(state, merchant_context, req, pagination.clone())
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Lists the disputes that come after the `starting_after` dispute, if any, in the descending order
+/// of `(created_at, dispute_id)`
+async fn list_disputes(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: dispute_models::DisputeListGetConstraints,
+    pagination: StripePagination,
+) -> RouterResponse<StripeList<types::StripeDispute>> {
+    let merchant_account = merchant_context.get_merchant_account();
+    let mut constraints = DisputeListConstraints::try_from((req, None))?;
+
+    if let Some(dispute_id) = pagination.starting_after.as_deref() {
+        let cursor = state
+            .store
+            .find_dispute_by_merchant_id_dispute_id(
+                merchant_account.get_id(),
+                dispute_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(ApiErrorResponse::DisputeNotFound {
+                dispute_id: dispute_id.to_string(),
+            })?;
+        constraints.starting_after = Some((cursor.created_at, cursor.dispute_id));
+    }
+
+    let data = state
+        .store
+        .find_disputes_by_constraints(merchant_account.get_id(), &constraints)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve disputes")?
+        .into_iter()
+        .map(|dispute| {
+            types::StripeDispute::try_from(dispute_models::DisputeResponse::foreign_from(dispute))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        pagination.into_list("/v1/disputes", data),
+    ))
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let dispute_retrieve_req = dispute_models::DisputeRetrieveRequest {
+        dispute_id: path.into_inner(),
+        force_sync: None,
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDispute,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        dispute_retrieve_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::retrieve_dispute(state, merchant_context, None, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceSubmit))]
+pub async fn dispute_update(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeDisputeUpdateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let submit_evidence_req =
+        match dispute_models::SubmitEvidenceRequest::try_from((path.into_inner(), payload)) {
+            Ok(req) => req,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    let flow = Flow::DisputesEvidenceSubmit;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDispute,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        submit_evidence_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::submit_evidence(state, merchant_context, None, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_close(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDispute,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        dispute_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::accept_dispute(state, merchant_context, None, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
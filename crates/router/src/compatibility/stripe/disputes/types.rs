@@ -0,0 +1,142 @@
+use api_models::disputes;
+use common_utils::{
+    id_type,
+    types::{AmountConvertor, StringMinorUnitForConnector},
+};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{compatibility::stripe::webhooks::StripeDisputeStatus, core::errors};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StripeDisputeListConstraints {
+    pub payment_intent: Option<id_type::PaymentId>,
+    pub limit: Option<u16>,
+    pub starting_after: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StripeDisputeUpdateRequest {
+    pub evidence: Option<StripeDisputeEvidence>,
+    pub submit: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StripeDisputeEvidence {
+    pub access_activity_log: Option<String>,
+    pub billing_address: Option<String>,
+    pub cancellation_policy: Option<String>,
+    pub cancellation_policy_disclosure: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub customer_communication: Option<String>,
+    pub customer_email_address: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_purchase_ip: Option<String>,
+    pub customer_signature: Option<String>,
+    pub duplicate_charge_documentation: Option<String>,
+    pub product_description: Option<String>,
+    pub receipt: Option<String>,
+    pub refund_policy: Option<String>,
+    pub refund_policy_disclosure: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub service_date: Option<String>,
+    pub service_documentation: Option<String>,
+    pub shipping_address: Option<String>,
+    pub shipping_carrier: Option<String>,
+    pub shipping_date: Option<String>,
+    pub shipping_documentation: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub uncategorized_file: Option<String>,
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeDispute {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: i64,
+    pub currency: String,
+    pub payment_intent: id_type::PaymentId,
+    pub reason: Option<String>,
+    pub status: StripeDisputeStatus,
+    pub created: i64,
+    pub evidence_details: StripeDisputeEvidenceDetails,
+    pub livemode: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+}
+
+impl TryFrom<(String, StripeDisputeUpdateRequest)> for disputes::SubmitEvidenceRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(
+        (dispute_id, item): (String, StripeDisputeUpdateRequest),
+    ) -> Result<Self, Self::Error> {
+        // Evidence is submitted to the connector right away, it cannot be staged for later
+        if item.submit == Some(false) {
+            Err(errors::ApiErrorResponse::NotSupported {
+                message: "Staging dispute evidence with `submit=false`".to_string(),
+            })?
+        }
+        let evidence = item.evidence.unwrap_or_default();
+
+        Ok(Self {
+            dispute_id,
+            access_activity_log: evidence.access_activity_log,
+            billing_address: evidence.billing_address,
+            cancellation_policy: evidence.cancellation_policy,
+            cancellation_policy_disclosure: evidence.cancellation_policy_disclosure,
+            cancellation_rebuttal: evidence.cancellation_rebuttal,
+            customer_communication: evidence.customer_communication,
+            customer_email_address: evidence.customer_email_address,
+            customer_name: evidence.customer_name,
+            customer_purchase_ip: evidence.customer_purchase_ip,
+            customer_signature: evidence.customer_signature,
+            product_description: evidence.product_description,
+            receipt: evidence.receipt,
+            refund_policy: evidence.refund_policy,
+            refund_policy_disclosure: evidence.refund_policy_disclosure,
+            refund_refusal_explanation: evidence.refund_refusal_explanation,
+            service_date: evidence.service_date,
+            service_documentation: evidence.service_documentation,
+            shipping_address: evidence.shipping_address,
+            shipping_carrier: evidence.shipping_carrier,
+            shipping_date: evidence.shipping_date,
+            shipping_documentation: evidence.shipping_documentation,
+            shipping_tracking_number: evidence.shipping_tracking_number,
+            invoice_showing_distinct_transactions: evidence.duplicate_charge_documentation,
+            recurring_transaction_agreement: None,
+            uncategorized_file: evidence.uncategorized_file,
+            uncategorized_text: evidence.uncategorized_text,
+        })
+    }
+}
+
+impl TryFrom<disputes::DisputeResponse> for StripeDispute {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: disputes::DisputeResponse) -> Result<Self, Self::Error> {
+        let amount = StringMinorUnitForConnector
+            .convert_back(item.amount, item.currency)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert dispute amount to minor unit")?;
+
+        Ok(Self {
+            id: item.dispute_id,
+            object: "dispute",
+            amount: amount.get_amount_as_i64(),
+            currency: item.currency.to_string().to_ascii_lowercase(),
+            payment_intent: item.payment_id,
+            reason: item.connector_reason,
+            status: StripeDisputeStatus::from(item.dispute_status),
+            created: item.created_at.assume_utc().unix_timestamp(),
+            evidence_details: StripeDisputeEvidenceDetails {
+                due_by: item
+                    .challenge_required_by
+                    .map(|due_by| due_by.assume_utc().unix_timestamp()),
+            },
+            livemode: false,
+        })
+    }
+}
@@ -0,0 +1,151 @@
+pub mod types;
+
+use std::collections::HashSet;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::webhook_events::{
+    EventListConstraints, EventListItemResponse, EventListRequestInternal,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{
+        stripe::{
+            errors,
+            pagination::{StripeList, StripePagination},
+        },
+        wrap,
+    },
+    core::{
+        api_locking,
+        errors::{utils::StorageErrorExt, ApiErrorResponse, RouterResponse},
+        webhooks::webhook_events,
+    },
+    routes::{self, SessionState},
+    services::{self, api, authentication as auth},
+    types::domain,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventInitialDeliveryAttemptList))]
+pub async fn event_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeEventListConstraints>,
+) -> HttpResponse {
+    let payload = query_payload.into_inner();
+    let pagination = match StripePagination::new(payload.limit, payload.starting_after) {
+        Ok(pagination) => pagination,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
+    let request_internal = EventListRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        constraints: EventListConstraints {
+            created_after: None,
+            created_before: None,
+            limit: Some(pagination.get_fetch_limit()),
+            offset: None,
+            object_id: None,
+            profile_id: None,
+            event_classes: None,
+            event_types: None,
+            is_delivered: payload.delivery_success,
+        },
+    };
+
+    let flow = Flow::WebhookEventInitialDeliveryAttemptList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeList<types::StripeEvent>,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        request_internal,
+        |state, auth: auth::AuthenticationData, request_internal, _| {
+            list_events(
+                state,
+                auth.merchant_account.get_id().clone(),
+                auth.key_store,
+                request_internal.constraints,
+                pagination.clone(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Lists the events created at or before the `starting_after` event, if any, leaving out the
+/// `starting_after` event itself
+async fn list_events(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    constraints: EventListConstraints,
+    pagination: StripePagination,
+) -> RouterResponse<StripeList<types::StripeEvent>> {
+    let key_manager_state = &(&state).into();
+    let now = common_utils::date_time::now();
+    let created_after = (now.date()
+        - time::Duration::days(webhook_events::INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS))
+    .midnight();
+
+    let cursor = match pagination.starting_after.as_deref() {
+        Some(event_id) => Some(
+            state
+                .store
+                .find_event_by_merchant_id_event_id(
+                    key_manager_state,
+                    &merchant_id,
+                    event_id,
+                    &key_store,
+                )
+                .await
+                .to_not_found_response(ApiErrorResponse::EventNotFound)?,
+        ),
+        None => None,
+    };
+
+    // Events are bounded by the creation time of the cursor inclusively, so the cursor event is
+    // fetched along with the page
+    let limit = pagination
+        .get_fetch_limit()
+        .saturating_add(u16::from(cursor.is_some()));
+
+    let data = state
+        .store
+        .list_initial_events_by_merchant_id_constraints(
+            key_manager_state,
+            &merchant_id,
+            created_after,
+            cursor.map_or(now, |cursor| cursor.created_at),
+            Some(i64::from(limit)),
+            None,
+            HashSet::new(),
+            constraints.is_delivered,
+            &key_store,
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events with specified constraints")?
+        .into_iter()
+        .filter(|event| Some(event.event_id.as_str()) != pagination.starting_after.as_deref())
+        .map(|event| EventListItemResponse::try_from(event).map(types::StripeEvent::from))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        pagination.into_list("/v1/events", data),
+    ))
+}
@@ -0,0 +1,67 @@
+use api_models::{enums::EventClass, webhook_events};
+use serde::{Deserialize, Serialize};
+
+use crate::compatibility::stripe::webhooks::get_stripe_event_type;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StripeEventListConstraints {
+    pub delivery_success: Option<bool>,
+    pub limit: Option<u16>,
+    pub starting_after: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEvent {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: &'static str,
+    pub created: i64,
+    pub data: StripeEventData,
+    pub pending_webhooks: u8,
+    pub livemode: bool,
+}
+
+/// The event payloads are not listed by the core, so only a reference to the object is returned
+#[derive(Debug, Serialize)]
+pub struct StripeEventData {
+    pub object: StripeEventObject,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEventObject {
+    pub id: String,
+    pub object: &'static str,
+}
+
+fn get_stripe_object_type(event_class: EventClass) -> &'static str {
+    match event_class {
+        EventClass::Payments => "payment_intent",
+        EventClass::Refunds => "refund",
+        EventClass::Disputes => "dispute",
+        EventClass::Mandates => "mandate",
+        #[cfg(feature = "payouts")]
+        EventClass::Payouts => "payout",
+        EventClass::MetricAlerts => "metric_alert",
+        EventClass::Subscriptions => "subscription",
+    }
+}
+
+impl From<webhook_events::EventListItemResponse> for StripeEvent {
+    fn from(item: webhook_events::EventListItemResponse) -> Self {
+        Self {
+            id: item.event_id,
+            object: "event",
+            stype: get_stripe_event_type(item.event_type),
+            created: item.created.assume_utc().unix_timestamp(),
+            data: StripeEventData {
+                object: StripeEventObject {
+                    id: item.object_id,
+                    object: get_stripe_object_type(item.event_class),
+                },
+            },
+            pending_webhooks: u8::from(item.is_delivery_successful.is_none()),
+            livemode: false,
+        }
+    }
+}
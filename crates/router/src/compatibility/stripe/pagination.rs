@@ -0,0 +1,102 @@
+use serde::Serialize;
+
+use crate::{core::errors, events::api_logs::ApiEventMetric};
+
+const DEFAULT_LIST_LIMIT: u16 = 10;
+const MAX_LIST_LIMIT: u16 = 100;
+
+/// A page of objects in the shape of a Stripe list object
+#[derive(Debug, Serialize)]
+pub struct StripeList<T> {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<T>,
+}
+
+impl<T> ApiEventMetric for StripeList<T> {}
+
+/// Stripe style cursor pagination of a list request
+///
+/// Pages are fetched newest first and strictly after the `starting_after` object, so a page never
+/// contains the cursor object itself.
+#[derive(Clone, Debug)]
+pub struct StripePagination {
+    pub limit: u16,
+    pub starting_after: Option<String>,
+}
+
+impl StripePagination {
+    /// Validates the `limit` of a list request, which Stripe allows to be between 1 and 100
+    pub fn new(
+        limit: Option<u16>,
+        starting_after: Option<String>,
+    ) -> Result<Self, errors::ApiErrorResponse> {
+        let limit = match limit {
+            None => DEFAULT_LIST_LIMIT,
+            Some(limit) if (1..=MAX_LIST_LIMIT).contains(&limit) => limit,
+            Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`limit` must be a number between 1 and {MAX_LIST_LIMIT}"),
+            })?,
+        };
+        Ok(Self {
+            limit,
+            starting_after,
+        })
+    }
+
+    /// Returns the number of objects to fetch for the page, which includes one more object to
+    /// know whether there are more objects
+    pub fn get_fetch_limit(&self) -> u16 {
+        self.limit.min(MAX_LIST_LIMIT).saturating_add(1)
+    }
+
+    /// Builds the page from the objects fetched with [`Self::get_fetch_limit`]
+    pub fn into_list<T>(self, url: &'static str, mut objects: Vec<T>) -> StripeList<T> {
+        let has_more = objects.len() > usize::from(self.limit);
+        objects.truncate(usize::from(self.limit));
+
+        StripeList {
+            object: "list",
+            url,
+            has_more,
+            data: objects,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_stripe_list_with_more_objects() {
+        let pagination = StripePagination::new(Some(2), None).unwrap();
+        assert_eq!(pagination.get_fetch_limit(), 3);
+
+        let list = pagination.into_list("/v1/refunds", vec!["a", "b", "c"]);
+        assert!(list.has_more);
+        assert_eq!(list.data, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_stripe_list_last_page() {
+        let pagination = StripePagination::new(Some(2), Some("b".to_string())).unwrap();
+        assert_eq!(pagination.get_fetch_limit(), 3);
+
+        let list = pagination.into_list("/v1/refunds", vec!["c", "d"]);
+        assert!(!list.has_more);
+        assert_eq!(list.data, vec!["c", "d"]);
+    }
+
+    #[test]
+    fn test_stripe_pagination_limit() {
+        assert_eq!(StripePagination::new(None, None).unwrap().limit, 10);
+        assert!(StripePagination::new(Some(0), None).is_err());
+        assert!(StripePagination::new(Some(101), None).is_err());
+
+        let pagination = StripePagination::new(Some(100), Some("re_123".to_string())).unwrap();
+        assert_eq!(pagination.get_fetch_limit(), 101);
+    }
+}
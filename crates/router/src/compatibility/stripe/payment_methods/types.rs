@@ -0,0 +1,127 @@
+use api_models::{payment_methods, payments};
+use common_utils::{id_type, pii};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::payment_intents::types::{
+        StripeBillingDetails, StripePaymentMethodType,
+    },
+    core::errors,
+    types::api::enums as api_enums,
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StripePaymentMethodCreateRequest {
+    #[serde(rename = "type")]
+    pub stype: StripePaymentMethodType,
+    pub card: Option<StripePaymentMethodCard>,
+    pub billing_details: Option<StripeBillingDetails>,
+    pub customer: Option<id_type::CustomerId>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StripePaymentMethodCard {
+    pub number: cards::CardNumber,
+    pub exp_month: masking::Secret<String>,
+    pub exp_year: masking::Secret<String>,
+    // The card verification code is only used during payments and is never stored
+    #[serde(skip_serializing)]
+    pub cvc: Option<masking::Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: Option<api_enums::PaymentMethod>,
+    pub card: Option<StripePaymentMethodCardDetails>,
+    pub customer: Option<id_type::CustomerId>,
+    pub created: Option<i64>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub livemode: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripePaymentMethodCardDetails {
+    pub brand: Option<api_enums::CardNetwork>,
+    pub country: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+    pub last4: Option<String>,
+    pub fingerprint: Option<masking::Secret<String>>,
+}
+
+impl TryFrom<StripePaymentMethodCreateRequest> for payment_methods::PaymentMethodCreate {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripePaymentMethodCreateRequest) -> Result<Self, Self::Error> {
+        let card = match (item.stype, item.card) {
+            (StripePaymentMethodType::Card, Some(card)) => payment_methods::CardDetail {
+                card_number: card.number,
+                card_exp_month: card.exp_month,
+                card_exp_year: card.exp_year,
+                card_holder_name: item
+                    .billing_details
+                    .as_ref()
+                    .and_then(|billing_details| billing_details.name.clone())
+                    .map(masking::Secret::new),
+                nick_name: None,
+                card_issuing_country: None,
+                card_network: None,
+                card_issuer: None,
+                card_type: None,
+            },
+            (StripePaymentMethodType::Card, None) => {
+                Err(errors::ApiErrorResponse::MissingRequiredField { field_name: "card" })?
+            }
+            (_, _) => Err(errors::ApiErrorResponse::NotSupported {
+                message: "Only payment methods of type `card` can be created".to_string(),
+            })?,
+        };
+
+        Ok(Self {
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: Some(card),
+            metadata: item.metadata,
+            customer_id: item.customer,
+            card_network: None,
+            #[cfg(feature = "payouts")]
+            bank_transfer: None,
+            #[cfg(feature = "payouts")]
+            wallet: None,
+            client_secret: None,
+            payment_method_data: None,
+            billing: item.billing_details.map(payments::Address::from),
+            connector_mandate_details: None,
+            network_transaction_id: None,
+        })
+    }
+}
+
+impl From<payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            stype: item.payment_method,
+            card: item.card.map(|card| StripePaymentMethodCardDetails {
+                brand: card.card_network,
+                country: card.issuer_country,
+                exp_month: card.expiry_month,
+                exp_year: card.expiry_year,
+                last4: card.last4_digits,
+                fingerprint: card.card_fingerprint,
+            }),
+            customer: item.customer_id,
+            created: item
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+            metadata: item.metadata,
+            livemode: false,
+        }
+    }
+}
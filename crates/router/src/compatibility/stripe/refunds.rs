@@ -2,8 +2,18 @@ pub mod types;
 
 use actix_web::{web, HttpRequest, HttpResponse};
 use error_stack::report;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::refunds::RefundListConstraints;
 use router_env::{instrument, tracing, Flow, Tag};
 
+#[cfg(feature = "olap")]
+use crate::{
+    compatibility::stripe::pagination::{StripeList, StripePagination},
+    core::errors::{utils::StorageErrorExt, ApiErrorResponse, RouterResponse},
+    routes::SessionState,
+    services,
+    types::transformers::ForeignFrom,
+};
 use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking, refunds},
@@ -208,3 +218,107 @@ pub async fn refund_update(
     ))
     .await
 }
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsList))]
+pub async fn refund_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeRefundListConstraints>,
+) -> HttpResponse {
+    let payload = query_payload.into_inner();
+    let pagination = match StripePagination::new(payload.limit, payload.starting_after) {
+        Ok(pagination) => pagination,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
+    let refund_list_req = refund_types::RefundListRequest {
+        payment_id: payload.payment_intent,
+        refund_id: None,
+        profile_id: None,
+        limit: Some(i64::from(pagination.get_fetch_limit())),
+        offset: None,
+        time_range: None,
+        amount_filter: None,
+        connector: None,
+        merchant_connector_id: None,
+        currency: None,
+        refund_status: None,
+    };
+
+    let flow = Flow::RefundsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeList<types::StripeRefundResponse>,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        refund_list_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            list_refunds(state, merchant_context, req, pagination.clone())
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Lists the refunds that come after the `starting_after` refund, if any, in the descending order
+/// of `(created_at, refund_id)`
+#[cfg(feature = "olap")]
+async fn list_refunds(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: refund_types::RefundListRequest,
+    pagination: StripePagination,
+) -> RouterResponse<StripeList<types::StripeRefundResponse>> {
+    let merchant_account = merchant_context.get_merchant_account();
+    let mut constraints = RefundListConstraints::try_from((req, None))?;
+
+    if let Some(refund_id) = pagination.starting_after.as_deref() {
+        let cursor = state
+            .store
+            .find_refund_by_merchant_id_refund_id(
+                merchant_account.get_id(),
+                refund_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(ApiErrorResponse::RefundNotFound)?;
+        constraints.starting_after = Some((cursor.created_at, cursor.refund_id));
+    }
+
+    let data = state
+        .store
+        .filter_refund_by_constraints(
+            merchant_account.get_id(),
+            &constraints,
+            merchant_account.storage_scheme,
+            i64::from(pagination.get_fetch_limit()),
+            0,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::RefundNotFound)?
+        .into_iter()
+        .map(|refund| {
+            types::StripeRefundResponse::from(refund_types::RefundResponse::foreign_from(refund))
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        pagination.into_list("/v1/refunds", data),
+    ))
+}
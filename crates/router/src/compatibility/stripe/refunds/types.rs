@@ -22,6 +22,13 @@ pub struct StripeUpdateRefundRequest {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StripeRefundListConstraints {
+    pub payment_intent: Option<common_utils::id_type::PaymentId>,
+    pub limit: Option<u16>,
+    pub starting_after: Option<String>,
+}
+
 #[derive(Clone, Serialize, PartialEq, Eq, Debug)]
 pub struct StripeRefundResponse {
     pub id: String,
@@ -90,6 +90,9 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    MetricAlert(Box<api_models::analytics::metric_alerts::MetricAlertDetails>),
+    Subscription(Box<api_models::subscriptions::SubscriptionResponse>),
+    Invoice(Box<api_models::subscriptions::InvoiceResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -265,7 +268,7 @@ impl From<DisputeStatus> for StripeDisputeStatus {
     }
 }
 
-fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
+pub fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
     match event_type {
         api_models::enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
@@ -302,6 +305,14 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::MetricAlertTriggered => "metric_alert.triggered",
+        api_models::enums::EventType::SubscriptionCreated => "customer.subscription.created",
+        api_models::enums::EventType::SubscriptionUpdated
+        | api_models::enums::EventType::SubscriptionPastDue => "customer.subscription.updated",
+        api_models::enums::EventType::SubscriptionCancelled => "customer.subscription.deleted",
+        api_models::enums::EventType::InvoiceCreated => "invoice.created",
+        api_models::enums::EventType::InvoicePaid => "invoice.paid",
+        api_models::enums::EventType::InvoicePaymentFailed => "invoice.payment_failed",
     }
 }
 
@@ -344,6 +355,11 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::MetricAlertDetails(alert) => Self::MetricAlert(alert),
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription(subscription)
+            }
+            api::OutgoingWebhookContent::InvoiceDetails(invoice) => Self::Invoice(invoice),
         }
     }
 }
@@ -95,6 +95,27 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::NativeRevenueRecovery {
+    fn default() -> Self {
+        Self {
+            max_retries: 8,
+            recovery_window_days: 30,
+            history_lookback_days: 90,
+        }
+    }
+}
+
+impl Default for super::settings::AccountUpdater {
+    fn default() -> Self {
+        Self {
+            interval_hours: 24,
+            expiry_window_months: 2,
+            batch_size: 100,
+            connector_list: HashSet::new(),
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
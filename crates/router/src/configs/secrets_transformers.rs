@@ -328,6 +328,34 @@ impl SecretsHandler for settings::NetworkTokenizationService {
     }
 }
 
+/// Decrypts the Redis ACL and Sentinel passwords. `RedisSettings` is defined in `redis_interface`,
+/// hence `SecretsHandler` cannot be implemented for it here.
+async fn convert_redis_to_raw_secret(
+    redis: redis_interface::RedisSettings,
+    secret_management_client: &dyn SecretManagementInterface,
+) -> CustomResult<redis_interface::RedisSettings, SecretsManagementError> {
+    let password = match redis.password {
+        Some(password) => Some(secret_management_client.get_secret(password).await?),
+        None => None,
+    };
+    let sentinel = match redis.sentinel {
+        Some(sentinel) => Some(redis_interface::RedisSentinelSettings {
+            password: match sentinel.password {
+                Some(password) => Some(secret_management_client.get_secret(password).await?),
+                None => None,
+            },
+            ..sentinel
+        }),
+        None => None,
+    };
+
+    Ok(redis_interface::RedisSettings {
+        password,
+        sentinel,
+        ..redis
+    })
+}
+
 /// # Panics
 ///
 /// Will panic even if kms decryption fails for at least one field
@@ -450,11 +478,16 @@ pub(crate) async fn fetch_raw_secrets(
         })
         .await;
 
+    #[allow(clippy::expect_used)]
+    let redis = convert_redis_to_raw_secret(conf.redis, secret_management_client)
+        .await
+        .expect("Failed to decrypt redis configs");
+
     Settings {
         server: conf.server,
         chat: conf.chat,
         master_database,
-        redis: conf.redis,
+        redis,
         log: conf.log,
         #[cfg(feature = "kv_store")]
         drainer: conf.drainer,
@@ -471,6 +504,8 @@ pub(crate) async fn fetch_raw_secrets(
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
+        native_revenue_recovery: conf.native_revenue_recovery,
+        account_updater: conf.account_updater,
         eph_key: conf.eph_key,
         scheduler: conf.scheduler,
         jwekey,
@@ -84,6 +84,10 @@ pub struct Settings<S: SecretState> {
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
     pub refund: Refund,
+    #[serde(default)]
+    pub native_revenue_recovery: NativeRevenueRecovery,
+    #[serde(default)]
+    pub account_updater: AccountUpdater,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -766,6 +770,31 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct NativeRevenueRecovery {
+    /// Maximum number of retries of an enrolled payment
+    pub max_retries: u16,
+    /// Number of days after enrollment during which retries may be scheduled
+    pub recovery_window_days: u16,
+    /// Number of days of past retries considered when picking the hour of a retry
+    pub history_lookback_days: u16,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AccountUpdater {
+    /// Number of hours between two runs checking the stored cards of a merchant for updates
+    pub interval_hours: u16,
+    /// Cards expiring within this number of months, or already expired, are checked for updates
+    pub expiry_window_months: u8,
+    /// Number of stored cards sent to the connector in a single account updater request
+    pub batch_size: u16,
+    /// Connectors supporting account updater, for which the account updater can be enabled
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -997,6 +1026,7 @@ impl Settings<SecuredSecret> {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel.nodes")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
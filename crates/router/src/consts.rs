@@ -82,6 +82,8 @@ pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
+pub const EMAIL_SUBJECT_ANALYTICS_REPORT: &str = "Your scheduled report is ready";
+pub const EMAIL_SUBJECT_METRIC_ALERT: &str = "Metric alert triggered";
 
 pub const ROLE_INFO_CACHE_PREFIX: &str = "CR_INFO_";
 
@@ -91,6 +93,66 @@ pub const GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX: &str = "GUEST_USER_CARD_BLO
 
 pub const CUSTOMER_ID_BLOCKING_PREFIX: &str = "CUSTOMER_ID_BLOCKING";
 
+pub const VELOCITY_COUNTER_PREFIX: &str = "VELOCITY_COUNTER";
+
+/// Number of buckets a velocity rule window is divided into for approximating a sliding window
+pub const VELOCITY_WINDOW_BUCKET_COUNT: u32 = 10;
+
+/// Maximum window of a velocity rule (30 days)
+pub const MAX_VELOCITY_RULE_WINDOW_IN_SECS: u32 = 2_592_000;
+
+pub const MAX_VELOCITY_RULES_PER_PROFILE: usize = 50;
+
+/// Value cached in place of an optional JSON config which has not been configured
+pub const ABSENT_JSON_CONFIG: &str = "null";
+
+/// Number of disputes fetched at a time while computing the net loss of disputes
+pub const DISPUTE_FINANCIAL_RECORDS_PAGE_SIZE: i64 = 1000;
+
+/// Maximum number of days before `challenge_required_by` at which evidence can be submitted automatically
+pub const MAX_DISPUTE_AUTO_SUBMIT_DAYS_BEFORE_DUE: u16 = 30;
+
+/// Default time for which reads check both Redis and Postgres after a storage scheme migration
+/// has drained the pending entries of the merchant (5 mins)
+pub const DEFAULT_KV_MIGRATION_GRACE_PERIOD_IN_SECS: u32 = 300;
+
+/// Maximum grace period of a storage scheme migration (1 day)
+pub const MAX_KV_MIGRATION_GRACE_PERIOD_IN_SECS: u32 = 86_400;
+
+/// Interval at which the storage scheme migration workflow checks the progress of the migration
+pub const KV_MIGRATION_POLL_INTERVAL_IN_SECS: i64 = 30;
+
+/// Time for which the grace period of an ongoing migration is held without being refreshed by
+/// the storage scheme migration workflow (15 mins)
+pub const KV_MIGRATION_GRACE_PERIOD_LEASE_IN_SECS: i64 = 900;
+
+/// Maximum number of items allowed in a payout batch
+pub const MAX_PAYOUT_BATCH_SIZE: usize = 10_000;
+
+/// Number of payout batch items picked up in a single run of the payout batch workflow
+pub const PAYOUT_BATCH_ITEMS_PER_RUN: u32 = 100;
+
+/// Number of payout batch items processed concurrently
+pub const PAYOUT_BATCH_CONCURRENCY_LIMIT: usize = 10;
+
+/// Maximum number of scheduled analytics reports allowed per profile
+pub const MAX_ANALYTICS_REPORT_SCHEDULES_PER_PROFILE: usize = 20;
+
+/// Maximum number of recipients of a scheduled analytics report
+pub const MAX_ANALYTICS_REPORT_RECIPIENTS: usize = 10;
+
+/// Prefix of the file keys of the exports of scheduled analytics reports
+pub const ANALYTICS_REPORT_FILE_KEY_PREFIX: &str = "analytics_reports";
+
+/// Maximum number of metric alert rules allowed per profile
+pub const MAX_METRIC_ALERT_RULES_PER_PROFILE: usize = 20;
+
+/// Maximum number of email recipients of a metric alert rule
+pub const MAX_METRIC_ALERT_RECIPIENTS: usize = 10;
+
+/// Default minimum number of payments or refunds needed to evaluate a metric alert rule
+pub const DEFAULT_METRIC_ALERT_MIN_SAMPLE_SIZE: u64 = 20;
+
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
 #[cfg(feature = "olap")]
@@ -1,4 +1,8 @@
+#[cfg(feature = "v1")]
+pub mod account_updater;
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics_reports;
 pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
@@ -16,6 +20,8 @@ pub mod connector_onboarding;
 pub mod connector_validation;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
+#[cfg(feature = "v1")]
+pub mod customer_deduplication;
 pub mod customers;
 pub mod disputes;
 pub mod encryption;
@@ -26,9 +32,14 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+#[cfg(feature = "injector")]
+pub mod injector;
+pub mod kv_migration;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
+#[cfg(feature = "olap")]
+pub mod metric_alerts;
 pub mod metrics;
 pub mod payment_link;
 pub mod payment_methods;
@@ -42,6 +53,8 @@ pub mod poll;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
+pub mod recovery_enrollments;
+#[cfg(feature = "v1")]
 pub mod refunds;
 #[cfg(feature = "v2")]
 pub mod refunds_v2;
@@ -49,6 +62,8 @@ pub mod refunds_v2;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 pub mod surcharge_decision_config;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "olap")]
@@ -56,6 +71,8 @@ pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
 pub mod utils;
+#[cfg(feature = "v1")]
+pub mod velocity_rules;
 #[cfg(feature = "olap")]
 pub mod verification;
 #[cfg(feature = "olap")]
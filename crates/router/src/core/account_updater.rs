@@ -0,0 +1,1055 @@
+use std::{collections::HashMap, marker::PhantomData, str::FromStr};
+
+use api_models::{
+    account_updater::{
+        AccountUpdaterPTData, AccountUpdaterRequest, AccountUpdaterResponse,
+        PaymentMethodAccountUpdateListConstraints, PaymentMethodAccountUpdateListResponse,
+        PaymentMethodAccountUpdateResponse,
+    },
+    payment_methods::{Card, CardDetailsPaymentMethod, PaymentMethodsData},
+};
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    ext_traits::Encode,
+    id_type,
+};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, transformers as pm_transformers},
+        payments::CallConnectorAction,
+        recovery_enrollments,
+    },
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{
+        self,
+        api::{self, ConnectorData, GetToken},
+        domain, storage,
+    },
+    utils,
+};
+
+const ACCOUNT_UPDATER_TASK: &str = "ACCOUNT_UPDATER";
+const ACCOUNT_UPDATE_ID_PREFIX: &str = "pmau";
+
+/// Context under which the key for the card fingerprints is derived from the merchant key, so
+/// that the merchant key itself is only ever used for encryption
+const CARD_FINGERPRINT_KEY_CONTEXT: &[u8] = b"card_account_updater";
+
+const IRRELEVANT_ATTEMPT_ID_IN_ACCOUNT_UPDATER_FLOW: &str =
+    "irrelevant_attempt_id_in_account_updater_flow";
+const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_ACCOUNT_UPDATER_FLOW: &str =
+    "irrelevant_connector_request_reference_id_in_account_updater_flow";
+
+/// A stored card which is due to be checked for updates, along with its details in the locker
+struct AccountUpdaterCandidate {
+    payment_method: domain::PaymentMethod,
+    card_details: CardDetailsPaymentMethod,
+    card: Card,
+}
+
+#[instrument(skip_all)]
+pub async fn enable_account_updater(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: AccountUpdaterRequest,
+) -> RouterResponse<AccountUpdaterResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let merchant_connector_account =
+        find_merchant_connector_account(&state, &merchant_context, &request.merchant_connector_id)
+            .await?;
+
+    if merchant_connector_account.disabled.unwrap_or(false)
+        || merchant_connector_account.connector_type
+            != storage_enums::ConnectorType::PaymentProcessor
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The account updater can only be enabled for an enabled payment processor"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let is_account_updater_supported = api::enums::Connector::from_str(
+        &merchant_connector_account.connector_name,
+    )
+    .is_ok_and(|connector| {
+        state
+            .conf
+            .account_updater
+            .connector_list
+            .contains(&connector)
+    });
+    if !is_account_updater_supported {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The account updater is not supported by the connector {}",
+                merchant_connector_account.connector_name
+            ),
+        }
+        .into());
+    }
+
+    let process_tracker_id =
+        get_account_updater_process_tracker_id(merchant_id, &request.merchant_connector_id);
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the account updater process tracker task")?;
+
+    let tracking_data = AccountUpdaterPTData {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id: request.merchant_connector_id.clone(),
+        offset: 0,
+    };
+    let schedule_time = common_utils::date_time::now();
+
+    let next_run_at = match existing_process {
+        Some(process) if process.status != storage_enums::ProcessTrackerStatus::Finish => {
+            process.schedule_time
+        }
+        Some(process) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the account updater tracking data")?;
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to re-enable the account updater process tracker task")?;
+            Some(schedule_time)
+        }
+        None => {
+            add_account_updater_task_to_pt(db, process_tracker_id, tracking_data, schedule_time)
+                .await?;
+            Some(schedule_time)
+        }
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        AccountUpdaterResponse {
+            merchant_id: merchant_id.clone(),
+            merchant_connector_id: request.merchant_connector_id,
+            connector: merchant_connector_account.connector_name,
+            enabled: true,
+            next_run_at,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn disable_account_updater(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: AccountUpdaterRequest,
+) -> RouterResponse<AccountUpdaterResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let merchant_connector_account =
+        find_merchant_connector_account(&state, &merchant_context, &request.merchant_connector_id)
+            .await?;
+
+    let process_tracker_id =
+        get_account_updater_process_tracker_id(merchant_id, &request.merchant_connector_id);
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the account updater process tracker task")?;
+
+    if let Some(process) = existing_process
+        .filter(|process| process.status != storage_enums::ProcessTrackerStatus::Finish)
+    {
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to disable the account updater process tracker task")?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        AccountUpdaterResponse {
+            merchant_id: merchant_id.clone(),
+            merchant_connector_id: request.merchant_connector_id,
+            connector: merchant_connector_account.connector_name,
+            enabled: false,
+            next_run_at: None,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_payment_method_account_updates(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_method_id: String,
+    constraints: PaymentMethodAccountUpdateListConstraints,
+) -> RouterResponse<PaymentMethodAccountUpdateListResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let payment_method = db
+        .find_payment_method(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            &payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    utils::when(&payment_method.merchant_id != merchant_id, || {
+        Err(errors::ApiErrorResponse::PaymentMethodNotFound)
+    })?;
+
+    let limit = constraints
+        .limit
+        .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into())
+        .clamp(1, common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1.into());
+    let account_updates = db
+        .list_payment_method_account_updates_by_merchant_id_payment_method_id(
+            merchant_id,
+            &payment_method_id,
+            Some(limit),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the account updates of the payment method")?;
+
+    let data = account_updates
+        .into_iter()
+        .map(get_account_update_response)
+        .collect::<Vec<_>>();
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodAccountUpdateListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Checks the next page of stored cards of the merchant for updates at the connector. Returns the
+/// offset of the page that follows, or `None` once all the stored cards have been checked
+#[instrument(skip_all)]
+pub async fn run_account_updater(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &AccountUpdaterPTData,
+) -> RouterResult<Option<i64>> {
+    let db = state.store.as_ref();
+    let config = &state.conf.account_updater;
+    let merchant_connector_account = find_merchant_connector_account(
+        state,
+        merchant_context,
+        &tracking_data.merchant_connector_id,
+    )
+    .await?;
+
+    let page_size = i64::from(config.batch_size.max(1));
+    let payment_methods = db
+        .find_payment_methods_by_merchant_id_payment_method_status(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            storage_enums::PaymentMethod::Card,
+            storage_enums::PaymentMethodStatus::Active,
+            Some(page_size),
+            Some(tracking_data.offset),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the stored cards of the merchant")?;
+    let fetched_count = i64::try_from(payment_methods.len()).unwrap_or(page_size);
+
+    let expiry_threshold =
+        get_expiry_threshold(common_utils::date_time::now(), config.expiry_window_months);
+    let mut candidates = Vec::new();
+    for payment_method in payment_methods {
+        let Some(card_details) = get_card_details(&payment_method)
+            .filter(|card_details| is_due_for_update(card_details, expiry_threshold))
+        else {
+            continue;
+        };
+        match cards::get_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            payment_method
+                .locker_id
+                .as_ref()
+                .unwrap_or(payment_method.get_id()),
+        )
+        .await
+        {
+            Ok(card) => candidates.push(AccountUpdaterCandidate {
+                payment_method,
+                card_details,
+                card,
+            }),
+            Err(error) => logger::error!(
+                payment_method_id = %payment_method.get_id(),
+                ?error,
+                "Failed to fetch the stored card from the locker"
+            ),
+        }
+    }
+
+    let closed_count = if candidates.is_empty() {
+        0
+    } else {
+        update_stored_cards(
+            state,
+            merchant_context,
+            &merchant_connector_account,
+            candidates,
+        )
+        .await?
+    };
+
+    // Cards of closed accounts are no longer active, and drop out of the pages that follow
+    Ok((fetched_count == page_size).then_some(tracking_data.offset + fetched_count - closed_count))
+}
+
+/// Sends the stored cards to the connector and applies the updates it returns. Returns the number
+/// of payment methods deactivated as their account was closed
+async fn update_stored_cards(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    candidates: Vec<AccountUpdaterCandidate>,
+) -> RouterResult<i64> {
+    let fingerprint_key = HmacSha256
+        .sign_message(
+            merchant_context
+                .get_merchant_key_store()
+                .key
+                .get_inner()
+                .peek(),
+            CARD_FINGERPRINT_KEY_CONTEXT,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive the card fingerprint key")?;
+
+    // The same card may be stored for several customers, and is sent to the connector only once
+    let mut candidates_by_fingerprint: HashMap<String, Vec<AccountUpdaterCandidate>> =
+        HashMap::new();
+    for candidate in candidates {
+        let fingerprint = HmacSha256
+            .sign_message(
+                &fingerprint_key,
+                candidate.card.card_number.get_card_no().as_bytes(),
+            )
+            .map(hex::encode)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to compute the card fingerprint")?;
+        candidates_by_fingerprint
+            .entry(fingerprint)
+            .or_default()
+            .push(candidate);
+    }
+
+    let request = types::AccountUpdaterRequestData {
+        cards: candidates_by_fingerprint
+            .iter()
+            .filter_map(|(fingerprint, candidates)| {
+                candidates
+                    .first()
+                    .map(|candidate| types::AccountUpdaterCardData {
+                        card_reference: fingerprint.clone(),
+                        card_number: candidate.card.card_number.clone(),
+                        card_exp_month: candidate.card.card_exp_month.clone(),
+                        card_exp_year: candidate.card.card_exp_year.clone(),
+                    })
+            })
+            .collect(),
+    };
+    let card_updates = call_connector_account_updater(
+        state,
+        merchant_context,
+        merchant_connector_account,
+        request,
+    )
+    .await?;
+
+    let mut closed_count = 0;
+    for card_update in card_updates {
+        let Some(candidates) = candidates_by_fingerprint.remove(&card_update.card_reference) else {
+            logger::warn!("Connector returned an update for a card which was not requested");
+            continue;
+        };
+        for candidate in candidates {
+            if card_update.status == storage_enums::CardAccountUpdateStatus::AccountClosed {
+                closed_count += 1;
+            }
+            apply_card_update(
+                state,
+                merchant_context,
+                merchant_connector_account,
+                candidate,
+                &card_update,
+            )
+            .await?;
+        }
+    }
+    Ok(closed_count)
+}
+
+/// The updates returned by the connector for the cards. If the connector fails the request as a
+/// whole, every card is considered to have failed with the error of the connector
+async fn call_connector_account_updater(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    request: types::AccountUpdaterRequestData,
+) -> RouterResult<Vec<types::AccountUpdaterCardUpdate>> {
+    let connector_data = ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+    let connector_integration: services::BoxedAccountUpdaterConnectorIntegrationInterface<
+        api::AccountUpdate,
+        types::AccountUpdaterRequestData,
+        types::AccountUpdaterResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let card_references = request
+        .cards
+        .iter()
+        .map(|card| card.card_reference.clone())
+        .collect::<Vec<_>>();
+    let router_data = construct_account_updater_router_data(
+        state,
+        merchant_context,
+        merchant_connector_account,
+        request,
+    )?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+        None,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to call the connector for account updates")?;
+
+    match response.response {
+        Ok(response) => Ok(response.card_updates),
+        Err(error) => {
+            logger::error!(
+                connector = %merchant_connector_account.connector_name,
+                error_code = %error.code,
+                error_message = %error.message,
+                "Connector failed the account updater request"
+            );
+            Ok(card_references
+                .into_iter()
+                .map(|card_reference| types::AccountUpdaterCardUpdate {
+                    card_reference,
+                    status: storage_enums::CardAccountUpdateStatus::Failed,
+                    card_number: None,
+                    card_exp_month: None,
+                    card_exp_year: None,
+                    error_code: Some(error.code.clone()),
+                    error_message: Some(error.message.clone()),
+                })
+                .collect())
+        }
+    }
+}
+
+fn construct_account_updater_router_data(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    request: types::AccountUpdaterRequestData,
+) -> RouterResult<types::AccountUpdaterRouterData> {
+    let auth_type = merchant_connector_account
+        .get_connector_account_details()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing ConnectorAuthType")?;
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        customer_id: None,
+        tenant_id: state.tenant.tenant_id.clone(),
+        connector_customer: None,
+        connector: merchant_connector_account.connector_name.clone(),
+        payment_id: id_type::PaymentId::get_irrelevant_id("account_updater")
+            .get_string_repr()
+            .to_owned(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_ACCOUNT_UPDATER_FLOW.to_string(),
+        status: storage_enums::AttemptStatus::default(),
+        payment_method: storage_enums::PaymentMethod::Card,
+        connector_auth_type: auth_type,
+        description: None,
+        address: types::PaymentAddress::default(),
+        auth_type: storage_enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata.clone(),
+        connector_wallets_details: None,
+        amount_captured: None,
+        minor_amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        payment_method_status: None,
+        request,
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        connector_request_reference_id:
+            IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_ACCOUNT_UPDATER_FLOW.to_string(),
+        test_mode: merchant_connector_account.test_mode,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+        authentication_id: None,
+        psd2_sca_exemption_type: None,
+        raw_connector_response: None,
+        is_payment_id_from_merchant: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
+        l2_l3_data: None,
+        minor_amount_capturable: None,
+    })
+}
+
+/// Applies the update of the card to the payment method, and records it in the update history of
+/// the payment method
+async fn apply_card_update(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    candidate: AccountUpdaterCandidate,
+    card_update: &types::AccountUpdaterCardUpdate,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let AccountUpdaterCandidate {
+        payment_method,
+        card_details,
+        card,
+    } = candidate;
+    let payment_method_id = payment_method.get_id().clone();
+
+    let updated_card = match card_update.status {
+        storage_enums::CardAccountUpdateStatus::CardUpdated
+        | storage_enums::CardAccountUpdateStatus::ExpiryUpdated => {
+            let is_card_number_updated = card_update
+                .card_number
+                .as_ref()
+                .is_some_and(|card_number| *card_number != card.card_number);
+            let updated_card = Card {
+                card_number: card_update.card_number.clone().unwrap_or(card.card_number),
+                card_exp_month: card_update
+                    .card_exp_month
+                    .clone()
+                    .unwrap_or(card.card_exp_month),
+                card_exp_year: card_update
+                    .card_exp_year
+                    .clone()
+                    .unwrap_or(card.card_exp_year),
+                ..card
+            };
+            update_stored_card(
+                state,
+                merchant_context,
+                payment_method,
+                card_details.clone(),
+                updated_card.clone(),
+                is_card_number_updated,
+            )
+            .await?;
+            Some(updated_card)
+        }
+        storage_enums::CardAccountUpdateStatus::AccountClosed => {
+            // The locker has no notion of a closed account, the payment method is deactivated
+            // instead so that it is no longer used for payments
+            db.update_payment_method(
+                &state.into(),
+                merchant_context.get_merchant_key_store(),
+                payment_method,
+                storage::PaymentMethodUpdate::StatusUpdate {
+                    status: Some(storage_enums::PaymentMethodStatus::Inactive),
+                },
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deactivate the payment method of a closed account")?;
+            None
+        }
+        storage_enums::CardAccountUpdateStatus::NoChange
+        | storage_enums::CardAccountUpdateStatus::ContactCardholder
+        | storage_enums::CardAccountUpdateStatus::Failed => None,
+    };
+
+    db.insert_payment_method_account_update(storage::PaymentMethodAccountUpdateNew {
+        update_id: utils::generate_id(consts::ID_LENGTH, ACCOUNT_UPDATE_ID_PREFIX),
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        payment_method_id,
+        connector: merchant_connector_account.connector_name.clone(),
+        merchant_connector_id: merchant_connector_account.get_id(),
+        status: card_update.status,
+        previous_card_last4: card_details.last4_digits,
+        previous_card_exp_month: card_details
+            .expiry_month
+            .map(|expiry_month| expiry_month.expose()),
+        previous_card_exp_year: card_details
+            .expiry_year
+            .map(|expiry_year| expiry_year.expose()),
+        updated_card_last4: updated_card
+            .as_ref()
+            .map(|card| card.card_number.get_last4()),
+        updated_card_exp_month: updated_card
+            .as_ref()
+            .map(|card| card.card_exp_month.peek().clone()),
+        updated_card_exp_year: updated_card
+            .as_ref()
+            .map(|card| card.card_exp_year.peek().clone()),
+        error_code: card_update.error_code.clone(),
+        error_message: card_update.error_message.clone(),
+        created_at: common_utils::date_time::now(),
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to record the account update of the payment method")?;
+    Ok(())
+}
+
+/// Replaces the card in the locker with the updated card, and points the payment method to it
+async fn update_stored_card(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    card_details: CardDetailsPaymentMethod,
+    updated_card: Card,
+    is_card_number_updated: bool,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let previous_locker_id = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or(payment_method.get_id().clone());
+
+    let payload = pm_transformers::StoreLockerReq::LockerCard(pm_transformers::StoreCardReq {
+        merchant_id: payment_method.merchant_id.clone(),
+        merchant_customer_id: payment_method.customer_id.clone(),
+        requestor_card_reference: None,
+        card: updated_card.clone(),
+        ttl: state.conf.locker.ttl_for_storage_in_secs,
+    });
+    let stored_card = cards::add_card_to_hs_locker(
+        state,
+        &payload,
+        &payment_method.customer_id,
+        api::enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the updated card in the locker")?;
+
+    let card_info = if is_card_number_updated {
+        db.get_card_info(&updated_card.card_number.get_card_isin())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get card info")?
+    } else {
+        None
+    };
+
+    let payment_method_data = cards::create_encrypted_data(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        PaymentMethodsData::Card(get_updated_card_details(
+            card_details,
+            updated_card,
+            is_card_number_updated,
+            card_info,
+        )),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt payment method data")?;
+
+    let customer_id = payment_method.customer_id.clone();
+    let merchant_id = payment_method.merchant_id.clone();
+    db.update_payment_method(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        payment_method,
+        storage::PaymentMethodUpdate::AdditionalDataUpdate {
+            payment_method_data: Some(payment_method_data.into()),
+            status: None,
+            locker_id: Some(stored_card.card_reference),
+            payment_method: None,
+            payment_method_type: None,
+            payment_method_issuer: None,
+            network_token_requestor_reference_id: None,
+            network_token_locker_id: None,
+            network_token_payment_method_data: None,
+        },
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the payment method with the updated card")?;
+
+    // The payment method no longer refers to the previous card, failing to delete it only leaves
+    // an orphaned entry in the locker
+    if let Err(error) =
+        cards::delete_card_from_hs_locker(state, &customer_id, &merchant_id, &previous_locker_id)
+            .await
+    {
+        logger::error!(?error, "Failed to delete the previous card from the locker");
+    }
+    Ok(())
+}
+
+/// The details of the stored card after the update. The details derived from the BIN of the card
+/// are replaced by those of the new card number when the card number changes, an update of the
+/// expiry alone retains them.
+fn get_updated_card_details(
+    card_details: CardDetailsPaymentMethod,
+    updated_card: Card,
+    is_card_number_updated: bool,
+    card_info: Option<storage::CardInfo>,
+) -> CardDetailsPaymentMethod {
+    let card_details = CardDetailsPaymentMethod {
+        last4_digits: Some(updated_card.card_number.get_last4()),
+        expiry_month: Some(updated_card.card_exp_month),
+        expiry_year: Some(updated_card.card_exp_year),
+        ..card_details
+    };
+    if !is_card_number_updated {
+        return card_details;
+    }
+
+    CardDetailsPaymentMethod {
+        issuer_country: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_issuing_country.clone()),
+        card_isin: Some(updated_card.card_number.get_card_isin()),
+        card_issuer: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_issuer.clone()),
+        card_network: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_network.clone()),
+        card_type: card_info.and_then(|card_info| card_info.card_type),
+        co_badged_card_data: None,
+        ..card_details
+    }
+}
+
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_context.get_merchant_account().get_id(),
+            merchant_connector_id,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })
+}
+
+async fn add_account_updater_task_to_pt(
+    db: &dyn StorageInterface,
+    process_tracker_id: String,
+    tracking_data: AccountUpdaterPTData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        process_tracker_id,
+        ACCOUNT_UPDATER_TASK,
+        storage::ProcessTrackerRunner::AccountUpdaterWorkflow,
+        ["ACCOUNT_UPDATER"],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct account updater process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add account updater process tracker task")?;
+
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "account_updater")),
+    );
+    Ok(())
+}
+
+fn get_account_updater_process_tracker_id(
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::AccountUpdaterWorkflow,
+        ACCOUNT_UPDATER_TASK,
+        merchant_connector_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+/// The card details stored with the payment method, for cards saved in the locker
+fn get_card_details(payment_method: &domain::PaymentMethod) -> Option<CardDetailsPaymentMethod> {
+    payment_method
+        .payment_method_data
+        .clone()
+        .map(|payment_method_data| payment_method_data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|payment_methods_data| match payment_methods_data {
+            PaymentMethodsData::Card(card) => Some(card),
+            PaymentMethodsData::BankDetails(_) | PaymentMethodsData::WalletDetails(_) => None,
+        })
+        .filter(|card| card.saved_to_locker)
+}
+
+/// Cards expiring on or before the returned date and time are due to be checked for updates,
+/// that is those expiring within the window, including the current month
+fn get_expiry_threshold(now: PrimitiveDateTime, expiry_window_months: u8) -> PrimitiveDateTime {
+    let months = now.year() * 12
+        + i32::from(u8::from(now.month()) - 1)
+        + i32::from(expiry_window_months)
+        + 1;
+    time::Month::try_from(u8::try_from(months.rem_euclid(12) + 1).unwrap_or(1))
+        .ok()
+        .and_then(|month| time::Date::from_calendar_date(months.div_euclid(12), month, 1).ok())
+        .map_or(now, |date| date.midnight())
+}
+
+/// Cards with an unknown expiry are not checked, as they cannot be told to be at risk
+fn is_due_for_update(
+    card_details: &CardDetailsPaymentMethod,
+    threshold: PrimitiveDateTime,
+) -> bool {
+    card_details
+        .expiry_month
+        .as_ref()
+        .zip(card_details.expiry_year.as_ref())
+        .and_then(|(expiry_month, expiry_year)| {
+            recovery_enrollments::parse_card_expiry(expiry_month.peek(), expiry_year.peek())
+        })
+        .is_some_and(|card_expiry| card_expiry <= threshold)
+}
+
+fn get_account_update_response(
+    account_update: storage::PaymentMethodAccountUpdate,
+) -> PaymentMethodAccountUpdateResponse {
+    PaymentMethodAccountUpdateResponse {
+        update_id: account_update.update_id,
+        payment_method_id: account_update.payment_method_id,
+        connector: account_update.connector,
+        merchant_connector_id: account_update.merchant_connector_id,
+        status: account_update.status,
+        previous_card_last4: account_update.previous_card_last4,
+        previous_card_exp_month: account_update.previous_card_exp_month,
+        previous_card_exp_year: account_update.previous_card_exp_year,
+        updated_card_last4: account_update.updated_card_last4,
+        updated_card_exp_month: account_update.updated_card_exp_month,
+        updated_card_exp_year: account_update.updated_card_exp_year,
+        error_code: account_update.error_code,
+        error_message: account_update.error_message,
+        created_at: account_update.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn card_details(expiry_month: &str, expiry_year: &str) -> CardDetailsPaymentMethod {
+        CardDetailsPaymentMethod {
+            last4_digits: Some("4242".to_string()),
+            issuer_country: None,
+            expiry_month: Some(Secret::new(expiry_month.to_string())),
+            expiry_year: Some(Secret::new(expiry_year.to_string())),
+            nick_name: None,
+            card_holder_name: None,
+            card_isin: None,
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            saved_to_locker: true,
+            co_badged_card_data: None,
+        }
+    }
+
+    #[test]
+    fn test_expiry_threshold_spans_the_window_after_the_current_month() {
+        assert_eq!(
+            get_expiry_threshold(datetime!(2026-10-19 10:00), 2),
+            datetime!(2027-01-01 00:00)
+        );
+        assert_eq!(
+            get_expiry_threshold(datetime!(2026-12-31 23:59), 0),
+            datetime!(2027-01-01 00:00)
+        );
+    }
+
+    #[test]
+    fn test_cards_expiring_within_the_window_are_due_for_update() {
+        let threshold = get_expiry_threshold(datetime!(2026-10-19 10:00), 2);
+
+        assert!(is_due_for_update(&card_details("12", "26"), threshold));
+        assert!(is_due_for_update(&card_details("10", "2026"), threshold));
+        assert!(is_due_for_update(&card_details("03", "2025"), threshold));
+        assert!(!is_due_for_update(&card_details("01", "27"), threshold));
+        assert!(!is_due_for_update(&card_details("13", "26"), threshold));
+    }
+
+    fn stored_card_details() -> CardDetailsPaymentMethod {
+        CardDetailsPaymentMethod {
+            issuer_country: Some("US".to_string()),
+            card_isin: Some("424242".to_string()),
+            card_issuer: Some("Stripe Bank".to_string()),
+            card_network: Some(common_enums::CardNetwork::Visa),
+            card_type: Some("credit".to_string()),
+            ..card_details("10", "26")
+        }
+    }
+
+    fn updated_card(card_number: &str) -> Card {
+        Card {
+            card_number: ::cards::CardNumber::try_from(card_number.to_string()).unwrap(),
+            name_on_card: None,
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new("29".to_string()),
+            card_brand: None,
+            card_isin: None,
+            nick_name: None,
+        }
+    }
+
+    #[test]
+    fn test_expiry_update_retains_the_card_details_derived_from_the_bin() {
+        let card_details = get_updated_card_details(
+            stored_card_details(),
+            updated_card("4242424242424242"),
+            false,
+            None,
+        );
+
+        assert_eq!(card_details.last4_digits.as_deref(), Some("4242"));
+        assert_eq!(
+            card_details
+                .expiry_year
+                .map(|year| year.expose())
+                .as_deref(),
+            Some("29")
+        );
+        assert_eq!(card_details.card_isin.as_deref(), Some("424242"));
+        assert_eq!(card_details.card_issuer.as_deref(), Some("Stripe Bank"));
+        assert_eq!(
+            card_details.card_network,
+            Some(common_enums::CardNetwork::Visa)
+        );
+        assert_eq!(card_details.card_type.as_deref(), Some("credit"));
+        assert_eq!(card_details.issuer_country.as_deref(), Some("US"));
+    }
+
+    #[test]
+    fn test_card_number_update_replaces_the_card_details_derived_from_the_bin() {
+        let card_info = storage::CardInfo {
+            card_iin: "555555".to_string(),
+            card_issuer: Some("Mastercard Bank".to_string()),
+            card_network: Some(common_enums::CardNetwork::Mastercard),
+            card_type: Some("debit".to_string()),
+            card_subtype: None,
+            card_issuing_country: Some("GB".to_string()),
+            bank_code_id: None,
+            bank_code: None,
+            country_code: None,
+            date_created: datetime!(2026-01-01 00:00),
+            last_updated: None,
+            last_updated_provider: None,
+        };
+
+        let card_details = get_updated_card_details(
+            stored_card_details(),
+            updated_card("5555555555554444"),
+            true,
+            Some(card_info),
+        );
+
+        assert_eq!(card_details.last4_digits.as_deref(), Some("4444"));
+        assert_eq!(card_details.card_isin.as_deref(), Some("555555"));
+        assert_eq!(card_details.card_issuer.as_deref(), Some("Mastercard Bank"));
+        assert_eq!(
+            card_details.card_network,
+            Some(common_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(card_details.card_type.as_deref(), Some("debit"));
+        assert_eq!(card_details.issuer_country.as_deref(), Some("GB"));
+
+        let card_details = get_updated_card_details(
+            stored_card_details(),
+            updated_card("5555555555554444"),
+            true,
+            None,
+        );
+
+        assert_eq!(card_details.card_isin.as_deref(), Some("555555"));
+        assert_eq!(card_details.card_issuer, None);
+        assert_eq!(card_details.card_network, None);
+        assert_eq!(card_details.card_type, None);
+        assert_eq!(card_details.issuer_country, None);
+    }
+}
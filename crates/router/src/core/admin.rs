@@ -3476,6 +3476,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
+            is_payout_auto_retries_enabled: self.is_payout_auto_retries_enabled.unwrap_or_default(),
+            max_payout_auto_retries_enabled: self.max_payout_auto_retries_enabled.map(i16::from),
         }))
     }
 
@@ -3627,6 +3629,7 @@ impl ProfileCreateBridge for api::ProfileCreate {
             merchant_category_code: self.merchant_category_code,
             merchant_country_code: self.merchant_country_code,
             split_txns_enabled: self.split_txns_enabled.unwrap_or_default(),
+            allowed_proxy_destination_hosts: self.allowed_proxy_destination_hosts,
         }))
     }
 }
@@ -3971,6 +3974,10 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 merchant_category_code: self.merchant_category_code,
                 merchant_country_code: self.merchant_country_code,
                 dispute_polling_interval: self.dispute_polling_interval,
+                is_payout_auto_retries_enabled: self.is_payout_auto_retries_enabled,
+                max_payout_auto_retries_enabled: self
+                    .max_payout_auto_retries_enabled
+                    .map(i16::from),
             },
         )))
     }
@@ -4116,6 +4123,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 merchant_country_code: self.merchant_country_code,
                 revenue_recovery_retry_algorithm_type,
                 split_txns_enabled: self.split_txns_enabled,
+                allowed_proxy_destination_hosts: self.allowed_proxy_destination_hosts,
             },
         )))
     }
@@ -0,0 +1,873 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use api_models::analytics::{
+    report_schedules::{
+        ReportExport, ReportExportId, ReportFormat, ReportFrequency, ReportQuery, ReportSchedule,
+        ReportScheduleCreateRequest, ReportScheduleListResponse, ReportSchedulePTData,
+    },
+    AnalyticsRequest, GetDisputeMetricRequest, GetPaymentMetricRequest, GetRefundMetricRequest,
+    TimeSeries,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    generate_id, id_type,
+    types::{authentication::AuthInfo, TimeRange},
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    analytics_validator::request_validator,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::storage,
+};
+
+const ANALYTICS_REPORT_TASK: &str = "ANALYTICS_REPORT";
+const MAX_REPORT_NAME_LENGTH: usize = 64;
+
+fn validate_report_schedule_request(request: &ReportScheduleCreateRequest) -> RouterResult<()> {
+    let is_valid_name = !request.name.trim().is_empty()
+        && request.name.len() <= MAX_REPORT_NAME_LENGTH
+        && request
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_'));
+    if !is_valid_name {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "name must be at most {MAX_REPORT_NAME_LENGTH} characters long and can only contain alphanumeric characters, spaces, hyphens and underscores"
+            ),
+        }
+        .into());
+    }
+
+    let is_metrics_empty = match &request.query {
+        ReportQuery::Payments(query) => query.metrics.is_empty(),
+        ReportQuery::Refunds(query) => query.metrics.is_empty(),
+        ReportQuery::Disputes(query) => query.metrics.is_empty(),
+    };
+    if is_metrics_empty {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one metric is required in a report".to_string(),
+        }
+        .into());
+    }
+
+    if request.recipients.is_empty()
+        || request.recipients.len() > consts::MAX_ANALYTICS_REPORT_RECIPIENTS
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "A report must have between 1 and {} recipients",
+                consts::MAX_ANALYTICS_REPORT_RECIPIENTS
+            ),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Returns the start of the reporting period of the given frequency in which `time` falls
+fn get_period_start(frequency: ReportFrequency, time: PrimitiveDateTime) -> PrimitiveDateTime {
+    let date = time.date();
+    let period_start_date = match frequency {
+        ReportFrequency::Daily => date,
+        ReportFrequency::Weekly => {
+            date - time::Duration::days(i64::from(date.weekday().number_days_from_monday()))
+        }
+        ReportFrequency::Monthly => date.replace_day(1).unwrap_or(date),
+    };
+    period_start_date.midnight()
+}
+
+/// Returns the start of the reporting period following the one in which `time` falls
+fn get_next_run_at(frequency: ReportFrequency, time: PrimitiveDateTime) -> PrimitiveDateTime {
+    let period_start = get_period_start(frequency, time);
+    match frequency {
+        ReportFrequency::Daily => period_start + time::Duration::days(1),
+        ReportFrequency::Weekly => period_start + time::Duration::weeks(1),
+        ReportFrequency::Monthly => {
+            let date = period_start.date();
+            let (year, month) = match date.month() {
+                time::Month::December => (date.year() + 1, time::Month::January),
+                month => (date.year(), month.next()),
+            };
+            time::Date::from_calendar_date(year, month, 1)
+                .map(|next_date| next_date.midnight())
+                .unwrap_or(period_start + time::Duration::days(31))
+        }
+    }
+}
+
+/// Returns the last complete reporting period as of `time`
+fn get_report_time_range(frequency: ReportFrequency, time: PrimitiveDateTime) -> TimeRange {
+    let end_time = get_period_start(frequency, time);
+    TimeRange {
+        start_time: get_period_start(frequency, end_time - time::Duration::seconds(1)),
+        end_time: Some(end_time),
+    }
+}
+
+fn get_report_export_file_key(
+    merchant_id: &id_type::MerchantId,
+    report: &ReportSchedule,
+    export_id: &str,
+) -> String {
+    let extension = match report.format {
+        ReportFormat::Csv => "csv",
+        ReportFormat::Parquet => "parquet",
+    };
+    format!(
+        "{}/{}/{}/{}/{export_id}.{extension}",
+        consts::ANALYTICS_REPORT_FILE_KEY_PREFIX,
+        merchant_id.get_string_repr(),
+        report.profile_id.get_string_repr(),
+        report.report_id,
+    )
+}
+
+fn get_process_tracker_id(merchant_id: &id_type::MerchantId, report_id: &str) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::AnalyticsReportWorkflow,
+        ANALYTICS_REPORT_TASK,
+        report_id,
+        merchant_id,
+    )
+}
+
+/// Fetches the report schedules of the profile, if any have been configured
+async fn get_report_schedules(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<Vec<ReportSchedule>>> {
+    match db
+        .find_config_by_key(&profile_id.get_analytics_report_schedules_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("Vec<ReportSchedule>")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse analytics report schedules")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch analytics report schedules"),
+    }
+}
+
+async fn store_report_schedules(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    schedules: &[ReportSchedule],
+    is_new_config: bool,
+) -> RouterResult<()> {
+    let key = profile_id.get_analytics_report_schedules_key();
+    let serialized_schedules = schedules
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize analytics report schedules")?;
+
+    if is_new_config {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_schedules,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert analytics report schedules")?;
+    } else {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_schedules),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update analytics report schedules")?;
+    }
+    Ok(())
+}
+
+fn report_schedule_not_found() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Report schedule does not exist".to_string(),
+    }
+}
+
+async fn find_report_schedule(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    report_id: &str,
+) -> RouterResult<ReportSchedule> {
+    get_report_schedules(db, profile_id)
+        .await?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|schedule| schedule.report_id == report_id)
+        .ok_or(report_schedule_not_found().into())
+}
+
+async fn add_report_schedule_task_to_pt(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    report: &ReportSchedule,
+) -> RouterResult<()> {
+    let tracking_data = ReportSchedulePTData {
+        merchant_id: merchant_id.clone(),
+        profile_id: report.profile_id.clone(),
+        report_id: report.report_id.clone(),
+    };
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        get_process_tracker_id(merchant_id, &report.report_id),
+        ANALYTICS_REPORT_TASK,
+        storage::ProcessTrackerRunner::AnalyticsReportWorkflow,
+        ["ANALYTICS", "REPORT"],
+        tracking_data,
+        None,
+        report.next_run_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct analytics report process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add analytics report process tracker task")?;
+
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "analytics_report")),
+    );
+    Ok(())
+}
+
+pub async fn create_report_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    request: ReportScheduleCreateRequest,
+) -> RouterResponse<ReportSchedule> {
+    validate_report_schedule_request(&request)?;
+
+    let db = state.store.as_ref();
+    let existing_schedules = get_report_schedules(db, &profile_id).await?;
+    let is_new_config = existing_schedules.is_none();
+    let mut schedules = existing_schedules.unwrap_or_default();
+    if schedules.len() >= consts::MAX_ANALYTICS_REPORT_SCHEDULES_PER_PROFILE {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A profile can have at most {} report schedules",
+                consts::MAX_ANALYTICS_REPORT_SCHEDULES_PER_PROFILE
+            ),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let report = ReportSchedule {
+        report_id: generate_id(consts::ID_LENGTH, "report"),
+        profile_id: profile_id.clone(),
+        name: request.name,
+        query: request.query,
+        granularity: request.granularity,
+        format: request.format,
+        frequency: request.frequency,
+        recipients: request.recipients,
+        next_run_at: get_next_run_at(request.frequency, now),
+        last_export: None,
+        created_at: now,
+    };
+
+    schedules.push(report.clone());
+    store_report_schedules(db, &profile_id, &schedules, is_new_config).await?;
+    add_report_schedule_task_to_pt(db, &merchant_id, &report).await?;
+
+    Ok(services::ApplicationResponse::Json(report))
+}
+
+pub async fn list_report_schedules(
+    state: SessionState,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<ReportScheduleListResponse> {
+    let data = get_report_schedules(state.store.as_ref(), &profile_id)
+        .await?
+        .unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(
+        ReportScheduleListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+pub async fn retrieve_report_schedule(
+    state: SessionState,
+    profile_id: id_type::ProfileId,
+    report_id: String,
+) -> RouterResponse<ReportSchedule> {
+    find_report_schedule(state.store.as_ref(), &profile_id, &report_id)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn delete_report_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    report_id: String,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let mut schedules = get_report_schedules(db, &profile_id)
+        .await?
+        .ok_or(report_schedule_not_found())?;
+    let schedules_count = schedules.len();
+    schedules.retain(|schedule| schedule.report_id != report_id);
+    if schedules.len() == schedules_count {
+        return Err(report_schedule_not_found().into());
+    }
+
+    if schedules.is_empty() {
+        db.delete_config_by_key(&profile_id.get_analytics_report_schedules_key())
+            .await
+            .to_not_found_response(report_schedule_not_found())?;
+    } else {
+        store_report_schedules(db, &profile_id, &schedules, false).await?;
+    }
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_process_tracker_id(&merchant_id, &report_id)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: common_enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(diesel_models::business_status::REVOKED)),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to revoke analytics report process tracker task")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+pub async fn download_report_export(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    request: ReportExportId,
+) -> RouterResponse<serde_json::Value> {
+    // The export id is a part of the file key, so it is restricted to the characters of the
+    // generated ids
+    if !request
+        .export_id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid export_id".to_string(),
+        }
+        .into());
+    }
+
+    let report =
+        find_report_schedule(state.store.as_ref(), &profile_id, &request.report_id).await?;
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&get_report_export_file_key(
+            &merchant_id,
+            &report,
+            &request.export_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report export does not exist".to_string(),
+        })?;
+
+    let mime_type = match report.format {
+        ReportFormat::Csv => mime::TEXT_CSV,
+        ReportFormat::Parquet => mime::APPLICATION_OCTET_STREAM,
+    };
+    Ok(services::ApplicationResponse::FileData((
+        file_data, mime_type,
+    )))
+}
+
+/// Flattens a JSON value into `row`, with the keys of nested objects joined by a `.`
+fn flatten_json_value(
+    key: Option<String>,
+    value: serde_json::Value,
+    row: &mut BTreeMap<String, String>,
+) {
+    match (key, value) {
+        (prefix, serde_json::Value::Object(map)) => {
+            for (field, value) in map {
+                let key = match &prefix {
+                    Some(prefix) => format!("{prefix}.{field}"),
+                    None => field,
+                };
+                flatten_json_value(Some(key), value, row);
+            }
+        }
+        (Some(key), serde_json::Value::Null) => {
+            row.insert(key, String::new());
+        }
+        (Some(key), serde_json::Value::String(value)) => {
+            row.insert(key, value);
+        }
+        (Some(key), value) => {
+            row.insert(key, value.to_string());
+        }
+        (None, _) => {}
+    }
+}
+
+/// Flattens the metric buckets of a report into rows, along with the columns for every metric and
+/// dimension present in the buckets
+fn get_report_rows<T: serde::Serialize>(
+    buckets: &[T],
+) -> RouterResult<(BTreeSet<String>, Vec<BTreeMap<String, String>>)> {
+    let rows = buckets
+        .iter()
+        .map(|bucket| {
+            let value = serde_json::to_value(bucket)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize report bucket")?;
+            let mut row = BTreeMap::new();
+            flatten_json_value(None, value, &mut row);
+            Ok(row)
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+    let headers = rows
+        .iter()
+        .flat_map(|row| row.keys().cloned())
+        .collect::<BTreeSet<_>>();
+    Ok((headers, rows))
+}
+
+/// Renders the metric buckets of a report into a CSV file
+fn render_csv<T: serde::Serialize>(buckets: &[T]) -> RouterResult<Vec<u8>> {
+    let (headers, rows) = get_report_rows(buckets)?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    csv_writer
+        .write_record(&headers)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write report headers")?;
+    for row in rows {
+        csv_writer
+            .write_record(
+                headers
+                    .iter()
+                    .map(|header| row.get(header).map(String::as_str).unwrap_or_default()),
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write report record")?;
+    }
+    csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate report file")
+}
+
+/// Renders the metric buckets of a report into a Parquet file with a single row group, where every
+/// column is an optional string and the columns absent from a bucket are null
+fn render_parquet<T: serde::Serialize>(buckets: &[T]) -> RouterResult<Vec<u8>> {
+    use parquet::{
+        basic::{LogicalType, Repetition, Type as PhysicalType},
+        data_type::{ByteArray, ByteArrayType},
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        schema::types::Type,
+    };
+
+    let (headers, rows) = get_report_rows(buckets)?;
+
+    let fields = headers
+        .iter()
+        .map(|header| {
+            Type::primitive_type_builder(header, PhysicalType::BYTE_ARRAY)
+                .with_repetition(Repetition::OPTIONAL)
+                .with_logical_type(Some(LogicalType::String))
+                .build()
+                .map(std::sync::Arc::new)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build report schema")?;
+    let schema = Type::group_type_builder("report")
+        .with_fields(fields)
+        .build()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build report schema")?;
+
+    let mut file_data = Vec::new();
+    let mut file_writer = SerializedFileWriter::new(
+        &mut file_data,
+        std::sync::Arc::new(schema),
+        std::sync::Arc::new(WriterProperties::builder().build()),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to create report writer")?;
+    let mut row_group_writer = file_writer
+        .next_row_group()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create report row group")?;
+    for header in &headers {
+        let mut values = Vec::new();
+        let mut definition_levels = Vec::with_capacity(rows.len());
+        for row in &rows {
+            match row.get(header) {
+                Some(value) => {
+                    values.push(ByteArray::from(value.as_str()));
+                    definition_levels.push(1);
+                }
+                None => definition_levels.push(0),
+            }
+        }
+        if let Some(mut column_writer) = row_group_writer
+            .next_column()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to create report column")?
+        {
+            column_writer
+                .typed::<ByteArrayType>()
+                .write_batch(&values, Some(&definition_levels), None)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write report column")?;
+            column_writer
+                .close()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write report column")?;
+        }
+    }
+    row_group_writer
+        .close()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write report row group")?;
+    file_writer
+        .close()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate report file")?;
+
+    Ok(file_data)
+}
+
+fn render_report<T: serde::Serialize>(
+    format: ReportFormat,
+    buckets: &[T],
+) -> RouterResult<Vec<u8>> {
+    match format {
+        ReportFormat::Csv => render_csv(buckets),
+        ReportFormat::Parquet => render_parquet(buckets),
+    }
+}
+
+/// Executes the analytics query of the report, returning the generated file and its row count
+async fn generate_report_file(
+    state: &SessionState,
+    auth: &AuthInfo,
+    report: &ReportSchedule,
+    time_range: TimeRange,
+) -> RouterResult<(Vec<u8>, usize)> {
+    let time_series = report
+        .granularity
+        .map(|granularity| TimeSeries { granularity });
+
+    let (file_data, row_count) = match &report.query {
+        ReportQuery::Payments(query) => {
+            let request = GetPaymentMetricRequest {
+                time_series,
+                time_range,
+                group_by_names: query.group_by_names.clone(),
+                filters: query.filters.clone(),
+                metrics: query.metrics.clone(),
+                distribution: None,
+                delta: false,
+            };
+            let ex_rates = request_validator(
+                AnalyticsRequest {
+                    payment_attempt: Some(request.clone()),
+                    ..Default::default()
+                },
+                state,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            let response = analytics::payments::get_metrics(&state.pool, &ex_rates, auth, request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment metrics for the report")?;
+            (
+                render_report(report.format, &response.query_data)?,
+                response.query_data.len(),
+            )
+        }
+        ReportQuery::Refunds(query) => {
+            let request = GetRefundMetricRequest {
+                time_series,
+                time_range,
+                group_by_names: query.group_by_names.clone(),
+                filters: query.filters.clone(),
+                metrics: query.metrics.clone(),
+                distribution: None,
+                delta: false,
+            };
+            let ex_rates = request_validator(
+                AnalyticsRequest {
+                    refund: Some(request.clone()),
+                    ..Default::default()
+                },
+                state,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            let response = analytics::refunds::get_metrics(&state.pool, &ex_rates, auth, request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch refund metrics for the report")?;
+            (
+                render_report(report.format, &response.query_data)?,
+                response.query_data.len(),
+            )
+        }
+        ReportQuery::Disputes(query) => {
+            let request = GetDisputeMetricRequest {
+                time_series,
+                time_range,
+                group_by_names: query.group_by_names.clone(),
+                filters: query.filters.clone(),
+                metrics: query.metrics.clone(),
+                delta: false,
+            };
+            let response = analytics::disputes::get_metrics(&state.pool, auth, request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch dispute metrics for the report")?;
+            (
+                render_report(report.format, &response.query_data)?,
+                response.query_data.len(),
+            )
+        }
+    };
+
+    Ok((file_data, row_count))
+}
+
+#[cfg(feature = "email")]
+async fn send_report_export_emails(
+    state: &SessionState,
+    report: &ReportSchedule,
+    export: &ReportExport,
+) {
+    use crate::{
+        services::email::types::AnalyticsReport, types::domain::UserEmail,
+        utils::user as user_utils,
+    };
+
+    let link = format!(
+        "{}/analytics/v1/profile/report_schedules/{}/exports/{}",
+        state.base_url, report.report_id, export.export_id
+    );
+    for recipient in &report.recipients {
+        let recipient_email = match UserEmail::from_pii_email(recipient.clone()) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::error!(?error, report_id = %report.report_id, "Invalid report recipient");
+                continue;
+            }
+        };
+        let email_contents = AnalyticsReport {
+            recipient_email,
+            subject: consts::EMAIL_SUBJECT_ANALYTICS_REPORT,
+            report_name: report.name.clone(),
+            time_range: export.time_range,
+            link: link.clone(),
+        };
+
+        // A failure in delivering to one recipient does not fail the run, as the export is
+        // already available for download
+        let _ = state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, report_id = %report.report_id, "Failed to send report email")
+            });
+    }
+}
+
+/// Generates an export of the report for the last complete reporting period, stores it in the
+/// file storage and sends its link to the recipients of the report.
+/// Returns the time of the next run, or `None` if the report no longer exists.
+pub async fn run_report_schedule(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    tracking_data: &ReportSchedulePTData,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let Some(mut schedules) = get_report_schedules(db, &tracking_data.profile_id).await? else {
+        return Ok(None);
+    };
+    let Some(report) = schedules
+        .iter_mut()
+        .find(|schedule| schedule.report_id == tracking_data.report_id)
+    else {
+        return Ok(None);
+    };
+
+    let now = common_utils::date_time::now();
+    let time_range = get_report_time_range(report.frequency, now);
+    let auth = AuthInfo::ProfileLevel {
+        org_id: org_id.clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        profile_ids: vec![tracking_data.profile_id.clone()],
+    };
+    let (file_data, row_count) = generate_report_file(state, &auth, report, time_range).await?;
+
+    let export_id = generate_id(consts::ID_LENGTH, "export");
+    state
+        .file_storage_client
+        .upload_file(
+            &get_report_export_file_key(&tracking_data.merchant_id, report, &export_id),
+            file_data,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload report export")?;
+
+    let export = ReportExport {
+        export_id,
+        time_range,
+        row_count,
+        created_at: now,
+    };
+    logger::info!(
+        report_id = %report.report_id,
+        export_id = %export.export_id,
+        row_count,
+        "Generated analytics report export"
+    );
+
+    #[cfg(feature = "email")]
+    send_report_export_emails(state, report, &export).await;
+
+    let next_run_at = get_next_run_at(report.frequency, now);
+    report.next_run_at = next_run_at;
+    report.last_export = Some(export);
+    store_report_schedules(db, &tracking_data.profile_id, &schedules, false).await?;
+
+    Ok(Some(next_run_at))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_get_next_run_at() {
+        let time = datetime!(2025-12-17 10:30);
+        assert_eq!(
+            get_next_run_at(ReportFrequency::Daily, time),
+            datetime!(2025-12-18 0:00)
+        );
+        assert_eq!(
+            get_next_run_at(ReportFrequency::Weekly, time),
+            datetime!(2025-12-22 0:00)
+        );
+        assert_eq!(
+            get_next_run_at(ReportFrequency::Monthly, time),
+            datetime!(2026-01-01 0:00)
+        );
+    }
+
+    #[test]
+    fn test_get_report_time_range() {
+        let time = datetime!(2025-03-01 0:05);
+        assert_eq!(
+            get_report_time_range(ReportFrequency::Daily, time),
+            TimeRange {
+                start_time: datetime!(2025-02-28 0:00),
+                end_time: Some(datetime!(2025-03-01 0:00)),
+            }
+        );
+        assert_eq!(
+            get_report_time_range(ReportFrequency::Weekly, time),
+            TimeRange {
+                start_time: datetime!(2025-02-17 0:00),
+                end_time: Some(datetime!(2025-02-24 0:00)),
+            }
+        );
+        assert_eq!(
+            get_report_time_range(ReportFrequency::Monthly, time),
+            TimeRange {
+                start_time: datetime!(2025-02-01 0:00),
+                end_time: Some(datetime!(2025-03-01 0:00)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let buckets = vec![
+            serde_json::json!({
+                "payment_count": 10,
+                "currency": "USD",
+                "time_range": { "start_time": "2025-01-01T00:00:00.000Z", "end_time": null },
+            }),
+            serde_json::json!({
+                "payment_count": null,
+                "currency": "EUR",
+                "connector": "stripe",
+            }),
+        ];
+
+        let csv = String::from_utf8(render_csv(&buckets).unwrap()).unwrap();
+        assert_eq!(
+            csv,
+            "connector,currency,payment_count,time_range.end_time,time_range.start_time\n\
+             ,USD,10,,2025-01-01T00:00:00.000Z\n\
+             stripe,EUR,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_render_parquet() {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let buckets = vec![
+            serde_json::json!({ "payment_count": 10, "currency": "USD" }),
+            serde_json::json!({ "currency": "EUR", "connector": "stripe" }),
+        ];
+
+        let file_data = render_parquet(&buckets).unwrap();
+        let reader = SerializedFileReader::new(bytes::Bytes::from(file_data)).unwrap();
+        let file_metadata = reader.metadata().file_metadata();
+        assert_eq!(file_metadata.num_rows(), 2);
+        assert_eq!(
+            file_metadata
+                .schema_descr()
+                .columns()
+                .iter()
+                .map(|column| column.name().to_string())
+                .collect::<Vec<_>>(),
+            vec!["connector", "currency", "payment_count"]
+        );
+    }
+}
@@ -47,6 +47,7 @@ pub async fn perform_authentication(
     payment_id: common_utils::id_type::PaymentId,
     force_3ds_challenge: bool,
     merchant_key_store: &hyperswitch_domain_models::merchant_key_store::MerchantKeyStore,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> CustomResult<api::authentication::AuthenticationResponse, ApiErrorResponse> {
     let router_data = transformers::construct_authentication_router_data(
         state,
@@ -85,6 +86,7 @@ pub async fn perform_authentication(
         authentication_data,
         None,
         merchant_key_store,
+        storage_scheme,
     )
     .await?;
     response
@@ -105,6 +107,7 @@ pub async fn perform_post_authentication(
     business_profile: domain::Profile,
     authentication_id: common_utils::id_type::AuthenticationId,
     payment_id: &common_utils::id_type::PaymentId,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> CustomResult<
     hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore,
     ApiErrorResponse,
@@ -122,6 +125,7 @@ pub async fn perform_post_authentication(
         .find_authentication_by_merchant_id_authentication_id(
             &business_profile.merchant_id,
             &authentication_id,
+            storage_scheme,
         )
         .await
         .to_not_found_response(ApiErrorResponse::InternalServerError)
@@ -147,7 +151,15 @@ pub async fn perform_post_authentication(
         let router_data =
             utils::do_auth_connector_call(state, authentication_connector.to_string(), router_data)
                 .await?;
-        utils::update_trackers(state, router_data, authentication, None, key_store).await?
+        utils::update_trackers(
+            state,
+            router_data,
+            authentication,
+            None,
+            key_store,
+            storage_scheme,
+        )
+        .await?
     } else {
         // trigger in case of webhook flow
         authentication
@@ -186,6 +198,7 @@ pub async fn perform_pre_authentication(
     organization_id: common_utils::id_type::OrganizationId,
     force_3ds_challenge: Option<bool>,
     psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> CustomResult<
     hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore,
     ApiErrorResponse,
@@ -207,6 +220,7 @@ pub async fn perform_pre_authentication(
         organization_id,
         force_3ds_challenge,
         psd2_sca_exemption_type,
+        storage_scheme,
     )
     .await?;
 
@@ -233,6 +247,7 @@ pub async fn perform_pre_authentication(
             authentication,
             acquirer_details.clone(),
             key_store,
+            storage_scheme,
         )
         .await?;
         // from version call response, we will get to know the maximum supported 3ds version.
@@ -266,6 +281,7 @@ pub async fn perform_pre_authentication(
         authentication,
         acquirer_details,
         key_store,
+        storage_scheme,
     )
     .await?;
 
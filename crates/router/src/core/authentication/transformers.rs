@@ -208,6 +208,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         psd2_sca_exemption_type,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
         l2_l3_data: None,
         minor_amount_capturable: None,
     })
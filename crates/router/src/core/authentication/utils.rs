@@ -58,6 +58,7 @@ pub async fn update_trackers<F: Clone, Req>(
     authentication: storage::Authentication,
     acquirer_details: Option<super::types::AcquirerDetails>,
     merchant_key_store: &hyperswitch_domain_models::merchant_key_store::MerchantKeyStore,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> RouterResult<storage::Authentication> {
     let authentication_update = match router_data.response {
         Ok(response) => match response {
@@ -212,6 +213,7 @@ pub async fn update_trackers<F: Clone, Req>(
         .update_authentication_by_merchant_id_authentication_id(
             authentication,
             authentication_update,
+            storage_scheme,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -241,6 +243,7 @@ pub async fn create_new_authentication(
     organization_id: common_utils::id_type::OrganizationId,
     force_3ds_challenge: Option<bool>,
     psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> RouterResult<storage::Authentication> {
     let authentication_id = common_utils::id_type::AuthenticationId::generate_authentication_id(
         consts::AUTHENTICATION_ID_PREFIX,
@@ -303,7 +306,7 @@ pub async fn create_new_authentication(
     };
     state
         .store
-        .insert_authentication(new_authorization)
+        .insert_authentication(new_authorization, storage_scheme)
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: format!(
@@ -1,6 +1,9 @@
+use api_models::cache as cache_api;
 use common_utils::errors::CustomResult;
 use error_stack::{report, ResultExt};
-use storage_impl::redis::cache::{redact_from_redis_and_publish, CacheKind};
+use storage_impl::redis::cache::{
+    publish_cache_introspection, redact_from_redis_and_publish, CacheKind,
+};
 
 use super::errors;
 use crate::{routes::SessionState, services};
@@ -26,3 +29,72 @@ pub async fn invalidate(
             .attach_printable("Failed to invalidate cache"))
     }
 }
+
+/// Removes the entries whose keys start with the prefix from the in-memory caches of every pod.
+/// Redis is deliberately left untouched, as its keyspace also holds KV payment data, reverse
+/// lookups and locks which share the same key prefixes.
+pub async fn invalidate_by_prefix(
+    state: SessionState,
+    request: cache_api::CachePrefixInvalidationRequest,
+) -> CustomResult<
+    services::api::ApplicationResponse<cache_api::CacheIntrospectionResponse>,
+    errors::ApiErrorResponse,
+> {
+    if request.prefix.trim().is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "prefix must not be empty".to_string(),
+        }));
+    }
+
+    introspect(
+        state,
+        cache_api::CacheIntrospectionOperation::InvalidatePrefix {
+            prefix: request.prefix,
+        },
+    )
+    .await
+}
+
+pub async fn get_stats(
+    state: SessionState,
+) -> CustomResult<
+    services::api::ApplicationResponse<cache_api::CacheIntrospectionResponse>,
+    errors::ApiErrorResponse,
+> {
+    introspect(state, cache_api::CacheIntrospectionOperation::Stats).await
+}
+
+pub async fn lookup_key(
+    state: SessionState,
+    key: String,
+) -> CustomResult<
+    services::api::ApplicationResponse<cache_api::CacheIntrospectionResponse>,
+    errors::ApiErrorResponse,
+> {
+    introspect(
+        state,
+        cache_api::CacheIntrospectionOperation::Lookup { key },
+    )
+    .await
+}
+
+async fn introspect(
+    state: SessionState,
+    operation: cache_api::CacheIntrospectionOperation,
+) -> CustomResult<
+    services::api::ApplicationResponse<cache_api::CacheIntrospectionResponse>,
+    errors::ApiErrorResponse,
+> {
+    let (pods_expected, pods) =
+        publish_cache_introspection(state.store.get_cache_store().as_ref(), operation)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to collect the cache reports of the pods")?;
+
+    Ok(services::api::ApplicationResponse::Json(
+        cache_api::CacheIntrospectionResponse {
+            pods_expected,
+            pods,
+        },
+    ))
+}
@@ -0,0 +1,971 @@
+use api_models::customers::{
+    CustomerBlindIndexSyncResponse, CustomerDuplicate, CustomerDuplicateGroup,
+    CustomerDuplicateGroupListResponse, CustomerDuplicatesResponse, CustomerListRequest,
+    CustomerMergeListResponse, CustomerMergeRequestInternal, CustomerMergeResponse,
+};
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    encryption::Encryption,
+    id_type, pii, type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, transformers as pm_transformers},
+    },
+    routes::SessionState,
+    services,
+    types::{api, domain, storage},
+    utils,
+};
+
+const CUSTOMER_MERGE_ID_PREFIX: &str = "cmrg";
+
+/// Context under which the key for the blind indexes is derived from the merchant key, so that the
+/// merchant key itself is only ever used for encryption
+const BLIND_INDEX_KEY_CONTEXT: &[u8] = b"customer_blind_index";
+
+/// Phone numbers with fewer digits are not indexed, as they cannot identify a customer
+const MIN_PHONE_NUMBER_DIGITS: usize = 6;
+
+/// Upper bound on the number of customers returned for a single email or phone number
+const MAX_DUPLICATES_PER_VALUE: i64 = 100;
+
+/// Upper bound on the number of customers indexed by a single sync request
+const MAX_BLIND_INDEX_SYNC_BATCH_SIZE: u16 = 1000;
+
+/// Indexes the email and phone number of the customer for duplicate detection, replacing any
+/// previously indexed values
+#[instrument(skip_all)]
+pub async fn index_customer(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer: &domain::Customer,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let blind_indexes = get_customer_blind_indexes(key_store, customer)?;
+
+    let existing_blind_indexes = db
+        .find_customer_blind_indexes_by_merchant_id_customer_id(
+            &customer.merchant_id,
+            &customer.customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the blind indexes of the customer")?;
+    let is_unchanged = existing_blind_indexes.len() == blind_indexes.len()
+        && existing_blind_indexes.iter().all(|existing| {
+            blind_indexes.iter().any(|(field, hashed_value)| {
+                existing.field == *field && existing.hashed_value == *hashed_value
+            })
+        });
+    if is_unchanged {
+        return Ok(());
+    }
+
+    delete_customer_blind_indexes(state, &customer.merchant_id, &customer.customer_id).await?;
+    for (field, hashed_value) in blind_indexes {
+        db.insert_customer_blind_index(storage::CustomerBlindIndexNew {
+            merchant_id: customer.merchant_id.clone(),
+            customer_id: customer.customer_id.clone(),
+            field,
+            hashed_value,
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the blind index of the customer")?;
+    }
+    Ok(())
+}
+
+pub async fn delete_customer_blind_indexes(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    match state
+        .store
+        .delete_customer_blind_indexes_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the blind indexes of the customer"),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_customer_duplicates(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerDuplicatesResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let customer = find_customer(&state, &merchant_context, &customer_id).await?;
+
+    // The hashes are computed from the current details of the customer, so that duplicates are
+    // found even if the customer has not been indexed yet
+    let blind_indexes =
+        get_customer_blind_indexes(merchant_context.get_merchant_key_store(), &customer)?;
+
+    let mut duplicates: Vec<CustomerDuplicate> = Vec::new();
+    for (field, hashed_value) in blind_indexes {
+        let matching_customers = db
+            .find_customer_blind_indexes_by_merchant_id_field_hashed_value(
+                merchant_id,
+                field,
+                &hashed_value,
+                Some(MAX_DUPLICATES_PER_VALUE),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the customers matching the blind index")?;
+
+        for matching_customer in matching_customers {
+            if matching_customer.customer_id == customer_id {
+                continue;
+            }
+            match duplicates
+                .iter_mut()
+                .find(|duplicate| duplicate.customer_id == matching_customer.customer_id)
+            {
+                Some(duplicate) => duplicate.matched_on.push(field),
+                None => duplicates.push(CustomerDuplicate {
+                    customer_id: matching_customer.customer_id,
+                    matched_on: vec![field],
+                }),
+            }
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerDuplicatesResponse {
+            customer_id,
+            duplicates,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_customer_duplicate_groups(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: CustomerListRequest,
+) -> RouterResponse<CustomerDuplicateGroupListResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let max_limit = u16::try_from(common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Maximum list limit exceeds the range of u16")?;
+    let limit = request
+        .limit
+        .unwrap_or(consts::DEFAULT_LIST_API_LIMIT)
+        .min(max_limit);
+
+    let duplicate_values = db
+        .list_duplicate_customer_blind_indexes_by_merchant_id(
+            merchant_id,
+            limit.into(),
+            request.offset.unwrap_or_default().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the duplicate blind indexes of the merchant")?;
+
+    let mut data = Vec::with_capacity(duplicate_values.len());
+    for (field, hashed_value, _) in duplicate_values {
+        let customer_ids = db
+            .find_customer_blind_indexes_by_merchant_id_field_hashed_value(
+                merchant_id,
+                field,
+                &hashed_value,
+                Some(MAX_DUPLICATES_PER_VALUE),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the customers matching the blind index")?
+            .into_iter()
+            .map(|blind_index| blind_index.customer_id)
+            .collect();
+        data.push(CustomerDuplicateGroup {
+            matched_on: field,
+            customer_ids,
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerDuplicateGroupListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Indexes a batch of the existing customers of the merchant, oldest first, for customers created
+/// before duplicate detection was available
+#[instrument(skip_all)]
+pub async fn sync_customer_blind_indexes(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: CustomerListRequest,
+) -> RouterResponse<CustomerBlindIndexSyncResponse> {
+    let limit = request
+        .limit
+        .unwrap_or(MAX_BLIND_INDEX_SYNC_BATCH_SIZE)
+        .min(MAX_BLIND_INDEX_SYNC_BATCH_SIZE);
+    let offset = request.offset.unwrap_or_default();
+
+    let customers = state
+        .store
+        .list_customers_by_merchant_id(
+            &(&state).into(),
+            merchant_context.get_merchant_account().get_id(),
+            merchant_context.get_merchant_key_store(),
+            crate::db::customers::CustomerListConstraints {
+                limit,
+                offset: Some(offset),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the customers of the merchant")?;
+
+    for customer in customers.iter() {
+        index_customer(&state, merchant_context.get_merchant_key_store(), customer).await?;
+    }
+
+    let customers_indexed = customers.len();
+    let next_offset = (customers_indexed == usize::from(limit))
+        .then(|| u32::try_from(customers_indexed).map(|indexed| offset.saturating_add(indexed)))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of indexed customers exceeds the offset range")?;
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerBlindIndexSyncResponse {
+            customers_indexed,
+            next_offset,
+        },
+    ))
+}
+
+/// Moves the payment methods, mandates and payments of a duplicate customer to the surviving
+/// customer and records the merge.
+///
+/// The merge is recorded as in progress before anything is moved, and each payment method is
+/// recorded as soon as it is moved, so a merge which fails midway is audited and can be completed
+/// by retrying it. The duplicate customer itself is retained, without any payment methods,
+/// mandates or payments.
+#[instrument(skip_all)]
+pub async fn merge_customers(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: CustomerMergeRequestInternal,
+) -> RouterResponse<CustomerMergeResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+    let surviving_customer_id = request.customer_id;
+    let merged_customer_id = request.request.merged_customer_id;
+
+    // Payment methods, mandates and payments are moved in the database directly, which would leave
+    // stale copies of them in the key value store
+    if merchant_account.storage_scheme != storage_enums::MerchantStorageScheme::PostgresOnly {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "Merging customers of merchants using the key value storage scheme"
+                .to_string(),
+        }
+        .into());
+    }
+    if surviving_customer_id == merged_customer_id {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A customer cannot be merged into itself".to_string(),
+        }
+        .into());
+    }
+
+    let surviving_customer =
+        find_customer(&state, &merchant_context, &surviving_customer_id).await?;
+    let merged_customer = find_customer(&state, &merchant_context, &merged_customer_id).await?;
+
+    // Merges involving the same customer would move its payment methods, mandates and payments
+    // concurrently, so a customer takes part in a single merge in progress at a time
+    for customer_id in [&surviving_customer_id, &merged_customer_id] {
+        let is_in_another_merge = db
+            .list_customer_merges_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merges of the customer")?
+            .iter()
+            .any(|customer_merge| {
+                customer_merge.status == storage_enums::CustomerMergeStatus::InProgress
+                    && (customer_merge.surviving_customer_id != surviving_customer_id
+                        || customer_merge.merged_customer_id != merged_customer_id)
+            });
+        if is_in_another_merge {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The customer {} is part of another merge in progress",
+                    customer_id.get_string_repr()
+                ),
+            }
+            .into());
+        }
+    }
+
+    // The merge is recorded before anything is moved and updated as the merge progresses, so
+    // that a merge which fails midway is audited and resumed when the customers are merged again
+    let mut customer_merge = match db
+        .find_in_progress_customer_merge_by_merchant_id_merged_customer_id(
+            merchant_id,
+            &merged_customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the in progress merge of the merged customer")?
+    {
+        Some(customer_merge) => customer_merge,
+        None => {
+            let now = common_utils::date_time::now();
+            db.insert_customer_merge(storage::CustomerMergeNew {
+                merge_id: utils::generate_id(consts::ID_LENGTH, CUSTOMER_MERGE_ID_PREFIX),
+                merchant_id: merchant_id.clone(),
+                surviving_customer_id: surviving_customer_id.clone(),
+                merged_customer_id: merged_customer_id.clone(),
+                payment_method_ids: Vec::new(),
+                mandate_ids: Vec::new(),
+                payments_moved: 0,
+                reason: request.request.reason,
+                created_at: now,
+                status: storage_enums::CustomerMergeStatus::InProgress,
+                orphaned_locker_references: Vec::new(),
+                modified_at: now,
+            })
+            .await
+            .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The customer is being merged into another customer".to_string(),
+            })
+            .attach_printable("Failed to record the customer merge")?
+        }
+    };
+
+    let payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            &merged_customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment methods of the merged customer")?,
+    };
+
+    for payment_method in payment_methods {
+        let moved_locker_references = move_payment_method(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            merchant_id,
+            &merged_customer_id,
+            &surviving_customer_id,
+            &payment_method,
+        )
+        .await?;
+
+        // The payment method is already stored for the surviving customer, so the copies of the
+        // merged customer which could not be deleted are recorded for cleaning up the locker
+        let orphaned_locker_references = delete_locker_data(
+            &state,
+            merchant_id,
+            &merged_customer_id,
+            moved_locker_references,
+        )
+        .await;
+
+        let mut payment_method_ids = customer_merge.payment_method_ids.clone();
+        payment_method_ids.push(payment_method.payment_method_id);
+        let mut all_orphaned_locker_references = customer_merge.orphaned_locker_references.clone();
+        all_orphaned_locker_references.extend(orphaned_locker_references);
+        customer_merge = db
+            .update_customer_merge(
+                customer_merge,
+                storage::CustomerMergeUpdate::PaymentMethodMoved {
+                    payment_method_ids,
+                    orphaned_locker_references: all_orphaned_locker_references,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the payment method moved by the customer merge")?;
+    }
+
+    let mandate_ids = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &merged_customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the merged customer")?
+        .into_iter()
+        .map(|mandate| mandate.mandate_id)
+        .collect::<Vec<_>>();
+    if !mandate_ids.is_empty() {
+        db.update_mandates_customer_id_by_merchant_id_customer_id(
+            merchant_id,
+            &merged_customer_id,
+            &surviving_customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to move the mandates to the surviving customer")?;
+    }
+
+    let payments_moved = db
+        .update_payment_intents_customer_id_by_merchant_id_customer_id(
+            merchant_id,
+            &merged_customer_id,
+            &surviving_customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to move the payments to the surviving customer")?;
+    let payments_moved = i64::try_from(payments_moved)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of moved payments exceeds the range of i64")?;
+
+    update_surviving_customer(
+        &state,
+        &merchant_context,
+        surviving_customer,
+        &merged_customer,
+    )
+    .await?;
+    if merged_customer.default_payment_method_id.is_some() {
+        db.update_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            merged_customer_id.clone(),
+            merchant_id.clone(),
+            merged_customer,
+            storage::CustomerUpdate::UpdateDefaultPaymentMethod {
+                default_payment_method_id: Some(None),
+            },
+            merchant_context.get_merchant_key_store(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to clear the default payment method of the merged customer")?;
+    }
+
+    // The merged customer no longer holds anything, and is not reported as a duplicate anymore
+    delete_customer_blind_indexes(&state, merchant_id, &merged_customer_id).await?;
+
+    // Mandates and payments moved by an earlier attempt of the merge are retained in the record
+    let mut all_mandate_ids = customer_merge.mandate_ids.clone();
+    all_mandate_ids.extend(mandate_ids);
+    let payments_moved = customer_merge.payments_moved.saturating_add(payments_moved);
+    let customer_merge = db
+        .update_customer_merge(
+            customer_merge,
+            storage::CustomerMergeUpdate::Completed {
+                mandate_ids: all_mandate_ids,
+                payments_moved,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to complete the customer merge")?;
+
+    Ok(services::ApplicationResponse::Json(get_merge_response(
+        customer_merge,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_customer_merges(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerMergeListResponse> {
+    let data = state
+        .store
+        .list_customer_merges_by_merchant_id_customer_id(
+            merchant_context.get_merchant_account().get_id(),
+            &customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merges of the customer")?
+        .into_iter()
+        .map(get_merge_response)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerMergeListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Stores the locker data of a payment method for the new customer, under the same reference for
+/// cards, returning the reference assigned by the locker
+async fn move_locker_data(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    new_customer_id: &id_type::CustomerId,
+    locker_reference: &str,
+    is_card: bool,
+) -> RouterResult<String> {
+    let payload = if is_card {
+        let card =
+            cards::get_card_from_locker(state, customer_id, merchant_id, locker_reference).await?;
+        pm_transformers::StoreLockerReq::LockerCard(pm_transformers::StoreCardReq {
+            merchant_id: merchant_id.clone(),
+            merchant_customer_id: new_customer_id.clone(),
+            requestor_card_reference: Some(locker_reference.to_string()),
+            card,
+            ttl: state.conf.locker.ttl_for_storage_in_secs,
+        })
+    } else {
+        let payment_method_data = cards::get_payment_method_from_hs_locker(
+            state,
+            key_store,
+            customer_id,
+            merchant_id,
+            locker_reference,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment method from the locker")?;
+        pm_transformers::StoreLockerReq::LockerGeneric(pm_transformers::StoreGenericReq {
+            merchant_id: merchant_id.clone(),
+            merchant_customer_id: new_customer_id.clone(),
+            enc_data: encrypt_locker_data(state, key_store, payment_method_data).await?,
+            ttl: state.conf.locker.ttl_for_storage_in_secs,
+        })
+    };
+    let stored_payment_method = cards::add_card_to_hs_locker(
+        state,
+        &payload,
+        new_customer_id,
+        api::enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable(
+        "Failed to store the payment method in the locker for the surviving customer",
+    )?;
+    Ok(stored_payment_method.card_reference)
+}
+
+/// Encrypts payment method data other than cards with the merchant key, in the hex encoded form
+/// in which it is stored in the locker
+async fn encrypt_locker_data(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_data: Secret<String>,
+) -> RouterResult<String> {
+    let encrypted_data = domain::types::crypto_operation(
+        &state.into(),
+        type_name!(storage::PaymentMethod),
+        domain::types::CryptoOperation::Encrypt(payment_method_data),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|value| value.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the payment method data for the locker")?;
+    Ok(hex::encode(
+        Encryption::from(encrypted_data).into_inner().peek(),
+    ))
+}
+
+/// Moves the payment method and its locker data to the surviving customer, returning the locker
+/// references of the merged customer whose data was moved.
+///
+/// If the payment method cannot be moved, the copies of the locker data stored for the surviving
+/// customer are deleted, so that they are not left behind unreferenced.
+async fn move_payment_method(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    merged_customer_id: &id_type::CustomerId,
+    surviving_customer_id: &id_type::CustomerId,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<Vec<String>> {
+    let mut moved_locker_references = Vec::new();
+    let mut new_locker_references = Vec::new();
+    let result = async {
+        let locker_id = match payment_method.locker_id.as_deref() {
+            Some(locker_id) => {
+                let new_locker_id = move_locker_data(
+                    state,
+                    key_store,
+                    merchant_id,
+                    merged_customer_id,
+                    surviving_customer_id,
+                    locker_id,
+                    payment_method.get_payment_method_type()
+                        == Some(storage_enums::PaymentMethod::Card),
+                )
+                .await?;
+                moved_locker_references.push(locker_id.to_string());
+                new_locker_references.push(new_locker_id.clone());
+                Some(new_locker_id)
+            }
+            None => None,
+        };
+        let network_token_locker_id = match payment_method.network_token_locker_id.as_deref() {
+            Some(network_token_locker_id) => {
+                let new_network_token_locker_id = move_locker_data(
+                    state,
+                    key_store,
+                    merchant_id,
+                    merged_customer_id,
+                    surviving_customer_id,
+                    network_token_locker_id,
+                    true,
+                )
+                .await?;
+                moved_locker_references.push(network_token_locker_id.to_string());
+                new_locker_references.push(new_network_token_locker_id.clone());
+                Some(new_network_token_locker_id)
+            }
+            None => None,
+        };
+
+        state
+            .store
+            .update_payment_method_customer_id_and_locker_ids(
+                &payment_method.payment_method_id,
+                surviving_customer_id,
+                locker_id,
+                network_token_locker_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to move the payment method to the surviving customer")
+    }
+    .await;
+
+    match result {
+        Ok(_) => Ok(moved_locker_references),
+        Err(error) => {
+            let orphaned_locker_references = delete_locker_data(
+                state,
+                merchant_id,
+                surviving_customer_id,
+                new_locker_references,
+            )
+            .await;
+            if !orphaned_locker_references.is_empty() {
+                logger::error!(
+                    payment_method_id = %payment_method.payment_method_id,
+                    ?orphaned_locker_references,
+                    "Locker data stored for the surviving customer could not be deleted"
+                );
+            }
+            Err(error)
+        }
+    }
+}
+
+/// Deletes the locker data of the customer, returning the references which could not be deleted
+async fn delete_locker_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    locker_references: Vec<String>,
+) -> Vec<String> {
+    let mut failed_locker_references = Vec::new();
+    for locker_reference in locker_references {
+        if let Err(error) =
+            cards::delete_card_from_hs_locker(state, customer_id, merchant_id, &locker_reference)
+                .await
+        {
+            logger::error!(?error, "Failed to delete the locker data of the customer");
+            failed_locker_references.push(locker_reference);
+        }
+    }
+    failed_locker_references
+}
+
+/// Carries over the connector customers and the default payment method of the merged customer
+/// which the surviving customer does not have
+async fn update_surviving_customer(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    mut surviving_customer: domain::Customer,
+    merged_customer: &domain::Customer,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    if let Some(connector_customer) = get_merged_connector_customer(
+        surviving_customer.connector_customer.as_ref(),
+        merged_customer.connector_customer.as_ref(),
+    ) {
+        surviving_customer = db
+            .update_customer_by_customer_id_merchant_id(
+                key_manager_state,
+                surviving_customer.customer_id.clone(),
+                merchant_id.clone(),
+                surviving_customer,
+                storage::CustomerUpdate::ConnectorCustomer {
+                    connector_customer: Some(connector_customer),
+                },
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to update the connector customers of the surviving customer",
+            )?;
+    }
+
+    if surviving_customer.default_payment_method_id.is_none()
+        && merged_customer.default_payment_method_id.is_some()
+    {
+        db.update_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            surviving_customer.customer_id.clone(),
+            merchant_id.clone(),
+            surviving_customer,
+            storage::CustomerUpdate::UpdateDefaultPaymentMethod {
+                default_payment_method_id: Some(merged_customer.default_payment_method_id.clone()),
+            },
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to update the default payment method of the surviving customer",
+        )?;
+    }
+    Ok(())
+}
+
+/// Adds the connector customers of the merged customer to those of the surviving customer, for
+/// the connectors at which the surviving customer does not exist. Returns `None` if nothing was
+/// added
+fn get_merged_connector_customer(
+    surviving_connector_customer: Option<&pii::SecretSerdeValue>,
+    merged_connector_customer: Option<&pii::SecretSerdeValue>,
+) -> Option<pii::SecretSerdeValue> {
+    let merged_connector_customers = merged_connector_customer?.peek().as_object()?;
+    let mut connector_customers = surviving_connector_customer
+        .and_then(|connector_customer| connector_customer.peek().as_object().cloned())
+        .unwrap_or_default();
+
+    let mut is_updated = false;
+    for (merchant_connector_id, connector_customer_id) in merged_connector_customers {
+        if !connector_customers.contains_key(merchant_connector_id) {
+            connector_customers
+                .insert(merchant_connector_id.clone(), connector_customer_id.clone());
+            is_updated = true;
+        }
+    }
+    is_updated.then(|| pii::SecretSerdeValue::new(serde_json::Value::Object(connector_customers)))
+}
+
+async fn find_customer(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<domain::Customer> {
+    state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &state.into(),
+            customer_id,
+            merchant_context.get_merchant_account().get_id(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
+}
+
+fn get_customer_blind_indexes(
+    key_store: &domain::MerchantKeyStore,
+    customer: &domain::Customer,
+) -> RouterResult<Vec<(storage_enums::CustomerBlindIndexField, String)>> {
+    let blind_index_key = HmacSha256
+        .sign_message(key_store.key.get_inner().peek(), BLIND_INDEX_KEY_CONTEXT)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive the blind index key")?;
+
+    let normalized_values = [
+        (
+            storage_enums::CustomerBlindIndexField::Email,
+            customer
+                .email
+                .as_ref()
+                .and_then(|email| normalize_email(email.get_inner().peek())),
+        ),
+        (
+            storage_enums::CustomerBlindIndexField::Phone,
+            customer.phone.as_ref().and_then(|phone| {
+                normalize_phone(
+                    phone.get_inner().peek(),
+                    customer.phone_country_code.as_deref(),
+                )
+            }),
+        ),
+    ];
+
+    normalized_values
+        .into_iter()
+        .filter_map(|(field, normalized_value)| {
+            normalized_value.map(|value| {
+                get_blind_index(&blind_index_key, field, &value)
+                    .map(|hashed_value| (field, hashed_value))
+            })
+        })
+        .collect()
+}
+
+fn get_blind_index(
+    blind_index_key: &[u8],
+    field: storage_enums::CustomerBlindIndexField,
+    normalized_value: &str,
+) -> RouterResult<String> {
+    HmacSha256
+        .sign_message(
+            blind_index_key,
+            format!("{field}:{normalized_value}").as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the blind index")
+}
+
+/// Emails are compared case insensitively. Values which are not emails, such as those of redacted
+/// customers, are not indexed
+fn normalize_email(email: &str) -> Option<String> {
+    let email = email.trim().to_lowercase();
+    email.contains('@').then_some(email)
+}
+
+/// Phone numbers are compared by their digits in international format. The country code is
+/// prefixed unless the number is already international, dropping the trunk prefix of the national
+/// number
+fn normalize_phone(phone: &str, phone_country_code: Option<&str>) -> Option<String> {
+    let get_digits = |value: &str| {
+        value
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+    };
+    let number = get_digits(phone);
+    if number.len() < MIN_PHONE_NUMBER_DIGITS {
+        return None;
+    }
+    if phone.trim_start().starts_with('+') {
+        return Some(number);
+    }
+    let country_code = phone_country_code.map(get_digits).unwrap_or_default();
+    Some(format!("{country_code}{}", number.trim_start_matches('0')))
+}
+
+fn get_merge_response(customer_merge: storage::CustomerMerge) -> CustomerMergeResponse {
+    CustomerMergeResponse {
+        merge_id: customer_merge.merge_id,
+        surviving_customer_id: customer_merge.surviving_customer_id,
+        merged_customer_id: customer_merge.merged_customer_id,
+        payment_method_ids: customer_merge.payment_method_ids,
+        mandate_ids: customer_merge.mandate_ids,
+        payments_moved: customer_merge.payments_moved,
+        status: customer_merge.status,
+        reason: customer_merge.reason,
+        created_at: customer_merge.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_normalize_email() {
+        assert_eq!(
+            normalize_email("  John.Doe@Example.com "),
+            Some("john.doe@example.com".to_string())
+        );
+        assert_eq!(normalize_email("Redacted"), None);
+    }
+
+    #[test]
+    fn test_normalize_phone() {
+        let expected = Some("447700900123".to_string());
+        assert_eq!(normalize_phone("07700 900123", Some("+44")), expected);
+        assert_eq!(normalize_phone("7700-900-123", Some("+44")), expected);
+        assert_eq!(normalize_phone("+44 7700 900123", Some("+1")), expected);
+        assert_eq!(normalize_phone("Redacted", Some("Redacted")), None);
+        assert_eq!(normalize_phone("123", Some("+1")), None);
+    }
+
+    #[test]
+    fn test_blind_index_is_keyed() {
+        let email = storage_enums::CustomerBlindIndexField::Email;
+        let index = get_blind_index(b"key_1", email, "john.doe@example.com").unwrap();
+        assert_eq!(
+            index,
+            get_blind_index(b"key_1", email, "john.doe@example.com").unwrap()
+        );
+        assert_ne!(
+            index,
+            get_blind_index(b"key_2", email, "john.doe@example.com").unwrap()
+        );
+        assert_ne!(
+            index,
+            get_blind_index(
+                b"key_1",
+                storage_enums::CustomerBlindIndexField::Phone,
+                "john.doe@example.com"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_merged_connector_customer() {
+        let surviving = pii::SecretSerdeValue::new(serde_json::json!({ "mca_1": "cus_a" }));
+        let merged =
+            pii::SecretSerdeValue::new(serde_json::json!({ "mca_1": "cus_b", "mca_2": "cus_c" }));
+
+        let connector_customer =
+            get_merged_connector_customer(Some(&surviving), Some(&merged)).unwrap();
+        assert_eq!(
+            connector_customer.peek(),
+            &serde_json::json!({ "mca_1": "cus_a", "mca_2": "cus_c" })
+        );
+        assert!(get_merged_connector_customer(Some(&merged), Some(&surviving)).is_none());
+        assert!(get_merged_connector_customer(Some(&surviving), None).is_none());
+    }
+}
@@ -12,12 +12,14 @@ use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::payment_methods as payment_methods_domain;
 use masking::{ExposeInterface, Secret, SwitchStrategy};
 use payment_methods::controller::PaymentMethodsController;
+#[cfg(feature = "v1")]
+use router_env::logger;
 use router_env::{instrument, tracing};
 
 #[cfg(feature = "v2")]
 use crate::core::payment_methods::cards::create_encrypted_data;
 #[cfg(feature = "v1")]
-use crate::utils::CustomerAddress;
+use crate::{core::customer_deduplication, utils::CustomerAddress};
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
@@ -90,6 +92,16 @@ pub async fn create_customer(
         .await
         .to_duplicate_response(errors::CustomersErrorResponse::CustomerAlreadyExists)?;
 
+    #[cfg(feature = "v1")]
+    customer_deduplication::index_customer(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &customer,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to index the customer for deduplication"))
+    .ok();
+
     customer_data.generate_response(&customer)
 }
 
@@ -1020,6 +1032,14 @@ impl CustomerDeleteBridge for id_type::CustomerId {
         .await
         .switch()?;
 
+        customer_deduplication::delete_customer_blind_indexes(
+            state,
+            merchant_context.get_merchant_account().get_id(),
+            self,
+        )
+        .await
+        .change_context(errors::CustomersErrorResponse::InternalServerError)?;
+
         let response = customers::CustomerDeleteResponse {
             customer_id: self.clone(),
             customer_deleted: true,
@@ -1073,6 +1093,16 @@ pub async fn update_customer(
         )
         .await?;
 
+    #[cfg(feature = "v1")]
+    customer_deduplication::index_customer(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &updated_customer,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to index the customer for deduplication"))
+    .ok();
+
     update_customer.request.generate_response(&updated_customer)
 }
 
@@ -3,13 +3,21 @@ use std::{collections::HashMap, ops::Deref, str::FromStr};
 use api_models::{
     admin::MerchantConnectorInfo, disputes as dispute_models, files as files_api_models,
 };
-use common_utils::ext_traits::{Encode, ValueExt};
+use common_types::disputes::{
+    DisputeFinancialRecord, DisputeFinancialRecordSource, DisputeFinancialRecordType,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
 use error_stack::ResultExt;
 use router_env::{
     instrument, logger,
     tracing::{self, Instrument},
 };
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod automation;
 pub mod transformers;
 
 use super::{
@@ -17,13 +25,14 @@ use super::{
     metrics,
 };
 use crate::{
-    core::{files, payments, utils as core_utils, webhooks},
-    routes::{app::StorageInterface, metrics::TASKS_ADDED_COUNT, SessionState},
+    consts,
+    core::{api_locking, files, payments, utils as core_utils, webhooks},
+    routes::{app::StorageInterface, lock_utils, metrics::TASKS_ADDED_COUNT, SessionState},
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
         transformers::{ForeignFrom, ForeignInto},
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, DisputePayload, DisputeSyncData, DisputeSyncResponse,
@@ -58,6 +67,7 @@ pub async fn retrieve_dispute(
         .find_dispute_by_merchant_id_dispute_id(
             merchant_context.get_merchant_account().get_id(),
             &req.dispute_id,
+            merchant_context.get_merchant_account().storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -268,6 +278,7 @@ pub async fn accept_dispute(
         .find_dispute_by_merchant_id_dispute_id(
             merchant_context.get_merchant_account().get_id(),
             &req.dispute_id,
+            merchant_context.get_merchant_account().storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -355,7 +366,11 @@ pub async fn accept_dispute(
         connector_status: accept_dispute_response.connector_status.clone(),
     };
     let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+        .update_dispute(
+            dispute.clone(),
+            update_dispute,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| {
@@ -390,6 +405,7 @@ pub async fn submit_evidence(
         .find_dispute_by_merchant_id_dispute_id(
             merchant_context.get_merchant_account().get_id(),
             &req.dispute_id,
+            merchant_context.get_merchant_account().storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -529,7 +545,11 @@ pub async fn submit_evidence(
         connector_status,
     };
     let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+        .update_dispute(
+            dispute.clone(),
+            update_dispute,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -553,10 +573,12 @@ pub async fn attach_evidence(
         .dispute_id
         .clone()
         .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
     let dispute = db
         .find_dispute_by_merchant_id_dispute_id(
             merchant_context.get_merchant_account().get_id(),
             &dispute_id,
+            storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -605,7 +627,7 @@ pub async fn attach_evidence(
             .attach_printable("Error while encoding dispute evidence")?
             .into(),
     };
-    db.update_dispute(dispute, update_dispute)
+    db.update_dispute(dispute, update_dispute, storage_scheme)
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -628,6 +650,7 @@ pub async fn retrieve_dispute_evidence(
         .find_dispute_by_merchant_id_dispute_id(
             merchant_context.get_merchant_account().get_id(),
             &req.dispute_id,
+            merchant_context.get_merchant_account().storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -656,6 +679,7 @@ pub async fn delete_evidence(
         .find_dispute_by_merchant_id_dispute_id(
             merchant_context.get_merchant_account().get_id(),
             &dispute_id,
+            merchant_context.get_merchant_account().storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -678,7 +702,11 @@ pub async fn delete_evidence(
     };
     state
         .store
-        .update_dispute(dispute, update_dispute)
+        .update_dispute(
+            dispute,
+            update_dispute,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -689,6 +717,172 @@ pub async fn delete_evidence(
     Ok(services::ApplicationResponse::StatusOk)
 }
 
+/// Lock held while the financial records of a dispute are read and written back, so that records
+/// added concurrently through the API and webhooks are not lost
+pub fn get_dispute_financial_records_lock_action(dispute_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: dispute_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Disputes,
+            override_lock_retries: None,
+        },
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn add_financial_record(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: dispute_models::DisputeFinancialRecordRequest,
+) -> RouterResponse<dispute_models::DisputeResponse> {
+    let dispute_id = req.dispute_id.clone();
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_context.get_merchant_account().get_id(),
+            &dispute_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.clone(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    if req.amount < MinorUnit::zero() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount of a dispute financial record cannot be negative".to_string(),
+        }
+        .into());
+    }
+    let financial_record = DisputeFinancialRecord {
+        record_type: req.record_type,
+        amount: req.amount,
+        currency: req.currency,
+        source: DisputeFinancialRecordSource::Manual,
+        reference_id: req.reference_id,
+        description: req.description,
+        created_at: common_utils::date_time::now(),
+    };
+    let update_dispute = diesel_models::dispute::DisputeUpdate::FinancialRecordsUpdate {
+        financial_records: dispute
+            .financial_records
+            .clone()
+            .unwrap_or_default()
+            .merge(vec![financial_record]),
+    };
+    let updated_dispute = state
+        .store
+        .update_dispute(
+            dispute,
+            update_dispute,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.to_owned(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeResponse::foreign_from(updated_dispute),
+    ))
+}
+
+type DisputesNetLossKey = (String, Option<String>, storage_enums::Currency);
+
+/// Adds the financial impact of the disputes to the net loss computed so far, grouped by
+/// connector, reason code and the currency of the financial records
+fn accumulate_disputes_net_loss(
+    net_loss_map: &mut HashMap<DisputesNetLossKey, dispute_models::DisputeNetLoss>,
+    disputes: &[storage::DisputeFinancialRecordsEntry],
+) {
+    for dispute in disputes {
+        let Some(financial_records) = dispute.financial_records.as_ref() else {
+            continue;
+        };
+        let mut counted_currencies = Vec::new();
+        for record in financial_records.get_records() {
+            let net_loss = net_loss_map
+                .entry((
+                    dispute.connector.clone(),
+                    dispute.connector_reason_code.clone(),
+                    record.currency,
+                ))
+                .or_insert_with(|| dispute_models::DisputeNetLoss {
+                    connector: dispute.connector.clone(),
+                    connector_reason_code: dispute.connector_reason_code.clone(),
+                    currency: record.currency,
+                    dispute_count: 0,
+                    total_fees: MinorUnit::zero(),
+                    total_amount_debited: MinorUnit::zero(),
+                    total_amount_reinstated: MinorUnit::zero(),
+                    net_loss: MinorUnit::zero(),
+                });
+
+            if !counted_currencies.contains(&record.currency) {
+                counted_currencies.push(record.currency);
+                net_loss.dispute_count += 1;
+            }
+
+            match record.record_type {
+                DisputeFinancialRecordType::ChargebackFee => {
+                    net_loss.total_fees = net_loss.total_fees + record.amount
+                }
+                DisputeFinancialRecordType::FeeRefunded => {
+                    net_loss.total_fees = net_loss.total_fees - record.amount
+                }
+                DisputeFinancialRecordType::AmountDebited => {
+                    net_loss.total_amount_debited = net_loss.total_amount_debited + record.amount
+                }
+                DisputeFinancialRecordType::AmountReinstated => {
+                    net_loss.total_amount_reinstated =
+                        net_loss.total_amount_reinstated + record.amount
+                }
+            }
+            net_loss.net_loss = net_loss.net_loss - record.get_signed_amount();
+        }
+    }
+}
+
+/// Computes the net loss of the disputes created within the time range, paging through the
+/// disputes which have financial records so that only a page is held in memory at a time
+async fn get_disputes_net_loss(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    time_range: &common_utils::types::TimeRange,
+) -> errors::RouterResult<Vec<dispute_models::DisputeNetLoss>> {
+    let mut net_loss_map = HashMap::new();
+    let mut offset = 0;
+
+    loop {
+        let disputes = db
+            .get_dispute_financial_records(
+                merchant_id,
+                profile_id_list.clone(),
+                time_range,
+                consts::DISPUTE_FINANCIAL_RECORDS_PAGE_SIZE,
+                offset,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to retrieve dispute financial records")?;
+
+        accumulate_disputes_net_loss(&mut net_loss_map, &disputes);
+
+        if i64::try_from(disputes.len()).unwrap_or(i64::MAX)
+            < consts::DISPUTE_FINANCIAL_RECORDS_PAGE_SIZE
+        {
+            break;
+        }
+        offset += consts::DISPUTE_FINANCIAL_RECORDS_PAGE_SIZE;
+    }
+
+    Ok(net_loss_map.into_values().collect())
+}
+
 #[instrument(skip(state))]
 pub async fn get_aggregates_for_disputes(
     state: SessionState,
@@ -700,7 +894,7 @@ pub async fn get_aggregates_for_disputes(
     let dispute_status_with_count = db
         .get_dispute_status_with_count(
             merchant_context.get_merchant_account().get_id(),
-            profile_id_list,
+            profile_id_list.clone(),
             &time_range,
         )
         .await
@@ -714,9 +908,18 @@ pub async fn get_aggregates_for_disputes(
         status_map.entry(status).or_default();
     }
 
+    let net_loss = get_disputes_net_loss(
+        db,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id_list,
+        &time_range,
+    )
+    .await?;
+
     Ok(services::ApplicationResponse::Json(
         dispute_models::DisputesAggregateResponse {
             status_with_count: status_map,
+            net_loss,
         },
     ))
 }
@@ -889,6 +1092,7 @@ pub async fn update_dispute_data(
         dispute_data,
         merchant_context.get_merchant_account().get_id(),
         &merchant_context.get_merchant_account().organization_id,
+        merchant_context.get_merchant_account().storage_scheme,
         &payment_attempt,
         dispute_details.dispute_status,
         &business_profile,
@@ -913,6 +1117,52 @@ pub async fn update_dispute_data(
     Ok(disputes_response)
 }
 
+/// Stores a newly discovered dispute and schedules its automation, if the automation rules of
+/// its profile apply to it. Incoming webhooks as well as the dispute list and sync flows create
+/// disputes through this function, so that every dispute is considered for automation.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn insert_dispute(
+    state: &SessionState,
+    new_dispute: diesel_models::dispute::DisputeNew,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> errors::CustomResult<diesel_models::dispute::Dispute, errors::StorageError> {
+    let dispute = state
+        .store
+        .insert_dispute(new_dispute, storage_scheme)
+        .await?;
+
+    automation::schedule_dispute_automation_task(state, &dispute)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to schedule dispute automation task"))
+        .ok();
+
+    Ok(dispute)
+}
+
+/// Updates a dispute with the details received from the connector and schedules its automation,
+/// as the dispute could have moved to a state where the automation rules of its profile apply
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn update_dispute(
+    state: &SessionState,
+    dispute: diesel_models::dispute::Dispute,
+    dispute_update: diesel_models::dispute::DisputeUpdate,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> errors::CustomResult<diesel_models::dispute::Dispute, errors::StorageError> {
+    let dispute = state
+        .store
+        .update_dispute(dispute, dispute_update, storage_scheme)
+        .await?;
+
+    automation::schedule_dispute_automation_task(state, &dispute)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to schedule dispute automation task"))
+        .ok();
+
+    Ok(dispute)
+}
+
 #[cfg(feature = "v1")]
 pub async fn add_process_dispute_task_to_pt(
     db: &dyn StorageInterface,
@@ -1055,3 +1305,159 @@ pub async fn schedule_dispute_sync_task(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use common_types::disputes::DisputeFinancialRecords;
+
+    use super::*;
+
+    fn record(
+        record_type: DisputeFinancialRecordType,
+        amount: i64,
+        currency: storage_enums::Currency,
+    ) -> DisputeFinancialRecord {
+        DisputeFinancialRecord {
+            record_type,
+            amount: MinorUnit::new(amount),
+            currency,
+            source: DisputeFinancialRecordSource::Connector,
+            reference_id: None,
+            description: None,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    fn dispute(
+        connector: &str,
+        records: Option<Vec<DisputeFinancialRecord>>,
+    ) -> storage::DisputeFinancialRecordsEntry {
+        storage::DisputeFinancialRecordsEntry {
+            connector: connector.to_string(),
+            connector_reason_code: Some("fraudulent".to_string()),
+            financial_records: records.map(DisputeFinancialRecords),
+        }
+    }
+
+    fn get_net_loss(
+        pages: &[Vec<storage::DisputeFinancialRecordsEntry>],
+    ) -> HashMap<DisputesNetLossKey, dispute_models::DisputeNetLoss> {
+        let mut net_loss_map = HashMap::new();
+        for page in pages {
+            accumulate_disputes_net_loss(&mut net_loss_map, page);
+        }
+        net_loss_map
+    }
+
+    #[test]
+    fn test_net_loss_of_lost_and_won_disputes() {
+        let net_loss_map = get_net_loss(&[vec![
+            dispute(
+                "stripe",
+                Some(vec![
+                    record(
+                        DisputeFinancialRecordType::AmountDebited,
+                        10000,
+                        storage_enums::Currency::USD,
+                    ),
+                    record(
+                        DisputeFinancialRecordType::ChargebackFee,
+                        1500,
+                        storage_enums::Currency::USD,
+                    ),
+                ]),
+            ),
+            dispute(
+                "stripe",
+                Some(vec![
+                    record(
+                        DisputeFinancialRecordType::AmountDebited,
+                        5000,
+                        storage_enums::Currency::USD,
+                    ),
+                    record(
+                        DisputeFinancialRecordType::ChargebackFee,
+                        1500,
+                        storage_enums::Currency::USD,
+                    ),
+                    record(
+                        DisputeFinancialRecordType::AmountReinstated,
+                        5000,
+                        storage_enums::Currency::USD,
+                    ),
+                    record(
+                        DisputeFinancialRecordType::FeeRefunded,
+                        1500,
+                        storage_enums::Currency::USD,
+                    ),
+                ]),
+            ),
+            dispute("stripe", None),
+        ]]);
+
+        assert_eq!(net_loss_map.len(), 1);
+        let net_loss = net_loss_map
+            .get(&(
+                "stripe".to_string(),
+                Some("fraudulent".to_string()),
+                storage_enums::Currency::USD,
+            ))
+            .expect("net loss for stripe in USD");
+        assert_eq!(net_loss.dispute_count, 2);
+        assert_eq!(net_loss.total_fees, MinorUnit::new(1500));
+        assert_eq!(net_loss.total_amount_debited, MinorUnit::new(15000));
+        assert_eq!(net_loss.total_amount_reinstated, MinorUnit::new(5000));
+        assert_eq!(net_loss.net_loss, MinorUnit::new(11500));
+    }
+
+    #[test]
+    fn test_net_loss_is_grouped_by_record_currency_across_pages() {
+        let net_loss_map = get_net_loss(&[
+            vec![dispute(
+                "adyen",
+                Some(vec![
+                    record(
+                        DisputeFinancialRecordType::AmountDebited,
+                        10000,
+                        storage_enums::Currency::EUR,
+                    ),
+                    record(
+                        DisputeFinancialRecordType::ChargebackFee,
+                        2500,
+                        storage_enums::Currency::USD,
+                    ),
+                ]),
+            )],
+            vec![dispute(
+                "adyen",
+                Some(vec![record(
+                    DisputeFinancialRecordType::AmountDebited,
+                    2000,
+                    storage_enums::Currency::EUR,
+                )]),
+            )],
+        ]);
+
+        let eur_net_loss = net_loss_map
+            .get(&(
+                "adyen".to_string(),
+                Some("fraudulent".to_string()),
+                storage_enums::Currency::EUR,
+            ))
+            .expect("net loss for adyen in EUR");
+        assert_eq!(eur_net_loss.dispute_count, 2);
+        assert_eq!(eur_net_loss.net_loss, MinorUnit::new(12000));
+
+        let usd_net_loss = net_loss_map
+            .get(&(
+                "adyen".to_string(),
+                Some("fraudulent".to_string()),
+                storage_enums::Currency::USD,
+            ))
+            .expect("net loss for adyen in USD");
+        assert_eq!(usd_net_loss.dispute_count, 1);
+        assert_eq!(usd_net_loss.total_fees, MinorUnit::new(2500));
+        assert_eq!(usd_net_loss.net_loss, MinorUnit::new(2500));
+    }
+}
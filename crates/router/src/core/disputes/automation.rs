@@ -0,0 +1,835 @@
+use std::collections::HashSet;
+
+use api_models::disputes::{
+    DisputeAutomationConfig, DisputeAutomationConfigResponse, DisputeAutomationRules,
+    DisputeEvidenceTemplate, SubmitEvidenceRequest,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    id_type,
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::router_request_types::BrowserInformation;
+use masking::ExposeInterface;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{
+        api::{self, disputes},
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const DISPUTE_AUTOMATION_TASK: &str = "DISPUTE_AUTOMATION";
+
+fn validate_dispute_automation_config(config: &DisputeAutomationConfig) -> RouterResult<()> {
+    let mut currencies = HashSet::with_capacity(config.rules.auto_accept.len());
+    for rule in &config.rules.auto_accept {
+        if !currencies.insert(rule.currency) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate auto accept rule for currency {}", rule.currency),
+            }
+            .into());
+        }
+        if rule.max_amount < MinorUnit::zero() {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "max_amount of the auto accept rule for currency {} cannot be negative",
+                    rule.currency
+                ),
+            }
+            .into());
+        }
+    }
+
+    if let Some(days) = config.rules.auto_submit_days_before_due {
+        if days == 0 || days > consts::MAX_DISPUTE_AUTO_SUBMIT_DAYS_BEFORE_DUE {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "auto_submit_days_before_due must be between 1 and {}",
+                    consts::MAX_DISPUTE_AUTO_SUBMIT_DAYS_BEFORE_DUE
+                ),
+            }
+            .into());
+        }
+        if config.evidence_template.is_none() {
+            return Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "evidence_template",
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &state.into(),
+        merchant_context.get_merchant_key_store(),
+        Some(profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await
+    .map(|_| ())
+}
+
+/// Fetches the dispute automation config of the profile, if any
+pub async fn get_dispute_automation_config(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<DisputeAutomationConfig>> {
+    core_utils::find_optional_json_config(
+        state,
+        &profile_id.get_dispute_automation_config_key(),
+        "DisputeAutomationConfig",
+    )
+    .await
+}
+
+pub async fn upsert_dispute_automation_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+    request: DisputeAutomationConfig,
+) -> RouterResponse<DisputeAutomationConfigResponse> {
+    validate_dispute_automation_config(&request)?;
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    let db = state.store.as_ref();
+    let key = profile_id.get_dispute_automation_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize dispute automation config")?;
+
+    if get_dispute_automation_config(&state, &profile_id)
+        .await?
+        .is_some()
+    {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update dispute automation config")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_config,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert dispute automation config")?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        DisputeAutomationConfigResponse {
+            profile_id,
+            evidence_template: request.evidence_template,
+            rules: request.rules,
+        },
+    ))
+}
+
+pub async fn retrieve_dispute_automation_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<DisputeAutomationConfigResponse> {
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    let config = get_dispute_automation_config(&state, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Dispute automation is not configured for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        DisputeAutomationConfigResponse {
+            profile_id,
+            evidence_template: config.evidence_template,
+            rules: config.rules,
+        },
+    ))
+}
+
+pub async fn delete_dispute_automation_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_dispute_automation_config_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Dispute automation is not configured for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Decides the action to be taken on the dispute as per the automation rules, along with the
+/// time at which it is to be taken. Auto acceptance takes precedence over evidence submission.
+pub fn get_dispute_automation_action(
+    rules: &DisputeAutomationRules,
+    dispute: &diesel_models::dispute::Dispute,
+    current_time: PrimitiveDateTime,
+) -> Option<(disputes::DisputeAutomationAction, PrimitiveDateTime)> {
+    let should_auto_accept = core_utils::should_proceed_with_accept_dispute(
+        dispute.dispute_stage,
+        dispute.dispute_status,
+    ) && dispute.dispute_currency.is_some_and(|currency| {
+        rules
+            .auto_accept
+            .iter()
+            .any(|rule| rule.currency == currency && dispute.dispute_amount <= rule.max_amount)
+    });
+
+    if should_auto_accept {
+        return Some((disputes::DisputeAutomationAction::Accept, current_time));
+    }
+
+    if !core_utils::should_proceed_with_submit_evidence(
+        dispute.dispute_stage,
+        dispute.dispute_status,
+    ) {
+        return None;
+    }
+
+    rules
+        .auto_submit_days_before_due
+        .zip(dispute.challenge_required_by)
+        .map(|(days, challenge_required_by)| {
+            let submit_at =
+                challenge_required_by.saturating_sub(time::Duration::days(i64::from(days)));
+            (
+                disputes::DisputeAutomationAction::SubmitEvidence,
+                submit_at.max(current_time),
+            )
+        })
+}
+
+fn get_dispute_automation_process_tracker_id(dispute: &diesel_models::dispute::Dispute) -> String {
+    scheduler::utils::get_process_tracker_id(
+        common_enums::ProcessTrackerRunner::DisputeAutomationWorkflow,
+        DISPUTE_AUTOMATION_TASK,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    )
+}
+
+/// A task which is already running evaluates the rules again on the latest dispute, any other
+/// task is rescheduled as per the dispute it was received or updated with
+fn should_reschedule_dispute_automation_task(status: storage_enums::ProcessTrackerStatus) -> bool {
+    !matches!(
+        status,
+        storage_enums::ProcessTrackerStatus::Processing
+            | storage_enums::ProcessTrackerStatus::ProcessStarted
+    )
+}
+
+async fn add_dispute_automation_task_to_pt(
+    db: &dyn StorageInterface,
+    dispute: &diesel_models::dispute::Dispute,
+    profile_id: id_type::ProfileId,
+    action: disputes::DisputeAutomationAction,
+    schedule_time: PrimitiveDateTime,
+) -> common_utils::errors::CustomResult<(), errors::StorageError> {
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "dispute_automation")),
+    );
+    let tracking_data = disputes::DisputeAutomationPTData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+        profile_id,
+        action,
+    };
+    let tag = ["AUTOMATION", "DISPUTE"];
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        get_dispute_automation_process_tracker_id(dispute),
+        DISPUTE_AUTOMATION_TASK,
+        common_enums::ProcessTrackerRunner::DisputeAutomationWorkflow,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Schedules the dispute automation workflow for a dispute which was received or updated, if the
+/// automation rules of its profile apply to it. An existing task of the dispute is rescheduled.
+pub async fn schedule_dispute_automation_task(
+    state: &SessionState,
+    dispute: &diesel_models::dispute::Dispute,
+) -> RouterResult<()> {
+    let Some(profile_id) = dispute.profile_id.clone() else {
+        return Ok(());
+    };
+    let Some(config) = get_dispute_automation_config(state, &profile_id).await? else {
+        return Ok(());
+    };
+
+    if let Some((action, schedule_time)) =
+        get_dispute_automation_action(&config.rules, dispute, common_utils::date_time::now())
+    {
+        logger::info!(dispute_automation_action=?action, %schedule_time, "Scheduling dispute automation task");
+        let db = state.store.as_ref();
+        let existing_process = db
+            .find_process_by_id(&get_dispute_automation_process_tracker_id(dispute))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the dispute automation process tracker task")?;
+
+        match existing_process {
+            Some(process) if !should_reschedule_dispute_automation_task(process.status) => {}
+            Some(process) => {
+                let tracking_data = disputes::DisputeAutomationPTData {
+                    dispute_id: dispute.dispute_id.clone(),
+                    merchant_id: dispute.merchant_id.clone(),
+                    profile_id,
+                    action,
+                }
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode dispute automation tracking data")?;
+                db.update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(schedule_time),
+                        tracking_data: Some(tracking_data),
+                        business_status: None,
+                        status: Some(storage_enums::ProcessTrackerStatus::New),
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to reschedule the dispute automation process tracker task",
+                )?;
+            }
+            None => {
+                add_dispute_automation_task_to_pt(db, dispute, profile_id, action, schedule_time)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed while adding dispute automation task to process tracker",
+                    )?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn format_address(address: domain::Address) -> Option<String> {
+    let address_details = api_models::payments::Address::foreign_from(address).address?;
+    let formatted_address = [
+        address_details.line1.map(ExposeInterface::expose),
+        address_details.line2.map(ExposeInterface::expose),
+        address_details.line3.map(ExposeInterface::expose),
+        address_details.city,
+        address_details.state.map(ExposeInterface::expose),
+        address_details.zip.map(ExposeInterface::expose),
+        address_details.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|value| !value.trim().is_empty())
+    .collect::<Vec<_>>()
+    .join(", ");
+
+    (!formatted_address.is_empty()).then_some(formatted_address)
+}
+
+async fn get_formatted_address(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    address_id: Option<&str>,
+) -> RouterResult<Option<String>> {
+    let Some(address_id) = address_id else {
+        return Ok(None);
+    };
+    let address = state
+        .store
+        .find_address_by_address_id(
+            &state.into(),
+            address_id,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch address for building dispute evidence")?;
+
+    Ok(format_address(address))
+}
+
+fn get_metadata_value(
+    metadata: Option<&serde_json::Value>,
+    key: Option<&String>,
+) -> Option<String> {
+    metadata
+        .zip(key)
+        .and_then(|(metadata, key)| metadata.get(key))
+        .map(|value| match value {
+            serde_json::Value::String(value) => value.clone(),
+            value => value.to_string(),
+        })
+}
+
+fn get_authentication_results(payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    let mut results = Vec::new();
+
+    if let Some(authentication_type) = payment_attempt.authentication_type {
+        results.push(format!("Authentication type: {authentication_type}"));
+    }
+    if payment_attempt.external_three_ds_authentication_attempted == Some(true) {
+        results.push(format!(
+            "External 3DS authentication performed by: {}",
+            payment_attempt
+                .authentication_connector
+                .as_deref()
+                .unwrap_or("unknown")
+        ));
+    }
+
+    let card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .map_err(|error| logger::warn!(additional_payment_data_parsing_error=?error))
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => Some(card_info),
+            _ => None,
+        });
+
+    if let Some(card_info) = card_info {
+        if let Some(authentication_data) = card_info.authentication_data {
+            results.push(format!("3DS authentication details: {authentication_data}"));
+        }
+        if let Some(payment_checks) = card_info.payment_checks {
+            results.push(format!("AVS and CVV check results: {payment_checks}"));
+        }
+    }
+
+    (!results.is_empty()).then(|| results.join("\n"))
+}
+
+/// Builds the evidence of the dispute from the evidence template of its profile
+async fn build_evidence_request_from_template(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &diesel_models::dispute::Dispute,
+    template: DisputeEvidenceTemplate,
+) -> RouterResult<SubmitEvidenceRequest> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &dispute.payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let customer = match (
+        template.include_customer_details,
+        payment_intent.customer_id.as_ref(),
+    ) {
+        (true, Some(customer_id)) => db
+            .find_customer_optional_by_customer_id_merchant_id(
+                &state.into(),
+                customer_id,
+                merchant_id,
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch customer for building dispute evidence")?,
+        _ => None,
+    };
+
+    let billing_address = if template.include_billing_address {
+        get_formatted_address(
+            state,
+            merchant_context,
+            payment_intent.billing_address_id.as_deref(),
+        )
+        .await?
+    } else {
+        None
+    };
+
+    let shipping_address = if template.include_shipping_address {
+        get_formatted_address(
+            state,
+            merchant_context,
+            payment_intent.shipping_address_id.as_deref(),
+        )
+        .await?
+    } else {
+        None
+    };
+
+    let customer_purchase_ip = if template.include_purchase_ip {
+        payment_attempt
+            .browser_info
+            .clone()
+            .map(|browser_info| {
+                browser_info.parse_value::<BrowserInformation>("BrowserInformation")
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse browser info of the payment attempt")?
+            .and_then(|browser_info| browser_info.ip_address)
+            .map(|ip_address| ip_address.to_string())
+    } else {
+        None
+    };
+
+    let authentication_results = if template.include_authentication_results {
+        get_authentication_results(&payment_attempt)
+    } else {
+        None
+    };
+
+    let uncategorized_text = match (template.uncategorized_text, authentication_results) {
+        (Some(text), Some(results)) => Some(format!("{text}\n{results}")),
+        (text, results) => text.or(results),
+    };
+
+    let attached_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+
+    let metadata = payment_intent.metadata.as_ref();
+    let metadata_keys = template.metadata_keys.unwrap_or_default();
+
+    Ok(SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        access_activity_log: get_metadata_value(
+            metadata,
+            metadata_keys.access_activity_log.as_ref(),
+        ),
+        billing_address,
+        cancellation_policy: attached_evidence
+            .cancellation_policy
+            .or(template.cancellation_policy),
+        cancellation_policy_disclosure: template.cancellation_policy_disclosure,
+        cancellation_rebuttal: None,
+        customer_communication: attached_evidence.customer_communication,
+        customer_email_address: customer
+            .as_ref()
+            .and_then(|customer| customer.email.clone())
+            .map(|email| email.into_inner().expose()),
+        customer_name: customer
+            .and_then(|customer| customer.name)
+            .map(|name| name.into_inner().expose()),
+        customer_purchase_ip,
+        customer_signature: attached_evidence.customer_signature,
+        product_description: payment_intent.description.or(template.product_description),
+        receipt: attached_evidence.receipt,
+        refund_policy: attached_evidence.refund_policy.or(template.refund_policy),
+        refund_policy_disclosure: template.refund_policy_disclosure,
+        refund_refusal_explanation: None,
+        service_date: get_metadata_value(metadata, metadata_keys.service_date.as_ref()),
+        service_documentation: attached_evidence.service_documentation,
+        shipping_address,
+        shipping_carrier: get_metadata_value(metadata, metadata_keys.shipping_carrier.as_ref()),
+        shipping_date: get_metadata_value(metadata, metadata_keys.shipping_date.as_ref()),
+        shipping_documentation: attached_evidence.shipping_documentation,
+        shipping_tracking_number: get_metadata_value(
+            metadata,
+            metadata_keys.shipping_tracking_number.as_ref(),
+        ),
+        invoice_showing_distinct_transactions: attached_evidence
+            .invoice_showing_distinct_transactions,
+        recurring_transaction_agreement: attached_evidence.recurring_transaction_agreement,
+        uncategorized_file: attached_evidence.uncategorized_file,
+        uncategorized_text,
+    })
+}
+
+/// Fills the fields which are not provided in the request from the template evidence
+fn merge_evidence_requests(
+    request: SubmitEvidenceRequest,
+    template_request: SubmitEvidenceRequest,
+) -> SubmitEvidenceRequest {
+    SubmitEvidenceRequest {
+        dispute_id: request.dispute_id,
+        access_activity_log: request
+            .access_activity_log
+            .or(template_request.access_activity_log),
+        billing_address: request.billing_address.or(template_request.billing_address),
+        cancellation_policy: request
+            .cancellation_policy
+            .or(template_request.cancellation_policy),
+        cancellation_policy_disclosure: request
+            .cancellation_policy_disclosure
+            .or(template_request.cancellation_policy_disclosure),
+        cancellation_rebuttal: request
+            .cancellation_rebuttal
+            .or(template_request.cancellation_rebuttal),
+        customer_communication: request
+            .customer_communication
+            .or(template_request.customer_communication),
+        customer_email_address: request
+            .customer_email_address
+            .or(template_request.customer_email_address),
+        customer_name: request.customer_name.or(template_request.customer_name),
+        customer_purchase_ip: request
+            .customer_purchase_ip
+            .or(template_request.customer_purchase_ip),
+        customer_signature: request
+            .customer_signature
+            .or(template_request.customer_signature),
+        product_description: request
+            .product_description
+            .or(template_request.product_description),
+        receipt: request.receipt.or(template_request.receipt),
+        refund_policy: request.refund_policy.or(template_request.refund_policy),
+        refund_policy_disclosure: request
+            .refund_policy_disclosure
+            .or(template_request.refund_policy_disclosure),
+        refund_refusal_explanation: request
+            .refund_refusal_explanation
+            .or(template_request.refund_refusal_explanation),
+        service_date: request.service_date.or(template_request.service_date),
+        service_documentation: request
+            .service_documentation
+            .or(template_request.service_documentation),
+        shipping_address: request
+            .shipping_address
+            .or(template_request.shipping_address),
+        shipping_carrier: request
+            .shipping_carrier
+            .or(template_request.shipping_carrier),
+        shipping_date: request.shipping_date.or(template_request.shipping_date),
+        shipping_documentation: request
+            .shipping_documentation
+            .or(template_request.shipping_documentation),
+        shipping_tracking_number: request
+            .shipping_tracking_number
+            .or(template_request.shipping_tracking_number),
+        invoice_showing_distinct_transactions: request
+            .invoice_showing_distinct_transactions
+            .or(template_request.invoice_showing_distinct_transactions),
+        recurring_transaction_agreement: request
+            .recurring_transaction_agreement
+            .or(template_request.recurring_transaction_agreement),
+        uncategorized_file: request
+            .uncategorized_file
+            .or(template_request.uncategorized_file),
+        uncategorized_text: request
+            .uncategorized_text
+            .or(template_request.uncategorized_text),
+    }
+}
+
+/// Completes the evidence request submitted automatically using the evidence template of the
+/// dispute's profile, if one is configured. Values present in the request are retained.
+pub async fn apply_evidence_template(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &diesel_models::dispute::Dispute,
+    request: SubmitEvidenceRequest,
+) -> RouterResult<SubmitEvidenceRequest> {
+    let Some(profile_id) = dispute.profile_id.as_ref() else {
+        return Ok(request);
+    };
+    let Some(template) = get_dispute_automation_config(state, profile_id)
+        .await?
+        .and_then(|config| config.evidence_template)
+    else {
+        return Ok(request);
+    };
+
+    let template_request =
+        build_evidence_request_from_template(state, merchant_context, dispute, template).await?;
+
+    Ok(merge_evidence_requests(request, template_request))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::disputes::DisputeAutoAcceptRule;
+    use common_enums::{Currency, DisputeStage, DisputeStatus};
+    use common_utils::types::{AmountConvertor, StringMinorUnitForConnector};
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_dispute(
+        dispute_amount: i64,
+        challenge_required_by: Option<PrimitiveDateTime>,
+    ) -> diesel_models::dispute::Dispute {
+        diesel_models::dispute::Dispute {
+            dispute_id: "dp_123".to_string(),
+            amount: StringMinorUnitForConnector::convert(
+                &StringMinorUnitForConnector,
+                MinorUnit::new(dispute_amount),
+                Currency::USD,
+            )
+            .unwrap(),
+            currency: "USD".to_string(),
+            dispute_stage: DisputeStage::Dispute,
+            dispute_status: DisputeStatus::DisputeOpened,
+            payment_id: id_type::PaymentId::default(),
+            attempt_id: "attempt_123".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: "du_123".to_string(),
+            connector_reason: None,
+            connector_reason_code: None,
+            challenge_required_by,
+            connector_created_at: None,
+            connector_updated_at: None,
+            created_at: datetime!(2025-01-01 0:00),
+            modified_at: datetime!(2025-01-01 0:00),
+            connector: "stripe".to_string(),
+            evidence: serde_json::Value::Null.into(),
+            profile_id: None,
+            merchant_connector_id: None,
+            dispute_amount: MinorUnit::new(dispute_amount),
+            organization_id: id_type::OrganizationId::default(),
+            dispute_currency: Some(Currency::USD),
+            financial_records: None,
+        }
+    }
+
+    fn get_rules() -> DisputeAutomationRules {
+        DisputeAutomationRules {
+            auto_accept: vec![DisputeAutoAcceptRule {
+                currency: Currency::USD,
+                max_amount: MinorUnit::new(1000),
+            }],
+            auto_submit_days_before_due: Some(2),
+        }
+    }
+
+    #[test]
+    fn test_low_value_dispute_is_accepted_immediately() {
+        let now = datetime!(2025-01-02 0:00);
+        let action = get_dispute_automation_action(
+            &get_rules(),
+            &get_dispute(1000, Some(datetime!(2025-01-10 0:00))),
+            now,
+        );
+
+        assert_eq!(
+            action,
+            Some((disputes::DisputeAutomationAction::Accept, now))
+        );
+    }
+
+    #[test]
+    fn test_evidence_is_submitted_before_due_date() {
+        let action = get_dispute_automation_action(
+            &get_rules(),
+            &get_dispute(1001, Some(datetime!(2025-01-10 0:00))),
+            datetime!(2025-01-02 0:00),
+        );
+
+        assert_eq!(
+            action,
+            Some((
+                disputes::DisputeAutomationAction::SubmitEvidence,
+                datetime!(2025-01-08 0:00)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_evidence_submission_is_not_scheduled_in_the_past() {
+        let now = datetime!(2025-01-09 0:00);
+        let action = get_dispute_automation_action(
+            &get_rules(),
+            &get_dispute(1001, Some(datetime!(2025-01-10 0:00))),
+            now,
+        );
+
+        assert_eq!(
+            action,
+            Some((disputes::DisputeAutomationAction::SubmitEvidence, now))
+        );
+    }
+
+    #[test]
+    fn test_no_action_without_due_date() {
+        let action = get_dispute_automation_action(
+            &get_rules(),
+            &get_dispute(1001, None),
+            datetime!(2025-01-02 0:00),
+        );
+
+        assert_eq!(action, None);
+    }
+
+    #[test]
+    fn test_running_automation_task_is_not_rescheduled() {
+        assert!(!should_reschedule_dispute_automation_task(
+            storage_enums::ProcessTrackerStatus::ProcessStarted
+        ));
+        assert!(should_reschedule_dispute_automation_task(
+            storage_enums::ProcessTrackerStatus::Pending
+        ));
+        assert!(should_reschedule_dispute_automation_task(
+            storage_enums::ProcessTrackerStatus::Finish
+        ));
+    }
+}
@@ -135,6 +135,8 @@ pub enum AwsKmsError {
 pub enum WebhooksFlowError {
     #[error("Merchant webhook config not found")]
     MerchantConfigNotFound,
+    #[error("Merchant webhook config is invalid")]
+    MerchantConfigInvalid,
     #[error("Webhook details for merchant not configured")]
     MerchantWebhookDetailsNotFound,
     #[error("Merchant does not have a webhook URL configured")]
@@ -163,6 +165,7 @@ impl WebhooksFlowError {
     pub(crate) fn is_webhook_delivery_retryable_error(&self) -> bool {
         match self {
             Self::MerchantConfigNotFound
+            | Self::MerchantConfigInvalid
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
             | Self::OutgoingWebhookResponseEncodingFailed => false,
@@ -47,6 +47,7 @@ pub async fn validate_file_upload(
                 .find_dispute_by_merchant_id_dispute_id(
                     merchant_context.get_merchant_account().get_id(),
                     dispute_id,
+                    merchant_context.get_merchant_account().storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -146,6 +147,7 @@ pub async fn retrieve_file_from_connector(
                 .find_dispute_by_merchant_id_dispute_id(
                     merchant_context.get_merchant_account().get_id(),
                     &dispute,
+                    merchant_context.get_merchant_account().storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
@@ -307,6 +309,7 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 .find_dispute_by_merchant_id_dispute_id(
                     merchant_context.get_merchant_account().get_id(),
                     &dispute_id,
+                    merchant_context.get_merchant_account().storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })?;
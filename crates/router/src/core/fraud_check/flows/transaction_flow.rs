@@ -148,6 +148,7 @@ impl
             psd2_sca_exemption_type: None,
             raw_connector_response: None,
             is_payment_id_from_merchant: None,
+            merchant_connector_id: None,
             l2_l3_data: None,
             minor_amount_capturable: None,
         };
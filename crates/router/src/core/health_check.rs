@@ -17,6 +17,9 @@ use crate::{
 pub trait HealthCheckInterface {
     async fn health_check_db(&self) -> CustomResult<HealthState, errors::HealthCheckDBError>;
     async fn health_check_redis(&self) -> CustomResult<HealthState, errors::HealthCheckRedisError>;
+    async fn get_redis_primary_nodes(
+        &self,
+    ) -> CustomResult<Vec<String>, errors::HealthCheckRedisError>;
     async fn health_check_locker(
         &self,
     ) -> CustomResult<HealthState, errors::HealthCheckLockerError>;
@@ -84,6 +87,20 @@ impl HealthCheckInterface for app::SessionState {
         Ok(HealthState::Running)
     }
 
+    async fn get_redis_primary_nodes(
+        &self,
+    ) -> CustomResult<Vec<String>, errors::HealthCheckRedisError> {
+        let db = &*self.store;
+        let redis_conn = db
+            .get_redis_conn()
+            .change_context(errors::HealthCheckRedisError::RedisConnectionError)?;
+
+        redis_conn
+            .get_primary_nodes()
+            .await
+            .change_context(errors::HealthCheckRedisError::PrimaryNodesFetchFailed)
+    }
+
     async fn health_check_locker(
         &self,
     ) -> CustomResult<HealthState, errors::HealthCheckLockerError> {
@@ -0,0 +1,205 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::domain,
+};
+
+/// Derives the vault configuration of the injector from the merchant connector account of the
+/// vault holding the tokens
+pub fn get_vault_connector_config(
+    vault_merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<injector::VaultConnectorConfig> {
+    injector::VaultConnectorConfig::from_merchant_connector_account(
+        &vault_merchant_connector_account.connector_name.to_string(),
+        vault_merchant_connector_account.metadata.as_ref(),
+        vault_merchant_connector_account
+            .connector_account_details
+            .get_inner(),
+    )
+    .map_err(|message| {
+        report!(errors::ApiErrorResponse::InvalidConnectorConfiguration {
+            config: "vault configuration".to_string(),
+        })
+        .attach_printable(message)
+    })
+}
+
+/// Sends the connector request of the injector, resolving its tokens only through the vault of
+/// the given merchant connector account
+#[instrument(skip_all)]
+pub async fn injector_core(
+    vault_merchant_connector_account: &domain::MerchantConnectorAccount,
+    request: injector::InjectorRequest,
+) -> RouterResult<injector::InjectorResponse> {
+    let vault_connector_config = get_vault_connector_config(vault_merchant_connector_account)?;
+
+    injector::injector_core(request, vault_connector_config)
+        .await
+        .map_err(|error| {
+            if matches!(
+                error.current_context(),
+                injector::InjectorError::VaultConnectorMismatch(_)
+            ) {
+                error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "token_data does not belong to the vault connector".to_string(),
+                })
+            } else {
+                error.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })
+        .attach_printable("Failed to send the connector request through the injector")
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::collections::HashMap;
+
+    use common_utils::{crypto::Encryptable, date_time, id_type};
+    use masking::Secret;
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+
+    fn get_vault_merchant_connector_account(
+        connector_name: &str,
+    ) -> domain::MerchantConnectorAccount {
+        domain::MerchantConnectorAccount {
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("test_merchant"))
+                .unwrap(),
+            connector_name: connector_name.to_string(),
+            connector_account_details: Encryptable::new(
+                Secret::new(serde_json::json!({ "auth_type": "NoKey" })),
+                Secret::new(Vec::new()),
+            ),
+            test_mode: None,
+            disabled: None,
+            merchant_connector_id: id_type::MerchantConnectorAccountId::wrap(
+                "mca_vault".to_string(),
+            )
+            .unwrap(),
+            payment_methods_enabled: None,
+            connector_type: common_enums::ConnectorType::VaultProcessor,
+            metadata: None,
+            frm_configs: None,
+            connector_label: None,
+            business_country: None,
+            business_label: None,
+            business_sub_label: None,
+            created_at: date_time::now(),
+            modified_at: date_time::now(),
+            connector_webhook_details: None,
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("test_profile"))
+                .unwrap(),
+            applepay_verified_domains: None,
+            pm_auth_config: None,
+            status: common_enums::ConnectorStatus::Active,
+            connector_wallets_details: None,
+            additional_merchant_data: None,
+            version: common_types::consts::API_VERSION,
+        }
+    }
+
+    fn get_injector_request(
+        vault_connector: injector::VaultConnectors,
+        base_url: String,
+    ) -> injector::InjectorRequest {
+        injector::InjectorRequest {
+            token_data: injector::TokenData {
+                specific_token_data: Secret::new(serde_json::json!({
+                    "card_number": "4111111111111111"
+                })),
+                vault_connector,
+                alias: None,
+            },
+            additional_token_data: Vec::new(),
+            connector_payload: injector::ConnectorPayload {
+                template: r#"{"card_number":"{{$card_number}}"}"#.to_string(),
+            },
+            connection_config: injector::ConnectionConfig {
+                base_url,
+                endpoint_path: "/v1/charges".to_string(),
+                http_method: injector::HttpMethod::POST,
+                headers: HashMap::from([(
+                    "Content-Type".to_string(),
+                    Secret::new("application/json".to_string()),
+                )]),
+                proxy_url: None,
+                client_cert: None,
+                client_key: None,
+                ca_cert: None,
+                insecure: None,
+                cert_password: None,
+                cert_format: None,
+                max_response_size: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_injector_core_with_vault_merchant_connector_account() {
+        let connector_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/charges"))
+            .and(body_json(
+                serde_json::json!({ "card_number": "4111111111111111" }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "status": "ok" })),
+            )
+            .expect(1)
+            .mount(&connector_server)
+            .await;
+
+        let response = injector_core(
+            &get_vault_merchant_connector_account("vgs"),
+            get_injector_request(injector::VaultConnectors::VGS, connector_server.uri()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response, serde_json::json!({ "status": "ok" }));
+    }
+
+    #[tokio::test]
+    async fn test_injector_core_rejects_tokens_of_other_vaults() {
+        let connector_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&connector_server)
+            .await;
+
+        let error = injector_core(
+            &get_vault_merchant_connector_account("vgs"),
+            get_injector_request(
+                injector::VaultConnectors::HyperswitchVault,
+                connector_server.uri(),
+            ),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+
+    #[test]
+    fn test_get_vault_connector_config_rejects_non_vault_connectors() {
+        let error = get_vault_connector_config(&get_vault_merchant_connector_account("stripe"))
+            .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidConnectorConfiguration { .. }
+        ));
+    }
+}
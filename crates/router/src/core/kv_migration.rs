@@ -0,0 +1,414 @@
+use api_models::admin::{KvMigrationRequest, KvMigrationResponse, KvMigrationStatus};
+use common_utils::{ext_traits::Encode, id_type};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::logger;
+use storage_impl::redis::kv_migration;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{
+        domain,
+        storage::{self, enums::MerchantStorageScheme},
+    },
+};
+
+const KV_MIGRATION_TASK: &str = "KV_MIGRATION";
+
+/// Fetches the latest storage scheme migration of the merchant, if any
+pub async fn get_kv_migration(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<KvMigrationResponse>> {
+    core_utils::find_optional_json_config(
+        state,
+        &merchant_id.get_kv_migration_key(),
+        "KvMigrationResponse",
+    )
+    .await
+}
+
+async fn save_kv_migration(
+    state: &SessionState,
+    migration: &KvMigrationResponse,
+    is_new_config: bool,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key = migration.merchant_id.get_kv_migration_key();
+    let serialized_migration = migration
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize KV migration")?;
+
+    if is_new_config {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_migration,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert KV migration")?;
+    } else {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_migration),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update KV migration")?;
+    }
+
+    Ok(())
+}
+
+async fn get_merchant_account_and_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<(domain::MerchantAccount, domain::MerchantKeyStore)> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok((merchant_account, key_store))
+}
+
+/// Holds the grace period of the merchant for another lease, so that `postgres_only` lookups keep
+/// checking Redis until the migration is completed
+async fn refresh_grace_period(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    kv_migration::set_kv_migration_grace_period(
+        &redis_conn,
+        merchant_id,
+        common_utils::date_time::now_unix_timestamp()
+            .saturating_add(consts::KV_MIGRATION_GRACE_PERIOD_LEASE_IN_SECS),
+        consts::KV_MIGRATION_GRACE_PERIOD_LEASE_IN_SECS,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to set KV migration grace period")
+}
+
+async fn end_grace_period(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    kv_migration::delete_kv_migration_grace_period(&redis_conn, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete KV migration grace period")?;
+
+    Ok(())
+}
+
+#[cfg(feature = "kv_store")]
+async fn count_pending_drainer_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<usize> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    kv_migration::count_pending_drainer_entries(
+        &redis_conn,
+        &state.conf.drainer.stream_name,
+        state.conf.drainer.num_partitions,
+        merchant_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to count the pending drainer entries of the merchant")
+}
+
+/// Without the KV store, no entries are ever pushed to the drainer streams
+#[cfg(not(feature = "kv_store"))]
+async fn count_pending_drainer_entries(
+    _state: &SessionState,
+    _merchant_id: &id_type::MerchantId,
+) -> RouterResult<usize> {
+    Ok(0)
+}
+
+async fn add_kv_migration_task_to_pt(
+    db: &dyn StorageInterface,
+    migration: &KvMigrationResponse,
+    schedule_time: PrimitiveDateTime,
+) -> common_utils::errors::CustomResult<(), errors::StorageError> {
+    TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "kv_migration")));
+    let tracking_data = storage::KvMigrationTrackingData {
+        merchant_id: migration.merchant_id.clone(),
+    };
+    let runner = common_enums::ProcessTrackerRunner::KvMigrationWorkflow;
+    let tag = ["KV_MIGRATION"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        KV_MIGRATION_TASK,
+        &migration
+            .created_at
+            .assume_utc()
+            .unix_timestamp()
+            .to_string(),
+        &migration.merchant_id,
+    );
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        process_tracker_id,
+        KV_MIGRATION_TASK,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Starts migrating the merchant to the requested storage scheme.
+///
+/// The grace period of the merchant is recorded right away, while the storage scheme is switched
+/// by the storage scheme migration workflow only after every pod has observed the grace period.
+pub async fn start_kv_migration(
+    state: SessionState,
+    request: KvMigrationRequest,
+) -> RouterResponse<KvMigrationResponse> {
+    let grace_period_in_secs = request
+        .grace_period_in_secs
+        .unwrap_or(consts::DEFAULT_KV_MIGRATION_GRACE_PERIOD_IN_SECS);
+    if grace_period_in_secs > consts::MAX_KV_MIGRATION_GRACE_PERIOD_IN_SECS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "grace_period_in_secs cannot be greater than {}",
+                consts::MAX_KV_MIGRATION_GRACE_PERIOD_IN_SECS
+            ),
+        }
+        .into());
+    }
+
+    let merchant_id = request.merchant_id;
+    let (merchant_account, _) = get_merchant_account_and_key_store(&state, &merchant_id).await?;
+
+    let target_storage_scheme = if request.kv_enabled {
+        MerchantStorageScheme::RedisKv
+    } else {
+        MerchantStorageScheme::PostgresOnly
+    };
+    if merchant_account.storage_scheme == target_storage_scheme {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Merchant account already uses the {target_storage_scheme} storage scheme"
+            ),
+        }
+        .into());
+    }
+    if request.kv_enabled && state.conf.as_ref().is_kv_soft_kill_mode() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Kv cannot be enabled when application is in soft_kill_mode".to_owned(),
+        }
+        .into());
+    }
+
+    let existing_migration = get_kv_migration(&state, &merchant_id).await?;
+    if existing_migration
+        .as_ref()
+        .is_some_and(|migration| migration.status != KvMigrationStatus::Completed)
+    {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A storage scheme migration is already in progress for the merchant"
+                .to_owned(),
+        }
+        .into());
+    }
+
+    let current_time = common_utils::date_time::now();
+    let migration = KvMigrationResponse {
+        merchant_id,
+        kv_enabled: request.kv_enabled,
+        status: KvMigrationStatus::Scheduled,
+        pending_drainer_entries: 0,
+        grace_period_in_secs,
+        created_at: current_time,
+        switched_at: None,
+        drained_at: None,
+        completed_at: None,
+    };
+
+    refresh_grace_period(&state, &migration.merchant_id).await?;
+    save_kv_migration(&state, &migration, existing_migration.is_none()).await?;
+
+    // Pods cache the grace periods for a while, the storage scheme is switched only after the
+    // grace period is guaranteed to be observed by all of them
+    let schedule_time = current_time.saturating_add(time::Duration::seconds(
+        i64::try_from(kv_migration::KV_MIGRATION_GRACE_PERIODS_CACHE_TTL)
+            .unwrap_or(i64::MAX)
+            .saturating_mul(2),
+    ));
+    add_kv_migration_task_to_pt(state.store.as_ref(), &migration, schedule_time)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the KV migration workflow")?;
+
+    Ok(services::ApplicationResponse::Json(migration))
+}
+
+pub async fn retrieve_kv_migration(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<KvMigrationResponse> {
+    let mut migration = get_kv_migration(&state, &merchant_id).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No storage scheme migration found for the merchant".to_string(),
+        },
+    )?;
+
+    // The workflow only records the pending entries once per poll interval, the current count
+    // is reported while the entries are being drained
+    if migration.status == KvMigrationStatus::Draining {
+        migration.pending_drainer_entries =
+            count_pending_drainer_entries(&state, &merchant_id).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(migration))
+}
+
+/// Moves the migration of the merchant forward, returning the time at which it is to be checked
+/// again, or `None` once it is completed
+pub async fn process_kv_migration(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let Some(mut migration) = get_kv_migration(state, merchant_id).await? else {
+        logger::warn!("No storage scheme migration found for the merchant");
+        return Ok(None);
+    };
+    let current_time = common_utils::date_time::now();
+
+    match migration.status {
+        KvMigrationStatus::Completed => return Ok(None),
+        KvMigrationStatus::Scheduled => {
+            refresh_grace_period(state, merchant_id).await?;
+            switch_storage_scheme(state, merchant_id, migration.kv_enabled).await?;
+            migration.switched_at = Some(current_time);
+
+            // Nothing is pending in the drainer streams when moving into KV, only the lookups
+            // of the pods which are yet to observe the new storage scheme have to be covered
+            if migration.kv_enabled {
+                migration.status = KvMigrationStatus::GracePeriod;
+                migration.drained_at = Some(current_time);
+            } else {
+                migration.status = KvMigrationStatus::Draining;
+            }
+        }
+        KvMigrationStatus::Draining => {
+            refresh_grace_period(state, merchant_id).await?;
+            migration.pending_drainer_entries =
+                count_pending_drainer_entries(state, merchant_id).await?;
+            if migration.pending_drainer_entries == 0 {
+                migration.status = KvMigrationStatus::GracePeriod;
+                migration.drained_at = Some(current_time);
+            }
+        }
+        KvMigrationStatus::GracePeriod => {
+            refresh_grace_period(state, merchant_id).await?;
+            let grace_period_ends_at = migration.drained_at.unwrap_or(current_time).saturating_add(
+                time::Duration::seconds(i64::from(migration.grace_period_in_secs)),
+            );
+
+            if grace_period_ends_at <= current_time {
+                // Updates of the records still present in Redis are applied there during the
+                // grace period, the migration is completed only if those are drained as well
+                if !migration.kv_enabled {
+                    migration.pending_drainer_entries =
+                        count_pending_drainer_entries(state, merchant_id).await?;
+                }
+
+                if migration.pending_drainer_entries == 0 {
+                    end_grace_period(state, merchant_id).await?;
+                    migration.status = KvMigrationStatus::Completed;
+                    migration.completed_at = Some(current_time);
+                } else {
+                    migration.status = KvMigrationStatus::Draining;
+                    migration.drained_at = None;
+                }
+            }
+        }
+    }
+
+    logger::info!(kv_migration_status = ?migration.status, pending_drainer_entries = migration.pending_drainer_entries);
+    save_kv_migration(state, &migration, false).await?;
+
+    Ok((migration.status != KvMigrationStatus::Completed).then(|| {
+        current_time.saturating_add(time::Duration::seconds(
+            consts::KV_MIGRATION_POLL_INTERVAL_IN_SECS,
+        ))
+    }))
+}
+
+async fn switch_storage_scheme(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    kv_enabled: bool,
+) -> RouterResult<()> {
+    let (merchant_account, key_store) =
+        get_merchant_account_and_key_store(state, merchant_id).await?;
+    let storage_scheme = if kv_enabled {
+        MerchantStorageScheme::RedisKv
+    } else {
+        MerchantStorageScheme::PostgresOnly
+    };
+
+    if merchant_account.storage_scheme != storage_scheme {
+        state
+            .store
+            .update_merchant(
+                &state.into(),
+                merchant_account,
+                storage::MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme },
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to switch merchant_storage_scheme")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,1008 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::analytics::{
+    metric_alerts::{
+        ActiveMetricAlert, AlertComparison, AlertCondition, AlertMetric, AlertWindow,
+        MetricAlertDetails, MetricAlertPTData, MetricAlertRule, MetricAlertRuleCreateRequest,
+        MetricAlertRuleListResponse,
+    },
+    payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
+    refunds::{RefundDimensions, RefundMetrics},
+    GetPaymentMetricRequest, GetRefundMetricRequest, PaymentDistributionBody, QueryLimit,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    generate_id, id_type,
+    types::{authentication::AuthInfo, TimeRange},
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{domain, storage},
+};
+
+const METRIC_ALERT_TASK: &str = "METRIC_ALERT";
+const MAX_RULE_NAME_LENGTH: usize = 64;
+const MAX_BASELINE_WINDOWS: u8 = 12;
+const MAX_COOLDOWN_MINUTES: u32 = 7 * 24 * 60;
+
+fn validate_metric_alert_rule_request(request: &MetricAlertRuleCreateRequest) -> RouterResult<()> {
+    let is_valid_name = !request.name.trim().is_empty()
+        && request.name.len() <= MAX_RULE_NAME_LENGTH
+        && request
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_'));
+    if !is_valid_name {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "name must be at most {MAX_RULE_NAME_LENGTH} characters long and can only contain alphanumeric characters, spaces, hyphens and underscores"
+            ),
+        }
+        .into());
+    }
+
+    match request.condition {
+        AlertCondition::Threshold { value, .. } if !(0.0..=100.0).contains(&value) => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The threshold value must be a percentage between 0 and 100".to_string(),
+            }
+            .into());
+        }
+        AlertCondition::Baseline { deviation, .. } if !(deviation > 0.0 && deviation <= 100.0) => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The deviation must be between 0 and 100 percentage points".to_string(),
+            }
+            .into());
+        }
+        AlertCondition::Baseline {
+            baseline_windows, ..
+        } if !(1..=MAX_BASELINE_WINDOWS).contains(&baseline_windows) => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The number of baseline windows must be between 1 and {MAX_BASELINE_WINDOWS}"
+                ),
+            }
+            .into());
+        }
+        AlertCondition::Threshold { .. } | AlertCondition::Baseline { .. } => {}
+    }
+
+    if request.cooldown_minutes.is_some_and(|cooldown_minutes| {
+        cooldown_minutes == 0 || cooldown_minutes > MAX_COOLDOWN_MINUTES
+    }) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("cooldown_minutes must be between 1 and {MAX_COOLDOWN_MINUTES}"),
+        }
+        .into());
+    }
+
+    if request.recipients.len() > consts::MAX_METRIC_ALERT_RECIPIENTS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "An alert rule can have at most {} recipients",
+                consts::MAX_METRIC_ALERT_RECIPIENTS
+            ),
+        }
+        .into());
+    }
+
+    if request.recipients.is_empty() && !request.send_webhook {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "An alert rule must either have recipients or send webhooks".to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Returns the end of the last complete evaluation window as of `time`. Windows are aligned to
+/// the Unix epoch, the same way as the time buckets of the analytics queries.
+fn get_window_end(window: AlertWindow, time: PrimitiveDateTime) -> PrimitiveDateTime {
+    let window_seconds = window.get_duration().whole_seconds();
+    let timestamp = time.assume_utc().unix_timestamp();
+    time::OffsetDateTime::from_unix_timestamp(timestamp - timestamp.rem_euclid(window_seconds))
+        .map(|window_end| PrimitiveDateTime::new(window_end.date(), window_end.time()))
+        .unwrap_or(time)
+}
+
+/// Returns the evaluation window at the given offset before the last complete window as of
+/// `time`, offset 0 being the last complete window itself
+fn get_window_time_range(window: AlertWindow, time: PrimitiveDateTime, offset: u8) -> TimeRange {
+    let end_time = get_window_end(window, time) - window.get_duration() * u32::from(offset);
+    TimeRange {
+        start_time: end_time - window.get_duration(),
+        end_time: Some(end_time),
+    }
+}
+
+fn get_process_tracker_id(merchant_id: &id_type::MerchantId, rule_id: &str) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::MetricAlertWorkflow,
+        METRIC_ALERT_TASK,
+        rule_id,
+        merchant_id,
+    )
+}
+
+/// Fetches the metric alert rules of the profile, if any have been configured
+async fn get_metric_alert_rules(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<Vec<MetricAlertRule>>> {
+    match db
+        .find_config_by_key(&profile_id.get_metric_alert_rules_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("Vec<MetricAlertRule>")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse metric alert rules")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch metric alert rules"),
+    }
+}
+
+async fn store_metric_alert_rules(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    rules: &[MetricAlertRule],
+    is_new_config: bool,
+) -> RouterResult<()> {
+    let key = profile_id.get_metric_alert_rules_key();
+    let serialized_rules = rules
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize metric alert rules")?;
+
+    if is_new_config {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_rules,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert metric alert rules")?;
+    } else {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_rules),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update metric alert rules")?;
+    }
+    Ok(())
+}
+
+fn metric_alert_rule_not_found() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Metric alert rule does not exist".to_string(),
+    }
+}
+
+async fn add_metric_alert_task_to_pt(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    rule: &MetricAlertRule,
+) -> RouterResult<()> {
+    let tracking_data = MetricAlertPTData {
+        merchant_id: merchant_id.clone(),
+        profile_id: rule.profile_id.clone(),
+        rule_id: rule.rule_id.clone(),
+    };
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        get_process_tracker_id(merchant_id, &rule.rule_id),
+        METRIC_ALERT_TASK,
+        storage::ProcessTrackerRunner::MetricAlertWorkflow,
+        ["ANALYTICS", "ALERT"],
+        tracking_data,
+        None,
+        rule.next_evaluation_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct metric alert process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add metric alert process tracker task")?;
+
+    TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "metric_alert")));
+    Ok(())
+}
+
+pub async fn create_metric_alert_rule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    request: MetricAlertRuleCreateRequest,
+) -> RouterResponse<MetricAlertRule> {
+    validate_metric_alert_rule_request(&request)?;
+
+    let db = state.store.as_ref();
+    let existing_rules = get_metric_alert_rules(db, &profile_id).await?;
+    let is_new_config = existing_rules.is_none();
+    let mut rules = existing_rules.unwrap_or_default();
+    if rules.len() >= consts::MAX_METRIC_ALERT_RULES_PER_PROFILE {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A profile can have at most {} metric alert rules",
+                consts::MAX_METRIC_ALERT_RULES_PER_PROFILE
+            ),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let window_minutes = u32::try_from(request.window.get_duration().whole_minutes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let rule = MetricAlertRule {
+        rule_id: generate_id(consts::ID_LENGTH, "alert_rule"),
+        profile_id: profile_id.clone(),
+        name: request.name,
+        metric: request.metric,
+        condition: request.condition,
+        window: request.window,
+        group_by_connector: request.group_by_connector,
+        min_sample_size: request
+            .min_sample_size
+            .unwrap_or(consts::DEFAULT_METRIC_ALERT_MIN_SAMPLE_SIZE),
+        cooldown_minutes: request.cooldown_minutes.unwrap_or(window_minutes),
+        recipients: request.recipients,
+        send_webhook: request.send_webhook,
+        next_evaluation_at: get_window_end(request.window, now) + request.window.get_duration(),
+        active_alerts: Vec::new(),
+        created_at: now,
+    };
+
+    rules.push(rule.clone());
+    store_metric_alert_rules(db, &profile_id, &rules, is_new_config).await?;
+    add_metric_alert_task_to_pt(db, &merchant_id, &rule).await?;
+
+    Ok(services::ApplicationResponse::Json(rule))
+}
+
+pub async fn list_metric_alert_rules(
+    state: SessionState,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<MetricAlertRuleListResponse> {
+    let data = get_metric_alert_rules(state.store.as_ref(), &profile_id)
+        .await?
+        .unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(
+        MetricAlertRuleListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+pub async fn retrieve_metric_alert_rule(
+    state: SessionState,
+    profile_id: id_type::ProfileId,
+    rule_id: String,
+) -> RouterResponse<MetricAlertRule> {
+    get_metric_alert_rules(state.store.as_ref(), &profile_id)
+        .await?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|rule| rule.rule_id == rule_id)
+        .ok_or(metric_alert_rule_not_found().into())
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn delete_metric_alert_rule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    rule_id: String,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let mut rules = get_metric_alert_rules(db, &profile_id)
+        .await?
+        .ok_or(metric_alert_rule_not_found())?;
+    let rules_count = rules.len();
+    rules.retain(|rule| rule.rule_id != rule_id);
+    if rules.len() == rules_count {
+        return Err(metric_alert_rule_not_found().into());
+    }
+
+    if rules.is_empty() {
+        db.delete_config_by_key(&profile_id.get_metric_alert_rules_key())
+            .await
+            .to_not_found_response(metric_alert_rule_not_found())?;
+    } else {
+        store_metric_alert_rules(db, &profile_id, &rules, false).await?;
+    }
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_process_tracker_id(&merchant_id, &rule_id)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: common_enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(diesel_models::business_status::REVOKED)),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to revoke metric alert process tracker task")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// The value of the metric of a rule in one window, for one connector and error message
+#[derive(Clone, Debug, PartialEq)]
+struct MetricObservation {
+    connector: Option<String>,
+    error_message: Option<String>,
+    value: f64,
+    sample_size: u64,
+}
+
+/// The outcome of evaluating the condition of a rule for one connector and error message
+#[derive(Clone, Debug, PartialEq)]
+struct AlertEvaluation {
+    connector: Option<String>,
+    error_message: Option<String>,
+    observed_value: f64,
+    reference_value: f64,
+    sample_size: u64,
+    is_breached: bool,
+}
+
+/// Fetches the values of the metric of the rule in the given window, grouped by connector if
+/// the rule is grouped by connector
+async fn fetch_metric_observations(
+    state: &SessionState,
+    auth: &AuthInfo,
+    rule: &MetricAlertRule,
+    time_range: TimeRange,
+) -> RouterResult<Vec<MetricObservation>> {
+    match rule.metric {
+        AlertMetric::ConnectorSuccessRate | AlertMetric::PaymentErrorMessage => {
+            let (metrics, distribution) = match rule.metric {
+                AlertMetric::ConnectorSuccessRate => (
+                    HashSet::from([
+                        PaymentMetrics::ConnectorSuccessRate,
+                        PaymentMetrics::PaymentCount,
+                    ]),
+                    None,
+                ),
+                _ => (
+                    HashSet::from([PaymentMetrics::PaymentCount]),
+                    Some(PaymentDistributionBody {
+                        distribution_for: PaymentDistributions::PaymentErrorMessage,
+                        distribution_cardinality: QueryLimit::Top5,
+                    }),
+                ),
+            };
+            let request = GetPaymentMetricRequest {
+                time_series: None,
+                time_range,
+                group_by_names: if rule.group_by_connector {
+                    vec![PaymentDimensions::Connector]
+                } else {
+                    Vec::new()
+                },
+                filters: Default::default(),
+                metrics,
+                distribution,
+                delta: false,
+            };
+            let response = analytics::payments::get_metrics(&state.pool, &None, auth, request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment metrics for the alert rule")?;
+
+            let sample_sizes: HashMap<Option<String>, u64> = response
+                .query_data
+                .iter()
+                .filter_map(|bucket| {
+                    bucket
+                        .values
+                        .payment_count
+                        .map(|count| (bucket.dimensions.connector.clone(), count))
+                })
+                .collect();
+            let get_sample_size = |connector: &Option<String>| {
+                sample_sizes.get(connector).copied().unwrap_or_default()
+            };
+
+            Ok(response
+                .query_data
+                .into_iter()
+                .flat_map(|bucket| {
+                    let connector = bucket.dimensions.connector;
+                    let sample_size = get_sample_size(&connector);
+                    let success_rate =
+                        bucket
+                            .values
+                            .connector_success_rate
+                            .map(|value| MetricObservation {
+                                connector: connector.clone(),
+                                error_message: None,
+                                value,
+                                sample_size,
+                            });
+                    let error_messages = bucket
+                        .values
+                        .payment_error_message
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(move |error| MetricObservation {
+                            connector: connector.clone(),
+                            error_message: Some(error.reason),
+                            value: error.percentage,
+                            sample_size,
+                        });
+                    success_rate.into_iter().chain(error_messages)
+                })
+                .collect())
+        }
+        AlertMetric::RefundFailureRate => {
+            let request = GetRefundMetricRequest {
+                time_series: None,
+                time_range,
+                group_by_names: if rule.group_by_connector {
+                    vec![RefundDimensions::Connector]
+                } else {
+                    Vec::new()
+                },
+                filters: Default::default(),
+                metrics: HashSet::from([
+                    RefundMetrics::RefundSuccessRate,
+                    RefundMetrics::RefundCount,
+                ]),
+                distribution: None,
+                delta: false,
+            };
+            let response = analytics::refunds::get_metrics(&state.pool, &None, auth, request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch refund metrics for the alert rule")?;
+
+            let sample_sizes: HashMap<Option<String>, u64> = response
+                .query_data
+                .iter()
+                .filter_map(|bucket| {
+                    bucket
+                        .values
+                        .refund_count
+                        .map(|count| (bucket.dimensions.connector.clone(), count))
+                })
+                .collect();
+
+            Ok(response
+                .query_data
+                .into_iter()
+                .filter_map(|bucket| {
+                    let success_rate = bucket.values.refund_success_rate?;
+                    let sample_size = sample_sizes
+                        .get(&bucket.dimensions.connector)
+                        .copied()
+                        .unwrap_or_default();
+                    Some(MetricObservation {
+                        connector: bucket.dimensions.connector,
+                        error_message: None,
+                        value: 100.0 - success_rate,
+                        sample_size,
+                    })
+                })
+                .collect())
+        }
+    }
+}
+
+/// Evaluates the condition of the rule against the observations of the current window, and for
+/// baseline conditions, of the preceding windows. Observations with fewer samples than the
+/// minimum sample size of the rule are not evaluated.
+fn evaluate_condition(
+    condition: AlertCondition,
+    min_sample_size: u64,
+    current: Vec<MetricObservation>,
+    baseline: &[MetricObservation],
+) -> Vec<AlertEvaluation> {
+    current
+        .into_iter()
+        .filter(|observation| observation.sample_size >= min_sample_size)
+        .filter_map(|observation| {
+            let (comparison, limit, reference_value) = match condition {
+                AlertCondition::Threshold { comparison, value } => (comparison, value, value),
+                AlertCondition::Baseline {
+                    comparison,
+                    deviation,
+                    ..
+                } => {
+                    let baseline_values: Vec<f64> = baseline
+                        .iter()
+                        .filter(|previous| {
+                            previous.connector == observation.connector
+                                && previous.error_message == observation.error_message
+                        })
+                        .map(|previous| previous.value)
+                        .collect();
+                    if baseline_values.is_empty() {
+                        return None;
+                    }
+                    #[allow(clippy::as_conversions)]
+                    let average =
+                        baseline_values.iter().sum::<f64>() / baseline_values.len() as f64;
+                    let limit = match comparison {
+                        AlertComparison::Above => average + deviation,
+                        AlertComparison::Below => average - deviation,
+                    };
+                    (comparison, limit, average)
+                }
+            };
+            Some(AlertEvaluation {
+                is_breached: comparison.is_breached(observation.value, limit),
+                connector: observation.connector,
+                error_message: observation.error_message,
+                observed_value: observation.value,
+                reference_value,
+                sample_size: observation.sample_size,
+            })
+        })
+        .collect()
+}
+
+/// Updates the active alerts of the rule with the outcome of an evaluation, and returns the
+/// alerts which have to be notified.
+///
+/// An alert is notified when it is first triggered, and again only after the cooldown of the rule
+/// has passed since its last notification. An alert recovers when the scope it belongs to (the
+/// connector, or the whole profile) was evaluated and the alert was not breached.
+fn update_active_alerts(
+    rule: &mut MetricAlertRule,
+    evaluations: &[AlertEvaluation],
+    now: PrimitiveDateTime,
+) -> Vec<(ActiveMetricAlert, AlertEvaluation)> {
+    let cooldown = time::Duration::minutes(i64::from(rule.cooldown_minutes));
+    let evaluated_connectors: HashSet<Option<&str>> = evaluations
+        .iter()
+        .map(|evaluation| evaluation.connector.as_deref())
+        .collect();
+
+    let mut active_alerts = Vec::new();
+    let mut alerts_to_notify = Vec::new();
+    for evaluation in evaluations
+        .iter()
+        .filter(|evaluation| evaluation.is_breached)
+    {
+        let existing_alert = rule.active_alerts.iter().find(|alert| {
+            alert.is_for(
+                evaluation.connector.as_deref(),
+                evaluation.error_message.as_deref(),
+            )
+        });
+        let alert = match existing_alert {
+            Some(alert) if alert.last_notified_at + cooldown > now => ActiveMetricAlert {
+                observed_value: evaluation.observed_value,
+                ..alert.clone()
+            },
+            Some(alert) => {
+                let alert = ActiveMetricAlert {
+                    observed_value: evaluation.observed_value,
+                    last_notified_at: now,
+                    ..alert.clone()
+                };
+                alerts_to_notify.push((alert.clone(), evaluation.clone()));
+                alert
+            }
+            None => {
+                let alert = ActiveMetricAlert {
+                    alert_id: generate_id(consts::ID_LENGTH, "alert"),
+                    connector: evaluation.connector.clone(),
+                    error_message: evaluation.error_message.clone(),
+                    observed_value: evaluation.observed_value,
+                    triggered_at: now,
+                    last_notified_at: now,
+                };
+                alerts_to_notify.push((alert.clone(), evaluation.clone()));
+                alert
+            }
+        };
+        active_alerts.push(alert);
+    }
+
+    for alert in rule.active_alerts.drain(..) {
+        let is_still_active = active_alerts
+            .iter()
+            .any(|active| active.alert_id == alert.alert_id);
+        if is_still_active {
+            continue;
+        }
+        if evaluated_connectors.contains(&alert.connector.as_deref()) {
+            logger::info!(rule_id = %rule.rule_id, alert_id = %alert.alert_id, "Metric alert recovered");
+        } else {
+            // Not enough data to evaluate the alert in this window, keep it active
+            active_alerts.push(alert);
+        }
+    }
+    rule.active_alerts = active_alerts;
+
+    alerts_to_notify
+}
+
+fn get_alert_description(details: &MetricAlertDetails) -> String {
+    let mut scope = String::new();
+    if let Some(connector) = &details.connector {
+        scope.push_str(&format!(" for connector {connector}"));
+    }
+    if let Some(error_message) = &details.error_message {
+        scope.push_str(&format!(" with error message \"{error_message}\""));
+    }
+    let (comparison, reference) = match details.condition {
+        AlertCondition::Threshold { comparison, .. } => (comparison, "the threshold"),
+        AlertCondition::Baseline { comparison, .. } => (comparison, "the baseline"),
+    };
+    let comparison = match comparison {
+        AlertComparison::Above => "above",
+        AlertComparison::Below => "below",
+    };
+    format!(
+        "The {}{scope} was {:.2}%, {comparison} {reference} of {:.2}%, over {} samples.",
+        details.metric, details.observed_value, details.reference_value, details.sample_size
+    )
+}
+
+#[cfg(feature = "email")]
+async fn send_metric_alert_emails(
+    state: &SessionState,
+    rule: &MetricAlertRule,
+    details: &MetricAlertDetails,
+) {
+    use crate::{
+        services::email::types::MetricAlert, types::domain::UserEmail, utils::user as user_utils,
+    };
+
+    let description = get_alert_description(details);
+    for recipient in &rule.recipients {
+        let recipient_email = match UserEmail::from_pii_email(recipient.clone()) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::error!(?error, rule_id = %rule.rule_id, "Invalid metric alert recipient");
+                continue;
+            }
+        };
+        let email_contents = MetricAlert {
+            recipient_email,
+            subject: consts::EMAIL_SUBJECT_METRIC_ALERT,
+            rule_name: rule.name.clone(),
+            description: description.clone(),
+            time_range: details.time_range,
+        };
+
+        let _ = state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, rule_id = %rule.rule_id, "Failed to send metric alert email")
+            });
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn send_metric_alert_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    details: MetricAlertDetails,
+) -> RouterResult<()> {
+    use crate::{core::webhooks, types::api};
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &details.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: details.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // Every notification of an alert is a separate event, so that notifications sent after the
+    // cooldown are not deduplicated as retries of the first one
+    let primary_object_id = format!(
+        "{}_{}",
+        details.alert_id,
+        details.triggered_at.assume_utc().unix_timestamp()
+    );
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile,
+        storage::enums::EventType::MetricAlertTriggered,
+        storage::enums::EventClass::MetricAlerts,
+        primary_object_id,
+        storage::enums::EventObjectType::MetricAlertDetails,
+        api::OutgoingWebhookContent::MetricAlertDetails(Box::new(details)),
+        None,
+    ))
+    .await
+}
+
+#[cfg(feature = "v2")]
+async fn send_metric_alert_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    details: MetricAlertDetails,
+) -> RouterResult<()> {
+    use crate::{core::webhooks, types::api};
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &details.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: details.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let primary_object_id = format!(
+        "{}_{}",
+        details.alert_id,
+        details.triggered_at.assume_utc().unix_timestamp()
+    );
+    let primary_object_created_at = details.triggered_at;
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        business_profile,
+        merchant_context.get_merchant_key_store(),
+        storage::enums::EventType::MetricAlertTriggered,
+        storage::enums::EventClass::MetricAlerts,
+        primary_object_id,
+        storage::enums::EventObjectType::MetricAlertDetails,
+        api::OutgoingWebhookContent::MetricAlertDetails(Box::new(details)),
+        primary_object_created_at,
+    ))
+    .await
+}
+
+/// Evaluates the rule over the last complete window, notifies the alerts which were triggered
+/// and stores the updated state of the active alerts of the rule.
+/// Returns the time of the next evaluation, or `None` if the rule no longer exists.
+pub async fn run_metric_alert_rule(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &MetricAlertPTData,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let Some(mut rules) = get_metric_alert_rules(db, &tracking_data.profile_id).await? else {
+        return Ok(None);
+    };
+    let Some(rule) = rules
+        .iter_mut()
+        .find(|rule| rule.rule_id == tracking_data.rule_id)
+    else {
+        return Ok(None);
+    };
+
+    let now = common_utils::date_time::now();
+    let auth = AuthInfo::ProfileLevel {
+        org_id: merchant_context.get_merchant_account().get_org_id().clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        profile_ids: vec![tracking_data.profile_id.clone()],
+    };
+
+    let time_range = get_window_time_range(rule.window, now, 0);
+    let current = fetch_metric_observations(state, &auth, rule, time_range).await?;
+    let mut baseline = Vec::new();
+    for offset in 1..=rule.condition.get_baseline_windows() {
+        let baseline_time_range = get_window_time_range(rule.window, now, offset);
+        baseline.extend(fetch_metric_observations(state, &auth, rule, baseline_time_range).await?);
+    }
+
+    let evaluations = evaluate_condition(rule.condition, rule.min_sample_size, current, &baseline);
+    let alerts_to_notify = update_active_alerts(rule, &evaluations, now);
+
+    for (alert, evaluation) in alerts_to_notify {
+        let details = MetricAlertDetails {
+            alert_id: alert.alert_id,
+            rule_id: rule.rule_id.clone(),
+            rule_name: rule.name.clone(),
+            profile_id: rule.profile_id.clone(),
+            metric: rule.metric,
+            condition: rule.condition,
+            connector: evaluation.connector,
+            error_message: evaluation.error_message,
+            observed_value: evaluation.observed_value,
+            reference_value: evaluation.reference_value,
+            sample_size: evaluation.sample_size,
+            time_range,
+            triggered_at: now,
+        };
+        logger::info!(
+            rule_id = %details.rule_id,
+            alert_id = %details.alert_id,
+            connector = ?details.connector,
+            observed_value = details.observed_value,
+            "Metric alert triggered"
+        );
+
+        #[cfg(feature = "email")]
+        send_metric_alert_emails(state, rule, &details).await;
+
+        // A failure in delivering the webhook does not fail the evaluation, as the state of the
+        // alert has to be stored to avoid notifying it again in the next evaluation
+        if rule.send_webhook {
+            let _ = send_metric_alert_webhook(state, merchant_context, details)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, rule_id = %rule.rule_id, "Failed to send metric alert webhook")
+                });
+        }
+    }
+
+    let next_evaluation_at = get_window_end(rule.window, now) + rule.window.get_duration();
+    rule.next_evaluation_at = next_evaluation_at;
+    store_metric_alert_rules(db, &tracking_data.profile_id, &rules, false).await?;
+
+    Ok(Some(next_evaluation_at))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn observation(connector: &str, value: f64, sample_size: u64) -> MetricObservation {
+        MetricObservation {
+            connector: Some(connector.to_string()),
+            error_message: None,
+            value,
+            sample_size,
+        }
+    }
+
+    fn rule(condition: AlertCondition) -> MetricAlertRule {
+        MetricAlertRule {
+            rule_id: "alert_rule_1".to_string(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap(),
+            name: "Success rate drop".to_string(),
+            metric: AlertMetric::ConnectorSuccessRate,
+            condition,
+            window: AlertWindow::OneHour,
+            group_by_connector: true,
+            min_sample_size: 10,
+            cooldown_minutes: 60,
+            recipients: Vec::new(),
+            send_webhook: true,
+            next_evaluation_at: datetime!(2025-01-01 00:00),
+            active_alerts: Vec::new(),
+            created_at: datetime!(2025-01-01 00:00),
+        }
+    }
+
+    #[test]
+    fn test_get_window_time_range() {
+        let time_range =
+            get_window_time_range(AlertWindow::FifteenMin, datetime!(2025-03-10 10:37:12), 0);
+        assert_eq!(time_range.start_time, datetime!(2025-03-10 10:15));
+        assert_eq!(time_range.end_time, Some(datetime!(2025-03-10 10:30)));
+
+        let time_range =
+            get_window_time_range(AlertWindow::OneDay, datetime!(2025-03-10 10:37:12), 2);
+        assert_eq!(time_range.start_time, datetime!(2025-03-07 00:00));
+        assert_eq!(time_range.end_time, Some(datetime!(2025-03-08 00:00)));
+    }
+
+    #[test]
+    fn test_evaluate_threshold_condition() {
+        let condition = AlertCondition::Threshold {
+            comparison: AlertComparison::Below,
+            value: 80.0,
+        };
+        let current = vec![
+            observation("stripe", 72.5, 100),
+            observation("adyen", 95.0, 100),
+            observation("checkout", 10.0, 5),
+        ];
+
+        let evaluations = evaluate_condition(condition, 10, current, &[]);
+        assert_eq!(evaluations.len(), 2);
+        assert!(evaluations[0].is_breached);
+        assert_eq!(evaluations[0].reference_value, 80.0);
+        assert!(!evaluations[1].is_breached);
+    }
+
+    #[test]
+    fn test_evaluate_baseline_condition() {
+        let condition = AlertCondition::Baseline {
+            comparison: AlertComparison::Below,
+            deviation: 10.0,
+            baseline_windows: 2,
+        };
+        let current = vec![
+            observation("stripe", 75.0, 100),
+            observation("adyen", 85.0, 100),
+        ];
+        let baseline = vec![
+            observation("stripe", 90.0, 100),
+            observation("stripe", 88.0, 100),
+            observation("adyen", 90.0, 100),
+        ];
+
+        let evaluations = evaluate_condition(condition, 10, current, &baseline);
+        assert_eq!(evaluations.len(), 2);
+        assert!(evaluations[0].is_breached);
+        assert_eq!(evaluations[0].reference_value, 89.0);
+        assert!(!evaluations[1].is_breached);
+    }
+
+    #[test]
+    fn test_update_active_alerts_deduplicates_and_recovers() {
+        let mut rule = rule(AlertCondition::Threshold {
+            comparison: AlertComparison::Below,
+            value: 80.0,
+        });
+        let breached = |connector: &str| AlertEvaluation {
+            connector: Some(connector.to_string()),
+            error_message: None,
+            observed_value: 70.0,
+            reference_value: 80.0,
+            sample_size: 100,
+            is_breached: true,
+        };
+        let recovered = |connector: &str| AlertEvaluation {
+            is_breached: false,
+            ..breached(connector)
+        };
+
+        let now = datetime!(2025-03-10 10:00);
+        let notified = update_active_alerts(&mut rule, &[breached("stripe")], now);
+        assert_eq!(notified.len(), 1);
+        let alert_id = notified[0].0.alert_id.clone();
+
+        // Still breached within the cooldown, not notified again
+        let now = now + time::Duration::minutes(30);
+        let notified = update_active_alerts(&mut rule, &[breached("stripe")], now);
+        assert!(notified.is_empty());
+        assert_eq!(rule.active_alerts.len(), 1);
+
+        // Still breached after the cooldown, notified again with the same alert id
+        let now = now + time::Duration::minutes(60);
+        let notified = update_active_alerts(&mut rule, &[breached("stripe")], now);
+        assert_eq!(notified.len(), 1);
+        assert_eq!(notified[0].0.alert_id, alert_id);
+
+        // Not evaluated for lack of data, stays active
+        let notified = update_active_alerts(&mut rule, &[recovered("adyen")], now);
+        assert!(notified.is_empty());
+        assert_eq!(rule.active_alerts.len(), 1);
+
+        // Recovered
+        let notified = update_active_alerts(&mut rule, &[recovered("stripe")], now);
+        assert!(notified.is_empty());
+        assert!(rule.active_alerts.is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod reusable_payment_link;
 pub mod validator;
 use std::collections::HashMap;
 
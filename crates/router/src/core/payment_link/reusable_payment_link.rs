@@ -0,0 +1,746 @@
+use api_models::payments as payment_types;
+use common_utils::{
+    ext_traits::{Encode, OptionExt, ValueExt},
+    id_type,
+    types::{AmountConvertor, MinorUnit, StringMajorUnit, StringMajorUnitForCore},
+};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::HeaderPayload;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_link, payments, utils as core_utils,
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{api, domain, storage},
+    utils,
+};
+
+const REUSABLE_PAYMENT_LINK_ID_PREFIX: &str = "rplink";
+
+#[instrument(skip_all)]
+pub async fn create_reusable_payment_link(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::ReusablePaymentLinkCreateRequest,
+) -> RouterResponse<payment_types::ReusablePaymentLinkResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+
+    validate_amount_configuration(
+        request.amount_type,
+        request.amount,
+        request.min_amount,
+        request.max_amount,
+    )?;
+    validate_usage_limit(request.usage_limit)?;
+    validate_expiry(request.expires_at)?;
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        request.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let merchant_name = merchant_context
+        .get_merchant_account()
+        .merchant_name
+        .clone()
+        .map(|name| name.into_inner().peek().to_owned())
+        .unwrap_or_default();
+
+    // Validates the link configuration up front, so that visits do not fail while creating payments
+    let (_, domain_name) = payment_link::get_payment_link_config_based_on_priority(
+        request.payment_link_config.clone(),
+        business_profile.payment_link_config,
+        merchant_name,
+        state.base_url.clone(),
+        None,
+    )?;
+
+    let reusable_payment_link_id =
+        utils::generate_id(consts::ID_LENGTH, REUSABLE_PAYMENT_LINK_ID_PREFIX);
+    let link_to_pay = format!(
+        "{domain_name}/payment_link/reusable/{}/{reusable_payment_link_id}",
+        merchant_id.get_string_repr()
+    );
+
+    let payment_link_config = request
+        .payment_link_config
+        .map(|config| config.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payment link config")?;
+
+    let now = common_utils::date_time::now();
+    let reusable_payment_link_new = storage::ReusablePaymentLinkNew {
+        reusable_payment_link_id,
+        merchant_id,
+        profile_id,
+        amount_type: request.amount_type,
+        amount: request.amount,
+        min_amount: request.min_amount,
+        max_amount: request.max_amount,
+        currency: request.currency,
+        description: request.description,
+        payment_link_config,
+        link_to_pay,
+        is_active: true,
+        usage_limit: request.usage_limit,
+        usage_count: 0,
+        visit_count: 0,
+        expires_at: request.expires_at,
+        last_used_at: None,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let reusable_payment_link = db
+        .insert_reusable_payment_link(reusable_payment_link_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the reusable payment link")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_reusable_payment_link_response(reusable_payment_link),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_reusable_payment_link(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::ReusablePaymentLinkId,
+) -> RouterResponse<payment_types::ReusablePaymentLinkResponse> {
+    let reusable_payment_link = find_reusable_payment_link(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        &request.reusable_payment_link_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_reusable_payment_link_response(reusable_payment_link),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_reusable_payment_link(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    reusable_payment_link_id: String,
+    request: payment_types::ReusablePaymentLinkUpdateRequest,
+) -> RouterResponse<payment_types::ReusablePaymentLinkResponse> {
+    let db = state.store.as_ref();
+    let reusable_payment_link = find_reusable_payment_link(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        &reusable_payment_link_id,
+    )
+    .await?;
+
+    validate_usage_limit(request.usage_limit)?;
+    validate_expiry(request.expires_at)?;
+
+    let payment_link_config = request
+        .payment_link_config
+        .map(|config| config.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payment link config")?;
+
+    let link_update = storage::ReusablePaymentLinkUpdate::Update {
+        is_active: request.is_active,
+        description: request.description,
+        usage_limit: request.usage_limit,
+        expires_at: request.expires_at,
+        payment_link_config,
+        metadata: request.metadata,
+    };
+
+    let reusable_payment_link = db
+        .update_reusable_payment_link(reusable_payment_link, link_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the reusable payment link")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_reusable_payment_link_response(reusable_payment_link),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_reusable_payment_links(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: payment_types::ReusablePaymentLinkListConstraints,
+) -> RouterResponse<payment_types::ReusablePaymentLinkListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into())
+        .clamp(1, common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1.into());
+
+    let reusable_payment_links = state
+        .store
+        .list_reusable_payment_links_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the reusable payment links")?;
+
+    let data = reusable_payment_links
+        .into_iter()
+        .map(get_reusable_payment_link_response)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::ReusablePaymentLinkListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn get_reusable_payment_link_analytics(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::ReusablePaymentLinkId,
+) -> RouterResponse<payment_types::ReusablePaymentLinkAnalyticsResponse> {
+    let reusable_payment_link = find_reusable_payment_link(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        &request.reusable_payment_link_id,
+    )
+    .await?;
+
+    let conversion_rate = if reusable_payment_link.visit_count > 0 {
+        f64::from(reusable_payment_link.usage_count) * 100.0
+            / f64::from(reusable_payment_link.visit_count)
+    } else {
+        0.0
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::ReusablePaymentLinkAnalyticsResponse {
+            remaining_uses: reusable_payment_link.usage_limit.map(|usage_limit| {
+                usage_limit
+                    .saturating_sub(reusable_payment_link.usage_count)
+                    .max(0)
+            }),
+            reusable_payment_link_id: reusable_payment_link.reusable_payment_link_id,
+            visit_count: reusable_payment_link.visit_count,
+            usage_count: reusable_payment_link.usage_count,
+            conversion_rate,
+            last_used_at: reusable_payment_link.last_used_at,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn get_reusable_payment_link_qr_code(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    reusable_payment_link_id: String,
+    request: payment_types::ReusablePaymentLinkQrCodeRequest,
+) -> RouterResponse<serde_json::Value> {
+    let reusable_payment_link = find_reusable_payment_link(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        &reusable_payment_link_id,
+    )
+    .await?;
+
+    let (image_data, content_type) = match request.format {
+        payment_types::QrCodeImageFormat::Png => (
+            utils::QrImage::png_bytes_from_data(&reusable_payment_link.link_to_pay)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to generate the QR code image")?,
+            mime::IMAGE_PNG,
+        ),
+        payment_types::QrCodeImageFormat::Svg => (
+            utils::QrImage::svg_from_data(&reusable_payment_link.link_to_pay)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to generate the QR code image")?
+                .into_bytes(),
+            "image/svg+xml"
+                .parse::<mime::Mime>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        ),
+    };
+
+    Ok(services::ApplicationResponse::FileData((
+        image_data,
+        content_type,
+    )))
+}
+
+/// Records a visit of the reusable payment link and renders the page on which the customer
+/// confirms the payment, entering the amount for customer chosen amount links. No payment is
+/// created until the customer confirms.
+#[instrument(skip_all)]
+pub async fn visit_reusable_payment_link(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::ReusablePaymentLinkVisitRequest,
+) -> RouterResponse<serde_json::Value> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let reusable_payment_link =
+        find_reusable_payment_link(&state, merchant_id, &request.reusable_payment_link_id).await?;
+    check_reusable_payment_link_usable(&reusable_payment_link, common_utils::date_time::now())?;
+
+    state
+        .store
+        .increment_reusable_payment_link_visit_count(
+            merchant_id,
+            &reusable_payment_link.reusable_payment_link_id,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to record the reusable payment link visit");
+        })
+        .ok();
+
+    let confirm_form = get_confirm_form_html(&reusable_payment_link)?;
+    Ok(services::ApplicationResponse::FileData((
+        confirm_form.into_bytes(),
+        mime::TEXT_HTML_UTF_8,
+    )))
+}
+
+/// Creates a new payment when the customer confirms the reusable payment link and redirects to
+/// its payment link. The use of the link is only counted once the payment succeeds.
+#[instrument(skip_all)]
+pub async fn confirm_reusable_payment_link(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::ReusablePaymentLinkConfirmRequest,
+) -> RouterResponse<serde_json::Value> {
+    let reusable_payment_link = find_reusable_payment_link(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        &request.reusable_payment_link_id,
+    )
+    .await?;
+    check_reusable_payment_link_usable(&reusable_payment_link, common_utils::date_time::now())?;
+
+    let amount = match reusable_payment_link.amount_type {
+        storage_enums::ReusablePaymentLinkAmountType::Fixed => reusable_payment_link
+            .amount
+            .get_required_value("amount")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Amount missing for the fixed amount reusable payment link")?,
+        storage_enums::ReusablePaymentLinkAmountType::CustomerChosen => {
+            let amount = StringMajorUnitForCore
+                .convert_back(
+                    request
+                        .amount
+                        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                            field_name: "amount",
+                        })?,
+                    reusable_payment_link.currency,
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "amount",
+                })?;
+            validate_amount_in_range(
+                amount,
+                reusable_payment_link.min_amount,
+                reusable_payment_link.max_amount,
+            )?;
+            amount
+        }
+    };
+
+    let payment_link_config = reusable_payment_link
+        .payment_link_config
+        .clone()
+        .map(|config| config.parse_value("PaymentCreatePaymentLinkConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment link config")?;
+
+    let payments_request = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            id_type::PaymentId::default(),
+        )),
+        amount: Some(payment_types::Amount::from(amount)),
+        currency: Some(reusable_payment_link.currency),
+        description: reusable_payment_link.description.clone(),
+        metadata: reusable_payment_link
+            .metadata
+            .clone()
+            .map(|metadata| metadata.peek().clone()),
+        payment_link: Some(true),
+        payment_link_config,
+        profile_id: Some(reusable_payment_link.profile_id.clone()),
+        // Identifies the link whose use is counted once the payment succeeds
+        merchant_order_reference_id: Some(reusable_payment_link.reusable_payment_link_id.clone()),
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state,
+        req_state,
+        merchant_context,
+        Some(reusable_payment_link.profile_id.clone()),
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+
+    let payments_response = match response {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            payments_response
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from the payment create flow")?,
+    };
+
+    let link = payments_response
+        .payment_link
+        .map(|payment_link| payment_link.link)
+        .get_required_value("payment_link")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment link missing for the payment created by the reusable link")?;
+
+    Ok(services::ApplicationResponse::JsonForRedirection(
+        payment_types::RedirectionResponse {
+            return_url: link.clone(),
+            params: vec![],
+            return_url_with_query_params: link,
+            http_method: "GET".to_string(),
+            headers: vec![],
+        },
+    ))
+}
+
+/// Counts a use of the reusable payment link through which the payment was created, once the
+/// payment has succeeded
+#[instrument(skip_all)]
+pub async fn record_reusable_payment_link_usage(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+) {
+    let Some(reusable_payment_link_id) = payment_intent
+        .merchant_order_reference_id
+        .as_deref()
+        .filter(|reference_id| is_reusable_payment_link_id(reference_id))
+    else {
+        return;
+    };
+
+    state
+        .store
+        .increment_reusable_payment_link_usage_count(
+            &payment_intent.merchant_id,
+            reusable_payment_link_id,
+            common_utils::date_time::now(),
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to record the reusable payment link usage");
+        })
+        .ok();
+}
+
+fn is_reusable_payment_link_id(reference_id: &str) -> bool {
+    reference_id
+        .strip_prefix(REUSABLE_PAYMENT_LINK_ID_PREFIX)
+        .is_some_and(|suffix| suffix.starts_with('_'))
+}
+
+async fn find_reusable_payment_link(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    reusable_payment_link_id: &str,
+) -> RouterResult<storage::ReusablePaymentLink> {
+    state
+        .store
+        .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+            merchant_id,
+            reusable_payment_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+}
+
+fn get_reusable_payment_link_status(
+    reusable_payment_link: &storage::ReusablePaymentLink,
+    now: time::PrimitiveDateTime,
+) -> payment_types::ReusablePaymentLinkStatus {
+    if !reusable_payment_link.is_active {
+        payment_types::ReusablePaymentLinkStatus::Inactive
+    } else if reusable_payment_link
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= now)
+    {
+        payment_types::ReusablePaymentLinkStatus::Expired
+    } else if reusable_payment_link
+        .usage_limit
+        .is_some_and(|usage_limit| reusable_payment_link.usage_count >= usage_limit)
+    {
+        payment_types::ReusablePaymentLinkStatus::UsageLimitReached
+    } else {
+        payment_types::ReusablePaymentLinkStatus::Active
+    }
+}
+
+fn check_reusable_payment_link_usable(
+    reusable_payment_link: &storage::ReusablePaymentLink,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let message = match get_reusable_payment_link_status(reusable_payment_link, now) {
+        payment_types::ReusablePaymentLinkStatus::Active => return Ok(()),
+        payment_types::ReusablePaymentLinkStatus::Inactive => "The payment link is not active",
+        payment_types::ReusablePaymentLinkStatus::Expired => "The payment link has expired",
+        payment_types::ReusablePaymentLinkStatus::UsageLimitReached => {
+            "The payment link has reached its usage limit"
+        }
+    };
+    Err(errors::ApiErrorResponse::PreconditionFailed {
+        message: message.to_string(),
+    }
+    .into())
+}
+
+fn get_reusable_payment_link_response(
+    reusable_payment_link: storage::ReusablePaymentLink,
+) -> payment_types::ReusablePaymentLinkResponse {
+    let status =
+        get_reusable_payment_link_status(&reusable_payment_link, common_utils::date_time::now());
+    payment_types::ReusablePaymentLinkResponse {
+        qr_code_url: format!("{}/qr", reusable_payment_link.link_to_pay),
+        reusable_payment_link_id: reusable_payment_link.reusable_payment_link_id,
+        merchant_id: reusable_payment_link.merchant_id,
+        profile_id: reusable_payment_link.profile_id,
+        amount_type: reusable_payment_link.amount_type,
+        amount: reusable_payment_link.amount,
+        min_amount: reusable_payment_link.min_amount,
+        max_amount: reusable_payment_link.max_amount,
+        currency: reusable_payment_link.currency,
+        description: reusable_payment_link.description,
+        link_to_pay: reusable_payment_link.link_to_pay,
+        status,
+        usage_limit: reusable_payment_link.usage_limit,
+        usage_count: reusable_payment_link.usage_count,
+        expires_at: reusable_payment_link.expires_at,
+        metadata: reusable_payment_link.metadata,
+        created_at: reusable_payment_link.created_at,
+    }
+}
+
+fn validate_amount_configuration(
+    amount_type: storage_enums::ReusablePaymentLinkAmountType,
+    amount: Option<MinorUnit>,
+    min_amount: Option<MinorUnit>,
+    max_amount: Option<MinorUnit>,
+) -> RouterResult<()> {
+    let is_positive =
+        |amount: Option<MinorUnit>| amount.map_or(true, |amount| amount.get_amount_as_i64() > 0);
+    if !(is_positive(amount) && is_positive(min_amount) && is_positive(max_amount)) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amounts of a reusable payment link must be greater than zero".to_string(),
+        }
+        .into());
+    }
+
+    match amount_type {
+        storage_enums::ReusablePaymentLinkAmountType::Fixed => {
+            if amount.is_none() {
+                return Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "amount",
+                }
+                .into());
+            }
+            if min_amount.is_some() || max_amount.is_some() {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "min_amount and max_amount are only allowed for customer_chosen links"
+                        .to_string(),
+                }
+                .into());
+            }
+        }
+        storage_enums::ReusablePaymentLinkAmountType::CustomerChosen => {
+            if let (Some(min_amount), Some(max_amount)) = (min_amount, max_amount) {
+                if min_amount > max_amount {
+                    return Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "min_amount must not be greater than max_amount".to_string(),
+                    }
+                    .into());
+                }
+            }
+            if let Some(amount) = amount {
+                validate_amount_in_range(amount, min_amount, max_amount)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_amount_in_range(
+    amount: MinorUnit,
+    min_amount: Option<MinorUnit>,
+    max_amount: Option<MinorUnit>,
+) -> RouterResult<()> {
+    if amount.get_amount_as_i64() <= 0
+        || min_amount.is_some_and(|min_amount| amount < min_amount)
+        || max_amount.is_some_and(|max_amount| amount > max_amount)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount is outside the range allowed by the payment link".to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn validate_usage_limit(usage_limit: Option<i32>) -> RouterResult<()> {
+    if usage_limit.is_some_and(|usage_limit| usage_limit <= 0) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "usage_limit must be greater than zero".to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn validate_expiry(expires_at: Option<time::PrimitiveDateTime>) -> RouterResult<()> {
+    if expires_at.is_some_and(|expires_at| expires_at <= common_utils::date_time::now()) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "expires_at must be in the future".to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Renders the page on which the customer confirms the payment, entering the amount for customer
+/// chosen amount links. The form is submitted with a POST request, so that the payment is only
+/// created once the customer confirms.
+fn get_confirm_form_html(
+    reusable_payment_link: &storage::ReusablePaymentLink,
+) -> RouterResult<String> {
+    let currency = reusable_payment_link.currency;
+    let to_major_unit = |amount: Option<MinorUnit>| {
+        amount
+            .map(|amount| StringMajorUnitForCore.convert(amount, currency))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::AmountConversionFailed {
+                amount_type: "StringMajorUnit",
+            })
+    };
+    let format_attribute = |name: &str, amount: Option<StringMajorUnit>| {
+        amount
+            .map(|amount| {
+                format!(
+                    r#" {name}="{}""#,
+                    escape_html(&amount.get_amount_as_string())
+                )
+            })
+            .unwrap_or_default()
+    };
+
+    let amount_field = match reusable_payment_link.amount_type {
+        storage_enums::ReusablePaymentLinkAmountType::Fixed => {
+            let amount = to_major_unit(reusable_payment_link.amount)?
+                .map(|amount| escape_html(&amount.get_amount_as_string()))
+                .unwrap_or_default();
+            format!("<p>Amount: {amount} {currency}</p>")
+        }
+        storage_enums::ReusablePaymentLinkAmountType::CustomerChosen => {
+            let min_attribute =
+                format_attribute("min", to_major_unit(reusable_payment_link.min_amount)?);
+            let max_attribute =
+                format_attribute("max", to_major_unit(reusable_payment_link.max_amount)?);
+            let value_attribute =
+                format_attribute("value", to_major_unit(reusable_payment_link.amount)?);
+            format!(
+                r#"<label for="amount">Amount ({currency})</label>
+<input id="amount" name="amount" type="number" step="any" required{min_attribute}{max_attribute}{value_attribute}>"#
+            )
+        }
+    };
+    let description = reusable_payment_link
+        .description
+        .as_deref()
+        .map(escape_html)
+        .unwrap_or_default();
+
+    Ok(format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{description}</title>
+<style>
+body {{ font-family: sans-serif; display: flex; justify-content: center; padding-top: 10vh; }}
+form {{ display: flex; flex-direction: column; gap: 12px; min-width: 280px; }}
+input, button {{ font-size: 1.1em; padding: 8px; }}
+</style>
+</head>
+<body>
+<form method="post">
+<h2>{description}</h2>
+{amount_field}
+<button type="submit">Continue to payment</button>
+</form>
+</body>
+</html>"#
+    ))
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .chars()
+        .map(|character| match character {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#x27;".to_string(),
+            other => other.to_string(),
+        })
+        .collect()
+}
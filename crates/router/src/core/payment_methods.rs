@@ -3769,6 +3769,7 @@ async fn create_single_use_tokenization_flow(
             psd2_sca_exemption_type: None,
             raw_connector_response: None,
             is_payment_id_from_merchant: None,
+            merchant_connector_id: None,
             l2_l3_data: None,
             minor_amount_capturable: None,
         };
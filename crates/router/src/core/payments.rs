@@ -723,6 +723,7 @@ where
                     &business_profile,
                     merchant_context.get_merchant_key_store(),
                     mandate_type,
+                    merchant_context.get_merchant_account().storage_scheme,
                 )
                 .await?;
         } else {
@@ -740,6 +741,7 @@ where
                     &business_profile,
                     merchant_context.get_merchant_key_store(),
                     mandate_type,
+                    merchant_context.get_merchant_account().storage_scheme,
                 )
                 .await?;
         };
@@ -3554,7 +3556,11 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
             .attach_printable("missing authentication_id in payment_attempt")?;
         let authentication = state
             .store
-            .find_authentication_by_merchant_id_authentication_id(&merchant_id, &authentication_id)
+            .find_authentication_by_merchant_id_authentication_id(
+                &merchant_id,
+                &authentication_id,
+                merchant_context.get_merchant_account().storage_scheme,
+            )
             .await
             .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
                 id: authentication_id.get_string_repr().to_string(),
@@ -7454,6 +7460,8 @@ where
     pub service_details: Option<api_models::payments::CtpServiceDetails>,
     pub card_testing_guard_data:
         Option<hyperswitch_domain_models::card_testing_guard_data::CardTestingGuardData>,
+    pub velocity_check_data:
+        Option<hyperswitch_domain_models::velocity_check_data::VelocityCheckData>,
     pub vault_operation: Option<domain_payments::VaultOperation>,
     pub threeds_method_comp_ind: Option<api_models::payments::ThreeDsCompletionIndicator>,
     pub whole_connector_response: Option<Secret<String>>,
@@ -9752,7 +9760,7 @@ pub async fn route_connector_v1_for_payouts(
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
-            api::ConnectorData::get_connector_by_name(
+            api::ConnectorData::get_payout_connector_by_name(
                 &state.conf.connectors,
                 &conn.connector.to_string(),
                 api::GetToken::Connector,
@@ -9906,6 +9914,7 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
                 .clone()
                 .ok_or(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("missing authentication_id in payment_attempt")?,
+            storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
@@ -10006,6 +10015,7 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
             None,
             None,
             None,
+            storage_scheme,
         )
         .await?;
         authentication::AuthenticationResponse::try_from(authentication)?
@@ -10040,6 +10050,7 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
             payment_intent.payment_id,
             payment_intent.force_3ds_challenge_trigger.unwrap_or(false),
             merchant_context.get_merchant_key_store(),
+            storage_scheme,
         ))
         .await?
     };
@@ -10366,6 +10377,11 @@ pub trait OperationSessionGetters<F> {
     #[cfg(feature = "v1")]
     fn get_whole_connector_response(&self) -> Option<Secret<String>>;
 
+    #[cfg(feature = "v1")]
+    fn get_velocity_check_data(
+        &self,
+    ) -> Option<&hyperswitch_domain_models::velocity_check_data::VelocityCheckData>;
+
     #[cfg(feature = "v1")]
     fn get_vault_operation(&self) -> Option<&domain_payments::VaultOperation>;
 
@@ -10603,6 +10619,12 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.whole_connector_response.clone()
     }
 
+    fn get_velocity_check_data(
+        &self,
+    ) -> Option<&hyperswitch_domain_models::velocity_check_data::VelocityCheckData> {
+        self.velocity_check_data.as_ref()
+    }
+
     #[cfg(feature = "v1")]
     fn get_capture_method(&self) -> Option<enums::CaptureMethod> {
         self.payment_attempt.capture_method
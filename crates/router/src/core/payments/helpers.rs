@@ -101,7 +101,8 @@ use crate::{
 use crate::{core::admin as core_admin, headers, types::ConnectorAuthType};
 #[cfg(feature = "v1")]
 use crate::{
-    core::payment_methods::cards::create_encrypted_data, types::storage::CustomerUpdate::Update,
+    core::{customer_deduplication, payment_methods::cards::create_encrypted_data},
+    types::storage::CustomerUpdate::Update,
 };
 
 #[instrument(skip_all)]
@@ -1734,6 +1735,10 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
         .change_context(errors::StorageError::EncryptionError)
         .attach_printable("Unable to encrypt customer details")?;
 
+    // Only details passed in the request change what is indexed for deduplication
+    let is_customer_contact_in_request = request_customer_details.email.is_some()
+        || request_customer_details.phone.is_some()
+        || request_customer_details.phone_country_code.is_some();
     let customer_id = request_customer_details
         .customer_id
         .or(payment_data.payment_intent.customer_id.clone());
@@ -1874,6 +1879,15 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
             Some(customer) => {
                 let customer = customer?;
 
+                if is_customer_contact_in_request {
+                    customer_deduplication::index_customer(state, key_store, &customer)
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to index the customer for deduplication")
+                        })
+                        .ok();
+                }
+
                 payment_data.payment_intent.customer_id = Some(customer.customer_id.clone());
                 payment_data.email = payment_data.email.clone().or_else(|| {
                     customer
@@ -3892,6 +3906,7 @@ mod tests {
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
             is_payment_id_from_merchant: None,
+            merchant_connector_id: None,
             payment_channel: None,
             tax_status: None,
             discount_amount: None,
@@ -3976,6 +3991,7 @@ mod tests {
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
             is_payment_id_from_merchant: None,
+            merchant_connector_id: None,
             payment_channel: None,
             tax_status: None,
             discount_amount: None,
@@ -4058,6 +4074,7 @@ mod tests {
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
             is_payment_id_from_merchant: None,
+            merchant_connector_id: None,
             payment_channel: None,
             tax_status: None,
             discount_amount: None,
@@ -4409,6 +4426,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         psd2_sca_exemption_type: router_data.psd2_sca_exemption_type,
         raw_connector_response: router_data.raw_connector_response,
         is_payment_id_from_merchant: router_data.is_payment_id_from_merchant,
+        merchant_connector_id: router_data.merchant_connector_id.clone(),
         l2_l3_data: router_data.l2_l3_data,
         minor_amount_capturable: router_data.minor_amount_capturable,
     }
@@ -341,6 +341,7 @@ pub trait Domain<F: Clone, R, D>: Send + Sync {
         _business_profile: &domain::Profile,
         _key_store: &domain::MerchantKeyStore,
         _mandate_type: Option<api_models::payments::MandateTransactionType>,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
         Ok(())
     }
@@ -355,6 +356,7 @@ pub trait Domain<F: Clone, R, D>: Send + Sync {
         _business_profile: &domain::Profile,
         _key_store: &domain::MerchantKeyStore,
         _mandate_type: Option<api_models::payments::MandateTransactionType>,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
         Ok(())
     }
@@ -195,6 +195,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelPo
             session_id: None,
             service_details: None,
             card_testing_guard_data: None,
+            velocity_check_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
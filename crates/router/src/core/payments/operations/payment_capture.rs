@@ -261,6 +261,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
             session_id: None,
             service_details: None,
             card_testing_guard_data: None,
+            velocity_check_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
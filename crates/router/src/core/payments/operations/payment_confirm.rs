@@ -24,7 +24,10 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 #[cfg(feature = "v1")]
 use crate::{
     consts,
-    core::payment_methods::cards::create_encrypted_data,
+    core::{
+        payment_methods::cards::create_encrypted_data,
+        velocity_rules::utils as velocity_rules_utils,
+    },
     events::audit_events::{AuditEvent, AuditEventType},
 };
 use crate::{
@@ -826,6 +829,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             session_id: None,
             service_details: request.ctp_service_details.clone(),
             card_testing_guard_data: None,
+            velocity_check_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
@@ -858,21 +862,46 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         let customer_id = &payment_data.payment_intent.customer_id;
 
-        match payment_method_data {
-            Some(api_models::payments::PaymentMethodData::Card(_card)) => {
-                payment_data.card_testing_guard_data =
-                    card_testing_guard_utils::validate_card_testing_guard_checks(
-                        state,
-                        request,
-                        payment_method_data,
-                        customer_id,
-                        business_profile,
-                    )
-                    .await?;
-                Ok(())
-            }
-            _ => Ok(()),
+        if let Some(api_models::payments::PaymentMethodData::Card(_card)) = payment_method_data {
+            payment_data.card_testing_guard_data =
+                card_testing_guard_utils::validate_card_testing_guard_checks(
+                    state,
+                    request,
+                    payment_method_data,
+                    customer_id,
+                    business_profile,
+                )
+                .await?;
+        }
+
+        let email = helpers::get_customer_details_from_request(request)
+            .email
+            .or(payment_data.email.clone());
+
+        payment_data.velocity_check_data = velocity_rules_utils::validate_velocity_rules(
+            state,
+            request,
+            payment_method_data,
+            email.as_ref(),
+            payment_data.payment_intent.amount,
+            payment_data.payment_intent.currency,
+            &payment_data.payment_intent.payment_id,
+            business_profile,
+        )
+        .await?;
+
+        // Velocity rules with the `three_ds` action override the authentication type of the attempt
+        if payment_data
+            .velocity_check_data
+            .as_ref()
+            .and_then(|velocity_check_data| velocity_check_data.action)
+            == Some(api_models::velocity_rules::VelocityRuleAction::ThreeDs)
+        {
+            payment_data.payment_attempt.authentication_type =
+                Some(storage_enums::AuthenticationType::ThreeDs);
         }
+
+        Ok(())
     }
 }
 
@@ -1009,6 +1038,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
         business_profile: &domain::Profile,
         key_store: &domain::MerchantKeyStore,
         mandate_type: Option<api_models::payments::MandateTransactionType>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
         let external_authentication_flow =
             helpers::get_payment_external_authentication_flow_during_confirm(
@@ -1037,6 +1067,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     payment_data.payment_attempt.organization_id.clone(),
                     payment_data.payment_intent.force_3ds_challenge,
                     payment_data.payment_intent.psd2_sca_exemption_type,
+                    storage_scheme,
                 ))
                 .await?;
                 if authentication_store
@@ -1091,6 +1122,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     business_profile.clone(),
                     authentication_id.clone(),
                     &payment_data.payment_intent.payment_id,
+                    storage_scheme,
                 ))
                 .await?;
                 //If authentication is not successful, skip the payment connector flows and mark the payment as failure
@@ -1227,6 +1259,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
         business_profile: &domain::Profile,
         key_store: &domain::MerchantKeyStore,
         mandate_type: Option<api_models::payments::MandateTransactionType>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
         let unified_authentication_service_flow =
             helpers::decide_action_for_unified_authentication_service(
@@ -1367,7 +1400,8 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                             None,
                             None,
                             None,
-                            None
+                            None,
+                            storage_scheme,
                         )
                         .await?;
                         let authentication_store = hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore {
@@ -1398,6 +1432,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     payment_data.payment_attempt.organization_id.clone(),
                     payment_data.payment_intent.force_3ds_challenge,
                     payment_data.payment_intent.psd2_sca_exemption_type,
+                    storage_scheme,
                 )
                 .await?;
 
@@ -1430,6 +1465,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     None,
                     None,
                     None,
+                    storage_scheme,
                 ).await?;
                 let authentication_store = hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore {
                     cavv: None, // since in case of pre_authentication cavv is not present
@@ -1485,6 +1521,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     .find_authentication_by_merchant_id_authentication_id(
                         &business_profile.merchant_id,
                         &authentication_id,
+                        storage_scheme,
                     )
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
@@ -1513,6 +1550,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                         None,
                         None,
                         None,
+                        storage_scheme,
                     ).await?
                 } else {
                     authentication
@@ -24,8 +24,12 @@ use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
+#[cfg(feature = "v1")]
+use crate::core::payment_link::reusable_payment_link;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
+#[cfg(feature = "v1")]
+use crate::core::velocity_rules::utils as velocity_rules_utils;
 #[cfg(feature = "v2")]
 use crate::utils::OptionExt;
 use crate::{
@@ -2097,6 +2101,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 .update_authentication_by_merchant_id_authentication_id(
                     authentication_store.authentication,
                     authentication_update,
+                    storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
@@ -2312,6 +2317,12 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         }
     }
 
+    if payment_intent.status == enums::IntentStatus::Succeeded
+        && payment_data.payment_intent.status != enums::IntentStatus::Succeeded
+    {
+        reusable_payment_link::record_reusable_payment_link_usage(state, &payment_intent).await;
+    }
+
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
     router_data.payment_method_status.and_then(|status| {
@@ -2327,6 +2338,12 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             payment_data.card_testing_guard_data.clone(),
         )
         .await;
+
+        velocity_rules_utils::increment_decline_counters(
+            state,
+            payment_data.velocity_check_data.as_ref(),
+        )
+        .await;
     }
 
     match router_data.integrity_check {
@@ -374,7 +374,7 @@ async fn get_tracker_for_sync<
         })?;
 
     let disputes = db
-        .find_disputes_by_merchant_id_payment_id(merchant_context.get_merchant_account().get_id(), &payment_id)
+        .find_disputes_by_merchant_id_payment_id(merchant_context.get_merchant_account().get_id(), &payment_id, merchant_context.get_merchant_account().storage_scheme)
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)
         .attach_printable_lazy(|| {
@@ -460,29 +460,32 @@ async fn get_tracker_for_sync<
 
     let merchant_id = payment_intent.merchant_id.clone();
 
-    let authentication_store = if let Some(ref authentication_id) =
-        payment_attempt.authentication_id
-    {
-        let authentication = db
-            .find_authentication_by_merchant_id_authentication_id(&merchant_id, authentication_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable_lazy(|| {
-                format!(
-                    "Error while fetching authentication record with authentication_id {}",
-                    authentication_id.get_string_repr()
+    let authentication_store =
+        if let Some(ref authentication_id) = payment_attempt.authentication_id {
+            let authentication = db
+                .find_authentication_by_merchant_id_authentication_id(
+                    &merchant_id,
+                    authentication_id,
+                    storage_scheme,
                 )
-            })?;
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Error while fetching authentication record with authentication_id {}",
+                        authentication_id.get_string_repr()
+                    )
+                })?;
 
-        Some(
+            Some(
             hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore {
                 authentication,
                 cavv: None, // marking this as None since we don't need authentication value in payment status flow
             },
         )
-    } else {
-        None
-    };
+        } else {
+            None
+        };
 
     let payment_link_data = payment_intent
         .payment_link_id
@@ -550,6 +553,7 @@ async fn get_tracker_for_sync<
         session_id: None,
         service_details: None,
         card_testing_guard_data: None,
+        velocity_check_data: None,
         vault_operation: None,
         threeds_method_comp_ind: None,
         whole_connector_response: None,
@@ -188,6 +188,7 @@ impl<F: Send + Clone + Sync>
             session_id: request.session_id.clone(),
             service_details: None,
             card_testing_guard_data: None,
+            velocity_check_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
@@ -33,6 +33,7 @@ use external_services::grpc_client::dynamic_routing::{
     success_rate_client::SuccessBasedDynamicRouting, DynamicRoutingError,
 };
 use hyperswitch_domain_models::address::Address;
+use hyperswitch_interfaces::events::routing_api_logs as routing_events;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine};
 use kgraph_utils::{
@@ -55,7 +56,6 @@ use crate::core::admin;
 use crate::core::payouts;
 #[cfg(feature = "v1")]
 use crate::core::routing::transformers::OpenRouterDecideGatewayRequestExt;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::routes::app::SessionStateInfo;
 use crate::{
     core::{
@@ -203,6 +203,17 @@ pub fn make_dsl_input_for_payouts(
             }),
         card_network: None,
     };
+    let payout = dsl_inputs::PayoutDataInput {
+        payout_type: payout_data
+            .payouts
+            .payout_type
+            .map(euclid_enums::PayoutType::foreign_from),
+        destination_country: payout_data
+            .payout_method_data
+            .as_ref()
+            .and_then(|payout_method_data| payout_method_data.get_destination_country())
+            .map(api_enums::Country::from_alpha2),
+    };
     Ok(dsl_inputs::BackendInput {
         mandate,
         metadata,
@@ -211,6 +222,7 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout_data: Some(payout),
     })
 }
 
@@ -326,6 +338,7 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout_data: None,
     })
 }
 
@@ -440,6 +453,7 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout_data: None,
     })
 }
 
@@ -505,6 +519,15 @@ pub async fn perform_static_routing_v1(
             .to_string(),
     };
 
+    let routing_event_request = serde_json::to_value(&backend_input)
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "euclid_routing: failed to serialize routing event request"
+            );
+        })
+        .unwrap_or_default();
+
     // Decision of de-routing is stored
     let de_evaluated_connector = if !state.conf.open_router.static_routing_enabled {
         logger::debug!("decision_engine_euclid: decision_engine routing not enabled");
@@ -514,7 +537,7 @@ pub async fn perform_static_routing_v1(
             state,
             backend_input.clone(),
             business_profile,
-            payment_id,
+            payment_id.clone(),
             get_merchant_fallback_config().await?,
         )
         .await
@@ -525,21 +548,28 @@ pub async fn perform_static_routing_v1(
         .unwrap_or_default()
     };
 
-    let (routable_connectors, routing_approach) = match cached_algorithm.as_ref() {
+    let (routable_connectors, routing_approach, rule_name) = match cached_algorithm.as_ref() {
         CachedAlgorithm::Single(conn) => (
             vec![(**conn).clone()],
             Some(common_enums::RoutingApproach::StraightThroughRouting),
+            None,
         ),
-        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None, None),
         CachedAlgorithm::VolumeSplit(splits) => (
             perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
             Some(common_enums::RoutingApproach::VolumeBasedRouting),
+            None,
         ),
-        CachedAlgorithm::Advanced(interpreter) => (
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
-            Some(common_enums::RoutingApproach::RuleBasedRouting),
-        ),
+        CachedAlgorithm::Advanced(interpreter) => {
+            let (connectors, rule_name) =
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
+            (
+                connectors,
+                Some(common_enums::RoutingApproach::RuleBasedRouting),
+                rule_name,
+            )
+        }
     };
 
     // Results are logged for diff(between legacy and decision_engine's euclid) and have parameters as:
@@ -554,16 +584,42 @@ pub async fn perform_static_routing_v1(
         "evaluate_routing".to_string(),
     );
 
-    Ok((
-        utils::select_routing_result(
-            state,
-            business_profile,
-            routable_connectors,
-            de_evaluated_connector,
-        )
-        .await,
-        routing_approach,
-    ))
+    let routable_connectors = utils::select_routing_result(
+        state,
+        business_profile,
+        routable_connectors,
+        de_evaluated_connector,
+    )
+    .await;
+
+    let mut routing_event = routing_events::RoutingEvent::new(
+        state.tenant.tenant_id.clone(),
+        String::new(),
+        "Euclid: Static Routing",
+        routing_event_request,
+        String::new(),
+        routing_events::ApiMethod::Internal,
+        payment_id,
+        business_profile.get_id().to_owned(),
+        merchant_id.to_owned(),
+        state.request_id,
+        routing_events::RoutingEngine::Euclid,
+    );
+    routing_event.set_routing_algorithm_id(algorithm_id);
+    routing_event.set_routable_connectors(routable_connectors.clone());
+    if let Some(connector) = routable_connectors.first() {
+        routing_event.set_payment_connector(connector.clone());
+    }
+    if let Some(approach) = routing_approach {
+        routing_event.set_routing_approach(approach.to_string());
+    }
+    if let Some(rule_name) = rule_name {
+        routing_event.set_rule_name(rule_name);
+    }
+    routing_event.set_status_code(200);
+    state.event_handler().log_event(&routing_event);
+
+    Ok((routable_connectors, routing_approach))
 }
 
 async fn ensure_algorithm_cached_v1(
@@ -648,16 +704,19 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
+/// Executes the routing DSL, returning the selected connectors along with the name of the rule
+/// that matched, if any. No rule name is returned when the default selection was used.
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::StaticRoutingAlgorithm = interpreter
-        .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
-        .change_context(errors::RoutingError::DslExecutionError)?;
-
-    Ok(match routing_output {
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    let (routing_output, rule_name): (routing_types::StaticRoutingAlgorithm, Option<String>) =
+        interpreter
+            .execute(backend_input)
+            .map(|out| (out.connector_selection.foreign_into(), out.rule_name))
+            .change_context(errors::RoutingError::DslExecutionError)?;
+
+    let connectors = match routing_output {
         routing_types::StaticRoutingAlgorithm::Priority(plist) => plist,
 
         routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits)
@@ -665,7 +724,9 @@ fn execute_dsl_and_get_connector_v1(
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, rule_name))
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -1155,6 +1216,7 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1304,6 +1366,7 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1418,7 +1481,8 @@ async fn perform_session_routing_for_pm_type(
                 execute_dsl_and_get_connector_v1(
                     session_pm_input.backend_input.clone(),
                     interpreter,
-                )?,
+                )?
+                .0,
                 Some(common_enums::RoutingApproach::RuleBasedRouting),
             ),
         }
@@ -1501,10 +1565,13 @@ async fn get_chosen_connectors<'a>(
             CachedAlgorithm::Priority(plist) => plist.clone(),
             CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::Advanced(interpreter) => {
+                execute_dsl_and_get_connector_v1(
+                    session_pm_input.backend_input.clone(),
+                    interpreter,
+                )?
+                .0
+            }
         }
     } else {
         profile_wrapper
@@ -1628,6 +1695,7 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout_data: None,
     };
     Ok(backend_input)
 }
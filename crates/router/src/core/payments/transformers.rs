@@ -177,6 +177,7 @@ where
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -506,6 +507,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -849,6 +851,7 @@ pub async fn construct_payment_router_data_for_capture<'a>(
         authentication_id: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -979,6 +982,7 @@ pub async fn construct_router_data_for_psync<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1174,6 +1178,7 @@ pub async fn construct_payment_router_data_for_sdk_session<'a>(
         authentication_id: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1399,6 +1404,7 @@ pub async fn construct_payment_router_data_for_setup_mandate<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1671,6 +1677,7 @@ where
         psd2_sca_exemption_type: payment_data.payment_intent.psd2_sca_exemption_type,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data,
         minor_amount_capturable: None,
     };
@@ -1867,6 +1874,7 @@ pub async fn construct_payment_router_data_for_update_metadata<'a>(
         psd2_sca_exemption_type: payment_data.payment_intent.psd2_sca_exemption_type,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -3403,6 +3411,9 @@ where
             whole_connector_response: payment_data.get_whole_connector_response(),
             payment_channel: payment_intent.payment_channel,
             enable_partial_authorization: payment_intent.enable_partial_authorization,
+            velocity_check: payment_data
+                .get_velocity_check_data()
+                .and_then(|data| data.get_velocity_check_response()),
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -3700,6 +3711,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             payment_channel: pi.payment_channel,
             network_transaction_id: None,
             enable_partial_authorization: pi.enable_partial_authorization,
+            velocity_check: None,
         }
     }
 }
@@ -1,4 +1,6 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod batch;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -185,6 +187,7 @@ pub async fn make_connector_decision(
                 let config_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -220,6 +223,7 @@ pub async fn make_connector_decision(
                 let config_multiple_connector_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::MultiConnector,
                 )
                 .await;
@@ -238,6 +242,7 @@ pub async fn make_connector_decision(
                 let config_single_connector_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
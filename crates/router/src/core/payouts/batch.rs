@@ -0,0 +1,906 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::{enums as api_enums, payouts as payout_types};
+use common_utils::{
+    encryption::Encryption,
+    errors::ErrorSwitch,
+    ext_traits::{Encode, ValueExt},
+    generate_id,
+    id_type::{self, GenerateId},
+    type_name,
+    types::{keymanager::Identifier, MinorUnit},
+};
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::logger;
+
+use super::{helpers, payouts_create_core};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{domain, storage},
+};
+
+const PAYOUT_BATCH_INVALID_ITEM_ERROR_CODE: &str = "invalid_item";
+
+#[derive(Debug, MultipartForm)]
+pub struct PayoutBatchUploadForm {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+    pub profile_id: Option<Text<id_type::ProfileId>>,
+}
+
+/// Payout batch, as parsed from the JSON request or from the uploaded CSV file
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutBatchCreateData {
+    pub profile_id: Option<id_type::ProfileId>,
+    pub file_name: Option<String>,
+    /// Items of the batch, or the reason for which an item could not be parsed
+    pub items: Vec<Result<payout_types::PayoutCreateRequest, String>>,
+}
+
+impl From<payout_types::PayoutBatchCreateRequest> for PayoutBatchCreateData {
+    fn from(request: payout_types::PayoutBatchCreateRequest) -> Self {
+        Self {
+            profile_id: request.profile_id,
+            file_name: None,
+            items: request.items.into_iter().map(Ok).collect(),
+        }
+    }
+}
+
+/// A row of the result file of a payout batch
+#[derive(Debug, serde::Serialize)]
+struct PayoutBatchResultRecord {
+    line_number: i32,
+    item_id: String,
+    merchant_order_reference_id: Option<String>,
+    status: api_enums::PayoutBatchItemStatus,
+    payout_id: Option<id_type::PayoutId>,
+    payout_status: Option<api_enums::PayoutStatus>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+}
+
+impl From<storage::PayoutBatchItem> for PayoutBatchResultRecord {
+    fn from(item: storage::PayoutBatchItem) -> Self {
+        Self {
+            line_number: item.line_number,
+            item_id: item.item_id,
+            merchant_order_reference_id: item.merchant_order_reference_id,
+            status: item.status,
+            payout_id: item.payout_id,
+            payout_status: item.payout_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        }
+    }
+}
+
+/// Parses the rows of the uploaded file, a row which cannot be parsed is recorded as an invalid item
+pub fn parse_payout_batch_form(form: PayoutBatchUploadForm) -> PayoutBatchCreateData {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(form.file.data.as_ref());
+
+    let items = csv_reader
+        .deserialize::<payout_types::PayoutBatchRecord>()
+        .enumerate()
+        .map(|(index, result)| {
+            result
+                .map_err(|error| {
+                    logger::error!("Error parsing payout batch line {}: {}", index + 1, error);
+                    format!("Failed to parse the row: {error}")
+                })
+                .and_then(payout_types::PayoutCreateRequest::try_from)
+        })
+        .collect::<Vec<_>>();
+
+    logger::info!("Parsed a total of {} payout batch records", items.len());
+
+    PayoutBatchCreateData {
+        profile_id: form.profile_id.map(|profile_id| profile_id.0),
+        file_name: form.file.file_name,
+        items,
+    }
+}
+
+/// Validates an item of the batch and fills in the batch level details in the request
+fn validate_payout_batch_item(
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    payout_ids: &mut HashSet<id_type::PayoutId>,
+    merchant_references: &mut HashSet<String>,
+    mut request: payout_types::PayoutCreateRequest,
+) -> Result<payout_types::PayoutCreateRequest, String> {
+    if request
+        .merchant_id
+        .as_ref()
+        .is_some_and(|request_merchant_id| request_merchant_id != merchant_id)
+    {
+        return Err("merchant_id does not match the merchant account".to_string());
+    }
+    if request
+        .profile_id
+        .as_ref()
+        .is_some_and(|request_profile_id| request_profile_id != profile_id)
+    {
+        return Err("profile_id does not match the profile of the batch".to_string());
+    }
+
+    let amount = request
+        .amount
+        .map(MinorUnit::from)
+        .ok_or_else(|| "Missing required field: amount".to_string())?;
+    if amount <= MinorUnit::new(0) || amount.get_amount_as_i64() > consts::MAX_ALLOWED_AMOUNT {
+        return Err(format!(
+            "amount must be greater than 0 and at most {}",
+            consts::MAX_ALLOWED_AMOUNT
+        ));
+    }
+    if request.currency.is_none() {
+        return Err("Missing required field: currency".to_string());
+    }
+
+    if request.payout_method_data.is_none()
+        && request.payout_token.is_none()
+        && request.payout_method_id.is_none()
+        && !request.payout_link.unwrap_or(false)
+    {
+        return Err(
+            "One of payout_method_data, payout_token or payout_method_id is required".to_string(),
+        );
+    }
+
+    if let Some(merchant_reference) = request.merchant_order_reference_id.clone() {
+        if !merchant_references.insert(merchant_reference) {
+            return Err("Duplicate merchant_order_reference_id in the batch".to_string());
+        }
+    }
+
+    // Payout id is assigned upfront, so that an item which is picked up again after a failure
+    // does not create another payout
+    let payout_id = request
+        .payout_id
+        .get_or_insert_with(id_type::PayoutId::generate)
+        .clone();
+    if !payout_ids.insert(payout_id) {
+        return Err("Duplicate payout_id in the batch".to_string());
+    }
+
+    request.merchant_id = Some(merchant_id.clone());
+    request.profile_id = Some(profile_id.clone());
+    request.confirm = request.confirm.or(Some(true));
+
+    Ok(request)
+}
+
+async fn encrypt_payout_batch_item_request(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    request: &payout_types::PayoutCreateRequest,
+) -> RouterResult<Encryption> {
+    let encoded_request = request
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode payout batch item request")?;
+
+    domain::types::crypto_operation(
+        &state.into(),
+        type_name!(storage::PayoutBatchItem),
+        domain::types::CryptoOperation::Encrypt(Secret::<_, masking::WithType>::new(
+            encoded_request,
+        )),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Encryption::from)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt payout batch item request")
+}
+
+async fn decrypt_payout_batch_item_request(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    request: Option<Encryption>,
+) -> RouterResult<payout_types::PayoutCreateRequest> {
+    domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+        &state.into(),
+        type_name!(storage::PayoutBatchItem),
+        domain::types::CryptoOperation::DecryptOptional(request),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_optionaloperation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt payout batch item request")?
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Payout batch item request not found")?
+    .into_inner()
+    .expose()
+    .parse_value("PayoutCreateRequest")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+async fn add_payout_batch_task_to_pt(
+    db: &dyn StorageInterface,
+    payout_batch: &storage::PayoutBatch,
+) -> common_utils::errors::CustomResult<(), errors::StorageError> {
+    TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "payout_batch")));
+    let tracking_data = payout_types::PayoutBatchPTData {
+        merchant_id: payout_batch.merchant_id.clone(),
+        batch_id: payout_batch.batch_id.clone(),
+    };
+    let runner = common_enums::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let task = "PAYOUT_BATCH";
+    let tag = ["PAYOUTS", "BATCH"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_batch.batch_id,
+        &payout_batch.merchant_id,
+    );
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+async fn get_payout_batch_response(
+    db: &dyn StorageInterface,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<payout_types::PayoutBatchResponse> {
+    let status_with_count = db
+        .get_payout_batch_item_status_with_count(&payout_batch.merchant_id, &payout_batch.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch item counts")?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+    Ok(payout_types::PayoutBatchResponse {
+        batch_id: payout_batch.batch_id,
+        merchant_id: payout_batch.merchant_id,
+        profile_id: payout_batch.profile_id,
+        status: payout_batch.status,
+        file_name: payout_batch.file_name,
+        total_count: payout_batch.total_count,
+        status_with_count,
+        created_at: payout_batch.created_at,
+        modified_at: payout_batch.modified_at,
+    })
+}
+
+async fn find_payout_batch(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout batch {batch_id} not found"),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payout_batch)?;
+    Ok(payout_batch)
+}
+
+pub async fn create_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: PayoutBatchCreateData,
+) -> RouterResponse<payout_types::PayoutBatchResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    if request.items.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Payout batch must contain at least one item".to_string(),
+        }));
+    }
+    if request.items.len() > consts::MAX_PAYOUT_BATCH_SIZE {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout batch can contain at most {} items",
+                consts::MAX_PAYOUT_BATCH_SIZE
+            ),
+        }));
+    }
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        request.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    let batch_id = generate_id(consts::ID_LENGTH, "payout_batch");
+    let now = common_utils::date_time::now();
+    let total_count = i32::try_from(request.items.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let mut payout_ids = HashSet::new();
+    let mut merchant_references = HashSet::new();
+    let mut items = Vec::with_capacity(request.items.len());
+    for (line_number, item) in (1..).zip(request.items) {
+        let merchant_order_reference_id = item
+            .as_ref()
+            .ok()
+            .and_then(|item| item.merchant_order_reference_id.clone());
+        let validated_item = item.and_then(|item| {
+            validate_payout_batch_item(
+                merchant_id,
+                &profile_id,
+                &mut payout_ids,
+                &mut merchant_references,
+                item,
+            )
+        });
+
+        let (status, payout_id, encrypted_request, error_code, error_message) = match validated_item
+        {
+            Ok(item) => (
+                api_enums::PayoutBatchItemStatus::Pending,
+                item.payout_id.clone(),
+                Some(
+                    encrypt_payout_batch_item_request(
+                        &state,
+                        merchant_context.get_merchant_key_store(),
+                        &item,
+                    )
+                    .await?,
+                ),
+                None,
+                None,
+            ),
+            Err(error_message) => (
+                api_enums::PayoutBatchItemStatus::Invalid,
+                None,
+                None,
+                Some(PAYOUT_BATCH_INVALID_ITEM_ERROR_CODE.to_string()),
+                Some(error_message),
+            ),
+        };
+
+        items.push(storage::PayoutBatchItemNew {
+            item_id: generate_id(consts::ID_LENGTH, "payout_batch_item"),
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_id.clone(),
+            line_number,
+            status,
+            request: encrypted_request,
+            merchant_order_reference_id,
+            payout_id,
+            payout_status: None,
+            error_code,
+            error_message,
+            created_at: now,
+            modified_at: now,
+        });
+    }
+
+    let has_pending_items = items
+        .iter()
+        .any(|item| item.status == api_enums::PayoutBatchItemStatus::Pending);
+
+    let payout_batch = db
+        .insert_payout_batch(storage::PayoutBatchNew {
+            batch_id,
+            merchant_id: merchant_id.clone(),
+            profile_id,
+            // A batch without any valid items is not processed further
+            status: if has_pending_items {
+                api_enums::PayoutBatchStatus::Pending
+            } else {
+                api_enums::PayoutBatchStatus::Failed
+            },
+            file_name: request.file_name,
+            total_count,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    let insert_items_and_task = async {
+        for chunk in items.chunks(1000) {
+            db.insert_payout_batch_items(chunk.to_vec())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert payout batch items")?;
+        }
+
+        if has_pending_items {
+            add_payout_batch_task_to_pt(db, &payout_batch)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding payout batch task to process tracker")?;
+        }
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(())
+    };
+
+    // A batch whose items or task could not be stored would never be processed, so it is failed
+    // instead of being left pending
+    let insert_result = insert_items_and_task.await;
+    if let Err(error) = insert_result {
+        db.update_payout_batch_status(payout_batch, api_enums::PayoutBatchStatus::Failed)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to mark the payout batch as failed"))
+            .ok();
+        return Err(error);
+    }
+
+    get_payout_batch_response(db, payout_batch)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn retrieve_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: payout_types::PayoutBatchRetrieveRequest,
+) -> RouterResponse<payout_types::PayoutBatchResponse> {
+    let db = state.store.as_ref();
+    let payout_batch = find_payout_batch(
+        db,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &request.batch_id,
+    )
+    .await?;
+
+    get_payout_batch_response(db, payout_batch)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn list_payout_batch_items(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: payout_types::PayoutBatchItemListRequest,
+) -> RouterResponse<payout_types::PayoutBatchItemListResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let payout_batch = find_payout_batch(db, merchant_id, profile_id, &request.batch_id).await?;
+
+    let limit = request
+        .constraints
+        .limit
+        .min(consts::PAYOUTS_LIST_MAX_LIMIT_GET);
+    let data = db
+        .filter_payout_batch_items(
+            merchant_id,
+            &payout_batch.batch_id,
+            request.constraints.status,
+            Some(i64::from(limit)),
+            request.constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batch items")?
+        .into_iter()
+        .map(|item| payout_types::PayoutBatchItemResponse {
+            item_id: item.item_id,
+            line_number: item.line_number,
+            status: item.status,
+            merchant_order_reference_id: item.merchant_order_reference_id,
+            payout_id: item.payout_id,
+            payout_status: item.payout_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+            modified_at: item.modified_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payout_types::PayoutBatchItemListResponse {
+            batch_id: payout_batch.batch_id,
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Generates a CSV file with the result of each item of the batch
+pub async fn get_payout_batch_results(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: payout_types::PayoutBatchRetrieveRequest,
+) -> RouterResponse<serde_json::Value> {
+    const RESULTS_PAGE_SIZE: i64 = 1000;
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let payout_batch = find_payout_batch(db, merchant_id, profile_id, &request.batch_id).await?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    let mut offset = 0;
+    loop {
+        let items = db
+            .filter_payout_batch_items(
+                merchant_id,
+                &payout_batch.batch_id,
+                None,
+                Some(RESULTS_PAGE_SIZE),
+                Some(offset),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list payout batch items")?;
+        let fetched_count = i64::try_from(items.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        for item in items {
+            csv_writer
+                .serialize(PayoutBatchResultRecord::from(item))
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write payout batch result record")?;
+        }
+
+        if fetched_count < RESULTS_PAGE_SIZE {
+            break;
+        }
+        offset += fetched_count;
+    }
+
+    let file_data = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate payout batch result file")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        file_data,
+        mime::TEXT_CSV,
+    )))
+}
+
+/// Builds the result of an item for which the payout was created
+fn get_item_result_from_payout_status(
+    payout_id: Option<id_type::PayoutId>,
+    payout_status: api_enums::PayoutStatus,
+    error_code: Option<String>,
+    error_message: Option<String>,
+) -> storage::PayoutBatchItemUpdate {
+    let status = if helpers::is_payout_err_state(payout_status) {
+        api_enums::PayoutBatchItemStatus::Failed
+    } else {
+        api_enums::PayoutBatchItemStatus::Succeeded
+    };
+
+    storage::PayoutBatchItemUpdate::ResultUpdate {
+        status,
+        payout_id,
+        payout_status: Some(payout_status),
+        error_code,
+        error_message,
+    }
+}
+
+/// Builds the result of an item for which the payout could not be created
+fn get_failed_item_result(
+    error: &error_stack::Report<errors::ApiErrorResponse>,
+) -> storage::PayoutBatchItemUpdate {
+    let error_response =
+        api_models::errors::types::ErrorResponse::from(&error.current_context().switch());
+    storage::PayoutBatchItemUpdate::ResultUpdate {
+        status: api_enums::PayoutBatchItemStatus::Failed,
+        payout_id: None,
+        payout_status: None,
+        error_code: Some(error_response.code),
+        error_message: Some(error_response.message),
+    }
+}
+
+async fn get_payout_batch_item_result(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    item: &storage::PayoutBatchItem,
+) -> RouterResult<storage::PayoutBatchItemUpdate> {
+    let db = state.store.as_ref();
+    let request = decrypt_payout_batch_item_request(
+        state,
+        merchant_context.get_merchant_key_store(),
+        item.request.clone(),
+    )
+    .await?;
+
+    let item_update = match Box::pin(payouts_create_core(
+        state.clone(),
+        merchant_context.clone(),
+        request,
+    ))
+    .await
+    {
+        Ok(services::ApplicationResponse::Json(payout_response)) => {
+            get_item_result_from_payout_status(
+                Some(payout_response.payout_id),
+                payout_response.status,
+                payout_response.error_code,
+                payout_response.error_message,
+            )
+        }
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payouts create")?,
+        // The payout was created in an earlier run, which failed before the item was updated
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayout { .. }
+            ) && item.payout_id.is_some() =>
+        {
+            let payout_id = item
+                .payout_id
+                .clone()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)?;
+            let payout = db
+                .find_payout_by_merchant_id_payout_id(
+                    &item.merchant_id,
+                    &payout_id,
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+            get_item_result_from_payout_status(Some(payout_id), payout.status, None, None)
+        }
+        Err(error) => {
+            logger::info!(item_id = %item.item_id, ?error, "Failed to create payout for batch item");
+            get_failed_item_result(&error)
+        }
+    };
+
+    Ok(item_update)
+}
+
+async fn process_payout_batch_item(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    item: storage::PayoutBatchItem,
+) -> RouterResult<()> {
+    // An error while processing the item is recorded as its result, so that the item is not
+    // picked up again and the other items of the batch are still processed
+    let item_update = get_payout_batch_item_result(state, merchant_context, &item)
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(item_id = %item.item_id, ?error, "Failed to process payout batch item");
+            get_failed_item_result(&error)
+        });
+
+    state
+        .store
+        .update_payout_batch_item(item, item_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch item")?;
+
+    Ok(())
+}
+
+fn get_payout_batch_final_status(
+    status_with_count: &[(api_enums::PayoutBatchItemStatus, i64)],
+) -> api_enums::PayoutBatchStatus {
+    let (succeeded, unsuccessful) =
+        status_with_count
+            .iter()
+            .fold(
+                (0, 0),
+                |(succeeded, unsuccessful), (status, count)| match status {
+                    api_enums::PayoutBatchItemStatus::Succeeded => {
+                        (succeeded + count, unsuccessful)
+                    }
+                    api_enums::PayoutBatchItemStatus::Failed
+                    | api_enums::PayoutBatchItemStatus::Invalid => {
+                        (succeeded, unsuccessful + count)
+                    }
+                    api_enums::PayoutBatchItemStatus::Pending => (succeeded, unsuccessful),
+                },
+            );
+
+    match (succeeded, unsuccessful) {
+        (_, 0) => api_enums::PayoutBatchStatus::Completed,
+        (0, _) => api_enums::PayoutBatchStatus::Failed,
+        _ => api_enums::PayoutBatchStatus::PartiallyCompleted,
+    }
+}
+
+/// Processes the next set of pending items of the batch.
+/// Returns `true` once all the items are processed and the final status of the batch is set.
+pub async fn process_payout_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<bool> {
+    let db = state.store.as_ref();
+
+    let payout_batch = match payout_batch.status {
+        api_enums::PayoutBatchStatus::Pending => db
+            .update_payout_batch_status(payout_batch, api_enums::PayoutBatchStatus::Processing)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payout batch status")?,
+        api_enums::PayoutBatchStatus::Processing => payout_batch,
+        api_enums::PayoutBatchStatus::Completed
+        | api_enums::PayoutBatchStatus::PartiallyCompleted
+        | api_enums::PayoutBatchStatus::Failed => return Ok(true),
+    };
+
+    let pending_items = db
+        .filter_payout_batch_items(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            Some(api_enums::PayoutBatchItemStatus::Pending),
+            Some(i64::from(consts::PAYOUT_BATCH_ITEMS_PER_RUN)),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch pending payout batch items")?;
+
+    if pending_items.is_empty() {
+        let status_with_count = db
+            .get_payout_batch_item_status_with_count(
+                &payout_batch.merchant_id,
+                &payout_batch.batch_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payout batch item counts")?;
+        let status = get_payout_batch_final_status(&status_with_count);
+
+        logger::info!(batch_id = %payout_batch.batch_id, %status, "Payout batch processed");
+        db.update_payout_batch_status(payout_batch, status)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payout batch status")?;
+        return Ok(true);
+    }
+
+    let results = futures::stream::iter(pending_items)
+        .map(|item| process_payout_batch_item(state, merchant_context, item))
+        .buffer_unordered(consts::PAYOUT_BATCH_CONCURRENCY_LIMIT)
+        .collect::<Vec<_>>()
+        .await;
+
+    // Items whose result could not be stored are left pending for the next run. The run fails
+    // only when none of the items could be processed, so that the task is retried later.
+    let processed_count = results.iter().filter(|result| result.is_ok()).count();
+    for error in results.into_iter().filter_map(Result::err) {
+        if processed_count == 0 {
+            return Err(error);
+        }
+        logger::error!(?error, "Failed to store the result of a payout batch item");
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_valid_request() -> payout_types::PayoutCreateRequest {
+        payout_types::PayoutCreateRequest {
+            amount: Some(MinorUnit::new(1000).into()),
+            currency: Some(api_enums::Currency::EUR),
+            payout_token: Some("token".to_string()),
+            merchant_order_reference_id: Some("ref_1".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_payout_batch_item() {
+        let merchant_id = id_type::MerchantId::default();
+        let profile_id =
+            id_type::ProfileId::try_from(std::borrow::Cow::from("pro_batch_test")).unwrap();
+        let mut payout_ids = HashSet::new();
+        let mut merchant_references = HashSet::new();
+
+        let request = validate_payout_batch_item(
+            &merchant_id,
+            &profile_id,
+            &mut payout_ids,
+            &mut merchant_references,
+            get_valid_request(),
+        )
+        .unwrap();
+        assert!(request.payout_id.is_some());
+        assert_eq!(request.profile_id, Some(profile_id.clone()));
+        assert_eq!(request.confirm, Some(true));
+
+        // Same merchant reference as the earlier item
+        assert!(validate_payout_batch_item(
+            &merchant_id,
+            &profile_id,
+            &mut payout_ids,
+            &mut merchant_references,
+            get_valid_request(),
+        )
+        .is_err());
+
+        let request_without_amount = payout_types::PayoutCreateRequest {
+            amount: None,
+            merchant_order_reference_id: None,
+            ..get_valid_request()
+        };
+        assert!(validate_payout_batch_item(
+            &merchant_id,
+            &profile_id,
+            &mut payout_ids,
+            &mut merchant_references,
+            request_without_amount,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_payout_batch_final_status() {
+        use api_enums::{PayoutBatchItemStatus as ItemStatus, PayoutBatchStatus as Status};
+
+        assert_eq!(
+            get_payout_batch_final_status(&[(ItemStatus::Succeeded, 3)]),
+            Status::Completed
+        );
+        assert_eq!(
+            get_payout_batch_final_status(&[(ItemStatus::Succeeded, 3), (ItemStatus::Invalid, 1)]),
+            Status::PartiallyCompleted
+        );
+        assert_eq!(
+            get_payout_batch_final_status(&[(ItemStatus::Failed, 2), (ItemStatus::Invalid, 1)]),
+            Status::Failed
+        );
+    }
+
+    #[test]
+    fn test_payout_batch_record_conversion() {
+        let data = "amount,currency,customer_id,iban,bic\n1000,EUR,cus_1,DE89370400440532013000,COBADEFFXXX\n0,EUR,cus_2,,\n";
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(data.as_bytes());
+        let records = csv_reader
+            .deserialize::<payout_types::PayoutBatchRecord>()
+            .map(|record| payout_types::PayoutCreateRequest::try_from(record.unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 2);
+        assert!(matches!(
+            records[0].payout_method_data,
+            Some(payout_types::PayoutMethodData::Bank(
+                payout_types::Bank::Sepa(_)
+            ))
+        ));
+        assert_eq!(records[0].payout_type, Some(api_enums::PayoutType::Bank));
+        assert!(records[1].payout_method_data.is_none());
+    }
+}
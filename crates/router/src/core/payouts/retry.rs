@@ -39,13 +39,17 @@ pub async fn do_gsm_multiple_connector_actions(
 
         match get_gsm_decision(gsm) {
             common_enums::GsmDecision::Retry => {
-                retries = get_retries(
-                    state,
-                    retries,
-                    merchant_context.get_merchant_account().get_id(),
-                    PayoutRetryType::MultiConnector,
-                )
-                .await;
+                retries = get_multiple_connector_retries(
+                    get_retries(
+                        state,
+                        retries,
+                        merchant_context.get_merchant_account().get_id(),
+                        &payout_data.business_profile,
+                        PayoutRetryType::MultiConnector,
+                    )
+                    .await,
+                    connectors_routing_data.len(),
+                );
 
                 if retries.is_none() || retries == Some(0) {
                     metrics::AUTO_PAYOUT_RETRY_EXHAUSTED_COUNT.add(1, &[]);
@@ -106,6 +110,7 @@ pub async fn do_gsm_single_connector_actions(
                     state,
                     retries,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -137,6 +142,7 @@ pub async fn get_retries(
     state: &app::SessionState,
     retries: Option<i32>,
     merchant_id: &common_utils::id_type::MerchantId,
+    profile: &domain::Profile,
     retry_type: PayoutRetryType,
 ) -> Option<i32> {
     match retries {
@@ -159,10 +165,19 @@ pub async fn get_retries(
                     None::<i32>
                 })
                 .ok()
+                .or(profile.max_payout_auto_retries_enabled.map(i32::from))
         }
     }
 }
 
+/// Without an explicit limit, the payout cascades through all the remaining eligible connectors
+fn get_multiple_connector_retries(
+    retries: Option<i32>,
+    remaining_connectors: usize,
+) -> Option<i32> {
+    retries.or_else(|| i32::try_from(remaining_connectors).ok())
+}
+
 #[instrument(skip_all)]
 pub async fn get_gsm(
     state: &app::SessionState,
@@ -272,8 +287,8 @@ pub async fn modify_trackers(
         error_code: None,
         created_at: common_utils::date_time::now(),
         last_modified_at: common_utils::date_time::now(),
-        merchant_connector_id: None,
-        routing_info: None,
+        merchant_connector_id: connector.merchant_connector_id.clone(),
+        routing_info: payout_data.payout_attempt.routing_info.clone(),
         unified_code: None,
         unified_message: None,
         additional_payout_method_data: payout_data
@@ -299,19 +314,21 @@ pub async fn modify_trackers(
 pub async fn config_should_call_gsm_payout(
     db: &dyn StorageInterface,
     merchant_id: &common_utils::id_type::MerchantId,
+    profile: &domain::Profile,
     retry_type: PayoutRetryType,
 ) -> bool {
     let key = merchant_id.get_should_call_gsm_payout_key(retry_type);
     let config = db
         .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
         .await;
-    match config {
+    let merchant_config_gsm = match config {
         Ok(conf) => conf.config == "true",
         Err(error) => {
             logger::error!(?error);
             false
         }
-    }
+    };
+    merchant_config_gsm || profile.is_payout_auto_retries_enabled
 }
 
 pub trait GsmValidation {
@@ -339,3 +356,61 @@ impl GsmValidation for PayoutData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_gsm_with_decision(
+        decision: common_enums::GsmDecision,
+    ) -> hyperswitch_domain_models::gsm::GatewayStatusMap {
+        hyperswitch_domain_models::gsm::GatewayStatusMap {
+            connector: "adyen".to_string(),
+            flow: "PoFulfill".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: "insufficient_funds".to_string(),
+            message: "Insufficient funds".to_string(),
+            status: "failed".to_string(),
+            router_error: None,
+            unified_code: None,
+            unified_message: None,
+            error_category: None,
+            feature_data: common_types::domain::GsmFeatureData::Retry(
+                common_types::domain::RetryFeatureData {
+                    step_up_possible: false,
+                    clear_pan_possible: false,
+                    alternate_network_possible: false,
+                    decision,
+                },
+            ),
+            feature: common_enums::GsmFeature::Retry,
+        }
+    }
+
+    #[test]
+    fn test_get_gsm_decision() {
+        assert_eq!(get_gsm_decision(None), common_enums::GsmDecision::DoDefault);
+        assert_eq!(
+            get_gsm_decision(Some(get_gsm_with_decision(
+                common_enums::GsmDecision::Retry
+            ))),
+            common_enums::GsmDecision::Retry
+        );
+        assert_eq!(
+            get_gsm_decision(Some(get_gsm_with_decision(
+                common_enums::GsmDecision::DoDefault
+            ))),
+            common_enums::GsmDecision::DoDefault
+        );
+    }
+
+    #[test]
+    fn test_get_multiple_connector_retries() {
+        // An explicit limit is kept even when more connectors are eligible
+        assert_eq!(get_multiple_connector_retries(Some(1), 3), Some(1));
+        assert_eq!(get_multiple_connector_retries(Some(0), 3), Some(0));
+        // Without a limit, every remaining connector is tried once
+        assert_eq!(get_multiple_connector_retries(None, 3), Some(3));
+        assert_eq!(get_multiple_connector_retries(None, 0), Some(0));
+    }
+}
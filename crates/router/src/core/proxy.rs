@@ -1,5 +1,5 @@
 use super::errors::{self, RouterResponse, RouterResult};
-use crate::{logger, routes::SessionState, services, types::domain};
+use crate::{core::tokenization, logger, routes::SessionState, services, types::domain};
 pub mod utils;
 use api_models::proxy as proxy_api_models;
 use common_utils::{
@@ -13,9 +13,12 @@ use serde_json::Value;
 pub async fn proxy_core(
     state: SessionState,
     merchant_context: domain::MerchantContext,
+    profile: domain::Profile,
     req: proxy_api_models::ProxyRequest,
 ) -> RouterResponse<proxy_api_models::ProxyResponse> {
     let req_wrapper = utils::ProxyRequestWrapper(req.clone());
+    req_wrapper.validate_destination_host(&profile)?;
+
     let proxy_record = req_wrapper
         .get_proxy_record(
             &state,
@@ -25,39 +28,211 @@ pub async fn proxy_core(
         .await?;
 
     let vault_data = proxy_record
-        .get_vault_data(&state, merchant_context)
+        .get_vault_data(&state, merchant_context.clone())
         .await?;
 
-    let processed_body =
-        interpolate_token_references_with_vault_data(req.request_body.clone(), &vault_data)?;
+    let mut vault_data_sources = vec![VaultDataSource {
+        alias: req.token_alias.clone(),
+        data: vault_data,
+    }];
+
+    for additional_token in &req.additional_tokens {
+        let record = utils::get_proxy_record(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+            &additional_token.token,
+            &additional_token.token_type,
+        )
+        .await?;
+
+        vault_data_sources.push(VaultDataSource {
+            alias: additional_token.alias.clone(),
+            data: record
+                .get_vault_data(&state, merchant_context.clone())
+                .await?,
+        });
+    }
+
+    let processed_body = build_proxy_request_body(
+        req.request_body.clone(),
+        &vault_data_sources,
+        req_wrapper.get_content_type(),
+    )?;
 
     let res = execute_proxy_request(&state, &req_wrapper, processed_body).await?;
 
-    let proxy_response = proxy_api_models::ProxyResponse::try_from(ProxyResponseWrapper(res))?;
+    let mut proxy_response = proxy_api_models::ProxyResponse::try_from(ProxyResponseWrapper(res))?;
+
+    if let Some(response_tokenization) = req.response_tokenization.as_ref() {
+        tokenize_response_fields(
+            &state,
+            &merchant_context,
+            &proxy_record.get_customer_id(),
+            response_tokenization,
+            &mut proxy_response,
+        )
+        .await?;
+    }
 
     Ok(services::ApplicationResponse::Json(proxy_response))
 }
 
+/// The vault data of a token, along with the alias by which the request body refers to it
+struct VaultDataSource {
+    alias: Option<String>,
+    data: Value,
+}
+
+enum ProxyRequestBody {
+    Json(Value),
+    FormUrlEncoded(Vec<(String, String)>),
+    Raw {
+        body: String,
+        content_type: &'static str,
+    },
+}
+
+fn build_proxy_request_body(
+    request_body: Value,
+    vault_data_sources: &[VaultDataSource],
+    content_type: proxy_api_models::ProxyContentType,
+) -> RouterResult<ProxyRequestBody> {
+    match (content_type, request_body) {
+        (proxy_api_models::ProxyContentType::Json, request_body) => Ok(ProxyRequestBody::Json(
+            interpolate_token_references_with_vault_data(request_body, vault_data_sources)?,
+        )),
+        (proxy_api_models::ProxyContentType::FormUrlEncoded, Value::Object(obj)) => obj
+            .into_iter()
+            .map(|(key, val)| {
+                interpolate_token_references_with_vault_data(val, vault_data_sources)
+                    .map(|processed| (key, value_to_string(processed)))
+            })
+            .collect::<RouterResult<Vec<_>>>()
+            .map(ProxyRequestBody::FormUrlEncoded),
+        (proxy_api_models::ProxyContentType::FormUrlEncoded, Value::String(template)) => {
+            Ok(ProxyRequestBody::Raw {
+                body: interpolate_string_template(&template, vault_data_sources, |value| {
+                    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+                })?,
+                content_type: "application/x-www-form-urlencoded",
+            })
+        }
+        (proxy_api_models::ProxyContentType::Xml, Value::String(template)) => {
+            Ok(ProxyRequestBody::Raw {
+                body: interpolate_string_template(&template, vault_data_sources, escape_xml)?,
+                content_type: "application/xml",
+            })
+        }
+        (proxy_api_models::ProxyContentType::FormUrlEncoded, _) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "request_body must be an object or a string for form_url_encoded content"
+                    .to_string(),
+            }
+            .into())
+        }
+        (proxy_api_models::ProxyContentType::Xml, _) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "request_body must be a string for xml content".to_string(),
+            }
+            .into())
+        }
+    }
+}
+
 fn interpolate_token_references_with_vault_data(
     value: Value,
-    vault_data: &Value,
+    vault_data_sources: &[VaultDataSource],
 ) -> RouterResult<Value> {
     match value {
         Value::Object(obj) => {
             let new_obj = obj
                 .into_iter()
-                .map(|(key, val)| interpolate_token_references_with_vault_data(val, vault_data).map(|processed| (key, processed)))
+                .map(|(key, val)| interpolate_token_references_with_vault_data(val, vault_data_sources).map(|processed| (key, processed)))
                 .collect::<Result<serde_json::Map<_, _>, error_stack::Report<errors::ApiErrorResponse>>>()?;
 
             Ok(Value::Object(new_obj))
         }
-        Value::String(s) => utils::parse_token(&s)
-            .map(|(_, token_ref)| extract_field_from_vault_data(vault_data, &token_ref.field))
-            .unwrap_or(Ok(Value::String(s.clone()))),
+        Value::Array(arr) => arr
+            .into_iter()
+            .map(|val| interpolate_token_references_with_vault_data(val, vault_data_sources))
+            .collect::<RouterResult<Vec<_>>>()
+            .map(Value::Array),
+        Value::String(s) => {
+            match utils::parse_template(&s).as_slice() {
+                // A string which is only a token reference takes the value of the field as is
+                [utils::TemplateSegment::Token(token_ref)] => {
+                    extract_field_from_vault_data_sources(vault_data_sources, token_ref)
+                }
+                [] | [utils::TemplateSegment::Literal(_)] => Ok(Value::String(s.clone())),
+                _ => interpolate_string_template(&s, vault_data_sources, |value| value.to_string())
+                    .map(Value::String),
+            }
+        }
         _ => Ok(value),
     }
 }
 
+/// Replaces every token reference in the template with the value of its field, escaped for the
+/// format of the template
+fn interpolate_string_template(
+    template: &str,
+    vault_data_sources: &[VaultDataSource],
+    escape: impl Fn(&str) -> String,
+) -> RouterResult<String> {
+    utils::parse_template(template)
+        .into_iter()
+        .map(|segment| match segment {
+            utils::TemplateSegment::Literal(literal) => Ok(literal.to_string()),
+            utils::TemplateSegment::Token(token_ref) => {
+                extract_field_from_vault_data_sources(vault_data_sources, &token_ref)
+                    .map(|value| escape(&value_to_string(value)))
+            }
+        })
+        .collect()
+}
+
+fn value_to_string(value: Value) -> String {
+    match value {
+        Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn extract_field_from_vault_data_sources(
+    vault_data_sources: &[VaultDataSource],
+    token_ref: &utils::TokenReference,
+) -> RouterResult<Value> {
+    match token_ref.alias.as_deref() {
+        Some(alias) => {
+            let source = vault_data_sources
+                .iter()
+                .find(|source| source.alias.as_deref() == Some(alias))
+                .ok_or_else(|| errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("No token found with alias '{alias}'"),
+                })?;
+            extract_field_from_vault_data(&source.data, &token_ref.field)
+        }
+        None => vault_data_sources
+            .iter()
+            .find_map(|source| match &source.data {
+                Value::Object(obj) => find_field_recursively_in_vault_data(obj, &token_ref.field),
+                _ => None,
+            })
+            .ok_or_else(|| errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(format!("Field '{}' not found", token_ref.field)),
+    }
+}
+
 fn find_field_recursively_in_vault_data(
     obj: &serde_json::Map<String, Value>,
     field_name: &str,
@@ -85,18 +260,79 @@ fn extract_field_from_vault_data(vault_data: &Value, field_name: &str) -> Router
     }
 }
 
+/// Stores each of the requested response fields in the vault and replaces it by its token
+async fn tokenize_response_fields(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &common_utils::id_type::GlobalCustomerId,
+    response_tokenization: &proxy_api_models::ProxyResponseTokenization,
+    proxy_response: &mut proxy_api_models::ProxyResponse,
+) -> RouterResult<()> {
+    for field in &response_tokenization.fields {
+        let Some(field_value) = proxy_response.response.pointer_mut(field) else {
+            logger::debug!("Field {field} not present in the proxy response");
+            continue;
+        };
+
+        // The value is stored under the last segment of the pointer, so that the token can be
+        // referenced as `{{$segment}}` in later proxy requests
+        let field_name = field
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .replace("~1", "/")
+            .replace("~0", "~");
+        let token_request = masking::Secret::new(serde_json::json!({
+            field_name: field_value.take()
+        }));
+
+        let tokenization = tokenization::create_vault_token(
+            state,
+            merchant_context.get_merchant_account(),
+            merchant_context.get_merchant_key_store(),
+            customer_id,
+            token_request,
+        )
+        .await?;
+
+        let token = tokenization.id.get_string_repr().to_string();
+        *field_value = Value::String(token.clone());
+        proxy_response
+            .tokenized_fields
+            .push(proxy_api_models::ProxyTokenizedField {
+                field: field.clone(),
+                token,
+            });
+    }
+
+    Ok(())
+}
+
 async fn execute_proxy_request(
     state: &SessionState,
     req_wrapper: &utils::ProxyRequestWrapper,
-    processed_body: Value,
+    processed_body: ProxyRequestBody,
 ) -> RouterResult<Response> {
-    let request = RequestBuilder::new()
+    let mut request_builder = RequestBuilder::new()
         .method(req_wrapper.get_method())
         .attach_default_headers()
         .headers(req_wrapper.get_headers())
-        .url(req_wrapper.get_destination_url())
-        .set_body(request::RequestContent::Json(Box::new(processed_body)))
-        .build();
+        .url(req_wrapper.get_destination_url());
+
+    let request_content = match processed_body {
+        ProxyRequestBody::Json(body) => request::RequestContent::Json(Box::new(body)),
+        ProxyRequestBody::FormUrlEncoded(body) => {
+            request::RequestContent::FormUrlEncoded(Box::new(body))
+        }
+        ProxyRequestBody::Raw { body, content_type } => {
+            if !req_wrapper.has_header("Content-Type") {
+                request_builder = request_builder.header("Content-Type", content_type);
+            }
+            request::RequestContent::RawBytes(body.into_bytes())
+        }
+    };
+
+    let request = request_builder.set_body(request_content).build();
 
     let response = services::call_connector_api(state, request, "proxy")
         .await
@@ -122,11 +358,12 @@ impl TryFrom<ProxyResponseWrapper> for proxy_api_models::ProxyResponse {
 
     fn try_from(wrapper: ProxyResponseWrapper) -> Result<Self, Self::Error> {
         let res = wrapper.0;
+        // Destinations which do not respond with JSON have their response returned as a string
         let response_body: Value = res
             .response
+            .clone()
             .parse_struct("ProxyResponse")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to parse the response")?;
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&res.response).into_owned()));
 
         let status_code = res.status_code;
         let response_headers = proxy_api_models::Headers::from_header_map(res.headers.as_ref());
@@ -135,6 +372,7 @@ impl TryFrom<ProxyResponseWrapper> for proxy_api_models::ProxyResponse {
             response: response_body,
             status_code,
             response_headers,
+            tokenized_fields: Vec::new(),
         })
     }
 }
@@ -36,47 +36,14 @@ impl ProxyRequestWrapper {
         key_store: &domain::MerchantKeyStore,
         storage_scheme: common_enums::enums::MerchantStorageScheme,
     ) -> RouterResult<ProxyRecord> {
-        let token = &self.0.token;
-
-        match self.0.token_type {
-            proxy_api_models::TokenType::PaymentMethodId => {
-                let pm_id = PaymentMethodId {
-                    payment_method_id: token.clone(),
-                };
-                let pm_id =
-                    id_type::GlobalPaymentMethodId::generate_from_string(pm_id.payment_method_id)
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Unable to generate GlobalPaymentMethodId")?;
-
-                let payment_method_record = state
-                    .store
-                    .find_payment_method(&((state).into()), key_store, &pm_id, storage_scheme)
-                    .await
-                    .change_context(errors::ApiErrorResponse::PaymentMethodNotFound)?;
-                Ok(ProxyRecord::PaymentMethodRecord(Box::new(
-                    payment_method_record,
-                )))
-            }
-            proxy_api_models::TokenType::TokenizationId => {
-                let token_id = id_type::GlobalTokenId::from_string(token.clone().as_str())
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable(
-                        "Error while coneverting from string to GlobalTokenId type",
-                    )?;
-                let db = state.store.as_ref();
-                let key_manager_state = &(state).into();
-
-                let tokenization_record = db
-                    .get_entity_id_vault_id_by_token_id(&token_id, key_store, key_manager_state)
-                    .await
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Error while fetching tokenization record from vault")?;
-
-                Ok(ProxyRecord::TokenizationRecord(Box::new(
-                    tokenization_record,
-                )))
-            }
-        }
+        get_proxy_record(
+            state,
+            key_store,
+            storage_scheme,
+            &self.0.token,
+            &self.0.token_type,
+        )
+        .await
     }
 
     pub fn get_headers(&self) -> Vec<(String, masking::Maskable<String>)> {
@@ -88,6 +55,14 @@ impl ProxyRequestWrapper {
             .collect()
     }
 
+    pub fn has_header(&self, header_name: &str) -> bool {
+        self.0
+            .headers
+            .as_map()
+            .keys()
+            .any(|key| key.eq_ignore_ascii_case(header_name))
+    }
+
     pub fn get_destination_url(&self) -> &str {
         self.0.destination_url.as_str()
     }
@@ -95,6 +70,95 @@ impl ProxyRequestWrapper {
     pub fn get_method(&self) -> common_utils::request::Method {
         self.0.method
     }
+
+    pub fn get_content_type(&self) -> proxy_api_models::ProxyContentType {
+        self.0.content_type
+    }
+
+    /// Checks that the destination of the request is one of the hosts allowed for the profile
+    pub fn validate_destination_host(&self, profile: &domain::Profile) -> RouterResult<()> {
+        let Some(allowed_hosts) = profile.allowed_proxy_destination_hosts.as_ref() else {
+            return Ok(());
+        };
+
+        let host = self.0.destination_url.host_str().ok_or(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "destination_url",
+            },
+        )?;
+
+        if allowed_hosts
+            .iter()
+            .any(|allowed_host| is_host_allowed(allowed_host, host))
+        {
+            Ok(())
+        } else {
+            Err(errors::ApiErrorResponse::AccessForbidden {
+                resource: host.to_string(),
+            })
+            .attach_printable("Destination host is not in the allowed proxy destination hosts")
+        }
+    }
+}
+
+/// Matches a host against an allowed host, which can be a wildcard like `*.example.com` that
+/// matches any subdomain of `example.com`
+fn is_host_allowed(allowed_host: &str, host: &str) -> bool {
+    let allowed_host = allowed_host.trim().to_ascii_lowercase();
+    let host = host.to_ascii_lowercase();
+
+    match allowed_host.strip_prefix("*.") {
+        Some(domain) => host
+            .strip_suffix(domain)
+            .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+        None => host == allowed_host,
+    }
+}
+
+pub async fn get_proxy_record(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: common_enums::enums::MerchantStorageScheme,
+    token: &str,
+    token_type: &proxy_api_models::TokenType,
+) -> RouterResult<ProxyRecord> {
+    match token_type {
+        proxy_api_models::TokenType::PaymentMethodId => {
+            let pm_id = PaymentMethodId {
+                payment_method_id: token.to_string(),
+            };
+            let pm_id =
+                id_type::GlobalPaymentMethodId::generate_from_string(pm_id.payment_method_id)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to generate GlobalPaymentMethodId")?;
+
+            let payment_method_record = state
+                .store
+                .find_payment_method(&((state).into()), key_store, &pm_id, storage_scheme)
+                .await
+                .change_context(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+            Ok(ProxyRecord::PaymentMethodRecord(Box::new(
+                payment_method_record,
+            )))
+        }
+        proxy_api_models::TokenType::TokenizationId => {
+            let token_id = id_type::GlobalTokenId::from_string(token)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error while coneverting from string to GlobalTokenId type")?;
+            let db = state.store.as_ref();
+            let key_manager_state = &(state).into();
+
+            let tokenization_record = db
+                .get_entity_id_vault_id_by_token_id(&token_id, key_store, key_manager_state)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error while fetching tokenization record from vault")?;
+
+            Ok(ProxyRecord::TokenizationRecord(Box::new(
+                tokenization_record,
+            )))
+        }
+    }
 }
 
 impl ProxyRecord {
@@ -112,7 +176,7 @@ impl ProxyRecord {
         }
     }
 
-    fn get_customer_id(&self) -> id_type::GlobalCustomerId {
+    pub fn get_customer_id(&self) -> id_type::GlobalCustomerId {
         match self {
             Self::PaymentMethodRecord(payment_method) => payment_method.customer_id.clone(),
             Self::TokenizationRecord(tokenization_record) => {
@@ -163,18 +227,21 @@ impl ProxyRecord {
 
 #[derive(Debug)]
 pub struct TokenReference {
+    /// The alias of the token whose data is referenced, if any
+    pub alias: Option<String>,
     pub field: String,
 }
 
+/// Parses a token reference of the form `{{$field}}` or `{{$alias.field}}`
 pub fn parse_token(input: &str) -> IResult<&str, TokenReference> {
-    let (input, field) = delimited(
+    let (input, reference) = delimited(
         tag("{{"),
         preceded(
             multispace0,
             preceded(
                 char('$'),
                 terminated(
-                    take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+                    take_while1(|c: char| c.is_alphanumeric() || c == '_' || c == '.'),
                     multispace0,
                 ),
             ),
@@ -182,12 +249,57 @@ pub fn parse_token(input: &str) -> IResult<&str, TokenReference> {
         tag("}}"),
     )(input)?;
 
-    Ok((
-        input,
-        TokenReference {
+    let token_ref = match reference.split_once('.') {
+        Some((alias, field)) => TokenReference {
+            alias: Some(alias.to_string()),
             field: field.to_string(),
         },
-    ))
+        None => TokenReference {
+            alias: None,
+            field: reference.to_string(),
+        },
+    };
+
+    Ok((input, token_ref))
+}
+
+#[derive(Debug)]
+pub enum TemplateSegment<'a> {
+    Literal(&'a str),
+    Token(TokenReference),
+}
+
+/// Splits a template string into literal text and token references, in order
+pub fn parse_template(input: &str) -> Vec<TemplateSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut literal_start = 0;
+    let mut search_from = 0;
+
+    while let Some(offset) = input.get(search_from..).and_then(|rest| rest.find("{{")) {
+        let token_start = search_from.saturating_add(offset);
+        match input.get(token_start..).map(parse_token) {
+            Some(Ok((remaining, token_ref))) => {
+                if token_start > literal_start {
+                    segments.push(TemplateSegment::Literal(
+                        input.get(literal_start..token_start).unwrap_or_default(),
+                    ));
+                }
+                segments.push(TemplateSegment::Token(token_ref));
+                literal_start = input.len().saturating_sub(remaining.len());
+                search_from = literal_start;
+            }
+            // Move past the opening braces if they do not start a token
+            _ => search_from = token_start.saturating_add(1),
+        }
+    }
+
+    if literal_start < input.len() {
+        segments.push(TemplateSegment::Literal(
+            input.get(literal_start..).unwrap_or_default(),
+        ));
+    }
+
+    segments
 }
 
 pub fn contains_token(s: &str) -> bool {
@@ -0,0 +1,1222 @@
+use api_models::{
+    mandates::RecurringDetails,
+    payment_methods::PaymentMethodsData,
+    payments::{PaymentsRequest, PaymentsResponse},
+    recovery_enrollments::{
+        RecoveryAttemptResponse, RecoveryEnrollmentCreateRequest, RecoveryEnrollmentId,
+        RecoveryEnrollmentListConstraints, RecoveryEnrollmentListResponse,
+        RecoveryEnrollmentResponse, RecoveryEnrollmentRetryPTData,
+    },
+};
+use common_utils::{errors::ErrorSwitch, id_type};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::HeaderPayload;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{api, domain, storage},
+    utils,
+};
+
+const RECOVERY_RETRY_TASK: &str = "RECOVERY_RETRY";
+const RECOVERY_ENROLLMENT_ID_PREFIX: &str = "rcv";
+const RECOVERY_ATTEMPT_ID_PREFIX: &str = "rcva";
+
+/// The gateway status map flow under which the decline categories of recovery retries are
+/// configured, shared with the billing connector driven revenue recovery
+const RECOVERY_GSM_FLOW: &str = "revenue_recovery";
+
+/// Interval at which the status of a retry that is still being processed is checked again
+const PENDING_RETRY_CHECK_INTERVAL_IN_MINUTES: i64 = 60;
+
+/// Upper bound on the number of past retries used to compute the success rates by hour
+const MAX_RETRY_HISTORY_SIZE: i64 = 10_000;
+
+/// Delays after a soft decline, or a decline without a configured category, at which a payment
+/// is retried. Retries beyond the last delay use the last delay
+const SOFT_DECLINE_RETRY_DELAYS: [time::Duration; 4] = [
+    time::Duration::days(1),
+    time::Duration::days(3),
+    time::Duration::days(5),
+    time::Duration::days(7),
+];
+
+/// Downtimes of the processor are usually resolved within hours
+const PROCESSOR_DOWNTIME_RETRY_DELAYS: [time::Duration; 4] = [
+    time::Duration::hours(1),
+    time::Duration::hours(4),
+    time::Duration::hours(12),
+    time::Duration::hours(24),
+];
+
+/// Declines caused by the payment method or its details are only retried a few times, with long
+/// delays to give the customer time to update the payment method
+const PAYMENT_METHOD_ISSUE_RETRY_DELAYS: [time::Duration; 2] =
+    [time::Duration::days(3), time::Duration::days(7)];
+
+#[instrument(skip_all)]
+pub async fn create_recovery_enrollment(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: RecoveryEnrollmentCreateRequest,
+) -> RouterResponse<RecoveryEnrollmentResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let recovery_config = &state.conf.native_revenue_recovery;
+
+    let max_retries = get_bounded_value(
+        "max_retries",
+        request.max_retries,
+        recovery_config.max_retries,
+    )?;
+    let recovery_window_days = get_bounded_value(
+        "recovery_window_days",
+        request.recovery_window_days,
+        recovery_config.recovery_window_days,
+    )?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &request.payment_id,
+            &merchant_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.status != storage_enums::IntentStatus::Failed {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only failed payments can be enrolled for recovery".to_string(),
+        }
+        .into());
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let is_merchant_initiated =
+        payment_intent.off_session == Some(true) || payment_attempt.mandate_id.is_some();
+    let (Some(customer_id), Some(payment_method_id)) = (
+        payment_intent.customer_id.clone(),
+        payment_attempt.payment_method_id.clone(),
+    ) else {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only payments made with a saved payment method of a customer can be enrolled for recovery".to_string(),
+        }
+        .into());
+    };
+    if !is_merchant_initiated {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only merchant initiated payments can be enrolled for recovery".to_string(),
+        }
+        .into());
+    }
+    let profile_id = payment_intent
+        .profile_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id not found in the payment intent")?;
+    let currency = payment_intent
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("currency not found in the payment intent")?;
+
+    if db
+        .find_optional_revenue_recovery_enrollment_by_merchant_id_payment_id(
+            &merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the recovery enrollment of the payment")?
+        .is_some()
+    {
+        return Err(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "The payment is already enrolled for recovery".to_string(),
+        }
+        .into());
+    }
+
+    let decline_category = get_decline_category(
+        &state,
+        payment_attempt.connector.clone(),
+        payment_attempt.error_code.clone(),
+        payment_attempt.error_message.clone(),
+    )
+    .await;
+    if is_hard_decline(decline_category) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payments which were hard declined cannot be recovered".to_string(),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let expires_at = now + time::Duration::days(recovery_window_days.into());
+    let card_expiry = get_card_expiry(&state, &merchant_context, &payment_method_id).await?;
+    if card_expiry.is_some_and(|card_expiry| card_expiry <= now) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The card of the payment has expired".to_string(),
+        }
+        .into());
+    }
+
+    let retry_history = get_retry_history(&state, &merchant_id, now).await?;
+    let next_retry_at = get_next_retry_time(
+        decline_category,
+        0,
+        max_retries.into(),
+        now,
+        get_retry_deadline(expires_at, card_expiry),
+        &retry_history,
+    )
+    .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+        message: "No retry can be scheduled before the end of the recovery window or the expiry of the card".to_string(),
+    })?;
+
+    let enrollment_new = storage::RevenueRecoveryEnrollmentNew {
+        enrollment_id: utils::generate_id(consts::ID_LENGTH, RECOVERY_ENROLLMENT_ID_PREFIX),
+        merchant_id,
+        profile_id,
+        payment_id: payment_intent.payment_id,
+        customer_id,
+        payment_method_id,
+        mandate_id: payment_attempt.mandate_id,
+        amount: payment_intent.amount,
+        currency,
+        status: storage_enums::RecoveryEnrollmentStatus::Scheduled,
+        decline_category,
+        retry_count: 0,
+        max_retries: max_retries.into(),
+        next_retry_at: Some(next_retry_at),
+        expires_at,
+        last_error_code: payment_attempt.error_code,
+        last_error_message: payment_attempt.error_message,
+        recovered_payment_id: None,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let enrollment = db
+        .insert_revenue_recovery_enrollment(enrollment_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "The payment is already enrolled for recovery".to_string(),
+        })?;
+
+    add_recovery_retry_task_to_pt(db, &enrollment, next_retry_at).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_enrollment_response(enrollment, None),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_recovery_enrollment(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: RecoveryEnrollmentId,
+) -> RouterResponse<RecoveryEnrollmentResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let enrollment = find_enrollment(db, merchant_id, &request.enrollment_id).await?;
+
+    let attempts = db
+        .list_revenue_recovery_attempts_by_merchant_id_enrollment_id(
+            merchant_id,
+            &enrollment.enrollment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the retries of the recovery enrollment")?
+        .into_iter()
+        .map(get_attempt_response)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        get_enrollment_response(enrollment, Some(attempts)),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn cancel_recovery_enrollment(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: RecoveryEnrollmentId,
+) -> RouterResponse<RecoveryEnrollmentResponse> {
+    let db = state.store.as_ref();
+    let enrollment = find_enrollment(
+        db,
+        merchant_context.get_merchant_account().get_id(),
+        &request.enrollment_id,
+    )
+    .await?;
+
+    if enrollment.status.is_terminal() {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Recovery enrollments with status {} cannot be cancelled",
+                enrollment.status
+            ),
+        }
+        .into());
+    }
+
+    // The scheduled retry task finishes by itself once it finds the enrollment cancelled
+    let enrollment = db
+        .update_revenue_recovery_enrollment(
+            enrollment,
+            storage::RevenueRecoveryEnrollmentUpdate::StatusUpdate {
+                status: storage_enums::RecoveryEnrollmentStatus::Cancelled,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel the recovery enrollment")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_enrollment_response(enrollment, None),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_recovery_enrollments(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: RecoveryEnrollmentListConstraints,
+) -> RouterResponse<RecoveryEnrollmentListResponse> {
+    let enrollments = state
+        .store
+        .list_revenue_recovery_enrollments_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            constraints.status,
+            Some(get_list_limit(constraints.limit)),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the recovery enrollments")?;
+
+    let data = enrollments
+        .into_iter()
+        .map(|enrollment| get_enrollment_response(enrollment, None))
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        RecoveryEnrollmentListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Retries the enrolled payment when the retry is due, or checks the outcome of a retry which was
+/// still being processed.
+/// Returns the time at which the enrollment has to be processed next, or `None` once it is no
+/// longer retried.
+#[instrument(skip_all)]
+pub async fn run_recovery_enrollment_retry(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &RecoveryEnrollmentRetryPTData,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let enrollment = match db
+        .find_revenue_recovery_enrollment_by_merchant_id_enrollment_id(
+            &tracking_data.merchant_id,
+            &tracking_data.enrollment_id,
+        )
+        .await
+    {
+        Ok(enrollment) => enrollment,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the recovery enrollment")
+        }
+    };
+
+    if enrollment.status.is_terminal() {
+        return Ok(None);
+    }
+
+    let pending_attempt = db
+        .list_revenue_recovery_attempts_by_merchant_id_enrollment_id(
+            &enrollment.merchant_id,
+            &enrollment.enrollment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the retries of the recovery enrollment")?
+        .into_iter()
+        .find(|attempt| attempt.status == storage_enums::RecoveryAttemptStatus::Processing);
+
+    if let Some(attempt) = pending_attempt {
+        let Some(payment_id) = attempt.payment_id.clone() else {
+            return Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Processing recovery retry without a payment");
+        };
+        let outcome = get_retry_payment_outcome(state, merchant_context, &payment_id).await?;
+        return apply_retry_outcome(
+            state,
+            merchant_context,
+            enrollment,
+            attempt,
+            payment_id,
+            outcome,
+        )
+        .await;
+    }
+
+    let now = common_utils::date_time::now();
+    if let Some(next_retry_at) = enrollment
+        .next_retry_at
+        .filter(|next_retry_at| *next_retry_at > now)
+    {
+        return Ok(Some(next_retry_at));
+    }
+
+    retry_enrolled_payment(state, merchant_context, enrollment).await
+}
+
+/// The outcome of a retry of an enrolled payment
+enum RetryPaymentOutcome {
+    Succeeded,
+    Pending,
+    Failed {
+        connector: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+impl RetryPaymentOutcome {
+    fn from_intent_status(
+        status: storage_enums::IntentStatus,
+        connector: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    ) -> Self {
+        match status {
+            storage_enums::IntentStatus::Succeeded => Self::Succeeded,
+            storage_enums::IntentStatus::Processing => Self::Pending,
+            _ => Self::Failed {
+                connector,
+                error_code,
+                error_message,
+            },
+        }
+    }
+}
+
+/// Charges the enrolled payment off-session with the saved payment method or mandate of the
+/// original payment
+async fn retry_enrolled_payment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    enrollment: storage::RevenueRecoveryEnrollment,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let attempt_number = enrollment.retry_count.saturating_add(1);
+    let recurring_details = match &enrollment.mandate_id {
+        Some(mandate_id) => RecurringDetails::MandateId(mandate_id.clone()),
+        None => RecurringDetails::PaymentMethodId(enrollment.payment_method_id.clone()),
+    };
+    let payments_request = PaymentsRequest {
+        amount: Some(enrollment.amount.into()),
+        currency: Some(enrollment.currency),
+        customer_id: Some(enrollment.customer_id.clone()),
+        profile_id: Some(enrollment.profile_id.clone()),
+        recurring_details: Some(recurring_details),
+        off_session: Some(true),
+        confirm: Some(true),
+        description: Some(format!(
+            "Recovery retry {attempt_number} of payment {}",
+            enrollment.payment_id.get_string_repr()
+        )),
+        metadata: Some(serde_json::json!({
+            "recovery_enrollment_id": enrollment.enrollment_id,
+            "original_payment_id": enrollment.payment_id,
+        })),
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_context.clone(),
+        Some(enrollment.profile_id.clone()),
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    let (payment_id, outcome) = match response {
+        Ok(services::ApplicationResponse::Json(payments_response))
+        | Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => (
+            Some(payments_response.payment_id),
+            RetryPaymentOutcome::from_intent_status(
+                payments_response.status,
+                payments_response.connector,
+                payments_response.error_code,
+                payments_response.error_message,
+            ),
+        ),
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from the payment create flow")?,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                enrollment_id = %enrollment.enrollment_id,
+                "Failed to retry the enrolled payment"
+            );
+            let api_error: api_models::errors::types::ApiErrorResponse =
+                error.current_context().switch();
+            let error_response = api_models::errors::types::ErrorResponse::from(&api_error);
+            (
+                None,
+                RetryPaymentOutcome::Failed {
+                    connector: None,
+                    error_code: Some(error_response.code),
+                    error_message: Some(error_response.message),
+                },
+            )
+        }
+    };
+
+    let now = common_utils::date_time::now();
+    let attempt_new = storage::RevenueRecoveryAttemptNew {
+        attempt_id: utils::generate_id(consts::ID_LENGTH, RECOVERY_ATTEMPT_ID_PREFIX),
+        enrollment_id: enrollment.enrollment_id.clone(),
+        merchant_id: enrollment.merchant_id.clone(),
+        payment_id: payment_id.clone(),
+        attempt_number,
+        status: storage_enums::RecoveryAttemptStatus::Processing,
+        error_code: None,
+        error_message: None,
+        decline_category: None,
+        attempted_at: now,
+        modified_at: now,
+    };
+    let attempt = state
+        .store
+        .insert_revenue_recovery_attempt(attempt_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the recovery retry")?;
+
+    let enrollment = state
+        .store
+        .update_revenue_recovery_enrollment(
+            enrollment,
+            storage::RevenueRecoveryEnrollmentUpdate::RetryScheduled {
+                retry_count: attempt_number,
+                next_retry_at: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the retry count of the recovery enrollment")?;
+
+    match payment_id {
+        Some(payment_id) => {
+            apply_retry_outcome(
+                state,
+                merchant_context,
+                enrollment,
+                attempt,
+                payment_id,
+                outcome,
+            )
+            .await
+        }
+        None => record_failed_retry(state, merchant_context, enrollment, attempt, outcome).await,
+    }
+}
+
+/// Records the outcome of a retry payment, and schedules the next retry if it failed
+async fn apply_retry_outcome(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    enrollment: storage::RevenueRecoveryEnrollment,
+    attempt: storage::RevenueRecoveryAttempt,
+    payment_id: id_type::PaymentId,
+    outcome: RetryPaymentOutcome,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+
+    match outcome {
+        RetryPaymentOutcome::Succeeded => {
+            db.update_revenue_recovery_attempt(
+                attempt,
+                storage::RevenueRecoveryAttemptUpdate::StatusUpdate {
+                    status: storage_enums::RecoveryAttemptStatus::Succeeded,
+                    error_code: None,
+                    error_message: None,
+                    decline_category: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the recovery retry")?;
+
+            let retry_count = enrollment.retry_count;
+            db.update_revenue_recovery_enrollment(
+                enrollment,
+                storage::RevenueRecoveryEnrollmentUpdate::Recovered {
+                    retry_count,
+                    recovered_payment_id: payment_id,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to mark the recovery enrollment as recovered")?;
+            Ok(None)
+        }
+        RetryPaymentOutcome::Pending => {
+            let next_check_at = common_utils::date_time::now()
+                + time::Duration::minutes(PENDING_RETRY_CHECK_INTERVAL_IN_MINUTES);
+            Ok(Some(next_check_at))
+        }
+        outcome @ RetryPaymentOutcome::Failed { .. } => {
+            record_failed_retry(state, merchant_context, enrollment, attempt, outcome).await
+        }
+    }
+}
+
+/// Records the failure of a retry, and computes the next retry from the decline category of the
+/// failure. The enrollment is exhausted once no further retry can be scheduled
+async fn record_failed_retry(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    enrollment: storage::RevenueRecoveryEnrollment,
+    attempt: storage::RevenueRecoveryAttempt,
+    outcome: RetryPaymentOutcome,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let (connector, error_code, error_message) = match outcome {
+        RetryPaymentOutcome::Failed {
+            connector,
+            error_code,
+            error_message,
+        } => (connector, error_code, error_message),
+        RetryPaymentOutcome::Succeeded | RetryPaymentOutcome::Pending => (None, None, None),
+    };
+    let decline_category =
+        get_decline_category(state, connector, error_code.clone(), error_message.clone()).await;
+
+    db.update_revenue_recovery_attempt(
+        attempt,
+        storage::RevenueRecoveryAttemptUpdate::StatusUpdate {
+            status: storage_enums::RecoveryAttemptStatus::Failed,
+            error_code: error_code.clone(),
+            error_message: error_message.clone(),
+            decline_category,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the recovery retry")?;
+
+    let now = common_utils::date_time::now();
+    let card_expiry =
+        get_card_expiry(state, merchant_context, &enrollment.payment_method_id).await?;
+    let retry_history = get_retry_history(state, &enrollment.merchant_id, now).await?;
+    let next_retry_at = get_next_retry_time(
+        decline_category,
+        enrollment.retry_count,
+        enrollment.max_retries,
+        now,
+        get_retry_deadline(enrollment.expires_at, card_expiry),
+        &retry_history,
+    );
+
+    let retry_count = enrollment.retry_count;
+    db.update_revenue_recovery_enrollment(
+        enrollment,
+        storage::RevenueRecoveryEnrollmentUpdate::RetryFailed {
+            status: if next_retry_at.is_some() {
+                storage_enums::RecoveryEnrollmentStatus::Scheduled
+            } else {
+                storage_enums::RecoveryEnrollmentStatus::Exhausted
+            },
+            retry_count,
+            next_retry_at,
+            decline_category,
+            last_error_code: error_code,
+            last_error_message: error_message,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the recovery enrollment")?;
+
+    Ok(next_retry_at)
+}
+
+async fn get_retry_payment_outcome(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<RetryPaymentOutcome> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment of the recovery retry")?;
+
+    if matches!(
+        payment_intent.status,
+        storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::Processing
+    ) {
+        return Ok(RetryPaymentOutcome::from_intent_status(
+            payment_intent.status,
+            None,
+            None,
+            None,
+        ));
+    }
+
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment attempt of the recovery retry")?;
+
+    Ok(RetryPaymentOutcome::from_intent_status(
+        payment_intent.status,
+        payment_attempt.connector,
+        payment_attempt.error_code,
+        payment_attempt.error_message,
+    ))
+}
+
+/// The decline category configured in the gateway status map for the error of the connector
+async fn get_decline_category(
+    state: &SessionState,
+    connector: Option<String>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+) -> Option<storage_enums::ErrorCategory> {
+    let connector = connector?;
+    payments::helpers::get_gsm_record(
+        state,
+        error_code,
+        error_message,
+        connector,
+        RECOVERY_GSM_FLOW.to_string(),
+    )
+    .await
+    .and_then(|gsm_record| gsm_record.error_category)
+}
+
+/// The time at which the card of the payment method expires, `None` for payment methods other
+/// than cards or cards without an expiry
+async fn get_card_expiry(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method_id: &str,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let card = payment_method
+        .payment_method_data
+        .map(|payment_method_data| payment_method_data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|payment_methods_data| match payment_methods_data {
+            PaymentMethodsData::Card(card) => Some(card),
+            PaymentMethodsData::BankDetails(_) | PaymentMethodsData::WalletDetails(_) => None,
+        });
+
+    Ok(
+        card.and_then(|card| {
+            parse_card_expiry(card.expiry_month?.peek(), card.expiry_year?.peek())
+        }),
+    )
+}
+
+/// Success rates of the past retries of a merchant by the weekday and hour (UTC) at which they
+/// were made
+#[derive(Debug, Default)]
+struct RetryHistory {
+    slots: [[RetrySlotStats; 24]; 7],
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct RetrySlotStats {
+    attempts: u64,
+    successes: u64,
+}
+
+impl RetryHistory {
+    fn from_attempts(attempts: &[storage::RevenueRecoveryAttempt]) -> Self {
+        let mut history = Self::default();
+        for attempt in attempts {
+            if let Some(slot) = history.get_slot_mut(attempt.attempted_at) {
+                slot.attempts = slot.attempts.saturating_add(1);
+                if attempt.status == storage_enums::RecoveryAttemptStatus::Succeeded {
+                    slot.successes = slot.successes.saturating_add(1);
+                }
+            }
+        }
+        history
+    }
+
+    fn get_slot(&self, time: PrimitiveDateTime) -> RetrySlotStats {
+        self.slots
+            .get(usize::from(time.weekday().number_days_from_monday()))
+            .and_then(|hours| hours.get(usize::from(time.hour())))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn get_slot_mut(&mut self, time: PrimitiveDateTime) -> Option<&mut RetrySlotStats> {
+        self.slots
+            .get_mut(usize::from(time.weekday().number_days_from_monday()))
+            .and_then(|hours| hours.get_mut(usize::from(time.hour())))
+    }
+
+    /// Whether the slot of `candidate` has a higher success rate than the slot of `current`.
+    /// The success rates are smoothed as `(successes + 1) / (attempts + 2)`, so that slots with
+    /// few retries are neither preferred nor avoided
+    fn is_better_slot(&self, candidate: PrimitiveDateTime, current: PrimitiveDateTime) -> bool {
+        let candidate = self.get_slot(candidate);
+        let current = self.get_slot(current);
+        let candidate_score = u128::from(candidate.successes.saturating_add(1))
+            * u128::from(current.attempts.saturating_add(2));
+        let current_score = u128::from(current.successes.saturating_add(1))
+            * u128::from(candidate.attempts.saturating_add(2));
+        candidate_score > current_score
+    }
+}
+
+async fn get_retry_history(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    now: PrimitiveDateTime,
+) -> RouterResult<RetryHistory> {
+    let lookback = time::Duration::days(
+        state
+            .conf
+            .native_revenue_recovery
+            .history_lookback_days
+            .into(),
+    );
+    let attempts = state
+        .store
+        .list_completed_revenue_recovery_attempts_by_merchant_id_attempted_after(
+            merchant_id,
+            now - lookback,
+            Some(MAX_RETRY_HISTORY_SIZE),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the past recovery retries of the merchant")?;
+    Ok(RetryHistory::from_attempts(&attempts))
+}
+
+fn is_hard_decline(decline_category: Option<storage_enums::ErrorCategory>) -> bool {
+    matches!(
+        decline_category,
+        Some(storage_enums::ErrorCategory::HardDecline | storage_enums::ErrorCategory::FrmDecline)
+    )
+}
+
+/// Delays at which a payment is retried after a decline of the category
+fn get_retry_delays(
+    decline_category: Option<storage_enums::ErrorCategory>,
+) -> &'static [time::Duration] {
+    match decline_category {
+        Some(
+            storage_enums::ErrorCategory::HardDecline | storage_enums::ErrorCategory::FrmDecline,
+        ) => &[],
+        Some(storage_enums::ErrorCategory::ProcessorDowntime) => &PROCESSOR_DOWNTIME_RETRY_DELAYS,
+        Some(
+            storage_enums::ErrorCategory::IssueWithPaymentMethod
+            | storage_enums::ErrorCategory::ProcessorDeclineIncorrectData
+            | storage_enums::ErrorCategory::ProcessorDeclineUnauthorized,
+        ) => &PAYMENT_METHOD_ISSUE_RETRY_DELAYS,
+        Some(storage_enums::ErrorCategory::SoftDecline) | None => &SOFT_DECLINE_RETRY_DELAYS,
+    }
+}
+
+/// Computes the time of the next retry after `retry_count` retries, or `None` if the payment is
+/// not retried anymore.
+/// The earliest retry time is given by the delay of the decline category. The retry is then moved
+/// to the hour with the best historical success rate within the following `min(delay, 24h)`,
+/// the earliest such hour being picked on ties. Retries are never scheduled after `deadline`.
+fn get_next_retry_time(
+    decline_category: Option<storage_enums::ErrorCategory>,
+    retry_count: i32,
+    max_retries: i32,
+    now: PrimitiveDateTime,
+    deadline: PrimitiveDateTime,
+    history: &RetryHistory,
+) -> Option<PrimitiveDateTime> {
+    if retry_count >= max_retries {
+        return None;
+    }
+    let delays = get_retry_delays(decline_category);
+    let delay = usize::try_from(retry_count)
+        .ok()
+        .and_then(|retry_index| delays.get(retry_index))
+        .or(delays.last())?;
+
+    let earliest = now + *delay;
+    if earliest > deadline {
+        return None;
+    }
+    let window_in_hours = delay.whole_hours().clamp(0, 24);
+
+    let mut best = earliest;
+    for offset in 1..=window_in_hours {
+        let candidate = earliest + time::Duration::hours(offset);
+        if candidate > deadline {
+            break;
+        }
+        if history.is_better_slot(candidate, best) {
+            best = candidate;
+        }
+    }
+    Some(best)
+}
+
+fn get_retry_deadline(
+    expires_at: PrimitiveDateTime,
+    card_expiry: Option<PrimitiveDateTime>,
+) -> PrimitiveDateTime {
+    card_expiry.map_or(expires_at, |card_expiry| expires_at.min(card_expiry))
+}
+
+/// Cards expire at the end of their expiry month. Two digit years are taken to be in the 2000s
+pub(crate) fn parse_card_expiry(
+    expiry_month: &str,
+    expiry_year: &str,
+) -> Option<PrimitiveDateTime> {
+    let month = expiry_month.trim().parse::<u8>().ok()?;
+    let year = expiry_year.trim().parse::<i32>().ok()?;
+    let year = if year < 100 { year + 2000 } else { year };
+
+    let (next_month_year, next_month) = if month == 12 {
+        (year.checked_add(1)?, time::Month::January)
+    } else {
+        (year, time::Month::try_from(month).ok()?.next())
+    };
+    time::Date::from_calendar_date(next_month_year, next_month, 1)
+        .ok()
+        .map(|date| date.midnight())
+}
+
+/// The requested value, which defaults to and cannot exceed the configured maximum
+fn get_bounded_value(field_name: &str, requested: Option<u16>, maximum: u16) -> RouterResult<u16> {
+    match requested {
+        Some(value) if value == 0 || value > maximum => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("{field_name} must be between 1 and {maximum}"),
+            }
+            .into())
+        }
+        Some(value) => Ok(value),
+        None => Ok(maximum),
+    }
+}
+
+async fn add_recovery_retry_task_to_pt(
+    db: &dyn StorageInterface,
+    enrollment: &storage::RevenueRecoveryEnrollment,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let tracking_data = RecoveryEnrollmentRetryPTData {
+        merchant_id: enrollment.merchant_id.clone(),
+        enrollment_id: enrollment.enrollment_id.clone(),
+    };
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        scheduler::utils::get_process_tracker_id(
+            storage::ProcessTrackerRunner::RecoveryEnrollmentWorkflow,
+            RECOVERY_RETRY_TASK,
+            &enrollment.enrollment_id,
+            &enrollment.merchant_id,
+        ),
+        RECOVERY_RETRY_TASK,
+        storage::ProcessTrackerRunner::RecoveryEnrollmentWorkflow,
+        ["REVENUE_RECOVERY", "NATIVE"],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct recovery retry process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add recovery retry process tracker task")?;
+
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "recovery_enrollment_retry")),
+    );
+    Ok(())
+}
+
+async fn find_enrollment(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    enrollment_id: &str,
+) -> RouterResult<storage::RevenueRecoveryEnrollment> {
+    db.find_revenue_recovery_enrollment_by_merchant_id_enrollment_id(merchant_id, enrollment_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Recovery enrollment does not exist".to_string(),
+        })
+}
+
+fn get_list_limit(limit: Option<i64>) -> i64 {
+    limit
+        .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into())
+        .clamp(1, common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1.into())
+}
+
+pub fn get_enrollment_response(
+    enrollment: storage::RevenueRecoveryEnrollment,
+    attempts: Option<Vec<RecoveryAttemptResponse>>,
+) -> RecoveryEnrollmentResponse {
+    RecoveryEnrollmentResponse {
+        enrollment_id: enrollment.enrollment_id,
+        merchant_id: enrollment.merchant_id,
+        profile_id: enrollment.profile_id,
+        payment_id: enrollment.payment_id,
+        customer_id: enrollment.customer_id,
+        payment_method_id: enrollment.payment_method_id,
+        amount: enrollment.amount,
+        currency: enrollment.currency,
+        status: enrollment.status,
+        decline_category: enrollment.decline_category,
+        retry_count: enrollment.retry_count,
+        max_retries: enrollment.max_retries,
+        next_retry_at: enrollment.next_retry_at,
+        expires_at: enrollment.expires_at,
+        last_error_code: enrollment.last_error_code,
+        last_error_message: enrollment.last_error_message,
+        recovered_payment_id: enrollment.recovered_payment_id,
+        metadata: enrollment.metadata,
+        created_at: enrollment.created_at,
+        attempts,
+    }
+}
+
+fn get_attempt_response(attempt: storage::RevenueRecoveryAttempt) -> RecoveryAttemptResponse {
+    RecoveryAttemptResponse {
+        attempt_id: attempt.attempt_id,
+        payment_id: attempt.payment_id,
+        attempt_number: attempt.attempt_number,
+        status: attempt.status,
+        error_code: attempt.error_code,
+        error_message: attempt.error_message,
+        decline_category: attempt.decline_category,
+        attempted_at: attempt.attempted_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_attempt(
+        attempted_at: PrimitiveDateTime,
+        status: storage_enums::RecoveryAttemptStatus,
+    ) -> storage::RevenueRecoveryAttempt {
+        storage::RevenueRecoveryAttempt {
+            attempt_id: "rcva_test".to_string(),
+            enrollment_id: "rcv_test".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            payment_id: None,
+            attempt_number: 1,
+            status,
+            error_code: None,
+            error_message: None,
+            decline_category: None,
+            attempted_at,
+            modified_at: attempted_at,
+        }
+    }
+
+    #[test]
+    fn test_retry_delays_follow_the_decline_category() {
+        let now = datetime!(2024-04-01 10:00);
+        let deadline = datetime!(2024-05-01 00:00);
+        let history = RetryHistory::default();
+
+        assert_eq!(
+            get_next_retry_time(None, 0, 8, now, deadline, &history),
+            Some(datetime!(2024-04-02 10:00))
+        );
+        assert_eq!(
+            get_next_retry_time(
+                Some(storage_enums::ErrorCategory::ProcessorDowntime),
+                1,
+                8,
+                now,
+                deadline,
+                &history
+            ),
+            Some(datetime!(2024-04-01 14:00))
+        );
+        // Retries beyond the schedule keep using its last delay
+        assert_eq!(
+            get_next_retry_time(
+                Some(storage_enums::ErrorCategory::SoftDecline),
+                6,
+                8,
+                now,
+                deadline,
+                &history
+            ),
+            Some(datetime!(2024-04-08 10:00))
+        );
+        assert_eq!(
+            get_next_retry_time(
+                Some(storage_enums::ErrorCategory::HardDecline),
+                0,
+                8,
+                now,
+                deadline,
+                &history
+            ),
+            None
+        );
+        assert_eq!(
+            get_next_retry_time(None, 8, 8, now, deadline, &history),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_is_moved_to_the_hour_with_the_best_success_rate() {
+        let now = datetime!(2024-04-01 10:00);
+        let deadline = datetime!(2024-05-01 00:00);
+        // 2024-04-02 is a Tuesday, a week earlier retries at 18:00 mostly succeeded
+        let mut attempts = vec![
+            get_attempt(
+                datetime!(2024-03-26 18:30),
+                storage_enums::RecoveryAttemptStatus::Succeeded
+            );
+            3
+        ];
+        attempts.push(get_attempt(
+            datetime!(2024-03-26 18:10),
+            storage_enums::RecoveryAttemptStatus::Failed,
+        ));
+        attempts.extend(vec![
+            get_attempt(
+                datetime!(2024-03-26 12:00),
+                storage_enums::RecoveryAttemptStatus::Failed
+            );
+            4
+        ]);
+        let history = RetryHistory::from_attempts(&attempts);
+
+        assert_eq!(
+            get_next_retry_time(None, 0, 8, now, deadline, &history),
+            Some(datetime!(2024-04-02 18:00))
+        );
+        // The better hour is not used if it is after the deadline
+        assert_eq!(
+            get_next_retry_time(None, 0, 8, now, datetime!(2024-04-02 15:00), &history),
+            Some(datetime!(2024-04-02 10:00))
+        );
+    }
+
+    #[test]
+    fn test_retries_stop_at_the_deadline() {
+        let now = datetime!(2024-04-01 10:00);
+        let history = RetryHistory::default();
+
+        assert_eq!(
+            get_next_retry_time(None, 0, 8, now, datetime!(2024-04-02 09:00), &history),
+            None
+        );
+        assert_eq!(
+            get_retry_deadline(datetime!(2024-05-01 00:00), parse_card_expiry("04", "24")),
+            datetime!(2024-05-01 00:00)
+        );
+        assert_eq!(
+            get_retry_deadline(datetime!(2024-05-01 00:00), parse_card_expiry("3", "2024")),
+            datetime!(2024-04-01 00:00)
+        );
+    }
+
+    #[test]
+    fn test_parse_card_expiry() {
+        assert_eq!(
+            parse_card_expiry("12", "2025"),
+            Some(datetime!(2026-01-01 00:00))
+        );
+        assert_eq!(
+            parse_card_expiry("02", "28"),
+            Some(datetime!(2028-03-01 00:00))
+        );
+        assert_eq!(parse_card_expiry("13", "2025"), None);
+        assert_eq!(parse_card_expiry("ab", "2025"), None);
+    }
+}
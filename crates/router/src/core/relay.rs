@@ -41,6 +41,68 @@ impl Validate for relay_api_models::RelayRefundRequestData {
     }
 }
 
+impl Validate for relay_api_models::RelayCaptureRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(self.amount_to_capture.get_amount_as_i64() <= 0, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Amount to capture should be greater than 0".to_string(),
+            })
+        })?;
+        fp_utils::when(self.amount_to_capture > self.authorized_amount, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Amount to capture should not exceed the authorized amount".to_string(),
+            })
+        })?;
+        Ok(())
+    }
+}
+
+impl Validate for relay_api_models::RelayVoidRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(
+            self.amount
+                .is_some_and(|amount| amount.get_amount_as_i64() <= 0),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Amount should be greater than 0".to_string(),
+                })
+            },
+        )?;
+        Ok(())
+    }
+}
+
+impl Validate for relay_api_models::RelayIncrementalAuthorizationRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(self.additional_amount.get_amount_as_i64() <= 0, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Additional amount should be greater than 0".to_string(),
+            })
+        })?;
+        fp_utils::when(self.additional_amount > self.total_amount, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Additional amount should not exceed the total amount".to_string(),
+            })
+        })?;
+        Ok(())
+    }
+}
+
+impl Validate for relay_api_models::RelaySyncRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(self.amount.get_amount_as_i64() < 0, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Amount should not be negative".to_string(),
+            })
+        })?;
+        Ok(())
+    }
+}
+
 #[async_trait]
 pub trait RelayInterface {
     type Request: Validate;
@@ -90,6 +152,161 @@ impl RelayRequestInner<RelayRefund> {
     }
 }
 
+impl RelayRequestInner<RelayCapture> {
+    pub fn from_relay_request(relay_request: relay_api_models::RelayRequest) -> RouterResult<Self> {
+        match relay_request.data {
+            Some(relay_api_models::RelayData::Capture(capture_data)) => Ok(Self {
+                connector_resource_id: relay_request.connector_resource_id,
+                connector_id: relay_request.connector_id,
+                relay_type: PhantomData,
+                data: capture_data,
+            }),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Capture data is required for relay type capture".to_string(),
+            })?,
+        }
+    }
+}
+
+impl RelayRequestInner<RelayVoid> {
+    pub fn from_relay_request(relay_request: relay_api_models::RelayRequest) -> RouterResult<Self> {
+        match relay_request.data {
+            Some(relay_api_models::RelayData::Void(void_data)) => Ok(Self {
+                connector_resource_id: relay_request.connector_resource_id,
+                connector_id: relay_request.connector_id,
+                relay_type: PhantomData,
+                data: void_data,
+            }),
+            // All the fields of a void are optional, so the data can be omitted
+            None => Ok(Self {
+                connector_resource_id: relay_request.connector_resource_id,
+                connector_id: relay_request.connector_id,
+                relay_type: PhantomData,
+                data: relay_api_models::RelayVoidRequestData {
+                    amount: None,
+                    currency: None,
+                    cancellation_reason: None,
+                },
+            }),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Void data is expected for relay type void".to_string(),
+            })?,
+        }
+    }
+}
+
+impl RelayRequestInner<RelayIncrementalAuthorization> {
+    pub fn from_relay_request(relay_request: relay_api_models::RelayRequest) -> RouterResult<Self> {
+        match relay_request.data {
+            Some(relay_api_models::RelayData::IncrementalAuthorization(
+                incremental_authorization_data,
+            )) => Ok(Self {
+                connector_resource_id: relay_request.connector_resource_id,
+                connector_id: relay_request.connector_id,
+                relay_type: PhantomData,
+                data: incremental_authorization_data,
+            }),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Incremental authorization data is required for relay type incremental_authorization".to_string(),
+            })?,
+        }
+    }
+}
+
+impl RelayRequestInner<RelaySync> {
+    pub fn from_relay_request(relay_request: relay_api_models::RelayRequest) -> RouterResult<Self> {
+        match relay_request.data {
+            Some(relay_api_models::RelayData::Sync(sync_data)) => Ok(Self {
+                connector_resource_id: relay_request.connector_resource_id,
+                connector_id: relay_request.connector_id,
+                relay_type: PhantomData,
+                data: sync_data,
+            }),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Sync data is required for relay type sync".to_string(),
+            })?,
+        }
+    }
+}
+
+fn construct_relay_domain_model<T: RelayInterface + ?Sized>(
+    relay_request: &RelayRequestInner<T>,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    relay_type: common_enums::RelayType,
+    request_data: relay::RelayData,
+) -> relay::Relay {
+    relay::Relay {
+        id: id_type::RelayId::generate(),
+        connector_resource_id: relay_request.connector_resource_id.clone(),
+        connector_id: relay_request.connector_id.clone(),
+        profile_id: profile_id.clone(),
+        merchant_id: merchant_id.clone(),
+        relay_type,
+        request_data: Some(request_data),
+        status: RelayStatus::Created,
+        connector_reference_id: None,
+        error_code: None,
+        error_message: None,
+        created_at: common_utils::date_time::now(),
+        modified_at: common_utils::date_time::now(),
+        response_data: None,
+    }
+}
+
+fn get_connector_data_for_relay(
+    state: &SessionState,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &relay::Relay,
+) -> RouterResult<api::ConnectorData> {
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_account.get_connector_name_as_string(),
+        api::GetToken::Connector,
+        Some(relay_record.connector_id.clone()),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")
+}
+
+/// Calls the connector for a relay made against a payment and builds the update of the relay from
+/// the connector response
+async fn execute_relay_payment_flow<F, Req>(
+    state: &SessionState,
+    relay_record: &relay::Relay,
+    connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        F,
+        Req,
+        hyperswitch_domain_models::router_response_types::PaymentsResponseData,
+    >,
+    router_data: hyperswitch_domain_models::router_data::RouterData<
+        F,
+        Req,
+        hyperswitch_domain_models::router_response_types::PaymentsResponseData,
+    >,
+) -> RouterResult<relay::RelayUpdate>
+where
+    F: Clone + std::fmt::Debug + Send + Sync + 'static,
+    Req: Clone + std::fmt::Debug + Send + Sync + 'static,
+{
+    let router_data_res = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+        None,
+    )
+    .await
+    .to_payment_failed_response()?;
+
+    Ok(relay::RelayUpdate::from_payments_response(
+        relay_record.relay_type,
+        router_data_res.response,
+        router_data_res.status,
+    ))
+}
+
 pub struct RelayRefund;
 
 #[async_trait]
@@ -194,28 +411,264 @@ impl RelayInterface for RelayRefund {
             relay_type: value.relay_type,
             data: Some(data),
             connector_reference_id: value.connector_reference_id,
+            connector_payment_status: None,
         })
     }
 }
 
+pub struct RelayCapture;
+
+#[async_trait]
+impl RelayInterface for RelayCapture {
+    type Request = relay_api_models::RelayCaptureRequestData;
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        let relay_capture = relay::RelayCaptureData::from(relay_request.data.clone());
+        construct_relay_domain_model(
+            &relay_request,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::Capture,
+            relay::RelayData::Capture(relay_capture),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+        let connector_data = get_connector_data_for_relay(state, &connector_account, relay_record)?;
+
+        let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+            api::Capture,
+            hyperswitch_domain_models::router_request_types::PaymentsCaptureData,
+            hyperswitch_domain_models::router_response_types::PaymentsResponseData,
+        > = connector_data.connector.get_connector_integration();
+
+        let router_data = utils::construct_relay_capture_router_data(
+            state,
+            merchant_id,
+            &connector_account,
+            relay_record,
+        )
+        .await?;
+
+        execute_relay_payment_flow(state, relay_record, connector_integration, router_data).await
+    }
+
+    fn generate_response(value: relay::Relay) -> RouterResult<api_models::relay::RelayResponse> {
+        Ok(api_models::relay::RelayResponse::from(value))
+    }
+}
+
+pub struct RelayVoid;
+
+#[async_trait]
+impl RelayInterface for RelayVoid {
+    type Request = relay_api_models::RelayVoidRequestData;
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        let relay_void = relay::RelayVoidData::from(relay_request.data.clone());
+        construct_relay_domain_model(
+            &relay_request,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::Void,
+            relay::RelayData::Void(relay_void),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+        let connector_data = get_connector_data_for_relay(state, &connector_account, relay_record)?;
+
+        let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+            api::Void,
+            hyperswitch_domain_models::router_request_types::PaymentsCancelData,
+            hyperswitch_domain_models::router_response_types::PaymentsResponseData,
+        > = connector_data.connector.get_connector_integration();
+
+        let router_data = utils::construct_relay_void_router_data(
+            state,
+            merchant_id,
+            &connector_account,
+            relay_record,
+        )
+        .await?;
+
+        execute_relay_payment_flow(state, relay_record, connector_integration, router_data).await
+    }
+
+    fn generate_response(value: relay::Relay) -> RouterResult<api_models::relay::RelayResponse> {
+        Ok(api_models::relay::RelayResponse::from(value))
+    }
+}
+
+pub struct RelayIncrementalAuthorization;
+
+#[async_trait]
+impl RelayInterface for RelayIncrementalAuthorization {
+    type Request = relay_api_models::RelayIncrementalAuthorizationRequestData;
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        let relay_incremental_authorization =
+            relay::RelayIncrementalAuthorizationData::from(relay_request.data.clone());
+        construct_relay_domain_model(
+            &relay_request,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::IncrementalAuthorization,
+            relay::RelayData::IncrementalAuthorization(relay_incremental_authorization),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+        let connector_data = get_connector_data_for_relay(state, &connector_account, relay_record)?;
+
+        let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+            api::IncrementalAuthorization,
+            hyperswitch_domain_models::router_request_types::PaymentsIncrementalAuthorizationData,
+            hyperswitch_domain_models::router_response_types::PaymentsResponseData,
+        > = connector_data.connector.get_connector_integration();
+
+        let router_data = utils::construct_relay_incremental_authorization_router_data(
+            state,
+            merchant_id,
+            &connector_account,
+            relay_record,
+        )
+        .await?;
+
+        execute_relay_payment_flow(state, relay_record, connector_integration, router_data).await
+    }
+
+    fn generate_response(value: relay::Relay) -> RouterResult<api_models::relay::RelayResponse> {
+        Ok(api_models::relay::RelayResponse::from(value))
+    }
+}
+
+pub struct RelaySync;
+
+#[async_trait]
+impl RelayInterface for RelaySync {
+    type Request = relay_api_models::RelaySyncRequestData;
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        let relay_sync = relay::RelaySyncData::from(relay_request.data.clone());
+        construct_relay_domain_model(
+            &relay_request,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::Sync,
+            relay::RelayData::Sync(relay_sync),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        sync_relay_payment_with_gateway(state, &merchant_context, relay_record, connector_account)
+            .await
+    }
+
+    fn generate_response(value: relay::Relay) -> RouterResult<api_models::relay::RelayResponse> {
+        Ok(api_models::relay::RelayResponse::from(value))
+    }
+}
+
 pub async fn relay_flow_decider(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     profile_id_optional: Option<id_type::ProfileId>,
     request: relay_api_models::RelayRequest,
 ) -> RouterResponse<relay_api_models::RelayResponse> {
-    let relay_flow_request = match request.relay_type {
+    match request.relay_type {
         common_enums::RelayType::Refund => {
-            RelayRequestInner::<RelayRefund>::from_relay_request(request)?
+            let relay_flow_request = RelayRequestInner::<RelayRefund>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
         }
-    };
-    relay(
-        state,
-        merchant_context,
-        profile_id_optional,
-        relay_flow_request,
-    )
-    .await
+        common_enums::RelayType::Capture => {
+            let relay_flow_request =
+                RelayRequestInner::<RelayCapture>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+        common_enums::RelayType::Void => {
+            let relay_flow_request = RelayRequestInner::<RelayVoid>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+        common_enums::RelayType::IncrementalAuthorization => {
+            let relay_flow_request =
+                RelayRequestInner::<RelayIncrementalAuthorization>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+        common_enums::RelayType::Sync => {
+            let relay_flow_request = RelayRequestInner::<RelaySync>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+    }
 }
 
 pub async fn relay<T: RelayInterface>(
@@ -392,6 +845,32 @@ pub async fn relay_retrieve(
                 )
                 .await?;
 
+                db.update_relay(
+                    key_manager_state,
+                    merchant_context.get_merchant_key_store(),
+                    relay_record,
+                    relay_response,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the relay record")?
+            } else {
+                relay_record
+            }
+        }
+        common_enums::RelayType::Capture
+        | common_enums::RelayType::Void
+        | common_enums::RelayType::IncrementalAuthorization
+        | common_enums::RelayType::Sync => {
+            if should_call_connector_for_relay_payment_status(&relay_record, req.force_sync) {
+                let relay_response = sync_relay_payment_with_gateway(
+                    &state,
+                    &merchant_context,
+                    &relay_record,
+                    connector_account,
+                )
+                .await?;
+
                 db.update_relay(
                     key_manager_state,
                     merchant_context.get_merchant_key_store(),
@@ -420,6 +899,57 @@ fn should_call_connector_for_relay_refund_status(relay: &relay::Relay, force_syn
     !matches!(relay.status, RelayStatus::Failure | RelayStatus::Success) && force_sync
 }
 
+fn should_call_connector_for_relay_payment_status(relay: &relay::Relay, force_sync: bool) -> bool {
+    // A sync relay reflects the latest status of the payment, so it is always synced when
+    // force_sync is enabled, the other relays are synced until they reach a terminal state
+    force_sync
+        && (relay.relay_type == common_enums::RelayType::Sync
+            || !matches!(relay.status, RelayStatus::Failure | RelayStatus::Success))
+}
+
+/// Syncs the payment against which the relay was made and updates the status of the relay from
+/// the status of the payment at the connector
+pub async fn sync_relay_payment_with_gateway(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    relay_record: &relay::Relay,
+    connector_account: domain::MerchantConnectorAccount,
+) -> RouterResult<relay::RelayUpdate> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let connector_data = get_connector_data_for_relay(state, &connector_account, relay_record)?;
+
+    let router_data = utils::construct_relay_sync_router_data(
+        state,
+        merchant_id,
+        &connector_account,
+        relay_record,
+    )
+    .await?;
+
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::PSync,
+        hyperswitch_domain_models::router_request_types::PaymentsSyncData,
+        hyperswitch_domain_models::router_response_types::PaymentsResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let relay_update =
+        execute_relay_payment_flow(state, relay_record, connector_integration, router_data).await?;
+
+    // The response of an incremental authorization sync is the status of the payment, which does
+    // not decide whether the additional amount was authorized
+    Ok(match (relay_record.relay_type, relay_update) {
+        (
+            common_enums::RelayType::IncrementalAuthorization,
+            relay::RelayUpdate::StatusUpdate { response_data, .. },
+        ) => relay::RelayUpdate::StatusUpdate {
+            connector_reference_id: relay_record.connector_reference_id.clone(),
+            status: relay_record.status,
+            response_data,
+        },
+        (_, relay_update) => relay_update,
+    })
+}
+
 pub async fn sync_relay_refund_with_gateway(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
@@ -23,6 +23,270 @@ pub async fn construct_relay_refund_router_data<F>(
     connector_account: &domain::MerchantConnectorAccount,
     relay_record: &hyperswitch_domain_models::relay::Relay,
 ) -> RouterResult<types::RefundsRouterData<F>> {
+    let hyperswitch_domain_models::relay::RelayData::Refund(relay_refund_data) = relay_record
+        .request_data
+        .clone()
+        .get_required_value("refund relay data")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to obtain relay data to construct relay refund data")?
+    else {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Relay data does not belong to a refund relay");
+    };
+
+    let relay_id_string = relay_record.id.get_string_repr().to_string();
+
+    let request = hyperswitch_domain_models::router_request_types::RefundsData {
+        refund_id: relay_id_string.clone(),
+        connector_transaction_id: relay_record.connector_resource_id.clone(),
+        refund_amount: relay_refund_data.amount.get_amount_as_i64(),
+        minor_refund_amount: relay_refund_data.amount,
+        currency: relay_refund_data.currency,
+        payment_amount: relay_refund_data.amount.get_amount_as_i64(),
+        minor_payment_amount: relay_refund_data.amount,
+        webhook_url: Some(get_relay_webhook_url(state, merchant_id, connector_account)),
+        connector_metadata: None,
+        refund_connector_metadata: None,
+        reason: relay_refund_data.reason,
+        connector_refund_id: relay_record.connector_reference_id.clone(),
+        browser_info: None,
+        split_refunds: None,
+        integrity_object: None,
+        refund_status: common_enums::RefundStatus::from(relay_record.status),
+        merchant_account_id: None,
+        merchant_config_currency: None,
+        capture_method: None,
+        additional_payment_method_data: None,
+    };
+
+    let mut router_data = construct_relay_router_data(
+        state,
+        merchant_id,
+        connector_account,
+        relay_record,
+        common_enums::AttemptStatus::Charged,
+        request,
+    )
+    .await?;
+    router_data.refund_id = Some(relay_id_string);
+
+    Ok(router_data)
+}
+
+pub async fn construct_relay_capture_router_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &hyperswitch_domain_models::relay::Relay,
+) -> RouterResult<types::PaymentsCaptureRouterData> {
+    let hyperswitch_domain_models::relay::RelayData::Capture(relay_capture_data) = relay_record
+        .request_data
+        .clone()
+        .get_required_value("capture relay data")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to obtain relay data to construct relay capture data")?
+    else {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Relay data does not belong to a capture relay");
+    };
+
+    let request = hyperswitch_domain_models::router_request_types::PaymentsCaptureData {
+        amount_to_capture: relay_capture_data.amount_to_capture.get_amount_as_i64(),
+        minor_amount_to_capture: relay_capture_data.amount_to_capture,
+        currency: relay_capture_data.currency,
+        connector_transaction_id: relay_record.connector_resource_id.clone(),
+        payment_amount: relay_capture_data.authorized_amount.get_amount_as_i64(),
+        minor_payment_amount: relay_capture_data.authorized_amount,
+        webhook_url: Some(get_relay_webhook_url(state, merchant_id, connector_account)),
+        capture_method: Some(common_enums::CaptureMethod::Manual),
+        ..Default::default()
+    };
+
+    construct_relay_router_data(
+        state,
+        merchant_id,
+        connector_account,
+        relay_record,
+        common_enums::AttemptStatus::Authorized,
+        request,
+    )
+    .await
+}
+
+pub async fn construct_relay_void_router_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &hyperswitch_domain_models::relay::Relay,
+) -> RouterResult<types::PaymentsCancelRouterData> {
+    let hyperswitch_domain_models::relay::RelayData::Void(relay_void_data) = relay_record
+        .request_data
+        .clone()
+        .get_required_value("void relay data")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to obtain relay data to construct relay void data")?
+    else {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Relay data does not belong to a void relay");
+    };
+
+    let request = hyperswitch_domain_models::router_request_types::PaymentsCancelData {
+        amount: relay_void_data
+            .amount
+            .map(|amount| amount.get_amount_as_i64()),
+        minor_amount: relay_void_data.amount,
+        currency: relay_void_data.currency,
+        connector_transaction_id: relay_record.connector_resource_id.clone(),
+        cancellation_reason: relay_void_data.cancellation_reason,
+        webhook_url: Some(get_relay_webhook_url(state, merchant_id, connector_account)),
+        ..Default::default()
+    };
+
+    construct_relay_router_data(
+        state,
+        merchant_id,
+        connector_account,
+        relay_record,
+        common_enums::AttemptStatus::Authorized,
+        request,
+    )
+    .await
+}
+
+pub async fn construct_relay_incremental_authorization_router_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &hyperswitch_domain_models::relay::Relay,
+) -> RouterResult<types::PaymentsIncrementalAuthorizationRouterData> {
+    let hyperswitch_domain_models::relay::RelayData::IncrementalAuthorization(
+        relay_incremental_authorization_data,
+    ) = relay_record
+        .request_data
+        .clone()
+        .get_required_value("incremental authorization relay data")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to obtain relay data to construct relay incremental authorization data",
+        )?
+    else {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Relay data does not belong to an incremental authorization relay");
+    };
+
+    let request =
+        hyperswitch_domain_models::router_request_types::PaymentsIncrementalAuthorizationData {
+            total_amount: relay_incremental_authorization_data
+                .total_amount
+                .get_amount_as_i64(),
+            additional_amount: relay_incremental_authorization_data
+                .additional_amount
+                .get_amount_as_i64(),
+            currency: relay_incremental_authorization_data.currency,
+            reason: relay_incremental_authorization_data.reason,
+            connector_transaction_id: relay_record.connector_resource_id.clone(),
+            connector_meta: None,
+        };
+
+    construct_relay_router_data(
+        state,
+        merchant_id,
+        connector_account,
+        relay_record,
+        common_enums::AttemptStatus::Authorized,
+        request,
+    )
+    .await
+}
+
+pub async fn construct_relay_sync_router_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &hyperswitch_domain_models::relay::Relay,
+) -> RouterResult<types::PaymentsSyncRouterData> {
+    let (amount, currency, capture_method) = match relay_record
+        .request_data
+        .clone()
+        .get_required_value("relay data")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to obtain relay data to construct relay sync data")?
+    {
+        hyperswitch_domain_models::relay::RelayData::Sync(relay_sync_data) => (
+            relay_sync_data.amount,
+            relay_sync_data.currency,
+            relay_sync_data.capture_method,
+        ),
+        hyperswitch_domain_models::relay::RelayData::Capture(relay_capture_data) => (
+            relay_capture_data.amount_to_capture,
+            relay_capture_data.currency,
+            Some(common_enums::CaptureMethod::Manual),
+        ),
+        hyperswitch_domain_models::relay::RelayData::IncrementalAuthorization(
+            relay_incremental_authorization_data,
+        ) => (
+            relay_incremental_authorization_data.total_amount,
+            relay_incremental_authorization_data.currency,
+            Some(common_enums::CaptureMethod::Manual),
+        ),
+        hyperswitch_domain_models::relay::RelayData::Void(relay_void_data) => (
+            relay_void_data.amount.unwrap_or_default(),
+            relay_void_data
+                .currency
+                .get_required_value("currency")
+                .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Void relay without a currency cannot be synced".to_string(),
+                })?,
+            Some(common_enums::CaptureMethod::Manual),
+        ),
+        hyperswitch_domain_models::relay::RelayData::Refund(_) => {
+            return Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Refund relay cannot be synced as a payment");
+        }
+    };
+
+    let request = hyperswitch_domain_models::router_request_types::PaymentsSyncData {
+        connector_transaction_id:
+            hyperswitch_domain_models::router_request_types::ResponseId::ConnectorTransactionId(
+                relay_record.connector_resource_id.clone(),
+            ),
+        capture_method,
+        currency,
+        amount,
+        ..Default::default()
+    };
+
+    construct_relay_router_data(
+        state,
+        merchant_id,
+        connector_account,
+        relay_record,
+        common_enums::AttemptStatus::Pending,
+        request,
+    )
+    .await
+}
+
+fn get_relay_webhook_url(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+) -> String {
+    payments::helpers::create_webhook_url(
+        &state.base_url.clone(),
+        merchant_id,
+        connector_account.get_id().get_string_repr(),
+    )
+}
+
+async fn construct_relay_router_data<F, Req, Resp>(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &hyperswitch_domain_models::relay::Relay,
+    status: common_enums::AttemptStatus,
+    request: Req,
+) -> RouterResult<hyperswitch_domain_models::router_data::RouterData<F, Req, Resp>> {
     let connector_auth_type = connector_account
         .get_connector_account_details()
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -34,12 +298,6 @@ pub async fn construct_relay_refund_router_data<F>(
     #[cfg(feature = "v1")]
     let connector_name = &connector_account.connector_name;
 
-    let webhook_url = Some(payments::helpers::create_webhook_url(
-        &state.base_url.clone(),
-        merchant_id,
-        connector_account.get_id().get_string_repr(),
-    ));
-
     let supported_connector = &state
         .conf
         .multiple_api_version_supported_connectors
@@ -63,13 +321,6 @@ pub async fn construct_relay_refund_router_data<F>(
         None
     };
 
-    let hyperswitch_domain_models::relay::RelayData::Refund(relay_refund_data) = relay_record
-        .request_data
-        .clone()
-        .get_required_value("refund relay data")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to obtain relay data to construct relay refund data")?;
-
     let relay_id_string = relay_record.id.get_string_repr().to_string();
 
     let router_data = hyperswitch_domain_models::router_data::RouterData {
@@ -80,7 +331,7 @@ pub async fn construct_relay_refund_router_data<F>(
         connector: connector_name.to_string(),
         payment_id: IRRELEVANT_PAYMENT_INTENT_ID.to_string(),
         attempt_id: IRRELEVANT_PAYMENT_ATTEMPT_ID.to_string(),
-        status: common_enums::AttemptStatus::Charged,
+        status,
         payment_method: common_enums::PaymentMethod::default(),
         connector_auth_type,
         description: None,
@@ -91,29 +342,7 @@ pub async fn construct_relay_refund_router_data<F>(
         amount_captured: None,
         payment_method_status: None,
         minor_amount_captured: None,
-        request: hyperswitch_domain_models::router_request_types::RefundsData {
-            refund_id: relay_id_string.clone(),
-            connector_transaction_id: relay_record.connector_resource_id.clone(),
-            refund_amount: relay_refund_data.amount.get_amount_as_i64(),
-            minor_refund_amount: relay_refund_data.amount,
-            currency: relay_refund_data.currency,
-            payment_amount: relay_refund_data.amount.get_amount_as_i64(),
-            minor_payment_amount: relay_refund_data.amount,
-            webhook_url,
-            connector_metadata: None,
-            refund_connector_metadata: None,
-            reason: relay_refund_data.reason,
-            connector_refund_id: relay_record.connector_reference_id.clone(),
-            browser_info: None,
-            split_refunds: None,
-            integrity_object: None,
-            refund_status: common_enums::RefundStatus::from(relay_record.status),
-            merchant_account_id: None,
-            merchant_config_currency: None,
-            capture_method: None,
-            additional_payment_method_data: None,
-        },
-
+        request,
         response: Err(ErrorResponse::default()),
         access_token: None,
         session_token: None,
@@ -122,7 +351,7 @@ pub async fn construct_relay_refund_router_data<F>(
         connector_customer: None,
         recurring_mandate_payment_data: None,
         preprocessing_id: None,
-        connector_request_reference_id: relay_id_string.clone(),
+        connector_request_reference_id: relay_id_string,
         #[cfg(feature = "payouts")]
         payout_method_data: None,
         #[cfg(feature = "payouts")]
@@ -134,7 +363,7 @@ pub async fn construct_relay_refund_router_data<F>(
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
-        refund_id: Some(relay_id_string),
+        refund_id: None,
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
@@ -145,6 +374,7 @@ pub async fn construct_relay_refund_router_data<F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
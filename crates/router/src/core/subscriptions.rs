@@ -0,0 +1,2103 @@
+use api_models::{
+    mandates::RecurringDetails,
+    payments::{PaymentsRequest, PaymentsResponse},
+    subscriptions::{
+        InvoiceId, InvoiceListResponse, InvoiceResponse, SubscriptionBillingPTData,
+        SubscriptionCancelRequest, SubscriptionCreateRequest, SubscriptionId,
+        SubscriptionListConstraints, SubscriptionListResponse, SubscriptionPaginationConstraints,
+        SubscriptionPriceCreateRequest, SubscriptionPriceId, SubscriptionPriceListConstraints,
+        SubscriptionPriceListResponse, SubscriptionPriceResponse, SubscriptionPriceUpdateRequest,
+        SubscriptionProductCreateRequest, SubscriptionProductId, SubscriptionProductListResponse,
+        SubscriptionProductResponse, SubscriptionProductUpdateRequest, SubscriptionResponse,
+        SubscriptionUpdateRequest,
+    },
+};
+use common_utils::{errors::ErrorSwitch, id_type, types::MinorUnit};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils, webhooks,
+    },
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{api, domain, storage},
+    utils,
+};
+
+const SUBSCRIPTION_BILLING_TASK: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_PRODUCT_ID_PREFIX: &str = "prod";
+const SUBSCRIPTION_PRICE_ID_PREFIX: &str = "price";
+const SUBSCRIPTION_ID_PREFIX: &str = "sub";
+const INVOICE_ID_PREFIX: &str = "inv";
+const MAX_NAME_LENGTH: usize = 255;
+const MAX_TRIAL_PERIOD_DAYS: i32 = 730;
+const MAX_BILLING_PERIOD_DAYS: i64 = 3 * 366;
+
+/// Days after a failed attempt at which an invoice is charged again. An invoice is marked as
+/// uncollectible once all the retries have failed
+const INVOICE_RETRY_DELAYS_IN_DAYS: [i64; 3] = [1, 3, 5];
+
+/// Interval at which the status of a payment that is still being processed is checked again
+const PENDING_PAYMENT_CHECK_INTERVAL_IN_MINUTES: i64 = 60;
+
+#[instrument(skip_all)]
+pub async fn create_subscription_product(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SubscriptionProductCreateRequest,
+) -> RouterResponse<SubscriptionProductResponse> {
+    validate_name(&request.name)?;
+
+    let now = common_utils::date_time::now();
+    let product_new = storage::SubscriptionProductNew {
+        product_id: utils::generate_id(consts::ID_LENGTH, SUBSCRIPTION_PRODUCT_ID_PREFIX),
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        name: request.name,
+        description: request.description,
+        is_active: true,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let product = state
+        .store
+        .insert_subscription_product(product_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the subscription product")?;
+
+    Ok(services::ApplicationResponse::Json(get_product_response(
+        product,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription_product(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SubscriptionProductId,
+) -> RouterResponse<SubscriptionProductResponse> {
+    let product = find_product(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+        &request.product_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(get_product_response(
+        product,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn update_subscription_product(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    product_id: String,
+    request: SubscriptionProductUpdateRequest,
+) -> RouterResponse<SubscriptionProductResponse> {
+    let db = state.store.as_ref();
+    let product = find_product(
+        db,
+        merchant_context.get_merchant_account().get_id(),
+        &product_id,
+    )
+    .await?;
+
+    if let Some(name) = &request.name {
+        validate_name(name)?;
+    }
+
+    let product = db
+        .update_subscription_product(
+            product,
+            storage::SubscriptionProductUpdate::Update {
+                name: request.name,
+                description: request.description,
+                is_active: request.is_active,
+                metadata: request.metadata,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the subscription product")?;
+
+    Ok(services::ApplicationResponse::Json(get_product_response(
+        product,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_products(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: SubscriptionPaginationConstraints,
+) -> RouterResponse<SubscriptionProductListResponse> {
+    let products = state
+        .store
+        .list_subscription_products_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            Some(get_list_limit(constraints.limit)),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the subscription products")?;
+
+    let data = products
+        .into_iter()
+        .map(get_product_response)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        SubscriptionProductListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription_price(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SubscriptionPriceCreateRequest,
+) -> RouterResponse<SubscriptionPriceResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let interval_count = request.interval_count.unwrap_or(1);
+
+    if request.amount < MinorUnit::zero() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must not be negative".to_string(),
+        }
+        .into());
+    }
+    validate_billing_period(request.billing_interval, interval_count)?;
+    if let Some(trial_period_days) = request.trial_period_days {
+        validate_trial_period_days(trial_period_days)?;
+    }
+
+    let product = find_product(db, merchant_id, &request.product_id).await?;
+
+    let now = common_utils::date_time::now();
+    let price_new = storage::SubscriptionPriceNew {
+        price_id: utils::generate_id(consts::ID_LENGTH, SUBSCRIPTION_PRICE_ID_PREFIX),
+        merchant_id: merchant_id.clone(),
+        product_id: product.product_id,
+        amount: request.amount,
+        currency: request.currency,
+        billing_interval: request.billing_interval,
+        interval_count,
+        trial_period_days: request.trial_period_days,
+        is_active: true,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let price = db
+        .insert_subscription_price(price_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the subscription price")?;
+
+    Ok(services::ApplicationResponse::Json(get_price_response(
+        price,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription_price(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SubscriptionPriceId,
+) -> RouterResponse<SubscriptionPriceResponse> {
+    let price = find_price(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+        &request.price_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(get_price_response(
+        price,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn update_subscription_price(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    price_id: String,
+    request: SubscriptionPriceUpdateRequest,
+) -> RouterResponse<SubscriptionPriceResponse> {
+    let db = state.store.as_ref();
+    let price = find_price(
+        db,
+        merchant_context.get_merchant_account().get_id(),
+        &price_id,
+    )
+    .await?;
+
+    let price = db
+        .update_subscription_price(
+            price,
+            storage::SubscriptionPriceUpdate::Update {
+                is_active: request.is_active,
+                metadata: request.metadata,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the subscription price")?;
+
+    Ok(services::ApplicationResponse::Json(get_price_response(
+        price,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_prices(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: SubscriptionPriceListConstraints,
+) -> RouterResponse<SubscriptionPriceListResponse> {
+    let prices = state
+        .store
+        .list_subscription_prices_by_merchant_id_product_id(
+            merchant_context.get_merchant_account().get_id(),
+            &constraints.product_id,
+            Some(get_list_limit(constraints.limit)),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the subscription prices")?;
+
+    let data = prices
+        .into_iter()
+        .map(get_price_response)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        SubscriptionPriceListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SubscriptionCreateRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let quantity = request.quantity.unwrap_or(1);
+
+    validate_quantity(quantity)?;
+    if let Some(trial_period_days) = request.trial_period_days {
+        validate_trial_period_days(trial_period_days)?;
+    }
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        request.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    db.find_customer_by_customer_id_merchant_id(
+        &(&state).into(),
+        &request.customer_id,
+        &merchant_id,
+        merchant_context.get_merchant_key_store(),
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let price = find_price(db, &merchant_id, &request.price_id).await?;
+    let product = find_product(db, &merchant_id, &price.product_id).await?;
+    if !price.is_active || !product.is_active {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The price is not active".to_string(),
+        }
+        .into());
+    }
+
+    validate_payment_method(
+        &state,
+        &merchant_context,
+        &request.payment_method_id,
+        &request.customer_id,
+    )
+    .await?;
+
+    let first_period_amount = get_period_amount(&price, quantity)?;
+
+    let now = common_utils::date_time::now();
+    let trial_end = request
+        .trial_period_days
+        .or(price.trial_period_days)
+        .filter(|trial_period_days| *trial_period_days > 0)
+        .map(|trial_period_days| now + time::Duration::days(trial_period_days.into()));
+
+    // The billing periods start at the end of the trial, a subscription without a trial is
+    // invoiced for its first period right away
+    let (status, billing_cycle_anchor, current_period_end, latest_invoice_id) = match trial_end {
+        Some(trial_end) => (
+            storage_enums::SubscriptionStatus::Trialing,
+            trial_end,
+            trial_end,
+            None,
+        ),
+        None => (
+            storage_enums::SubscriptionStatus::Incomplete,
+            now,
+            get_period_end_after(now, price.billing_interval, price.interval_count, now)
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to compute the end of the billing period")?,
+            Some(utils::generate_id(consts::ID_LENGTH, INVOICE_ID_PREFIX)),
+        ),
+    };
+
+    let subscription_new = storage::SubscriptionNew {
+        subscription_id: utils::generate_id(consts::ID_LENGTH, SUBSCRIPTION_ID_PREFIX),
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        customer_id: request.customer_id,
+        price_id: price.price_id.clone(),
+        pending_price_id: None,
+        payment_method_id: request.payment_method_id,
+        status,
+        quantity,
+        billing_cycle_anchor,
+        current_period_start: now,
+        current_period_end,
+        trial_start: trial_end.map(|_| now),
+        trial_end,
+        cancel_at_period_end: false,
+        cancelled_at: None,
+        pending_proration_amount: MinorUnit::zero(),
+        latest_invoice_id: latest_invoice_id.clone(),
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let subscription = db
+        .insert_subscription(subscription_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the subscription")?;
+
+    trigger_subscription_webhook(
+        &state,
+        &merchant_context,
+        &subscription,
+        storage_enums::EventType::SubscriptionCreated,
+    )
+    .await;
+
+    let (subscription, next_billing_at) = match latest_invoice_id {
+        Some(invoice_id) => {
+            let invoice = create_invoice(
+                &state,
+                &merchant_context,
+                &subscription,
+                InvoiceDetails {
+                    invoice_id,
+                    billing_reason: storage_enums::InvoiceBillingReason::SubscriptionCreate,
+                    amount: first_period_amount,
+                    proration_amount: MinorUnit::zero(),
+                    currency: price.currency,
+                    period_start: subscription.current_period_start,
+                    period_end: subscription.current_period_end,
+                },
+            )
+            .await?;
+            let (subscription, invoice) =
+                collect_invoice(&state, &merchant_context, subscription, invoice).await?;
+            let next_billing_at = get_next_billing_time(&subscription, &invoice);
+            (subscription, next_billing_at)
+        }
+        None => {
+            let next_billing_at = Some(subscription.current_period_end);
+            (subscription, next_billing_at)
+        }
+    };
+
+    if let Some(next_billing_at) = next_billing_at {
+        add_subscription_billing_task_to_pt(db, &subscription, next_billing_at).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SubscriptionId,
+) -> RouterResponse<SubscriptionResponse> {
+    let subscription = find_subscription(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+        &request.subscription_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    subscription_id: String,
+    request: SubscriptionUpdateRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let subscription = find_subscription(db, merchant_id, &subscription_id).await?;
+
+    if !subscription.status.is_billable()
+        && subscription.status != storage_enums::SubscriptionStatus::Incomplete
+    {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A subscription in the {} status cannot be updated",
+                subscription.status
+            ),
+        }
+        .into());
+    }
+
+    if let Some(payment_method_id) = &request.payment_method_id {
+        validate_payment_method(
+            &state,
+            &merchant_context,
+            payment_method_id,
+            &subscription.customer_id,
+        )
+        .await?;
+    }
+
+    let mut subscription = subscription;
+    let is_plan_change = request
+        .price_id
+        .as_ref()
+        .is_some_and(|price_id| *price_id != subscription.price_id)
+        || request
+            .quantity
+            .is_some_and(|quantity| quantity != subscription.quantity);
+
+    if is_plan_change {
+        subscription = change_subscription_plan(
+            &state,
+            &merchant_context,
+            subscription,
+            request.price_id,
+            request.quantity,
+            request.proration_behavior.unwrap_or_default(),
+        )
+        .await?;
+    }
+
+    if request.payment_method_id.is_some()
+        || request.cancel_at_period_end.is_some()
+        || request.metadata.is_some()
+    {
+        subscription = db
+            .update_subscription(
+                subscription,
+                storage::SubscriptionUpdate::Update {
+                    payment_method_id: request.payment_method_id,
+                    cancel_at_period_end: request.cancel_at_period_end,
+                    metadata: request.metadata,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the subscription")?;
+    }
+
+    trigger_subscription_webhook(
+        &state,
+        &merchant_context,
+        &subscription,
+        storage_enums::EventType::SubscriptionUpdated,
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn cancel_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    subscription_id: String,
+    request: SubscriptionCancelRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let subscription = find_subscription(
+        db,
+        merchant_context.get_merchant_account().get_id(),
+        &subscription_id,
+    )
+    .await?;
+
+    if subscription.status == storage_enums::SubscriptionStatus::Cancelled {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The subscription is already cancelled".to_string(),
+        }
+        .into());
+    }
+
+    let subscription = if request.at_period_end && subscription.status.is_billable() {
+        let subscription = db
+            .update_subscription(
+                subscription,
+                storage::SubscriptionUpdate::Update {
+                    payment_method_id: None,
+                    cancel_at_period_end: Some(true),
+                    metadata: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the subscription")?;
+        trigger_subscription_webhook(
+            &state,
+            &merchant_context,
+            &subscription,
+            storage_enums::EventType::SubscriptionUpdated,
+        )
+        .await;
+        subscription
+    } else {
+        cancel_subscription_now(&state, &merchant_context, subscription).await?
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscriptions(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: SubscriptionListConstraints,
+) -> RouterResponse<SubscriptionListResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let limit = Some(get_list_limit(constraints.limit));
+
+    let subscriptions = match &constraints.customer_id {
+        Some(customer_id) => {
+            db.list_subscriptions_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                limit,
+                constraints.offset,
+            )
+            .await
+        }
+        None => {
+            db.list_subscriptions_by_merchant_id(merchant_id, limit, constraints.offset)
+                .await
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to list the subscriptions")?;
+
+    let data = subscriptions
+        .into_iter()
+        .map(get_subscription_response)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        SubscriptionListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_invoice(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: InvoiceId,
+) -> RouterResponse<InvoiceResponse> {
+    let invoice = state
+        .store
+        .find_subscription_invoice_by_merchant_id_invoice_id(
+            merchant_context.get_merchant_account().get_id(),
+            &request.invoice_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Invoice does not exist".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(get_invoice_response(
+        invoice,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_invoices(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    subscription_id: String,
+    constraints: SubscriptionPaginationConstraints,
+) -> RouterResponse<InvoiceListResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let subscription = find_subscription(db, merchant_id, &subscription_id).await?;
+
+    let invoices = db
+        .list_subscription_invoices_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription.subscription_id,
+            Some(get_list_limit(constraints.limit)),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the invoices of the subscription")?;
+
+    let data = invoices
+        .into_iter()
+        .map(get_invoice_response)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(InvoiceListResponse {
+        size: data.len(),
+        data,
+    }))
+}
+
+/// Charges the open invoice of the subscription when its next attempt is due, or starts the next
+/// billing period once the current one has ended.
+/// Returns the time at which the subscription has to be billed next, or `None` if it is no
+/// longer billed.
+#[instrument(skip_all)]
+pub async fn run_subscription_billing(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &SubscriptionBillingPTData,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let subscription = match db
+        .find_subscription_by_merchant_id_subscription_id(
+            &tracking_data.merchant_id,
+            &tracking_data.subscription_id,
+        )
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the subscription")
+        }
+    };
+
+    if matches!(
+        subscription.status,
+        storage_enums::SubscriptionStatus::Cancelled | storage_enums::SubscriptionStatus::Unpaid
+    ) {
+        return Ok(None);
+    }
+
+    let now = common_utils::date_time::now();
+    let open_invoice = db
+        .find_open_subscription_invoices_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the open invoices of the subscription")?
+        .into_iter()
+        .next();
+
+    if let Some(invoice) = open_invoice {
+        if let Some(next_payment_attempt_at) = invoice
+            .next_payment_attempt_at
+            .filter(|next_payment_attempt_at| *next_payment_attempt_at > now)
+        {
+            return Ok(Some(next_payment_attempt_at));
+        }
+        let (subscription, invoice) =
+            collect_invoice(state, merchant_context, subscription, invoice).await?;
+        return Ok(get_next_billing_time(&subscription, &invoice));
+    }
+
+    if !subscription.status.is_billable() {
+        return Ok(None);
+    }
+    if now < subscription.current_period_end {
+        return Ok(Some(subscription.current_period_end));
+    }
+    if subscription.cancel_at_period_end {
+        cancel_subscription_now(state, merchant_context, subscription).await?;
+        return Ok(None);
+    }
+
+    let (subscription, invoice) =
+        start_next_billing_period(state, merchant_context, subscription).await?;
+    let (subscription, invoice) =
+        collect_invoice(state, merchant_context, subscription, invoice).await?;
+    Ok(get_next_billing_time(&subscription, &invoice))
+}
+
+/// Moves the subscription to its next billing period, switching to the pending price if any,
+/// and creates the invoice of the period with the pending prorations
+async fn start_next_billing_period(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+) -> RouterResult<(storage::Subscription, storage::SubscriptionInvoice)> {
+    let db = state.store.as_ref();
+    let price_id = subscription
+        .pending_price_id
+        .clone()
+        .unwrap_or_else(|| subscription.price_id.clone());
+    let price = find_price(db, &subscription.merchant_id, &price_id).await?;
+
+    let period_start = subscription.current_period_end;
+    let period_end = get_period_end_after(
+        subscription.billing_cycle_anchor,
+        price.billing_interval,
+        price.interval_count,
+        period_start,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to compute the end of the billing period")?;
+
+    // Credits larger than the amount of the period are carried over to the next invoice
+    let pending_proration_amount = subscription.pending_proration_amount;
+    let total_amount = get_period_amount(&price, subscription.quantity)? + pending_proration_amount;
+    let remaining_credit = if total_amount < MinorUnit::zero() {
+        total_amount
+    } else {
+        MinorUnit::zero()
+    };
+
+    let invoice_id = utils::generate_id(consts::ID_LENGTH, INVOICE_ID_PREFIX);
+    let status = subscription.status;
+    let subscription = db
+        .update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::PeriodUpdate {
+                status,
+                price_id: price.price_id.clone(),
+                current_period_start: period_start,
+                current_period_end: period_end,
+                pending_proration_amount: remaining_credit,
+                latest_invoice_id: Some(invoice_id.clone()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the billing period of the subscription")?;
+
+    let invoice = create_invoice(
+        state,
+        merchant_context,
+        &subscription,
+        InvoiceDetails {
+            invoice_id,
+            billing_reason: storage_enums::InvoiceBillingReason::SubscriptionCycle,
+            amount: total_amount - remaining_credit,
+            proration_amount: pending_proration_amount - remaining_credit,
+            currency: price.currency,
+            period_start,
+            period_end,
+        },
+    )
+    .await?;
+
+    Ok((subscription, invoice))
+}
+
+/// Changes the price or quantity of the subscription, prorating the change over the remaining
+/// part of the current billing period as per the proration behavior
+async fn change_subscription_plan(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    price_id: Option<String>,
+    quantity: Option<i32>,
+    proration_behavior: storage_enums::ProrationBehavior,
+) -> RouterResult<storage::Subscription> {
+    let db = state.store.as_ref();
+    let quantity = quantity.unwrap_or(subscription.quantity);
+    validate_quantity(quantity)?;
+
+    let current_price = find_price(db, &subscription.merchant_id, &subscription.price_id).await?;
+    let new_price = match price_id.filter(|price_id| *price_id != subscription.price_id) {
+        Some(price_id) => {
+            let new_price = find_price(db, &subscription.merchant_id, &price_id).await?;
+            if !new_price.is_active {
+                return Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The price is not active".to_string(),
+                }
+                .into());
+            }
+            if new_price.currency != current_price.currency
+                || new_price.billing_interval != current_price.billing_interval
+                || new_price.interval_count != current_price.interval_count
+            {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The new price must have the same currency and billing period as the current price".to_string(),
+                }
+                .into());
+            }
+            new_price
+        }
+        None => current_price.clone(),
+    };
+
+    // Only the periods which were invoiced are prorated, a change during the trial is free
+    let now = common_utils::date_time::now();
+    let proration_amount = match subscription.status {
+        storage_enums::SubscriptionStatus::Active | storage_enums::SubscriptionStatus::PastDue => {
+            calculate_proration_amount(
+                get_period_amount(&current_price, subscription.quantity)?,
+                get_period_amount(&new_price, quantity)?,
+                subscription.current_period_start,
+                subscription.current_period_end,
+                now,
+            )
+        }
+        storage_enums::SubscriptionStatus::Incomplete
+        | storage_enums::SubscriptionStatus::Trialing
+        | storage_enums::SubscriptionStatus::Unpaid
+        | storage_enums::SubscriptionStatus::Cancelled => MinorUnit::zero(),
+    };
+
+    let pending_proration_amount = subscription.pending_proration_amount;
+    let (subscription_update, invoice_amount) = match proration_behavior {
+        storage_enums::ProrationBehavior::CreateProrations => (
+            storage::SubscriptionUpdate::PriceUpdate {
+                price_id: new_price.price_id.clone(),
+                pending_price_id: None,
+                quantity,
+                pending_proration_amount: pending_proration_amount + proration_amount,
+            },
+            None,
+        ),
+        storage_enums::ProrationBehavior::AlwaysInvoice if proration_amount > MinorUnit::zero() => {
+            (
+                storage::SubscriptionUpdate::PriceUpdate {
+                    price_id: new_price.price_id.clone(),
+                    pending_price_id: None,
+                    quantity,
+                    pending_proration_amount,
+                },
+                Some(proration_amount),
+            )
+        }
+        storage_enums::ProrationBehavior::AlwaysInvoice => (
+            storage::SubscriptionUpdate::PriceUpdate {
+                price_id: new_price.price_id.clone(),
+                pending_price_id: None,
+                quantity,
+                pending_proration_amount: pending_proration_amount + proration_amount,
+            },
+            None,
+        ),
+        storage_enums::ProrationBehavior::None => {
+            let pending_price_id =
+                (new_price.price_id != current_price.price_id).then(|| new_price.price_id.clone());
+            (
+                storage::SubscriptionUpdate::PriceUpdate {
+                    price_id: current_price.price_id.clone(),
+                    pending_price_id,
+                    quantity,
+                    pending_proration_amount,
+                },
+                None,
+            )
+        }
+    };
+
+    let subscription = db
+        .update_subscription(subscription, subscription_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the price of the subscription")?;
+
+    match invoice_amount {
+        Some(amount) => {
+            let invoice = create_invoice(
+                state,
+                merchant_context,
+                &subscription,
+                InvoiceDetails {
+                    invoice_id: utils::generate_id(consts::ID_LENGTH, INVOICE_ID_PREFIX),
+                    billing_reason: storage_enums::InvoiceBillingReason::SubscriptionUpdate,
+                    amount,
+                    proration_amount: amount,
+                    currency: new_price.currency,
+                    period_start: now,
+                    period_end: subscription.current_period_end,
+                },
+            )
+            .await?;
+            let (subscription, invoice) =
+                collect_invoice(state, merchant_context, subscription, invoice).await?;
+            // The billing task is scheduled for the end of the period, it is brought forward so
+            // that the proration invoice is retried when its next attempt is due
+            if let Some(next_payment_attempt_at) = invoice
+                .next_payment_attempt_at
+                .filter(|_| invoice.status == storage_enums::InvoiceStatus::Open)
+            {
+                logger::info!(
+                    invoice_id = %invoice.invoice_id,
+                    "Proration invoice could not be collected and will be retried"
+                );
+                schedule_subscription_billing_task_by(db, &subscription, next_payment_attempt_at)
+                    .await?;
+            }
+            Ok(subscription)
+        }
+        None => Ok(subscription),
+    }
+}
+
+/// Cancels the subscription immediately and voids its open invoices
+async fn cancel_subscription_now(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+) -> RouterResult<storage::Subscription> {
+    let db = state.store.as_ref();
+    let open_invoices = db
+        .find_open_subscription_invoices_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the open invoices of the subscription")?;
+
+    for invoice in open_invoices {
+        db.update_subscription_invoice(
+            invoice,
+            storage::SubscriptionInvoiceUpdate::StatusUpdate {
+                status: storage_enums::InvoiceStatus::Void,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to void the invoice of the subscription")?;
+    }
+
+    let subscription = db
+        .update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::Cancel {
+                cancelled_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel the subscription")?;
+
+    trigger_subscription_webhook(
+        state,
+        merchant_context,
+        &subscription,
+        storage_enums::EventType::SubscriptionCancelled,
+    )
+    .await;
+
+    Ok(subscription)
+}
+
+struct InvoiceDetails {
+    invoice_id: String,
+    billing_reason: storage_enums::InvoiceBillingReason,
+    amount: MinorUnit,
+    proration_amount: MinorUnit,
+    currency: storage_enums::Currency,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+}
+
+async fn create_invoice(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    details: InvoiceDetails,
+) -> RouterResult<storage::SubscriptionInvoice> {
+    let now = common_utils::date_time::now();
+    let invoice_new = storage::SubscriptionInvoiceNew {
+        invoice_id: details.invoice_id,
+        merchant_id: subscription.merchant_id.clone(),
+        subscription_id: subscription.subscription_id.clone(),
+        customer_id: subscription.customer_id.clone(),
+        status: storage_enums::InvoiceStatus::Open,
+        billing_reason: details.billing_reason,
+        amount: details.amount,
+        proration_amount: details.proration_amount,
+        currency: details.currency,
+        period_start: details.period_start,
+        period_end: details.period_end,
+        payment_id: None,
+        attempt_count: 0,
+        next_payment_attempt_at: None,
+        error_code: None,
+        error_message: None,
+        paid_at: None,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let invoice = state
+        .store
+        .insert_subscription_invoice(invoice_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the invoice of the subscription")?;
+
+    trigger_invoice_webhook(
+        state,
+        merchant_context,
+        subscription,
+        &invoice,
+        storage_enums::EventType::InvoiceCreated,
+    )
+    .await;
+
+    Ok(invoice)
+}
+
+enum InvoicePaymentOutcome {
+    Paid,
+    Pending,
+    Failed {
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+impl InvoicePaymentOutcome {
+    fn from_intent_status(
+        status: storage_enums::IntentStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    ) -> Self {
+        match status {
+            storage_enums::IntentStatus::Succeeded => Self::Paid,
+            storage_enums::IntentStatus::Processing => Self::Pending,
+            _ => Self::Failed {
+                error_code,
+                error_message,
+            },
+        }
+    }
+}
+
+/// Collects an open invoice. The latest payment of the invoice is checked first, since it may
+/// have been processing or may have succeeded since, otherwise the invoice is charged again if
+/// it has attempts left
+async fn collect_invoice(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+) -> RouterResult<(storage::Subscription, storage::SubscriptionInvoice)> {
+    if invoice.amount == MinorUnit::zero() {
+        let attempt_count = invoice.attempt_count;
+        return apply_invoice_payment_outcome(
+            state,
+            merchant_context,
+            subscription,
+            invoice,
+            None,
+            attempt_count,
+            InvoicePaymentOutcome::Paid,
+        )
+        .await;
+    }
+
+    if let Some(payment_id) = invoice.payment_id.clone() {
+        let payment_intent = state
+            .store
+            .find_payment_intent_by_payment_id_merchant_id(
+                &state.into(),
+                &payment_id,
+                &invoice.merchant_id,
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the latest payment of the invoice")?;
+
+        if matches!(
+            payment_intent.status,
+            storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::Processing
+        ) || !has_payment_attempts_left(&invoice)
+        {
+            let attempt_count = invoice.attempt_count;
+            let outcome = InvoicePaymentOutcome::from_intent_status(
+                payment_intent.status,
+                invoice.error_code.clone(),
+                invoice.error_message.clone(),
+            );
+            return apply_invoice_payment_outcome(
+                state,
+                merchant_context,
+                subscription,
+                invoice,
+                Some(payment_id),
+                attempt_count,
+                outcome,
+            )
+            .await;
+        }
+    }
+
+    charge_invoice(state, merchant_context, subscription, invoice).await
+}
+
+/// Charges the invoice off-session with the payment method of the subscription
+async fn charge_invoice(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+) -> RouterResult<(storage::Subscription, storage::SubscriptionInvoice)> {
+    let payments_request = PaymentsRequest {
+        amount: Some(invoice.amount.into()),
+        currency: Some(invoice.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        profile_id: Some(subscription.profile_id.clone()),
+        recurring_details: Some(RecurringDetails::PaymentMethodId(
+            subscription.payment_method_id.clone(),
+        )),
+        off_session: Some(true),
+        confirm: Some(true),
+        description: Some(format!(
+            "Invoice {} of subscription {}",
+            invoice.invoice_id, subscription.subscription_id
+        )),
+        merchant_order_reference_id: Some(invoice.invoice_id.clone()),
+        metadata: Some(serde_json::json!({
+            "subscription_id": subscription.subscription_id,
+            "invoice_id": invoice.invoice_id,
+        })),
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_context.clone(),
+        Some(subscription.profile_id.clone()),
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    let (payment_id, outcome) = match response {
+        Ok(services::ApplicationResponse::Json(payments_response))
+        | Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => (
+            Some(payments_response.payment_id),
+            InvoicePaymentOutcome::from_intent_status(
+                payments_response.status,
+                payments_response.error_code,
+                payments_response.error_message,
+            ),
+        ),
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from the payment create flow")?,
+        Err(error) => {
+            logger::error!(?error, invoice_id = %invoice.invoice_id, "Failed to charge the invoice");
+            let api_error: api_models::errors::types::ApiErrorResponse =
+                error.current_context().switch();
+            let error_response = api_models::errors::types::ErrorResponse::from(&api_error);
+            (
+                None,
+                InvoicePaymentOutcome::Failed {
+                    error_code: Some(error_response.code),
+                    error_message: Some(error_response.message),
+                },
+            )
+        }
+    };
+
+    let attempt_count = invoice.attempt_count.saturating_add(1);
+    apply_invoice_payment_outcome(
+        state,
+        merchant_context,
+        subscription,
+        invoice,
+        payment_id,
+        attempt_count,
+        outcome,
+    )
+    .await
+}
+
+/// Records the outcome of a payment of the invoice, and updates the status of the subscription
+/// accordingly
+async fn apply_invoice_payment_outcome(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+    payment_id: Option<id_type::PaymentId>,
+    attempt_count: i32,
+    outcome: InvoicePaymentOutcome,
+) -> RouterResult<(storage::Subscription, storage::SubscriptionInvoice)> {
+    let db = state.store.as_ref();
+    let now = common_utils::date_time::now();
+    let billing_reason = invoice.billing_reason;
+    let payment_id = payment_id.or_else(|| invoice.payment_id.clone());
+
+    let (invoice_update, event_type) = match outcome {
+        InvoicePaymentOutcome::Paid => (
+            storage::SubscriptionInvoiceUpdate::PaymentUpdate {
+                status: storage_enums::InvoiceStatus::Paid,
+                payment_id,
+                attempt_count,
+                next_payment_attempt_at: None,
+                error_code: None,
+                error_message: None,
+                paid_at: Some(now),
+            },
+            Some(storage_enums::EventType::InvoicePaid),
+        ),
+        InvoicePaymentOutcome::Pending => (
+            storage::SubscriptionInvoiceUpdate::PaymentUpdate {
+                status: storage_enums::InvoiceStatus::Open,
+                payment_id,
+                attempt_count,
+                next_payment_attempt_at: Some(
+                    now + time::Duration::minutes(PENDING_PAYMENT_CHECK_INTERVAL_IN_MINUTES),
+                ),
+                error_code: None,
+                error_message: None,
+                paid_at: None,
+            },
+            None,
+        ),
+        InvoicePaymentOutcome::Failed {
+            error_code,
+            error_message,
+        } => {
+            let next_payment_attempt_at =
+                get_next_payment_attempt_at(billing_reason, attempt_count, now);
+            (
+                storage::SubscriptionInvoiceUpdate::PaymentUpdate {
+                    status: if next_payment_attempt_at.is_some() {
+                        storage_enums::InvoiceStatus::Open
+                    } else {
+                        storage_enums::InvoiceStatus::Uncollectible
+                    },
+                    payment_id,
+                    attempt_count,
+                    next_payment_attempt_at,
+                    error_code,
+                    error_message,
+                    paid_at: None,
+                },
+                Some(storage_enums::EventType::InvoicePaymentFailed),
+            )
+        }
+    };
+
+    let invoice = db
+        .update_subscription_invoice(invoice, invoice_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the invoice of the subscription")?;
+
+    if let Some(event_type) = event_type {
+        trigger_invoice_webhook(state, merchant_context, &subscription, &invoice, event_type).await;
+    }
+
+    let subscription_status = match (invoice.status, billing_reason) {
+        (storage_enums::InvoiceStatus::Paid, _) => storage_enums::SubscriptionStatus::Active,
+        (storage_enums::InvoiceStatus::Open, _) if invoice.error_code.is_none() => {
+            subscription.status
+        }
+        (_, storage_enums::InvoiceBillingReason::SubscriptionCreate) => {
+            storage_enums::SubscriptionStatus::Incomplete
+        }
+        (storage_enums::InvoiceStatus::Open, _) => storage_enums::SubscriptionStatus::PastDue,
+        (
+            storage_enums::InvoiceStatus::Uncollectible | storage_enums::InvoiceStatus::Void,
+            storage_enums::InvoiceBillingReason::SubscriptionCycle
+            | storage_enums::InvoiceBillingReason::SubscriptionUpdate,
+        ) => storage_enums::SubscriptionStatus::Unpaid,
+    };
+
+    if subscription_status == subscription.status {
+        return Ok((subscription, invoice));
+    }
+
+    let subscription = db
+        .update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::StatusUpdate {
+                status: subscription_status,
+                latest_invoice_id: None,
+                pending_proration_amount: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the status of the subscription")?;
+
+    if let Some(event_type) = Option::<storage_enums::EventType>::from(subscription.status) {
+        trigger_subscription_webhook(state, merchant_context, &subscription, event_type).await;
+    }
+
+    Ok((subscription, invoice))
+}
+
+/// The first invoice of a subscription is not retried, the customer is expected to be present
+/// when the subscription is created
+fn get_next_payment_attempt_at(
+    billing_reason: storage_enums::InvoiceBillingReason,
+    attempt_count: i32,
+    now: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    if billing_reason == storage_enums::InvoiceBillingReason::SubscriptionCreate {
+        return None;
+    }
+    let retry_index = usize::try_from(attempt_count).ok()?.checked_sub(1)?;
+    INVOICE_RETRY_DELAYS_IN_DAYS
+        .get(retry_index)
+        .map(|delay_in_days| now + time::Duration::days(*delay_in_days))
+}
+
+fn has_payment_attempts_left(invoice: &storage::SubscriptionInvoice) -> bool {
+    invoice.attempt_count == 0
+        || get_next_payment_attempt_at(
+            invoice.billing_reason,
+            invoice.attempt_count,
+            invoice.modified_at,
+        )
+        .is_some()
+}
+
+/// The time at which the billing task of the subscription has to run next
+fn get_next_billing_time(
+    subscription: &storage::Subscription,
+    invoice: &storage::SubscriptionInvoice,
+) -> Option<PrimitiveDateTime> {
+    match invoice.status {
+        storage_enums::InvoiceStatus::Open => Some(
+            invoice
+                .next_payment_attempt_at
+                .unwrap_or_else(common_utils::date_time::now),
+        ),
+        storage_enums::InvoiceStatus::Paid
+        | storage_enums::InvoiceStatus::Void
+        | storage_enums::InvoiceStatus::Uncollectible => subscription
+            .status
+            .is_billable()
+            .then_some(subscription.current_period_end),
+    }
+}
+
+fn get_subscription_billing_process_tracker_id(subscription: &storage::Subscription) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::SubscriptionBillingWorkflow,
+        SUBSCRIPTION_BILLING_TASK,
+        &subscription.subscription_id,
+        &subscription.merchant_id,
+    )
+}
+
+/// Makes sure that the billing task of the subscription runs no later than the schedule time
+async fn schedule_subscription_billing_task_by(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_id = get_subscription_billing_process_tracker_id(subscription);
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the subscription billing process tracker task")?;
+
+    match existing_process {
+        Some(process)
+            if !should_reschedule_billing_task(
+                process.status,
+                process.schedule_time,
+                schedule_time,
+            ) => {}
+        Some(process) => {
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: None,
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to reschedule the subscription billing process tracker task",
+            )?;
+        }
+        None => add_subscription_billing_task_to_pt(db, subscription, schedule_time).await?,
+    }
+    Ok(())
+}
+
+/// A task which is already running reschedules itself once it is done, other tasks are
+/// rescheduled when they would run after the schedule time or have finished
+fn should_reschedule_billing_task(
+    status: storage_enums::ProcessTrackerStatus,
+    scheduled_at: Option<PrimitiveDateTime>,
+    schedule_time: PrimitiveDateTime,
+) -> bool {
+    match status {
+        storage_enums::ProcessTrackerStatus::Processing
+        | storage_enums::ProcessTrackerStatus::ProcessStarted => false,
+        storage_enums::ProcessTrackerStatus::Finish => true,
+        storage_enums::ProcessTrackerStatus::New
+        | storage_enums::ProcessTrackerStatus::Pending
+        | storage_enums::ProcessTrackerStatus::Review => {
+            !scheduled_at.is_some_and(|scheduled_at| scheduled_at <= schedule_time)
+        }
+    }
+}
+
+async fn add_subscription_billing_task_to_pt(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let tracking_data = SubscriptionBillingPTData {
+        merchant_id: subscription.merchant_id.clone(),
+        subscription_id: subscription.subscription_id.clone(),
+    };
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        get_subscription_billing_process_tracker_id(subscription),
+        SUBSCRIPTION_BILLING_TASK,
+        storage::ProcessTrackerRunner::SubscriptionBillingWorkflow,
+        ["SUBSCRIPTION", "BILLING"],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct subscription billing process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add subscription billing process tracker task")?;
+
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "subscription_billing")),
+    );
+    Ok(())
+}
+
+async fn trigger_subscription_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    event_type: storage_enums::EventType,
+) {
+    // Every change of a subscription is a separate event, so that the events are not
+    // deduplicated as retries of the first one
+    let primary_object_id = format!(
+        "{}_{}",
+        subscription.subscription_id,
+        subscription.modified_at.assume_utc().unix_timestamp()
+    );
+    let content = api::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+        get_subscription_response(subscription.clone()),
+    ));
+    trigger_webhook(
+        state,
+        merchant_context,
+        &subscription.profile_id,
+        event_type,
+        primary_object_id,
+        storage_enums::EventObjectType::SubscriptionDetails,
+        content,
+    )
+    .await;
+}
+
+async fn trigger_invoice_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    invoice: &storage::SubscriptionInvoice,
+    event_type: storage_enums::EventType,
+) {
+    let primary_object_id = format!("{}_{}", invoice.invoice_id, invoice.attempt_count);
+    let content = api::OutgoingWebhookContent::InvoiceDetails(Box::new(get_invoice_response(
+        invoice.clone(),
+    )));
+    trigger_webhook(
+        state,
+        merchant_context,
+        &subscription.profile_id,
+        event_type,
+        primary_object_id,
+        storage_enums::EventObjectType::InvoiceDetails,
+        content,
+    )
+    .await;
+}
+
+/// Webhooks are best effort, a failure to send them does not fail the billing of the subscription
+async fn trigger_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &id_type::ProfileId,
+    event_type: storage_enums::EventType,
+    primary_object_id: String,
+    event_object_type: storage_enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+) {
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the business profile for the webhook"
+            );
+            return;
+        }
+    };
+
+    let _ = Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile,
+        event_type,
+        storage_enums::EventClass::Subscriptions,
+        primary_object_id,
+        event_object_type,
+        content,
+        None,
+    ))
+    .await
+    .map_err(|error| logger::error!(?error, %event_type, "Failed to trigger the webhook"));
+}
+
+async fn validate_payment_method(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method_id: &str,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.merchant_id != *merchant_context.get_merchant_account().get_id()
+        || payment_method.customer_id != *customer_id
+    {
+        return Err(errors::ApiErrorResponse::PaymentMethodNotFound.into());
+    }
+    if payment_method.status != storage_enums::PaymentMethodStatus::Active {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment method is not active".to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+async fn find_product(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    product_id: &str,
+) -> RouterResult<storage::SubscriptionProduct> {
+    db.find_subscription_product_by_merchant_id_product_id(merchant_id, product_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription product does not exist".to_string(),
+        })
+}
+
+async fn find_price(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    price_id: &str,
+) -> RouterResult<storage::SubscriptionPrice> {
+    db.find_subscription_price_by_merchant_id_price_id(merchant_id, price_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription price does not exist".to_string(),
+        })
+}
+
+async fn find_subscription(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    db.find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription does not exist".to_string(),
+        })
+}
+
+fn get_list_limit(limit: Option<i64>) -> i64 {
+    limit
+        .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into())
+        .clamp(1, common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1.into())
+}
+
+fn validate_name(name: &str) -> RouterResult<()> {
+    if name.trim().is_empty() || name.len() > MAX_NAME_LENGTH {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("name must be between 1 and {MAX_NAME_LENGTH} characters long"),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn validate_quantity(quantity: i32) -> RouterResult<()> {
+    if quantity <= 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "quantity must be greater than zero".to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn validate_trial_period_days(trial_period_days: i32) -> RouterResult<()> {
+    if !(0..=MAX_TRIAL_PERIOD_DAYS).contains(&trial_period_days) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "trial_period_days must be between 0 and {MAX_TRIAL_PERIOD_DAYS} days"
+            ),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn validate_billing_period(
+    billing_interval: storage_enums::BillingInterval,
+    interval_count: i32,
+) -> RouterResult<()> {
+    let max_interval_count = match billing_interval {
+        storage_enums::BillingInterval::Day => MAX_BILLING_PERIOD_DAYS,
+        storage_enums::BillingInterval::Week => MAX_BILLING_PERIOD_DAYS / 7,
+        storage_enums::BillingInterval::Month => 36,
+        storage_enums::BillingInterval::Year => 3,
+    };
+    if interval_count <= 0 || i64::from(interval_count) > max_interval_count {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "interval_count must be between 1 and {max_interval_count} for the {billing_interval} billing interval"
+            ),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// The amount of a billing period of the price for the quantity
+fn get_period_amount(price: &storage::SubscriptionPrice, quantity: i32) -> RouterResult<MinorUnit> {
+    price
+        .amount
+        .get_amount_as_i64()
+        .checked_mul(quantity.into())
+        .map(MinorUnit::new)
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The amount of the billing period is too large".to_string(),
+        })
+        .map_err(Into::into)
+}
+
+/// Adds `count` billing intervals to `start`. Monthly and yearly intervals keep the day of the
+/// month of `start`, clamped to the last day of shorter months
+fn add_billing_intervals(
+    start: PrimitiveDateTime,
+    billing_interval: storage_enums::BillingInterval,
+    count: i32,
+) -> Option<PrimitiveDateTime> {
+    match billing_interval {
+        storage_enums::BillingInterval::Day => {
+            start.checked_add(time::Duration::days(count.into()))
+        }
+        storage_enums::BillingInterval::Week => {
+            start.checked_add(time::Duration::weeks(count.into()))
+        }
+        storage_enums::BillingInterval::Month => add_months(start, count),
+        storage_enums::BillingInterval::Year => add_months(start, count.checked_mul(12)?),
+    }
+}
+
+fn add_months(start: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let total_months = start
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(start.month())) - 1)?
+        .checked_add(months)?;
+    let year = total_months.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(total_months.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = start.day().min(time::util::days_in_year_month(year, month));
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| date.with_time(start.time()))
+}
+
+/// Returns the end of the billing period which contains `at`, the billing periods starting at
+/// `billing_cycle_anchor` and lasting `interval_count` billing intervals each
+fn get_period_end_after(
+    billing_cycle_anchor: PrimitiveDateTime,
+    billing_interval: storage_enums::BillingInterval,
+    interval_count: i32,
+    at: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    // Estimates the number of elapsed periods, the estimate is at most one period short because
+    // of the varying lengths of months
+    let elapsed_intervals = match billing_interval {
+        storage_enums::BillingInterval::Day => (at - billing_cycle_anchor).whole_days(),
+        storage_enums::BillingInterval::Week => (at - billing_cycle_anchor).whole_weeks(),
+        storage_enums::BillingInterval::Month => {
+            i64::from(at.year() - billing_cycle_anchor.year()) * 12
+                + i64::from(u8::from(at.month()))
+                - i64::from(u8::from(billing_cycle_anchor.month()))
+        }
+        storage_enums::BillingInterval::Year => i64::from(at.year() - billing_cycle_anchor.year()),
+    };
+    let mut periods =
+        i32::try_from(elapsed_intervals.max(0) / i64::from(interval_count.max(1))).ok()?;
+
+    loop {
+        let period_end = add_billing_intervals(
+            billing_cycle_anchor,
+            billing_interval,
+            interval_count.checked_mul(periods)?,
+        )?;
+        if period_end > at {
+            return Some(period_end);
+        }
+        periods = periods.checked_add(1)?;
+    }
+}
+
+/// Prorates the difference between the new and the old amount of a billing period over the part
+/// of the period which has not been used yet. Negative amounts are credits
+fn calculate_proration_amount(
+    old_period_amount: MinorUnit,
+    new_period_amount: MinorUnit,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+    at: PrimitiveDateTime,
+) -> MinorUnit {
+    let period_seconds = (period_end - period_start).whole_seconds();
+    if period_seconds <= 0 {
+        return MinorUnit::zero();
+    }
+    let remaining_seconds = (period_end - at).whole_seconds().clamp(0, period_seconds);
+    let difference = i128::from(new_period_amount.get_amount_as_i64())
+        - i128::from(old_period_amount.get_amount_as_i64());
+    let prorated_amount = difference * i128::from(remaining_seconds) / i128::from(period_seconds);
+    // The prorated amount is at most the difference, which fits in an i64
+    MinorUnit::new(i64::try_from(prorated_amount).unwrap_or_default())
+}
+
+fn get_product_response(product: storage::SubscriptionProduct) -> SubscriptionProductResponse {
+    SubscriptionProductResponse {
+        product_id: product.product_id,
+        merchant_id: product.merchant_id,
+        name: product.name,
+        description: product.description,
+        is_active: product.is_active,
+        metadata: product.metadata,
+        created_at: product.created_at,
+    }
+}
+
+fn get_price_response(price: storage::SubscriptionPrice) -> SubscriptionPriceResponse {
+    SubscriptionPriceResponse {
+        price_id: price.price_id,
+        product_id: price.product_id,
+        merchant_id: price.merchant_id,
+        amount: price.amount,
+        currency: price.currency,
+        billing_interval: price.billing_interval,
+        interval_count: price.interval_count,
+        trial_period_days: price.trial_period_days,
+        is_active: price.is_active,
+        metadata: price.metadata,
+        created_at: price.created_at,
+    }
+}
+
+pub fn get_subscription_response(subscription: storage::Subscription) -> SubscriptionResponse {
+    SubscriptionResponse {
+        subscription_id: subscription.subscription_id,
+        merchant_id: subscription.merchant_id,
+        profile_id: subscription.profile_id,
+        customer_id: subscription.customer_id,
+        price_id: subscription.price_id,
+        pending_price_id: subscription.pending_price_id,
+        payment_method_id: subscription.payment_method_id,
+        status: subscription.status,
+        quantity: subscription.quantity,
+        billing_cycle_anchor: subscription.billing_cycle_anchor,
+        current_period_start: subscription.current_period_start,
+        current_period_end: subscription.current_period_end,
+        trial_start: subscription.trial_start,
+        trial_end: subscription.trial_end,
+        cancel_at_period_end: subscription.cancel_at_period_end,
+        cancelled_at: subscription.cancelled_at,
+        pending_proration_amount: subscription.pending_proration_amount,
+        latest_invoice_id: subscription.latest_invoice_id,
+        metadata: subscription.metadata,
+        created_at: subscription.created_at,
+    }
+}
+
+pub fn get_invoice_response(invoice: storage::SubscriptionInvoice) -> InvoiceResponse {
+    InvoiceResponse {
+        invoice_id: invoice.invoice_id,
+        subscription_id: invoice.subscription_id,
+        merchant_id: invoice.merchant_id,
+        customer_id: invoice.customer_id,
+        status: invoice.status,
+        billing_reason: invoice.billing_reason,
+        amount: invoice.amount,
+        proration_amount: invoice.proration_amount,
+        currency: invoice.currency,
+        period_start: invoice.period_start,
+        period_end: invoice.period_end,
+        payment_id: invoice.payment_id,
+        attempt_count: invoice.attempt_count,
+        next_payment_attempt_at: invoice.next_payment_attempt_at,
+        error_code: invoice.error_code,
+        error_message: invoice.error_message,
+        paid_at: invoice.paid_at,
+        created_at: invoice.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_monthly_periods_clamp_to_the_end_of_the_month() {
+        let anchor = datetime!(2024-01-31 10:00);
+        let interval = storage_enums::BillingInterval::Month;
+
+        assert_eq!(
+            get_period_end_after(anchor, interval, 1, anchor),
+            Some(datetime!(2024-02-29 10:00))
+        );
+        assert_eq!(
+            get_period_end_after(anchor, interval, 1, datetime!(2024-02-29 10:00)),
+            Some(datetime!(2024-03-31 10:00))
+        );
+        assert_eq!(
+            get_period_end_after(anchor, interval, 1, datetime!(2024-04-15 00:00)),
+            Some(datetime!(2024-04-30 10:00))
+        );
+        assert_eq!(
+            get_period_end_after(anchor, interval, 3, datetime!(2024-05-01 00:00)),
+            Some(datetime!(2024-07-31 10:00))
+        );
+    }
+
+    #[test]
+    fn test_daily_weekly_and_yearly_periods() {
+        let anchor = datetime!(2024-02-29 00:00);
+
+        assert_eq!(
+            get_period_end_after(
+                anchor,
+                storage_enums::BillingInterval::Day,
+                10,
+                datetime!(2024-03-10 00:00)
+            ),
+            Some(datetime!(2024-03-20 00:00))
+        );
+        assert_eq!(
+            get_period_end_after(
+                anchor,
+                storage_enums::BillingInterval::Week,
+                2,
+                datetime!(2024-03-01 00:00)
+            ),
+            Some(datetime!(2024-03-14 00:00))
+        );
+        assert_eq!(
+            get_period_end_after(anchor, storage_enums::BillingInterval::Year, 1, anchor),
+            Some(datetime!(2025-02-28 00:00))
+        );
+    }
+
+    #[test]
+    fn test_proration_of_plan_changes() {
+        let period_start = datetime!(2024-04-01 00:00);
+        let period_end = datetime!(2024-05-01 00:00);
+        let halfway = datetime!(2024-04-16 00:00);
+
+        assert_eq!(
+            calculate_proration_amount(
+                MinorUnit::new(1000),
+                MinorUnit::new(3000),
+                period_start,
+                period_end,
+                halfway
+            ),
+            MinorUnit::new(1000)
+        );
+        assert_eq!(
+            calculate_proration_amount(
+                MinorUnit::new(3000),
+                MinorUnit::new(1000),
+                period_start,
+                period_end,
+                halfway
+            ),
+            MinorUnit::new(-1000)
+        );
+        assert_eq!(
+            calculate_proration_amount(
+                MinorUnit::new(1000),
+                MinorUnit::new(3000),
+                period_start,
+                period_end,
+                period_end
+            ),
+            MinorUnit::zero()
+        );
+    }
+
+    #[test]
+    fn test_invoice_retry_schedule() {
+        let now = datetime!(2024-04-01 00:00);
+        let cycle = storage_enums::InvoiceBillingReason::SubscriptionCycle;
+
+        assert_eq!(
+            get_next_payment_attempt_at(cycle, 1, now),
+            Some(datetime!(2024-04-02 00:00))
+        );
+        assert_eq!(
+            get_next_payment_attempt_at(cycle, 3, now),
+            Some(datetime!(2024-04-06 00:00))
+        );
+        assert_eq!(get_next_payment_attempt_at(cycle, 4, now), None);
+        assert_eq!(
+            get_next_payment_attempt_at(
+                storage_enums::InvoiceBillingReason::SubscriptionCreate,
+                1,
+                now
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_proration_retry_brings_the_billing_task_forward() {
+        let next_payment_attempt_at = datetime!(2024-04-02 00:00);
+        let period_end = datetime!(2024-05-01 00:00);
+
+        assert!(should_reschedule_billing_task(
+            storage_enums::ProcessTrackerStatus::New,
+            Some(period_end),
+            next_payment_attempt_at
+        ));
+        assert!(should_reschedule_billing_task(
+            storage_enums::ProcessTrackerStatus::Finish,
+            Some(datetime!(2024-04-01 00:00)),
+            next_payment_attempt_at
+        ));
+        assert!(!should_reschedule_billing_task(
+            storage_enums::ProcessTrackerStatus::Pending,
+            Some(datetime!(2024-04-01 12:00)),
+            next_payment_attempt_at
+        ));
+        assert!(!should_reschedule_billing_task(
+            storage_enums::ProcessTrackerStatus::ProcessStarted,
+            Some(period_end),
+            next_payment_attempt_at
+        ));
+    }
+}
@@ -41,16 +41,44 @@ pub async fn create_vault_token_core(
     merchant_key_store: &domain::MerchantKeyStore,
     req: api_models::tokenization::GenericTokenizationRequest,
 ) -> RouterResponse<api_models::tokenization::GenericTokenizationResponse> {
+    let tokenization = create_vault_token(
+        &state,
+        merchant_account,
+        merchant_key_store,
+        &req.customer_id,
+        req.token_request,
+    )
+    .await?;
+
+    // Convert to TokenizationResponse
+    Ok(hyperswitch_domain_models::api::ApplicationResponse::Json(
+        api_models::tokenization::GenericTokenizationResponse {
+            id: tokenization.id,
+            created_at: tokenization.created_at,
+            flag: tokenization.flag,
+        },
+    ))
+}
+
+/// Stores the data in the vault and creates the token referring to it
+#[instrument(skip_all)]
+#[cfg(all(feature = "v2", feature = "tokenization_v2"))]
+pub async fn create_vault_token(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::GlobalCustomerId,
+    token_request: masking::Secret<serde_json::Value>,
+) -> RouterResult<hyperswitch_domain_models::tokenization::Tokenization> {
     // Generate a unique vault ID
     let vault_id = domain::VaultId::generate(uuid::Uuid::now_v7().to_string());
     let db = state.store.as_ref();
-    let key_manager_state = &(&state).into();
-    let customer_id = req.customer_id.clone();
+    let key_manager_state = &state.into();
     // Create vault request
     let payload = pm_types::AddVaultRequest {
-        entity_id: req.customer_id.to_owned(),
+        entity_id: customer_id.to_owned(),
         vault_id: vault_id.clone(),
-        data: req.token_request.clone(),
+        data: token_request,
         ttl: state.conf.locker.ttl_for_storage_in_secs,
     }
     .encode_to_vec()
@@ -58,7 +86,7 @@ pub async fn create_vault_token_core(
     .attach_printable("Failed to encode Request")?;
 
     // Call the vault service
-    let resp = pm_vault::call_to_vault::<pm_types::AddVault>(&state, payload.clone())
+    let resp = pm_vault::call_to_vault::<pm_types::AddVault>(state, payload.clone())
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Call to vault failed")?;
@@ -82,24 +110,14 @@ pub async fn create_vault_token_core(
     };
 
     // Insert into database
-    let tokenization = db
-        .insert_tokenization(
-            tokenization_new,
-            &(merchant_key_store.clone()),
-            key_manager_state,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to insert tokenization record")?;
-
-    // Convert to TokenizationResponse
-    Ok(hyperswitch_domain_models::api::ApplicationResponse::Json(
-        api_models::tokenization::GenericTokenizationResponse {
-            id: tokenization.id,
-            created_at: tokenization.created_at,
-            flag: tokenization.flag,
-        },
-    ))
+    db.insert_tokenization(
+        tokenization_new,
+        &(merchant_key_store.clone()),
+        key_manager_state,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert tokenization record")
 }
 
 #[cfg(feature = "v2")]
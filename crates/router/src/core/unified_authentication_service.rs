@@ -550,6 +550,7 @@ pub async fn create_new_authentication(
     currency: Option<common_enums::Currency>,
     return_url: Option<String>,
     profile_acquirer_id: Option<common_utils::id_type::ProfileAcquirerId>,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> RouterResult<Authentication> {
     let service_details_value = service_details
         .map(serde_json::to_value)
@@ -616,7 +617,7 @@ pub async fn create_new_authentication(
     };
     state
         .store
-        .insert_authentication(new_authorization)
+        .insert_authentication(new_authorization, storage_scheme)
         .await
         .to_duplicate_response(ApiErrorResponse::GenericDuplicateError {
             message: format!(
@@ -723,6 +724,7 @@ pub async fn authentication_create_core(
         Some(req.currency),
         req.return_url,
         req.profile_acquirer_id.clone(),
+        merchant_account.storage_scheme,
     )
     .await?;
 
@@ -888,7 +890,11 @@ pub async fn authentication_eligibility_core(
     let merchant_id = merchant_account.get_id();
     let db = &*state.store;
     let authentication = db
-        .find_authentication_by_merchant_id_authentication_id(merchant_id, &authentication_id)
+        .find_authentication_by_merchant_id_authentication_id(
+            merchant_id,
+            &authentication_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(ApiErrorResponse::AuthenticationNotFound {
             id: authentication_id.get_string_repr().to_owned(),
@@ -1125,6 +1131,7 @@ pub async fn authentication_eligibility_core(
             .clone()
             .map(common_utils::encryption::Encryption::from),
         browser_info,
+        merchant_account.storage_scheme,
     )
     .await?;
 
@@ -1160,7 +1167,11 @@ pub async fn authentication_authenticate_core(
     let merchant_id = merchant_account.get_id();
     let db = &*state.store;
     let authentication = db
-        .find_authentication_by_merchant_id_authentication_id(merchant_id, &authentication_id)
+        .find_authentication_by_merchant_id_authentication_id(
+            merchant_id,
+            &authentication_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(ApiErrorResponse::AuthenticationNotFound {
             id: authentication_id.get_string_repr().to_owned(),
@@ -1282,6 +1293,7 @@ pub async fn authentication_authenticate_core(
         None,
         None,
         None,
+        merchant_account.storage_scheme,
     )
     .await?;
 
@@ -1391,7 +1403,11 @@ pub async fn authentication_sync_core(
     let merchant_id = merchant_account.get_id();
     let db = &*state.store;
     let authentication = db
-        .find_authentication_by_merchant_id_authentication_id(merchant_id, &authentication_id)
+        .find_authentication_by_merchant_id_authentication_id(
+            merchant_id,
+            &authentication_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(ApiErrorResponse::AuthenticationNotFound {
             id: authentication_id.get_string_repr().to_owned(),
@@ -1455,6 +1471,7 @@ pub async fn authentication_sync_core(
                 None,
                 None,
                 None,
+                merchant_account.storage_scheme,
             )
             .await?
         }
@@ -1656,7 +1673,11 @@ pub async fn authentication_post_sync_core(
     let merchant_id = merchant_account.get_id();
     let db = &*state.store;
     let authentication = db
-        .find_authentication_by_merchant_id_authentication_id(merchant_id, &authentication_id)
+        .find_authentication_by_merchant_id_authentication_id(
+            merchant_id,
+            &authentication_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(ApiErrorResponse::AuthenticationNotFound {
             id: authentication_id.get_string_repr().to_owned(),
@@ -1709,6 +1730,7 @@ pub async fn authentication_post_sync_core(
         None,
         None,
         None,
+        merchant_account.storage_scheme,
     )
     .await?;
 
@@ -128,6 +128,7 @@ pub fn construct_uas_router_data<F: Clone, Req, Res>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
         l2_l3_data: None,
         minor_amount_capturable: None,
     })
@@ -146,6 +147,7 @@ pub async fn external_authentication_update_trackers<F: Clone, Req>(
     shipping_address: Option<common_utils::encryption::Encryption>,
     email: Option<common_utils::encryption::Encryption>,
     browser_info: Option<serde_json::Value>,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> RouterResult<diesel_models::authentication::Authentication> {
     let authentication_update = match router_data.response {
         Ok(response) => match response {
@@ -306,6 +308,7 @@ pub async fn external_authentication_update_trackers<F: Clone, Req>(
         .update_authentication_by_merchant_id_authentication_id(
             authentication,
             authentication_update,
+            storage_scheme,
         )
         .await
         .change_context(ApiErrorResponse::InternalServerError)
@@ -835,6 +835,7 @@ where
     let merchant_id = router_data.merchant_id.clone();
     let refund_id = router_data.refund_id.clone();
     let dispute_id = router_data.dispute_id.clone();
+    let merchant_connector_id = router_data.merchant_connector_id.clone();
 
     // Log the actual gRPC request with masking
     let grpc_request_body = masking::masked_serialize(&grpc_request)
@@ -903,6 +904,7 @@ where
         refund_id,
         dispute_id,
         status_code,
+        merchant_connector_id,
     );
 
     // Set response body based on status code
@@ -11,7 +11,7 @@ use common_enums::{IntentStatus, RequestIncrementalAuthorization};
 use common_utils::{crypto::Encryptable, pii::Email};
 use common_utils::{
     errors::CustomResult,
-    ext_traits::AsyncExt,
+    ext_traits::{AsyncExt, StringExt},
     types::{keymanager::KeyManagerState, ConnectorTransactionIdTrait, MinorUnit},
 };
 use diesel_models::refund as diesel_refund;
@@ -239,6 +239,7 @@ pub async fn construct_payout_router_data<'a, F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -413,6 +414,7 @@ pub async fn construct_refund_router_data<'a, F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -619,6 +621,7 @@ pub async fn construct_refund_router_data<'a, F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1062,6 +1065,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1165,6 +1169,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1277,6 +1282,7 @@ pub async fn construct_upload_file_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1350,6 +1356,7 @@ pub async fn construct_dispute_list_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
         l2_l3_data: None,
         minor_amount_capturable: None,
     })
@@ -1455,6 +1462,7 @@ pub async fn construct_dispute_sync_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1583,6 +1591,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<F: Clone>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1689,6 +1698,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -1789,6 +1799,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         l2_l3_data: None,
         minor_amount_capturable: None,
     };
@@ -2320,6 +2331,13 @@ impl<T, F, R> GetProfileId for (storage::Payouts, T, F, R) {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl GetProfileId for storage::PayoutBatch {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
 /// Filter Objects based on profile ids
 pub(super) fn filter_objects_based_on_profile_id_list<
     T: GetProfileId,
@@ -2645,3 +2663,29 @@ pub fn should_proceed_with_accept_dispute(
         DisputeStatus::DisputeChallenged | DisputeStatus::DisputeOpened
     )
 }
+
+/// Fetches the JSON record stored in the configs table against `key`, if any.
+///
+/// The absence of a record is cached in the config cache just like the record itself, so that
+/// optional configs read on hot paths do not hit the database on every call.
+pub async fn find_optional_json_config<T>(
+    state: &SessionState,
+    key: &str,
+    type_name: &'static str,
+) -> RouterResult<Option<T>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(key, Some(consts::ABSENT_JSON_CONFIG.to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to fetch {type_name} config"))?;
+
+    config
+        .config
+        .parse_struct::<Option<T>>(type_name)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to parse {type_name} config"))
+}
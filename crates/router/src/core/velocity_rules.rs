@@ -0,0 +1,204 @@
+pub mod utils;
+
+use std::collections::HashSet;
+
+use api_models::velocity_rules::{
+    VelocityRule, VelocityRulesRecord, VelocityRulesRequest, VelocityRulesResponse,
+};
+use common_utils::{ext_traits::Encode, id_type};
+use diesel_models::configs;
+use error_stack::ResultExt;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::domain,
+};
+
+fn validate_velocity_rules(rules: &[VelocityRule]) -> RouterResult<()> {
+    if rules.len() > consts::MAX_VELOCITY_RULES_PER_PROFILE {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "A profile can have at most {} velocity rules",
+                consts::MAX_VELOCITY_RULES_PER_PROFILE
+            ),
+        }
+        .into());
+    }
+
+    let mut rule_names = HashSet::with_capacity(rules.len());
+    for rule in rules {
+        if rule.name.trim().is_empty() {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Velocity rule name cannot be empty".to_string(),
+            }
+            .into());
+        }
+        if !rule_names.insert(rule.name.as_str()) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate velocity rule name `{}`", rule.name),
+            }
+            .into());
+        }
+        if rule.threshold < 0 {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Threshold of velocity rule `{}` cannot be negative",
+                    rule.name
+                ),
+            }
+            .into());
+        }
+        if rule.window_in_secs == 0
+            || rule.window_in_secs > consts::MAX_VELOCITY_RULE_WINDOW_IN_SECS
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Window of velocity rule `{}` must be between 1 and {} seconds",
+                    rule.name,
+                    consts::MAX_VELOCITY_RULE_WINDOW_IN_SECS
+                ),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(())
+}
+
+/// Fetches the velocity rules configured for the profile, if any
+pub async fn get_velocity_rules_record(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<VelocityRulesRecord>> {
+    core_utils::find_optional_json_config(
+        state,
+        &profile_id.get_velocity_rules_config_key(),
+        "VelocityRulesRecord",
+    )
+    .await
+}
+
+pub async fn upsert_velocity_rules(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+    request: VelocityRulesRequest,
+) -> RouterResponse<VelocityRulesResponse> {
+    validate_velocity_rules(&request.rules)?;
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    let db = state.store.as_ref();
+    let key = profile_id.get_velocity_rules_config_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let existing_record = get_velocity_rules_record(&state, &profile_id).await?;
+
+    let record = VelocityRulesRecord {
+        rules: request.rules,
+        created_at: existing_record
+            .as_ref()
+            .map_or(timestamp, |record| record.created_at),
+        modified_at: timestamp,
+    };
+
+    let serialized_record = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize velocity rules record")?;
+
+    if existing_record.is_some() {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_record),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update velocity rules config")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_record,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert velocity rules config")?;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        VelocityRulesResponse {
+            profile_id,
+            rules: record.rules,
+            created_at: record.created_at,
+            modified_at: record.modified_at,
+        },
+    ))
+}
+
+pub async fn retrieve_velocity_rules(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<VelocityRulesResponse> {
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    let record = get_velocity_rules_record(&state, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Velocity rules are not configured for the profile".to_string(),
+        })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        VelocityRulesResponse {
+            profile_id,
+            rules: record.rules,
+            created_at: record.created_at,
+            modified_at: record.modified_at,
+        },
+    ))
+}
+
+pub async fn delete_velocity_rules(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    validate_profile(&state, &merchant_context, &profile_id).await?;
+
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_velocity_rules_config_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Velocity rules are not configured for the profile".to_string(),
+        })?;
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
@@ -0,0 +1,271 @@
+use api_models::velocity_rules::{
+    TriggeredVelocityRule, VelocityRule, VelocityRuleAction, VelocityRuleKey, VelocityRuleMetric,
+};
+use common_enums::Currency;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    id_type, pii,
+    types::MinorUnit,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    router_request_types::BrowserInformation,
+    velocity_check_data::{VelocityCheckData, VelocityCounter},
+};
+use masking::PeekInterface;
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{
+        card_testing_guard::utils as card_testing_guard_utils,
+        errors::{self, RouterResult},
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::SessionState,
+    services,
+    types::{api, domain},
+};
+
+/// Values of the payment attributes on which velocity counters are maintained
+struct VelocityKeyValues {
+    card_fingerprint: Option<String>,
+    email: Option<String>,
+    ip_address: Option<String>,
+    card_bin: Option<String>,
+}
+
+impl VelocityKeyValues {
+    fn get(&self, key: VelocityRuleKey) -> Option<&str> {
+        match key {
+            VelocityRuleKey::CardFingerprint => self.card_fingerprint.as_deref(),
+            VelocityRuleKey::Email => self.email.as_deref(),
+            VelocityRuleKey::IpAddress => self.ip_address.as_deref(),
+            VelocityRuleKey::CardBin => self.card_bin.as_deref(),
+        }
+    }
+}
+
+fn hash_key_value(value: &str) -> RouterResult<String> {
+    Sha256
+        .generate_digest(value.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash velocity key value")
+}
+
+fn get_ip_address(request: &api::PaymentsRequest) -> RouterResult<Option<String>> {
+    request
+        .browser_info
+        .clone()
+        .map(serde_json::from_value::<BrowserInformation>)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("could not parse browser_info")
+        .map(|browser_info| {
+            browser_info
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|ip_address| ip_address.to_string())
+        })
+}
+
+async fn get_velocity_key_values(
+    request: &api::PaymentsRequest,
+    payment_method_data: Option<&api_models::payments::PaymentMethodData>,
+    email: Option<&pii::Email>,
+    rules: &[VelocityRule],
+    business_profile: &domain::Profile,
+) -> RouterResult<VelocityKeyValues> {
+    let card = payment_method_data.and_then(|pm_data| match pm_data {
+        api_models::payments::PaymentMethodData::Card(card) => Some(card),
+        _ => None,
+    });
+
+    let requires_card_fingerprint = rules
+        .iter()
+        .any(|rule| rule.key == VelocityRuleKey::CardFingerprint);
+
+    let card_fingerprint = match (card, &business_profile.card_testing_secret_key) {
+        (Some(_), Some(_)) if requires_card_fingerprint => Some(
+            card_testing_guard_utils::generate_fingerprint(payment_method_data, business_profile)
+                .await?
+                .peek()
+                .clone(),
+        ),
+        (Some(_), None) if requires_card_fingerprint => {
+            logger::warn!(
+                "Skipping card fingerprint velocity rules as card testing secret key is not configured"
+            );
+            None
+        }
+        _ => None,
+    };
+
+    Ok(VelocityKeyValues {
+        card_fingerprint,
+        email: email
+            .map(|email| hash_key_value(&email.peek().to_lowercase()))
+            .transpose()?,
+        ip_address: get_ip_address(request)?
+            .map(|ip_address| hash_key_value(&ip_address))
+            .transpose()?,
+        card_bin: card.map(|card| card.card_number.get_card_isin()),
+    })
+}
+
+/// Amounts in different currencies cannot be summed up, so amount counters are scoped to the
+/// currency of the payment. Counters of rules with other metrics are independent of the currency.
+fn get_counter_cache_key(
+    profile_id: &id_type::ProfileId,
+    rule: &VelocityRule,
+    value: &str,
+    currency: Option<Currency>,
+) -> Option<String> {
+    let counter_scope = match rule.metric {
+        VelocityRuleMetric::Amount => format!("{}_{}", value, currency?),
+        VelocityRuleMetric::AttemptCount | VelocityRuleMetric::DeclineCount => value.to_owned(),
+    };
+
+    Some(format!(
+        "{}_{}_{}_{}",
+        consts::VELOCITY_COUNTER_PREFIX,
+        profile_id.get_string_repr(),
+        rule.name,
+        counter_scope
+    ))
+}
+
+/// Value contributed by the current payment attempt towards the metric of a rule
+fn get_current_attempt_contribution(metric: VelocityRuleMetric, amount: MinorUnit) -> i64 {
+    match metric {
+        VelocityRuleMetric::AttemptCount => 1,
+        VelocityRuleMetric::Amount => amount.get_amount_as_i64(),
+        // Declines are only known once the connector responds
+        VelocityRuleMetric::DeclineCount => 0,
+    }
+}
+
+/// Evaluates the velocity rules of the profile against the payment being confirmed.
+///
+/// Attempt and amount counters are incremented as part of the evaluation, decline counters are
+/// returned in the [`VelocityCheckData`] so that they can be incremented if the payment fails.
+#[allow(clippy::too_many_arguments)]
+pub async fn validate_velocity_rules(
+    state: &SessionState,
+    request: &api::PaymentsRequest,
+    payment_method_data: Option<&api_models::payments::PaymentMethodData>,
+    email: Option<&pii::Email>,
+    amount: MinorUnit,
+    currency: Option<Currency>,
+    payment_id: &id_type::PaymentId,
+    business_profile: &domain::Profile,
+) -> RouterResult<Option<VelocityCheckData>> {
+    let profile_id = business_profile.get_id();
+    let rules = match super::get_velocity_rules_record(state, profile_id).await? {
+        Some(record) if !record.rules.is_empty() => record.rules,
+        _ => return Ok(None),
+    };
+
+    let key_values = get_velocity_key_values(
+        request,
+        payment_method_data,
+        email,
+        &rules,
+        business_profile,
+    )
+    .await?;
+
+    let mut triggered_rules = Vec::new();
+    let mut decline_counters = Vec::new();
+
+    for rule in &rules {
+        let Some(cache_key) = key_values
+            .get(rule.key)
+            .and_then(|value| get_counter_cache_key(profile_id, rule, value, currency))
+        else {
+            continue;
+        };
+        let contribution = get_current_attempt_contribution(rule.metric, amount);
+
+        let observed_value =
+            services::velocity_rules::get_count_in_window(state, &cache_key, rule.window_in_secs)
+                .await?
+                + contribution;
+
+        if observed_value > rule.threshold {
+            triggered_rules.push(TriggeredVelocityRule {
+                name: rule.name.clone(),
+                key: rule.key,
+                metric: rule.metric,
+                threshold: rule.threshold,
+                observed_value,
+                action: rule.action,
+            });
+        }
+
+        match rule.metric {
+            VelocityRuleMetric::AttemptCount | VelocityRuleMetric::Amount => {
+                let _ = services::velocity_rules::increment_count_in_window(
+                    state,
+                    &cache_key,
+                    rule.window_in_secs,
+                    contribution,
+                )
+                .await
+                .map_err(|error| logger::error!(velocity_counter_increment_error=?error));
+            }
+            VelocityRuleMetric::DeclineCount => decline_counters.push(VelocityCounter {
+                cache_key,
+                window_in_secs: rule.window_in_secs,
+            }),
+        }
+    }
+
+    let action = triggered_rules.iter().map(|rule| rule.action).max();
+
+    if let Some(action) = action {
+        logger::info!(velocity_rule_action=%action, triggered_velocity_rules=?triggered_rules);
+        state
+            .get_req_state()
+            .event_context
+            .event(AuditEvent::new(AuditEventType::VelocityRuleTriggered {
+                payment_id: payment_id.clone(),
+                action,
+                triggered_rules: triggered_rules.clone(),
+            }))
+            .emit();
+    }
+
+    if action == Some(VelocityRuleAction::Block) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Blocked due to suspicious activity".to_string(),
+        }
+        .into());
+    }
+
+    Ok(Some(VelocityCheckData {
+        action,
+        triggered_rules,
+        decline_counters,
+    }))
+}
+
+/// Increments the decline counters of the velocity rules evaluated for a failed payment
+pub async fn increment_decline_counters(
+    state: &SessionState,
+    velocity_check_data: Option<&VelocityCheckData>,
+) {
+    for counter in velocity_check_data
+        .map(|data| data.decline_counters.as_slice())
+        .unwrap_or_default()
+    {
+        let _ = services::velocity_rules::increment_count_in_window(
+            state,
+            &counter.cache_key,
+            counter.window_in_secs,
+            1,
+        )
+        .await
+        .map_err(|error| logger::error!(velocity_counter_increment_error=?error));
+    }
+}
@@ -4,6 +4,7 @@ use actix_web::FromRequest;
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
 use api_models::webhooks::{self, WebhookResponseTracker};
+use common_types::disputes::DisputeFinancialRecords;
 use common_utils::{
     errors::ReportSwitchExt,
     events::ApiEventsType,
@@ -26,7 +27,7 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics, payment_methods,
         payments::{self, tokenization},
@@ -1269,6 +1270,7 @@ async fn relay_refunds_incoming_webhook_flow(
             status: common_enums::RelayStatus::foreign_try_from(event_type)
                 .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
                 .attach_printable("failed relay refund status mapping from event type")?,
+            response_data: None,
         };
         db.update_relay(
             key_manager_state,
@@ -1311,6 +1313,117 @@ async fn relay_refunds_incoming_webhook_flow(
     })
 }
 
+async fn relay_payments_incoming_webhook_flow(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    webhook_details: api::IncomingWebhookDetails,
+    event_type: webhooks::IncomingWebhookEvent,
+    source_verified: bool,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+
+    let relay_record = match webhook_details.object_reference_id {
+        webhooks::ObjectReferenceId::PaymentId(api::PaymentIdType::PaymentAttemptId(
+            attempt_id,
+        )) => {
+            // The id of the relay is sent to the connector as the reference of the payment
+            let relay_id = common_utils::id_type::RelayId::from_str(&attempt_id)
+                .change_context(errors::ValidationError::IncorrectValueProvided {
+                    field_name: "relay_id",
+                })
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            db.find_relay_by_id(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                &relay_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable("Failed to fetch the relay record")?
+        }
+        webhooks::ObjectReferenceId::PaymentId(api::PaymentIdType::ConnectorTransactionId(
+            connector_transaction_id,
+        )) => db
+            .find_latest_payment_relay_by_profile_id_connector_resource_id(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                business_profile.get_id(),
+                &connector_transaction_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable("Failed to fetch the relay record")?,
+        _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure).attach_printable(
+            "received an unsupported payment id when processing relay payment webhooks",
+        )?,
+    };
+
+    // The outcome of an incremental authorization is not conveyed by the payment webhooks
+    let attempt_status = match relay_record.relay_type {
+        common_enums::RelayType::IncrementalAuthorization => None,
+        _ => common_enums::AttemptStatus::foreign_try_from(event_type).ok(),
+    };
+
+    // if source_verified then update relay status else trigger relay force sync
+    let relay_response = match attempt_status {
+        Some(attempt_status) if source_verified => {
+            let relay_update = hyperswitch_domain_models::relay::RelayUpdate::StatusUpdate {
+                connector_reference_id: None,
+                status: hyperswitch_domain_models::relay::get_relay_status_for_attempt_status(
+                    relay_record.relay_type,
+                    attempt_status,
+                ),
+                response_data: hyperswitch_domain_models::relay::RelayPaymentResponseData {
+                    attempt_status,
+                }
+                .to_secret_value(),
+            };
+            db.update_relay(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                relay_record,
+                relay_update,
+            )
+            .await
+            .map(api_models::relay::RelayResponse::from)
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable("Failed to update relay")?
+        }
+        _ => {
+            let relay_retrieve_request = api_models::relay::RelayRetrieveRequest {
+                force_sync: true,
+                id: relay_record.id,
+            };
+            let relay_force_sync_response = Box::pin(relay::relay_retrieve(
+                state,
+                merchant_context,
+                Some(business_profile.get_id().clone()),
+                relay_retrieve_request,
+            ))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to force sync relay")?;
+
+            if let hyperswitch_domain_models::api::ApplicationResponse::Json(response) =
+                relay_force_sync_response
+            {
+                response
+            } else {
+                Err(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unexpected response from force sync relay")?
+            }
+        }
+    };
+
+    Ok(WebhookResponseTracker::Relay {
+        relay_id: relay_response.id,
+        status: relay_response.status,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn refunds_incoming_webhook_flow(
@@ -1434,8 +1547,17 @@ async fn relay_incoming_webhook_flow(
         ))
         .await
         .attach_printable("Incoming webhook flow for relay refund failed")?,
-        webhooks::WebhookFlow::Payment
-        | webhooks::WebhookFlow::Payout
+        webhooks::WebhookFlow::Payment => Box::pin(relay_payments_incoming_webhook_flow(
+            state,
+            merchant_context,
+            business_profile,
+            webhook_details,
+            event_type,
+            source_verified,
+        ))
+        .await
+        .attach_printable("Incoming webhook flow for relay payment failed")?,
+        webhooks::WebhookFlow::Payout
         | webhooks::WebhookFlow::Dispute
         | webhooks::WebhookFlow::Subscription
         | webhooks::WebhookFlow::ReturnResponse
@@ -1492,6 +1614,7 @@ pub async fn get_or_update_dispute_object(
     dispute_details: api::disputes::DisputePayload,
     merchant_id: &common_utils::id_type::MerchantId,
     organization_id: &common_utils::id_type::OrganizationId,
+    storage_scheme: common_enums::enums::MerchantStorageScheme,
     payment_attempt: &PaymentAttempt,
     dispute_status: common_enums::enums::DisputeStatus,
     business_profile: &domain::Profile,
@@ -1534,10 +1657,11 @@ pub async fn get_or_update_dispute_object(
                 )?,
                 organization_id: organization_id.clone(),
                 dispute_currency: Some(dispute_details.currency),
+                financial_records: (!dispute_details.financial_records.is_empty()).then(|| {
+                    DisputeFinancialRecords::default().merge(dispute_details.financial_records)
+                }),
             };
-            state
-                .store
-                .insert_dispute(new_dispute.clone())
+            disputes::insert_dispute(&state, new_dispute, storage_scheme)
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
         }
@@ -1552,22 +1676,80 @@ pub async fn get_or_update_dispute_object(
             )
             .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
             .attach_printable("dispute stage and status validation failed")?;
-            let update_dispute = diesel_models::dispute::DisputeUpdate::Update {
-                dispute_stage: dispute_details.dispute_stage,
-                dispute_status,
-                connector_status: dispute_details.connector_status,
-                connector_reason: dispute_details.connector_reason,
-                connector_reason_code: dispute_details.connector_reason_code,
-                challenge_required_by: dispute_details.challenge_required_by,
-                connector_updated_at: dispute_details.updated_at,
-            };
-            db.update_dispute(dispute, update_dispute)
+            if dispute_details.financial_records.is_empty() {
+                return update_dispute_object(
+                    &state,
+                    dispute,
+                    dispute_details,
+                    dispute_status,
+                    storage_scheme,
+                )
+                .await;
+            }
+
+            // The financial records are merged into the latest copy of the dispute, read while
+            // holding the lock taken by the API adding financial records
+            let lock_action =
+                disputes::get_dispute_financial_records_lock_action(&dispute.dispute_id);
+            lock_action
+                .clone()
+                .perform_locking_action(&state, merchant_id.to_owned())
+                .await?;
+            let updated_dispute = async {
+                let dispute = db
+                    .find_dispute_by_merchant_id_dispute_id(
+                        merchant_id,
+                        &dispute.dispute_id,
+                        storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+                update_dispute_object(
+                    &state,
+                    dispute,
+                    dispute_details,
+                    dispute_status,
+                    storage_scheme,
+                )
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            }
+            .await;
+            lock_action
+                .free_lock_action(&state, merchant_id.to_owned())
+                .await?;
+            updated_dispute
         }
     }
 }
 
+async fn update_dispute_object(
+    state: &SessionState,
+    dispute: diesel_models::dispute::Dispute,
+    dispute_details: api::disputes::DisputePayload,
+    dispute_status: common_enums::enums::DisputeStatus,
+    storage_scheme: common_enums::enums::MerchantStorageScheme,
+) -> CustomResult<diesel_models::dispute::Dispute, errors::ApiErrorResponse> {
+    let update_dispute = diesel_models::dispute::DisputeUpdate::Update {
+        dispute_stage: dispute_details.dispute_stage,
+        dispute_status,
+        connector_status: dispute_details.connector_status,
+        connector_reason: dispute_details.connector_reason,
+        connector_reason_code: dispute_details.connector_reason_code,
+        challenge_required_by: dispute_details.challenge_required_by,
+        connector_updated_at: dispute_details.updated_at,
+        financial_records: (!dispute_details.financial_records.is_empty()).then(|| {
+            dispute
+                .financial_records
+                .clone()
+                .unwrap_or_default()
+                .merge(dispute_details.financial_records)
+        }),
+    };
+    disputes::update_dispute(state, dispute, update_dispute, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn external_authentication_incoming_webhook_flow(
@@ -1606,6 +1788,7 @@ async fn external_authentication_incoming_webhook_flow(
                         .find_authentication_by_merchant_id_authentication_id(
                             merchant_context.get_merchant_account().get_id(),
                             &authentication_id,
+                            merchant_context.get_merchant_account().storage_scheme,
                         )
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
@@ -1619,6 +1802,7 @@ async fn external_authentication_incoming_webhook_flow(
                         .find_authentication_by_merchant_id_connector_authentication_id(
                             merchant_context.get_merchant_account().get_id().clone(),
                             connector_authentication_id.clone(),
+                            merchant_context.get_merchant_account().storage_scheme,
                         )
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
@@ -1636,6 +1820,7 @@ async fn external_authentication_incoming_webhook_flow(
             .update_authentication_by_merchant_id_authentication_id(
                 authentication,
                 authentication_update,
+                merchant_context.get_merchant_account().storage_scheme,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1978,6 +2163,7 @@ async fn disputes_incoming_webhook_flow(
                 merchant_context.get_merchant_account().get_id(),
                 &payment_attempt.payment_id,
                 &dispute_details.connector_dispute_id,
+                merchant_context.get_merchant_account().storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
@@ -1991,6 +2177,7 @@ async fn disputes_incoming_webhook_flow(
             dispute_details,
             merchant_context.get_merchant_account().get_id(),
             &merchant_context.get_merchant_account().organization_id,
+            merchant_context.get_merchant_account().storage_scheme,
             &payment_attempt,
             dispute_status,
             &business_profile,
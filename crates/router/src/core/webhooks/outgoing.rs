@@ -21,6 +21,8 @@ use router_env::{
 };
 
 use super::{types, utils, MERCHANT_ID};
+#[cfg(feature = "adyen")]
+use crate::compatibility::adyen::webhooks as adyen_webhooks;
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -261,6 +263,9 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     );
 
     let merchant_id = business_profile.merchant_id.clone();
+    let profile_id = business_profile.get_id().clone();
+    let webhook_url = get_webhook_url_from_business_profile(&business_profile).ok();
+    let delivery_start = std::time::Instant::now();
     let trigger_webhook_result = trigger_webhook_to_merchant(
         state.clone(),
         business_profile,
@@ -271,12 +276,16 @@ pub(crate) async fn trigger_webhook_and_raise_event(
         process_tracker,
     )
     .await;
+    let latency = delivery_start.elapsed().as_millis();
 
     let _ = raise_webhooks_analytics_event(
         state,
         trigger_webhook_result,
         content,
         merchant_id,
+        profile_id,
+        webhook_url,
+        latency,
         event,
         merchant_key_store,
     )
@@ -502,11 +511,15 @@ async fn trigger_webhook_to_merchant(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn raise_webhooks_analytics_event(
     state: SessionState,
     trigger_webhook_result: CustomResult<(), errors::WebhooksFlowError>,
     content: Option<api::OutgoingWebhookContent>,
     merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    webhook_url: Option<String>,
+    latency: u128,
     event: domain::Event,
     merchant_key_store: &domain::MerchantKeyStore,
 ) {
@@ -573,6 +586,9 @@ async fn raise_webhooks_analytics_event(
         event.initial_attempt_id,
         status_code,
         event.delivery_attempt,
+        Some(profile_id),
+        webhook_url,
+        Some(latency),
     );
     state.event_handler().log_event(&webhook_event);
 }
@@ -718,6 +734,10 @@ pub(crate) fn get_outgoing_webhook_request(
         Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
             stripe_webhooks::StripeOutgoingWebhook,
         >(outgoing_webhook, business_profile),
+        #[cfg(feature = "adyen")]
+        Some(api_models::enums::Connector::Adyen) => get_outgoing_webhook_request_inner::<
+            adyen_webhooks::AdyenNotification,
+        >(outgoing_webhook, business_profile),
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
@@ -1024,6 +1044,21 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::MetricAlertDetails(alert_details) => {
+                Self::MetricAlert {
+                    rule_id: alert_details.rule_id.clone(),
+                    alert_id: alert_details.alert_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_details) => {
+                Self::Subscription {
+                    subscription_id: subscription_details.subscription_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::InvoiceDetails(invoice_details) => Self::Invoice {
+                subscription_id: invoice_details.subscription_id.clone(),
+                invoice_id: invoice_details.invoice_id.clone(),
+            },
         }
     }
 }
@@ -1068,5 +1103,26 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::MetricAlert { rule_id, alert_id } => {
+            OutgoingWebhookEventContent::MetricAlert {
+                rule_id,
+                alert_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::Subscription { subscription_id } => {
+            OutgoingWebhookEventContent::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::Invoice {
+            subscription_id,
+            invoice_id,
+        } => OutgoingWebhookEventContent::Invoice {
+            subscription_id,
+            invoice_id,
+            content: serde_json::Value::Null,
+        },
     })
 }
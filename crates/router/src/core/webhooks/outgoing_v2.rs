@@ -179,6 +179,9 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     );
 
     let merchant_id = business_profile.merchant_id.clone();
+    let profile_id = business_profile.get_id().clone();
+    let webhook_url = business_profile.get_webhook_url_from_profile().ok();
+    let delivery_start = std::time::Instant::now();
     let trigger_webhook_result = trigger_webhook_to_merchant(
         state.clone(),
         business_profile,
@@ -188,10 +191,19 @@ pub(crate) async fn trigger_webhook_and_raise_event(
         delivery_attempt,
     )
     .await;
+    let latency = delivery_start.elapsed().as_millis();
 
-    let _ =
-        raise_webhooks_analytics_event(state, trigger_webhook_result, content, merchant_id, event)
-            .await;
+    let _ = raise_webhooks_analytics_event(
+        state,
+        trigger_webhook_result,
+        content,
+        merchant_id,
+        profile_id,
+        webhook_url,
+        latency,
+        event,
+    )
+    .await;
 }
 
 async fn trigger_webhook_to_merchant(
@@ -244,6 +256,7 @@ async fn trigger_webhook_to_merchant(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn raise_webhooks_analytics_event(
     state: SessionState,
     trigger_webhook_result: CustomResult<
@@ -252,6 +265,9 @@ async fn raise_webhooks_analytics_event(
     >,
     content: Option<api::OutgoingWebhookContent>,
     merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    webhook_url: Option<String>,
+    latency: u128,
     fallback_event: domain::Event,
 ) {
     let (updated_event, optional_error) = match trigger_webhook_result {
@@ -307,6 +323,9 @@ async fn raise_webhooks_analytics_event(
         updated_event.initial_attempt_id,
         status_code,
         updated_event.delivery_attempt,
+        Some(profile_id),
+        webhook_url,
+        Some(latency),
     );
     state.event_handler().log_event(&webhook_event);
 }
@@ -616,6 +635,21 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::MetricAlertDetails(alert_details) => {
+                Self::MetricAlert {
+                    rule_id: alert_details.rule_id.clone(),
+                    alert_id: alert_details.alert_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_details) => {
+                Self::Subscription {
+                    subscription_id: subscription_details.subscription_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::InvoiceDetails(invoice_details) => Self::Invoice {
+                subscription_id: invoice_details.subscription_id.clone(),
+                invoice_id: invoice_details.invoice_id.clone(),
+            },
         }
     }
 }
@@ -657,6 +691,23 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 mandate_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::MetricAlert { rule_id, alert_id } => Self::MetricAlert {
+                rule_id,
+                alert_id,
+                content: serde_json::Value::Null,
+            },
+            diesel_models::EventMetadata::Subscription { subscription_id } => Self::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            },
+            diesel_models::EventMetadata::Invoice {
+                subscription_id,
+                invoice_id,
+            } => Self::Invoice {
+                subscription_id,
+                invoice_id,
+                content: serde_json::Value::Null,
+            },
         }
     }
 }
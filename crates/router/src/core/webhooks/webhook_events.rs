@@ -14,7 +14,7 @@ use crate::{
 };
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
-const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+pub(crate) const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -1,3 +1,4 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod authentication;
@@ -9,6 +10,7 @@ pub mod business_profile;
 pub mod callback_mapper;
 pub mod capture;
 pub mod configs;
+pub mod customer_deduplication;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -29,11 +31,15 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method_session;
+pub mod payout_batch;
 pub mod refund;
 pub mod relay;
+pub mod reusable_payment_link;
+pub mod revenue_recovery;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -93,12 +99,14 @@ pub trait StorageInterface:
     Send
     + Sync
     + dyn_clone::DynClone
+    + account_updater::AccountUpdaterInterface
     + address::AddressInterface
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
     + customers::CustomerInterface<Error = StorageError>
+    + customer_deduplication::CustomerDeduplicationInterface
     + dashboard_metadata::DashboardMetadataInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
@@ -120,6 +128,10 @@ pub trait StorageInterface:
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface<Error = StorageError>
     + PayoutsInterface<Error = StorageError>
+    + payout_batch::PayoutBatchInterface
+    + reusable_payment_link::ReusablePaymentLinkInterface
+    + subscription::SubscriptionInterface
+    + revenue_recovery::RevenueRecoveryInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + CardsInfoInterface<Error = StorageError>
@@ -0,0 +1,205 @@
+#[cfg(feature = "v1")]
+use common_utils::types::keymanager::KeyManagerState;
+use error_stack::report;
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+#[cfg(feature = "v1")]
+use crate::types::domain::{self, behaviour::ReverseConversion};
+#[cfg(feature = "v1")]
+use crate::types::storage::enums;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AccountUpdaterInterface {
+    async fn insert_payment_method_account_update(
+        &self,
+        account_update: storage::PaymentMethodAccountUpdateNew,
+    ) -> CustomResult<storage::PaymentMethodAccountUpdate, errors::StorageError>;
+
+    async fn list_payment_method_account_updates_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodAccountUpdate>, errors::StorageError>;
+
+    /// Pages through the payment methods of a merchant, irrespective of the customer they belong to
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_methods_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method: enums::PaymentMethod,
+        status: enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_account_update(
+        &self,
+        account_update: storage::PaymentMethodAccountUpdateNew,
+    ) -> CustomResult<storage::PaymentMethodAccountUpdate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        account_update
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payment_method_account_updates_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodAccountUpdate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodAccountUpdate::list_by_merchant_id_payment_method_id(
+            &conn,
+            merchant_id,
+            payment_method_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_methods_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method: enums::PaymentMethod,
+        status: enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let payment_methods = storage::PaymentMethod::find_by_merchant_id_payment_method_status(
+            &conn,
+            merchant_id,
+            payment_method,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        let mut domain_payment_methods = Vec::with_capacity(payment_methods.len());
+        for payment_method in payment_methods {
+            domain_payment_methods.push(
+                payment_method
+                    .convert(
+                        state,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(domain_payment_methods)
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for MockDb {
+    async fn insert_payment_method_account_update(
+        &self,
+        _account_update: storage::PaymentMethodAccountUpdateNew,
+    ) -> CustomResult<storage::PaymentMethodAccountUpdate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payment_method_account_updates_by_merchant_id_payment_method_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_id: &str,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodAccountUpdate>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_methods_by_merchant_id_payment_method_status(
+        &self,
+        _state: &KeyManagerState,
+        _key_store: &domain::MerchantKeyStore,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method: enums::PaymentMethod,
+        _status: enums::PaymentMethodStatus,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for KafkaStore {
+    async fn insert_payment_method_account_update(
+        &self,
+        account_update: storage::PaymentMethodAccountUpdateNew,
+    ) -> CustomResult<storage::PaymentMethodAccountUpdate, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_account_update(account_update)
+            .await
+    }
+
+    async fn list_payment_method_account_updates_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethodAccountUpdate>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_method_account_updates_by_merchant_id_payment_method_id(
+                merchant_id,
+                payment_method_id,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_methods_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method: enums::PaymentMethod,
+        status: enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_methods_by_merchant_id_payment_method_status(
+                state,
+                key_store,
+                merchant_id,
+                payment_method,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
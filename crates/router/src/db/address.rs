@@ -379,6 +379,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             let address = match storage_scheme {
@@ -465,6 +466,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Update(key.clone(), &field, Some(address.updated_by.as_str())),
+                Some(&merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -546,6 +548,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Insert,
+                None,
             ))
             .await;
             match storage_scheme {
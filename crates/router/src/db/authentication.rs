@@ -1,12 +1,9 @@
 use diesel_models::authentication::AuthenticationUpdateInternal;
-use error_stack::report;
-use router_env::{instrument, tracing};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, enums},
 };
 
 #[async_trait::async_trait]
@@ -14,87 +11,433 @@ pub trait AuthenticationInterface {
     async fn insert_authentication(
         &self,
         authentication: storage::AuthenticationNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError>;
 
     async fn find_authentication_by_merchant_id_authentication_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         authentication_id: &common_utils::id_type::AuthenticationId,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError>;
 
     async fn find_authentication_by_merchant_id_connector_authentication_id(
         &self,
         merchant_id: common_utils::id_type::MerchantId,
         connector_authentication_id: String,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError>;
 
     async fn update_authentication_by_merchant_id_authentication_id(
         &self,
         previous_state: storage::Authentication,
         authentication_update: storage::AuthenticationUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl AuthenticationInterface for Store {
-    #[instrument(skip_all)]
-    async fn insert_authentication(
-        &self,
-        authentication: storage::AuthenticationNew,
-    ) -> CustomResult<storage::Authentication, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        authentication
-            .insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::fallback_reverse_lookup_not_found;
+    use diesel_models::{authentication::AuthenticationUpdateInternal, kv};
+    use error_stack::{report, ResultExt};
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    };
 
-    #[instrument(skip_all)]
-    async fn find_authentication_by_merchant_id_authentication_id(
-        &self,
+    use super::AuthenticationInterface;
+    use crate::{
+        connection,
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self, enums},
+        utils::db_utils,
+    };
+
+    fn connector_authentication_lookup_id(
         merchant_id: &common_utils::id_type::MerchantId,
-        authentication_id: &common_utils::id_type::AuthenticationId,
-    ) -> CustomResult<storage::Authentication, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Authentication::find_by_merchant_id_authentication_id(
-            &conn,
-            merchant_id,
-            authentication_id,
+        connector_authentication_id: &str,
+    ) -> String {
+        format!(
+            "mid_{}_conn_authn_{}",
+            merchant_id.get_string_repr(),
+            connector_authentication_id
         )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
-    async fn find_authentication_by_merchant_id_connector_authentication_id(
-        &self,
-        merchant_id: common_utils::id_type::MerchantId,
-        connector_authentication_id: String,
-    ) -> CustomResult<storage::Authentication, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Authentication::find_authentication_by_merchant_id_connector_authentication_id(
-            &conn,
-            &merchant_id,
-            &connector_authentication_id,
-        )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
+    #[async_trait::async_trait]
+    impl AuthenticationInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_authentication(
+            &self,
+            authentication: storage::AuthenticationNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Authentication>(
+                self,
+                storage_scheme,
+                Op::Insert,
+                None,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    authentication
+                        .insert(&conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let merchant_id = authentication.merchant_id.clone();
+                    let authentication_id = authentication.authentication_id.clone();
+                    let key = PartitionKey::MerchantIdAuthenticationId {
+                        merchant_id: &merchant_id,
+                        authentication_id: &authentication_id,
+                    };
+                    let key_str = key.to_string();
+                    let field = format!("authentication_{}", authentication_id.get_string_repr());
+
+                    if let Some(connector_authentication_id) =
+                        &authentication.connector_authentication_id
+                    {
+                        let reverse_lookup_entry = diesel_models::ReverseLookupNew {
+                            sk_id: field.clone(),
+                            pk_id: key_str.clone(),
+                            lookup_id: connector_authentication_lookup_id(
+                                &merchant_id,
+                                connector_authentication_id,
+                            ),
+                            source: "authentication".to_string(),
+                            updated_by: storage_scheme.to_string(),
+                        };
+                        self.insert_reverse_lookup(reverse_lookup_entry, storage_scheme)
+                            .await?;
+                    }
+
+                    let storage_authentication = storage::Authentication::from(&authentication);
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: Box::new(kv::Insertable::Authentication(Box::new(
+                                authentication,
+                            ))),
+                        },
+                    };
+
+                    match Box::pin(kv_wrapper::<storage::Authentication, _, _>(
+                        self,
+                        KvOperation::<storage::Authentication>::HSetNx(
+                            &field,
+                            &storage_authentication,
+                            redis_entry,
+                        ),
+                        key,
+                    ))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "authentication_id",
+                            key: Some(authentication_id.get_string_repr().to_string()),
+                        }
+                        .into()),
+                        Ok(HsetnxReply::KeySet) => Ok(storage_authentication),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn find_authentication_by_merchant_id_authentication_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            authentication_id: &common_utils::id_type::AuthenticationId,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Authentication::find_by_merchant_id_authentication_id(
+                    &conn,
+                    merchant_id,
+                    authentication_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Authentication>(
+                self,
+                storage_scheme,
+                Op::Find,
+                Some(merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdAuthenticationId {
+                        merchant_id,
+                        authentication_id,
+                    };
+                    let field = format!("authentication_{}", authentication_id.get_string_repr());
+
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            Box::pin(kv_wrapper(
+                                self,
+                                KvOperation::<storage::Authentication>::HGet(&field),
+                                key,
+                            ))
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        async fn find_authentication_by_merchant_id_connector_authentication_id(
+            &self,
+            merchant_id: common_utils::id_type::MerchantId,
+            connector_authentication_id: String,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Authentication::find_authentication_by_merchant_id_connector_authentication_id(
+                    &conn,
+                    &merchant_id,
+                    &connector_authentication_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Authentication>(
+                self,
+                storage_scheme,
+                Op::Find,
+                Some(&merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let lookup_id = connector_authentication_lookup_id(
+                        &merchant_id,
+                        &connector_authentication_id,
+                    );
+                    let lookup = fallback_reverse_lookup_not_found!(
+                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                            .await,
+                        database_call().await
+                    );
+
+                    let key = PartitionKey::CombinationKey {
+                        combination: &lookup.pk_id,
+                    };
+
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            Box::pin(kv_wrapper(
+                                self,
+                                KvOperation::<storage::Authentication>::HGet(&lookup.sk_id),
+                                key,
+                            ))
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn update_authentication_by_merchant_id_authentication_id(
+            &self,
+            previous_state: storage::Authentication,
+            authentication_update: storage::AuthenticationUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let merchant_id = previous_state.merchant_id.clone();
+            let authentication_id = previous_state.authentication_id.clone();
+            let key = PartitionKey::MerchantIdAuthenticationId {
+                merchant_id: &merchant_id,
+                authentication_id: &authentication_id,
+            };
+            let field = format!("authentication_{}", authentication_id.get_string_repr());
+            // Authentications do not track `updated_by`, the configured scheme is used in its place
+            let updated_by = storage_scheme.to_string();
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Authentication>(
+                self,
+                storage_scheme,
+                Op::Update(key.clone(), &field, Some(updated_by.as_str())),
+                Some(&merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage::Authentication::update_by_merchant_id_authentication_id(
+                        &conn,
+                        previous_state.merchant_id,
+                        previous_state.authentication_id,
+                        authentication_update,
+                    )
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key_str = key.to_string();
+                    let authentication_update =
+                        AuthenticationUpdateInternal::from(authentication_update);
+
+                    if let Some(connector_authentication_id) = authentication_update
+                        .connector_authentication_id
+                        .as_ref()
+                        .filter(|connector_authentication_id| {
+                            previous_state.connector_authentication_id.as_ref()
+                                != Some(*connector_authentication_id)
+                        })
+                    {
+                        let reverse_lookup_entry = diesel_models::ReverseLookupNew {
+                            sk_id: field.clone(),
+                            pk_id: key_str.clone(),
+                            lookup_id: connector_authentication_lookup_id(
+                                &merchant_id,
+                                connector_authentication_id,
+                            ),
+                            source: "authentication".to_string(),
+                            updated_by: storage_scheme.to_string(),
+                        };
+                        self.insert_reverse_lookup(reverse_lookup_entry, storage_scheme)
+                            .await?;
+                    }
+
+                    let updated_authentication = authentication_update
+                        .clone()
+                        .apply_changeset(previous_state.clone());
+
+                    let redis_value = serde_json::to_string(&updated_authentication)
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: Box::new(kv::Updateable::AuthenticationUpdate(Box::new(
+                                kv::AuthenticationUpdateMems {
+                                    orig: previous_state,
+                                    update_data: authentication_update,
+                                },
+                            ))),
+                        },
+                    };
+
+                    Box::pin(kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::<storage::Authentication>::Hset(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        key,
+                    ))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_authentication)
+                }
+            }
+        }
     }
+}
 
-    #[instrument(skip_all)]
-    async fn update_authentication_by_merchant_id_authentication_id(
-        &self,
-        previous_state: storage::Authentication,
-        authentication_update: storage::AuthenticationUpdate,
-    ) -> CustomResult<storage::Authentication, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Authentication::update_by_merchant_id_authentication_id(
-            &conn,
-            previous_state.merchant_id,
-            previous_state.authentication_id,
-            authentication_update,
-        )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::report;
+    use router_env::{instrument, tracing};
+
+    use super::AuthenticationInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl AuthenticationInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_authentication(
+            &self,
+            authentication: storage::AuthenticationNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            authentication
+                .insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_authentication_by_merchant_id_authentication_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            authentication_id: &common_utils::id_type::AuthenticationId,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Authentication::find_by_merchant_id_authentication_id(
+                &conn,
+                merchant_id,
+                authentication_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        async fn find_authentication_by_merchant_id_connector_authentication_id(
+            &self,
+            merchant_id: common_utils::id_type::MerchantId,
+            connector_authentication_id: String,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Authentication::find_authentication_by_merchant_id_connector_authentication_id(
+                &conn,
+                &merchant_id,
+                &connector_authentication_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn update_authentication_by_merchant_id_authentication_id(
+            &self,
+            previous_state: storage::Authentication,
+            authentication_update: storage::AuthenticationUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Authentication, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Authentication::update_by_merchant_id_authentication_id(
+                &conn,
+                previous_state.merchant_id,
+                previous_state.authentication_id,
+                authentication_update,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -103,6 +446,7 @@ impl AuthenticationInterface for MockDb {
     async fn insert_authentication(
         &self,
         authentication: storage::AuthenticationNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         let mut authentications = self.authentications.lock().await;
         if authentications.iter().any(|authentication_inner| {
@@ -181,6 +525,7 @@ impl AuthenticationInterface for MockDb {
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         authentication_id: &common_utils::id_type::AuthenticationId,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         let authentications = self.authentications.lock().await;
         authentications
@@ -197,6 +542,7 @@ impl AuthenticationInterface for MockDb {
         &self,
         _merchant_id: common_utils::id_type::MerchantId,
         _connector_authentication_id: String,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
@@ -205,6 +551,7 @@ impl AuthenticationInterface for MockDb {
         &self,
         previous_state: storage::Authentication,
         authentication_update: storage::AuthenticationUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         let mut authentications = self.authentications.lock().await;
         let authentication_id = previous_state.authentication_id.clone();
@@ -32,13 +32,18 @@ pub trait CaptureInterface {
 
 #[cfg(feature = "kv_store")]
 mod storage {
-    use error_stack::report;
+    use diesel_models::kv;
+    use error_stack::{report, ResultExt};
+    use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    };
 
     use super::CaptureInterface;
     use crate::{
         connection,
-        core::errors::{self, CustomResult},
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
         services::Store,
         types::storage::{capture::*, enums},
     };
@@ -49,16 +54,60 @@ mod storage {
         async fn insert_capture(
             &self,
             capture: CaptureNew,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                capture
-                    .insert(&conn)
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, Capture>(
+                self,
+                storage_scheme,
+                Op::Insert,
+                None,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    capture
+                        .insert(&conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let merchant_id = capture.merchant_id.clone();
+                    let payment_id = capture.payment_id.clone();
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &merchant_id,
+                        payment_id: &payment_id,
+                    };
+                    let key_str = key.to_string();
+                    let field = format!("capture_{}", capture.capture_id);
+
+                    let storage_capture = Capture::from(&capture);
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: Box::new(kv::Insertable::Capture(Box::new(capture))),
+                        },
+                    };
+
+                    match Box::pin(kv_wrapper::<Capture, _, _>(
+                        self,
+                        KvOperation::<Capture>::HSetNx(&field, &storage_capture, redis_entry),
+                        key,
+                    ))
                     .await
-                    .map_err(|error| report!(errors::StorageError::from(error)))
-            };
-            db_call().await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "capture",
+                            key: Some(storage_capture.capture_id),
+                        }
+                        .into()),
+                        Ok(HsetnxReply::KeySet) => Ok(storage_capture),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
         }
 
         #[instrument(skip_all)]
@@ -66,15 +115,60 @@ mod storage {
             &self,
             this: Capture,
             capture: CaptureUpdate,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                this.update_with_capture_id(&conn, capture)
-                    .await
-                    .map_err(|error| report!(errors::StorageError::from(error)))
+            let key = PartitionKey::MerchantIdPaymentId {
+                merchant_id: &this.merchant_id,
+                payment_id: &this.payment_id,
             };
-            db_call().await
+            let field = format!("capture_{}", this.capture_id);
+            // Captures do not track `updated_by`, the configured scheme is used in its place
+            let updated_by = storage_scheme.to_string();
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, Capture>(
+                self,
+                storage_scheme,
+                Op::Update(key.clone(), &field, Some(updated_by.as_str())),
+                Some(&this.merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update_with_capture_id(&conn, capture)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key_str = key.to_string();
+                    let updated_capture = capture.clone().apply_changeset(this.clone());
+
+                    let redis_value = serde_json::to_string(&updated_capture)
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: Box::new(kv::Updateable::CaptureUpdate(Box::new(
+                                kv::CaptureUpdateMems {
+                                    orig: this,
+                                    update_data: capture,
+                                },
+                            ))),
+                        },
+                    };
+
+                    Box::pin(kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::<Capture>::Hset((&field, redis_value), redis_entry),
+                        key,
+                    ))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_capture)
+                }
+            }
         }
 
         #[instrument(skip_all)]
@@ -83,7 +177,7 @@ mod storage {
             merchant_id: &common_utils::id_type::MerchantId,
             payment_id: &common_utils::id_type::PaymentId,
             authorized_attempt_id: &str,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<Capture>, errors::StorageError> {
             let db_call = || async {
                 let conn = connection::pg_connection_read(self).await?;
@@ -96,7 +190,44 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
             };
-            db_call().await
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, Capture>(
+                self,
+                storage_scheme,
+                Op::Find,
+                Some(merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => db_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id,
+                    };
+                    let redis_fut = async {
+                        Box::pin(kv_wrapper(
+                            self,
+                            KvOperation::<Capture>::Scan("capture_*"),
+                            key,
+                        ))
+                        .await?
+                        .try_into_scan()
+                        .map(|captures| {
+                            captures
+                                .into_iter()
+                                .filter(|capture| {
+                                    capture.authorized_attempt_id == authorized_attempt_id
+                                })
+                                .collect()
+                        })
+                    };
+                    // Captures written before the merchant moved to KV only exist in the database
+                    Box::pin(storage_impl::utils::find_all_combined_kv_database(
+                        redis_fut, db_call, None,
+                    ))
+                    .await
+                }
+            }
         }
     }
 }
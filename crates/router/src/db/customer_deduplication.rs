@@ -0,0 +1,548 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait CustomerDeduplicationInterface {
+    async fn insert_customer_blind_index(
+        &self,
+        blind_index: storage::CustomerBlindIndexNew,
+    ) -> CustomResult<storage::CustomerBlindIndex, errors::StorageError>;
+
+    async fn find_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError>;
+
+    async fn find_customer_blind_indexes_by_merchant_id_field_hashed_value(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        field: enums::CustomerBlindIndexField,
+        hashed_value: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError>;
+
+    async fn list_duplicate_customer_blind_indexes_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<(enums::CustomerBlindIndexField, String, i64)>, errors::StorageError>;
+
+    async fn delete_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_customer_merge(
+        &self,
+        customer_merge: storage::CustomerMergeNew,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError>;
+
+    async fn update_customer_merge(
+        &self,
+        this: storage::CustomerMerge,
+        customer_merge: storage::CustomerMergeUpdate,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError>;
+
+    async fn find_in_progress_customer_merge_by_merchant_id_merged_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merged_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMerge>, errors::StorageError>;
+
+    async fn list_customer_merges_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerMerge>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_method_customer_id_and_locker_ids(
+        &self,
+        payment_method_id: &str,
+        new_customer_id: &common_utils::id_type::CustomerId,
+        locker_id: Option<String>,
+        network_token_locker_id: Option<String>,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
+    async fn update_mandates_customer_id_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_intents_customer_id_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerDeduplicationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_customer_blind_index(
+        &self,
+        blind_index: storage::CustomerBlindIndexNew,
+    ) -> CustomResult<storage::CustomerBlindIndex, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        blind_index
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerBlindIndex::find_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_blind_indexes_by_merchant_id_field_hashed_value(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        field: enums::CustomerBlindIndexField,
+        hashed_value: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerBlindIndex::find_by_merchant_id_field_hashed_value(
+            &conn,
+            merchant_id,
+            field,
+            hashed_value,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_duplicate_customer_blind_indexes_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<(enums::CustomerBlindIndexField, String, i64)>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerBlindIndex::list_duplicates_by_merchant_id(
+            &conn,
+            merchant_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::CustomerBlindIndex::delete_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_customer_merge(
+        &self,
+        customer_merge: storage::CustomerMergeNew,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_merge
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_customer_merge(
+        &self,
+        this: storage::CustomerMerge,
+        customer_merge: storage::CustomerMergeUpdate,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, customer_merge.into())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_in_progress_customer_merge_by_merchant_id_merged_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merged_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMerge>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::CustomerMerge::find_in_progress_by_merchant_id_merged_customer_id(
+            &conn,
+            merchant_id,
+            merged_customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_merges_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerMerge>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerMerge::list_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn update_payment_method_customer_id_and_locker_ids(
+        &self,
+        payment_method_id: &str,
+        new_customer_id: &common_utils::id_type::CustomerId,
+        locker_id: Option<String>,
+        network_token_locker_id: Option<String>,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethod::update_customer_id_and_locker_ids_by_payment_method_id(
+            &conn,
+            payment_method_id,
+            new_customer_id,
+            locker_id,
+            network_token_locker_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_mandates_customer_id_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Mandate::update_customer_id_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            new_customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn update_payment_intents_customer_id_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        diesel_models::PaymentIntent::update_customer_id_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            new_customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerDeduplicationInterface for MockDb {
+    async fn insert_customer_blind_index(
+        &self,
+        _blind_index: storage::CustomerBlindIndexNew,
+    ) -> CustomResult<storage::CustomerBlindIndex, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_customer_blind_indexes_by_merchant_id_field_hashed_value(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _field: enums::CustomerBlindIndexField,
+        _hashed_value: &str,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_duplicate_customer_blind_indexes_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<(enums::CustomerBlindIndexField, String, i64)>, errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_customer_merge(
+        &self,
+        _customer_merge: storage::CustomerMergeNew,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_customer_merge(
+        &self,
+        _this: storage::CustomerMerge,
+        _customer_merge: storage::CustomerMergeUpdate,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_in_progress_customer_merge_by_merchant_id_merged_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merged_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMerge>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_customer_merges_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerMerge>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_method_customer_id_and_locker_ids(
+        &self,
+        _payment_method_id: &str,
+        _new_customer_id: &common_utils::id_type::CustomerId,
+        _locker_id: Option<String>,
+        _network_token_locker_id: Option<String>,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_mandates_customer_id_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_intents_customer_id_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerDeduplicationInterface for KafkaStore {
+    async fn insert_customer_blind_index(
+        &self,
+        blind_index: storage::CustomerBlindIndexNew,
+    ) -> CustomResult<storage::CustomerBlindIndex, errors::StorageError> {
+        self.diesel_store
+            .insert_customer_blind_index(blind_index)
+            .await
+    }
+
+    async fn find_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError> {
+        self.diesel_store
+            .find_customer_blind_indexes_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    async fn find_customer_blind_indexes_by_merchant_id_field_hashed_value(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        field: enums::CustomerBlindIndexField,
+        hashed_value: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerBlindIndex>, errors::StorageError> {
+        self.diesel_store
+            .find_customer_blind_indexes_by_merchant_id_field_hashed_value(
+                merchant_id,
+                field,
+                hashed_value,
+                limit,
+            )
+            .await
+    }
+
+    async fn list_duplicate_customer_blind_indexes_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<(enums::CustomerBlindIndexField, String, i64)>, errors::StorageError>
+    {
+        self.diesel_store
+            .list_duplicate_customer_blind_indexes_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn delete_customer_blind_indexes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_customer_blind_indexes_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    async fn insert_customer_merge(
+        &self,
+        customer_merge: storage::CustomerMergeNew,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError> {
+        self.diesel_store
+            .insert_customer_merge(customer_merge)
+            .await
+    }
+
+    async fn update_customer_merge(
+        &self,
+        this: storage::CustomerMerge,
+        customer_merge: storage::CustomerMergeUpdate,
+    ) -> CustomResult<storage::CustomerMerge, errors::StorageError> {
+        self.diesel_store
+            .update_customer_merge(this, customer_merge)
+            .await
+    }
+
+    async fn find_in_progress_customer_merge_by_merchant_id_merged_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merged_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMerge>, errors::StorageError> {
+        self.diesel_store
+            .find_in_progress_customer_merge_by_merchant_id_merged_customer_id(
+                merchant_id,
+                merged_customer_id,
+            )
+            .await
+    }
+
+    async fn list_customer_merges_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerMerge>, errors::StorageError> {
+        self.diesel_store
+            .list_customer_merges_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_method_customer_id_and_locker_ids(
+        &self,
+        payment_method_id: &str,
+        new_customer_id: &common_utils::id_type::CustomerId,
+        locker_id: Option<String>,
+        network_token_locker_id: Option<String>,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .update_payment_method_customer_id_and_locker_ids(
+                payment_method_id,
+                new_customer_id,
+                locker_id,
+                network_token_locker_id,
+            )
+            .await
+    }
+
+    async fn update_mandates_customer_id_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .update_mandates_customer_id_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                new_customer_id,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn update_payment_intents_customer_id_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .update_payment_intents_customer_id_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                new_customer_id,
+            )
+            .await
+    }
+}
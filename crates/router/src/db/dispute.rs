@@ -1,14 +1,11 @@
 use std::collections::HashMap;
 
-use error_stack::report;
 use hyperswitch_domain_models::disputes;
-use router_env::{instrument, tracing};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage::{self, DisputeDbExt},
+    types::storage::{self, enums},
 };
 
 #[async_trait::async_trait]
@@ -16,6 +13,7 @@ pub trait DisputeInterface {
     async fn insert_dispute(
         &self,
         dispute: storage::DisputeNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError>;
 
     async fn find_by_merchant_id_payment_id_connector_dispute_id(
@@ -23,12 +21,14 @@ pub trait DisputeInterface {
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
         connector_dispute_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Dispute>, errors::StorageError>;
 
     async fn find_dispute_by_merchant_id_dispute_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError>;
 
     async fn find_disputes_by_constraints(
@@ -41,12 +41,14 @@ pub trait DisputeInterface {
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
 
     async fn update_dispute(
         &self,
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError>;
 
     async fn get_dispute_status_with_count(
@@ -55,104 +57,536 @@ pub trait DisputeInterface {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::StorageError>;
-}
 
-#[async_trait::async_trait]
-impl DisputeInterface for Store {
-    #[instrument(skip_all)]
-    async fn insert_dispute(
-        &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        dispute
-            .insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-    }
-
-    #[instrument(skip_all)]
-    async fn find_by_merchant_id_payment_id_connector_dispute_id(
+    /// Pages through the financial records of the disputes created within the time range,
+    /// skipping the disputes which do not have any
+    async fn get_dispute_financial_records(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
-        payment_id: &common_utils::id_type::PaymentId,
-        connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
-            &conn,
-            merchant_id,
-            payment_id,
-            connector_dispute_id,
-        )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::DisputeFinancialRecordsEntry>, errors::StorageError>;
+}
 
-    #[instrument(skip_all)]
-    async fn find_dispute_by_merchant_id_dispute_id(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::fallback_reverse_lookup_not_found;
+    use diesel_models::{dispute::DisputeUpdateInternal, kv};
+    use error_stack::{report, ResultExt};
+    use hyperswitch_domain_models::disputes;
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    };
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self, enums, DisputeDbExt},
+        utils::db_utils,
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_dispute(
+            &self,
+            dispute: storage::DisputeNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Dispute, errors::StorageError> {
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Dispute>(
+                self,
+                storage_scheme,
+                Op::Insert,
+                None,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    dispute
+                        .insert(&conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let merchant_id = dispute.merchant_id.clone();
+                    let payment_id = dispute.payment_id.clone();
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &merchant_id,
+                        payment_id: &payment_id,
+                    };
+                    let key_str = key.to_string();
+                    let field = format!("dispute_{}", dispute.dispute_id);
+
+                    let reverse_lookup_entry = diesel_models::ReverseLookupNew {
+                        sk_id: field.clone(),
+                        pk_id: key_str.clone(),
+                        lookup_id: format!(
+                            "mid_{}_dispute_{}",
+                            merchant_id.get_string_repr(),
+                            dispute.dispute_id
+                        ),
+                        source: "dispute".to_string(),
+                        updated_by: storage_scheme.to_string(),
+                    };
+                    self.insert_reverse_lookup(reverse_lookup_entry, storage_scheme)
+                        .await?;
+
+                    let storage_dispute = storage::Dispute::from(&dispute);
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: Box::new(kv::Insertable::Dispute(Box::new(dispute))),
+                        },
+                    };
+
+                    match Box::pin(kv_wrapper::<storage::Dispute, _, _>(
+                        self,
+                        KvOperation::<storage::Dispute>::HSetNx(
+                            &field,
+                            &storage_dispute,
+                            redis_entry,
+                        ),
+                        key,
+                    ))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "dispute",
+                            key: Some(storage_dispute.dispute_id),
+                        }
+                        .into()),
+                        Ok(HsetnxReply::KeySet) => Ok(storage_dispute),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            connector_dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                    connector_dispute_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Dispute>(
+                self,
+                storage_scheme,
+                Op::Find,
+                Some(merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id,
+                    };
+
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            Box::pin(kv_wrapper(
+                                self,
+                                KvOperation::<storage::Dispute>::Scan("dispute_*"),
+                                key,
+                            ))
+                            .await?
+                            .try_into_scan()?
+                            .into_iter()
+                            .find(|dispute| dispute.connector_dispute_id == connector_dispute_id)
+                            .map(Some)
+                            .ok_or(report!(redis_interface::errors::RedisError::NotFound))
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Dispute, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Dispute>(
+                self,
+                storage_scheme,
+                Op::Find,
+                Some(merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let lookup_id = format!(
+                        "mid_{}_dispute_{}",
+                        merchant_id.get_string_repr(),
+                        dispute_id
+                    );
+                    let lookup = fallback_reverse_lookup_not_found!(
+                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                            .await,
+                        database_call().await
+                    );
+
+                    let key = PartitionKey::CombinationKey {
+                        combination: &lookup.pk_id,
+                    };
+
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            Box::pin(kv_wrapper(
+                                self,
+                                KvOperation::<storage::Dispute>::HGet(&lookup.sk_id),
+                                key,
+                            ))
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn find_disputes_by_constraints(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_constraints: &disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Dispute>(
+                self,
+                storage_scheme,
+                Op::Find,
+                Some(merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id,
+                    };
+                    let redis_fut = async {
+                        Box::pin(kv_wrapper(
+                            self,
+                            KvOperation::<storage::Dispute>::Scan("dispute_*"),
+                            key,
+                        ))
+                        .await?
+                        .try_into_scan()
+                    };
+                    // Disputes raised before the merchant moved to KV only exist in the database
+                    Box::pin(storage_impl::utils::find_all_combined_kv_database(
+                        redis_fut,
+                        database_call,
+                        None,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn update_dispute(
+            &self,
+            this: storage::Dispute,
+            dispute: storage::DisputeUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Dispute, errors::StorageError> {
+            let merchant_id = this.merchant_id.clone();
+            let payment_id = this.payment_id.clone();
+            let key = PartitionKey::MerchantIdPaymentId {
+                merchant_id: &merchant_id,
+                payment_id: &payment_id,
+            };
+            let field = format!("dispute_{}", this.dispute_id);
+            // Disputes do not track `updated_by`, the configured scheme is used in its place
+            let updated_by = storage_scheme.to_string();
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, storage::Dispute>(
+                self,
+                storage_scheme,
+                Op::Update(key.clone(), &field, Some(updated_by.as_str())),
+                Some(&merchant_id),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update(&conn, dispute)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key_str = key.to_string();
+                    let dispute_update = DisputeUpdateInternal::from(dispute);
+                    let updated_dispute = dispute_update.clone().apply_changeset(this.clone());
+
+                    let redis_value = serde_json::to_string(&updated_dispute)
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: Box::new(kv::Updateable::DisputeUpdate(Box::new(
+                                kv::DisputeUpdateMems {
+                                    orig: this,
+                                    update_data: dispute_update,
+                                },
+                            ))),
+                        },
+                    };
+
+                    Box::pin(kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::<storage::Dispute>::Hset((&field, redis_value), redis_entry),
+                        key,
+                    ))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_dispute)
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn get_dispute_status_with_count(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+            time_range: &common_utils::types::TimeRange,
+        ) -> CustomResult<Vec<(common_enums::DisputeStatus, i64)>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::get_dispute_status_with_count(
+                &conn,
+                merchant_id,
+                profile_id_list,
+                time_range,
+            )
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+        }
 
-    #[instrument(skip_all)]
-    async fn find_disputes_by_constraints(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        dispute_constraints: &disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+        #[instrument(skip_all)]
+        async fn get_dispute_financial_records(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+            time_range: &common_utils::types::TimeRange,
+            limit: i64,
+            offset: i64,
+        ) -> CustomResult<Vec<storage::DisputeFinancialRecordsEntry>, errors::StorageError>
+        {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::get_financial_records_by_time_range(
+                &conn,
+                merchant_id,
+                profile_id_list,
+                time_range,
+                limit,
+                offset,
+            )
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
+}
 
-    #[instrument(skip_all)]
-    async fn find_disputes_by_merchant_id_payment_id(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        payment_id: &common_utils::id_type::PaymentId,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::report;
+    use hyperswitch_domain_models::disputes;
+    use router_env::{instrument, tracing};
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self, enums, DisputeDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_dispute(
+            &self,
+            dispute: storage::DisputeNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            dispute
+                .insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            connector_dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                &conn,
+                merchant_id,
+                payment_id,
+                connector_dispute_id,
+            )
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+        }
 
-    #[instrument(skip_all)]
-    async fn update_dispute(
-        &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        this.update(&conn, dispute)
+        #[instrument(skip_all)]
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_disputes_by_constraints(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_constraints: &disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn update_dispute(
+            &self,
+            this: storage::Dispute,
+            dispute: storage::DisputeUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            this.update(&conn, dispute)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn get_dispute_status_with_count(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+            time_range: &common_utils::types::TimeRange,
+        ) -> CustomResult<Vec<(common_enums::DisputeStatus, i64)>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::get_dispute_status_with_count(
+                &conn,
+                merchant_id,
+                profile_id_list,
+                time_range,
+            )
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+        }
 
-    #[instrument(skip_all)]
-    async fn get_dispute_status_with_count(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
-        time_range: &common_utils::types::TimeRange,
-    ) -> CustomResult<Vec<(common_enums::DisputeStatus, i64)>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::get_dispute_status_with_count(
-            &conn,
-            merchant_id,
-            profile_id_list,
-            time_range,
-        )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
+        #[instrument(skip_all)]
+        async fn get_dispute_financial_records(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+            time_range: &common_utils::types::TimeRange,
+            limit: i64,
+            offset: i64,
+        ) -> CustomResult<Vec<storage::DisputeFinancialRecordsEntry>, errors::StorageError>
+        {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Dispute::get_financial_records_by_time_range(
+                &conn,
+                merchant_id,
+                profile_id_list,
+                time_range,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -161,6 +595,7 @@ impl DisputeInterface for MockDb {
     async fn insert_dispute(
         &self,
         dispute: storage::DisputeNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         let evidence = dispute.evidence.ok_or(errors::StorageError::MockDbError)?;
 
@@ -200,6 +635,7 @@ impl DisputeInterface for MockDb {
             dispute_amount: dispute.dispute_amount,
             organization_id: dispute.organization_id,
             dispute_currency: dispute.dispute_currency,
+            financial_records: dispute.financial_records,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -211,6 +647,7 @@ impl DisputeInterface for MockDb {
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
         connector_dispute_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
         Ok(self
             .disputes
@@ -229,6 +666,7 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
@@ -326,6 +764,12 @@ impl DisputeInterface for MockDb {
                                     .end_time
                                     .map_or(true, |end_time| dispute_time <= end_time)
                         })
+                    && dispute_constraints.starting_after.as_ref().map_or(
+                        true,
+                        |(created_at, dispute_id)| {
+                            (dispute.created_at, &dispute.dispute_id) < (*created_at, dispute_id)
+                        },
+                    )
             })
             .skip(offset_usize)
             .take(limit_usize)
@@ -339,6 +783,7 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
@@ -353,6 +798,7 @@ impl DisputeInterface for MockDb {
         &self,
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         let mut locked_disputes = self.disputes.lock().await;
 
@@ -372,6 +818,7 @@ impl DisputeInterface for MockDb {
                 connector_reason_code,
                 challenge_required_by,
                 connector_updated_at,
+                financial_records,
             } => {
                 if connector_reason.is_some() {
                     dispute_to_update.connector_reason = connector_reason;
@@ -389,6 +836,10 @@ impl DisputeInterface for MockDb {
                     dispute_to_update.connector_updated_at = connector_updated_at;
                 }
 
+                if financial_records.is_some() {
+                    dispute_to_update.financial_records = financial_records;
+                }
+
                 dispute_to_update.dispute_stage = dispute_stage;
                 dispute_to_update.dispute_status = dispute_status;
                 dispute_to_update.connector_status = connector_status;
@@ -405,6 +856,9 @@ impl DisputeInterface for MockDb {
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
+            storage::DisputeUpdate::FinancialRecordsUpdate { financial_records } => {
+                dispute_to_update.financial_records = Some(financial_records);
+            }
         }
 
         dispute_to_update.modified_at = now;
@@ -455,6 +909,45 @@ impl DisputeInterface for MockDb {
             .into_iter()
             .collect::<Vec<(common_enums::DisputeStatus, i64)>>())
     }
+
+    async fn get_dispute_financial_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::DisputeFinancialRecordsEntry>, errors::StorageError> {
+        let locked_disputes = self.disputes.lock().await;
+
+        Ok(locked_disputes
+            .iter()
+            .filter(|d| {
+                d.merchant_id == *merchant_id
+                    && d.financial_records.is_some()
+                    && d.created_at >= time_range.start_time
+                    && time_range
+                        .end_time
+                        .as_ref()
+                        .map(|received_end_time| received_end_time >= &d.created_at)
+                        .unwrap_or(true)
+                    && profile_id_list
+                        .as_ref()
+                        .zip(d.profile_id.as_ref())
+                        .map(|(received_profile_list, received_profile_id)| {
+                            received_profile_list.contains(received_profile_id)
+                        })
+                        .unwrap_or(true)
+            })
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .map(|d| storage::DisputeFinancialRecordsEntry {
+                connector: d.connector.clone(),
+                connector_reason_code: d.connector_reason_code.clone(),
+                financial_records: d.financial_records.clone(),
+            })
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -467,7 +960,7 @@ mod tests {
         use common_utils::types::{AmountConvertor, MinorUnit, StringMinorUnitForConnector};
         use diesel_models::{
             dispute::DisputeNew,
-            enums::{DisputeStage, DisputeStatus},
+            enums::{DisputeStage, DisputeStatus, MerchantStorageScheme},
         };
         use hyperswitch_domain_models::disputes::DisputeListConstraints;
         use masking::Secret;
@@ -514,6 +1007,7 @@ mod tests {
                 dispute_amount: MinorUnit::new(1040),
                 organization_id: common_utils::id_type::OrganizationId::default(),
                 dispute_currency: Some(Currency::default()),
+                financial_records: None,
             }
         }
 
@@ -527,16 +1021,19 @@ mod tests {
                 common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
-                        "payment_1",
-                    ))
-                    .unwrap(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
+                            "payment_1",
+                        ))
+                        .unwrap(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -563,30 +1060,36 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
-                        "payment_1",
-                    ))
-                    .unwrap(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
+                            "payment_1",
+                        ))
+                        .unwrap(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
-                        "payment_1",
-                    ))
-                    .unwrap(),
-                    connector_dispute_id: "connector_dispute_2".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
+                            "payment_1",
+                        ))
+                        .unwrap(),
+                        connector_dispute_id: "connector_dispute_2".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -596,6 +1099,7 @@ mod tests {
                     &common_utils::id_type::PaymentId::try_from(Cow::Borrowed("payment_1"))
                         .unwrap(),
                     "connector_dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
                 )
                 .await
                 .unwrap();
@@ -618,29 +1122,39 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_dispute = mockdb
-                .find_dispute_by_merchant_id_dispute_id(&merchant_id, "dispute_1")
+                .find_dispute_by_merchant_id_dispute_id(
+                    &merchant_id,
+                    "dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -660,24 +1174,30 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -697,6 +1217,7 @@ mod tests {
                         dispute_stage: None,
                         reason: None,
                         time_range: None,
+                        starting_after: None,
                     },
                 )
                 .await
@@ -720,29 +1241,39 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_disputes = mockdb
-                .find_disputes_by_merchant_id_payment_id(&merchant_id, &payment_id)
+                .find_disputes_by_merchant_id_payment_id(
+                    &merchant_id,
+                    &payment_id,
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -756,7 +1287,7 @@ mod tests {
 
             use diesel_models::{
                 dispute::DisputeUpdate,
-                enums::{DisputeStage, DisputeStatus},
+                enums::{DisputeStage, DisputeStatus, MerchantStorageScheme},
             };
             use masking::Secret;
             use serde_json::Value;
@@ -783,13 +1314,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: merchant_id.clone(),
-                        payment_id: payment_id.clone(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: merchant_id.clone(),
+                            payment_id: payment_id.clone(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -804,7 +1338,9 @@ mod tests {
                             connector_reason_code: Some("updated_connector_reason_code".into()),
                             challenge_required_by: Some(datetime!(2019-01-10 0:00)),
                             connector_updated_at: Some(datetime!(2019-01-11 0:00)),
+                            financial_records: None,
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -867,13 +1403,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: merchant_id.clone(),
-                        payment_id: payment_id.clone(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: merchant_id.clone(),
+                            payment_id: payment_id.clone(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -884,6 +1423,7 @@ mod tests {
                             dispute_status: DisputeStatus::DisputeExpired,
                             connector_status: Some("updated_connector_status".into()),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -946,13 +1486,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: merchant_id.clone(),
-                        payment_id: payment_id.clone(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: merchant_id.clone(),
+                            payment_id: payment_id.clone(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -962,6 +1505,7 @@ mod tests {
                         DisputeUpdate::EvidenceUpdate {
                             evidence: Secret::from(Value::String("updated_evidence".into())),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
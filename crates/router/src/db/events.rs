@@ -1289,6 +1289,8 @@ mod tests {
             is_pre_network_tokenization_enabled: false,
             merchant_category_code: None,
             dispute_polling_interval: None,
+            is_payout_auto_retries_enabled: false,
+            max_payout_auto_retries_enabled: None,
         });
 
         let business_profile = state
@@ -1404,6 +1406,7 @@ mod tests {
             payment_channel: None,
             network_transaction_id: None,
             enable_partial_authorization: None,
+            velocity_check: None,
         };
         let content =
             api_webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(expected_response));
@@ -574,8 +574,12 @@ impl DisputeInterface for KafkaStore {
     async fn insert_dispute(
         &self,
         dispute_new: storage::DisputeNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let dispute = self.diesel_store.insert_dispute(dispute_new).await?;
+        let dispute = self
+            .diesel_store
+            .insert_dispute(dispute_new, storage_scheme)
+            .await?;
 
         if let Err(er) = self
             .kafka_producer
@@ -593,12 +597,14 @@ impl DisputeInterface for KafkaStore {
         merchant_id: &id_type::MerchantId,
         payment_id: &id_type::PaymentId,
         connector_dispute_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
         self.diesel_store
             .find_by_merchant_id_payment_id_connector_dispute_id(
                 merchant_id,
                 payment_id,
                 connector_dispute_id,
+                storage_scheme,
             )
             .await
     }
@@ -607,9 +613,10 @@ impl DisputeInterface for KafkaStore {
         &self,
         merchant_id: &id_type::MerchantId,
         dispute_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         self.diesel_store
-            .find_dispute_by_merchant_id_dispute_id(merchant_id, dispute_id)
+            .find_dispute_by_merchant_id_dispute_id(merchant_id, dispute_id, storage_scheme)
             .await
     }
 
@@ -627,10 +634,11 @@ impl DisputeInterface for KafkaStore {
         &self,
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         let dispute_new = self
             .diesel_store
-            .update_dispute(this.clone(), dispute)
+            .update_dispute(this.clone(), dispute, storage_scheme)
             .await?;
         if let Err(er) = self
             .kafka_producer
@@ -647,9 +655,10 @@ impl DisputeInterface for KafkaStore {
         &self,
         merchant_id: &id_type::MerchantId,
         payment_id: &id_type::PaymentId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
         self.diesel_store
-            .find_disputes_by_merchant_id_payment_id(merchant_id, payment_id)
+            .find_disputes_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
             .await
     }
 
@@ -663,6 +672,19 @@ impl DisputeInterface for KafkaStore {
             .get_dispute_status_with_count(merchant_id, profile_id_list, time_range)
             .await
     }
+
+    async fn get_dispute_financial_records(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id_list: Option<Vec<id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::DisputeFinancialRecordsEntry>, errors::StorageError> {
+        self.diesel_store
+            .get_dispute_financial_records(merchant_id, profile_id_list, time_range, limit, offset)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3812,10 +3834,11 @@ impl AuthenticationInterface for KafkaStore {
     async fn insert_authentication(
         &self,
         authentication: storage::AuthenticationNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         let auth = self
             .diesel_store
-            .insert_authentication(authentication)
+            .insert_authentication(authentication, storage_scheme)
             .await?;
 
         if let Err(er) = self
@@ -3833,9 +3856,14 @@ impl AuthenticationInterface for KafkaStore {
         &self,
         merchant_id: &id_type::MerchantId,
         authentication_id: &id_type::AuthenticationId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         self.diesel_store
-            .find_authentication_by_merchant_id_authentication_id(merchant_id, authentication_id)
+            .find_authentication_by_merchant_id_authentication_id(
+                merchant_id,
+                authentication_id,
+                storage_scheme,
+            )
             .await
     }
 
@@ -3843,11 +3871,13 @@ impl AuthenticationInterface for KafkaStore {
         &self,
         merchant_id: id_type::MerchantId,
         connector_authentication_id: String,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         self.diesel_store
             .find_authentication_by_merchant_id_connector_authentication_id(
                 merchant_id,
                 connector_authentication_id,
+                storage_scheme,
             )
             .await
     }
@@ -3856,12 +3886,14 @@ impl AuthenticationInterface for KafkaStore {
         &self,
         previous_state: storage::Authentication,
         authentication_update: storage::AuthenticationUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         let auth = self
             .diesel_store
             .update_authentication_by_merchant_id_authentication_id(
                 previous_state.clone(),
                 authentication_update,
+                storage_scheme,
             )
             .await?;
 
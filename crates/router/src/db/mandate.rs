@@ -101,6 +101,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -150,6 +151,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -230,6 +232,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Update(key.clone(), &field, mandate.updated_by.as_deref()),
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -324,6 +327,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Insert,
+                None,
             ))
             .await;
             mandate.update_storage_scheme(storage_scheme);
@@ -444,6 +444,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -490,6 +491,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Insert,
+                None,
             ))
             .await;
             match storage_scheme {
@@ -668,6 +670,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -743,6 +746,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Update(key.clone(), &field, Some(&this.updated_by)),
+                Some(&merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -821,6 +825,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -878,6 +883,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -935,6 +941,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                Some(merchant_id),
             ))
             .await;
             match storage_scheme {
@@ -1458,6 +1465,14 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
+            .filter(|refund| {
+                refund_details
+                    .starting_after
+                    .as_ref()
+                    .map_or(true, |(created_at, refund_id)| {
+                        (refund.created_at, &refund.refund_id) < (*created_at, refund_id)
+                    })
+            })
             .skip(usize::try_from(offset).unwrap_or_default())
             .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
             .cloned()
@@ -43,6 +43,14 @@ pub trait RelayInterface {
         profile_id: &common_utils::id_type::ProfileId,
         connector_reference_id: &str,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError>;
+
+    async fn find_latest_payment_relay_by_profile_id_connector_resource_id(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &common_utils::id_type::ProfileId,
+        connector_resource_id: &str,
+    ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -137,6 +145,30 @@ impl RelayInterface for Store {
         .await
         .change_context(errors::StorageError::DecryptionError)
     }
+
+    async fn find_latest_payment_relay_by_profile_id_connector_resource_id(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &common_utils::id_type::ProfileId,
+        connector_resource_id: &str,
+    ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::relay::Relay::find_latest_payment_relay_by_profile_id_connector_resource_id(
+            &conn,
+            profile_id,
+            connector_resource_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            key_manager_state,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
 }
 
 #[async_trait::async_trait]
@@ -178,6 +210,16 @@ impl RelayInterface for MockDb {
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_latest_payment_relay_by_profile_id_connector_resource_id(
+        &self,
+        _key_manager_state: &KeyManagerState,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _connector_resource_id: &str,
+    ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[async_trait::async_trait]
@@ -237,4 +279,21 @@ impl RelayInterface for KafkaStore {
             )
             .await
     }
+
+    async fn find_latest_payment_relay_by_profile_id_connector_resource_id(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &common_utils::id_type::ProfileId,
+        connector_resource_id: &str,
+    ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
+        self.diesel_store
+            .find_latest_payment_relay_by_profile_id_connector_resource_id(
+                key_manager_state,
+                merchant_key_store,
+                profile_id,
+                connector_resource_id,
+            )
+            .await
+    }
 }
@@ -0,0 +1,265 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReusablePaymentLinkInterface {
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError>;
+
+    async fn update_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLink,
+        link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn increment_reusable_payment_link_visit_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn increment_reusable_payment_link_usage_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+        used_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        reusable_payment_link
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::find_by_merchant_id_reusable_payment_link_id(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLink,
+        link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        reusable_payment_link
+            .update(&conn, link_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn increment_reusable_payment_link_visit_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLink::increment_visit_count(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn increment_reusable_payment_link_usage_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+        used_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLink::increment_usage_count(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+            used_at,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for MockDb {
+    async fn insert_reusable_payment_link(
+        &self,
+        _reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_reusable_payment_link(
+        &self,
+        _reusable_payment_link: storage::ReusablePaymentLink,
+        _link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn increment_reusable_payment_link_visit_count(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn increment_reusable_payment_link_usage_count(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _reusable_payment_link_id: &str,
+        _used_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for KafkaStore {
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .insert_reusable_payment_link(reusable_payment_link)
+            .await
+    }
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+                merchant_id,
+                reusable_payment_link_id,
+            )
+            .await
+    }
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .list_reusable_payment_links_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLink,
+        link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_reusable_payment_link(reusable_payment_link, link_update)
+            .await
+    }
+
+    async fn increment_reusable_payment_link_visit_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .increment_reusable_payment_link_visit_count(merchant_id, reusable_payment_link_id)
+            .await
+    }
+
+    async fn increment_reusable_payment_link_usage_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reusable_payment_link_id: &str,
+        used_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .increment_reusable_payment_link_usage_count(
+                merchant_id,
+                reusable_payment_link_id,
+                used_at,
+            )
+            .await
+    }
+}
@@ -0,0 +1,388 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait RevenueRecoveryInterface {
+    async fn insert_revenue_recovery_enrollment(
+        &self,
+        enrollment: storage::RevenueRecoveryEnrollmentNew,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError>;
+
+    async fn find_revenue_recovery_enrollment_by_merchant_id_enrollment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError>;
+
+    async fn find_optional_revenue_recovery_enrollment_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::RevenueRecoveryEnrollment>, errors::StorageError>;
+
+    async fn list_revenue_recovery_enrollments_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<enums::RecoveryEnrollmentStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryEnrollment>, errors::StorageError>;
+
+    async fn update_revenue_recovery_enrollment(
+        &self,
+        enrollment: storage::RevenueRecoveryEnrollment,
+        enrollment_update: storage::RevenueRecoveryEnrollmentUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError>;
+
+    async fn insert_revenue_recovery_attempt(
+        &self,
+        attempt: storage::RevenueRecoveryAttemptNew,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError>;
+
+    async fn list_revenue_recovery_attempts_by_merchant_id_enrollment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError>;
+
+    async fn list_completed_revenue_recovery_attempts_by_merchant_id_attempted_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempted_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError>;
+
+    async fn update_revenue_recovery_attempt(
+        &self,
+        attempt: storage::RevenueRecoveryAttempt,
+        attempt_update: storage::RevenueRecoveryAttemptUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RevenueRecoveryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_revenue_recovery_enrollment(
+        &self,
+        enrollment: storage::RevenueRecoveryEnrollmentNew,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        enrollment
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_revenue_recovery_enrollment_by_merchant_id_enrollment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryEnrollment::find_by_merchant_id_enrollment_id(
+            &conn,
+            merchant_id,
+            enrollment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_optional_revenue_recovery_enrollment_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::RevenueRecoveryEnrollment>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryEnrollment::find_optional_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_revenue_recovery_enrollments_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<enums::RecoveryEnrollmentStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryEnrollment>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryEnrollment::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_revenue_recovery_enrollment(
+        &self,
+        enrollment: storage::RevenueRecoveryEnrollment,
+        enrollment_update: storage::RevenueRecoveryEnrollmentUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        enrollment
+            .update(&conn, enrollment_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_revenue_recovery_attempt(
+        &self,
+        attempt: storage::RevenueRecoveryAttemptNew,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        attempt
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_revenue_recovery_attempts_by_merchant_id_enrollment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryAttempt::list_by_merchant_id_enrollment_id(
+            &conn,
+            merchant_id,
+            enrollment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_completed_revenue_recovery_attempts_by_merchant_id_attempted_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempted_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryAttempt::list_completed_by_merchant_id_attempted_after(
+            &conn,
+            merchant_id,
+            attempted_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_revenue_recovery_attempt(
+        &self,
+        attempt: storage::RevenueRecoveryAttempt,
+        attempt_update: storage::RevenueRecoveryAttemptUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        attempt
+            .update(&conn, attempt_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RevenueRecoveryInterface for MockDb {
+    async fn insert_revenue_recovery_enrollment(
+        &self,
+        _enrollment: storage::RevenueRecoveryEnrollmentNew,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_revenue_recovery_enrollment_by_merchant_id_enrollment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _enrollment_id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_optional_revenue_recovery_enrollment_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::RevenueRecoveryEnrollment>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_revenue_recovery_enrollments_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _status: Option<enums::RecoveryEnrollmentStatus>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryEnrollment>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_revenue_recovery_enrollment(
+        &self,
+        _enrollment: storage::RevenueRecoveryEnrollment,
+        _enrollment_update: storage::RevenueRecoveryEnrollmentUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_revenue_recovery_attempt(
+        &self,
+        _attempt: storage::RevenueRecoveryAttemptNew,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_revenue_recovery_attempts_by_merchant_id_enrollment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _enrollment_id: &str,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_completed_revenue_recovery_attempts_by_merchant_id_attempted_after(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _attempted_after: time::PrimitiveDateTime,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_revenue_recovery_attempt(
+        &self,
+        _attempt: storage::RevenueRecoveryAttempt,
+        _attempt_update: storage::RevenueRecoveryAttemptUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RevenueRecoveryInterface for KafkaStore {
+    async fn insert_revenue_recovery_enrollment(
+        &self,
+        enrollment: storage::RevenueRecoveryEnrollmentNew,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        self.diesel_store
+            .insert_revenue_recovery_enrollment(enrollment)
+            .await
+    }
+
+    async fn find_revenue_recovery_enrollment_by_merchant_id_enrollment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        self.diesel_store
+            .find_revenue_recovery_enrollment_by_merchant_id_enrollment_id(
+                merchant_id,
+                enrollment_id,
+            )
+            .await
+    }
+
+    async fn find_optional_revenue_recovery_enrollment_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Option<storage::RevenueRecoveryEnrollment>, errors::StorageError> {
+        self.diesel_store
+            .find_optional_revenue_recovery_enrollment_by_merchant_id_payment_id(
+                merchant_id,
+                payment_id,
+            )
+            .await
+    }
+
+    async fn list_revenue_recovery_enrollments_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<enums::RecoveryEnrollmentStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryEnrollment>, errors::StorageError> {
+        self.diesel_store
+            .list_revenue_recovery_enrollments_by_merchant_id(merchant_id, status, limit, offset)
+            .await
+    }
+
+    async fn update_revenue_recovery_enrollment(
+        &self,
+        enrollment: storage::RevenueRecoveryEnrollment,
+        enrollment_update: storage::RevenueRecoveryEnrollmentUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryEnrollment, errors::StorageError> {
+        self.diesel_store
+            .update_revenue_recovery_enrollment(enrollment, enrollment_update)
+            .await
+    }
+
+    async fn insert_revenue_recovery_attempt(
+        &self,
+        attempt: storage::RevenueRecoveryAttemptNew,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError> {
+        self.diesel_store
+            .insert_revenue_recovery_attempt(attempt)
+            .await
+    }
+
+    async fn list_revenue_recovery_attempts_by_merchant_id_enrollment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        enrollment_id: &str,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError> {
+        self.diesel_store
+            .list_revenue_recovery_attempts_by_merchant_id_enrollment_id(merchant_id, enrollment_id)
+            .await
+    }
+
+    async fn list_completed_revenue_recovery_attempts_by_merchant_id_attempted_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempted_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryAttempt>, errors::StorageError> {
+        self.diesel_store
+            .list_completed_revenue_recovery_attempts_by_merchant_id_attempted_after(
+                merchant_id,
+                attempted_after,
+                limit,
+            )
+            .await
+    }
+
+    async fn update_revenue_recovery_attempt(
+        &self,
+        attempt: storage::RevenueRecoveryAttempt,
+        attempt_update: storage::RevenueRecoveryAttemptUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryAttempt, errors::StorageError> {
+        self.diesel_store
+            .update_revenue_recovery_attempt(attempt, attempt_update)
+            .await
+    }
+}
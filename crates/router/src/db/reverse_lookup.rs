@@ -97,6 +97,7 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Insert,
+                None,
             ))
             .await;
             match storage_scheme {
@@ -162,6 +163,8 @@ mod storage {
                 self,
                 storage_scheme,
                 Op::Find,
+                // Lookups are read with the storage scheme already decided for the record being looked up
+                None,
             ))
             .await;
             match storage_scheme {
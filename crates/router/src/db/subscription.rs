@@ -0,0 +1,726 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription_product(
+        &self,
+        product: storage::SubscriptionProductNew,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError>;
+
+    async fn find_subscription_product_by_merchant_id_product_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError>;
+
+    async fn list_subscription_products_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionProduct>, errors::StorageError>;
+
+    async fn update_subscription_product(
+        &self,
+        product: storage::SubscriptionProduct,
+        product_update: storage::SubscriptionProductUpdate,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError>;
+
+    async fn insert_subscription_price(
+        &self,
+        price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError>;
+
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError>;
+
+    async fn list_subscription_prices_by_merchant_id_product_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError>;
+
+    async fn update_subscription_price(
+        &self,
+        price: storage::SubscriptionPrice,
+        price_update: storage::SubscriptionPriceUpdate,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError>;
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn update_subscription(
+        &self,
+        subscription: storage::Subscription,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn insert_subscription_invoice(
+        &self,
+        invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    async fn find_open_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    async fn update_subscription_invoice(
+        &self,
+        invoice: storage::SubscriptionInvoice,
+        invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_subscription_product(
+        &self,
+        product: storage::SubscriptionProductNew,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        product
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_product_by_merchant_id_product_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionProduct::find_by_merchant_id_product_id(&conn, merchant_id, product_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_products_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionProduct>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionProduct::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_product(
+        &self,
+        product: storage::SubscriptionProduct,
+        product_update: storage::SubscriptionProductUpdate,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        product
+            .update(&conn, product_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_price(
+        &self,
+        price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        price
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPrice::find_by_merchant_id_price_id(&conn, merchant_id, price_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_prices_by_merchant_id_product_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPrice::list_by_merchant_id_product_id(
+            &conn,
+            merchant_id,
+            product_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_price(
+        &self,
+        price: storage::SubscriptionPrice,
+        price_update: storage::SubscriptionPriceUpdate,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        price
+            .update(&conn, price_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::list_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription(
+        &self,
+        subscription: storage::Subscription,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .update(&conn, subscription_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        invoice
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::find_by_merchant_id_invoice_id(&conn, merchant_id, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::list_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_open_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::find_open_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice(
+        &self,
+        invoice: storage::SubscriptionInvoice,
+        invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        invoice
+            .update(&conn, invoice_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription_product(
+        &self,
+        _product: storage::SubscriptionProductNew,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_product_by_merchant_id_product_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _product_id: &str,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_products_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionProduct>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_product(
+        &self,
+        _product: storage::SubscriptionProduct,
+        _product_update: storage::SubscriptionProductUpdate,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription_price(
+        &self,
+        _price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_prices_by_merchant_id_product_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _product_id: &str,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_price(
+        &self,
+        _price: storage::SubscriptionPrice,
+        _price_update: storage::SubscriptionPriceUpdate,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription(
+        &self,
+        _subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription(
+        &self,
+        _subscription: storage::Subscription,
+        _subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription_invoice(
+        &self,
+        _invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_open_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_invoice(
+        &self,
+        _invoice: storage::SubscriptionInvoice,
+        _invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for KafkaStore {
+    async fn insert_subscription_product(
+        &self,
+        product: storage::SubscriptionProductNew,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        self.diesel_store.insert_subscription_product(product).await
+    }
+
+    async fn find_subscription_product_by_merchant_id_product_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_product_by_merchant_id_product_id(merchant_id, product_id)
+            .await
+    }
+
+    async fn list_subscription_products_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionProduct>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_products_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_subscription_product(
+        &self,
+        product: storage::SubscriptionProduct,
+        product_update: storage::SubscriptionProductUpdate,
+    ) -> CustomResult<storage::SubscriptionProduct, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_product(product, product_update)
+            .await
+    }
+
+    async fn insert_subscription_price(
+        &self,
+        price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        self.diesel_store.insert_subscription_price(price).await
+    }
+
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_price_by_merchant_id_price_id(merchant_id, price_id)
+            .await
+    }
+
+    async fn list_subscription_prices_by_merchant_id_product_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        product_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_prices_by_merchant_id_product_id(
+                merchant_id,
+                product_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    async fn update_subscription_price(
+        &self,
+        price: storage::SubscriptionPrice,
+        price_update: storage::SubscriptionPriceUpdate,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_price(price, price_update)
+            .await
+    }
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store.insert_subscription(subscription).await
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+            .await
+    }
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        self.diesel_store
+            .list_subscriptions_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn list_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        self.diesel_store
+            .list_subscriptions_by_merchant_id_customer_id(merchant_id, customer_id, limit, offset)
+            .await
+    }
+
+    async fn update_subscription(
+        &self,
+        subscription: storage::Subscription,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .update_subscription(subscription, subscription_update)
+            .await
+    }
+
+    async fn insert_subscription_invoice(
+        &self,
+        invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store.insert_subscription_invoice(invoice).await
+    }
+
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id)
+            .await
+    }
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_invoices_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    async fn find_open_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .find_open_subscription_invoices_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+            )
+            .await
+    }
+
+    async fn update_subscription_invoice(
+        &self,
+        invoice: storage::SubscriptionInvoice,
+        invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_invoice(invoice, invoice_update)
+            .await
+    }
+}
@@ -6,6 +6,8 @@ use serde::Serialize;
 use time::OffsetDateTime;
 
 use super::EventType;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+use crate::core::payouts::batch::PayoutBatchCreateData;
 #[cfg(feature = "dummy_connector")]
 use crate::routes::dummy_connector::types::{
     DummyConnectorPaymentCompleteRequest, DummyConnectorPaymentConfirmRequest,
@@ -156,6 +158,9 @@ impl ApiEventMetric for PaymentsRedirectResponseData {
     }
 }
 
+#[cfg(all(feature = "payouts", feature = "v1"))]
+impl_api_event_type!(Miscellaneous, (PayoutBatchCreateData));
+
 impl ApiEventMetric for DisputeId {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Dispute {
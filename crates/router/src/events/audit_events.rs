@@ -1,5 +1,8 @@
-use api_models::payments::Amount;
-use common_utils::types::MinorUnit;
+use api_models::{
+    payments::Amount,
+    velocity_rules::{TriggeredVelocityRule, VelocityRuleAction},
+};
+use common_utils::{id_type, types::MinorUnit};
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
@@ -39,6 +42,11 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    VelocityRuleTriggered {
+        payment_id: id_type::PaymentId,
+        action: VelocityRuleAction,
+        triggered_rules: Vec<TriggeredVelocityRule>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +91,7 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::VelocityRuleTriggered { .. } => "velocity_rule_triggered",
         };
         format!(
             "{event_type}-{}",
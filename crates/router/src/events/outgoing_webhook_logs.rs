@@ -22,6 +22,10 @@ pub struct OutgoingWebhookEvent {
     initial_attempt_id: Option<String>,
     status_code: Option<u16>,
     delivery_attempt: Option<WebhookDeliveryAttempt>,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    endpoint: Option<String>,
+    status_code_class: Option<String>,
+    latency: Option<u128>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -72,6 +76,20 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    MetricAlert {
+        rule_id: String,
+        alert_id: String,
+        content: Value,
+    },
+    Subscription {
+        subscription_id: String,
+        content: Value,
+    },
+    Invoice {
+        subscription_id: String,
+        invoice_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -111,6 +129,27 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::MetricAlertDetails(alert_payload) => {
+                Some(OutgoingWebhookEventContent::MetricAlert {
+                    rule_id: alert_payload.rule_id.clone(),
+                    alert_id: alert_payload.alert_id.clone(),
+                    content: masking::masked_serialize(&alert_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::SubscriptionDetails(subscription_payload) => {
+                Some(OutgoingWebhookEventContent::Subscription {
+                    subscription_id: subscription_payload.subscription_id.clone(),
+                    content: masking::masked_serialize(&subscription_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::InvoiceDetails(invoice_payload) => Some(OutgoingWebhookEventContent::Invoice {
+                subscription_id: invoice_payload.subscription_id.clone(),
+                invoice_id: invoice_payload.invoice_id.clone(),
+                content: masking::masked_serialize(&invoice_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
         }
     }
 }
@@ -146,6 +185,27 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::MetricAlertDetails(alert_payload) => {
+                Some(OutgoingWebhookEventContent::MetricAlert {
+                    rule_id: alert_payload.rule_id.clone(),
+                    alert_id: alert_payload.alert_id.clone(),
+                    content: masking::masked_serialize(&alert_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::SubscriptionDetails(subscription_payload) => {
+                Some(OutgoingWebhookEventContent::Subscription {
+                    subscription_id: subscription_payload.subscription_id.clone(),
+                    content: masking::masked_serialize(&subscription_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::InvoiceDetails(invoice_payload) => Some(OutgoingWebhookEventContent::Invoice {
+                subscription_id: invoice_payload.subscription_id.clone(),
+                invoice_id: invoice_payload.invoice_id.clone(),
+                content: masking::masked_serialize(&invoice_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
         }
     }
 }
@@ -162,6 +222,9 @@ impl OutgoingWebhookEvent {
         initial_attempt_id: Option<String>,
         status_code: Option<u16>,
         delivery_attempt: Option<WebhookDeliveryAttempt>,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        endpoint: Option<String>,
+        latency: Option<u128>,
     ) -> Self {
         Self {
             tenant_id,
@@ -175,10 +238,25 @@ impl OutgoingWebhookEvent {
             initial_attempt_id,
             status_code,
             delivery_attempt,
+            profile_id,
+            endpoint: endpoint.as_deref().and_then(sanitize_webhook_endpoint),
+            status_code_class: status_code.map(|code| format!("{}xx", code / 100)),
+            latency,
         }
     }
 }
 
+/// Strips credentials, query parameters and fragments from the merchant's webhook URL, so that
+/// only the scheme, host, port and path are logged and used as an analytics dimension.
+fn sanitize_webhook_endpoint(endpoint: &str) -> Option<String> {
+    let mut url = url::Url::parse(endpoint).ok()?;
+    url.set_query(None);
+    url.set_fragment(None);
+    url.set_username("").ok()?;
+    url.set_password(None).ok()?;
+    Some(url.to_string())
+}
+
 impl KafkaMessage for OutgoingWebhookEvent {
     fn event_type(&self) -> EventType {
         EventType::OutgoingWebhookLogs
@@ -1,4 +1,4 @@
-#[cfg(all(feature = "stripe", feature = "v1"))]
+#[cfg(all(any(feature = "stripe", feature = "adyen"), feature = "v1"))]
 pub mod compatibility;
 pub mod configs;
 pub mod connection;
@@ -79,6 +79,7 @@ pub mod headers {
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
+    pub const ADYEN_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "X-Adyen-Hmac-Signature";
     pub const X_CLIENT_VERSION: &str = "X-Client-Version";
     pub const X_CLIENT_SOURCE: &str = "X-Client-Source";
     pub const X_PAYMENT_CONFIRM_SOURCE: &str = "X-Payment-Confirm-Source";
@@ -174,6 +175,9 @@ pub fn mk_app(
         {
             server_app = server_app
                 .service(routes::Refunds::server(state.clone()))
+                .service(routes::Subscriptions::server(state.clone()))
+                .service(routes::RecoveryEnrollments::server(state.clone()))
+                .service(routes::AccountUpdater::server(state.clone()))
                 .service(routes::Mandates::server(state.clone()))
                 .service(routes::Authentication::server(state.clone()));
         }
@@ -242,6 +246,11 @@ pub fn mk_app(
             .service(routes::Cards::server(state.clone()));
     }
 
+    #[cfg(all(feature = "adyen", feature = "v1"))]
+    {
+        server_app = server_app.service(routes::AdyenApis::server(state.clone()));
+    }
+
     #[cfg(all(feature = "oltp", feature = "v2"))]
     {
         server_app = server_app.service(routes::Proxy::server(state.clone()));
@@ -1,3 +1,5 @@
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+pub mod account_updater;
 pub mod admin;
 pub mod api_keys;
 pub mod app;
@@ -47,9 +49,13 @@ pub mod profile_acquirer;
 pub mod profiles;
 #[cfg(feature = "recon")]
 pub mod recon;
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+pub mod recovery_enrollments;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+pub mod subscriptions;
 pub mod three_ds_decision_rule;
 pub mod tokenization;
 #[cfg(feature = "olap")]
@@ -88,17 +94,19 @@ pub use self::app::Recon;
 #[cfg(feature = "v2")]
 pub use self::app::Tokenization;
 pub use self::app::{
-    ApiKeys, AppState, ApplePayCertificatesMigration, Authentication, Cache, Cards, Chat, Configs,
-    ConnectorOnboarding, Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm,
-    Health, Hypersense, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink,
-    PaymentMethods, Payments, Poll, ProcessTracker, ProcessTrackerDeprecated, Profile,
-    ProfileAcquirer, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState, ThreeDsDecisionRule,
-    User, UserDeprecated, Webhooks,
+    AccountUpdater, ApiKeys, AppState, ApplePayCertificatesMigration, Authentication, Cache, Cards,
+    Chat, Configs, ConnectorOnboarding, Customers, Disputes, EphemeralKey, FeatureMatrix, Files,
+    Forex, Gsm, Health, Hypersense, Mandates, MerchantAccount, MerchantConnectorAccount,
+    PaymentLink, PaymentMethods, Payments, Poll, ProcessTracker, ProcessTrackerDeprecated, Profile,
+    ProfileAcquirer, ProfileNew, RecoveryEnrollments, Refunds, Relay, RelayWebhooks, SessionState,
+    Subscriptions, ThreeDsDecisionRule, User, UserDeprecated, Webhooks,
 };
 #[cfg(feature = "olap")]
 pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
+#[cfg(all(feature = "adyen", feature = "v1"))]
+pub use super::compatibility::adyen::AdyenApis;
 #[cfg(all(feature = "stripe", feature = "v1"))]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
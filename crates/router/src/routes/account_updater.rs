@@ -0,0 +1,110 @@
+use actix_web::{web, Responder};
+use api_models::account_updater as account_updater_types;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{account_updater, api_locking},
+    services::{api, authentication as auth},
+    types::domain,
+    AppState,
+};
+
+/// Account Updater - Enable
+///
+/// To periodically check the stored cards of the merchant for updates through a connector
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::AccountUpdaterEnable))]
+pub async fn account_updater_enable(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<account_updater_types::AccountUpdaterRequest>,
+) -> impl Responder {
+    let flow = Flow::AccountUpdaterEnable;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            account_updater::enable_account_updater(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Account Updater - Disable
+///
+/// To stop checking the stored cards of the merchant for updates through a connector
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::AccountUpdaterDisable))]
+pub async fn account_updater_disable(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<account_updater_types::AccountUpdaterRequest>,
+) -> impl Responder {
+    let flow = Flow::AccountUpdaterDisable;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            account_updater::disable_account_updater(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Method - Account Updates
+///
+/// To list the updates of a stored card received from the connectors, latest first
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodAccountUpdatesList))]
+pub async fn payment_method_account_updates_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<account_updater_types::PaymentMethodAccountUpdateListConstraints>,
+) -> impl Responder {
+    let flow = Flow::PaymentMethodAccountUpdatesList;
+    let payment_method_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            account_updater::list_payment_method_account_updates(
+                state,
+                merchant_context,
+                payment_method_id.clone(),
+                constraints,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -60,6 +60,8 @@ use super::tokenization as tokenization_routes;
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "oltp")]
 use super::webhooks::*;
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+use super::{account_updater, mandates::*, recovery_enrollments, refunds::*, subscriptions};
 use super::{
     admin, api_keys, cache::*, chat, connector_onboarding, disputes, files, gsm, health::*,
     profiles, relay, user, user_role,
@@ -68,8 +70,6 @@ use super::{
 use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers, payments};
-#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
-use super::{mandates::*, refunds::*};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -1202,6 +1202,26 @@ impl Customers {
                         .route(web::get().to(customers::get_customer_mandates)),
                 )
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
+                .service(
+                    web::resource("/duplicates")
+                        .route(web::get().to(customers::list_customer_duplicate_groups)),
+                )
+                .service(
+                    web::resource("/duplicates/index")
+                        .route(web::post().to(customers::sync_customer_blind_indexes)),
+                )
+                .service(
+                    web::resource("/{customer_id}/duplicates")
+                        .route(web::get().to(customers::retrieve_customer_duplicates)),
+                )
+                .service(
+                    web::resource("/{customer_id}/merge")
+                        .route(web::post().to(customers::merge_customers)),
+                )
+                .service(
+                    web::resource("/{customer_id}/merges")
+                        .route(web::get().to(customers::list_customer_merges)),
+                )
         }
 
         #[cfg(feature = "oltp")]
@@ -1304,6 +1324,111 @@ impl Refunds {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::scope("/products")
+                    .service(
+                        web::resource("")
+                            .route(web::post().to(subscriptions::subscription_product_create)),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(subscriptions::subscription_product_list)),
+                    )
+                    .service(
+                        web::resource("/{product_id}")
+                            .route(web::get().to(subscriptions::subscription_product_retrieve))
+                            .route(web::post().to(subscriptions::subscription_product_update)),
+                    ),
+            )
+            .service(
+                web::scope("/prices")
+                    .service(
+                        web::resource("")
+                            .route(web::post().to(subscriptions::subscription_price_create)),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(subscriptions::subscription_price_list)),
+                    )
+                    .service(
+                        web::resource("/{price_id}")
+                            .route(web::get().to(subscriptions::subscription_price_retrieve))
+                            .route(web::post().to(subscriptions::subscription_price_update)),
+                    ),
+            )
+            .service(
+                web::resource("/invoices/{invoice_id}")
+                    .route(web::get().to(subscriptions::subscription_invoice_retrieve)),
+            )
+            .service(web::resource("").route(web::post().to(subscriptions::subscription_create)))
+            .service(web::resource("/list").route(web::get().to(subscriptions::subscription_list)))
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(subscriptions::subscription_retrieve))
+                    .route(web::post().to(subscriptions::subscription_update)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscriptions::subscription_cancel)),
+            )
+            .service(
+                web::resource("/{subscription_id}/invoices")
+                    .route(web::get().to(subscriptions::subscription_invoice_list)),
+            )
+    }
+}
+
+pub struct RecoveryEnrollments;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl RecoveryEnrollments {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/recovery_enrollments")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(recovery_enrollments::recovery_enrollment_create)),
+            )
+            .service(
+                web::resource("/list")
+                    .route(web::get().to(recovery_enrollments::recovery_enrollment_list)),
+            )
+            .service(
+                web::resource("/{enrollment_id}")
+                    .route(web::get().to(recovery_enrollments::recovery_enrollment_retrieve)),
+            )
+            .service(
+                web::resource("/{enrollment_id}/cancel")
+                    .route(web::post().to(recovery_enrollments::recovery_enrollment_cancel)),
+            )
+    }
+}
+
+pub struct AccountUpdater;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl AccountUpdater {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/account_updater")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/enable")
+                    .route(web::post().to(account_updater::account_updater_enable)),
+            )
+            .service(
+                web::resource("/disable")
+                    .route(web::post().to(account_updater::account_updater_disable)),
+            )
+    }
+}
+
 #[cfg(feature = "payouts")]
 pub struct Payouts;
 
@@ -1336,6 +1461,19 @@ impl Payouts {
                 );
         }
         route = route
+            .service(web::resource("/batches").route(web::post().to(payout_batch_create)))
+            .service(web::resource("/batches/upload").route(web::post().to(payout_batch_upload)))
+            .service(
+                web::resource("/batches/{batch_id}").route(web::get().to(payout_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}/items")
+                    .route(web::get().to(payout_batch_items_list)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}/results")
+                    .route(web::get().to(payout_batch_results)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1466,6 +1604,10 @@ impl PaymentMethods {
                     web::resource("/{payment_method_id}/save")
                         .route(web::post().to(payment_methods::save_payment_method_api)),
                 )
+                .service(
+                    web::resource("/{payment_method_id}/account_updates")
+                        .route(web::get().to(account_updater::payment_method_account_updates_list)),
+                )
                 .service(
                     web::resource("/auth/link").route(web::post().to(pm_auth::link_token_create)),
                 )
@@ -1661,6 +1803,11 @@ impl MerchantAccount {
                         web::resource("/kv")
                             .route(web::post().to(admin::merchant_account_toggle_kv))
                             .route(web::get().to(admin::merchant_account_kv_status)),
+                    )
+                    .service(
+                        web::resource("/kv/migration")
+                            .route(web::post().to(admin::merchant_account_kv_migration_create))
+                            .route(web::get().to(admin::merchant_account_kv_migration_retrieve)),
                     ),
             )
     }
@@ -1677,6 +1824,11 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/kv/migration")
+                    .route(web::post().to(admin::merchant_account_kv_migration_create))
+                    .route(web::get().to(admin::merchant_account_kv_migration_retrieve)),
+            )
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -2036,6 +2188,10 @@ impl Disputes {
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
+            .service(
+                web::resource("/{dispute_id}/financial_records")
+                    .route(web::post().to(disputes::add_dispute_financial_record)),
+            )
             .service(
                 web::resource("/{connector_id}/fetch")
                     .route(web::get().to(disputes::fetch_disputes)),
@@ -2080,6 +2236,11 @@ impl Cache {
         web::scope("/cache")
             .app_data(web::Data::new(state))
             .service(web::resource("/invalidate/{key}").route(web::post().to(invalidate)))
+            .service(
+                web::resource("/invalidate_prefix").route(web::post().to(invalidate_by_prefix)),
+            )
+            .service(web::resource("/stats").route(web::get().to(cache_stats)))
+            .service(web::resource("/lookup/{key}").route(web::get().to(cache_key_lookup)))
     }
 }
 
@@ -2091,6 +2252,35 @@ impl PaymentLink {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::post().to(payment_link::payments_link_list)))
+            .service(
+                web::scope("/reusable")
+                    .service(
+                        web::resource("")
+                            .route(web::post().to(payment_link::reusable_payment_link_create)),
+                    )
+                    .service(
+                        web::resource("/list")
+                            .route(web::get().to(payment_link::reusable_payment_link_list)),
+                    )
+                    .service(
+                        web::resource("/{reusable_payment_link_id}/analytics")
+                            .route(web::get().to(payment_link::reusable_payment_link_analytics)),
+                    )
+                    .service(
+                        web::resource("/{merchant_id}/{reusable_payment_link_id}/qr")
+                            .route(web::get().to(payment_link::reusable_payment_link_qr_code)),
+                    )
+                    .service(
+                        web::resource("/{merchant_id}/{reusable_payment_link_id}")
+                            .route(web::get().to(payment_link::reusable_payment_link_visit))
+                            .route(web::post().to(payment_link::reusable_payment_link_confirm)),
+                    )
+                    .service(
+                        web::resource("/{reusable_payment_link_id}")
+                            .route(web::get().to(payment_link::reusable_payment_link_retrieve))
+                            .route(web::post().to(payment_link::reusable_payment_link_update)),
+                    ),
+            )
             .service(
                 web::resource("/{payment_link_id}")
                     .route(web::get().to(payment_link::payment_link_retrieve)),
@@ -2280,6 +2470,18 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/velocity_rules")
+                        .route(web::get().to(profiles::velocity_rules_retrieve))
+                        .route(web::post().to(profiles::velocity_rules_upsert))
+                        .route(web::delete().to(profiles::velocity_rules_delete)),
+                )
+                .service(
+                    web::resource("/dispute_automation")
+                        .route(web::get().to(profiles::dispute_automation_config_retrieve))
+                        .route(web::post().to(profiles::dispute_automation_config_upsert))
+                        .route(web::delete().to(profiles::dispute_automation_config_delete)),
                 ),
         );
 
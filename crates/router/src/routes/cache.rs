@@ -28,3 +28,61 @@ pub async fn invalidate(
     )
     .await
 }
+
+#[instrument(skip_all)]
+pub async fn invalidate_by_prefix(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::cache::CachePrefixInvalidationRequest>,
+) -> impl Responder {
+    let flow = Flow::CacheInvalidatePrefix;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| cache::invalidate_by_prefix(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn cache_stats(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::CacheStats;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| cache::get_stats(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn cache_key_lookup(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    key: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::CacheKeyLookup;
+
+    let key = key.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        key,
+        |state, _, key, _| cache::lookup_key(state, key),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
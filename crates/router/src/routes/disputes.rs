@@ -570,6 +570,63 @@ pub async fn delete_dispute_evidence(
     .await
 }
 
+#[cfg(feature = "v1")]
+/// Disputes - Add Financial Record
+///
+/// To record a chargeback fee or amount adjustment against a dispute
+#[utoipa::path(
+    post,
+    path = "/disputes/{dispute_id}/financial_records",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    request_body=DisputeFinancialRecordRequest,
+    responses(
+        (status = 200, description = "The financial record was added to the dispute", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Add a Financial Record to a Dispute",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AddDisputeFinancialRecord))]
+pub async fn add_dispute_financial_record(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dispute_models::DisputeFinancialRecordRequest>,
+) -> HttpResponse {
+    let flow = Flow::AddDisputeFinancialRecord;
+    let mut financial_record_request = json_payload.into_inner();
+    financial_record_request.dispute_id = path.into_inner();
+    let lock_action =
+        disputes::get_dispute_financial_records_lock_action(&financial_record_request.dispute_id);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        financial_record_request,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::add_financial_record(state, merchant_context, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+            },
+            req.headers(),
+        ),
+        lock_action,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::DisputesAggregate))]
 pub async fn get_disputes_aggregate(
     state: web::Data<AppState>,
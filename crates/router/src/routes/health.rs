@@ -68,6 +68,14 @@ async fn deep_health_check_func(
         })
     })?;
 
+    // Failing to look up the primary nodes does not fail the health check, as Redis itself is
+    // reachable at this point
+    let redis_primary_nodes = state
+        .get_redis_primary_nodes()
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to fetch Redis primary nodes"))
+        .ok();
+
     logger::debug!("Redis health check end");
 
     logger::debug!("Locker health check begin");
@@ -168,6 +176,7 @@ async fn deep_health_check_func(
     let response = RouterHealthCheckResponse {
         database: db_status.into(),
         redis: redis_status.into(),
+        redis_primary_nodes,
         vault: locker_status.into(),
         #[cfg(feature = "olap")]
         analytics: analytics_status.into(),
@@ -49,6 +49,9 @@ pub enum ApiIdentifier {
     ProfileAcquirer,
     ThreeDsDecisionRule,
     GenericTokenization,
+    Subscriptions,
+    RecoveryEnrollments,
+    AccountUpdater,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -60,7 +63,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
             | Flow::MerchantAccountList
-            | Flow::EnablePlatformAccount => Self::MerchantAccount,
+            | Flow::EnablePlatformAccount
+            | Flow::MerchantKvMigrationCreate
+            | Flow::MerchantKvMigrationRetrieve => Self::MerchantAccount,
 
             Flow::OrganizationCreate | Flow::OrganizationRetrieve | Flow::OrganizationUpdate => {
                 Self::Organization
@@ -112,7 +117,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
-            | Flow::CustomersList => Self::Customers,
+            | Flow::CustomersList
+            | Flow::CustomersDuplicatesList
+            | Flow::CustomersDuplicatesRetrieve
+            | Flow::CustomersBlindIndexSync
+            | Flow::CustomersMerge
+            | Flow::CustomersMergeList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
@@ -182,6 +192,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchItemsList
+            | Flow::PayoutBatchResults
             | Flow::PayoutLinkInitiate => Self::Payouts,
 
             Flow::RefundsCreate
@@ -216,6 +230,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
+            | Flow::AddDisputeFinancialRecord
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
             Flow::CardsInfo
@@ -225,7 +240,10 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
 
-            Flow::CacheInvalidate => Self::Cache,
+            Flow::CacheInvalidate
+            | Flow::CacheInvalidatePrefix
+            | Flow::CacheStats
+            | Flow::CacheKeyLookup => Self::Cache,
 
             Flow::ProfileCreate
             | Flow::ProfileUpdate
@@ -233,13 +251,27 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::VelocityRulesUpsert
+            | Flow::VelocityRulesRetrieve
+            | Flow::VelocityRulesDelete
+            | Flow::DisputeAutomationConfigUpsert
+            | Flow::DisputeAutomationConfigRetrieve
+            | Flow::DisputeAutomationConfigDelete => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::ReusablePaymentLinkCreate
+            | Flow::ReusablePaymentLinkRetrieve
+            | Flow::ReusablePaymentLinkUpdate
+            | Flow::ReusablePaymentLinkList
+            | Flow::ReusablePaymentLinkAnalytics
+            | Flow::ReusablePaymentLinkVisit
+            | Flow::ReusablePaymentLinkConfirm
+            | Flow::ReusablePaymentLinkQrCode => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -379,6 +411,31 @@ impl From<Flow> for ApiIdentifier {
             Flow::TokenizationCreate | Flow::TokenizationRetrieve | Flow::TokenizationDelete => {
                 Self::GenericTokenization
             }
+
+            Flow::SubscriptionProductCreate
+            | Flow::SubscriptionProductRetrieve
+            | Flow::SubscriptionProductUpdate
+            | Flow::SubscriptionProductList
+            | Flow::SubscriptionPriceCreate
+            | Flow::SubscriptionPriceRetrieve
+            | Flow::SubscriptionPriceUpdate
+            | Flow::SubscriptionPriceList
+            | Flow::SubscriptionCreate
+            | Flow::SubscriptionRetrieve
+            | Flow::SubscriptionUpdate
+            | Flow::SubscriptionCancel
+            | Flow::SubscriptionList
+            | Flow::SubscriptionInvoiceRetrieve
+            | Flow::SubscriptionInvoiceList => Self::Subscriptions,
+
+            Flow::RecoveryEnrollmentCreate
+            | Flow::RecoveryEnrollmentRetrieve
+            | Flow::RecoveryEnrollmentCancel
+            | Flow::RecoveryEnrollmentList => Self::RecoveryEnrollments,
+
+            Flow::AccountUpdaterEnable
+            | Flow::AccountUpdaterDisable
+            | Flow::PaymentMethodAccountUpdatesList => Self::AccountUpdater,
         }
     }
 }
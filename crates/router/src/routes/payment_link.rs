@@ -179,3 +179,274 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+/// Reusable Payment Link - Create
+///
+/// To create a payment link which creates a new payment on every visit
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkCreate))]
+pub async fn reusable_payment_link_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::ReusablePaymentLinkCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::create_reusable_payment_link(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Retrieve
+///
+/// To retrieve the properties of a reusable payment link
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkRetrieve))]
+pub async fn reusable_payment_link_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkRetrieve;
+    let payload = api_models::payments::ReusablePaymentLinkId {
+        reusable_payment_link_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::retrieve_reusable_payment_link(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Update
+///
+/// To update the properties of a reusable payment link, or to deactivate it
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkUpdate))]
+pub async fn reusable_payment_link_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::ReusablePaymentLinkUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkUpdate;
+    let reusable_payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::update_reusable_payment_link(
+                state,
+                merchant_context,
+                reusable_payment_link_id.clone(),
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - List
+///
+/// To list the reusable payment links
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkList))]
+pub async fn reusable_payment_link_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<api_models::payments::ReusablePaymentLinkListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::list_reusable_payment_links(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Analytics
+///
+/// To retrieve the visits and payments of a reusable payment link
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkAnalytics))]
+pub async fn reusable_payment_link_analytics(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkAnalytics;
+    let payload = api_models::payments::ReusablePaymentLinkId {
+        reusable_payment_link_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::get_reusable_payment_link_analytics(
+                state,
+                merchant_context,
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkVisit))]
+pub async fn reusable_payment_link_visit(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkVisit;
+    let (merchant_id, reusable_payment_link_id) = path.into_inner();
+    let payload = api_models::payments::ReusablePaymentLinkVisitRequest {
+        merchant_id: merchant_id.clone(),
+        reusable_payment_link_id,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::visit_reusable_payment_link(state, merchant_context, payload)
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkConfirm))]
+pub async fn reusable_payment_link_confirm(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    form: web::Form<api_models::payments::ReusablePaymentLinkConfirmForm>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkConfirm;
+    let (merchant_id, reusable_payment_link_id) = path.into_inner();
+    let payload = api_models::payments::ReusablePaymentLinkConfirmRequest {
+        merchant_id: merchant_id.clone(),
+        reusable_payment_link_id,
+        amount: form.into_inner().amount,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::confirm_reusable_payment_link(
+                state,
+                req_state,
+                merchant_context,
+                payload,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkQrCode))]
+pub async fn reusable_payment_link_qr_code(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    query: web::Query<api_models::payments::ReusablePaymentLinkQrCodeRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkQrCode;
+    let (merchant_id, reusable_payment_link_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reusable_payment_link::get_reusable_payment_link_qr_code(
+                state,
+                merchant_context,
+                reusable_payment_link_id.clone(),
+                payload,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
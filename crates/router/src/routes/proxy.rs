@@ -25,7 +25,7 @@ pub async fn proxy(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            proxy::proxy_core(state, merchant_context, req)
+            proxy::proxy_core(state, merchant_context, auth.profile, req)
         },
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
@@ -0,0 +1,140 @@
+use actix_web::{web, Responder};
+use api_models::recovery_enrollments as recovery_enrollment_types;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, recovery_enrollments},
+    services::{api, authentication as auth},
+    types::domain,
+    AppState,
+};
+
+/// Recovery Enrollment - Create
+///
+/// To enroll a failed merchant initiated payment for revenue recovery
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RecoveryEnrollmentCreate))]
+pub async fn recovery_enrollment_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<recovery_enrollment_types::RecoveryEnrollmentCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::RecoveryEnrollmentCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            recovery_enrollments::create_recovery_enrollment(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Recovery Enrollment - List
+///
+/// To list the revenue recovery enrollments of the merchant
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RecoveryEnrollmentList))]
+pub async fn recovery_enrollment_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<recovery_enrollment_types::RecoveryEnrollmentListConstraints>,
+) -> impl Responder {
+    let flow = Flow::RecoveryEnrollmentList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            recovery_enrollments::list_recovery_enrollments(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Recovery Enrollment - Retrieve
+///
+/// To retrieve a revenue recovery enrollment along with its retries
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RecoveryEnrollmentRetrieve))]
+pub async fn recovery_enrollment_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::RecoveryEnrollmentRetrieve;
+    let payload = recovery_enrollment_types::RecoveryEnrollmentId {
+        enrollment_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            recovery_enrollments::retrieve_recovery_enrollment(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Recovery Enrollment - Cancel
+///
+/// To stop retrying an enrolled payment
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RecoveryEnrollmentCancel))]
+pub async fn recovery_enrollment_cancel(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::RecoveryEnrollmentCancel;
+    let payload = recovery_enrollment_types::RecoveryEnrollmentId {
+        enrollment_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            recovery_enrollments::cancel_recovery_enrollment(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
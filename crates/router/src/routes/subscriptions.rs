@@ -0,0 +1,522 @@
+use actix_web::{web, Responder};
+use api_models::subscriptions as subscription_types;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, subscriptions},
+    services::{api, authentication as auth},
+    types::domain,
+    AppState,
+};
+
+/// Subscription Product - Create
+///
+/// To create a product which can be subscribed to through its prices
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionProductCreate))]
+pub async fn subscription_product_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionProductCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionProductCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::create_subscription_product(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Product - List
+///
+/// To list the subscription products of the merchant
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionProductList))]
+pub async fn subscription_product_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<subscription_types::SubscriptionPaginationConstraints>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionProductList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::list_subscription_products(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Product - Retrieve
+///
+/// To retrieve a subscription product
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionProductRetrieve))]
+pub async fn subscription_product_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionProductRetrieve;
+    let payload = subscription_types::SubscriptionProductId {
+        product_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::retrieve_subscription_product(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Product - Update
+///
+/// To update the details of a subscription product, or to deactivate it
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionProductUpdate))]
+pub async fn subscription_product_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionProductUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionProductUpdate;
+    let product_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::update_subscription_product(
+                state,
+                merchant_context,
+                product_id.clone(),
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Price - Create
+///
+/// To create a recurring price of a subscription product
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPriceCreate))]
+pub async fn subscription_price_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionPriceCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionPriceCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::create_subscription_price(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Price - List
+///
+/// To list the prices of a subscription product
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPriceList))]
+pub async fn subscription_price_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<subscription_types::SubscriptionPriceListConstraints>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionPriceList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::list_subscription_prices(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Price - Retrieve
+///
+/// To retrieve a subscription price
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPriceRetrieve))]
+pub async fn subscription_price_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionPriceRetrieve;
+    let payload = subscription_types::SubscriptionPriceId {
+        price_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::retrieve_subscription_price(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Price - Update
+///
+/// To update the metadata of a subscription price, or to deactivate it
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPriceUpdate))]
+pub async fn subscription_price_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionPriceUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionPriceUpdate;
+    let price_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::update_subscription_price(
+                state,
+                merchant_context,
+                price_id.clone(),
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription - Create
+///
+/// To subscribe a customer to a price. The first billing period is charged immediately with the saved payment method, unless the subscription starts with a trial
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCreate))]
+pub async fn subscription_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::create_subscription(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription - List
+///
+/// To list the subscriptions of the merchant, optionally filtered by customer
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionList))]
+pub async fn subscription_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<subscription_types::SubscriptionListConstraints>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::list_subscriptions(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription - Retrieve
+///
+/// To retrieve a subscription
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionRetrieve))]
+pub async fn subscription_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionRetrieve;
+    let payload = subscription_types::SubscriptionId {
+        subscription_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::retrieve_subscription(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription - Update
+///
+/// To change the price, quantity or payment method of a subscription. Price and quantity changes are prorated as per the proration behavior
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionUpdate))]
+pub async fn subscription_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionUpdate;
+    let subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::update_subscription(
+                state,
+                merchant_context,
+                subscription_id.clone(),
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription - Cancel
+///
+/// To cancel a subscription immediately, or at the end of its current billing period
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCancel))]
+pub async fn subscription_cancel(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_types::SubscriptionCancelRequest>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionCancel;
+    let subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::cancel_subscription(
+                state,
+                merchant_context,
+                subscription_id.clone(),
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Invoice - List
+///
+/// To list the invoices of a subscription
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionInvoiceList))]
+pub async fn subscription_invoice_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    payload: web::Query<subscription_types::SubscriptionPaginationConstraints>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionInvoiceList;
+    let subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::list_subscription_invoices(
+                state,
+                merchant_context,
+                subscription_id.clone(),
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Invoice - Retrieve
+///
+/// To retrieve an invoice of a subscription
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionInvoiceRetrieve))]
+pub async fn subscription_invoice_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SubscriptionInvoiceRetrieve;
+    let payload = subscription_types::InvoiceId {
+        invoice_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions::retrieve_invoice(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
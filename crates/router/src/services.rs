@@ -14,6 +14,7 @@ pub mod pm_auth;
 pub mod card_testing_guard;
 #[cfg(feature = "olap")]
 pub mod openidconnect;
+pub mod velocity_rules;
 
 use std::sync::Arc;
 
@@ -91,6 +91,8 @@ pub type BoxedDisputeConnectorIntegrationInterface<T, Req, Resp> =
     BoxedConnectorIntegrationInterface<T, common_types::DisputesFlowData, Req, Resp>;
 pub type BoxedMandateRevokeConnectorIntegrationInterface<T, Req, Resp> =
     BoxedConnectorIntegrationInterface<T, common_types::MandateRevokeFlowData, Req, Resp>;
+pub type BoxedAccountUpdaterConnectorIntegrationInterface<T, Req, Resp> =
+    BoxedConnectorIntegrationInterface<T, common_types::AccountUpdaterFlowData, Req, Resp>;
 #[cfg(feature = "payouts")]
 pub type BoxedPayoutConnectorIntegrationInterface<T, Req, Resp> =
     BoxedConnectorIntegrationInterface<T, common_types::PayoutFlowData, Req, Resp>;
@@ -350,6 +352,7 @@ where
                         req.refund_id.clone(),
                         req.dispute_id.clone(),
                         status_code,
+                        req.merchant_connector_id.clone(),
                     );
 
                     match response {
@@ -431,6 +434,7 @@ where
                                                     body,
                                                     Some(&mut connector_event),
                                                 )?;
+                                            connector_event.set_error_code(error_res.code.clone());
                                             state.event_handler().log_event(&connector_event);
                                             error_res
                                         }
@@ -443,6 +447,7 @@ where
                                             if let Some(status) = error_res.attempt_status {
                                                 router_data.status = status;
                                             };
+                                            connector_event.set_error_code(error_res.code.clone());
                                             state.event_handler().log_event(&connector_event);
                                             error_res
                                         }
@@ -457,8 +462,14 @@ where
                         }
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
+                            let is_upstream_timeout = error.current_context().is_upstream_timeout();
+                            if is_upstream_timeout {
+                                connector_event.set_status_code(504);
+                                connector_event
+                                    .set_error_code(consts::REQUEST_TIMEOUT_ERROR_CODE.to_string());
+                            }
                             state.event_handler().log_event(&connector_event);
-                            if error.current_context().is_upstream_timeout() {
+                            if is_upstream_timeout {
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
                                     message: consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string(),
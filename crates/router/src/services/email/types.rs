@@ -80,6 +80,18 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    AnalyticsReport {
+        report_name: String,
+        start_time: String,
+        end_time: String,
+        link: String,
+    },
+    MetricAlert {
+        rule_name: String,
+        description: String,
+        start_time: String,
+        end_time: String,
+    },
     WelcomeToCommunity,
 }
 
@@ -239,6 +251,30 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::AnalyticsReport {
+                report_name,
+                start_time,
+                end_time,
+                link,
+            } => format!(
+                include_str!("assets/analytics_report.html"),
+                report_name = report_name,
+                start_time = start_time,
+                end_time = end_time,
+                link = link,
+            ),
+            EmailBody::MetricAlert {
+                rule_name,
+                description,
+                start_time,
+                end_time,
+            } => format!(
+                include_str!("assets/metric_alert.html"),
+                rule_name = rule_name,
+                description = description,
+                start_time = start_time,
+                end_time = end_time,
+            ),
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
@@ -662,6 +698,70 @@ impl EmailData for ApiKeyExpiryReminder {
     }
 }
 
+pub struct AnalyticsReport {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub report_name: String,
+    pub time_range: common_utils::types::TimeRange,
+    pub link: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsReport {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+        let end_time = self
+            .time_range
+            .end_time
+            .unwrap_or(self.time_range.start_time);
+
+        let body = html::get_html_body(EmailBody::AnalyticsReport {
+            report_name: self.report_name.clone(),
+            start_time: self.time_range.start_time.to_string(),
+            end_time: end_time.to_string(),
+            link: self.link.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct MetricAlert {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub rule_name: String,
+    pub description: String,
+    pub time_range: common_utils::types::TimeRange,
+}
+
+#[async_trait::async_trait]
+impl EmailData for MetricAlert {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+        let end_time = self
+            .time_range
+            .end_time
+            .unwrap_or(self.time_range.start_time);
+
+        let body = html::get_html_body(EmailBody::MetricAlert {
+            rule_name: self.rule_name.clone(),
+            description: self.description.clone(),
+            start_time: self.time_range.start_time.to_string(),
+            end_time: end_time.to_string(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
 pub struct WelcomeToCommunity {
     pub recipient_email: domain::UserEmail,
 }
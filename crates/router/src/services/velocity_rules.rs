@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use error_stack::ResultExt;
+use redis_interface::{RedisConnectionPool, RedisKey};
+
+use crate::{
+    consts,
+    core::errors::{ApiErrorResponse, RouterResult},
+    routes::app::SessionStateInfo,
+};
+
+fn get_redis_connection<A: SessionStateInfo>(state: &A) -> RouterResult<Arc<RedisConnectionPool>> {
+    state
+        .store()
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+/// Size of a single bucket of the window in seconds
+fn get_bucket_size(window_in_secs: u32) -> u32 {
+    (window_in_secs / consts::VELOCITY_WINDOW_BUCKET_COUNT).max(1)
+}
+
+fn get_bucket_keys(cache_key: &str, window_in_secs: u32, now: i64) -> Vec<String> {
+    let bucket_size = get_bucket_size(window_in_secs);
+    let current_bucket = now / i64::from(bucket_size);
+    let bucket_count = window_in_secs.div_ceil(bucket_size);
+
+    (0..i64::from(bucket_count))
+        .map(|offset| format!("{cache_key}_{}", current_bucket - offset))
+        .collect()
+}
+
+/// Returns the value accumulated for the cache key in the buckets overlapping the window
+pub async fn get_count_in_window<A>(
+    state: &A,
+    cache_key: &str,
+    window_in_secs: u32,
+) -> RouterResult<i64>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+    let keys = get_bucket_keys(
+        cache_key,
+        window_in_secs,
+        common_utils::date_time::now_unix_timestamp(),
+    )
+    .into_iter()
+    .map(RedisKey::from)
+    .collect::<Vec<_>>();
+
+    let values: Vec<Option<i64>> = redis_conn
+        .get_multiple_keys(&keys)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch velocity counters from redis")?;
+
+    Ok(values.into_iter().flatten().sum())
+}
+
+/// Adds the value to the current bucket of the cache key
+pub async fn increment_count_in_window<A>(
+    state: &A,
+    cache_key: &str,
+    window_in_secs: u32,
+    value: i64,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = get_redis_connection(state)?;
+    let bucket_size = get_bucket_size(window_in_secs);
+    let current_bucket = common_utils::date_time::now_unix_timestamp() / i64::from(bucket_size);
+    let bucket_key: RedisKey = format!("{cache_key}_{current_bucket}").into();
+
+    redis_conn
+        .increment_key_by(&bucket_key, value)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment velocity counter in redis")?;
+
+    // A bucket is relevant as long as any part of it falls within the window
+    redis_conn
+        .set_expiry(&bucket_key, i64::from(window_in_secs + bucket_size))
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set expiry for velocity counter in redis")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_bucket_keys_cover_window() {
+        let keys = get_bucket_keys("counter", 3600, 7200);
+        assert_eq!(keys.len(), 10);
+        assert_eq!(keys.first().unwrap(), "counter_20");
+        assert_eq!(keys.last().unwrap(), "counter_11");
+    }
+
+    #[test]
+    fn test_bucket_keys_for_window_smaller_than_bucket_count() {
+        let keys = get_bucket_keys("counter", 5, 100);
+        assert_eq!(keys.len(), 5);
+        assert_eq!(keys.first().unwrap(), "counter_100");
+    }
+}
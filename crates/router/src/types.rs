@@ -32,6 +32,7 @@ pub use hyperswitch_domain_models::router_data_v2::FrmFlowData;
 use hyperswitch_domain_models::router_flow_types::{
     self,
     access_token_auth::AccessTokenAuth,
+    account_updater::AccountUpdate,
     dispute::{Accept, Defend, Dsync, Evidence, Fetch},
     files::{Retrieve, Upload},
     mandate_revoke::MandateRevoke,
@@ -54,7 +55,7 @@ pub use hyperswitch_domain_models::{
         RecurringMandatePaymentData, RouterData,
     },
     router_data_v2::{
-        AccessTokenFlowData, AuthenticationTokenFlowData, DisputesFlowData,
+        AccessTokenFlowData, AccountUpdaterFlowData, AuthenticationTokenFlowData, DisputesFlowData,
         ExternalAuthenticationFlowData, FilesFlowData, MandateRevokeFlowData, PaymentFlowData,
         RefundFlowData, RouterDataV2, UasFlowData, WebhookSourceVerifyData,
     },
@@ -69,14 +70,14 @@ pub use hyperswitch_domain_models::{
             UasPreAuthenticationRequestData,
         },
         AcceptDisputeRequestData, AccessTokenAuthenticationRequestData, AccessTokenRequestData,
-        AuthorizeSessionTokenData, BrowserInformation, ChargeRefunds, ChargeRefundsOptions,
-        CompleteAuthorizeData, CompleteAuthorizeRedirectResponse, ConnectorCustomerData,
-        CreateOrderRequestData, DefendDisputeRequestData, DestinationChargeRefund,
-        DirectChargeRefund, DisputeSyncData, ExternalVaultProxyPaymentsData,
-        FetchDisputesRequestData, MandateRevokeRequestData, MultipleCaptureRequestData,
-        PaymentMethodTokenizationData, PaymentsApproveData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCancelPostCaptureData, PaymentsCaptureData,
-        PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
+        AccountUpdaterCardData, AccountUpdaterRequestData, AuthorizeSessionTokenData,
+        BrowserInformation, ChargeRefunds, ChargeRefundsOptions, CompleteAuthorizeData,
+        CompleteAuthorizeRedirectResponse, ConnectorCustomerData, CreateOrderRequestData,
+        DefendDisputeRequestData, DestinationChargeRefund, DirectChargeRefund, DisputeSyncData,
+        ExternalVaultProxyPaymentsData, FetchDisputesRequestData, MandateRevokeRequestData,
+        MultipleCaptureRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
+        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCancelPostCaptureData,
+        PaymentsCaptureData, PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsRejectData,
         PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData,
         PaymentsUpdateMetadataData, RefundsData, ResponseId, RetrieveFileRequestData,
@@ -89,9 +90,10 @@ pub use hyperswitch_domain_models::{
             BillingConnectorInvoiceSyncResponse, BillingConnectorPaymentsSyncResponse,
             RevenueRecoveryRecordBackResponse,
         },
-        AcceptDisputeResponse, CaptureSyncResponse, DefendDisputeResponse, DisputeSyncResponse,
-        FetchDisputesResponse, MandateReference, MandateRevokeResponseData, PaymentsResponseData,
-        PreprocessingResponseId, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
+        AcceptDisputeResponse, AccountUpdaterCardUpdate, AccountUpdaterResponseData,
+        CaptureSyncResponse, DefendDisputeResponse, DisputeSyncResponse, FetchDisputesResponse,
+        MandateReference, MandateRevokeResponseData, PaymentsResponseData, PreprocessingResponseId,
+        RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
         TaxCalculationResponseData, UploadFileResponse, VaultResponseData,
         VerifyWebhookSourceResponseData, VerifyWebhookStatus,
     },
@@ -109,8 +111,8 @@ pub use hyperswitch_interfaces::types::{
 pub use hyperswitch_interfaces::{
     disputes::DisputePayload,
     types::{
-        AcceptDisputeType, ConnectorCustomerType, DefendDisputeType, FetchDisputesType,
-        IncrementalAuthorizationType, MandateRevokeType, PaymentsAuthorizeType,
+        AcceptDisputeType, AccountUpdaterType, ConnectorCustomerType, DefendDisputeType,
+        FetchDisputesType, IncrementalAuthorizationType, MandateRevokeType, PaymentsAuthorizeType,
         PaymentsBalanceType, PaymentsCaptureType, PaymentsCompleteAuthorizeType, PaymentsInitType,
         PaymentsPostCaptureVoidType, PaymentsPostProcessingType, PaymentsPostSessionTokensType,
         PaymentsPreAuthorizeType, PaymentsPreProcessingType, PaymentsSessionType, PaymentsSyncType,
@@ -248,6 +250,9 @@ pub type DisputeSyncRouterData = RouterData<Dsync, DisputeSyncData, DisputeSyncR
 pub type MandateRevokeRouterData =
     RouterData<MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
 
+pub type AccountUpdaterRouterData =
+    RouterData<AccountUpdate, AccountUpdaterRequestData, AccountUpdaterResponseData>;
+
 #[cfg(feature = "payouts")]
 pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
 
@@ -1304,6 +1309,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             psd2_sca_exemption_type: data.psd2_sca_exemption_type,
             raw_connector_response: data.raw_connector_response.clone(),
             is_payment_id_from_merchant: data.is_payment_id_from_merchant,
+            merchant_connector_id: data.merchant_connector_id.clone(),
             l2_l3_data: data.l2_l3_data.clone(),
             minor_amount_capturable: data.minor_amount_capturable,
         }
@@ -1375,6 +1381,7 @@ impl<F1, F2>
             connector_mandate_request_reference_id: None,
             raw_connector_response: None,
             is_payment_id_from_merchant: data.is_payment_id_from_merchant,
+            merchant_connector_id: data.merchant_connector_id.clone(),
             l2_l3_data: None,
             minor_amount_capturable: None,
         }
@@ -45,6 +45,7 @@ use common_enums::RoutableConnectors;
 use error_stack::ResultExt;
 pub use hyperswitch_domain_models::router_flow_types::{
     access_token_auth::{AccessTokenAuth, AccessTokenAuthentication},
+    account_updater::AccountUpdate,
     mandate_revoke::MandateRevoke,
     webhooks::VerifyWebhookSource,
 };
@@ -65,10 +66,10 @@ pub use hyperswitch_interfaces::{
         },
         revenue_recovery_v2::RevenueRecoveryV2,
         BoxedConnector, Connector, ConnectorAccessToken, ConnectorAccessTokenV2,
-        ConnectorAuthenticationToken, ConnectorAuthenticationTokenV2, ConnectorCommon,
-        ConnectorCommonExt, ConnectorMandateRevoke, ConnectorMandateRevokeV2,
-        ConnectorTransactionId, ConnectorVerifyWebhookSource, ConnectorVerifyWebhookSourceV2,
-        CurrencyUnit,
+        ConnectorAccountUpdater, ConnectorAccountUpdaterV2, ConnectorAuthenticationToken,
+        ConnectorAuthenticationTokenV2, ConnectorCommon, ConnectorCommonExt,
+        ConnectorMandateRevoke, ConnectorMandateRevokeV2, ConnectorTransactionId,
+        ConnectorVerifyWebhookSource, ConnectorVerifyWebhookSourceV2, CurrencyUnit,
     },
     connector_integration_v2::{BoxedConnectorV2, ConnectorV2},
 };
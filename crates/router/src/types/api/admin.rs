@@ -4,12 +4,13 @@ use std::collections::HashMap;
 pub use api_models::admin;
 pub use api_models::{
     admin::{
-        MaskedHeaders, MerchantAccountCreate, MerchantAccountDeleteResponse,
-        MerchantAccountResponse, MerchantAccountUpdate, MerchantConnectorCreate,
-        MerchantConnectorDeleteResponse, MerchantConnectorDetails, MerchantConnectorDetailsWrap,
-        MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId,
-        PaymentMethodsEnabled, ProfileCreate, ProfileResponse, ProfileUpdate, ToggleAllKVRequest,
-        ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+        KvMigrationRequest, KvMigrationResponse, MaskedHeaders, MerchantAccountCreate,
+        MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
+        MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
+        MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse,
+        MerchantDetails, MerchantId, PaymentMethodsEnabled, ProfileCreate, ProfileResponse,
+        ProfileUpdate, ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse,
+        WebhookDetails,
     },
     organization::{
         OrganizationCreateRequest, OrganizationId, OrganizationResponse, OrganizationUpdateRequest,
@@ -233,6 +234,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             merchant_category_code: item.merchant_category_code,
             merchant_country_code: item.merchant_country_code,
             dispute_polling_interval: item.dispute_polling_interval,
+            is_payout_auto_retries_enabled: item.is_payout_auto_retries_enabled,
+            max_payout_auto_retries_enabled: item.max_payout_auto_retries_enabled,
         })
     }
 }
@@ -320,6 +323,7 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             merchant_category_code: item.merchant_category_code,
             merchant_country_code: item.merchant_country_code,
             split_txns_enabled: item.split_txns_enabled,
+            allowed_proxy_destination_hosts: item.allowed_proxy_destination_hosts,
         })
     }
 }
@@ -490,5 +494,7 @@ pub async fn create_profile_from_merchant_account(
         merchant_category_code: request.merchant_category_code,
         merchant_country_code: request.merchant_country_code,
         dispute_polling_interval: request.dispute_polling_interval,
+        is_payout_auto_retries_enabled: request.is_payout_auto_retries_enabled.unwrap_or_default(),
+        max_payout_auto_retries_enabled: request.max_payout_auto_retries_enabled.map(i16::from),
     }))
 }
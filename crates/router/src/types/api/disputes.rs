@@ -70,6 +70,22 @@ pub struct ProcessDisputePTData {
     pub merchant_id: common_utils::id_type::MerchantId,
 }
 
+/// Action taken on a dispute by the dispute automation workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeAutomationAction {
+    Accept,
+    SubmitEvidence,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct DisputeAutomationPTData {
+    pub dispute_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub action: DisputeAutomationAction,
+}
+
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct DisputeListPTData {
     pub connector_name: String,
@@ -1,6 +1,9 @@
 pub use api_models::payouts::{
     AchBankTransfer, BacsBankTransfer, Bank as BankPayout, CardPayout, PaymentMethodTypeInfo,
-    PayoutActionRequest, PayoutAttemptResponse, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutActionRequest, PayoutAttemptResponse, PayoutBatchCreateRequest,
+    PayoutBatchItemListConstraints, PayoutBatchItemListRequest, PayoutBatchItemListResponse,
+    PayoutBatchItemResponse, PayoutBatchPTData, PayoutBatchRecord, PayoutBatchResponse,
+    PayoutBatchRetrieveRequest, PayoutCreateRequest, PayoutCreateResponse,
     PayoutEnabledPaymentMethodsInfo, PayoutLinkResponse, PayoutListConstraints,
     PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse, PayoutMethodData,
     PayoutMethodDataResponse, PayoutRequest, PayoutRetrieveBody, PayoutRetrieveRequest,
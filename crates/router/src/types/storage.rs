@@ -10,6 +10,7 @@ pub mod callback_mapper;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod customer_deduplication;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -31,16 +32,21 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_account_update;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
+pub mod reusable_payment_link;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
+pub mod revenue_recovery_enrollment;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery_redis_operation;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -72,10 +78,12 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
-    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    capture::*, cards_info::*, configs::*, customer_deduplication::*, customers::*,
+    dashboard_metadata::*, dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*,
+    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
+    payment_method::*, payment_method_account_update::*, payout_batch::*, process_tracker::*,
+    refund::*, reusable_payment_link::*, revenue_recovery_enrollment::*, reverse_lookup::*,
+    role::*, routing_algorithm::*, subscription::*, unified_translations::*, user::*,
+    user_authentication_method::*, user_role::*,
 };
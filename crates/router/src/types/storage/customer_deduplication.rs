@@ -0,0 +1,4 @@
+pub use diesel_models::customer_deduplication::{
+    CustomerBlindIndex, CustomerBlindIndexNew, CustomerMerge, CustomerMergeNew,
+    CustomerMergeUpdate, CustomerMergeUpdateInternal,
+};
@@ -1,7 +1,9 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
-pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
+pub use diesel_models::dispute::{
+    Dispute, DisputeFinancialRecordsEntry, DisputeNew, DisputeUpdate,
+};
 use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::disputes;
@@ -22,6 +24,15 @@ pub trait DisputeDbExt: Sized {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::DatabaseError>;
+
+    async fn get_financial_records_by_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<DisputeFinancialRecordsEntry>, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -33,7 +44,7 @@ impl DisputeDbExt for Dispute {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::dispute_id.desc()))
             .into_boxed();
 
         let mut search_by_payment_or_dispute_id = false;
@@ -69,6 +80,14 @@ impl DisputeDbExt for Dispute {
             }
         }
 
+        if let Some((created_at, dispute_id)) = &dispute_list_constraints.starting_after {
+            filter = filter.filter(
+                dsl::created_at.lt(*created_at).or(dsl::created_at
+                    .eq(*created_at)
+                    .and(dsl::dispute_id.lt(dispute_id.clone()))),
+            );
+        }
+
         if let Some(profile_id) = &dispute_list_constraints.profile_id {
             filter = filter.filter(dsl::profile_id.eq_any(profile_id.clone()));
         }
@@ -142,4 +161,46 @@ impl DisputeDbExt for Dispute {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+
+    async fn get_financial_records_by_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<DisputeFinancialRecordsEntry>, errors::DatabaseError> {
+        let mut query = <Self as HasTable>::table()
+            .select((
+                dsl::connector,
+                dsl::connector_reason_code,
+                dsl::financial_records,
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::financial_records.is_not_null())
+            .filter(dsl::created_at.ge(time_range.start_time))
+            .order(dsl::dispute_id.asc())
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id_list {
+            query = query.filter(dsl::profile_id.eq_any(profile_id));
+        }
+
+        query = match time_range.end_time {
+            Some(ending_at) => query.filter(dsl::created_at.le(ending_at)),
+            None => query,
+        };
+
+        query = query.limit(limit).offset(offset);
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<DisputeFinancialRecordsEntry>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
 }
@@ -3,3 +3,8 @@ pub use diesel_models::merchant_account::{
 };
 
 pub use crate::types::domain::MerchantAccountUpdate;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KvMigrationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -0,0 +1,3 @@
+pub use diesel_models::payment_method_account_update::{
+    PaymentMethodAccountUpdate, PaymentMethodAccountUpdateNew,
+};
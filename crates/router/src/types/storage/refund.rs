@@ -79,7 +79,7 @@ impl RefundDbExt for Refund {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::refund_id.desc()))
             .into_boxed();
         let mut search_by_pay_or_ref_id = false;
 
@@ -138,6 +138,14 @@ impl RefundDbExt for Refund {
             }
         }
 
+        if let Some((created_at, refund_id)) = &refund_list_details.starting_after {
+            filter = filter.filter(
+                dsl::created_at.lt(*created_at).or(dsl::created_at
+                    .eq(*created_at)
+                    .and(dsl::refund_id.lt(refund_id.clone()))),
+            );
+        }
+
         filter = match refund_list_details.amount_filter {
             Some(AmountFilter {
                 start_amount: Some(start),
@@ -191,7 +199,7 @@ impl RefundDbExt for Refund {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::id.desc()))
             .into_boxed();
 
         if let Some(payment_id) = &refund_list_details.payment_id {
@@ -271,7 +279,7 @@ impl RefundDbExt for Refund {
 
         let filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::refund_id.desc()))
             .filter(dsl::created_at.ge(start_time))
             .filter(dsl::created_at.le(end_time));
 
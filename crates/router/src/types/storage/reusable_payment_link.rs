@@ -0,0 +1,3 @@
+pub use diesel_models::reusable_payment_link::{
+    ReusablePaymentLink, ReusablePaymentLinkNew, ReusablePaymentLinkUpdate,
+};
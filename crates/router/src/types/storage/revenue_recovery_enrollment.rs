@@ -0,0 +1,4 @@
+pub use diesel_models::revenue_recovery::{
+    RevenueRecoveryAttempt, RevenueRecoveryAttemptNew, RevenueRecoveryAttemptUpdate,
+    RevenueRecoveryEnrollment, RevenueRecoveryEnrollmentNew, RevenueRecoveryEnrollmentUpdate,
+};
@@ -0,0 +1,5 @@
+pub use diesel_models::subscription::{
+    Subscription, SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+    SubscriptionNew, SubscriptionPrice, SubscriptionPriceNew, SubscriptionPriceUpdate,
+    SubscriptionProduct, SubscriptionProductNew, SubscriptionProductUpdate, SubscriptionUpdate,
+};
@@ -450,6 +450,46 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for api_enums::R
     }
 }
 
+/// Status of the payment against which a relay was made, as reported by a payment webhook
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for api_enums::AttemptStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentSuccess
+            | api_models::webhooks::IncomingWebhookEvent::PaymentIntentCaptureSuccess => {
+                Ok(Self::Charged)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentPartiallyFunded => {
+                Ok(Self::PartialCharged)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentProcessing => {
+                Ok(Self::Pending)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentFailure => Ok(Self::Failure),
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCancelled => Ok(Self::Voided),
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCancelFailure => {
+                Ok(Self::VoidFailed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationSuccess => {
+                Ok(Self::Authorized)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationFailure => {
+                Ok(Self::AuthorizationFailed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentCaptureFailure => {
+                Ok(Self::CaptureFailed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentExpired => Ok(Self::Expired),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
     type Error = errors::ValidationError;
@@ -707,6 +747,10 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            financial_records: dispute
+                .financial_records
+                .map(|financial_records| financial_records.0)
+                .unwrap_or_default(),
         }
     }
 }
@@ -1253,6 +1297,17 @@ impl ForeignFrom<api_models::enums::PayoutType> for api_enums::PaymentMethod {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl ForeignFrom<api_models::enums::PayoutType> for euclid::enums::PayoutType {
+    fn foreign_from(value: api_models::enums::PayoutType) -> Self {
+        match value {
+            api_models::enums::PayoutType::Bank => Self::BankTransfer,
+            api_models::enums::PayoutType::Card => Self::Card,
+            api_models::enums::PayoutType::Wallet => Self::Wallet,
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl ForeignTryFrom<api_enums::PaymentMethod> for api_models::enums::PayoutType {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
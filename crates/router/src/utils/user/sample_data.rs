@@ -475,6 +475,7 @@ pub async fn generate_sample_data(
                     dispute_amount: MinorUnit::new(amount * 100),
                     organization_id: org_id.clone(),
                     dispute_currency: Some(payment_intent.currency.unwrap_or_default()),
+                    financial_records: None,
                 })
             } else {
                 None
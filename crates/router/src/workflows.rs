@@ -1,10 +1,23 @@
+#[cfg(feature = "v1")]
+pub mod account_updater;
+#[cfg(feature = "olap")]
+pub mod analytics_report;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod kv_migration;
+#[cfg(feature = "olap")]
+pub mod metric_alert;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
+#[cfg(feature = "v1")]
+pub mod recovery_enrollment_retry;
+#[cfg(feature = "v1")]
+pub mod subscription_billing;
 
 pub mod refund_router;
 
@@ -15,3 +28,5 @@ pub mod revenue_recovery;
 pub mod process_dispute;
 
 pub mod dispute_list;
+
+pub mod dispute_automation;
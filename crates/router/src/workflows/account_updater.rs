@@ -0,0 +1,97 @@
+use api_models::account_updater::AccountUpdaterPTData;
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::enums as storage_enums;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::account_updater,
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct AccountUpdaterWorkflow;
+
+/// This workflow checks the stored cards of a merchant for updates at a connector, a page of
+/// payment methods at a time. Once all of them have been checked it starts over after the
+/// configured interval, until the account updater is disabled
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: AccountUpdaterPTData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccountUpdaterPTData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let next_offset =
+            account_updater::run_account_updater(state, &merchant_context, &tracking_data).await?;
+
+        let schedule_time = match next_offset {
+            Some(_) => common_utils::date_time::now(),
+            None => {
+                common_utils::date_time::now()
+                    + time::Duration::hours(i64::from(state.conf.account_updater.interval_hours))
+            }
+        };
+        let tracking_data = AccountUpdaterPTData {
+            offset: next_offset.unwrap_or(0),
+            ..tracking_data
+        }
+        .encode_to_value()?;
+
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::disputes,
+    db::StorageInterface,
+    types::{api, domain},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct DisputeAutomationWorkflow;
+
+/// This workflow accepts the dispute or submits the evidence built from the evidence template,
+/// as decided by the dispute automation rules of the profile
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeAutomationWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::DisputeAutomationPTData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeAutomationPTData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await?;
+
+        // The rules are evaluated again, as the config or the dispute could have changed since
+        // the task was scheduled
+        let action =
+            disputes::automation::get_dispute_automation_config(state, &tracking_data.profile_id)
+                .await?
+                .and_then(|config| {
+                    disputes::automation::get_dispute_automation_action(
+                        &config.rules,
+                        &dispute,
+                        common_utils::date_time::now(),
+                    )
+                });
+
+        match action {
+            None => {
+                logger::info!(
+                    dispute_id = %tracking_data.dispute_id,
+                    "No dispute automation action applicable, skipping"
+                );
+            }
+            Some((_, schedule_time)) if schedule_time > common_utils::date_time::now() => {
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+                return Ok(());
+            }
+            Some((api::DisputeAutomationAction::Accept, _)) => {
+                Box::pin(disputes::accept_dispute(
+                    state.clone(),
+                    merchant_context,
+                    None,
+                    api::DisputeId {
+                        dispute_id: tracking_data.dispute_id,
+                    },
+                ))
+                .await?;
+            }
+            Some((api::DisputeAutomationAction::SubmitEvidence, _)) => {
+                let evidence_request = disputes::automation::apply_evidence_template(
+                    state,
+                    &merchant_context,
+                    &dispute,
+                    api_models::disputes::SubmitEvidenceRequest {
+                        dispute_id: tracking_data.dispute_id,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+                Box::pin(disputes::submit_evidence(
+                    state.clone(),
+                    merchant_context,
+                    None,
+                    evidence_request,
+                ))
+                .await?;
+            }
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
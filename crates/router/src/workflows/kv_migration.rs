@@ -0,0 +1,64 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::kv_migration, db::StorageInterface, errors, routes::SessionState, types::storage,
+};
+
+pub struct KvMigrationWorkflow;
+
+/// This workflow switches the storage scheme of the merchant, waits for its pending drainer
+/// entries to be applied to Postgres and ends the grace period, polling until the migration is
+/// completed
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for KvMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::KvMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("KvMigrationTrackingData")?;
+
+        match kv_migration::process_kv_migration(state, &tracking_data.merchant_id).await? {
+            Some(next_check_at) => {
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(next_check_at),
+                            tracking_data: None,
+                            business_status: None,
+                            status: Some(storage_enums::ProcessTrackerStatus::New),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
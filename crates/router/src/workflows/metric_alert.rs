@@ -0,0 +1,97 @@
+use api_models::analytics::metric_alerts::MetricAlertPTData;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::metric_alerts,
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct MetricAlertWorkflow;
+
+/// This workflow evaluates a metric alert rule over the last complete window and reschedules
+/// itself for the next window, until the rule is deleted
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MetricAlertWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: MetricAlertPTData = process
+            .tracking_data
+            .clone()
+            .parse_value("MetricAlertPTData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let next_evaluation_at =
+            metric_alerts::run_metric_alert_rule(state, &merchant_context, &tracking_data).await?;
+
+        match next_evaluation_at {
+            Some(next_evaluation_at) => {
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(next_evaluation_at),
+                            tracking_data: None,
+                            business_status: None,
+                            status: Some(storage_enums::ProcessTrackerStatus::New),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+            }
+            None => {
+                logger::info!(
+                    rule_id = %tracking_data.rule_id,
+                    "Metric alert rule no longer exists, finishing the task"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::REVOKED)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
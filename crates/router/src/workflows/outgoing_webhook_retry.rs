@@ -571,5 +571,13 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+        // Metric alert and subscription events describe the resource at a point in time, and are
+        // always stored along with the request content
+        diesel_models::enums::EventClass::MetricAlerts
+        | diesel_models::enums::EventClass::Subscriptions => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
     }
 }
@@ -87,6 +87,7 @@ impl ProcessTrackerWorkflow<SessionState> for ProcessDisputeWorkflow {
                 merchant_context.get_merchant_account().get_id(),
                 &payment_attempt.payment_id,
                 &tracking_data.dispute_payload.connector_dispute_id,
+                merchant_context.get_merchant_account().storage_scheme,
             )
             .await
             .ok()
@@ -0,0 +1,102 @@
+use api_models::recovery_enrollments::RecoveryEnrollmentRetryPTData;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::recovery_enrollments,
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct RecoveryEnrollmentWorkflow;
+
+/// This workflow retries a payment enrolled for native revenue recovery, rescheduling itself for
+/// the next retry computed from the decline of the previous one, until the payment is recovered,
+/// the retries are exhausted or the enrollment is cancelled
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RecoveryEnrollmentWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: RecoveryEnrollmentRetryPTData = process
+            .tracking_data
+            .clone()
+            .parse_value("RecoveryEnrollmentRetryPTData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let next_retry_at = recovery_enrollments::run_recovery_enrollment_retry(
+            state,
+            &merchant_context,
+            &tracking_data,
+        )
+        .await?;
+
+        match next_retry_at {
+            Some(next_retry_at) => {
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(next_retry_at),
+                            tracking_data: None,
+                            business_status: None,
+                            status: Some(storage_enums::ProcessTrackerStatus::New),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+            }
+            None => {
+                logger::info!(
+                    enrollment_id = %tracking_data.enrollment_id,
+                    "Enrolled payment is no longer retried, finishing the task"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -137,6 +137,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
         l2_l3_data: None,
         minor_amount_capturable: None,
     }
@@ -213,6 +214,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
         l2_l3_data: None,
         minor_amount_capturable: None,
     }
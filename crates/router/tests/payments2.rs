@@ -230,6 +230,7 @@ async fn payments_create_core() {
         payment_channel: None,
         network_transaction_id: None,
         enable_partial_authorization: None,
+        velocity_check: None,
     };
 
     let expected_response =
@@ -519,6 +520,7 @@ async fn payments_create_core_adyen_no_redirect() {
             payment_channel: None,
             network_transaction_id: None,
             enable_partial_authorization: None,
+            velocity_check: None,
         },
         vec![],
     ));
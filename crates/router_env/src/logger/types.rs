@@ -104,6 +104,16 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers duplicates list flow.
+    CustomersDuplicatesList,
+    /// Customers duplicates retrieve flow.
+    CustomersDuplicatesRetrieve,
+    /// Customers blind index sync flow.
+    CustomersBlindIndexSync,
+    /// Customers merge flow.
+    CustomersMerge,
+    /// Customers merge list flow.
+    CustomersMergeList,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -211,6 +221,14 @@ pub enum Flow {
     PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payout batch create flow.
+    PayoutBatchCreate,
+    /// Payout batch retrieve flow.
+    PayoutBatchRetrieve,
+    /// Payout batch items list flow.
+    PayoutBatchItemsList,
+    /// Payout batch results download flow.
+    PayoutBatchResults,
     /// Payout link initiate flow
     PayoutLinkInitiate,
     /// Payments Redirect flow
@@ -319,8 +337,16 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Add Dispute Financial Record flow
+    AddDisputeFinancialRecord,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Invalidate cache entries by prefix flow
+    CacheInvalidatePrefix,
+    /// Cache stats flow
+    CacheStats,
+    /// Cache key lookup flow
+    CacheKeyLookup,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -331,6 +357,66 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Reusable Payment Link Create flow
+    ReusablePaymentLinkCreate,
+    /// Reusable Payment Link Retrieve flow
+    ReusablePaymentLinkRetrieve,
+    /// Reusable Payment Link Update flow
+    ReusablePaymentLinkUpdate,
+    /// Reusable Payment Link List flow
+    ReusablePaymentLinkList,
+    /// Reusable Payment Link Analytics flow
+    ReusablePaymentLinkAnalytics,
+    /// Reusable Payment Link Visit flow
+    ReusablePaymentLinkVisit,
+    /// Reusable Payment Link Confirm flow
+    ReusablePaymentLinkConfirm,
+    /// Reusable Payment Link QR Code flow
+    ReusablePaymentLinkQrCode,
+    /// Subscription Product Create flow
+    SubscriptionProductCreate,
+    /// Subscription Product Retrieve flow
+    SubscriptionProductRetrieve,
+    /// Subscription Product Update flow
+    SubscriptionProductUpdate,
+    /// Subscription Product List flow
+    SubscriptionProductList,
+    /// Subscription Price Create flow
+    SubscriptionPriceCreate,
+    /// Subscription Price Retrieve flow
+    SubscriptionPriceRetrieve,
+    /// Subscription Price Update flow
+    SubscriptionPriceUpdate,
+    /// Subscription Price List flow
+    SubscriptionPriceList,
+    /// Subscription Create flow
+    SubscriptionCreate,
+    /// Subscription Retrieve flow
+    SubscriptionRetrieve,
+    /// Subscription Update flow
+    SubscriptionUpdate,
+    /// Subscription Cancel flow
+    SubscriptionCancel,
+    /// Subscription List flow
+    SubscriptionList,
+    /// Subscription Invoice Retrieve flow
+    SubscriptionInvoiceRetrieve,
+    /// Subscription Invoice List flow
+    SubscriptionInvoiceList,
+    /// Revenue recovery enrollment create flow
+    RecoveryEnrollmentCreate,
+    /// Revenue recovery enrollment retrieve flow
+    RecoveryEnrollmentRetrieve,
+    /// Revenue recovery enrollment cancel flow
+    RecoveryEnrollmentCancel,
+    /// Revenue recovery enrollment list flow
+    RecoveryEnrollmentList,
+    /// Account updater enable flow
+    AccountUpdaterEnable,
+    /// Account updater disable flow
+    AccountUpdaterDisable,
+    /// Payment method account updates list flow
+    PaymentMethodAccountUpdatesList,
     /// Create a profile
     ProfileCreate,
     /// Update a profile
@@ -650,6 +736,22 @@ pub enum Flow {
     RecoveryPaymentsCreate,
     /// Tokenization delete flow
     TokenizationDelete,
+    /// Velocity rules upsert flow
+    VelocityRulesUpsert,
+    /// Velocity rules retrieve flow
+    VelocityRulesRetrieve,
+    /// Velocity rules delete flow
+    VelocityRulesDelete,
+    /// Dispute automation config upsert flow
+    DisputeAutomationConfigUpsert,
+    /// Dispute automation config retrieve flow
+    DisputeAutomationConfigRetrieve,
+    /// Dispute automation config delete flow
+    DisputeAutomationConfigDelete,
+    /// Merchant storage scheme migration create flow
+    MerchantKvMigrationCreate,
+    /// Merchant storage scheme migration retrieve flow
+    MerchantKvMigrationRetrieve,
 }
 
 /// Trait for providing generic behaviour to flow metric
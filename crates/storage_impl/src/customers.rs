@@ -314,6 +314,7 @@ impl<T: DatabaseStore> domain::CustomerInterface for kv_router_store::KVRouterSt
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         new_customer.update_storage_scheme(decided_storage_scheme);
@@ -366,6 +367,7 @@ impl<T: DatabaseStore> domain::CustomerInterface for kv_router_store::KVRouterSt
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         new_customer.update_storage_scheme(storage_scheme);
@@ -260,6 +260,8 @@ pub enum HealthCheckRedisError {
     GetFailed,
     #[error("Failed to delete key value in Redis")]
     DeleteFailed,
+    #[error("Failed to fetch the primary nodes of Redis")]
+    PrimaryNodesFetchFailed,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -24,9 +24,12 @@ use crate::{
     errors::{self, RedisErrorExt, StorageResult},
     lookup::ReverseLookupInterface,
     metrics,
-    redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, KvStorePartition, Op, PartitionKey,
-        RedisConnInterface,
+    redis::{
+        kv_migration,
+        kv_store::{
+            decide_storage_scheme, kv_wrapper, KvOperation, KvStorePartition, Op, PartitionKey,
+            RedisConnInterface,
+        },
     },
     utils::{find_all_combined_kv_database, try_redis_get_else_try_database_get},
     RouterStore, UniqueConstraints,
@@ -168,7 +171,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
     }
 
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
-        format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
+        kv_migration::get_drainer_stream_name(shard_key, &self.drainer_stream_name)
     }
 
     pub async fn push_to_drainer_stream<R>(
@@ -226,6 +229,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(&key_store.merchant_id),
         ))
         .await;
         let res = || async {
@@ -295,6 +299,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(&key_store.merchant_id),
         ))
         .await;
         let res = || async {
@@ -370,6 +375,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         match storage_scheme {
@@ -448,6 +454,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
                     self,
                     storage_scheme,
                     Op::Update(key.clone(), field, updated_by),
+                    Some(&key_store.merchant_id),
                 ))
                 .await;
                 match storage_scheme {
@@ -9,12 +9,15 @@ use hyperswitch_domain_models::{
 use masking::StrongSecret;
 use redis::{kv_store::RedisConnInterface, pub_sub::PubSubInterface, RedisStore};
 mod address;
+mod authentication;
 pub mod callback_mapper;
+mod capture;
 pub mod cards_info;
 pub mod config;
 pub mod connection;
 pub mod customers;
 pub mod database;
+mod dispute;
 pub mod errors;
 pub mod kv_router_store;
 pub mod lookup;
@@ -129,6 +132,12 @@ impl<T: DatabaseStore> RouterStore<T> {
             .await
             .change_context(StorageError::InitializationError)
             .attach_printable("Failed to subscribe to inmemory cache stream")?;
+        cache_store
+            .redis_conn
+            .subscribe(redis::cache::CACHE_INTROSPECTION_CHANNEL)
+            .await
+            .change_context(StorageError::InitializationError)
+            .attach_printable("Failed to subscribe to cache introspection channel")?;
 
         Ok(Self {
             db_store,
@@ -482,6 +491,41 @@ impl UniqueConstraints for diesel_models::Mandate {
     }
 }
 
+impl UniqueConstraints for diesel_models::Dispute {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!(
+            "dispute_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.dispute_id
+        )]
+    }
+    fn table_name(&self) -> &str {
+        "Dispute"
+    }
+}
+
+impl UniqueConstraints for diesel_models::capture::Capture {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!("capture_{}", self.capture_id)]
+    }
+    fn table_name(&self) -> &str {
+        "Capture"
+    }
+}
+
+impl UniqueConstraints for diesel_models::authentication::Authentication {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!(
+            "authentication_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.authentication_id.get_string_repr()
+        )]
+    }
+    fn table_name(&self) -> &str {
+        "Authentication"
+    }
+}
+
 #[cfg(feature = "v1")]
 impl UniqueConstraints for diesel_models::Customer {
     fn unique_constraints(&self) -> Vec<String> {
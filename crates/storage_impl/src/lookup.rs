@@ -75,6 +75,7 @@ impl<T: DatabaseStore> ReverseLookupInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         match storage_scheme {
@@ -134,6 +135,8 @@ impl<T: DatabaseStore> ReverseLookupInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            // Lookups are read with the storage scheme already decided for the record being looked up
+            None,
         ))
         .await;
         match storage_scheme {
@@ -16,3 +16,5 @@ gauge_metric!(IN_MEMORY_CACHE_ENTRY_COUNT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_EVICTION_COUNT, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_COALESCED_WAIT, GLOBAL_METER); // No. of misses which waited on a concurrent load
+counter_metric!(IN_MEMORY_CACHE_STALE_SERVED, GLOBAL_METER); // No. of expired entries served while being refreshed
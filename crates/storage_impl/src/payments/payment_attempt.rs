@@ -596,6 +596,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         match storage_scheme {
@@ -758,6 +759,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
 
@@ -865,6 +867,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Update(key.clone(), &field, Some(&this.updated_by)),
+            Some(&this.merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1036,6 +1039,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1104,6 +1108,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1163,6 +1168,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1228,6 +1234,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(&merchant_key_store.merchant_id),
         ))
         .await;
 
@@ -1318,6 +1325,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1388,6 +1396,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1445,6 +1454,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1556,6 +1566,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1625,6 +1636,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
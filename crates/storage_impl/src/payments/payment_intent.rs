@@ -84,6 +84,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         match storage_scheme {
@@ -274,6 +275,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Update(key.clone(), &field, Some(&this.updated_by)),
+            Some(&merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -444,6 +446,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         let diesel_payment_intent = match storage_scheme {
@@ -494,6 +497,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(&merchant_key_store.merchant_id),
         ))
         .await;
 
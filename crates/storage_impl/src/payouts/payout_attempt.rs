@@ -47,6 +47,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         match storage_scheme {
@@ -159,6 +160,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Update(key.clone(), &field, None),
+            Some(&this.merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -255,6 +257,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
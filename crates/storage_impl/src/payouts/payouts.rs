@@ -66,6 +66,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Insert,
+            None,
         ))
         .await;
         match storage_scheme {
@@ -156,6 +157,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Update(key.clone(), &field, None),
+            Some(&this.merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -223,6 +225,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -271,6 +274,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
             self,
             storage_scheme,
             Op::Find,
+            Some(merchant_id),
         ))
         .await;
         match storage_scheme {
@@ -1,10 +1,13 @@
 use std::{
     any::Any,
     borrow::Cow,
+    collections::HashMap,
     fmt::Debug,
-    sync::{Arc, LazyLock},
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+    time::{Duration, Instant},
 };
 
+use api_models::cache::{CacheIntrospectionOperation, CacheReport, PodCacheReport};
 use common_utils::{
     errors::{self, CustomResult},
     ext_traits::ByteSliceExt,
@@ -27,6 +30,18 @@ use crate::{
 /// Redis channel name used for publishing invalidation messages
 pub const IMC_INVALIDATION_CHANNEL: &str = "hyperswitch_invalidate";
 
+/// Redis channel name used for publishing cache introspection requests to every pod
+pub const CACHE_INTROSPECTION_CHANNEL: &str = "hyperswitch_cache_introspection";
+
+/// Time for which the pods' reports of a cache introspection request are kept in Redis, 1 min
+const CACHE_INTROSPECTION_REPORTS_TTL: i64 = 60;
+
+/// Time for which the reports of all pods are awaited, 3 secs
+const CACHE_INTROSPECTION_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Interval at which the number of pods' reports is checked
+const CACHE_INTROSPECTION_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 /// Time to live 30 mins
 const CACHE_TTL: u64 = 30 * 60;
 
@@ -36,17 +51,35 @@ const CACHE_TTI: u64 = 10 * 60;
 /// Max Capacity of Cache in MB
 const MAX_CAPACITY: u64 = 30;
 
+/// Time for which expired entries are served while being refreshed, 5 mins
+const CACHE_STALE_WHILE_REVALIDATE: u64 = 5 * 60;
+
 /// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
 pub static CONFIG_CACHE: LazyLock<Cache> =
     LazyLock::new(|| Cache::new("CONFIG_CACHE", CACHE_TTL, CACHE_TTI, None));
 
-/// Accounts cache with time_to_live as 30 mins and size limit
-pub static ACCOUNTS_CACHE: LazyLock<Cache> =
-    LazyLock::new(|| Cache::new("ACCOUNTS_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+/// Accounts cache with time_to_live as 30 mins and size limit, expired entries are served for
+/// another 5 mins while being refreshed
+pub static ACCOUNTS_CACHE: LazyLock<Cache> = LazyLock::new(|| {
+    Cache::new_with_stale_while_revalidate(
+        "ACCOUNTS_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+        CACHE_STALE_WHILE_REVALIDATE,
+    )
+});
 
-/// Routing Cache
-pub static ROUTING_CACHE: LazyLock<Cache> =
-    LazyLock::new(|| Cache::new("ROUTING_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+/// Routing Cache, expired entries are served for another 5 mins while being refreshed
+pub static ROUTING_CACHE: LazyLock<Cache> = LazyLock::new(|| {
+    Cache::new_with_stale_while_revalidate(
+        "ROUTING_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+        CACHE_STALE_WHILE_REVALIDATE,
+    )
+});
 
 /// 3DS Decision Manager Cache
 pub static DECISION_MANAGER_CACHE: LazyLock<Cache> = LazyLock::new(|| {
@@ -106,6 +139,22 @@ pub static CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE: LazyLock<Cache> = LazyLock::n
     )
 });
 
+/// All in-memory caches, in the order in which they are reported
+fn get_all_caches() -> [&'static Cache; 10] {
+    [
+        &CONFIG_CACHE,
+        &ACCOUNTS_CACHE,
+        &ROUTING_CACHE,
+        &DECISION_MANAGER_CACHE,
+        &SURCHARGE_CACHE,
+        &CGRAPH_CACHE,
+        &PM_FILTERS_CGRAPH_CACHE,
+        &SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE,
+        &ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE,
+        &CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE,
+    ]
+}
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
@@ -190,7 +239,53 @@ dyn_clone::clone_trait_object!(Cacheable);
 
 pub struct Cache {
     name: &'static str,
-    inner: MokaCache<String, Arc<dyn Cacheable>>,
+    inner: MokaCache<String, CacheEntry>,
+    time_to_live: Duration,
+    /// Time after `time_to_live` for which an entry is served while it is being refreshed
+    stale_while_revalidate: Option<Duration>,
+    /// Keys being loaded into the cache, callers which miss the cache while a key is being loaded
+    /// wait on the semaphore instead of loading the key themselves
+    in_flight_loads: Arc<Mutex<HashMap<String, Arc<tokio::sync::Semaphore>>>>,
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    value: Arc<dyn Cacheable>,
+    inserted_at: Instant,
+    /// Approximate memory used by the value
+    estimated_size: usize,
+}
+
+enum CacheLookup<T> {
+    Fresh(T),
+    /// The entry has outlived the cache's `time_to_live`, but is within the stale-while-revalidate
+    /// window
+    Stale(T),
+}
+
+/// Held by the only caller loading a key into the cache. Dropping it wakes up the callers
+/// waiting on the load.
+struct InFlightLoad {
+    in_flight_loads: Arc<Mutex<HashMap<String, Arc<tokio::sync::Semaphore>>>>,
+    key: String,
+    semaphore: Arc<tokio::sync::Semaphore>,
+}
+
+impl Drop for InFlightLoad {
+    fn drop(&mut self) {
+        self.in_flight_loads
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.key);
+        self.semaphore.close();
+    }
+}
+
+enum LoadPermit {
+    /// No other caller is loading the key
+    Leader(InFlightLoad),
+    /// Another caller is loading the key, the semaphore is closed once the load completes
+    Follower(Arc<tokio::sync::Semaphore>),
 }
 
 #[derive(Debug, Clone)]
@@ -223,6 +318,38 @@ impl Cache {
         time_to_idle: u64,
         max_capacity: Option<u64>,
     ) -> Self {
+        Self::build(name, time_to_live, time_to_idle, max_capacity, None)
+    }
+
+    /// Creates a moka cache which keeps serving entries for `stale_while_revalidate` seconds after
+    /// their `time_to_live` has elapsed, while a single caller refreshes them.
+    ///
+    /// Invalidated entries are removed right away and are never served stale.
+    pub fn new_with_stale_while_revalidate(
+        name: &'static str,
+        time_to_live: u64,
+        time_to_idle: u64,
+        max_capacity: Option<u64>,
+        stale_while_revalidate: u64,
+    ) -> Self {
+        Self::build(
+            name,
+            time_to_live,
+            time_to_idle,
+            max_capacity,
+            Some(stale_while_revalidate),
+        )
+    }
+
+    fn build(
+        name: &'static str,
+        time_to_live: u64,
+        time_to_idle: u64,
+        max_capacity: Option<u64>,
+        stale_while_revalidate: Option<u64>,
+    ) -> Self {
+        let time_to_live = Duration::from_secs(time_to_live);
+        let stale_while_revalidate = stale_while_revalidate.map(Duration::from_secs);
         // Record the metrics of manual invalidation of cache entry by the application
         let eviction_listener = move |_, _, cause| {
             metrics::IN_MEMORY_CACHE_EVICTION_COUNT.add(
@@ -233,9 +360,10 @@ impl Cache {
                 ),
             );
         };
+        // Stale entries are kept around by moka, they are told apart by their insertion time
         let mut cache_builder = MokaCache::builder()
-            .time_to_live(std::time::Duration::from_secs(time_to_live))
-            .time_to_idle(std::time::Duration::from_secs(time_to_idle))
+            .time_to_live(time_to_live + stale_while_revalidate.unwrap_or_default())
+            .time_to_idle(Duration::from_secs(time_to_idle))
             .eviction_listener(eviction_listener);
 
         if let Some(capacity) = max_capacity {
@@ -245,28 +373,84 @@ impl Cache {
         Self {
             name,
             inner: cache_builder.build(),
+            time_to_live,
+            stale_while_revalidate,
+            in_flight_loads: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
     pub async fn push<T: Cacheable>(&self, key: CacheKey, val: T) {
-        self.inner.insert(key.into(), Arc::new(val)).await;
+        let estimated_size = std::mem::size_of_val(&val);
+        self.insert(key, val, estimated_size).await;
+    }
+
+    async fn insert<T: Cacheable>(&self, key: CacheKey, val: T, estimated_size: usize) {
+        self.inner
+            .insert(
+                key.into(),
+                CacheEntry {
+                    value: Arc::new(val),
+                    inserted_at: Instant::now(),
+                    estimated_size,
+                },
+            )
+            .await;
     }
 
+    /// Returns the value stored against the key, stale values are not returned
     pub async fn get_val<T: Clone + Cacheable>(&self, key: CacheKey) -> Option<T> {
-        let val = self.inner.get::<String>(&key.into()).await;
+        let val = match self.lookup::<T>(key).await {
+            Some(CacheLookup::Fresh(val)) => Some(val),
+            Some(CacheLookup::Stale(_)) | None => None,
+        };
+
+        self.record_lookup_metric(val.is_some());
+
+        val
+    }
 
+    async fn lookup<T: Clone + Cacheable>(&self, key: CacheKey) -> Option<CacheLookup<T>> {
+        let entry = self.inner.get::<String>(&key.into()).await?;
+        let val = entry.value.as_any().downcast_ref::<T>().cloned()?;
+
+        if entry.inserted_at.elapsed() < self.time_to_live {
+            Some(CacheLookup::Fresh(val))
+        } else {
+            Some(CacheLookup::Stale(val))
+        }
+    }
+
+    fn record_lookup_metric(&self, is_hit: bool) {
         // Add cache hit and cache miss metrics
-        if val.is_some() {
+        if is_hit {
             metrics::IN_MEMORY_CACHE_HIT
                 .add(1, router_env::metric_attributes!(("cache_type", self.name)));
         } else {
             metrics::IN_MEMORY_CACHE_MISS
                 .add(1, router_env::metric_attributes!(("cache_type", self.name)));
         }
+    }
+
+    /// Registers the caller as the one loading the key, unless another caller is already loading it
+    fn begin_load(&self, key: &str) -> LoadPermit {
+        let mut in_flight_loads = self
+            .in_flight_loads
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(semaphore) = in_flight_loads.get(key) {
+            return LoadPermit::Follower(semaphore.clone());
+        }
 
-        let val = (*val?).as_any().downcast_ref::<T>().cloned();
+        // The semaphore never has permits, waiting on it returns once the load closes it
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(0));
+        in_flight_loads.insert(key.to_owned(), semaphore.clone());
 
-        val
+        LoadPermit::Leader(InFlightLoad {
+            in_flight_loads: self.in_flight_loads.clone(),
+            key: key.to_owned(),
+            semaphore,
+        })
     }
 
     /// Check if a key exists in cache
@@ -278,6 +462,31 @@ impl Cache {
         self.inner.invalidate::<String>(&key.into()).await;
     }
 
+    /// Removes every entry whose key starts with the prefix, returns the number of entries removed
+    pub async fn remove_by_prefix(&self, prefix: CacheKey) -> u64 {
+        let prefix = String::from(prefix);
+        let keys = self
+            .inner
+            .iter()
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+
+        for key in &keys {
+            self.inner.invalidate(key.as_ref()).await;
+        }
+
+        u64::try_from(keys.len()).unwrap_or(u64::MAX)
+    }
+
+    /// Returns an approximate memory used by the entries of this cache, in bytes.
+    fn get_estimated_size(&self) -> u64 {
+        self.inner
+            .iter()
+            .map(|(key, entry)| u64::try_from(key.len() + entry.estimated_size).unwrap_or(u64::MAX))
+            .fold(0, u64::saturating_add)
+    }
+
     /// Performs any pending maintenance operations needed by the cache.
     async fn run_pending_tasks(&self) {
         self.inner.run_pending_tasks().await;
@@ -357,26 +566,76 @@ where
             RedisError::RedisConnectionError.into(),
         ))
         .attach_printable("Failed to get redis connection")?;
-    let cache_val = cache
-        .get_val::<T>(CacheKey {
-            key: key.to_string(),
-            prefix: redis.key_prefix.clone(),
-        })
-        .await;
-    if let Some(val) = cache_val {
-        Ok(val)
-    } else {
+    let cache_key = CacheKey {
+        key: key.to_string(),
+        prefix: redis.key_prefix.clone(),
+    };
+
+    let stale_val = match cache.lookup::<T>(cache_key.clone()).await {
+        Some(CacheLookup::Fresh(val)) => {
+            cache.record_lookup_metric(true);
+            return Ok(val);
+        }
+        Some(CacheLookup::Stale(val)) => Some(val),
+        None => None,
+    };
+    cache.record_lookup_metric(false);
+
+    let load_and_push = || async {
         let val = get_or_populate_redis(redis, key, fun).await?;
+        // The serialized value is a closer estimate of the memory used than the size of `T`, as
+        // it accounts for the data on the heap
+        let estimated_size = serde_json::to_vec(&val)
+            .map(|serialized_val| serialized_val.len())
+            .unwrap_or(std::mem::size_of::<T>());
         cache
-            .push(
-                CacheKey {
-                    key: key.to_string(),
-                    prefix: redis.key_prefix.clone(),
-                },
-                val.clone(),
-            )
+            .insert(cache_key.clone(), val.clone(), estimated_size)
             .await;
-        Ok(val)
+        Ok::<_, Report<StorageError>>(val)
+    };
+    let record_stale_served = || {
+        metrics::IN_MEMORY_CACHE_STALE_SERVED.add(
+            1,
+            router_env::metric_attributes!(("cache_type", cache.name)),
+        );
+    };
+
+    match (
+        cache.begin_load(&String::from(cache_key.clone())),
+        stale_val,
+    ) {
+        (LoadPermit::Leader(_in_flight_load), None) => load_and_push().await,
+        (LoadPermit::Leader(_in_flight_load), Some(stale_val)) => match load_and_push().await {
+            Ok(val) => Ok(val),
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    cache_type = cache.name,
+                    "Failed to refresh stale cache entry, serving the stale value"
+                );
+                record_stale_served();
+                Ok(stale_val)
+            }
+        },
+        // The entry is being refreshed by another caller
+        (LoadPermit::Follower(_), Some(stale_val)) => {
+            record_stale_served();
+            Ok(stale_val)
+        }
+        (LoadPermit::Follower(semaphore), None) => {
+            // Returns once the caller loading the key closes the semaphore
+            let _ = semaphore.acquire().await;
+            metrics::IN_MEMORY_CACHE_COALESCED_WAIT.add(
+                1,
+                router_env::metric_attributes!(("cache_type", cache.name)),
+            );
+
+            match cache.lookup::<T>(cache_key.clone()).await {
+                Some(CacheLookup::Fresh(val)) => Ok(val),
+                // The load failed, so the key is loaded by this caller instead
+                Some(CacheLookup::Stale(_)) | None => load_and_push().await,
+            }
+        }
     }
 }
 
@@ -427,6 +686,163 @@ pub async fn redact_from_redis_and_publish<
         .sum::<usize>())
 }
 
+/// Request published on the cache introspection channel, every pod executes the operation on its
+/// caches and appends its report to the list stored at `reports_key`
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CacheIntrospectionRequest {
+    pub tenant: String,
+    pub reports_key: String,
+    pub operation: CacheIntrospectionOperation,
+}
+
+impl TryFrom<&CacheIntrospectionRequest> for RedisValue {
+    type Error = Report<errors::ValidationError>;
+
+    fn try_from(v: &CacheIntrospectionRequest) -> Result<Self, Self::Error> {
+        Ok(Self::from_bytes(serde_json::to_vec(v).change_context(
+            errors::ValidationError::InvalidValue {
+                message: "Invalid cache introspection request provided in pubsub".into(),
+            },
+        )?))
+    }
+}
+
+impl TryFrom<RedisValue> for CacheIntrospectionRequest {
+    type Error = Report<errors::ValidationError>;
+
+    fn try_from(v: RedisValue) -> Result<Self, Self::Error> {
+        let bytes = v.as_bytes().ok_or(errors::ValidationError::InvalidValue {
+            message: "InvalidValue received in pubsub".to_string(),
+        })?;
+
+        bytes
+            .parse_struct("CacheIntrospectionRequest")
+            .change_context(errors::ValidationError::InvalidValue {
+                message: "Unable to deserialize the cache introspection request from pubsub"
+                    .to_string(),
+            })
+    }
+}
+
+/// Executes the operation on the caches of this pod
+pub async fn introspect_caches(
+    tenant: &str,
+    operation: &CacheIntrospectionOperation,
+) -> PodCacheReport {
+    let mut caches = Vec::new();
+
+    for cache in get_all_caches() {
+        let (key_present, invalidated_entries) = match operation {
+            CacheIntrospectionOperation::Stats => (None, None),
+            CacheIntrospectionOperation::Lookup { key } => {
+                let key_present = cache
+                    .exists(CacheKey {
+                        key: key.clone(),
+                        prefix: tenant.to_owned(),
+                    })
+                    .await;
+                (Some(key_present), None)
+            }
+            CacheIntrospectionOperation::InvalidatePrefix { prefix } => {
+                let invalidated_entries = cache
+                    .remove_by_prefix(CacheKey {
+                        key: prefix.clone(),
+                        prefix: tenant.to_owned(),
+                    })
+                    .await;
+                (None, Some(invalidated_entries))
+            }
+        };
+
+        cache.run_pending_tasks().await;
+        caches.push(CacheReport {
+            cache_name: cache.name.to_owned(),
+            entry_count: cache.get_entry_count(),
+            estimated_size_bytes: cache.get_estimated_size(),
+            key_present,
+            invalidated_entries,
+        });
+    }
+
+    PodCacheReport {
+        pod: std::env::var("POD_NAME").unwrap_or(String::from("hyperswitch-server-default")),
+        caches,
+    }
+}
+
+/// Publishes the operation to every pod and collects the pods' reports. Reports of the pods which
+/// do not respond within the timeout are left out.
+#[instrument(skip_all)]
+pub async fn publish_cache_introspection(
+    store: &(dyn RedisConnInterface + Send + Sync),
+    operation: CacheIntrospectionOperation,
+) -> CustomResult<(usize, Vec<PodCacheReport>), StorageError> {
+    let redis_conn = store
+        .get_redis_conn()
+        .change_context(StorageError::RedisError(
+            RedisError::RedisConnectionError.into(),
+        ))
+        .attach_printable("Failed to get redis connection")?;
+
+    let reports_key = common_utils::generate_id(32, "cache_introspection_reports");
+    let request = CacheIntrospectionRequest {
+        tenant: redis_conn.key_prefix.clone(),
+        reports_key: reports_key.clone(),
+        operation,
+    };
+
+    let pods_expected = redis_conn
+        .publish_cache_introspection_request(&request)
+        .await
+        .change_context(StorageError::KVError)?;
+
+    let started_at = Instant::now();
+    loop {
+        let reports_received = redis_conn
+            .get_list_length(&reports_key.as_str().into())
+            .await
+            .change_context(StorageError::KVError)?;
+
+        if reports_received >= pods_expected || started_at.elapsed() >= CACHE_INTROSPECTION_TIMEOUT
+        {
+            break;
+        }
+
+        tokio::time::sleep(CACHE_INTROSPECTION_POLL_INTERVAL).await;
+    }
+
+    let reports = redis_conn
+        .get_list_elements(&reports_key.as_str().into(), 0, -1)
+        .await
+        .change_context(StorageError::KVError)?
+        .into_iter()
+        .filter_map(|report| {
+            serde_json::from_str::<PodCacheReport>(&report)
+                .inspect_err(|error| logger::error!(?error, "Invalid pod cache report"))
+                .ok()
+        })
+        .collect();
+
+    Ok((pods_expected, reports))
+}
+
+/// Appends the report of this pod to the list of reports of the cache introspection request
+pub async fn push_cache_introspection_report(
+    redis_conn: &RedisConnectionPool,
+    reports_key: &str,
+    report: &PodCacheReport,
+) -> CustomResult<(), RedisError> {
+    let report =
+        serde_json::to_string(report).change_context(RedisError::JsonSerializationFailed)?;
+
+    redis_conn
+        .append_elements_to_list(&reports_key.into(), report)
+        .await?;
+    redis_conn
+        .set_expiry(&reports_key.into(), CACHE_INTROSPECTION_REPORTS_TTL)
+        .await
+}
+
 #[instrument(skip_all)]
 pub async fn publish_and_redact<'a, T, F, Fut>(
     store: &(dyn RedisConnInterface + Send + Sync),
@@ -539,6 +955,92 @@ mod cache_tests {
         );
     }
 
+    #[tokio::test]
+    async fn serve_stale_after_time_to_live() {
+        let cache = Cache::new_with_stale_while_revalidate("test", 1, 1800, None, 1800);
+        let key = CacheKey {
+            key: "key".to_string(),
+            prefix: "prefix".to_string(),
+        };
+        cache.push(key.clone(), "val".to_string()).await;
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+        assert_eq!(cache.get_val::<String>(key.clone()).await, None);
+        assert!(matches!(
+            cache.lookup::<String>(key).await,
+            Some(CacheLookup::Stale(val)) if val == "val"
+        ));
+    }
+
+    #[tokio::test]
+    async fn coalesce_concurrent_loads() {
+        let cache = Cache::new("test", 1800, 1800, None);
+
+        let in_flight_load = cache.begin_load("prefix:key");
+        assert!(matches!(in_flight_load, LoadPermit::Leader(_)));
+
+        let waiting_load = cache.begin_load("prefix:key");
+        assert!(matches!(waiting_load, LoadPermit::Follower(_)));
+        assert!(matches!(
+            cache.begin_load("prefix:other_key"),
+            LoadPermit::Leader(_)
+        ));
+
+        drop(in_flight_load);
+        if let LoadPermit::Follower(semaphore) = waiting_load {
+            assert!(semaphore.acquire().await.is_err());
+        }
+        assert!(matches!(
+            cache.begin_load("prefix:key"),
+            LoadPermit::Leader(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn invalidate_cache_by_prefix() {
+        let cache = Cache::new("test", 1800, 1800, None);
+        for key in [
+            "merchant_1_config",
+            "merchant_1_routing",
+            "merchant_2_config",
+        ] {
+            cache
+                .push(
+                    CacheKey {
+                        key: key.to_string(),
+                        prefix: "prefix".to_string(),
+                    },
+                    "val".to_string(),
+                )
+                .await;
+        }
+
+        let invalidated_entries = cache
+            .remove_by_prefix(CacheKey {
+                key: "merchant_1".to_string(),
+                prefix: "prefix".to_string(),
+            })
+            .await;
+
+        assert_eq!(invalidated_entries, 2);
+        assert!(
+            cache
+                .exists(CacheKey {
+                    key: "merchant_2_config".to_string(),
+                    prefix: "prefix".to_string(),
+                })
+                .await
+        );
+        assert!(
+            !cache
+                .exists(CacheKey {
+                    key: "merchant_1_config".to_string(),
+                    prefix: "prefix".to_string(),
+                })
+                .await
+        );
+    }
+
     #[tokio::test]
     async fn eviction_on_time_test() {
         let cache = Cache::new("test", 2, 2, None);
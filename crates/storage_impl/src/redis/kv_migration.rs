@@ -0,0 +1,160 @@
+//! Helpers for moving a merchant between the `redis_kv` and `postgres_only` storage schemes
+//! without losing the writes which are still pending in the drainer streams.
+//!
+//! While a migration is in progress, a grace period is recorded for the merchant. As long as its
+//! grace period is active, the storage scheme of the merchant, if `postgres_only`, is decided as
+//! in the soft kill mode, so that reads check both Redis and Postgres and updates of the records
+//! still present in Redis are applied there.
+
+use std::sync::LazyLock;
+
+use common_utils::{errors::CustomResult, id_type};
+use redis_interface::{errors::RedisError, DelReply, RedisConnectionPool};
+use router_env::logger;
+
+use crate::redis::cache::{Cache, CacheKey};
+
+/// Time for which the grace period of a merchant read from Redis is are cached in memory, 10 secs.
+///
+/// A newly recorded grace period is only guaranteed to be observed by every pod after this time.
+pub const KV_MIGRATION_GRACE_PERIODS_CACHE_TTL: u64 = 10;
+
+/// Number of drainer stream entries read at once while counting the pending entries
+const DRAINER_STREAM_READ_COUNT: u64 = 1000;
+
+/// Cache holding the end of the grace period of every merchant read recently, keyed by the
+/// merchant id
+static KV_MIGRATION_GRACE_PERIODS_CACHE: LazyLock<Cache> = LazyLock::new(|| {
+    Cache::new(
+        "KV_MIGRATION_GRACE_PERIODS_CACHE",
+        KV_MIGRATION_GRACE_PERIODS_CACHE_TTL,
+        KV_MIGRATION_GRACE_PERIODS_CACHE_TTL,
+        None,
+    )
+});
+
+/// Name of the drainer stream of the given shard
+pub fn get_drainer_stream_name(shard_key: &str, drainer_stream_name: &str) -> String {
+    format!("{{{shard_key}}}_{drainer_stream_name}")
+}
+
+/// Records the end of the grace period of the merchant, which expires after `ttl` seconds if it
+/// is not refreshed
+pub async fn set_kv_migration_grace_period(
+    redis: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    grace_period_ends_at: i64,
+    ttl: i64,
+) -> CustomResult<(), RedisError> {
+    redis
+        .set_key_with_expiry(
+            &merchant_id.get_kv_migration_grace_period_key().into(),
+            grace_period_ends_at,
+            ttl,
+        )
+        .await
+}
+
+pub async fn delete_kv_migration_grace_period(
+    redis: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<DelReply, RedisError> {
+    redis
+        .delete_key(&merchant_id.get_kv_migration_grace_period_key().into())
+        .await
+}
+
+/// Whether the grace period of the merchant is active. Failures in reading the grace period are
+/// logged and treated as no grace period being active.
+pub async fn is_kv_migration_grace_period_active(
+    redis: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+) -> bool {
+    let grace_period_key = merchant_id.get_kv_migration_grace_period_key();
+    let cache_key = CacheKey {
+        key: grace_period_key.clone(),
+        prefix: redis.key_prefix.clone(),
+    };
+
+    let grace_period_ends_at = match KV_MIGRATION_GRACE_PERIODS_CACHE
+        .get_val::<i64>(cache_key.clone())
+        .await
+    {
+        Some(grace_period_ends_at) => grace_period_ends_at,
+        None => {
+            let grace_period_ends_at = redis
+                .get_key::<Option<i64>>(&grace_period_key.into())
+                .await
+                .map(Option::unwrap_or_default)
+                .unwrap_or_else(|error| {
+                    logger::error!(?error, "Failed to read the KV migration grace period");
+                    0
+                });
+            KV_MIGRATION_GRACE_PERIODS_CACHE
+                .push(cache_key, grace_period_ends_at)
+                .await;
+            grace_period_ends_at
+        }
+    };
+
+    grace_period_ends_at > common_utils::date_time::now_unix_timestamp()
+}
+
+/// Counts the entries of the merchant which are yet to be drained, across all the drainer shards.
+///
+/// The drainer removes the entries from the streams once they are applied to the database, so
+/// the entries still present in the streams are the pending ones. Entries are attributed to the
+/// merchant through their `global_id`, which is the partition key of the record.
+pub async fn count_pending_drainer_entries(
+    redis: &RedisConnectionPool,
+    drainer_stream_name: &str,
+    drainer_num_partitions: u8,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<usize, RedisError> {
+    let global_id_prefix = format!("mid_{}_", merchant_id.get_string_repr());
+    let mut pending_entries = 0;
+
+    for partition in 0..drainer_num_partitions {
+        let stream_name =
+            get_drainer_stream_name(&format!("shard_{partition}"), drainer_stream_name);
+        let mut last_entry_id = "0-0".to_string();
+
+        loop {
+            let entries = match redis
+                .stream_read_entries(
+                    stream_name.as_str(),
+                    last_entry_id.as_str(),
+                    Some(DRAINER_STREAM_READ_COUNT),
+                )
+                .await
+            {
+                Ok(response) => response.into_values().flatten().collect::<Vec<_>>(),
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        RedisError::StreamEmptyOrNotAvailable
+                    ) =>
+                {
+                    break
+                }
+                Err(error) => return Err(error),
+            };
+
+            let Some((entry_id, _)) = entries.last() else {
+                break;
+            };
+            last_entry_id = entry_id.clone();
+
+            pending_entries += entries
+                .iter()
+                .filter(|(_, fields)| {
+                    fields
+                        .get("global_id")
+                        .is_some_and(|global_id| global_id.starts_with(&global_id_prefix))
+                })
+                .count();
+        }
+    }
+
+    Ok(pending_entries)
+}
@@ -51,6 +51,10 @@ pub enum PartitionKey<'a> {
         merchant_id: &'a common_utils::id_type::MerchantId,
         mandate_id: &'a str,
     },
+    MerchantIdAuthenticationId {
+        merchant_id: &'a common_utils::id_type::MerchantId,
+        authentication_id: &'a common_utils::id_type::AuthenticationId,
+    },
     #[cfg(feature = "v2")]
     GlobalId {
         id: &'a str,
@@ -111,6 +115,14 @@ impl std::fmt::Display for PartitionKey<'_> {
                 "mid_{}_mandate_{mandate_id}",
                 merchant_id.get_string_repr()
             )),
+            PartitionKey::MerchantIdAuthenticationId {
+                merchant_id,
+                authentication_id,
+            } => f.write_str(&format!(
+                "mid_{}_authn_{}",
+                merchant_id.get_string_repr(),
+                authentication_id.get_string_repr()
+            )),
 
             #[cfg(feature = "v2")]
             PartitionKey::GlobalId { id } => f.write_str(&format!("global_cust_{id}")),
@@ -299,10 +311,15 @@ impl std::fmt::Display for Op<'_> {
     }
 }
 
+/// Decides the storage scheme for the operation on a record of the merchant.
+///
+/// `merchant_id` is the merchant whose KV migration grace period is checked, it is `None` only for
+/// the lookups whose storage scheme has already been decided by the operation performing them.
 pub async fn decide_storage_scheme<T, D>(
     store: &KVRouterStore<T>,
     storage_scheme: MerchantStorageScheme,
     operation: Op<'_>,
+    merchant_id: Option<&common_utils::id_type::MerchantId>,
 ) -> MerchantStorageScheme
 where
     D: de::DeserializeOwned
@@ -313,36 +330,144 @@ where
         + Sync,
     T: crate::database::store::DatabaseStore,
 {
-    if store.soft_kill_mode {
+    // The storage scheme of a `postgres_only` merchant is decided as in the soft kill mode while
+    // the merchant is being migrated between the storage schemes, as its records could still be
+    // present in Redis
+    let decided_by = if store.soft_kill_mode {
+        Some("soft_kill_mode")
+    } else {
+        match get_kv_migration_merchant_id(storage_scheme, merchant_id) {
+            Some(merchant_id) if is_kv_migration_grace_period_active(store, merchant_id).await => {
+                Some("kv_migration_grace_period")
+            }
+            _ => None,
+        }
+    };
+
+    if let Some(decided_by) = decided_by {
         let ops = operation.to_string();
-        let updated_scheme = match operation {
-            Op::Insert => MerchantStorageScheme::PostgresOnly,
-            Op::Find => MerchantStorageScheme::RedisKv,
-            Op::Update(_, _, Some("postgres_only")) => MerchantStorageScheme::PostgresOnly,
-            Op::Update(partition_key, field, Some(_updated_by)) => {
-                match Box::pin(kv_wrapper::<D, _, _>(
-                    store,
-                    KvOperation::<D>::HGet(field),
-                    partition_key,
-                ))
-                .await
-                {
-                    Ok(_) => {
-                        metrics::KV_SOFT_KILL_ACTIVE_UPDATE.add(1, &[]);
-                        MerchantStorageScheme::RedisKv
+        let updated_scheme = match get_soft_kill_storage_scheme(&operation) {
+            Some(updated_scheme) => updated_scheme,
+            None => match operation {
+                Op::Update(partition_key, field, _) => {
+                    match Box::pin(kv_wrapper::<D, _, _>(
+                        store,
+                        KvOperation::<D>::HGet(field),
+                        partition_key,
+                    ))
+                    .await
+                    {
+                        Ok(_) => {
+                            metrics::KV_SOFT_KILL_ACTIVE_UPDATE.add(1, &[]);
+                            MerchantStorageScheme::RedisKv
+                        }
+                        Err(_) => MerchantStorageScheme::PostgresOnly,
                     }
-                    Err(_) => MerchantStorageScheme::PostgresOnly,
                 }
-            }
-
-            Op::Update(_, _, None) => MerchantStorageScheme::PostgresOnly,
+                Op::Insert | Op::Find => MerchantStorageScheme::PostgresOnly,
+            },
         };
 
         let type_name = std::any::type_name::<D>();
-        logger::info!(soft_kill_mode = "decide_storage_scheme", decided_scheme = %updated_scheme, configured_scheme = %storage_scheme,entity = %type_name, operation = %ops);
+        logger::info!(soft_kill_mode = "decide_storage_scheme", decided_by, decided_scheme = %updated_scheme, configured_scheme = %storage_scheme,entity = %type_name, operation = %ops);
 
         updated_scheme
     } else {
         storage_scheme
     }
 }
+
+/// Storage scheme of the operation in the soft kill mode, `None` if it depends on whether the
+/// record being updated is present in Redis
+fn get_soft_kill_storage_scheme(operation: &Op<'_>) -> Option<MerchantStorageScheme> {
+    match operation {
+        Op::Insert => Some(MerchantStorageScheme::PostgresOnly),
+        Op::Find => Some(MerchantStorageScheme::RedisKv),
+        Op::Update(_, _, Some("postgres_only")) => Some(MerchantStorageScheme::PostgresOnly),
+        Op::Update(_, _, Some(_updated_by)) => None,
+        Op::Update(_, _, None) => Some(MerchantStorageScheme::PostgresOnly),
+    }
+}
+
+/// The merchant whose KV migration grace period decides the storage scheme, only `postgres_only`
+/// merchants are affected by the grace period
+fn get_kv_migration_merchant_id(
+    storage_scheme: MerchantStorageScheme,
+    merchant_id: Option<&common_utils::id_type::MerchantId>,
+) -> Option<&common_utils::id_type::MerchantId> {
+    merchant_id.filter(|_| storage_scheme == MerchantStorageScheme::PostgresOnly)
+}
+
+async fn is_kv_migration_grace_period_active<T: crate::database::store::DatabaseStore>(
+    store: &KVRouterStore<T>,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> bool {
+    match store.get_redis_conn() {
+        Ok(redis_conn) => {
+            super::kv_migration::is_kv_migration_grace_period_active(&redis_conn, merchant_id).await
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_soft_kill_storage_scheme() {
+        let partition_key = PartitionKey::CombinationKey {
+            combination: "combination",
+        };
+
+        assert_eq!(
+            get_soft_kill_storage_scheme(&Op::Insert),
+            Some(MerchantStorageScheme::PostgresOnly)
+        );
+        assert_eq!(
+            get_soft_kill_storage_scheme(&Op::Find),
+            Some(MerchantStorageScheme::RedisKv)
+        );
+        assert_eq!(
+            get_soft_kill_storage_scheme(&Op::Update(
+                partition_key.clone(),
+                "field",
+                Some("postgres_only")
+            )),
+            Some(MerchantStorageScheme::PostgresOnly)
+        );
+        assert_eq!(
+            get_soft_kill_storage_scheme(&Op::Update(partition_key.clone(), "field", None)),
+            Some(MerchantStorageScheme::PostgresOnly)
+        );
+        // Updates by the KV flow are applied wherever the record currently is
+        assert_eq!(
+            get_soft_kill_storage_scheme(&Op::Update(partition_key, "field", Some("redis_kv"))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_kv_migration_merchant_id() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant"))
+                .unwrap();
+
+        assert_eq!(
+            get_kv_migration_merchant_id(MerchantStorageScheme::PostgresOnly, Some(&merchant_id)),
+            Some(&merchant_id)
+        );
+        assert_eq!(
+            get_kv_migration_merchant_id(MerchantStorageScheme::RedisKv, Some(&merchant_id)),
+            None
+        );
+        assert_eq!(
+            get_kv_migration_merchant_id(MerchantStorageScheme::PostgresOnly, None),
+            None
+        );
+    }
+}
@@ -5,7 +5,8 @@ use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
 use router_env::{logger, tracing::Instrument};
 
 use crate::redis::cache::{
-    CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
+    introspect_caches, push_cache_introspection_report, CacheIntrospectionRequest, CacheKey,
+    CacheKind, CacheRedact, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
     CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE, DECISION_MANAGER_CACHE,
     ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE, PM_FILTERS_CGRAPH_CACHE, ROUTING_CACHE,
     SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE, SURCHARGE_CACHE,
@@ -21,6 +22,11 @@ pub trait PubSubInterface {
         key: CacheKind<'a>,
     ) -> error_stack::Result<usize, redis_errors::RedisError>;
 
+    async fn publish_cache_introspection_request(
+        &self,
+        request: &CacheIntrospectionRequest,
+    ) -> error_stack::Result<usize, redis_errors::RedisError>;
+
     async fn on_message(&self) -> error_stack::Result<(), redis_errors::RedisError>;
 }
 
@@ -82,6 +88,21 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
             .change_context(redis_errors::RedisError::SubscribeError)
     }
 
+    #[inline]
+    async fn publish_cache_introspection_request(
+        &self,
+        request: &CacheIntrospectionRequest,
+    ) -> error_stack::Result<usize, redis_errors::RedisError> {
+        self.publisher
+            .publish(
+                super::cache::CACHE_INTROSPECTION_CHANNEL,
+                RedisValue::try_from(request)
+                    .change_context(redis_errors::RedisError::PublishError)?,
+            )
+            .await
+            .change_context(redis_errors::RedisError::PublishError)
+    }
+
     #[inline]
     async fn on_message(&self) -> error_stack::Result<(), redis_errors::RedisError> {
         logger::debug!("Started on message");
@@ -265,6 +286,37 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                         "Done invalidating {key}"
                     );
                 }
+                super::cache::CACHE_INTROSPECTION_CHANNEL => {
+                    let request =
+                        match CacheIntrospectionRequest::try_from(RedisValue::new(message.value))
+                            .change_context(redis_errors::RedisError::OnMessageError)
+                        {
+                            Ok(value) => value,
+                            Err(err) => {
+                                logger::error!(value_conversion_err=?err);
+                                continue;
+                            }
+                        };
+
+                    let report = introspect_caches(&request.tenant, &request.operation).await;
+
+                    // The report is stored under the tenant of the pod which published the request
+                    let redis_conn =
+                        redis_interface::RedisConnectionPool::clone(self, &request.tenant);
+                    if let Err(error) =
+                        push_cache_introspection_report(&redis_conn, &request.reports_key, &report)
+                            .await
+                    {
+                        logger::error!(?error, "Failed to push cache introspection report");
+                    }
+
+                    logger::debug!(
+                        key_prefix=?request.tenant,
+                        channel_name=?channel_name,
+                        operation=?request.operation,
+                        "Done executing cache introspection request"
+                    );
+                }
                 _ => {
                     logger::debug!("Received message from unknown channel: {channel_name}");
                 }
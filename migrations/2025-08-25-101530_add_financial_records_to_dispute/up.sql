@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE dispute
+ADD COLUMN IF NOT EXISTS financial_records JSONB;
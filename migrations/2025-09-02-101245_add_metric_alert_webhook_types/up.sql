@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'metric_alerts';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'metric_alert_triggered';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'metric_alert_details';
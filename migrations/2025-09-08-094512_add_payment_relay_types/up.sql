@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "RelayType" ADD VALUE IF NOT EXISTS 'capture';
+
+ALTER TYPE "RelayType" ADD VALUE IF NOT EXISTS 'void';
+
+ALTER TYPE "RelayType" ADD VALUE IF NOT EXISTS 'incremental_authorization';
+
+ALTER TYPE "RelayType" ADD VALUE IF NOT EXISTS 'sync';
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS reusable_payment_link (
+    reusable_payment_link_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    amount_type VARCHAR(32) NOT NULL,
+    amount BIGINT,
+    min_amount BIGINT,
+    max_amount BIGINT,
+    currency "Currency" NOT NULL,
+    description VARCHAR(255),
+    payment_link_config JSONB,
+    link_to_pay VARCHAR(255) NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    usage_limit INTEGER,
+    usage_count INTEGER NOT NULL DEFAULT 0,
+    visit_count INTEGER NOT NULL DEFAULT 0,
+    expires_at TIMESTAMP,
+    last_used_at TIMESTAMP,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS reusable_payment_link_merchant_id_created_at_index ON reusable_payment_link (merchant_id, created_at);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS subscription_invoice;
+
+DROP TABLE IF EXISTS subscription;
+
+DROP TABLE IF EXISTS subscription_price;
+
+DROP TABLE IF EXISTS subscription_product;
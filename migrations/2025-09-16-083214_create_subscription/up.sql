@@ -0,0 +1,82 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription_product (
+    product_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description VARCHAR(255),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_product_merchant_id_created_at_index ON subscription_product (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS subscription_price (
+    price_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    product_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(16) NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_period_days INTEGER,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_price_merchant_id_product_id_index ON subscription_price (merchant_id, product_id);
+
+CREATE TABLE IF NOT EXISTS subscription (
+    subscription_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    price_id VARCHAR(64) NOT NULL,
+    pending_price_id VARCHAR(64),
+    payment_method_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    quantity INTEGER NOT NULL DEFAULT 1,
+    billing_cycle_anchor TIMESTAMP NOT NULL,
+    current_period_start TIMESTAMP NOT NULL,
+    current_period_end TIMESTAMP NOT NULL,
+    trial_start TIMESTAMP,
+    trial_end TIMESTAMP,
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT FALSE,
+    cancelled_at TIMESTAMP,
+    pending_proration_amount BIGINT NOT NULL DEFAULT 0,
+    latest_invoice_id VARCHAR(64),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_created_at_index ON subscription (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
+
+CREATE TABLE IF NOT EXISTS subscription_invoice (
+    invoice_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    subscription_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    billing_reason VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    proration_amount BIGINT NOT NULL DEFAULT 0,
+    currency "Currency" NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    period_end TIMESTAMP NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    next_payment_attempt_at TIMESTAMP,
+    error_code VARCHAR(255),
+    error_message TEXT,
+    paid_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_invoice_merchant_id_subscription_id_index ON subscription_invoice (merchant_id, subscription_id);
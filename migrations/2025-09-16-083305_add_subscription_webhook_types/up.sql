@@ -0,0 +1,13 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_created';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_updated';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_past_due';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cancelled';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_created';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_paid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_payment_failed';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'invoice_details';
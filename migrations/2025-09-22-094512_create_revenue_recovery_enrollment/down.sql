@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS revenue_recovery_attempt;
+
+DROP TABLE IF EXISTS revenue_recovery_enrollment;
@@ -0,0 +1,46 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS revenue_recovery_enrollment (
+    enrollment_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    decline_category VARCHAR(64),
+    retry_count INTEGER NOT NULL DEFAULT 0,
+    max_retries INTEGER NOT NULL,
+    next_retry_at TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    last_error_code VARCHAR(255),
+    last_error_message TEXT,
+    recovered_payment_id VARCHAR(64),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS revenue_recovery_enrollment_merchant_id_payment_id_index ON revenue_recovery_enrollment (merchant_id, payment_id);
+
+CREATE INDEX IF NOT EXISTS revenue_recovery_enrollment_merchant_id_created_at_index ON revenue_recovery_enrollment (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS revenue_recovery_attempt (
+    attempt_id VARCHAR(64) PRIMARY KEY,
+    enrollment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_number INTEGER NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    error_code VARCHAR(255),
+    error_message TEXT,
+    decline_category VARCHAR(64),
+    attempted_at TIMESTAMP NOT NULL,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS revenue_recovery_attempt_merchant_id_enrollment_id_index ON revenue_recovery_attempt (merchant_id, enrollment_id);
+
+CREATE INDEX IF NOT EXISTS revenue_recovery_attempt_merchant_id_attempted_at_index ON revenue_recovery_attempt (merchant_id, attempted_at);
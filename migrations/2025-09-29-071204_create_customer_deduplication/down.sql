@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS customer_merge;
+
+DROP TABLE IF EXISTS customer_blind_index;
@@ -0,0 +1,33 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS customer_blind_index (
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    field VARCHAR(32) NOT NULL,
+    hashed_value VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, customer_id, field)
+);
+
+CREATE INDEX IF NOT EXISTS customer_blind_index_merchant_id_field_hashed_value_index ON customer_blind_index (merchant_id, field, hashed_value);
+
+CREATE TABLE IF NOT EXISTS customer_merge (
+    merge_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    surviving_customer_id VARCHAR(64) NOT NULL,
+    merged_customer_id VARCHAR(64) NOT NULL,
+    payment_method_ids TEXT[] NOT NULL,
+    mandate_ids TEXT[] NOT NULL,
+    payments_moved BIGINT NOT NULL,
+    reason VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    status VARCHAR(32) NOT NULL,
+    orphaned_locker_references TEXT[] NOT NULL,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS customer_merge_merchant_id_surviving_customer_id_index ON customer_merge (merchant_id, surviving_customer_id);
+
+CREATE INDEX IF NOT EXISTS customer_merge_merchant_id_merged_customer_id_index ON customer_merge (merchant_id, merged_customer_id);
+
+-- A customer can be merged into a single other customer at a time
+CREATE UNIQUE INDEX IF NOT EXISTS customer_merge_merchant_id_merged_customer_id_in_progress_index ON customer_merge (merchant_id, merged_customer_id) WHERE status = 'in_progress';
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_method_account_update (
+    update_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    previous_card_last4 VARCHAR(4),
+    previous_card_exp_month VARCHAR(2),
+    previous_card_exp_year VARCHAR(4),
+    updated_card_last4 VARCHAR(4),
+    updated_card_exp_month VARCHAR(2),
+    updated_card_exp_year VARCHAR(4),
+    error_code VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payment_method_account_update_merchant_id_payment_method_id_index ON payment_method_account_update (merchant_id, payment_method_id, created_at);
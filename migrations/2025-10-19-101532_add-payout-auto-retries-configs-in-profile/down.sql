@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+-- Drop is_payout_auto_retries_enabled column from business_profile table
+ALTER TABLE business_profile DROP COLUMN IF EXISTS is_payout_auto_retries_enabled;
+
+-- Drop max_payout_auto_retries_enabled column from business_profile table
+ALTER TABLE business_profile DROP COLUMN IF EXISTS max_payout_auto_retries_enabled;